@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, spanned::Spanned, Data, DeriveInput, LitStr, Path};
+
+/// Parses the `#[fixed_layout(crate = "...")]` attribute of the struct, returning the path of the
+/// `km_shared` crate.
+fn krate(input: &DeriveInput) -> syn::Result<Path> {
+    let mut krate = parse_quote!(::km_shared);
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("fixed_layout"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse::<LitStr>()?.parse()?;
+            } else {
+                return Err(meta.error("unknown `fixed_layout` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(krate)
+}
+
+/// Whether the struct is `repr(C)` or `repr(transparent)`, the layouts with defined field offsets.
+fn has_defined_layout(input: &DeriveInput) -> syn::Result<bool> {
+    let mut defined = false;
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                defined = true;
+            }
+            // skip the arguments of e.g. `align(8)`
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(defined)
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = krate(&input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`FixedLayout` can only be derived for structs",
+        ));
+    };
+
+    if !has_defined_layout(&input)? {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`FixedLayout` requires `#[repr(C)]` or `#[repr(transparent)]`",
+        ));
+    }
+
+    // every field has to have a fixed layout itself, which also rejects pointer-sized fields
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in &data.fields {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: #krate::ioctl::FixedLayout));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        // SAFETY: Macro generated; the struct has a defined layout, and consists of fields with
+        // fixed layouts.
+        unsafe impl #impl_generics #krate::ioctl::FixedLayout for #name #ty_generics #where_clause {}
+    })
+}
@@ -0,0 +1,41 @@
+//! Derive macros for `km-shared`, re-exported from there.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod fixed_layout;
+mod validate;
+mod wire;
+
+/// Derives `km_shared::wire::WireFormat` for a struct. See the documentation of the trait for
+/// the supported attributes.
+#[proc_macro_derive(WireFormat, attributes(wire))]
+pub fn derive_wire_format(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    wire::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `km_shared::ioctl::FixedLayout` for a `#[repr(C)]` struct whose fields all implement
+/// it. See the documentation of the trait.
+#[proc_macro_derive(FixedLayout, attributes(fixed_layout))]
+pub fn derive_fixed_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    fixed_layout::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `km_shared::ioctl::Validate` for a struct, checking the constraints of its
+/// `#[validate(...)]` field attributes. See the documentation of the derive's re-export.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    validate::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse_quote, spanned::Spanned, Data, DeriveInput, Expr, ExprArray, Index, LitStr, Member, Path,
+};
+
+/// A constraint of a field, from a `#[validate(...)]` attribute.
+enum Constraint {
+    Range(Expr),
+    OneOf(ExprArray),
+    Flags(Expr),
+    With(Path),
+    Nested,
+}
+
+/// Parses the `#[validate(crate = "...")]` attribute of the struct, returning the path of the
+/// `km_shared` crate.
+fn krate(input: &DeriveInput) -> syn::Result<Path> {
+    let mut krate = parse_quote!(::km_shared);
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse::<LitStr>()?.parse()?;
+            } else {
+                return Err(meta.error("unknown `validate` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(krate)
+}
+
+fn constraints(field: &syn::Field) -> syn::Result<Vec<Constraint>> {
+    let mut constraints = Vec::new();
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("range") {
+                constraints.push(Constraint::Range(meta.value()?.parse()?));
+            } else if meta.path.is_ident("one_of") {
+                constraints.push(Constraint::OneOf(meta.value()?.parse()?));
+            } else if meta.path.is_ident("flags") {
+                constraints.push(Constraint::Flags(meta.value()?.parse()?));
+            } else if meta.path.is_ident("with") {
+                constraints.push(Constraint::With(meta.value()?.parse()?));
+            } else if meta.path.is_ident("nested") {
+                constraints.push(Constraint::Nested);
+            } else {
+                return Err(meta.error("unknown `validate` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(constraints)
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = krate(&input)?;
+    let validate = quote!(#krate::ioctl::Validate);
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`Validate` can only be derived for structs",
+        ));
+    };
+
+    let mut checks = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+        let index = i as u32;
+
+        for constraint in constraints(field)? {
+            let valid = match constraint {
+                Constraint::Range(range) => quote!((#range).contains(&self.#member)),
+                Constraint::OneOf(values) => quote!(#values.contains(&self.#member)),
+                Constraint::Flags(mask) => quote!(self.#member & !(#mask) == 0),
+                Constraint::With(check) => quote!(#check(&self.#member)),
+                Constraint::Nested => quote!(#validate::validate(&self.#member).is_ok()),
+            };
+            checks.push(quote! {
+                if !(#valid) {
+                    return ::core::result::Result::Err(#krate::ioctl::InvalidField {
+                        index: #index,
+                    });
+                }
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #validate for #name #ty_generics #where_clause {
+            fn validate(&self) -> ::core::result::Result<(), #krate::ioctl::InvalidField> {
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,202 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, spanned::Spanned, Data, DeriveInput, Fields, Index, LitInt, LitStr, Member, Path,
+};
+
+/// The options of the `#[wire(...)]` attribute of the struct.
+struct ContainerOptions {
+    /// Path of the `km_shared` crate, e.g. `km::shared` if used through `km`.
+    krate: Path,
+    /// The version written by `encode`, if the struct is versioned.
+    version: Option<u16>,
+}
+
+/// The options of the `#[wire(...)]` attribute of a field.
+#[derive(Default)]
+struct FieldOptions {
+    big_endian: bool,
+    /// The version the field was added in.
+    since: Option<u16>,
+}
+
+fn container_options(input: &DeriveInput) -> syn::Result<ContainerOptions> {
+    let mut options = ContainerOptions {
+        krate: parse_quote!(::km_shared),
+        version: None,
+    };
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("wire")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                options.krate = meta.value()?.parse::<LitStr>()?.parse()?;
+            } else if meta.path.is_ident("version") {
+                options.version = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+            } else {
+                return Err(meta.error("unknown `wire` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("wire")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("big_endian") {
+                options.big_endian = true;
+            } else if meta.path.is_ident("since") {
+                options.since = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+            } else {
+                return Err(meta.error("unknown `wire` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+pub fn derive(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let options = container_options(&input)?;
+    let krate = &options.krate;
+    let wire = quote!(#krate::wire);
+    let io = quote!(#krate::io);
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`WireFormat` can only be derived for structs",
+        ));
+    };
+
+    let mut members = Vec::new();
+    let mut field_opts = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        let opts = field_options(field)?;
+        if let (Some(since), version) = (opts.since, options.version) {
+            if version.is_none_or(|v| since > v) {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`since` requires a struct `version` at least as high",
+                ));
+            }
+        }
+
+        members.push(match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        });
+        field_opts.push(opts);
+    }
+    let vars: Vec<_> = (0..members.len())
+        .map(|i| format_ident!("__field{i}"))
+        .collect();
+
+    let encode_fields = members.iter().zip(&field_opts).map(|(member, opts)| {
+        if opts.big_endian {
+            quote!(#wire::BigEndianWire::encode_be(&self.#member, writer)?;)
+        } else {
+            quote!(#wire::WireFormat::encode(&self.#member, writer)?;)
+        }
+    });
+    let field_lens = members.iter().zip(&field_opts).map(|(member, opts)| {
+        if opts.big_endian {
+            quote!(#wire::BigEndianWire::encoded_len_be(&self.#member))
+        } else {
+            quote!(#wire::WireFormat::encoded_len(&self.#member))
+        }
+    });
+    let decode_fields = vars.iter().zip(&field_opts).map(|(var, opts)| {
+        let decode = if opts.big_endian {
+            quote!(#wire::BigEndianWire::decode_be(reader)?)
+        } else {
+            quote!(#wire::WireFormat::decode(reader)?)
+        };
+        match opts.since {
+            Some(since) => quote! {
+                let #var = if __version >= #since {
+                    #decode
+                } else {
+                    ::core::default::Default::default()
+                };
+            },
+            None => quote!(let #var = #decode;),
+        }
+    });
+    let construct = match &data.fields {
+        Fields::Named(_) => quote!(Self { #(#members: #vars),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#vars),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let (encode, encoded_len, decode) = match options.version {
+        Some(version) => (
+            quote! {
+                #wire::WireFormat::encode(&#version, writer)?;
+                #wire::WireFormat::encode(&(self.__wire_body_len() as u32), writer)?;
+                #(#encode_fields)*
+                Ok(())
+            },
+            quote!(2 + 4 + self.__wire_body_len()),
+            quote! {
+                let __version = <u16 as #wire::WireFormat>::decode(reader)?;
+                let __len = <u32 as #wire::WireFormat>::decode(reader)? as usize;
+                // Fields added in later versions are skipped along with the rest of the body.
+                let mut __body = #io::ByteReader::new(reader.read_bytes(__len)?);
+                let reader = &mut __body;
+                #(#decode_fields)*
+                Ok(#construct)
+            },
+        ),
+        None => (
+            quote! {
+                #(#encode_fields)*
+                Ok(())
+            },
+            quote!(self.__wire_body_len()),
+            quote! {
+                #(#decode_fields)*
+                Ok(#construct)
+            },
+        ),
+    };
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#wire::WireFormat));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc(hidden)]
+            fn __wire_body_len(&self) -> usize {
+                0 #(+ #field_lens)*
+            }
+        }
+
+        impl #impl_generics #wire::WireFormat for #name #ty_generics #where_clause {
+            fn encode(
+                &self,
+                writer: &mut #io::ByteWriter<'_>,
+            ) -> ::core::result::Result<(), #io::CursorError> {
+                #encode
+            }
+
+            fn encoded_len(&self) -> usize {
+                #encoded_len
+            }
+
+            fn decode(
+                reader: &mut #io::ByteReader<'_>,
+            ) -> ::core::result::Result<Self, #io::CursorError> {
+                #decode
+            }
+        }
+    })
+}
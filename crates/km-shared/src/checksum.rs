@@ -0,0 +1,176 @@
+//! CRC checksums for device payloads, computed identically by drivers and their user mode clients.
+//!
+//! Each width has a type describing the algorithm ([`Crc8`], [`Crc16`], [`Crc32`]), with its
+//! lookup table computed at compile time, and constants for the common variants. Everything is a
+//! `const fn`, so checksums of constant data can be computed at compile time too:
+//!
+//! ```rs, ignore
+//! let pec = CRC8_SMBUS.checksum(&[address << 1, command, value]);
+//!
+//! // payloads received in pieces
+//! let mut digest = CRC32C.digest();
+//! digest.update(header);
+//! digest.update(body);
+//! let crc = digest.finalize();
+//! ```
+//!
+//! Other variants can be declared with the `new` functions, using the parameters of the
+//! [catalogue of CRC algorithms][catalogue], e.g. `Crc16::new(0x8005, 0x0000, true, 0x0000)` for
+//! CRC-16/ARC.
+//!
+//! [catalogue]: https://reveng.sourceforge.io/crc-catalogue/all.htm
+
+/// Declares the CRC algorithm type for an unsigned integer width.
+macro_rules! crc {
+    ($(#[$meta:meta])* $name:ident, $digest:ident, $t:ty) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name {
+            table: [$t; 256],
+            init: $t,
+            reflected: bool,
+            xor_out: $t,
+        }
+
+        impl $name {
+            /// Describes an algorithm by its parameters: the (normal, not reversed) generator
+            /// polynomial, the initial value, whether the input and output are reflected
+            /// (processed least significant bit first), and the value the result is XORed with.
+            pub const fn new(poly: $t, init: $t, reflected: bool, xor_out: $t) -> Self {
+                const BITS: u32 = <$t>::BITS;
+
+                let mut table = [0; 256];
+                let mut i = 0;
+                while i < 256 {
+                    let mut crc: $t;
+                    if reflected {
+                        let poly = poly.reverse_bits();
+                        crc = i as $t;
+                        let mut bit = 0;
+                        while bit < 8 {
+                            crc = if crc & 1 != 0 { (crc >> 1) ^ poly } else { crc >> 1 };
+                            bit += 1;
+                        }
+                    } else {
+                        // the byte goes into the top bits of the register
+                        crc = ((i as u64) << (BITS - 8)) as $t;
+                        let mut bit = 0;
+                        while bit < 8 {
+                            crc = if crc & (1 << (BITS - 1)) != 0 {
+                                (crc << 1) ^ poly
+                            } else {
+                                crc << 1
+                            };
+                            bit += 1;
+                        }
+                    }
+                    table[i] = crc;
+                    i += 1;
+                }
+
+                Self {
+                    table,
+                    init: if reflected { init.reverse_bits() } else { init },
+                    reflected,
+                    xor_out,
+                }
+            }
+
+            /// The checksum of `data`.
+            pub const fn checksum(&self, data: &[u8]) -> $t {
+                let mut digest = self.digest();
+                digest.update(data);
+                digest.finalize()
+            }
+
+            /// Starts computing a checksum of data that's passed in pieces.
+            pub const fn digest(&self) -> $digest<'_> {
+                $digest {
+                    crc: self,
+                    value: self.init,
+                }
+            }
+        }
+
+        #[doc = concat!("A checksum being computed, see [`", stringify!($name), "::digest`].")]
+        #[derive(Clone)]
+        pub struct $digest<'a> {
+            crc: &'a $name,
+            value: $t,
+        }
+
+        impl $digest<'_> {
+            /// Adds `data` to the checksum.
+            pub const fn update(&mut self, data: &[u8]) {
+                const BITS: u32 = <$t>::BITS;
+
+                let table = &self.crc.table;
+                let mut value = self.value;
+                let mut i = 0;
+                while i < data.len() {
+                    value = if self.crc.reflected {
+                        table[((value ^ data[i] as $t) & 0xFF) as usize]
+                            ^ ((value as u64) >> 8) as $t
+                    } else {
+                        table[(((value >> (BITS - 8)) ^ data[i] as $t) & 0xFF) as usize]
+                            ^ ((value as u64) << 8) as $t
+                    };
+                    i += 1;
+                }
+                self.value = value;
+            }
+
+            /// The checksum of the data passed to [`Self::update`].
+            pub const fn finalize(&self) -> $t {
+                self.value ^ self.crc.xor_out
+            }
+        }
+    };
+}
+
+crc! {
+    /// An 8-bit CRC algorithm, see the [module documentation](self).
+    Crc8, Crc8Digest, u8
+}
+
+crc! {
+    /// A 16-bit CRC algorithm, see the [module documentation](self).
+    Crc16, Crc16Digest, u16
+}
+
+crc! {
+    /// A 32-bit CRC algorithm, see the [module documentation](self).
+    Crc32, Crc32Digest, u32
+}
+
+/// CRC-8/SMBUS, the packet error code (PEC) of SMBus transactions, computed over all bytes of the
+/// transaction including the addresses.
+pub const CRC8_SMBUS: Crc8 = Crc8::new(0x07, 0x00, false, 0x00);
+
+/// CRC-16/IBM-3740, commonly (but ambiguously) called CRC-16/CCITT-FALSE.
+pub const CRC16_IBM_3740: Crc16 = Crc16::new(0x1021, 0xFFFF, false, 0x0000);
+
+/// CRC-16/XMODEM, the variant of the XMODEM protocol, also used by many microcontroller
+/// bootloaders.
+pub const CRC16_XMODEM: Crc16 = Crc16::new(0x1021, 0x0000, false, 0x0000);
+
+/// CRC-16/MODBUS, the variant of the Modbus protocol.
+pub const CRC16_MODBUS: Crc16 = Crc16::new(0x8005, 0xFFFF, true, 0x0000);
+
+/// CRC-32/ISO-HDLC, the variant of Ethernet, zlib, and PNG.
+pub const CRC32_ISO_HDLC: Crc32 = Crc32::new(0x04C1_1DB7, 0xFFFF_FFFF, true, 0xFFFF_FFFF);
+
+/// CRC-32C (Castagnoli, CRC-32/ISCSI), which detects more errors than [`CRC32_ISO_HDLC`], and is
+/// implemented in hardware by SSE 4.2.
+pub const CRC32C: Crc32 = Crc32::new(0x1EDC_6F41, 0xFFFF_FFFF, true, 0xFFFF_FFFF);
+
+// Check the algorithms against the check values of the catalogue, the checksums of "123456789".
+const _: () = {
+    const CHECK: &[u8] = b"123456789";
+    assert!(CRC8_SMBUS.checksum(CHECK) == 0xF4);
+    assert!(CRC16_IBM_3740.checksum(CHECK) == 0x29B1);
+    assert!(CRC16_XMODEM.checksum(CHECK) == 0x31C3);
+    assert!(CRC16_MODBUS.checksum(CHECK) == 0x4B37);
+    assert!(CRC32_ISO_HDLC.checksum(CHECK) == 0xCBF4_3926);
+    assert!(CRC32C.checksum(CHECK) == 0xE306_9283);
+};
@@ -0,0 +1,197 @@
+//! Fuzzing entry points for the parsing code shared between drivers and user mode, enabled by the
+//! `fuzzing` feature.
+//!
+//! The code decoding IOCTL payloads runs in the kernel on input from user mode, so it has to
+//! handle arbitrary bytes. Each function here takes arbitrary bytes, runs them through a decode
+//! path, and panics if it misbehaves or an invariant (e.g. a round trip) is violated. They're
+//! meant to be used as [cargo-fuzz] targets, which run in user mode on the host. The kernel
+//! bindings don't build there, so the fuzz crate depends on `km-shared` with just
+//! `features = ["fuzzing"]`, leaving the `km-sys` feature off:
+//!
+//! ```rs, ignore
+//! #![no_main]
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| km_shared::fuzz::smbus_request(data));
+//! ```
+//!
+//! Drivers can fuzz their own payloads with [`wire`]:
+//!
+//! ```rs, ignore
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| km_shared::fuzz::wire::<FanStatus>(data));
+//! ```
+//!
+//! [cargo-fuzz]: https://rust-fuzz.github.io/book/cargo-fuzz.html
+
+use crate::{
+    guid::Guid,
+    io::ByteReader,
+    ioctl::{IoControlCode, ProtocolVersion},
+    smbus::{Block, Direction, Operation, SmBusRequest, SmBusResponse, BLOCK_MAX},
+    utils::{ArrayString, ArrayVec},
+    wire::WireFormat,
+};
+use core::mem::size_of;
+
+/// Unpacks the first four bytes as an [`IoControlCode`] and checks that its fields pack back into
+/// the same code.
+pub fn ioctl_code(data: &[u8]) {
+    let Some(raw) = data.first_chunk::<4>() else {
+        return;
+    };
+    let code = IoControlCode(u32::from_le_bytes(*raw));
+
+    let (device_type, function) = (code.device_type(), code.function());
+    // Ported from the `CTL_CODE` macro in the WDK.
+    let packed = (u32::from(device_type.0) << 16)
+        | (u32::from(code.access().bits()) << 14)
+        | (u32::from(function) << 2)
+        | code.method() as u32;
+    assert_eq!(packed, code.0);
+
+    // the range accepted by `new_custom`
+    if device_type.is_custom() && (0x800..=0xFFF).contains(&function) {
+        let custom = IoControlCode::new_custom(device_type, function, code.method(), code.access());
+        assert_eq!(custom, code);
+    }
+}
+
+/// Decodes a `T` and checks that it encodes to [`WireFormat::encoded_len`] bytes, which decode
+/// back into a value with the same encoding.
+pub fn wire<T: WireFormat>(data: &[u8]) {
+    let Ok(value) = T::from_bytes(data) else {
+        return;
+    };
+
+    let encoded = encode(&value);
+    let mut reader = ByteReader::new(&encoded);
+    let decoded = T::decode(&mut reader).expect("encoded value doesn't decode");
+    assert_eq!(
+        reader.position(),
+        encoded.len(),
+        "decode didn't consume the encoding"
+    );
+    assert_eq!(encode(&decoded), encoded, "encoding isn't stable");
+}
+
+fn encode<T: WireFormat>(value: &T) -> Vec<u8> {
+    let mut buf = vec![0; value.encoded_len()];
+    let written = value
+        .to_bytes(&mut buf)
+        .expect("encoding exceeds `encoded_len`");
+    assert_eq!(written, buf.len(), "encoding is shorter than `encoded_len`");
+    buf
+}
+
+/// Runs [`wire`] for one of the built-in [`WireFormat`] impls, selected by the first byte.
+pub fn wire_builtin(data: &[u8]) {
+    let Some((selector, data)) = data.split_first() else {
+        return;
+    };
+
+    match selector % 10 {
+        0 => wire::<bool>(data),
+        1 => wire::<u64>(data),
+        2 => wire::<f64>(data),
+        3 => wire::<[i16; 3]>(data),
+        4 => wire::<Option<u32>>(data),
+        5 => wire::<Option<Option<bool>>>(data),
+        6 => wire::<ArrayVec<u16, 8>>(data),
+        7 => wire::<ArrayVec<ArrayString<4>, 4>>(data),
+        8 => wire::<ArrayString<32>>(data),
+        _ => wire::<Guid>(data),
+    }
+}
+
+/// Reads an [`SmBusRequest`] from the start of `data` and executes it, as a bus driver would,
+/// with a host that reads the remaining bytes of `data`. Checks that the operation passed to the
+/// host describes the request, and that the response is valid.
+pub fn smbus_request(data: &[u8]) {
+    let Some(raw) = data.get(..size_of::<SmBusRequest>()) else {
+        return;
+    };
+    let request: SmBusRequest = bytemuck::pod_read_unaligned(raw);
+    let mut input = data[raw.len()..].iter().copied();
+    let mut next = || input.next().unwrap_or(0);
+
+    let result = request.execute(|address, mut operation| {
+        assert!(address.get() <= 0x7F);
+        let described = SmBusRequest::new(address, &operation).expect("invalid operation");
+        assert_eq!(described.address, request.address);
+        assert_eq!(described.direction, request.direction);
+        assert_eq!(described.protocol, request.protocol);
+        if operation.command().is_some() {
+            assert_eq!(described.command, request.command);
+        }
+        if operation.direction() == Direction::Write {
+            let len = described.len as usize;
+            assert_eq!(described.len, request.len);
+            assert_eq!(described.data[..len], request.data[..len]);
+        }
+
+        match &mut operation {
+            Operation::ReceiveByte(byte) | Operation::ReadByteData { data: byte, .. } => {
+                **byte = next();
+            }
+            Operation::ReadWordData { data: word, .. } => {
+                **word = u16::from_le_bytes([next(), next()]);
+            }
+            Operation::ReadBlockData { data: block, .. } => {
+                for _ in 0..usize::from(next()).min(BLOCK_MAX) {
+                    block.push(next()).expect("block is full");
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    });
+
+    if let Ok(response) = result {
+        assert!(usize::from(response.len) <= BLOCK_MAX);
+    }
+}
+
+/// Reads an [`SmBusResponse`] from the start of `data`, after a byte selecting the kind of
+/// operation, and completes an operation with it, as a client would.
+pub fn smbus_response(data: &[u8]) {
+    let Some((selector, data)) = data.split_first() else {
+        return;
+    };
+    let Some(raw) = data.get(..size_of::<SmBusResponse>()) else {
+        return;
+    };
+    let response: SmBusResponse = bytemuck::pod_read_unaligned(raw);
+
+    let (mut byte, mut word, mut block) = (0, 0, Block::new());
+    let mut operation = match selector % 4 {
+        0 => Operation::ReceiveByte(&mut byte),
+        1 => Operation::ReadWordData {
+            command: 0,
+            data: &mut word,
+        },
+        2 => Operation::ReadBlockData {
+            command: 0,
+            data: &mut block,
+        },
+        _ => Operation::Quick(Direction::Write),
+    };
+
+    if response.complete(&mut operation).is_ok() {
+        if let Operation::ReadBlockData { data, .. } = operation {
+            assert_eq!(data.len(), usize::from(response.len));
+        }
+    }
+}
+
+/// Decodes a [`ProtocolVersion`] as returned by `IOCTL_GET_INTERFACE_VERSION`, and checks that
+/// [`ProtocolVersion::supports`] is consistent with its ordering.
+pub fn protocol_version(data: &[u8]) {
+    let Some(raw) = data.get(..2 * size_of::<ProtocolVersion>()) else {
+        return;
+    };
+    let [driver, required]: [ProtocolVersion; 2] = bytemuck::pod_read_unaligned(raw);
+
+    assert!(driver.supports(driver));
+    if driver.supports(required) {
+        assert!(driver >= required);
+        assert_eq!(driver.major, required.major);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::sys::GUID;
+use core::{fmt, str::FromStr};
+use snafu::Snafu;
+
+/// A globally unique identifier, laid out like the Windows [`GUID`] struct.
+///
+/// GUIDs are usually declared from their canonical string form using the [`guid!`](crate::guid!)
+/// macro, which validates the string at compile time:
+///
+/// ```rs, ignore
+/// const GUID_DEVINTERFACE_HID: Guid = guid!("4D1E55B2-F16F-11CF-88CB-001111000030");
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Guid {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+
+/// An error returned when parsing a [`Guid`] from its string form.
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGuidError {
+    #[snafu(display("GUID strings must be 36 characters long, or 38 with braces"))]
+    InvalidLength,
+    #[snafu(display("invalid character at position {position}"))]
+    InvalidCharacter { position: usize },
+}
+
+impl Guid {
+    pub const NULL: Guid = Guid::from_fields(0, 0, 0, [0; 8]);
+
+    pub const fn from_fields(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self {
+            data1,
+            data2,
+            data3,
+            data4,
+        }
+    }
+
+    /// Parses a GUID from its canonical string form, e.g.
+    /// `6B29FC40-CA47-1067-B31D-00DD010662DA`, optionally enclosed in braces. Hex digits may be
+    /// upper- or lowercase.
+    ///
+    /// This is a `const fn`, so it can be used (through [`guid!`](crate::guid!)) to validate GUIDs
+    /// at compile time.
+    pub const fn parse(s: &str) -> Result<Self, ParseGuidError> {
+        let mut bytes = s.as_bytes();
+        let mut offset = 0;
+
+        if bytes.len() == 38 {
+            if bytes[0] != b'{' {
+                return Err(ParseGuidError::InvalidCharacter { position: 0 });
+            }
+            if bytes[37] != b'}' {
+                return Err(ParseGuidError::InvalidCharacter { position: 37 });
+            }
+            bytes = bytes.split_at(1).1.split_at(36).0;
+            offset = 1;
+        } else if bytes.len() != 36 {
+            return Err(ParseGuidError::InvalidLength);
+        }
+
+        // the positions of the hex digit pairs making up the 16 bytes of the GUID, in string order
+        const POSITIONS: [usize; 16] = [0, 2, 4, 6, 9, 11, 14, 16, 19, 21, 24, 26, 28, 30, 32, 34];
+
+        let mut i = 0;
+        while i < 4 {
+            let position = [8, 13, 18, 23][i];
+            if bytes[position] != b'-' {
+                return Err(ParseGuidError::InvalidCharacter {
+                    position: position + offset,
+                });
+            }
+            i += 1;
+        }
+
+        let mut raw = [0u8; 16];
+        let mut i = 0;
+        while i < 16 {
+            let position = POSITIONS[i];
+            let hi = match hex_value(bytes[position]) {
+                Some(v) => v,
+                None => {
+                    return Err(ParseGuidError::InvalidCharacter {
+                        position: position + offset,
+                    })
+                }
+            };
+            let lo = match hex_value(bytes[position + 1]) {
+                Some(v) => v,
+                None => {
+                    return Err(ParseGuidError::InvalidCharacter {
+                        position: position + 1 + offset,
+                    })
+                }
+            };
+            raw[i] = (hi << 4) | lo;
+            i += 1;
+        }
+
+        Ok(Self::from_fields(
+            u32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]),
+            u16::from_be_bytes([raw[4], raw[5]]),
+            u16::from_be_bytes([raw[6], raw[7]]),
+            [
+                raw[8], raw[9], raw[10], raw[11], raw[12], raw[13], raw[14], raw[15],
+            ],
+        ))
+    }
+
+    /// Like [`Self::parse`], but panics on invalid input. Used by [`guid!`](crate::guid!).
+    pub const fn parse_or_panic(s: &str) -> Self {
+        match Self::parse(s) {
+            Ok(guid) => guid,
+            Err(ParseGuidError::InvalidLength) => {
+                panic!("GUID strings must be 36 characters long, or 38 with braces")
+            }
+            Err(ParseGuidError::InvalidCharacter { .. }) => panic!("invalid character in GUID"),
+        }
+    }
+
+    pub const fn to_raw(self) -> GUID {
+        GUID {
+            Data1: self.data1,
+            Data2: self.data2,
+            Data3: self.data3,
+            Data4: self.data4,
+        }
+    }
+
+    pub const fn from_raw(raw: GUID) -> Self {
+        Self::from_fields(raw.Data1, raw.Data2, raw.Data3, raw.Data4)
+    }
+}
+
+const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl From<GUID> for Guid {
+    fn from(raw: GUID) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<Guid> for GUID {
+    fn from(guid: Guid) -> Self {
+        guid.to_raw()
+    }
+}
+
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Formats the GUID in its canonical string form, without braces and in uppercase. Use the
+/// alternate flag (`{:#}`) to enclose it in braces, as the registry and most Windows tools do.
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("{")?;
+        }
+
+        let d4 = &self.data4;
+        write!(
+            f,
+            "{:08X}-{:04X}-{:04X}-{:02X}{:02X}-",
+            self.data1, self.data2, self.data3, d4[0], d4[1]
+        )?;
+        for b in &d4[2..] {
+            write!(f, "{b:02X}")?;
+        }
+
+        if f.alternate() {
+            f.write_str("}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{self}}}")
+    }
+}
+
+/// Declares a [`Guid`] from its canonical string form, validated at compile time.
+///
+/// Example:
+/// ```rs, ignore
+/// const MY_GUID: Guid = guid!("6B29FC40-CA47-1067-B31D-00DD010662DA");
+/// // braces are accepted as well
+/// const OTHER_GUID: Guid = guid!("{6B29FC40-CA47-1067-B31D-00DD010662DA}");
+/// ```
+#[macro_export]
+macro_rules! guid {
+    ($s:literal) => {{
+        const GUID: $crate::guid::Guid = $crate::guid::Guid::parse_or_panic($s);
+        GUID
+    }};
+}
@@ -0,0 +1,237 @@
+//! Cursors for parsing and building variable-layout byte payloads, e.g. of I/O control requests.
+//!
+//! Both cursors check every access against the remaining length, and never require the buffer
+//! to be aligned, so they can be used on any part of a payload:
+//!
+//! ```rs, ignore
+//! let mut reader = ByteReader::new(&input);
+//! let header: PayloadHeader = reader.read()?;
+//! let name = reader.read_bytes(header.name_len as usize)?;
+//! let flags = reader.read_u32_le()?;
+//! ```
+
+use bytemuck::{CheckedBitPattern, NoUninit};
+use snafu::Snafu;
+
+/// An error returned by [`ByteReader`] and [`ByteWriter`]. The cursor position is left unchanged
+/// on errors.
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+pub enum CursorError {
+    #[snafu(display("{needed} bytes needed at offset {offset}, but only {remaining} left"))]
+    OutOfBounds {
+        offset: usize,
+        needed: usize,
+        remaining: usize,
+    },
+    #[snafu(display("invalid value at offset {offset}"))]
+    InvalidValue { offset: usize },
+}
+
+/// Generates the fixed-endianness accessors of the cursors.
+macro_rules! endian_accessors {
+    ($($t:ty: $read_le:ident, $read_be:ident, $write_le:ident, $write_be:ident;)*) => {
+        impl ByteReader<'_> {
+            $(
+                pub fn $read_le(&mut self) -> Result<$t, CursorError> {
+                    self.read_array().map(<$t>::from_le_bytes)
+                }
+
+                pub fn $read_be(&mut self) -> Result<$t, CursorError> {
+                    self.read_array().map(<$t>::from_be_bytes)
+                }
+            )*
+        }
+
+        impl ByteWriter<'_> {
+            $(
+                pub fn $write_le(&mut self, value: $t) -> Result<(), CursorError> {
+                    self.write_bytes(&value.to_le_bytes())
+                }
+
+                pub fn $write_be(&mut self, value: $t) -> Result<(), CursorError> {
+                    self.write_bytes(&value.to_be_bytes())
+                }
+            )*
+        }
+    };
+}
+
+endian_accessors! {
+    u16: read_u16_le, read_u16_be, write_u16_le, write_u16_be;
+    u32: read_u32_le, read_u32_be, write_u32_le, write_u32_be;
+    u64: read_u64_le, read_u64_be, write_u64_le, write_u64_be;
+    i16: read_i16_le, read_i16_be, write_i16_le, write_i16_be;
+    i32: read_i32_le, read_i32_be, write_i32_le, write_i32_be;
+    i64: read_i64_le, read_i64_be, write_i64_le, write_i64_be;
+}
+
+/// Returns the number of padding bytes needed to advance `pos` to a multiple of `align`.
+///
+/// Panics if `align` isn't a power of two.
+fn padding(pos: usize, align: usize) -> usize {
+    assert!(align.is_power_of_two(), "alignment must be a power of two");
+    pos.wrapping_neg() & (align - 1)
+}
+
+/// A cursor reading from a byte slice.
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// The offset of the next byte to be read.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes left to read.
+    pub const fn remaining_len(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.remaining_len() == 0
+    }
+
+    /// The bytes left to read, without advancing.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    fn check(&self, needed: usize) -> Result<(), CursorError> {
+        if needed > self.remaining_len() {
+            return OutOfBoundsSnafu {
+                offset: self.pos,
+                needed,
+                remaining: self.remaining_len(),
+            }
+            .fail();
+        }
+        Ok(())
+    }
+
+    /// Returns the next `len` bytes without advancing.
+    pub fn peek_bytes(&self, len: usize) -> Result<&'a [u8], CursorError> {
+        self.check(len)?;
+        Ok(&self.data[self.pos..self.pos + len])
+    }
+
+    /// Reads the next `len` bytes.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], CursorError> {
+        let bytes = self.peek_bytes(len)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], CursorError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, CursorError> {
+        self.read_array().map(|[b]| b)
+    }
+
+    /// Skips the next `len` bytes.
+    pub fn skip(&mut self, len: usize) -> Result<(), CursorError> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Skips padding up to the next offset that is a multiple of `align`, relative to the start of
+    /// the data. Panics if `align` isn't a power of two.
+    pub fn align_to(&mut self, align: usize) -> Result<(), CursorError> {
+        self.skip(padding(self.pos, align))
+    }
+
+    /// Reads a value in native byte order, checking that the bytes are a valid `T`. The data
+    /// doesn't need to be aligned.
+    pub fn read<T: CheckedBitPattern>(&mut self) -> Result<T, CursorError> {
+        let offset = self.pos;
+        let value = bytemuck::checked::try_pod_read_unaligned(self.peek_bytes(size_of::<T>())?)
+            .map_err(|_| CursorError::InvalidValue { offset })?;
+        self.pos += size_of::<T>();
+        Ok(value)
+    }
+}
+
+/// A cursor writing into a mutable byte slice.
+#[derive(Debug)]
+pub struct ByteWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> ByteWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// The offset of the next byte to be written, i.e. the number of bytes written so far.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes that can still be written.
+    pub const fn remaining_len(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// The bytes written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Consumes the writer, returning the bytes written.
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buf[..self.pos]
+    }
+
+    /// Reserves the next `len` bytes, returning them to be filled by the caller.
+    pub fn reserve(&mut self, len: usize) -> Result<&mut [u8], CursorError> {
+        if len > self.remaining_len() {
+            return OutOfBoundsSnafu {
+                offset: self.pos,
+                needed: len,
+                remaining: self.remaining_len(),
+            }
+            .fail();
+        }
+
+        let start = self.pos;
+        self.pos += len;
+        Ok(&mut self.buf[start..start + len])
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), CursorError> {
+        self.reserve(bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> Result<(), CursorError> {
+        self.write_bytes(&[value])
+    }
+
+    /// Writes `len` zero bytes.
+    pub fn pad(&mut self, len: usize) -> Result<(), CursorError> {
+        self.reserve(len)?.fill(0);
+        Ok(())
+    }
+
+    /// Writes zero bytes up to the next offset that is a multiple of `align`, relative to the
+    /// start of the buffer. Panics if `align` isn't a power of two.
+    pub fn align_to(&mut self, align: usize) -> Result<(), CursorError> {
+        self.pad(padding(self.pos, align))
+    }
+
+    /// Writes a value in native byte order. The buffer doesn't need to be aligned.
+    pub fn write<T: NoUninit>(&mut self, value: &T) -> Result<(), CursorError> {
+        self.write_bytes(bytemuck::bytes_of(value))
+    }
+}
@@ -1,8 +1,27 @@
-use km_sys::{
+use crate::sys::{
     FILE_ANY_ACCESS, FILE_READ_DATA, FILE_WRITE_DATA, METHOD_BUFFERED, METHOD_IN_DIRECT,
     METHOD_NEITHER, METHOD_OUT_DIRECT,
 };
 
+mod abi;
+pub mod build_info;
+mod device_type;
+pub mod diagnostics;
+mod fixed_layout;
+mod registry;
+#[cfg(feature = "km-sys")]
+pub mod serial;
+#[cfg(feature = "km-sys")]
+pub mod storage;
+mod validate;
+mod version;
+
+pub use device_type::*;
+pub use fixed_layout::*;
+pub use registry::*;
+pub use validate::*;
+pub use version::*;
+
 /// Represents the method of transferring data to or from a device.
 ///
 /// See [MSDN] for more information.
@@ -52,14 +71,14 @@ impl IoCtlAccess {
 /// [MSDN]: https://docs.microsoft.com/en-us/windows-hardware/drivers/kernel/defining-i-o-control-codes
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct IoControlCode(pub km_sys::ULONG);
+pub struct IoControlCode(pub crate::sys::ULONG);
 
 impl IoControlCode {
     /// Creates a packed, non-Microsoft-defined I/O Control code. See [MSDN] for more information. This
     /// function mimicks the `CTL_CODE` macro from the WDK.
     ///
-    /// This function panics if a device type number in the range 0-32767 (`0x0`-`0x7FFF`) is supplied.
-    /// These are reserved for use by Microsoft.
+    /// This function panics if a standard device type, i.e. one that isn't
+    /// [custom](DeviceType::is_custom), is supplied. These are reserved for use by Microsoft.
     ///
     /// This function panics if a function code over 4095 (`0xFFF`) is supplied. This function also
     /// panics if a function code in the range 0-2047 (`0x0`-`0x7FF`) is supplied. These are reserved
@@ -70,26 +89,26 @@ impl IoControlCode {
     ///
     /// [MSDN]: https://docs.microsoft.com/en-us/windows-hardware/drivers/kernel/defining-i-o-control-codes
     pub const fn new_custom(
-        device_type: u16,
+        device_type: DeviceType,
         function: u16,
         method: IoCtlTransferType,
         access: IoCtlAccess,
     ) -> Self {
-        assert!(device_type >= 0x8000, "`device_type` value is reserved");
+        assert!(device_type.is_custom(), "`device_type` value is reserved");
         assert!(function >= 0x800, "`function` value is reserved");
 
         assert!(function <= 0xFFF, "`function` value is out of bounds");
 
         // Ported from the `CTL_CODE` macro in the WDK.
-        let raw = ((device_type as u32) << 16)
+        let raw = ((device_type.0 as u32) << 16)
             | ((access.bits() as u32) << 14)
             | ((function as u32) << 2)
             | (method as u32);
         Self(raw)
     }
 
-    pub const fn device_type(self) -> u16 {
-        (self.0 >> 16) as u16
+    pub const fn device_type(self) -> DeviceType {
+        DeviceType((self.0 >> 16) as u16)
     }
 
     pub const fn function(self) -> u16 {
@@ -131,3 +150,74 @@ impl<I, O> PartialEq<TypedIoControlCode<I, O>> for IoControlCode {
         <Self as PartialEq<Self>>::eq(self, &other.code)
     }
 }
+
+/// An internal I/O control code (`IOCTL_INTERNAL_*`), which is only sent between kernel mode
+/// drivers as `IRP_MJ_INTERNAL_DEVICE_CONTROL`, e.g. from a function driver to its bus driver. See
+/// [MSDN] for more information.
+///
+/// It is encoded like an [`IoControlCode`], but a separate type, so that internal codes can't be
+/// handled or sent as user facing ones by accident, and vice versa.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/irp-mj-internal-device-control
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InternalIoControlCode(pub IoControlCode);
+
+impl InternalIoControlCode {
+    /// Creates a packed, non-Microsoft-defined internal I/O control code, see
+    /// [`IoControlCode::new_custom`], which also describes when this panics.
+    pub const fn new_custom(
+        device_type: DeviceType,
+        function: u16,
+        method: IoCtlTransferType,
+        access: IoCtlAccess,
+    ) -> Self {
+        Self(IoControlCode::new_custom(
+            device_type,
+            function,
+            method,
+            access,
+        ))
+    }
+
+    /// The encoded code, e.g. to send it with a raw WDF function.
+    pub const fn code(self) -> IoControlCode {
+        self.0
+    }
+}
+
+/// An [`InternalIoControlCode`] with its input and output types, like [`TypedIoControlCode`].
+#[repr(transparent)]
+pub struct TypedInternalIoControlCode<I, O> {
+    pub code: InternalIoControlCode,
+    _phantom: core::marker::PhantomData<(I, O)>,
+}
+
+impl<I, O> TypedInternalIoControlCode<I, O> {
+    pub const fn new(code: InternalIoControlCode) -> Self {
+        Self {
+            code,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<I, O> PartialEq<InternalIoControlCode> for TypedInternalIoControlCode<I, O> {
+    fn eq(&self, other: &InternalIoControlCode) -> bool {
+        self.code == *other
+    }
+}
+
+impl<I, O> PartialEq<TypedInternalIoControlCode<I, O>> for InternalIoControlCode {
+    fn eq(&self, other: &TypedInternalIoControlCode<I, O>) -> bool {
+        <Self as PartialEq<Self>>::eq(self, &other.code)
+    }
+}
+
+// compile-time check of the standard codes
+crate::assert_ioctl_abi!(
+    ioctl_get_interface_version(DeviceType::FIRST_CUSTOM),
+    input: { size: 0, align: 1 },
+    output: { size: 4, align: 2 },
+);
+crate::assert_ioctl_fixed_layout!(ioctl_get_interface_version(DeviceType::FIRST_CUSTOM));
@@ -0,0 +1,74 @@
+use super::TypedIoControlCode;
+use bytemuck::NoUninit;
+use core::mem::{align_of, size_of};
+
+impl<I, O> TypedIoControlCode<I, O> {
+    pub const fn input_size(&self) -> usize {
+        size_of::<I>()
+    }
+
+    pub const fn input_align(&self) -> usize {
+        align_of::<I>()
+    }
+
+    pub const fn output_size(&self) -> usize {
+        size_of::<O>()
+    }
+
+    pub const fn output_align(&self) -> usize {
+        align_of::<O>()
+    }
+
+    /// Not to be used directly. Used by [`assert_ioctl_abi!`](crate::assert_ioctl_abi!) to
+    /// require both payload types to be free of padding.
+    #[doc(hidden)]
+    pub const fn _internal_assert_no_uninit(&self)
+    where
+        I: NoUninit,
+        O: NoUninit,
+    {
+    }
+}
+
+/// Asserts at compile time that the payload types of a [`TypedIoControlCode`] have the expected
+/// ABI, so that user-mode and kernel builds can't silently drift apart.
+///
+/// This checks that:
+/// - the sizes and alignments of the input and output types match the given values, and
+/// - both types implement [`NoUninit`], i.e. contain no padding. Deriving `NoUninit` also
+///   requires the types to be `repr(C)` or `repr(transparent)`.
+///
+/// Example:
+/// ```rs, ignore
+/// assert_ioctl_abi!(IOCTL_READ_SENSOR, input: { size: 4, align: 4 }, output: { size: 16, align: 8 });
+/// ```
+#[macro_export]
+macro_rules! assert_ioctl_abi {
+    (
+        $code:expr,
+        input: { size: $input_size:expr, align: $input_align:expr $(,)? },
+        output: { size: $output_size:expr, align: $output_align:expr $(,)? } $(,)?
+    ) => {
+        const _: () = {
+            let code: &$crate::ioctl::TypedIoControlCode<_, _> = &$code;
+            code._internal_assert_no_uninit();
+
+            assert!(
+                code.input_size() == $input_size,
+                concat!("input size of `", stringify!($code), "` changed")
+            );
+            assert!(
+                code.input_align() == $input_align,
+                concat!("input alignment of `", stringify!($code), "` changed")
+            );
+            assert!(
+                code.output_size() == $output_size,
+                concat!("output size of `", stringify!($code), "` changed")
+            );
+            assert!(
+                code.output_align() == $output_align,
+                concat!("output alignment of `", stringify!($code), "` changed")
+            );
+        };
+    };
+}
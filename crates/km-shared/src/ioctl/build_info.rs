@@ -0,0 +1,149 @@
+//! The standard build information code, which reports which build of a driver is installed.
+//!
+//! Drivers embed their [`BuildInfo`] with `km::build_info!`, and answer [`ioctl_km_build_info`]
+//! with it, usually through `km::diagnostics`, which also includes it in its report:
+//!
+//! ```rs, ignore
+//! const DEVICE_TYPE: DeviceType = DeviceType::custom(0x8000);
+//! const IOCTL_KM_BUILD_INFO: TypedIoControlCode<(), BuildInfo> = ioctl_km_build_info(DEVICE_TYPE);
+//!
+//! let build: BuildInfo = device.ioctl(IOCTL_KM_BUILD_INFO, &())?;
+//! println!("driver {} ({})", build.driver_version(), build.git_commit());
+//! ```
+
+use super::{
+    DeviceType, FixedLayout, IoControlCode, IoCtlAccess, IoCtlTransferType, TypedIoControlCode,
+};
+use bytemuck::{Pod, Zeroable};
+use core::{fmt, ops::BitOr};
+
+/// The function code reserved for [`ioctl_km_build_info`], the one below the
+/// [diagnostics'](super::diagnostics::DIAGNOSTICS_FUNCTION).
+pub const BUILD_INFO_FUNCTION: u16 = 0xFFD;
+
+/// The standard `IOCTL_KM_BUILD_INFO` code for a driver using `device_type` for its codes. Takes
+/// no input and returns the driver's [`BuildInfo`].
+///
+/// Like [`ioctl_km_diagnostics`](super::diagnostics::ioctl_km_diagnostics), the code only needs
+/// read access.
+pub const fn ioctl_km_build_info(device_type: DeviceType) -> TypedIoControlCode<(), BuildInfo> {
+    TypedIoControlCode::new(IoControlCode::new_custom(
+        device_type,
+        BUILD_INFO_FUNCTION,
+        IoCtlTransferType::Buffered,
+        IoCtlAccess::READ_DATA,
+    ))
+}
+
+/// Flags of a [`BuildInfo`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BuildFlags(pub u32);
+
+impl BuildFlags {
+    /// The working tree had uncommitted changes, so the build doesn't match its commit.
+    pub const GIT_DIRTY: Self = Self(1 << 0);
+    /// The driver was built with debug assertions, e.g. in the dev profile.
+    pub const DEBUG: Self = Self(1 << 1);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for BuildFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+// SAFETY: `BuildFlags` is a transparent `u32`, any bit pattern is valid for it.
+unsafe impl Zeroable for BuildFlags {}
+// SAFETY: See above.
+unsafe impl Pod for BuildFlags {}
+// SAFETY: `BuildFlags` is a transparent `u32`.
+unsafe impl FixedLayout for BuildFlags {}
+
+/// The output of [`ioctl_km_build_info`], describing the build of a driver.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BuildInfo {
+    /// The version of the driver, as major, minor, build, and revision, e.g. as in its INF.
+    pub driver_version: [u16; 4],
+    /// The KMDF version the driver was built against, as major and minor.
+    pub kmdf_built_against: [u16; 2],
+    /// The KMDF version the driver is running on, as major and minor, or zero if unknown.
+    pub kmdf_loaded: [u16; 2],
+    /// When the driver was built, in seconds since the Unix epoch, or zero if unknown.
+    pub build_timestamp: u64,
+    /// The git commit the driver was built from, or all zeroes if unknown.
+    pub git_commit: [u8; 20],
+    pub flags: BuildFlags,
+}
+
+// SAFETY: `BuildInfo` is `repr(C)`, consists of `u16` arrays, a `u64`, a `u8` array, and a `u32`
+// without padding (as checked by `assert_ioctl_abi!` below), and any bit pattern is valid for it.
+unsafe impl Zeroable for BuildInfo {}
+// SAFETY: See above.
+unsafe impl Pod for BuildInfo {}
+// SAFETY: `BuildInfo` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for BuildInfo {}
+
+impl BuildInfo {
+    /// Formats [`Self::driver_version`] as `major.minor.build.revision`.
+    pub fn driver_version(&self) -> impl fmt::Display + '_ {
+        struct Version<'a>(&'a [u16; 4]);
+
+        impl fmt::Display for Version<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let [major, minor, build, revision] = self.0;
+                write!(f, "{major}.{minor}.{build}.{revision}")
+            }
+        }
+
+        Version(&self.driver_version)
+    }
+
+    /// Formats [`Self::git_commit`] as a hex string, followed by `-dirty` for builds with
+    /// uncommitted changes, or as `unknown`.
+    pub fn git_commit(&self) -> impl fmt::Display + '_ {
+        struct Commit<'a>(&'a BuildInfo);
+
+        impl fmt::Display for Commit<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if !self.0.has_git_commit() {
+                    return f.write_str("unknown");
+                }
+
+                for byte in self.0.git_commit {
+                    write!(f, "{byte:02x}")?;
+                }
+                if self.0.flags.contains(BuildFlags::GIT_DIRTY) {
+                    f.write_str("-dirty")?;
+                }
+                Ok(())
+            }
+        }
+
+        Commit(self)
+    }
+
+    /// Whether the git commit the driver was built from is known.
+    pub fn has_git_commit(&self) -> bool {
+        self.git_commit != [0; 20]
+    }
+}
+
+// compile-time check of the layout, which is part of the protocol
+crate::assert_ioctl_abi!(
+    ioctl_km_build_info(DeviceType::FIRST_CUSTOM),
+    input: { size: 0, align: 1 },
+    output: { size: 48, align: 8 },
+);
+crate::assert_ioctl_fixed_layout!(ioctl_km_build_info(DeviceType::FIRST_CUSTOM));
@@ -0,0 +1,156 @@
+/// The device type of an [`IoControlCode`](super::IoControlCode), its upper 16 bits, which
+/// identifies the kind of device that handles it. See [MSDN] for more information.
+///
+/// Microsoft reserves the types below `0x8000` and defines the standard `FILE_DEVICE_*` types in
+/// that range, which are available as constants. Custom codes use a vendor type created with
+/// [`DeviceType::custom`]:
+///
+/// ```rs, ignore
+/// const DEVICE_TYPE: DeviceType = DeviceType::custom(0x8000);
+///
+/// const IOCTL_READ_SENSOR: IoControlCode = IoControlCode::new_custom(
+///     DEVICE_TYPE,
+///     0x800,
+///     IoCtlTransferType::Buffered,
+///     IoCtlAccess::READ_DATA,
+/// );
+/// ```
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/specifying-device-types
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeviceType(pub u16);
+
+// the standard types, which come from the bindings
+#[cfg(feature = "km-sys")]
+impl DeviceType {
+    pub const BEEP: Self = Self::standard(km_sys::FILE_DEVICE_BEEP);
+    pub const CD_ROM: Self = Self::standard(km_sys::FILE_DEVICE_CD_ROM);
+    pub const CD_ROM_FILE_SYSTEM: Self = Self::standard(km_sys::FILE_DEVICE_CD_ROM_FILE_SYSTEM);
+    pub const CONTROLLER: Self = Self::standard(km_sys::FILE_DEVICE_CONTROLLER);
+    pub const DATALINK: Self = Self::standard(km_sys::FILE_DEVICE_DATALINK);
+    pub const DFS: Self = Self::standard(km_sys::FILE_DEVICE_DFS);
+    pub const DISK: Self = Self::standard(km_sys::FILE_DEVICE_DISK);
+    pub const DISK_FILE_SYSTEM: Self = Self::standard(km_sys::FILE_DEVICE_DISK_FILE_SYSTEM);
+    pub const FILE_SYSTEM: Self = Self::standard(km_sys::FILE_DEVICE_FILE_SYSTEM);
+    pub const INPORT_PORT: Self = Self::standard(km_sys::FILE_DEVICE_INPORT_PORT);
+    pub const KEYBOARD: Self = Self::standard(km_sys::FILE_DEVICE_KEYBOARD);
+    pub const MAILSLOT: Self = Self::standard(km_sys::FILE_DEVICE_MAILSLOT);
+    pub const MIDI_IN: Self = Self::standard(km_sys::FILE_DEVICE_MIDI_IN);
+    pub const MIDI_OUT: Self = Self::standard(km_sys::FILE_DEVICE_MIDI_OUT);
+    pub const MOUSE: Self = Self::standard(km_sys::FILE_DEVICE_MOUSE);
+    pub const MULTI_UNC_PROVIDER: Self = Self::standard(km_sys::FILE_DEVICE_MULTI_UNC_PROVIDER);
+    pub const NAMED_PIPE: Self = Self::standard(km_sys::FILE_DEVICE_NAMED_PIPE);
+    pub const NETWORK: Self = Self::standard(km_sys::FILE_DEVICE_NETWORK);
+    pub const NETWORK_BROWSER: Self = Self::standard(km_sys::FILE_DEVICE_NETWORK_BROWSER);
+    pub const NETWORK_FILE_SYSTEM: Self = Self::standard(km_sys::FILE_DEVICE_NETWORK_FILE_SYSTEM);
+    pub const NULL: Self = Self::standard(km_sys::FILE_DEVICE_NULL);
+    pub const PARALLEL_PORT: Self = Self::standard(km_sys::FILE_DEVICE_PARALLEL_PORT);
+    pub const PHYSICAL_NETCARD: Self = Self::standard(km_sys::FILE_DEVICE_PHYSICAL_NETCARD);
+    pub const PRINTER: Self = Self::standard(km_sys::FILE_DEVICE_PRINTER);
+    pub const SCANNER: Self = Self::standard(km_sys::FILE_DEVICE_SCANNER);
+    pub const SERIAL_MOUSE_PORT: Self = Self::standard(km_sys::FILE_DEVICE_SERIAL_MOUSE_PORT);
+    pub const SERIAL_PORT: Self = Self::standard(km_sys::FILE_DEVICE_SERIAL_PORT);
+    pub const SCREEN: Self = Self::standard(km_sys::FILE_DEVICE_SCREEN);
+    pub const SOUND: Self = Self::standard(km_sys::FILE_DEVICE_SOUND);
+    pub const STREAMS: Self = Self::standard(km_sys::FILE_DEVICE_STREAMS);
+    pub const TAPE: Self = Self::standard(km_sys::FILE_DEVICE_TAPE);
+    pub const TAPE_FILE_SYSTEM: Self = Self::standard(km_sys::FILE_DEVICE_TAPE_FILE_SYSTEM);
+    pub const TRANSPORT: Self = Self::standard(km_sys::FILE_DEVICE_TRANSPORT);
+    pub const UNKNOWN: Self = Self::standard(km_sys::FILE_DEVICE_UNKNOWN);
+    pub const VIDEO: Self = Self::standard(km_sys::FILE_DEVICE_VIDEO);
+    pub const VIRTUAL_DISK: Self = Self::standard(km_sys::FILE_DEVICE_VIRTUAL_DISK);
+    pub const WAVE_IN: Self = Self::standard(km_sys::FILE_DEVICE_WAVE_IN);
+    pub const WAVE_OUT: Self = Self::standard(km_sys::FILE_DEVICE_WAVE_OUT);
+    /// `FILE_DEVICE_8042_PORT`
+    pub const PORT_8042: Self = Self::standard(km_sys::FILE_DEVICE_8042_PORT);
+    pub const NETWORK_REDIRECTOR: Self = Self::standard(km_sys::FILE_DEVICE_NETWORK_REDIRECTOR);
+    pub const BATTERY: Self = Self::standard(km_sys::FILE_DEVICE_BATTERY);
+    pub const BUS_EXTENDER: Self = Self::standard(km_sys::FILE_DEVICE_BUS_EXTENDER);
+    pub const MODEM: Self = Self::standard(km_sys::FILE_DEVICE_MODEM);
+    pub const VDM: Self = Self::standard(km_sys::FILE_DEVICE_VDM);
+    pub const MASS_STORAGE: Self = Self::standard(km_sys::FILE_DEVICE_MASS_STORAGE);
+    pub const SMB: Self = Self::standard(km_sys::FILE_DEVICE_SMB);
+    pub const KS: Self = Self::standard(km_sys::FILE_DEVICE_KS);
+    pub const CHANGER: Self = Self::standard(km_sys::FILE_DEVICE_CHANGER);
+    pub const SMARTCARD: Self = Self::standard(km_sys::FILE_DEVICE_SMARTCARD);
+    pub const ACPI: Self = Self::standard(km_sys::FILE_DEVICE_ACPI);
+    pub const DVD: Self = Self::standard(km_sys::FILE_DEVICE_DVD);
+    pub const FULLSCREEN_VIDEO: Self = Self::standard(km_sys::FILE_DEVICE_FULLSCREEN_VIDEO);
+    pub const DFS_FILE_SYSTEM: Self = Self::standard(km_sys::FILE_DEVICE_DFS_FILE_SYSTEM);
+    pub const DFS_VOLUME: Self = Self::standard(km_sys::FILE_DEVICE_DFS_VOLUME);
+    pub const SERENUM: Self = Self::standard(km_sys::FILE_DEVICE_SERENUM);
+    pub const TERMSRV: Self = Self::standard(km_sys::FILE_DEVICE_TERMSRV);
+    pub const KSEC: Self = Self::standard(km_sys::FILE_DEVICE_KSEC);
+    pub const FIPS: Self = Self::standard(km_sys::FILE_DEVICE_FIPS);
+    pub const INFINIBAND: Self = Self::standard(km_sys::FILE_DEVICE_INFINIBAND);
+    pub const VMBUS: Self = Self::standard(km_sys::FILE_DEVICE_VMBUS);
+    pub const CRYPT_PROVIDER: Self = Self::standard(km_sys::FILE_DEVICE_CRYPT_PROVIDER);
+    pub const WPD: Self = Self::standard(km_sys::FILE_DEVICE_WPD);
+    pub const BLUETOOTH: Self = Self::standard(km_sys::FILE_DEVICE_BLUETOOTH);
+    pub const MT_COMPOSITE: Self = Self::standard(km_sys::FILE_DEVICE_MT_COMPOSITE);
+    pub const MT_TRANSPORT: Self = Self::standard(km_sys::FILE_DEVICE_MT_TRANSPORT);
+    pub const BIOMETRIC: Self = Self::standard(km_sys::FILE_DEVICE_BIOMETRIC);
+    pub const PMI: Self = Self::standard(km_sys::FILE_DEVICE_PMI);
+    pub const EHSTOR: Self = Self::standard(km_sys::FILE_DEVICE_EHSTOR);
+    pub const DEVAPI: Self = Self::standard(km_sys::FILE_DEVICE_DEVAPI);
+    pub const GPIO: Self = Self::standard(km_sys::FILE_DEVICE_GPIO);
+    pub const USBEX: Self = Self::standard(km_sys::FILE_DEVICE_USBEX);
+    pub const CONSOLE: Self = Self::standard(km_sys::FILE_DEVICE_CONSOLE);
+    pub const NFP: Self = Self::standard(km_sys::FILE_DEVICE_NFP);
+    pub const SYSENV: Self = Self::standard(km_sys::FILE_DEVICE_SYSENV);
+    pub const VIRTUAL_BLOCK: Self = Self::standard(km_sys::FILE_DEVICE_VIRTUAL_BLOCK);
+    pub const POINT_OF_SERVICE: Self = Self::standard(km_sys::FILE_DEVICE_POINT_OF_SERVICE);
+    pub const STORAGE_REPLICATION: Self = Self::standard(km_sys::FILE_DEVICE_STORAGE_REPLICATION);
+    pub const TRUST_ENV: Self = Self::standard(km_sys::FILE_DEVICE_TRUST_ENV);
+    pub const UCM: Self = Self::standard(km_sys::FILE_DEVICE_UCM);
+    pub const UCMTCPCI: Self = Self::standard(km_sys::FILE_DEVICE_UCMTCPCI);
+    pub const PERSISTENT_MEMORY: Self = Self::standard(km_sys::FILE_DEVICE_PERSISTENT_MEMORY);
+    pub const NVDIMM: Self = Self::standard(km_sys::FILE_DEVICE_NVDIMM);
+    pub const HOLOGRAPHIC: Self = Self::standard(km_sys::FILE_DEVICE_HOLOGRAPHIC);
+    pub const SDFXHCI: Self = Self::standard(km_sys::FILE_DEVICE_SDFXHCI);
+    pub const UCMUCSI: Self = Self::standard(km_sys::FILE_DEVICE_UCMUCSI);
+    pub const PRM: Self = Self::standard(km_sys::FILE_DEVICE_PRM);
+    pub const EVENT_COLLECTOR: Self = Self::standard(km_sys::FILE_DEVICE_EVENT_COLLECTOR);
+    pub const USB4: Self = Self::standard(km_sys::FILE_DEVICE_USB4);
+    pub const SOUNDWIRE: Self = Self::standard(km_sys::FILE_DEVICE_SOUNDWIRE);
+    pub const FABRIC_NVME: Self = Self::standard(km_sys::FILE_DEVICE_FABRIC_NVME);
+    pub const SVM: Self = Self::standard(km_sys::FILE_DEVICE_SVM);
+    pub const HARDWARE_ACCELERATOR: Self = Self::standard(km_sys::FILE_DEVICE_HARDWARE_ACCELERATOR);
+    pub const I3C: Self = Self::standard(km_sys::FILE_DEVICE_I3C);
+
+    const fn standard(raw: u32) -> Self {
+        assert!(raw < Self::FIRST_CUSTOM.0 as u32);
+        Self(raw as u16)
+    }
+}
+
+impl DeviceType {
+    /// The lowest device type that isn't reserved for Microsoft.
+    pub const FIRST_CUSTOM: Self = Self(0x8000);
+
+    /// Creates a vendor-defined device type.
+    ///
+    /// This function panics if `raw` is in the range 0-32767 (`0x0`-`0x7FFF`), which is reserved
+    /// for use by Microsoft. Note that this is a `const fn`, so the panic happens at compile time if
+    /// used to define constants.
+    pub const fn custom(raw: u16) -> Self {
+        assert!(
+            raw >= Self::FIRST_CUSTOM.0,
+            "`DeviceType` value is reserved"
+        );
+        Self(raw)
+    }
+
+    /// Whether the type is outside the range reserved for Microsoft, i.e. usable by
+    /// [`IoControlCode::new_custom`](super::IoControlCode::new_custom).
+    pub const fn is_custom(self) -> bool {
+        self.0 >= Self::FIRST_CUSTOM.0
+    }
+}
+
+impl From<DeviceType> for u16 {
+    fn from(device_type: DeviceType) -> Self {
+        device_type.0
+    }
+}
@@ -0,0 +1,186 @@
+//! The standard self-diagnostics code, which reports the health of a driver built on `km` in the
+//! same format across products.
+//!
+//! Drivers answer [`ioctl_km_diagnostics`] with a [`Diagnostics`] report, usually through
+//! `km::diagnostics`. It holds the driver's interface version and [`BuildInfo`], its statistics
+//! counters, the depths of its queues, and the last errors it recorded:
+//!
+//! ```rs, ignore
+//! const DEVICE_TYPE: DeviceType = DeviceType::custom(0x8000);
+//! const IOCTL_KM_DIAGNOSTICS: TypedIoControlCode<(), Diagnostics> =
+//!     ioctl_km_diagnostics(DEVICE_TYPE);
+//!
+//! let report: Diagnostics = device.ioctl(IOCTL_KM_DIAGNOSTICS, &())?;
+//! println!("driver {}, interface {}", report.build.driver_version(), report.interface_version);
+//! for error in report.errors() {
+//!     println!("{:#x} failed with {:#010x}", error.source, error.status);
+//! }
+//! ```
+//!
+//! The report is versioned by [`Diagnostics::version`]. Later versions only append fields, and
+//! drivers return as much of the report as the client's buffer holds, so clients built against an
+//! older version keep working with newer drivers.
+
+use super::{
+    build_info::BuildInfo, DeviceType, FixedLayout, IoControlCode, IoCtlAccess, IoCtlTransferType,
+    ProtocolVersion, TypedIoControlCode,
+};
+use bytemuck::{Pod, Zeroable};
+
+/// The function code reserved for [`ioctl_km_diagnostics`], the one below the
+/// [interface version's](super::INTERFACE_VERSION_FUNCTION).
+pub const DIAGNOSTICS_FUNCTION: u16 = 0xFFE;
+
+/// The version of the [`Diagnostics`] layout defined here.
+pub const DIAGNOSTICS_VERSION: u32 = 1;
+
+/// The most counters a report holds.
+pub const MAX_DIAGNOSTICS_COUNTERS: usize = 32;
+/// The most queues a report holds.
+pub const MAX_DIAGNOSTICS_QUEUES: usize = 8;
+/// The most errors a report holds.
+pub const MAX_DIAGNOSTICS_ERRORS: usize = 8;
+
+/// The standard `IOCTL_KM_DIAGNOSTICS` code for a driver using `device_type` for its codes. Takes
+/// no input and returns the driver's [`Diagnostics`].
+///
+/// The code only needs read access, so that support tools can query drivers whose other codes are
+/// restricted.
+pub const fn ioctl_km_diagnostics(device_type: DeviceType) -> TypedIoControlCode<(), Diagnostics> {
+    TypedIoControlCode::new(IoControlCode::new_custom(
+        device_type,
+        DIAGNOSTICS_FUNCTION,
+        IoCtlTransferType::Buffered,
+        IoCtlAccess::READ_DATA,
+    ))
+}
+
+/// The requests of one of the driver's queues.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct QueueDepth {
+    /// Identifies the queue, chosen by the driver.
+    pub id: u32,
+    /// The requests waiting in the queue.
+    pub queued: u32,
+    /// The requests that were delivered to the driver, but not completed yet.
+    pub in_driver: u32,
+}
+
+// SAFETY: `QueueDepth` is `repr(C)`, consists of three `u32`s without padding, and any bit pattern
+// is valid for it.
+unsafe impl Zeroable for QueueDepth {}
+// SAFETY: See above.
+unsafe impl Pod for QueueDepth {}
+// SAFETY: `QueueDepth` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for QueueDepth {}
+
+/// An error recorded by the driver.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LastError {
+    /// When the error was recorded, in units of 100ns since January 1, 1601 (like a `FILETIME`).
+    pub timestamp: i64,
+    /// The raw `NTSTATUS` of the error.
+    pub status: i32,
+    /// Identifies what failed, chosen by the driver, e.g. the function code of a failed IOCTL.
+    pub source: u32,
+}
+
+// SAFETY: `LastError` is `repr(C)`, consists of an `i64` followed by two 32-bit integers without
+// padding, and any bit pattern is valid for it.
+unsafe impl Zeroable for LastError {}
+// SAFETY: See above.
+unsafe impl Pod for LastError {}
+// SAFETY: `LastError` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for LastError {}
+
+/// The output of [`ioctl_km_diagnostics`]. See the [module documentation](self).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Diagnostics {
+    /// The layout version of the report, see [`DIAGNOSTICS_VERSION`].
+    pub version: u32,
+    /// The version of the driver's IOCTL interface, see
+    /// [`ioctl_get_interface_version`](super::ioctl_get_interface_version).
+    pub interface_version: ProtocolVersion,
+    /// The same as returned by [`ioctl_km_build_info`](super::build_info::ioctl_km_build_info).
+    pub build: BuildInfo,
+    /// The time since the driver started answering diagnostics, in milliseconds.
+    pub uptime_ms: u64,
+    /// The number of errors the driver recorded, including those no longer in [`Self::errors`].
+    pub error_total: u64,
+    /// The number of valid entries of [`Self::counters`].
+    pub counter_count: u32,
+    /// The number of valid entries of [`Self::queues`].
+    pub queue_count: u32,
+    /// The number of valid entries of [`Self::errors`].
+    pub error_count: u32,
+    /// Zero, for future use.
+    pub reserved: u32,
+    /// The driver's statistics counters, whose meaning is specific to the driver.
+    pub counters: [u64; MAX_DIAGNOSTICS_COUNTERS],
+    pub queues: [QueueDepth; MAX_DIAGNOSTICS_QUEUES],
+    /// The last errors the driver recorded, newest first.
+    pub errors: [LastError; MAX_DIAGNOSTICS_ERRORS],
+}
+
+// SAFETY: `Diagnostics` is `repr(C)`, consists of fields without padding between or after them
+// (as checked by `assert_ioctl_abi!` below), and any bit pattern is valid for it.
+unsafe impl Zeroable for Diagnostics {}
+// SAFETY: See above.
+unsafe impl Pod for Diagnostics {}
+// SAFETY: `Diagnostics` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for Diagnostics {}
+
+impl Diagnostics {
+    /// An empty report of the current [version](DIAGNOSTICS_VERSION).
+    pub fn new(interface_version: ProtocolVersion, build: BuildInfo) -> Self {
+        Self {
+            version: DIAGNOSTICS_VERSION,
+            interface_version,
+            build,
+            ..Zeroable::zeroed()
+        }
+    }
+
+    /// The valid counters.
+    pub fn counters(&self) -> &[u64] {
+        &self.counters[..(self.counter_count as usize).min(MAX_DIAGNOSTICS_COUNTERS)]
+    }
+
+    /// The valid queues.
+    pub fn queues(&self) -> &[QueueDepth] {
+        &self.queues[..(self.queue_count as usize).min(MAX_DIAGNOSTICS_QUEUES)]
+    }
+
+    /// The valid errors, newest first.
+    pub fn errors(&self) -> &[LastError] {
+        &self.errors[..(self.error_count as usize).min(MAX_DIAGNOSTICS_ERRORS)]
+    }
+
+    /// Sets the counters, dropping those beyond [`MAX_DIAGNOSTICS_COUNTERS`].
+    pub fn set_counters(&mut self, counters: &[u64]) {
+        let len = counters.len().min(MAX_DIAGNOSTICS_COUNTERS);
+        self.counters[..len].copy_from_slice(&counters[..len]);
+        self.counter_count = len as u32;
+    }
+
+    /// Adds a queue, returning `false` if there are [`MAX_DIAGNOSTICS_QUEUES`] already.
+    pub fn push_queue(&mut self, queue: QueueDepth) -> bool {
+        let Some(slot) = self.queues.get_mut(self.queue_count as usize) else {
+            return false;
+        };
+        *slot = queue;
+        self.queue_count += 1;
+        true
+    }
+}
+
+// compile-time check of the layout, which is part of the protocol
+crate::assert_ioctl_abi!(
+    ioctl_km_diagnostics(DeviceType::FIRST_CUSTOM),
+    input: { size: 0, align: 1 },
+    output: { size: 568, align: 8 },
+);
+crate::assert_ioctl_fixed_layout!(ioctl_km_diagnostics(DeviceType::FIRST_CUSTOM));
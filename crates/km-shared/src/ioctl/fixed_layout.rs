@@ -0,0 +1,110 @@
+//! IOCTL payloads with the same layout in 32-bit and 64-bit processes.
+//!
+//! 32-bit processes on 64-bit Windows (WOW64) issue IOCTLs with their own struct layouts, and the
+//! I/O manager passes their buffers to the driver unchanged. Pointers, handles, `usize` and `isize`
+//! are only 32 bits there, so a payload containing them is laid out differently than the driver
+//! expects, and would have to be thunked (see [MSDN]).
+//!
+//! Payloads made of fixed-size integers don't need thunking. Implementing [`FixedLayout`], usually
+//! through its derive, checks this at compile time, and
+//! [`assert_ioctl_fixed_layout!`](crate::assert_ioctl_fixed_layout!) requires it for both payloads
+//! of a code:
+//!
+//! ```rs, ignore
+//! #[repr(C)]
+//! #[derive(Clone, Copy, FixedLayout)]
+//! pub struct MapRequest {
+//!     /// A user-mode address, as a `u64` instead of a pointer.
+//!     pub address: u64,
+//!     pub len: u32,
+//!     pub flags: u32,
+//! }
+//!
+//! pub const IOCTL_MAP: TypedIoControlCode<MapRequest, ()> = /* ... */;
+//! assert_ioctl_fixed_layout!(IOCTL_MAP);
+//! ```
+//!
+//! Pointers passed this way have to be validated and probed like any other user-mode address.
+//! Drivers that have to accept existing pointer-bearing payloads can instead check whether a request
+//! came from a 32-bit process, and parse the 32-bit variant of the struct.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/supporting-32-bit-i-o-in-your-64-bit-driver
+
+use super::TypedIoControlCode;
+
+/// Derives [`FixedLayout`] for a `#[repr(C)]` or `#[repr(transparent)]` struct, failing to
+/// compile if a field doesn't implement it.
+///
+/// If `km_shared` is used through another crate, its path can be given with
+/// `#[fixed_layout(crate = "km::shared")]`.
+pub use km_shared_derive::FixedLayout;
+
+/// A type with the same size, alignment, and field offsets in 32-bit and 64-bit processes, so it
+/// can be used as an IOCTL payload without thunking. See the [module documentation](self).
+///
+/// # Safety
+/// The type can't contain pointers, references, `usize`, `isize`, or any other type whose layout
+/// depends on the pointer width, and has to have a defined layout, i.e. be `repr(C)`,
+/// `repr(transparent)` or a primitive.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` may have a different layout in 32-bit processes",
+    label = "not a `FixedLayout` type",
+    note = "pointers, `usize`, and `isize` are 32 bits in 32-bit (WOW64) clients, use `u64` instead"
+)]
+pub unsafe trait FixedLayout: Copy {}
+
+macro_rules! impl_fixed_layout {
+    ($($t:ty),* $(,)?) => {
+        $(
+            // SAFETY: Primitives of a fixed size. 64-bit integers and floats are 8-byte aligned on
+            // 32-bit Windows too.
+            unsafe impl FixedLayout for $t {}
+        )*
+    };
+}
+
+impl_fixed_layout!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool, ());
+
+// SAFETY: Arrays are laid out as their elements.
+unsafe impl<T: FixedLayout, const N: usize> FixedLayout for [T; N] {}
+
+// SAFETY: `Guid` is `repr(C)` and only consists of integers.
+unsafe impl FixedLayout for crate::guid::Guid {}
+
+// SAFETY: `Fixed` is `repr(transparent)` over an `i32`.
+unsafe impl FixedLayout for crate::utils::Fixed {}
+
+// SAFETY: `IoControlCode` is `repr(transparent)` over a `u32`.
+unsafe impl FixedLayout for super::IoControlCode {}
+
+impl<I, O> TypedIoControlCode<I, O> {
+    /// Not to be used directly. Used by
+    /// [`assert_ioctl_fixed_layout!`](crate::assert_ioctl_fixed_layout!) to require both payload
+    /// types to implement [`FixedLayout`].
+    #[doc(hidden)]
+    pub const fn _internal_assert_fixed_layout(&self)
+    where
+        I: FixedLayout,
+        O: FixedLayout,
+    {
+    }
+}
+
+/// Asserts at compile time that both payload types of a [`TypedIoControlCode`] implement
+/// [`FixedLayout`], so 32-bit clients can issue the code on 64-bit Windows.
+///
+/// Example:
+/// ```rs, ignore
+/// assert_ioctl_fixed_layout!(IOCTL_READ_SENSOR);
+/// ```
+#[macro_export]
+macro_rules! assert_ioctl_fixed_layout {
+    ($($code:expr),+ $(,)?) => {
+        const _: () = {
+            $(
+                let code: &$crate::ioctl::TypedIoControlCode<_, _> = &$code;
+                code._internal_assert_fixed_layout();
+            )+
+        };
+    };
+}
@@ -0,0 +1,142 @@
+use super::{DeviceType, IoControlCode};
+
+/// Not to be used directly. Used by [`declare_ioctls!`](crate::declare_ioctls!) to check the
+/// declared codes at compile time.
+#[doc(hidden)]
+pub const fn _internal_assert_ioctl_registry(device_type: DeviceType, codes: &[IoControlCode]) {
+    let mut i = 0;
+    while i < codes.len() {
+        assert!(
+            codes[i].device_type().0 == device_type.0,
+            "an IOCTL doesn't use the declared device type"
+        );
+
+        let mut j = i + 1;
+        while j < codes.len() {
+            assert!(
+                codes[i].function() != codes[j].function(),
+                "two IOCTLs share a function code"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Declares all typed IOCTLs of a driver in a module, along with an enum to dispatch on them.
+///
+/// The module contains the `DEVICE_TYPE` of the driver, a [`TypedIoControlCode`] constant for
+/// each code, and the dispatch enum, with a variant per code. Each code is declared with its
+/// function code, transfer type and access, like for [`IoControlCode::new_custom`], or with an
+/// expression for a code defined elsewhere, e.g. one of the standard codes. It's asserted at
+/// compile time that no two codes share a function code, and that all of them use `DEVICE_TYPE`.
+///
+/// The dispatch enum's `from_code` returns the variant of a received code, so that matching on it
+/// has to handle every declared code.
+///
+/// Example:
+/// ```rs, ignore
+/// declare_ioctls! {
+///     /// The codes of the sensor driver.
+///     pub mod sensor_ioctls: DeviceType::custom(0x8000);
+///     /// A code of the sensor driver.
+///     pub enum SensorIoctl;
+///
+///     /// Reads the current value of a sensor.
+///     READ_SENSOR(ReadSensor): SensorId => SensorValue =
+///         0x800, IoCtlTransferType::Buffered, IoCtlAccess::READ_DATA;
+///     GET_INTERFACE_VERSION(GetInterfaceVersion): () => ProtocolVersion =
+///         ioctl_get_interface_version(DEVICE_TYPE);
+/// }
+///
+/// // in `EvtIoDeviceControl`
+/// match SensorIoctl::from_code(code) {
+///     Some(SensorIoctl::ReadSensor) => { /* ... */ }
+///     Some(SensorIoctl::GetInterfaceVersion) => { /* ... */ }
+///     None => request.complete(NtStatus::STATUS_INVALID_DEVICE_REQUEST),
+/// }
+/// ```
+///
+/// [`TypedIoControlCode`]: crate::ioctl::TypedIoControlCode
+/// [`IoControlCode::new_custom`]: crate::ioctl::IoControlCode::new_custom
+#[macro_export]
+macro_rules! declare_ioctls {
+    {
+        $(#[$mod_attr:meta])*
+        $vis:vis mod $mod_name:ident: $device_type:expr;
+        $(#[$enum_attr:meta])*
+        pub enum $enum_name:ident;
+
+        $(
+            $(#[$attr:meta])*
+            $name:ident($variant:ident): $input:ty => $output:ty = $($code:expr),+;
+        )*
+    } => {
+        $(#[$mod_attr])*
+        $vis mod $mod_name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            pub const DEVICE_TYPE: $crate::ioctl::DeviceType = $device_type;
+
+            $(
+                $(#[$attr])*
+                pub const $name: $crate::ioctl::TypedIoControlCode<$input, $output> =
+                    $crate::__declare_ioctls_code!($($code),+);
+            )*
+
+            const _: () = $crate::ioctl::_internal_assert_ioctl_registry(
+                DEVICE_TYPE,
+                &[$($name.code),*],
+            );
+
+            $(#[$enum_attr])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum $enum_name {
+                $(
+                    $(#[$attr])*
+                    $variant,
+                )*
+            }
+
+            impl $enum_name {
+                /// Every declared code, in the order of declaration.
+                pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+                /// The variant of `code`, or `None` if it isn't declared.
+                pub fn from_code(code: $crate::ioctl::IoControlCode) -> Option<Self> {
+                    $(
+                        if code == $name.code {
+                            return Some(Self::$variant);
+                        }
+                    )*
+
+                    None
+                }
+
+                pub const fn code(self) -> $crate::ioctl::IoControlCode {
+                    match self {
+                        $(Self::$variant => $name.code,)*
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Not to be used directly. Builds the code of an entry of [`declare_ioctls!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_ioctls_code {
+    ($function:expr, $method:expr, $access:expr) => {
+        $crate::ioctl::TypedIoControlCode::new($crate::ioctl::IoControlCode::new_custom(
+            DEVICE_TYPE,
+            $function,
+            $method,
+            $access,
+        ))
+    };
+    ($code:expr) => {
+        $code
+    };
+}
@@ -0,0 +1,268 @@
+//! Standard I/O control codes of serial ports (`ntddser.h`), for configuring UARTs from both kernel
+//! and user mode.
+//!
+//! The WDK defines these codes with the `CTL_CODE` macro, which bindgen can't evaluate, so they're
+//! defined here. Their input and output types mirror the structs in [`km_sys::serial`], with the
+//! layouts checked at compile time, and can be used with the typed IOCTL helpers:
+//!
+//! ```rs, ignore
+//! target.send_typed_ioctl_synchronously(
+//!     IOCTL_SERIAL_SET_BAUD_RATE,
+//!     &SerialBaudRate { baud_rate: 115_200 },
+//!     &RequestSendOptions::default(),
+//! )?;
+//! target.send_typed_ioctl_synchronously(
+//!     IOCTL_SERIAL_SET_LINE_CONTROL,
+//!     &SerialLineControl::EIGHT_N_ONE,
+//!     &RequestSendOptions::default(),
+//! )?;
+//! ```
+//!
+//! The `km::serial` module wraps these codes for drivers talking to devices behind a UART.
+
+use super::{IoControlCode, TypedIoControlCode};
+use core::{mem::size_of, ops::BitOr};
+use km_sys::{
+    serial::{
+        EVEN_PARITY, MARK_PARITY, NO_PARITY, ODD_PARITY, SERIAL_BAUD_RATE, SERIAL_LINE_CONTROL,
+        SERIAL_PURGE_RXABORT, SERIAL_PURGE_RXCLEAR, SERIAL_PURGE_TXABORT, SERIAL_PURGE_TXCLEAR,
+        SERIAL_TIMEOUTS, SPACE_PARITY, STOP_BITS_1_5, STOP_BITS_2, STOP_BIT_1,
+    },
+    FILE_ANY_ACCESS, FILE_DEVICE_SERIAL_PORT, METHOD_BUFFERED,
+};
+
+/// Mimicks the `CTL_CODE` macro from the WDK for the buffered serial port codes.
+const fn serial_ctl_code(function: u32) -> IoControlCode {
+    IoControlCode(
+        (FILE_DEVICE_SERIAL_PORT << 16)
+            | (FILE_ANY_ACCESS << 14)
+            | (function << 2)
+            | METHOD_BUFFERED,
+    )
+}
+
+/// Sets the baud rate of the port.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_set_baud_rate
+pub const IOCTL_SERIAL_SET_BAUD_RATE: TypedIoControlCode<SerialBaudRate, ()> =
+    TypedIoControlCode::new(serial_ctl_code(1));
+
+/// Gets the baud rate of the port.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_get_baud_rate
+pub const IOCTL_SERIAL_GET_BAUD_RATE: TypedIoControlCode<(), SerialBaudRate> =
+    TypedIoControlCode::new(serial_ctl_code(20));
+
+/// Sets the word length, parity and stop bits of the port.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_set_line_control
+pub const IOCTL_SERIAL_SET_LINE_CONTROL: TypedIoControlCode<SerialLineControl, ()> =
+    TypedIoControlCode::new(serial_ctl_code(3));
+
+/// Gets the word length, parity and stop bits of the port.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_get_line_control
+pub const IOCTL_SERIAL_GET_LINE_CONTROL: TypedIoControlCode<(), SerialLineControl> =
+    TypedIoControlCode::new(serial_ctl_code(21));
+
+/// Sets the timeouts of read and write requests.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_set_timeouts
+pub const IOCTL_SERIAL_SET_TIMEOUTS: TypedIoControlCode<SerialTimeouts, ()> =
+    TypedIoControlCode::new(serial_ctl_code(7));
+
+/// Gets the timeouts of read and write requests.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_get_timeouts
+pub const IOCTL_SERIAL_GET_TIMEOUTS: TypedIoControlCode<(), SerialTimeouts> =
+    TypedIoControlCode::new(serial_ctl_code(8));
+
+/// Cancels pending requests and/or clears the port's buffers, see [`SerialPurgeMask`].
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_purge
+pub const IOCTL_SERIAL_PURGE: TypedIoControlCode<SerialPurgeMask, ()> =
+    TypedIoControlCode::new(serial_ctl_code(19));
+
+/// Sets the data terminal ready (DTR) line.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_set_dtr
+pub const IOCTL_SERIAL_SET_DTR: TypedIoControlCode<(), ()> =
+    TypedIoControlCode::new(serial_ctl_code(9));
+
+/// Clears the data terminal ready (DTR) line.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_clr_dtr
+pub const IOCTL_SERIAL_CLR_DTR: TypedIoControlCode<(), ()> =
+    TypedIoControlCode::new(serial_ctl_code(10));
+
+/// Sets the request to send (RTS) line.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_set_rts
+pub const IOCTL_SERIAL_SET_RTS: TypedIoControlCode<(), ()> =
+    TypedIoControlCode::new(serial_ctl_code(12));
+
+/// Clears the request to send (RTS) line.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ni-ntddser-ioctl_serial_clr_rts
+pub const IOCTL_SERIAL_CLR_RTS: TypedIoControlCode<(), ()> =
+    TypedIoControlCode::new(serial_ctl_code(13));
+
+/// `SERIAL_BAUD_RATE`, the input of [`IOCTL_SERIAL_SET_BAUD_RATE`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerialBaudRate {
+    /// In bits per second.
+    pub baud_rate: u32,
+}
+
+/// The number of stop bits of a [`SerialLineControl`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StopBits(pub u8);
+
+impl StopBits {
+    pub const ONE: Self = Self(STOP_BIT_1 as u8);
+    /// Only valid with a word length of 5 bits.
+    pub const ONE_AND_A_HALF: Self = Self(STOP_BITS_1_5 as u8);
+    pub const TWO: Self = Self(STOP_BITS_2 as u8);
+}
+
+/// The parity of a [`SerialLineControl`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parity(pub u8);
+
+impl Parity {
+    pub const NONE: Self = Self(NO_PARITY as u8);
+    pub const ODD: Self = Self(ODD_PARITY as u8);
+    pub const EVEN: Self = Self(EVEN_PARITY as u8);
+    /// The parity bit is always set.
+    pub const MARK: Self = Self(MARK_PARITY as u8);
+    /// The parity bit is always cleared.
+    pub const SPACE: Self = Self(SPACE_PARITY as u8);
+}
+
+/// `SERIAL_LINE_CONTROL`, the input of [`IOCTL_SERIAL_SET_LINE_CONTROL`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerialLineControl {
+    pub stop_bits: StopBits,
+    pub parity: Parity,
+    /// The number of data bits, from 5 to 8.
+    pub word_length: u8,
+}
+
+impl SerialLineControl {
+    /// 8 data bits, no parity and one stop bit ("8N1"), the most common setting.
+    pub const EIGHT_N_ONE: Self = Self {
+        stop_bits: StopBits::ONE,
+        parity: Parity::NONE,
+        word_length: 8,
+    };
+}
+
+/// `SERIAL_TIMEOUTS`, the input of [`IOCTL_SERIAL_SET_TIMEOUTS`]. All timeouts are in
+/// milliseconds, with zero meaning no timeout.
+///
+/// A read completes when its buffer is full, when the time between two received bytes exceeds the
+/// interval timeout, or when the total timeout (the multiplier times the length of the read, plus
+/// the constant) elapsed. An interval timeout of `u32::MAX` with zero total timeouts makes reads
+/// return immediately with the bytes that were already received.
+///
+/// See [MSDN] for all combinations.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddser/ns-ntddser-_serial_timeouts
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SerialTimeouts {
+    pub read_interval_timeout: u32,
+    pub read_total_timeout_multiplier: u32,
+    pub read_total_timeout_constant: u32,
+    pub write_total_timeout_multiplier: u32,
+    pub write_total_timeout_constant: u32,
+}
+
+/// The input of [`IOCTL_SERIAL_PURGE`], a combination of the `SERIAL_PURGE_*` flags.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SerialPurgeMask(pub u32);
+
+impl SerialPurgeMask {
+    /// Cancels all pending write requests.
+    pub const TX_ABORT: Self = Self(SERIAL_PURGE_TXABORT);
+    /// Cancels all pending read requests.
+    pub const RX_ABORT: Self = Self(SERIAL_PURGE_RXABORT);
+    /// Discards the data in the transmit buffer.
+    pub const TX_CLEAR: Self = Self(SERIAL_PURGE_TXCLEAR);
+    /// Discards the data in the receive buffer.
+    pub const RX_CLEAR: Self = Self(SERIAL_PURGE_RXCLEAR);
+    /// Cancels all requests and discards all buffered data.
+    pub const ALL: Self = Self(
+        SERIAL_PURGE_TXABORT | SERIAL_PURGE_RXABORT | SERIAL_PURGE_TXCLEAR | SERIAL_PURGE_RXCLEAR,
+    );
+}
+
+impl BitOr for SerialPurgeMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+// SAFETY: All types are `repr(C)` or `repr(transparent)` and consist only of integers (and structs
+// of them) without padding, as checked below, and any bit pattern is valid for them.
+unsafe impl bytemuck::Zeroable for SerialBaudRate {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SerialBaudRate {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for StopBits {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for StopBits {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for Parity {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for Parity {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for SerialLineControl {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SerialLineControl {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for SerialTimeouts {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SerialTimeouts {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for SerialPurgeMask {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SerialPurgeMask {}
+
+// The mirrors have the size of the C structs, and no padding.
+const _: () = {
+    assert!(size_of::<SerialBaudRate>() == size_of::<SERIAL_BAUD_RATE>());
+    assert!(size_of::<SerialLineControl>() == size_of::<SERIAL_LINE_CONTROL>());
+    assert!(size_of::<SerialLineControl>() == 3);
+    assert!(size_of::<SerialTimeouts>() == size_of::<SERIAL_TIMEOUTS>());
+    assert!(size_of::<SerialTimeouts>() == 20);
+};
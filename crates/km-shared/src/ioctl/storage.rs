@@ -0,0 +1,324 @@
+//! Standard I/O control codes of the storage stack, for querying disks from both kernel and user
+//! mode.
+//!
+//! The WDK defines these codes with the `CTL_CODE` macro, which bindgen can't evaluate, so they're
+//! defined here. Their input and output types mirror the structs in [`km_sys::storage`], with the
+//! layouts checked at compile time, and can be used with the typed IOCTL helpers:
+//!
+//! ```rs, ignore
+//! let header = target.send_typed_ioctl_synchronously(
+//!     IOCTL_STORAGE_QUERY_PROPERTY,
+//!     &StoragePropertyQuery::standard(STORAGE_PROPERTY_ID::StorageDeviceProperty),
+//!     &RequestSendOptions::default(),
+//! )?;
+//! // query again with an output buffer of `header.size` bytes to get the whole descriptor
+//! ```
+
+use super::{IoControlCode, TypedIoControlCode};
+use core::mem::size_of;
+use km_sys::{
+    storage::{
+        DRIVERSTATUS, GETVERSIONINPARAMS, IDENTIFY_BUFFER_SIZE, IDEREGS, ID_CMD, IOCTL_DISK_BASE,
+        IOCTL_STORAGE_BASE, READ_ATTRIBUTES, READ_ATTRIBUTE_BUFFER_SIZE, READ_THRESHOLDS,
+        SENDCMDINPARAMS, SENDCMDOUTPARAMS, SMART_CMD, SMART_CYL_HI, SMART_CYL_LOW,
+        STORAGE_DESCRIPTOR_HEADER, STORAGE_DEVICE_NUMBER, STORAGE_PROPERTY_ID,
+        STORAGE_PROPERTY_QUERY, STORAGE_QUERY_TYPE,
+    },
+    FILE_ANY_ACCESS, FILE_READ_ACCESS, FILE_WRITE_ACCESS, METHOD_BUFFERED,
+};
+
+/// Mimicks the `CTL_CODE` macro from the WDK for Microsoft-defined codes, which
+/// [`IoControlCode::new_custom`] rejects.
+const fn ctl_code(device_type: u32, function: u32, method: u32, access: u32) -> IoControlCode {
+    IoControlCode((device_type << 16) | (access << 14) | (function << 2) | method)
+}
+
+/// Queries a property of a storage device or adapter.
+///
+/// Descriptors have a variable length, so the output is typed as their common header. A query
+/// with an output buffer of [`StorageDescriptorHeader::size`] bytes returns the whole descriptor.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ni-winioctl-ioctl_storage_query_property
+pub const IOCTL_STORAGE_QUERY_PROPERTY: TypedIoControlCode<
+    StoragePropertyQuery,
+    StorageDescriptorHeader,
+> = TypedIoControlCode::new(ctl_code(
+    IOCTL_STORAGE_BASE,
+    0x0500,
+    METHOD_BUFFERED,
+    FILE_ANY_ACCESS,
+));
+
+/// Gets the device type and number of a disk, and the partition number for partitions.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ni-winioctl-ioctl_storage_get_device_number
+pub const IOCTL_STORAGE_GET_DEVICE_NUMBER: TypedIoControlCode<(), StorageDeviceNumber> =
+    TypedIoControlCode::new(ctl_code(
+        IOCTL_STORAGE_BASE,
+        0x0420,
+        METHOD_BUFFERED,
+        FILE_ANY_ACCESS,
+    ));
+
+/// Gets the SMART capabilities of a disk, and which of its drives exist.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ni-winioctl-smart_get_version
+pub const SMART_GET_VERSION: TypedIoControlCode<(), GetVersionInParams> = TypedIoControlCode::new(
+    ctl_code(IOCTL_DISK_BASE, 0x0020, METHOD_BUFFERED, FILE_READ_ACCESS),
+);
+
+/// Sends a SMART command that doesn't return data, e.g. `ENABLE_SMART`.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ni-winioctl-smart_send_drive_command
+pub const SMART_SEND_DRIVE_COMMAND: TypedIoControlCode<SendCmdInParams, SendCmdOutParams> =
+    TypedIoControlCode::new(ctl_code(
+        IOCTL_DISK_BASE,
+        0x0021,
+        METHOD_BUFFERED,
+        FILE_READ_ACCESS | FILE_WRITE_ACCESS,
+    ));
+
+/// Sends a SMART or identify command returning a sector of data, e.g.
+/// [`SendCmdInParams::read_attributes`].
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ni-winioctl-smart_rcv_drive_data
+pub const SMART_RCV_DRIVE_DATA: TypedIoControlCode<SendCmdInParams, SmartDriveData> =
+    TypedIoControlCode::new(ctl_code(
+        IOCTL_DISK_BASE,
+        0x0022,
+        METHOD_BUFFERED,
+        FILE_READ_ACCESS | FILE_WRITE_ACCESS,
+    ));
+
+/// `STORAGE_PROPERTY_QUERY`, the input of [`IOCTL_STORAGE_QUERY_PROPERTY`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoragePropertyQuery {
+    /// A [`STORAGE_PROPERTY_ID`].
+    pub property_id: u32,
+    /// A [`STORAGE_QUERY_TYPE`].
+    pub query_type: u32,
+    /// Includes the C struct's trailing padding.
+    pub additional_parameters: [u8; 4],
+}
+
+impl StoragePropertyQuery {
+    /// Queries the descriptor of `property`.
+    pub const fn standard(property: STORAGE_PROPERTY_ID) -> Self {
+        Self::new(property, STORAGE_QUERY_TYPE::PropertyStandardQuery)
+    }
+
+    /// Queries whether `property` is supported. The query fails if it isn't.
+    pub const fn exists(property: STORAGE_PROPERTY_ID) -> Self {
+        Self::new(property, STORAGE_QUERY_TYPE::PropertyExistsQuery)
+    }
+
+    const fn new(property: STORAGE_PROPERTY_ID, query_type: STORAGE_QUERY_TYPE) -> Self {
+        Self {
+            property_id: property.0 as u32,
+            query_type: query_type.0 as u32,
+            additional_parameters: [0; 4],
+        }
+    }
+}
+
+/// `STORAGE_DESCRIPTOR_HEADER`, the start of every descriptor returned by
+/// [`IOCTL_STORAGE_QUERY_PROPERTY`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageDescriptorHeader {
+    pub version: u32,
+    /// The size of the whole descriptor, in bytes.
+    pub size: u32,
+}
+
+/// `STORAGE_DEVICE_NUMBER`, the output of [`IOCTL_STORAGE_GET_DEVICE_NUMBER`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageDeviceNumber {
+    /// A `FILE_DEVICE_*` value.
+    pub device_type: u32,
+    /// The `N` in `\\.\PhysicalDriveN`.
+    pub device_number: u32,
+    /// `0` for whole disks, or `u32::MAX` if the device can't be partitioned.
+    pub partition_number: u32,
+}
+
+/// `GETVERSIONINPARAMS`, the output of [`SMART_GET_VERSION`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetVersionInParams {
+    pub version: u8,
+    pub revision: u8,
+    pub reserved: u8,
+    /// A bitmask of the drives that exist, indexed by [`SendCmdInParams::drive_number`].
+    pub ide_device_map: u8,
+    /// `CAP_*` flags.
+    pub capabilities: u32,
+    pub reserved_dwords: [u32; 4],
+}
+
+/// `IDEREGS`, the ATA task file registers of a command.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdeRegs {
+    pub features: u8,
+    pub sector_count: u8,
+    pub sector_number: u8,
+    pub cyl_low: u8,
+    pub cyl_high: u8,
+    pub drive_head: u8,
+    pub command: u8,
+    pub reserved: u8,
+}
+
+/// `SENDCMDINPARAMS` without its trailing buffer, the input of the `SMART_*` commands.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendCmdInParams {
+    /// The size of the returned data, in bytes.
+    pub buffer_size: u32,
+    pub drive_regs: IdeRegs,
+    pub drive_number: u8,
+    pub reserved: [u8; 3],
+    pub reserved_dwords: [u32; 4],
+}
+
+impl SendCmdInParams {
+    /// Reads the SMART attributes (`READ_ATTRIBUTES`) of `drive`, with [`SMART_RCV_DRIVE_DATA`].
+    pub const fn read_attributes(drive: u8) -> Self {
+        Self::smart(drive, READ_ATTRIBUTES as u8)
+    }
+
+    /// Reads the SMART thresholds (`READ_THRESHOLDS`) of `drive`, with [`SMART_RCV_DRIVE_DATA`].
+    pub const fn read_thresholds(drive: u8) -> Self {
+        Self::smart(drive, READ_THRESHOLDS as u8)
+    }
+
+    /// Reads the identify data (`ID_CMD`) of `drive`, with [`SMART_RCV_DRIVE_DATA`].
+    pub const fn identify(drive: u8) -> Self {
+        Self::command(drive, IDENTIFY_BUFFER_SIZE, 0, ID_CMD as u8)
+    }
+
+    /// A SMART command with the sub-command `feature`, e.g. `ENABLE_SMART`.
+    pub const fn smart(drive: u8, feature: u8) -> Self {
+        let mut this = Self::command(drive, READ_ATTRIBUTE_BUFFER_SIZE, feature, SMART_CMD as u8);
+        this.drive_regs.cyl_low = SMART_CYL_LOW as u8;
+        this.drive_regs.cyl_high = SMART_CYL_HI as u8;
+        this
+    }
+
+    const fn command(drive: u8, buffer_size: u32, features: u8, command: u8) -> Self {
+        Self {
+            buffer_size,
+            drive_regs: IdeRegs {
+                features,
+                sector_count: 1,
+                sector_number: 1,
+                cyl_low: 0,
+                cyl_high: 0,
+                // LBA mode, and the device bit selecting the drive on its channel
+                drive_head: 0xA0 | ((drive & 1) << 4),
+                command,
+                reserved: 0,
+            },
+            drive_number: drive,
+            reserved: [0; 3],
+            reserved_dwords: [0; 4],
+        }
+    }
+}
+
+/// `DRIVERSTATUS`, the result of a `SMART_*` command.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriverStatus {
+    pub driver_error: u8,
+    /// The contents of the drive's error register, if `driver_error` is `SMART_IDE_ERROR`.
+    pub ide_error: u8,
+    pub reserved: [u8; 2],
+    pub reserved_dwords: [u32; 2],
+}
+
+/// `SENDCMDOUTPARAMS` without its trailing buffer, the output of [`SMART_SEND_DRIVE_COMMAND`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendCmdOutParams {
+    /// The size of the returned data, in bytes.
+    pub buffer_size: u32,
+    pub driver_status: DriverStatus,
+}
+
+/// `SENDCMDOUTPARAMS` with a sector of data, the output of [`SMART_RCV_DRIVE_DATA`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmartDriveData {
+    pub header: SendCmdOutParams,
+    pub data: [u8; READ_ATTRIBUTE_BUFFER_SIZE as usize],
+}
+
+// SAFETY: All types are `repr(C)` and consist only of integers (and arrays and structs of them)
+// without padding, as checked below, and any bit pattern is valid for them.
+unsafe impl bytemuck::Zeroable for StoragePropertyQuery {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for StoragePropertyQuery {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for StorageDescriptorHeader {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for StorageDescriptorHeader {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for StorageDeviceNumber {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for StorageDeviceNumber {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for GetVersionInParams {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for GetVersionInParams {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for IdeRegs {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for IdeRegs {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for SendCmdInParams {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SendCmdInParams {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for DriverStatus {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for DriverStatus {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for SendCmdOutParams {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SendCmdOutParams {}
+// SAFETY: See above.
+unsafe impl bytemuck::Zeroable for SmartDriveData {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SmartDriveData {}
+
+// The mirrors have the size of the C structs (minus the trailing one-byte buffers), and no padding.
+const _: () = {
+    assert!(size_of::<StoragePropertyQuery>() == size_of::<STORAGE_PROPERTY_QUERY>());
+    assert!(size_of::<StorageDescriptorHeader>() == size_of::<STORAGE_DESCRIPTOR_HEADER>());
+    assert!(size_of::<StorageDeviceNumber>() == size_of::<STORAGE_DEVICE_NUMBER>());
+    assert!(size_of::<StorageDeviceNumber>() == 12);
+    assert!(size_of::<GetVersionInParams>() == size_of::<GETVERSIONINPARAMS>());
+    assert!(size_of::<GetVersionInParams>() == 24);
+    assert!(size_of::<IdeRegs>() == size_of::<IDEREGS>());
+    assert!(size_of::<SendCmdInParams>() == size_of::<SENDCMDINPARAMS>() - 1);
+    assert!(size_of::<SendCmdInParams>() == 32);
+    assert!(size_of::<DriverStatus>() == size_of::<DRIVERSTATUS>());
+    assert!(size_of::<DriverStatus>() == 12);
+    assert!(size_of::<SendCmdOutParams>() == size_of::<SENDCMDOUTPARAMS>() - 1);
+    assert!(size_of::<SendCmdOutParams>() == 16);
+    assert!(size_of::<SmartDriveData>() == 16 + READ_ATTRIBUTE_BUFFER_SIZE as usize);
+};
@@ -0,0 +1,103 @@
+//! Semantic validation of IOCTL input payloads.
+//!
+//! A checked cast only rejects bit patterns that are invalid for a type, e.g. an out-of-range
+//! enum discriminant, but not values that are valid integers yet meaningless to the driver, like a
+//! fan duty cycle above 100%. [`Validate`], usually derived, declares those constraints on the
+//! payload, and `km`'s typed request buffers check them right after the cast, failing the request
+//! with `STATUS_INVALID_PARAMETER` and the index of the offending field:
+//!
+//! ```rs, ignore
+//! #[repr(C)]
+//! #[derive(Clone, Copy, Zeroable, Pod, FixedLayout, Validate)]
+//! pub struct SetFanDuty {
+//!     #[validate(range = 0..MAX_FANS)]
+//!     pub fan: u32,
+//!     #[validate(range = 0..=100)]
+//!     pub percent: u8,
+//!     #[validate(one_of = [MODE_MANUAL, MODE_CURVE])]
+//!     pub mode: u8,
+//!     #[validate(flags = FAN_FLAG_BOOST | FAN_FLAG_QUIET)]
+//!     pub flags: u16,
+//!     #[validate(nested)]
+//!     pub curve: FanCurve,
+//! }
+//! ```
+
+use crate::{guid::Guid, utils::Fixed};
+use snafu::Snafu;
+
+/// Derives [`Validate`] for a struct, checking the constraints given by `#[validate(...)]`
+/// attributes on its fields, in declaration order. Fields without the attribute aren't checked.
+///
+/// - `#[validate(range = 1..=8)]`: the value lies within the range, which can be any range
+///   expression of the field's type.
+/// - `#[validate(one_of = [A, B, C])]`: the value is one of the listed ones, e.g. the constants of
+///   an enum-like field.
+/// - `#[validate(flags = A | B)]`: the value has no bits set beyond the given mask.
+/// - `#[validate(with = path::to::check)]`: `check(&value)` returns `true`.
+/// - `#[validate(nested)]`: the field's own [`Validate`] implementation succeeds, e.g. for nested
+///   structs or arrays of them.
+///
+/// If `km_shared` is used through another crate, its path can be given with
+/// `#[validate(crate = "km::shared")]` on the struct.
+pub use km_shared_derive::Validate;
+
+/// The semantic constraints of an IOCTL payload, beyond it being a valid bit pattern. See the
+/// [module documentation](self).
+pub trait Validate {
+    /// Checks the constraints, returning the first field that violates them.
+    fn validate(&self) -> Result<(), InvalidField>;
+}
+
+/// A field of an IOCTL payload violated its constraints.
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+#[snafu(display("field {index} is invalid"))]
+pub struct InvalidField {
+    /// The index of the field in declaration order, or of the element of an array. For `nested`
+    /// fields, this is the index of the field in the outer struct.
+    pub index: u32,
+}
+
+macro_rules! impl_validate_unconstrained {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Validate for $t {
+                fn validate(&self) -> Result<(), InvalidField> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+// any valid bit pattern of these is valid
+impl_validate_unconstrained!(
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    bool,
+    (),
+    Guid,
+    Fixed,
+    super::IoControlCode
+);
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate(&self) -> Result<(), InvalidField> {
+        for (index, element) in self.iter().enumerate() {
+            if element.validate().is_err() {
+                return Err(InvalidField {
+                    index: index as u32,
+                });
+            }
+        }
+        Ok(())
+    }
+}
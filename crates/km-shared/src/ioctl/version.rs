@@ -0,0 +1,97 @@
+//! Versioning of the IOCTL interface between a driver and its user-mode clients.
+//!
+//! A driver exposes its [`ProtocolVersion`] through [`ioctl_get_interface_version`]. Clients query
+//! it first, and only issue codes that the installed driver [supports](VersionedIoControlCode),
+//! which allows them to gracefully handle older drivers.
+
+use super::{DeviceType, IoControlCode, IoCtlAccess, IoCtlTransferType, TypedIoControlCode};
+use core::fmt;
+
+/// The version of a driver's IOCTL interface.
+///
+/// Minor version bumps only add new codes, so a driver supports everything introduced up to its
+/// minor version within the same major version. Major version bumps are breaking.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProtocolVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+// SAFETY: `ProtocolVersion` is `repr(C)`, consists of two `u16`s without padding, and any bit
+// pattern is valid for it.
+unsafe impl bytemuck::Zeroable for ProtocolVersion {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for ProtocolVersion {}
+// SAFETY: `ProtocolVersion` is `repr(C)`, and consists of two `u16`s.
+unsafe impl super::FixedLayout for ProtocolVersion {}
+
+impl ProtocolVersion {
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+
+    /// Whether a driver implementing `self` supports everything introduced in `required`.
+    pub const fn supports(self, required: ProtocolVersion) -> bool {
+        self.major == required.major && self.minor >= required.minor
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The function code reserved for [`ioctl_get_interface_version`].
+///
+/// This is the highest available custom function code, so that it doesn't collide with codes
+/// allocated sequentially from `0x800`.
+pub const INTERFACE_VERSION_FUNCTION: u16 = 0xFFF;
+
+/// The standard `IOCTL_GET_INTERFACE_VERSION` code for a driver using `device_type` for its
+/// codes. Takes no input and returns the driver's [`ProtocolVersion`].
+///
+/// The code's layout never changes, so clients can always issue it, regardless of the version
+/// of the installed driver.
+pub const fn ioctl_get_interface_version(
+    device_type: DeviceType,
+) -> TypedIoControlCode<(), ProtocolVersion> {
+    TypedIoControlCode::new(IoControlCode::new_custom(
+        device_type,
+        INTERFACE_VERSION_FUNCTION,
+        IoCtlTransferType::Buffered,
+        IoCtlAccess::any_access(),
+    ))
+}
+
+/// A [`TypedIoControlCode`] tagged with the protocol version it was introduced in.
+///
+/// Created by [`TypedIoControlCode::since`]:
+/// ```rs, ignore
+/// const IOCTL_READ_SENSOR: VersionedIoControlCode<SensorId, SensorValue> =
+///     TypedIoControlCode::new(IoControlCode::new_custom(/* ... */))
+///         .since(ProtocolVersion::new(1, 2));
+/// ```
+#[repr(C)]
+pub struct VersionedIoControlCode<I, O> {
+    pub code: TypedIoControlCode<I, O>,
+    pub since: ProtocolVersion,
+}
+
+impl<I, O> VersionedIoControlCode<I, O> {
+    /// Whether a driver implementing `driver_version` supports this code.
+    pub const fn is_supported_by(&self, driver_version: ProtocolVersion) -> bool {
+        driver_version.supports(self.since)
+    }
+}
+
+impl<I, O> TypedIoControlCode<I, O> {
+    /// Tags the code with the protocol version it was introduced in.
+    pub const fn since(self, version: ProtocolVersion) -> VersionedIoControlCode<I, O> {
+        VersionedIoControlCode {
+            code: self,
+            since: version,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Definitions and helpers for use in both kernel and user mode.
+//!
+//! The kernel bindings are behind the `km-sys` feature, which `km` enables. User-mode clients,
+//! including 32-bit ones, use the crate without it, as the bindings only exist for x64.
 
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rust_2018_idioms)]
 // `unsafe` blocks inside `unsafe` fns make sense
 #![deny(unsafe_op_in_unsafe_fn)]
@@ -9,9 +12,20 @@
 // False positives on compile-time checks: https://github.com/rust-lang/rust-clippy/issues/8159
 #![allow(clippy::assertions_on_constants)]
 
+pub mod checksum;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+pub mod guid;
+pub mod io;
 pub mod ioctl;
 pub mod ntstatus;
+pub mod ring;
+pub mod smbus;
 pub mod strings;
+mod sys;
+pub mod sysinfo;
+pub mod transfer;
 pub mod utils;
+pub mod wire;
 
 pub use wchar::wchz;
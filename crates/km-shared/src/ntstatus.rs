@@ -1,15 +1,36 @@
-use core::{fmt::Display, num::NonZeroI32};
-use km_sys::NTSTATUS;
+use crate::sys::NTSTATUS;
+use core::{
+    fmt::{self, Display},
+    num::NonZeroI32,
+    panic::Location,
+};
 use snafu::Snafu;
 
+#[rustfmt::skip]
 mod consts;
+mod custom;
+mod win32;
 
-#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
-#[snafu(display("NTSTATUS {:X}", status))]
-#[repr(transparent)]
+pub use custom::*;
+
+/// An error `NTSTATUS` value.
+///
+/// With the `ntstatus-location` feature enabled, the error also records where it was created
+/// through [`NtStatus::result`] (or [`NtStatus::result_for`]), and optionally which operation
+/// failed. Both are shown by its `Display` impl. Without the feature, the error is just the
+/// status, so release builds don't pay for it.
+///
+/// Errors compare equal if their statuses are equal, regardless of their context.
+#[derive(Debug, Snafu, Clone, Copy)]
+#[snafu(display("NTSTATUS {}{}", self.status(), ErrorContextDisplay(self)))]
+#[cfg_attr(not(feature = "ntstatus-location"), repr(transparent))]
 pub struct NtStatusError {
     // Any non-success NTSTATUS cannot be 0.
     status: NonZeroI32,
+    #[cfg(feature = "ntstatus-location")]
+    location: Option<&'static Location<'static>>,
+    #[cfg(feature = "ntstatus-location")]
+    operation: Option<&'static str>,
 }
 
 impl NtStatusError {
@@ -17,11 +38,79 @@ impl NtStatusError {
         NtStatus(self.status.get())
     }
 
+    /// The source location the error was created at. Always `None` without the
+    /// `ntstatus-location` feature, and for the predefined `STATUS_*` constants.
+    pub const fn location(&self) -> Option<&'static Location<'static>> {
+        #[cfg(feature = "ntstatus-location")]
+        return self.location;
+        #[cfg(not(feature = "ntstatus-location"))]
+        return None;
+    }
+
+    /// The operation that failed, as passed to [`NtStatus::result_for`] or
+    /// [`Self::with_operation`]. Always `None` without the `ntstatus-location` feature.
+    pub const fn operation(&self) -> Option<&'static str> {
+        #[cfg(feature = "ntstatus-location")]
+        return self.operation;
+        #[cfg(not(feature = "ntstatus-location"))]
+        return None;
+    }
+
+    /// Attaches the name of the operation that failed, e.g. `"WdfIoTargetOpen"`. Does nothing
+    /// without the `ntstatus-location` feature.
+    #[allow(unused_mut, unused_variables)]
+    pub const fn with_operation(mut self, operation: &'static str) -> Self {
+        #[cfg(feature = "ntstatus-location")]
+        {
+            self.operation = Some(operation);
+        }
+        self
+    }
+
     pub(crate) const fn from_u32(status: u32) -> Self {
-        match NtStatus::from_u32(status).result() {
-            Ok(_) => panic!("not an error NTSTATUS"),
-            Err(e) => e,
+        match NtStatus::from_u32(status).severity() {
+            Severity::Error => {}
+            _ => panic!("not an error NTSTATUS"),
+        }
+
+        match NonZeroI32::new(status as i32) {
+            Some(status) => Self::new(status, None),
+            None => unreachable!(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    const fn new(status: NonZeroI32, location: Option<&'static Location<'static>>) -> Self {
+        NtStatusError {
+            status,
+            #[cfg(feature = "ntstatus-location")]
+            location,
+            #[cfg(feature = "ntstatus-location")]
+            operation: None,
+        }
+    }
+}
+
+impl PartialEq for NtStatusError {
+    fn eq(&self, other: &Self) -> bool {
+        self.status == other.status
+    }
+}
+
+impl Eq for NtStatusError {}
+
+/// Formats the operation and location of an [`NtStatusError`], if recorded.
+struct ErrorContextDisplay<'a>(&'a NtStatusError);
+
+impl Display for ErrorContextDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(operation) = self.0.operation() {
+            write!(f, " in {operation}")?;
+        }
+        if let Some(location) = self.0.location() {
+            write!(f, " at {location}")?;
         }
+        Ok(())
     }
 }
 
@@ -86,8 +175,19 @@ impl NtStatus {
         self.0 as u16
     }
 
+    /// Returns the symbolic name of the status as defined in `ntstatus.h`, e.g.
+    /// `"STATUS_ACCESS_DENIED"`. If several names are defined for the same value, the
+    /// alphabetically first one is returned.
+    #[cfg(feature = "ntstatus-names")]
+    pub const fn name(self) -> Option<&'static str> {
+        consts::name(self.0 as u32)
+    }
+
     /// Converts an NtStatus to a Result, returning an error if the status is an error code. With
     /// debug assertions enabled, warnings are also treated as errors.
+    ///
+    /// With the `ntstatus-location` feature enabled, the error records the caller's location.
+    #[track_caller]
     pub const fn result(self) -> Result<NtStatus, NtStatusError> {
         let n = match self.severity() {
             Severity::Error => self.0,
@@ -98,17 +198,40 @@ impl NtStatus {
         };
 
         if let Some(n) = NonZeroI32::new(n) {
-            Err(NtStatusError { status: n })
+            Err(NtStatusError::new(n, caller_location()))
         } else {
             // Any non-success NTSTATUS cannot be 0. The severity bits checked above are non-zero
             // for non success values, so this branch is unreachable and gets optimized out.
             unreachable!()
         }
     }
+
+    /// Like [`Self::result`], but also records the name of the operation that returned the
+    /// status, e.g. `"WdfIoTargetOpen"`.
+    #[track_caller]
+    pub const fn result_for(self, operation: &'static str) -> Result<NtStatus, NtStatusError> {
+        match self.result() {
+            Ok(status) => Ok(status),
+            Err(e) => Err(e.with_operation(operation)),
+        }
+    }
+}
+
+#[track_caller]
+const fn caller_location() -> Option<&'static Location<'static>> {
+    #[cfg(feature = "ntstatus-location")]
+    return Some(Location::caller());
+    #[cfg(not(feature = "ntstatus-location"))]
+    return None;
 }
 
 impl Display for NtStatus {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "ntstatus-names")]
+        if let Some(name) = self.name() {
+            return write!(f, "{name} ({:08X})", self.0);
+        }
+
         write!(f, "{:08X}", self.0)
     }
 }
@@ -125,6 +248,12 @@ impl From<NTSTATUS> for NtStatus {
     }
 }
 
+impl From<NtStatusError> for NtStatus {
+    fn from(error: NtStatusError) -> NtStatus {
+        error.status()
+    }
+}
+
 impl From<u32> for NtStatus {
     fn from(status: u32) -> NtStatus {
         NtStatus::from_u32(status)
@@ -0,0 +1,161 @@
+use super::{NtStatus, NtStatusError, Severity};
+use core::fmt::{self, Display};
+
+/// A facility for driver-defined `NTSTATUS` values.
+///
+/// All statuses created from a `CustomFacility` have the customer bit set, so they can never
+/// collide with system-defined statuses. Usually, statuses are declared with the
+/// [`custom_ntstatus!`](crate::custom_ntstatus!) macro instead of using this type directly.
+///
+/// See [Defining New NTSTATUS Values][MSDN] for more information.
+///
+/// [MSDN]:
+///     https://docs.microsoft.com/en-us/windows-hardware/drivers/kernel/defining-new-ntstatus-values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomFacility(u16);
+
+impl CustomFacility {
+    /// Creates a new custom facility.
+    ///
+    /// This function panics if `facility` doesn't fit into the 12 bits available for facilities
+    /// (`0x0`-`0xFFF`). Note that this is a `const fn`, so this panic happens at compile time if
+    /// used to define constants.
+    pub const fn new(facility: u16) -> Self {
+        assert!(
+            facility <= 0xFFF,
+            "facility has only 12 bits - must be <= 0xFFF"
+        );
+
+        Self(facility)
+    }
+
+    pub const fn facility(self) -> u16 {
+        self.0
+    }
+
+    pub const fn success(self, code: u16) -> NtStatus {
+        NtStatus::new(true, Severity::Success, self.0, code)
+    }
+
+    pub const fn information(self, code: u16) -> NtStatus {
+        NtStatus::new(true, Severity::Information, self.0, code)
+    }
+
+    pub const fn warning(self, code: u16) -> NtStatus {
+        NtStatus::new(true, Severity::Warning, self.0, code)
+    }
+
+    pub const fn error(self, code: u16) -> NtStatusError {
+        NtStatusError::from_u32(NtStatus::new(true, Severity::Error, self.0, code).0 as u32)
+    }
+
+    /// Whether `status` is a custom status of this facility.
+    pub const fn contains(self, status: NtStatus) -> bool {
+        status.custom() && status.facility() == self.0
+    }
+}
+
+/// Displays a status with its name, if known. Returned by the `display` function generated by
+/// [`custom_ntstatus!`](crate::custom_ntstatus!).
+#[derive(Debug, Clone, Copy)]
+pub struct CustomStatusDisplay {
+    pub status: NtStatus,
+    pub name: Option<&'static str>,
+}
+
+impl Display for CustomStatusDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{name} ({:08X})", self.status.0),
+            None => Display::fmt(&self.status, f),
+        }
+    }
+}
+
+/// Declares a module of driver-defined `NTSTATUS` values for a [`CustomFacility`].
+///
+/// Each status is declared with its severity (`success`, `information`, `warning` or `error`).
+/// Error statuses are declared as [`NtStatusError`]s, all others as [`NtStatus`]es. The module
+/// also contains a `name` function to look up the name of a status, and a `display` function to
+/// format a status with its name.
+///
+/// Example:
+/// ```rs, ignore
+/// custom_ntstatus! {
+///     /// Statuses of the sensor driver.
+///     pub mod sensor_status: CustomFacility::new(0x100);
+///
+///     /// The sensor didn't respond in time.
+///     error SENSOR_TIMEOUT = 0x1;
+///     /// The sensor reported a value out of its range, which was clamped.
+///     warning SENSOR_VALUE_CLAMPED = 0x2;
+/// }
+///
+/// log::error!("reading failed: {}", sensor_status::display(status));
+/// ```
+#[macro_export]
+macro_rules! custom_ntstatus {
+    {
+        $(#[$mod_attr:meta])*
+        $vis:vis mod $mod_name:ident: $facility:expr;
+
+        $(
+            $(#[$attr:meta])*
+            $severity:ident $name:ident = $code:expr;
+        )*
+    } => {
+        $(#[$mod_attr])*
+        $vis mod $mod_name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            pub const FACILITY: $crate::ntstatus::CustomFacility = $facility;
+
+            $(
+                $(#[$attr])*
+                pub const $name: $crate::__custom_ntstatus_type!($severity) =
+                    FACILITY.$severity($code);
+            )*
+
+            /// Looks up the name of a status declared in this module.
+            pub fn name(status: $crate::ntstatus::NtStatus) -> Option<&'static str> {
+                $(
+                    if status == $crate::ntstatus::NtStatus::from($name) {
+                        return Some(stringify!($name));
+                    }
+                )*
+
+                None
+            }
+
+            /// Formats a status with its name, if it is declared in this module.
+            pub fn display(
+                status: impl Into<$crate::ntstatus::NtStatus>,
+            ) -> $crate::ntstatus::CustomStatusDisplay {
+                let status = status.into();
+                $crate::ntstatus::CustomStatusDisplay {
+                    status,
+                    name: name(status),
+                }
+            }
+        }
+    };
+}
+
+/// Not to be used directly. Maps a severity keyword of [`custom_ntstatus!`] to its status type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __custom_ntstatus_type {
+    (error) => {
+        $crate::ntstatus::NtStatusError
+    };
+    (success) => {
+        $crate::ntstatus::NtStatus
+    };
+    (information) => {
+        $crate::ntstatus::NtStatus
+    };
+    (warning) => {
+        $crate::ntstatus::NtStatus
+    };
+}
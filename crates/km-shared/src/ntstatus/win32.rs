@@ -0,0 +1,136 @@
+use super::{NtStatus, NtStatusError, Severity};
+
+/// `FACILITY_NT_BIT`, set on HRESULTs that wrap an `NTSTATUS`.
+const FACILITY_NT_BIT: u32 = 0x1000_0000;
+/// `FACILITY_NTWIN32`, the NTSTATUS facility of Win32 error codes wrapped in an `NTSTATUS`.
+const FACILITY_NTWIN32: u16 = 0x7;
+/// `FACILITY_WIN32`, the HRESULT facility of Win32 error codes wrapped in an `HRESULT`.
+const FACILITY_WIN32: u32 = 0x7;
+
+const ERROR_MR_MID_NOT_FOUND: u32 = 317;
+
+/// Common `NTSTATUS` values and the Win32 error codes `RtlNtStatusToDosError` maps them to.
+///
+/// If several statuses map to the same error code, the first one is used for the reverse
+/// conversion.
+const STATUS_TO_WIN32: &[(u32, u32)] = &[
+    (0x0000_0000, 0),    // STATUS_SUCCESS -> ERROR_SUCCESS
+    (0x0000_0102, 258),  // STATUS_TIMEOUT -> WAIT_TIMEOUT
+    (0x0000_0103, 997),  // STATUS_PENDING -> ERROR_IO_PENDING
+    (0x8000_0005, 234),  // STATUS_BUFFER_OVERFLOW -> ERROR_MORE_DATA
+    (0x8000_0011, 170),  // STATUS_DEVICE_BUSY -> ERROR_BUSY
+    (0x8000_001A, 259),  // STATUS_NO_MORE_ENTRIES -> ERROR_NO_MORE_ITEMS
+    (0xC000_0001, 31),   // STATUS_UNSUCCESSFUL -> ERROR_GEN_FAILURE
+    (0xC000_0010, 1),    // STATUS_INVALID_DEVICE_REQUEST -> ERROR_INVALID_FUNCTION
+    (0xC000_0002, 1),    // STATUS_NOT_IMPLEMENTED -> ERROR_INVALID_FUNCTION
+    (0xC000_0004, 24),   // STATUS_INFO_LENGTH_MISMATCH -> ERROR_BAD_LENGTH
+    (0xC000_0008, 6),    // STATUS_INVALID_HANDLE -> ERROR_INVALID_HANDLE
+    (0xC000_0024, 6),    // STATUS_OBJECT_TYPE_MISMATCH -> ERROR_INVALID_HANDLE
+    (0xC000_000D, 87),   // STATUS_INVALID_PARAMETER -> ERROR_INVALID_PARAMETER
+    (0xC000_0034, 2),    // STATUS_OBJECT_NAME_NOT_FOUND -> ERROR_FILE_NOT_FOUND
+    (0xC000_000E, 2),    // STATUS_NO_SUCH_DEVICE -> ERROR_FILE_NOT_FOUND
+    (0xC000_000F, 2),    // STATUS_NO_SUCH_FILE -> ERROR_FILE_NOT_FOUND
+    (0xC000_0011, 38),   // STATUS_END_OF_FILE -> ERROR_HANDLE_EOF
+    (0xC000_0017, 8),    // STATUS_NO_MEMORY -> ERROR_NOT_ENOUGH_MEMORY
+    (0xC000_0022, 5),    // STATUS_ACCESS_DENIED -> ERROR_ACCESS_DENIED
+    (0xC000_0023, 122),  // STATUS_BUFFER_TOO_SMALL -> ERROR_INSUFFICIENT_BUFFER
+    (0xC000_0033, 123),  // STATUS_OBJECT_NAME_INVALID -> ERROR_INVALID_NAME
+    (0xC000_0035, 183),  // STATUS_OBJECT_NAME_COLLISION -> ERROR_ALREADY_EXISTS
+    (0xC000_003A, 3),    // STATUS_OBJECT_PATH_NOT_FOUND -> ERROR_PATH_NOT_FOUND
+    (0xC000_003E, 23),   // STATUS_DATA_ERROR -> ERROR_CRC
+    (0xC000_0043, 32),   // STATUS_SHARING_VIOLATION -> ERROR_SHARING_VIOLATION
+    (0xC000_0061, 1314), // STATUS_PRIVILEGE_NOT_HELD -> ERROR_PRIVILEGE_NOT_HELD
+    (0xC000_009A, 1450), // STATUS_INSUFFICIENT_RESOURCES -> ERROR_NO_SYSTEM_RESOURCES
+    (0xC000_00A3, 21),   // STATUS_DEVICE_NOT_READY -> ERROR_NOT_READY
+    (0xC000_009D, 21),   // STATUS_DEVICE_NOT_CONNECTED -> ERROR_NOT_READY
+    (0xC000_00B5, 121),  // STATUS_IO_TIMEOUT -> ERROR_SEM_TIMEOUT
+    (0xC000_00BB, 50),   // STATUS_NOT_SUPPORTED -> ERROR_NOT_SUPPORTED
+    (0xC000_00C0, 55),   // STATUS_DEVICE_DOES_NOT_EXIST -> ERROR_DEV_NOT_EXIST
+    (0xC000_00E5, 1359), // STATUS_INTERNAL_ERROR -> ERROR_INTERNAL_ERROR
+    (0xC000_00E8, 1784), // STATUS_INVALID_USER_BUFFER -> ERROR_INVALID_USER_BUFFER
+    (0xC000_0206, 1784), // STATUS_INVALID_BUFFER_SIZE -> ERROR_INVALID_USER_BUFFER
+    (0xC000_0120, 995),  // STATUS_CANCELLED -> ERROR_OPERATION_ABORTED
+    (0xC000_0184, 22),   // STATUS_INVALID_DEVICE_STATE -> ERROR_BAD_COMMAND
+    (0xC000_0185, 1117), // STATUS_IO_DEVICE_ERROR -> ERROR_IO_DEVICE
+    (0xC000_0225, 1168), // STATUS_NOT_FOUND -> ERROR_NOT_FOUND
+];
+
+impl NtStatus {
+    /// Converts the status to a Win32 error code, like `RtlNtStatusToDosError` does.
+    ///
+    /// Only common statuses are mapped. Statuses wrapping a Win32 error code (facility
+    /// `FACILITY_NTWIN32`) are unwrapped, and any other status is mapped to
+    /// `ERROR_MR_MID_NOT_FOUND`, just like `RtlNtStatusToDosError` does for unknown statuses.
+    pub const fn to_win32(self) -> u32 {
+        let status = self.0 as u32;
+
+        let mut i = 0;
+        while i < STATUS_TO_WIN32.len() {
+            if STATUS_TO_WIN32[i].0 == status {
+                return STATUS_TO_WIN32[i].1;
+            }
+            i += 1;
+        }
+
+        if self.facility() == FACILITY_NTWIN32 && !self.custom() {
+            return self.code() as u32;
+        }
+
+        ERROR_MR_MID_NOT_FOUND
+    }
+
+    /// Converts a Win32 error code to a status.
+    ///
+    /// Common error codes are mapped to their matching status. Any other code is wrapped into an
+    /// error status with the facility `FACILITY_NTWIN32`, like the `NTSTATUS_FROM_WIN32` macro
+    /// does, which [`Self::to_win32`] maps back to the original code.
+    pub const fn from_win32(error: u32) -> NtStatus {
+        let mut i = 0;
+        while i < STATUS_TO_WIN32.len() {
+            if STATUS_TO_WIN32[i].1 == error {
+                return NtStatus::from_u32(STATUS_TO_WIN32[i].0);
+            }
+            i += 1;
+        }
+
+        NtStatus::new(false, Severity::Error, FACILITY_NTWIN32, error as u16)
+    }
+
+    /// Converts the status to an `HRESULT`, like the `HRESULT_FROM_NT` macro does.
+    ///
+    /// Note that this sets the `FACILITY_NT_BIT` for every status, so `STATUS_SUCCESS` does *not*
+    /// map to `S_OK`.
+    pub const fn to_hresult(self) -> i32 {
+        ((self.0 as u32) | FACILITY_NT_BIT) as i32
+    }
+
+    /// Converts an `HRESULT` back to a status.
+    ///
+    /// This supports `HRESULT`s created by [`Self::to_hresult`] (`HRESULT_FROM_NT`) and by
+    /// `HRESULT_FROM_WIN32`, as well as `S_OK`. Returns `None` for any other `HRESULT`.
+    pub const fn from_hresult(hresult: i32) -> Option<NtStatus> {
+        let hresult = hresult as u32;
+
+        if hresult == 0 {
+            Some(NtStatus::STATUS_SUCCESS)
+        } else if hresult & FACILITY_NT_BIT != 0 {
+            Some(NtStatus::from_u32(hresult & !FACILITY_NT_BIT))
+        } else if (hresult >> 16) & 0x1FFF == FACILITY_WIN32 && hresult >> 31 == 1 {
+            Some(NtStatus::from_win32(hresult & 0xFFFF))
+        } else {
+            None
+        }
+    }
+}
+
+impl NtStatusError {
+    /// See [`NtStatus::to_win32`].
+    pub const fn to_win32(&self) -> u32 {
+        self.status().to_win32()
+    }
+
+    /// See [`NtStatus::to_hresult`].
+    pub const fn to_hresult(&self) -> i32 {
+        self.status().to_hresult()
+    }
+}
@@ -0,0 +1,337 @@
+//! A bounded single-producer/single-consumer byte ring, shared between kernel and user mode.
+//!
+//! The ring consists of a [`RingHeader`] directly followed by `capacity` bytes of data, so it can
+//! be placed in memory mapped into both the driver and a user-mode service (e.g. a common
+//! buffer). The driver writes samples through a [`RingProducer`] as they arrive, and the service
+//! reads them through a [`RingConsumer`] whenever it polls, instead of copying whole arrays.
+//!
+//! Neither side trusts the indices written by the other side: all accesses are masked to the
+//! capacity each side was created with, so a misbehaving peer can only corrupt the data in the
+//! ring, never memory outside of it.
+
+use crate::utils::CapacityError;
+use bytemuck::{AnyBitPattern, NoUninit};
+use core::{
+    cell::UnsafeCell,
+    marker::PhantomData,
+    mem::{size_of, zeroed},
+    ptr::{self, NonNull},
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+/// The header of a ring, followed by its data.
+///
+/// The indices are free-running and wrap around at `u32::MAX`, so the number of used bytes is
+/// `write - read`. The capacity is a power of two, so indices are turned into offsets by masking.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RingHeader {
+    /// The total number of bytes written, only modified by the producer.
+    write: AtomicU32,
+    /// The total number of bytes read, only modified by the consumer.
+    read: AtomicU32,
+    /// The number of data bytes following the header.
+    capacity: u32,
+    /// The number of writes dropped because the ring was full.
+    dropped: AtomicU32,
+}
+
+impl RingHeader {
+    /// Creates the header of an empty ring with `capacity` bytes of data.
+    ///
+    /// Panics if `capacity` isn't a power of two.
+    pub const fn new(capacity: u32) -> Self {
+        assert!(
+            capacity.is_power_of_two(),
+            "ring capacity must be a power of two"
+        );
+
+        Self {
+            write: AtomicU32::new(0),
+            read: AtomicU32::new(0),
+            capacity,
+            dropped: AtomicU32::new(0),
+        }
+    }
+
+    /// The capacity stored in the header. Only trust this value if the header was initialized by
+    /// the same side, as the peer might have modified it.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// The size in bytes of a ring with `capacity` bytes of data, including its header.
+    pub const fn ring_size(capacity: u32) -> usize {
+        size_of::<Self>() + capacity as usize
+    }
+}
+
+/// A ring with `N` bytes of data stored inline, e.g. in a static or a device context.
+///
+/// `N` must be a power of two.
+#[repr(C)]
+pub struct Ring<const N: usize> {
+    header: RingHeader,
+    data: [UnsafeCell<u8>; N],
+}
+
+// SAFETY: The halves ensure that there is only one producer and one consumer, and synchronize
+// through the atomic indices.
+unsafe impl<const N: usize> Sync for Ring<N> {}
+
+impl<const N: usize> Ring<N> {
+    const CAPACITY: u32 = {
+        assert!(N <= u32::MAX as usize, "capacity must fit into a u32");
+        N as u32
+    };
+
+    pub const fn new() -> Self {
+        // SAFETY: Zeroes are valid bytes. The data is initialized so that reading it is sound even
+        // if the indices say that bytes are available which were never written.
+        let data = unsafe { zeroed() };
+
+        Self {
+            header: RingHeader::new(Self::CAPACITY),
+            data,
+        }
+    }
+
+    /// Splits the ring into its producer and consumer halves.
+    pub fn split(&mut self) -> (RingProducer<'_>, RingConsumer<'_>) {
+        // derived from the whole ring, so that the halves may access the data after the header
+        let header = NonNull::from(self).cast::<RingHeader>();
+
+        // SAFETY: The ring is `repr(C)`, so the header is at its start, followed by `N` bytes of
+        // data. It's borrowed mutably, so these are the only halves.
+        unsafe {
+            (
+                RingProducer::from_raw(header, Self::CAPACITY),
+                RingConsumer::from_raw(header, Self::CAPACITY),
+            )
+        }
+    }
+}
+
+impl<const N: usize> Default for Ring<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The common state of both halves of a ring.
+struct RawRing<'a> {
+    header: NonNull<RingHeader>,
+    mask: u32,
+    _marker: PhantomData<&'a RingHeader>,
+}
+
+impl RawRing<'_> {
+    /// # Safety
+    /// See [`RingProducer::from_raw`].
+    unsafe fn new(header: NonNull<RingHeader>, capacity: u32) -> Self {
+        assert!(
+            capacity.is_power_of_two(),
+            "ring capacity must be a power of two"
+        );
+
+        Self {
+            header,
+            mask: capacity - 1,
+            _marker: PhantomData,
+        }
+    }
+
+    fn header(&self) -> &RingHeader {
+        // SAFETY: The header is valid for the lifetime of the ring, and only accessed atomically
+        // or read-only.
+        unsafe { self.header.as_ref() }
+    }
+
+    fn capacity(&self) -> u32 {
+        self.mask + 1
+    }
+
+    fn data(&self) -> *mut u8 {
+        // SAFETY: The data directly follows the header.
+        unsafe { self.header.as_ptr().add(1).cast() }
+    }
+
+    /// The number of used bytes, limited to the capacity in case the peer corrupted the indices.
+    fn used(&self, write: u32, read: u32) -> u32 {
+        u32::min(write.wrapping_sub(read), self.capacity())
+    }
+
+    /// Copies `src` into the ring, starting at index `at`, wrapping around at the end.
+    ///
+    /// # Safety
+    /// The range must not be accessed by the peer concurrently.
+    unsafe fn copy_in(&self, at: u32, src: &[u8]) {
+        let offset = (at & self.mask) as usize;
+        let first = usize::min(src.len(), self.capacity() as usize - offset);
+
+        // SAFETY: Both ranges are in bounds of the data due to masking, and guaranteed to be
+        // exclusive by the caller.
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), self.data().add(offset), first);
+            ptr::copy_nonoverlapping(src.as_ptr().add(first), self.data(), src.len() - first);
+        }
+    }
+
+    /// Copies bytes from the ring into `dst`, starting at index `at`, wrapping around at the end.
+    ///
+    /// # Safety
+    /// The range must not be accessed by the peer concurrently.
+    unsafe fn copy_out(&self, at: u32, dst: &mut [u8]) {
+        let offset = (at & self.mask) as usize;
+        let first = usize::min(dst.len(), self.capacity() as usize - offset);
+
+        // SAFETY: Both ranges are in bounds of the data due to masking, and guaranteed to be
+        // exclusive by the caller.
+        unsafe {
+            ptr::copy_nonoverlapping(self.data().add(offset), dst.as_mut_ptr(), first);
+            ptr::copy_nonoverlapping(self.data(), dst.as_mut_ptr().add(first), dst.len() - first);
+        }
+    }
+}
+
+/// The writing half of a ring, usually used by the driver.
+pub struct RingProducer<'a>(RawRing<'a>);
+
+// SAFETY: There is only one producer, which synchronizes with the consumer through the indices.
+unsafe impl Send for RingProducer<'_> {}
+
+impl RingProducer<'_> {
+    /// Creates the producer of the ring at `header`.
+    ///
+    /// `capacity` is the capacity the ring was created with. The capacity stored in the header
+    /// isn't used, as it might have been modified by the peer.
+    ///
+    /// Panics if `capacity` isn't a power of two.
+    ///
+    /// # Safety
+    /// - `header` must point to an initialized [`RingHeader`], directly followed by `capacity`
+    ///   initialized bytes, which must stay valid for the lifetime of the producer.
+    /// - There must be no other producer of the ring.
+    pub unsafe fn from_raw(header: NonNull<RingHeader>, capacity: u32) -> Self {
+        // SAFETY: Guaranteed by the caller.
+        Self(unsafe { RawRing::new(header, capacity) })
+    }
+
+    /// The number of bytes that can currently be written.
+    pub fn free(&self) -> usize {
+        let header = self.0.header();
+        let write = header.write.load(Ordering::Relaxed);
+        let read = header.read.load(Ordering::Acquire);
+
+        (self.0.capacity() - self.0.used(write, read)) as usize
+    }
+
+    /// Writes all of `bytes`, or nothing if they don't fit. Failed writes are counted as
+    /// [dropped](RingConsumer::dropped).
+    pub fn write_all(&mut self, bytes: &[u8]) -> Result<(), CapacityError> {
+        if bytes.len() > self.free() {
+            self.0.header().dropped.fetch_add(1, Ordering::Relaxed);
+            return Err(CapacityError(()));
+        }
+
+        self.write_unchecked(bytes);
+        Ok(())
+    }
+
+    /// Writes as many bytes of `bytes` as fit, returning their number.
+    pub fn write(&mut self, bytes: &[u8]) -> usize {
+        let len = usize::min(bytes.len(), self.free());
+        self.write_unchecked(&bytes[..len]);
+        len
+    }
+
+    /// Writes the bytes of `value`, or nothing if they don't fit (see [`Self::write_all`]).
+    pub fn push<T: NoUninit>(&mut self, value: &T) -> Result<(), CapacityError> {
+        self.write_all(bytemuck::bytes_of(value))
+    }
+
+    /// Writes `bytes`, which must fit into the free space.
+    fn write_unchecked(&mut self, bytes: &[u8]) {
+        let header = self.0.header();
+        let write = header.write.load(Ordering::Relaxed);
+
+        // SAFETY: The bytes fit into the free space, which the consumer doesn't access.
+        unsafe { self.0.copy_in(write, bytes) };
+        header
+            .write
+            .store(write.wrapping_add(bytes.len() as u32), Ordering::Release);
+    }
+}
+
+/// The reading half of a ring, usually used by a user-mode service.
+pub struct RingConsumer<'a>(RawRing<'a>);
+
+// SAFETY: There is only one consumer, which synchronizes with the producer through the indices.
+unsafe impl Send for RingConsumer<'_> {}
+
+impl RingConsumer<'_> {
+    /// Creates the consumer of the ring at `header`.
+    ///
+    /// See [`RingProducer::from_raw`] for the meaning of `capacity`.
+    ///
+    /// Panics if `capacity` isn't a power of two.
+    ///
+    /// # Safety
+    /// - `header` must point to an initialized [`RingHeader`], directly followed by `capacity`
+    ///   initialized bytes, which must stay valid for the lifetime of the consumer.
+    /// - There must be no other consumer of the ring.
+    pub unsafe fn from_raw(header: NonNull<RingHeader>, capacity: u32) -> Self {
+        // SAFETY: Guaranteed by the caller.
+        Self(unsafe { RawRing::new(header, capacity) })
+    }
+
+    /// The number of bytes that can currently be read.
+    pub fn available(&self) -> usize {
+        let header = self.0.header();
+        let write = header.write.load(Ordering::Acquire);
+        let read = header.read.load(Ordering::Relaxed);
+
+        self.0.used(write, read) as usize
+    }
+
+    /// The number of writes dropped by the producer so far because the ring was full.
+    pub fn dropped(&self) -> u32 {
+        self.0.header().dropped.load(Ordering::Relaxed)
+    }
+
+    /// Reads as many bytes into `buf` as are available, returning their number.
+    pub fn read(&mut self, buf: &mut [u8]) -> usize {
+        let len = usize::min(buf.len(), self.available());
+        self.read_unchecked(&mut buf[..len]);
+        len
+    }
+
+    /// Fills all of `buf`, or reads nothing if not enough bytes are available.
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> bool {
+        if buf.len() > self.available() {
+            return false;
+        }
+
+        self.read_unchecked(buf);
+        true
+    }
+
+    /// Reads a value written with [`RingProducer::push`], if available.
+    pub fn pop<T: AnyBitPattern + NoUninit>(&mut self) -> Option<T> {
+        let mut value = T::zeroed();
+        self.read_exact(bytemuck::bytes_of_mut(&mut value))
+            .then_some(value)
+    }
+
+    /// Reads `buf.len()` bytes, which must be available.
+    fn read_unchecked(&mut self, buf: &mut [u8]) {
+        let header = self.0.header();
+        let read = header.read.load(Ordering::Relaxed);
+
+        // SAFETY: The bytes are available, so the producer doesn't access them.
+        unsafe { self.0.copy_out(read, buf) };
+        header
+            .read
+            .store(read.wrapping_add(buf.len() as u32), Ordering::Release);
+    }
+}
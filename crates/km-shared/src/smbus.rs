@@ -0,0 +1,450 @@
+//! SMBus transactions, and their IOCTL encoding for talking to an SMBus through another driver.
+//!
+//! A transaction is described by an [`Operation`] sent to a 7-bit slave [`Address`]. Drivers
+//! owning an SMBus host controller can expose it to other drivers (or user mode) through
+//! [`ioctl_smbus_execute`], whose payloads are [`SmBusRequest`] and [`SmBusResponse`]:
+//!
+//! ```rs, ignore
+//! // client
+//! let mut temperature = 0;
+//! let mut op = Operation::ReadByteData { command: 0x25, data: &mut temperature };
+//! let request = SmBusRequest::new(SENSOR_ADDRESS, &op)?;
+//! let response = send(IOCTL_SMBUS_EXECUTE, &request)?;
+//! response.complete(&mut op)?;
+//!
+//! // bus driver
+//! let response = request.execute(|address, op| host.execute(address, op))?;
+//! ```
+
+use crate::{
+    ioctl::{DeviceType, IoControlCode, IoCtlAccess, IoCtlTransferType, TypedIoControlCode},
+    ntstatus::NtStatusError,
+    utils::ArrayVec,
+};
+use snafu::Snafu;
+
+/// The maximum length of the data of a block transfer.
+pub const BLOCK_MAX: usize = 32;
+
+/// The data of a block transfer.
+pub type Block = ArrayVec<u8, BLOCK_MAX>;
+
+/// A 7-bit SMBus slave address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Address(u8);
+
+impl Address {
+    /// Creates a 7-bit slave address (without the R/W bit).
+    ///
+    /// This function panics if `address` doesn't fit into 7 bits. Note that this is a `const fn`,
+    /// so this panic happens at compile time if used to define constants.
+    pub const fn new(address: u8) -> Self {
+        assert!(address <= 0x7F, "SMBus addresses have only 7 bits");
+
+        Self(address)
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// The address byte sent on the bus, i.e. the address followed by the R/W bit.
+    pub const fn with_direction(self, direction: Direction) -> u8 {
+        (self.0 << 1) | direction as u8
+    }
+}
+
+/// The direction of a transaction, as encoded in the R/W bit of the address byte.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Write = 0,
+    Read = 1,
+}
+
+/// The SMBus protocol of a transaction.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Quick = 0,
+    Byte = 1,
+    ByteData = 2,
+    WordData = 3,
+    BlockData = 4,
+}
+
+impl Protocol {
+    const fn from_raw(raw: u8) -> Option<Self> {
+        Some(match raw {
+            0 => Protocol::Quick,
+            1 => Protocol::Byte,
+            2 => Protocol::ByteData,
+            3 => Protocol::WordData,
+            4 => Protocol::BlockData,
+            _ => return None,
+        })
+    }
+}
+
+/// An SMBus transaction. Read operations borrow the buffer the data is read into.
+#[derive(Debug)]
+pub enum Operation<'a> {
+    /// Sends only the address byte, with the R/W bit as the single bit of data.
+    Quick(Direction),
+    /// Receives a byte without a command.
+    ReceiveByte(&'a mut u8),
+    /// Sends a byte without a command.
+    SendByte(u8),
+    ReadByteData {
+        command: u8,
+        data: &'a mut u8,
+    },
+    WriteByteData {
+        command: u8,
+        data: u8,
+    },
+    ReadWordData {
+        command: u8,
+        data: &'a mut u16,
+    },
+    WriteWordData {
+        command: u8,
+        data: u16,
+    },
+    /// Reads a block, whose length is sent by the device.
+    ReadBlockData {
+        command: u8,
+        data: &'a mut Block,
+    },
+    /// Writes a block of up to [`BLOCK_MAX`] bytes.
+    WriteBlockData {
+        command: u8,
+        data: &'a [u8],
+    },
+}
+
+impl Operation<'_> {
+    pub fn direction(&self) -> Direction {
+        match self {
+            Operation::Quick(direction) => *direction,
+            Operation::ReceiveByte(_)
+            | Operation::ReadByteData { .. }
+            | Operation::ReadWordData { .. }
+            | Operation::ReadBlockData { .. } => Direction::Read,
+            Operation::SendByte(_)
+            | Operation::WriteByteData { .. }
+            | Operation::WriteWordData { .. }
+            | Operation::WriteBlockData { .. } => Direction::Write,
+        }
+    }
+
+    pub fn protocol(&self) -> Protocol {
+        match self {
+            Operation::Quick(_) => Protocol::Quick,
+            Operation::ReceiveByte(_) | Operation::SendByte(_) => Protocol::Byte,
+            Operation::ReadByteData { .. } | Operation::WriteByteData { .. } => Protocol::ByteData,
+            Operation::ReadWordData { .. } | Operation::WriteWordData { .. } => Protocol::WordData,
+            Operation::ReadBlockData { .. } | Operation::WriteBlockData { .. } => {
+                Protocol::BlockData
+            }
+        }
+    }
+
+    /// The command byte, if the protocol has one. [`Protocol::Byte`] sends the data in its place.
+    pub fn command(&self) -> Option<u8> {
+        match *self {
+            Operation::Quick(_) | Operation::ReceiveByte(_) | Operation::SendByte(_) => None,
+            Operation::ReadByteData { command, .. }
+            | Operation::WriteByteData { command, .. }
+            | Operation::ReadWordData { command, .. }
+            | Operation::WriteWordData { command, .. }
+            | Operation::ReadBlockData { command, .. }
+            | Operation::WriteBlockData { command, .. } => Some(command),
+        }
+    }
+}
+
+/// An error of an SMBus transaction.
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+#[snafu(module)]
+pub enum SmBusError {
+    /// The device didn't acknowledge its address or data, e.g. because there is no device at the
+    /// address.
+    #[snafu(display("the device didn't acknowledge the transaction"))]
+    DeviceError,
+    /// Another master won the arbitration of the bus.
+    #[snafu(display("bus collision"))]
+    BusCollision,
+    /// The host controller failed the transaction, e.g. because it was killed.
+    #[snafu(display("the host controller failed the transaction"))]
+    Failed,
+    #[snafu(display("the transaction timed out"))]
+    Timeout,
+    /// The host controller is in use, e.g. by the firmware.
+    #[snafu(display("the host controller is busy"))]
+    Busy,
+    #[snafu(display("block length {len} is out of range"))]
+    InvalidBlockLength { len: usize },
+    /// The request or response doesn't describe a valid transaction.
+    #[snafu(display("invalid SMBus request or response"))]
+    Invalid,
+    /// The bus doesn't support the protocol.
+    #[snafu(display("the protocol isn't supported"))]
+    Unsupported,
+    /// Sending the transaction to another driver failed.
+    #[snafu(display("sending the transaction failed"))]
+    NtStatus { source: NtStatusError },
+}
+
+impl From<SmBusError> for NtStatusError {
+    fn from(error: SmBusError) -> Self {
+        match error {
+            SmBusError::DeviceError => NtStatusError::STATUS_NO_SUCH_DEVICE,
+            SmBusError::BusCollision | SmBusError::Failed => NtStatusError::STATUS_IO_DEVICE_ERROR,
+            SmBusError::Timeout => NtStatusError::STATUS_IO_TIMEOUT,
+            SmBusError::Busy => NtStatusError::STATUS_DEVICE_NOT_READY,
+            SmBusError::InvalidBlockLength { .. } | SmBusError::Invalid => {
+                NtStatusError::STATUS_INVALID_PARAMETER
+            }
+            SmBusError::Unsupported => NtStatusError::STATUS_NOT_SUPPORTED,
+            SmBusError::NtStatus { source } => source,
+        }
+    }
+}
+
+impl From<NtStatusError> for SmBusError {
+    /// Maps the statuses of the `From<SmBusError>` impl of [`NtStatusError`] back, so that
+    /// clients of a bus driver see the same errors as the bus driver.
+    fn from(status: NtStatusError) -> Self {
+        const MAPPED: [(NtStatusError, SmBusError); 5] = [
+            (
+                NtStatusError::STATUS_NO_SUCH_DEVICE,
+                SmBusError::DeviceError,
+            ),
+            (NtStatusError::STATUS_IO_DEVICE_ERROR, SmBusError::Failed),
+            (NtStatusError::STATUS_IO_TIMEOUT, SmBusError::Timeout),
+            (NtStatusError::STATUS_DEVICE_NOT_READY, SmBusError::Busy),
+            (NtStatusError::STATUS_NOT_SUPPORTED, SmBusError::Unsupported),
+        ];
+
+        MAPPED
+            .iter()
+            .find(|(mapped, _)| *mapped == status)
+            .map_or(SmBusError::NtStatus { source: status }, |(_, error)| *error)
+    }
+}
+
+/// The function code reserved for [`ioctl_smbus_execute`], right below
+/// [`INTERFACE_VERSION_FUNCTION`](crate::ioctl::INTERFACE_VERSION_FUNCTION).
+pub const SMBUS_EXECUTE_FUNCTION: u16 = 0xFFE;
+
+/// The `IOCTL_SMBUS_EXECUTE` code for a bus driver using `device_type` for its codes. Executes
+/// the transaction of an [`SmBusRequest`], and returns an [`SmBusResponse`].
+pub const fn ioctl_smbus_execute(
+    device_type: DeviceType,
+) -> TypedIoControlCode<SmBusRequest, SmBusResponse> {
+    TypedIoControlCode::new(IoControlCode::new_custom(
+        device_type,
+        SMBUS_EXECUTE_FUNCTION,
+        IoCtlTransferType::Buffered,
+        IoCtlAccess::READ_DATA.union(IoCtlAccess::WRITE_DATA),
+    ))
+}
+
+/// The input of [`ioctl_smbus_execute`], describing an [`Operation`] without borrowing its
+/// buffers.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmBusRequest {
+    /// The 7-bit slave address.
+    pub address: u8,
+    /// A [`Direction`].
+    pub direction: u8,
+    /// A [`Protocol`].
+    pub protocol: u8,
+    pub command: u8,
+    /// The number of bytes of `data` to write.
+    pub len: u8,
+    pub reserved: [u8; 3],
+    /// The data to write. Words are little endian.
+    pub data: [u8; BLOCK_MAX],
+}
+
+// SAFETY: `SmBusRequest` is `repr(C)`, consists only of bytes, and any bit pattern is valid for
+// it.
+unsafe impl bytemuck::Zeroable for SmBusRequest {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SmBusRequest {}
+// SAFETY: `SmBusRequest` is `repr(C)`, and consists only of bytes.
+unsafe impl crate::ioctl::FixedLayout for SmBusRequest {}
+
+/// The output of [`ioctl_smbus_execute`], holding the data read by the transaction.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmBusResponse {
+    /// The number of bytes of `data` that were read.
+    pub len: u8,
+    pub reserved: [u8; 3],
+    /// The data read. Words are little endian.
+    pub data: [u8; BLOCK_MAX],
+}
+
+// SAFETY: `SmBusResponse` is `repr(C)`, consists only of bytes, and any bit pattern is valid for
+// it.
+unsafe impl bytemuck::Zeroable for SmBusResponse {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for SmBusResponse {}
+// SAFETY: `SmBusResponse` is `repr(C)`, and consists only of bytes.
+unsafe impl crate::ioctl::FixedLayout for SmBusResponse {}
+
+crate::assert_ioctl_abi!(
+    ioctl_smbus_execute(DeviceType::FIRST_CUSTOM),
+    input: { size: 40, align: 1 },
+    output: { size: 36, align: 1 },
+);
+crate::assert_ioctl_fixed_layout!(ioctl_smbus_execute(DeviceType::FIRST_CUSTOM));
+
+impl SmBusRequest {
+    /// Describes `operation` on the device at `address`.
+    pub fn new(address: Address, operation: &Operation<'_>) -> Result<Self, SmBusError> {
+        let mut request = SmBusRequest {
+            address: address.get(),
+            direction: operation.direction() as u8,
+            protocol: operation.protocol() as u8,
+            command: operation.command().unwrap_or(0),
+            len: 0,
+            reserved: [0; 3],
+            data: [0; BLOCK_MAX],
+        };
+
+        let word;
+        let data: &[u8] = match operation {
+            Operation::SendByte(data) | Operation::WriteByteData { data, .. } => {
+                core::slice::from_ref(data)
+            }
+            Operation::WriteWordData { data, .. } => {
+                word = data.to_le_bytes();
+                &word
+            }
+            Operation::WriteBlockData { data, .. } => data,
+            _ => &[],
+        };
+        if data.len() > BLOCK_MAX {
+            return Err(SmBusError::InvalidBlockLength { len: data.len() });
+        }
+        request.data[..data.len()].copy_from_slice(data);
+        request.len = data.len() as u8;
+
+        Ok(request)
+    }
+
+    /// Executes the described transaction with `f`, returning the data read.
+    ///
+    /// This is meant for bus drivers serving [`ioctl_smbus_execute`], which pass the transaction
+    /// on to their host controller. The request is validated first, as it comes from another
+    /// driver or user mode.
+    pub fn execute(
+        &self,
+        f: impl FnOnce(Address, Operation<'_>) -> Result<(), SmBusError>,
+    ) -> Result<SmBusResponse, SmBusError> {
+        let invalid = || SmBusError::Invalid;
+        if self.address > 0x7F {
+            return Err(invalid());
+        }
+        let address = Address::new(self.address);
+        let protocol = Protocol::from_raw(self.protocol).ok_or_else(invalid)?;
+        let direction = match self.direction {
+            0 => Direction::Write,
+            1 => Direction::Read,
+            _ => return Err(invalid()),
+        };
+        let (command, len) = (self.command, self.len as usize);
+        let data = self
+            .data
+            .get(..len)
+            .ok_or(SmBusError::InvalidBlockLength { len })?;
+
+        let mut response = SmBusResponse {
+            len: 0,
+            reserved: [0; 3],
+            data: [0; BLOCK_MAX],
+        };
+        let (mut byte, mut word, mut block) = (0, 0, Block::new());
+
+        let operation = match (protocol, direction) {
+            (Protocol::Quick, direction) => Operation::Quick(direction),
+            (Protocol::Byte, Direction::Read) => Operation::ReceiveByte(&mut byte),
+            (Protocol::Byte, Direction::Write) => Operation::SendByte(single(data)?),
+            (Protocol::ByteData, Direction::Read) => Operation::ReadByteData {
+                command,
+                data: &mut byte,
+            },
+            (Protocol::ByteData, Direction::Write) => Operation::WriteByteData {
+                command,
+                data: single(data)?,
+            },
+            (Protocol::WordData, Direction::Read) => Operation::ReadWordData {
+                command,
+                data: &mut word,
+            },
+            (Protocol::WordData, Direction::Write) => Operation::WriteWordData {
+                command,
+                data: u16::from_le_bytes(data.try_into().map_err(|_| invalid())?),
+            },
+            (Protocol::BlockData, Direction::Read) => Operation::ReadBlockData {
+                command,
+                data: &mut block,
+            },
+            (Protocol::BlockData, Direction::Write) => Operation::WriteBlockData { command, data },
+        };
+        f(address, operation)?;
+
+        let word = word.to_le_bytes();
+        let read: &[u8] = match (protocol, direction) {
+            (Protocol::Byte | Protocol::ByteData, Direction::Read) => core::slice::from_ref(&byte),
+            (Protocol::WordData, Direction::Read) => &word,
+            (Protocol::BlockData, Direction::Read) => &block,
+            _ => &[],
+        };
+        response.data[..read.len()].copy_from_slice(read);
+        response.len = read.len() as u8;
+
+        Ok(response)
+    }
+}
+
+/// Returns the only byte of `data`.
+fn single(data: &[u8]) -> Result<u8, SmBusError> {
+    match data {
+        [byte] => Ok(*byte),
+        _ => Err(SmBusError::Invalid),
+    }
+}
+
+impl SmBusResponse {
+    /// Copies the data read into the buffers of `operation`, which has to be the operation the
+    /// request was [created](SmBusRequest::new) from.
+    pub fn complete(&self, operation: &mut Operation<'_>) -> Result<(), SmBusError> {
+        let data = self
+            .data
+            .get(..self.len as usize)
+            .ok_or(SmBusError::Invalid)?;
+
+        match operation {
+            Operation::ReceiveByte(byte) | Operation::ReadByteData { data: byte, .. } => {
+                **byte = single(data)?;
+            }
+            Operation::ReadWordData { data: word, .. } => {
+                **word = u16::from_le_bytes(data.try_into().map_err(|_| SmBusError::Invalid)?);
+            }
+            Operation::ReadBlockData { data: block, .. } => {
+                block.clear();
+                // Can't fail, as `data` has at most `BLOCK_MAX` bytes.
+                let _ = block.extend_from_slice(data);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
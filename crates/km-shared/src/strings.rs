@@ -1,8 +1,10 @@
-use core::mem::size_of;
-use km_sys::{UNICODE_STRING, WCHAR};
+use crate::sys::{UNICODE_STRING, WCHAR};
+use core::{mem::size_of, slice};
 
 pub use wchar;
 
+pub mod path;
+
 pub type UnicodeString = UNICODE_STRING;
 
 pub const fn make_const_unicode_string<const N: usize>(s: &'static [WCHAR; N]) -> UnicodeString {
@@ -21,3 +23,92 @@ pub const fn make_const_unicode_string<const N: usize>(s: &'static [WCHAR; N]) -
         Length: (len_bytes - size_of::<WCHAR>()) as u16,
     }
 }
+
+/// Declares a [`UnicodeString`] from a string literal, e.g. a device or object name, validated at
+/// compile time.
+///
+/// The string must not be empty, must fit into a `UNICODE_STRING`, and must not contain control
+/// characters such as NUL. Strings starting with a backslash are object names, whose components
+/// must not be empty, so they can't contain two backslashes in a row or end with one.
+///
+/// Example:
+/// ```rs, ignore
+/// const DEVICE_NAME: UnicodeString = unicode_string!("\\Device\\MyDevice");
+/// // fails to compile
+/// const TYPO: UnicodeString = unicode_string!("\\Device\\\\MyDevice");
+/// ```
+#[macro_export]
+macro_rules! unicode_string {
+    ($s:literal) => {{
+        const _: () = $crate::strings::validate_unicode_string($s);
+        const STRING: $crate::strings::UnicodeString =
+            $crate::strings::make_const_unicode_string($crate::wchz!($s));
+        STRING
+    }};
+}
+
+/// Not to be used directly. Used by [`unicode_string!`] to validate the literal at compile time.
+#[doc(hidden)]
+pub const fn validate_unicode_string(s: &str) {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        panic!("`unicode_string!` literals must not be empty");
+    }
+
+    // the root directory `\` on its own is a valid object name
+    let is_object_name = bytes[0] == b'\\' && bytes.len() > 1;
+
+    let mut len_units = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b < 0x20 || b == 0x7F {
+            panic!("`unicode_string!` literals must not contain control characters");
+        }
+
+        if is_object_name && b == b'\\' && (i + 1 == bytes.len() || bytes[i + 1] == b'\\') {
+            panic!("object names must not contain empty components");
+        }
+
+        // a UTF-16 code unit for every character, two for those outside of the BMP
+        match b {
+            0x80..=0xBF => {}
+            0xF0..=0xFF => len_units += 2,
+            _ => len_units += 1,
+        }
+        i += 1;
+    }
+
+    if (len_units + 1) * size_of::<WCHAR>() > u16::MAX as usize {
+        panic!("`UNICODE_STRING`s only support a maximum length of `u16::MAX` bytes");
+    }
+}
+
+/// The characters of a NUL-terminated `PCWSTR`, without the NUL.
+///
+/// # Safety
+/// `s` must point to a NUL-terminated string that's valid for `'a`.
+pub unsafe fn pcwstr_as_wide<'a>(s: *const WCHAR) -> &'a [WCHAR] {
+    let mut len = 0;
+    // SAFETY: The string is NUL-terminated, so every character up to the NUL can be read.
+    while unsafe { s.add(len).read() } != 0 {
+        len += 1;
+    }
+    // SAFETY: The `len` characters before the NUL were just read, and are valid for `'a`.
+    unsafe { slice::from_raw_parts(s, len) }
+}
+
+/// The characters of a [`UnicodeString`], i.e. `Length` bytes of its buffer.
+///
+/// # Safety
+/// The buffer of `s` must hold at least `Length` bytes, unless `Length` is zero, and be valid for
+/// as long as `s` is borrowed.
+pub unsafe fn unicode_string_as_wide(s: &UnicodeString) -> &[WCHAR] {
+    let len = s.Length as usize / size_of::<WCHAR>();
+    if len == 0 {
+        return &[];
+    }
+    // SAFETY: The buffer holds `Length` bytes, which are valid while `s` is borrowed, as
+    // guaranteed by the caller.
+    unsafe { slice::from_raw_parts(s.Buffer, len) }
+}
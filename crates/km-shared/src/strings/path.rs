@@ -0,0 +1,210 @@
+//! Conversion between DOS paths, as used in user mode, and NT paths, as used by the kernel's file
+//! and registry functions, in UTF-16.
+//!
+//! Paths sent by user mode clients, e.g. in an IOCTL, are usually DOS paths like `C:\Data\x.bin`,
+//! which have to be converted before they can be opened in the kernel:
+//!
+//! ```rs, ignore
+//! let mut buffer = [0; MAX_PATH_LEN];
+//! let len = dos_to_nt(trim_nul(&input.path), &mut buffer)?;
+//! let len = join(&mut buffer, len, wch!("config.bin"))?;
+//! // `\??\C:\Data\config.bin`
+//! let path = &buffer[..len];
+//! ```
+//!
+//! The conversions are purely textual, so only paths that have an NT equivalent without looking
+//! up a volume or the current directory are supported. See [MSDN] for the path formats.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/dotnet/standard/io/file-path-formats
+
+use crate::sys::WCHAR;
+use snafu::{ensure, OptionExt, Snafu};
+
+/// The prefix of NT paths in the DOS devices directory, `\??\`.
+pub const NT_PREFIX: &[WCHAR] = &wide(b"\\??\\");
+
+/// Prefixes of NT paths that are equivalent to [`NT_PREFIX`].
+const NT_PREFIX_ALIASES: [&[WCHAR]; 2] = [&wide(b"\\DosDevices\\"), &wide(b"\\GLOBAL??\\")];
+
+/// The prefix of Win32 device namespace paths, `\\.\`.
+const DEVICE_NAMESPACE_PREFIX: &[WCHAR] = &wide(b"\\\\.\\");
+/// The prefix of Win32 paths that skip normalization, `\\?\`.
+const VERBATIM_PREFIX: &[WCHAR] = &wide(b"\\\\?\\");
+/// The prefix of UNC paths, `\\`.
+const UNC_PREFIX: &[WCHAR] = &wide(b"\\\\");
+/// The directory of UNC paths in the DOS devices directory, `UNC\`.
+const UNC_DIRECTORY: &[WCHAR] = &wide(b"UNC\\");
+
+const SEPARATOR: WCHAR = b'\\' as WCHAR;
+
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+#[snafu(module)]
+pub enum PathError {
+    /// The path is relative to the current directory or drive, which the kernel doesn't have.
+    #[snafu(display("path is relative"))]
+    Relative,
+    /// The NT path isn't in the DOS devices directory, e.g. `\Device\HarddiskVolume1\x`, and has
+    /// no DOS equivalent without looking up the volume.
+    #[snafu(display("NT path has no DOS equivalent"))]
+    NotDosPath,
+    /// A joined component is empty, absolute, or refers to a parent or the current directory.
+    #[snafu(display("invalid path component"))]
+    InvalidComponent,
+    /// The output buffer can't hold the path of `required` `WCHAR`s.
+    #[snafu(display("path of {required} characters doesn't fit into the buffer"))]
+    BufferTooSmall { required: usize },
+}
+
+/// Converts a DOS path to an NT path in the DOS devices directory, writing it to `out` and
+/// returning its length:
+/// - `C:\Data` becomes `\??\C:\Data`.
+/// - `\\server\share\Data` becomes `\??\UNC\server\share\Data`.
+/// - `\\?\C:\Data` and `\\.\COM1` become `\??\C:\Data` and `\??\COM1`.
+///
+/// Paths relative to the current directory or drive, like `Data`, `\Data` or `C:Data`, fail with
+/// [`PathError::Relative`].
+pub fn dos_to_nt(dos: &[WCHAR], out: &mut [WCHAR]) -> Result<usize, PathError> {
+    let (directory, rest): (&[WCHAR], _) = if let Some(rest) = dos
+        .strip_prefix(VERBATIM_PREFIX)
+        .or_else(|| dos.strip_prefix(DEVICE_NAMESPACE_PREFIX))
+    {
+        (&[], rest)
+    } else if let Some(rest) = dos.strip_prefix(UNC_PREFIX) {
+        (UNC_DIRECTORY, rest)
+    } else if is_drive_absolute(dos) {
+        (&[], dos)
+    } else {
+        return path_error::RelativeSnafu.fail();
+    };
+
+    let mut writer = Writer::new(out);
+    writer.push(NT_PREFIX);
+    writer.push(directory);
+    writer.push(rest);
+    writer.finish()
+}
+
+/// Converts an NT path in the DOS devices directory to a DOS path, writing it to `out` and
+/// returning its length. The reverse of [`dos_to_nt`], which also accepts the `\DosDevices\` and
+/// `\GLOBAL??\` aliases of `\??\`.
+///
+/// Other NT paths, e.g. `\Device\HarddiskVolume1\Data`, fail with [`PathError::NotDosPath`].
+pub fn nt_to_dos(nt: &[WCHAR], out: &mut [WCHAR]) -> Result<usize, PathError> {
+    let rest = strip_prefix_ignore_ascii_case(nt, NT_PREFIX)
+        .or_else(|| {
+            NT_PREFIX_ALIASES
+                .iter()
+                .find_map(|prefix| strip_prefix_ignore_ascii_case(nt, prefix))
+        })
+        .context(path_error::NotDosPathSnafu)?;
+
+    let mut writer = Writer::new(out);
+    if let Some(unc) = strip_prefix_ignore_ascii_case(rest, UNC_DIRECTORY) {
+        writer.push(UNC_PREFIX);
+        writer.push(unc);
+    } else if is_drive_absolute(rest) {
+        writer.push(rest);
+    } else {
+        // e.g. a device like `\??\COM1`
+        writer.push(DEVICE_NAMESPACE_PREFIX);
+        writer.push(rest);
+    }
+    writer.finish()
+}
+
+/// Appends `component` to the path of `len` `WCHAR`s at the start of `buffer`, separated by a
+/// single backslash, returning the new length.
+///
+/// The component may consist of several components separated by backslashes, but none of them
+/// may be empty, `.` or `..`, so that joining can't leave the directory of the path.
+pub fn join(buffer: &mut [WCHAR], len: usize, component: &[WCHAR]) -> Result<usize, PathError> {
+    ensure!(
+        !component.is_empty()
+            && component.split(|&c| c == SEPARATOR).all(|part| {
+                !part.is_empty() && part != [b'.' as WCHAR] && part != [b'.' as WCHAR; 2]
+            }),
+        path_error::InvalidComponentSnafu
+    );
+
+    ensure!(
+        len <= buffer.len(),
+        path_error::BufferTooSmallSnafu { required: len }
+    );
+
+    let needs_separator = len > 0 && buffer[len - 1] != SEPARATOR;
+    let required = len + needs_separator as usize + component.len();
+    ensure!(
+        required <= buffer.len(),
+        path_error::BufferTooSmallSnafu { required }
+    );
+
+    if needs_separator {
+        buffer[len] = SEPARATOR;
+    }
+    buffer[required - component.len()..required].copy_from_slice(component);
+    Ok(required)
+}
+
+/// The part of `s` before the first NUL, e.g. of a fixed-size path buffer in an IOCTL.
+pub fn trim_nul(s: &[WCHAR]) -> &[WCHAR] {
+    let len = s.iter().position(|&c| c == 0).unwrap_or(s.len());
+    &s[..len]
+}
+
+/// Whether `path` starts with a drive letter followed by `:\`.
+fn is_drive_absolute(path: &[WCHAR]) -> bool {
+    matches!(
+        path,
+        [letter, colon, separator, ..]
+            if *letter < 0x80
+                && (*letter as u8).is_ascii_alphabetic()
+                && *colon == b':' as WCHAR
+                && *separator == SEPARATOR
+    )
+}
+
+fn strip_prefix_ignore_ascii_case<'a>(s: &'a [WCHAR], prefix: &[WCHAR]) -> Option<&'a [WCHAR]> {
+    let (head, rest) = s.split_at_checked(prefix.len())?;
+    let equal = head.iter().zip(prefix).all(|(&a, &b)| {
+        a == b || (a < 0x80 && b < 0x80 && (a as u8).eq_ignore_ascii_case(&(b as u8)))
+    });
+    equal.then_some(rest)
+}
+
+/// Writes a path to a buffer, remembering the length it would have had if it didn't fit.
+struct Writer<'a> {
+    out: &'a mut [WCHAR],
+    len: usize,
+}
+
+impl<'a> Writer<'a> {
+    fn new(out: &'a mut [WCHAR]) -> Self {
+        Self { out, len: 0 }
+    }
+
+    fn push(&mut self, s: &[WCHAR]) {
+        if let Some(dest) = self.out.get_mut(self.len..self.len + s.len()) {
+            dest.copy_from_slice(s);
+        }
+        self.len += s.len();
+    }
+
+    fn finish(self) -> Result<usize, PathError> {
+        ensure!(
+            self.len <= self.out.len(),
+            path_error::BufferTooSmallSnafu { required: self.len }
+        );
+        Ok(self.len)
+    }
+}
+
+/// Widens an ASCII string at compile time.
+const fn wide<const N: usize>(s: &[u8; N]) -> [WCHAR; N] {
+    let mut wide = [0; N];
+    let mut i = 0;
+    while i < N {
+        wide[i] = s[i] as WCHAR;
+        i += 1;
+    }
+    wide
+}
@@ -0,0 +1,50 @@
+//! The kernel definitions used by the shared code, from `km-sys` if the `km-sys` feature is
+//! enabled, or defined here with the same layout otherwise.
+//!
+//! The bindings only build for Windows, where `ULONG` is 32 bits, so without them, e.g. to build
+//! the [fuzzing](crate::fuzz) targets on a Linux host, the few definitions needed by the parsing
+//! code are provided here.
+
+#![allow(non_camel_case_types)]
+
+#[cfg(feature = "km-sys")]
+pub(crate) use km_sys::{
+    FILE_ANY_ACCESS, FILE_READ_DATA, FILE_WRITE_DATA, GUID, METHOD_BUFFERED, METHOD_IN_DIRECT,
+    METHOD_NEITHER, METHOD_OUT_DIRECT, NTSTATUS, ULONG, UNICODE_STRING, WCHAR,
+};
+
+#[cfg(not(feature = "km-sys"))]
+pub(crate) use self::host::*;
+
+#[cfg(not(feature = "km-sys"))]
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+mod host {
+    pub type ULONG = u32;
+    pub type WCHAR = u16;
+    pub type NTSTATUS = i32;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct GUID {
+        pub Data1: u32,
+        pub Data2: u16,
+        pub Data3: u16,
+        pub Data4: [u8; 8],
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct UNICODE_STRING {
+        pub Length: u16,
+        pub MaximumLength: u16,
+        pub Buffer: *mut WCHAR,
+    }
+
+    pub const METHOD_BUFFERED: u32 = 0;
+    pub const METHOD_IN_DIRECT: u32 = 1;
+    pub const METHOD_OUT_DIRECT: u32 = 2;
+    pub const METHOD_NEITHER: u32 = 3;
+    pub const FILE_ANY_ACCESS: u32 = 0;
+    pub const FILE_READ_DATA: u32 = 1;
+    pub const FILE_WRITE_DATA: u32 = 2;
+}
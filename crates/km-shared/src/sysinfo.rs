@@ -0,0 +1,140 @@
+//! System information that monitoring IOCTLs pass from the driver to user mode, e.g. the
+//! [`CpuTimes`] of each processor and the [`MemoryInfo`] of the system.
+//!
+//! The driver queries [`CpuTimes`] with `km::sysinfo::cpu_times`, and returns them as is. The
+//! utilization of a processor is then computed from two samples:
+//!
+//! ```rs, ignore
+//! let before: [CpuTimes; 64] = device.ioctl(IOCTL_CPU_TIMES, &0u16)?;
+//! sleep(Duration::from_secs(1));
+//! let after: [CpuTimes; 64] = device.ioctl(IOCTL_CPU_TIMES, &0u16)?;
+//!
+//! for (before, after) in before.iter().zip(&after) {
+//!     let permille = after.utilization_since(before);
+//!     println!("{}.{}%", permille / 10, permille % 10);
+//! }
+//! ```
+
+use crate::ioctl::FixedLayout;
+use bytemuck::{Pod, Zeroable};
+
+/// The time a processor spent in each state since boot, in units of 100ns.
+///
+/// This has the layout of `SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION`, so the kernel fills it in
+/// directly.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CpuTimes {
+    /// The time the processor was idle.
+    pub idle: u64,
+    /// The time the processor ran in kernel mode, including the idle time.
+    pub kernel: u64,
+    /// The time the processor ran in user mode.
+    pub user: u64,
+    /// The time the processor spent in DPCs, which is part of the kernel time.
+    pub dpc: u64,
+    /// The time the processor spent in interrupt service routines, which is part of the kernel
+    /// time.
+    pub interrupt: u64,
+    /// The number of interrupts the processor serviced.
+    pub interrupt_count: u32,
+    pub reserved: u32,
+}
+
+// SAFETY: `CpuTimes` is `repr(C)`, consists of five `u64`s followed by two `u32`s without padding,
+// and any bit pattern is valid for it.
+unsafe impl Zeroable for CpuTimes {}
+// SAFETY: See above.
+unsafe impl Pod for CpuTimes {}
+// SAFETY: `CpuTimes` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for CpuTimes {}
+
+impl CpuTimes {
+    /// The time the processor wasn't idle.
+    pub const fn busy(&self) -> u64 {
+        (self.kernel.saturating_sub(self.idle)).saturating_add(self.user)
+    }
+
+    /// The time the processor ran in kernel or user mode, whether idle or not.
+    pub const fn total(&self) -> u64 {
+        self.kernel.saturating_add(self.user)
+    }
+
+    /// The share of the time since `earlier` that the processor wasn't idle, in tenths of a
+    /// percent (0 to 1000). Returns 0 if no time passed.
+    pub const fn utilization_since(&self, earlier: &Self) -> u32 {
+        let total = self.total().saturating_sub(earlier.total());
+        if total == 0 {
+            return 0;
+        }
+
+        let busy = self.busy().saturating_sub(earlier.busy());
+        let permille = busy as u128 * 1000 / total as u128;
+        if permille > 1000 {
+            1000
+        } else {
+            permille as u32
+        }
+    }
+}
+
+/// The memory usage of the system, including the pools, with sizes in pages of
+/// [`Self::page_size`] bytes.
+///
+/// Unlike the kernel's structures it's queried from, the layout is the same in 32-bit and 64-bit
+/// processes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MemoryInfo {
+    /// The size of a page in bytes.
+    pub page_size: u32,
+    pub reserved: u32,
+    /// The physical memory managed by the memory manager.
+    pub physical_pages: u64,
+    /// The physical memory that is free, zeroed or on the standby list.
+    pub available_pages: u64,
+    /// The virtual memory committed by all processes and the system.
+    pub committed_pages: u64,
+    /// The virtual memory that can be committed without growing the page files.
+    pub commit_limit: u64,
+    /// The most virtual memory that was committed at once since boot.
+    pub peak_commitment: u64,
+    pub paged_pool_pages: u64,
+    pub non_paged_pool_pages: u64,
+    /// The allocations from the paged pool since boot.
+    pub paged_pool_allocs: u32,
+    /// The frees to the paged pool since boot.
+    pub paged_pool_frees: u32,
+    /// The allocations from the non-paged pool since boot.
+    pub non_paged_pool_allocs: u32,
+    /// The frees to the non-paged pool since boot.
+    pub non_paged_pool_frees: u32,
+}
+
+// SAFETY: `MemoryInfo` is `repr(C)`, consists of two `u32`s, seven `u64`s and four `u32`s without
+// padding, and any bit pattern is valid for it.
+unsafe impl Zeroable for MemoryInfo {}
+// SAFETY: See above.
+unsafe impl Pod for MemoryInfo {}
+// SAFETY: `MemoryInfo` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for MemoryInfo {}
+
+impl MemoryInfo {
+    /// The size of `pages` pages in bytes.
+    pub const fn bytes(&self, pages: u64) -> u64 {
+        pages.saturating_mul(self.page_size as u64)
+    }
+
+    /// The paged pool allocations that weren't freed yet. The counters wrap around, so this is
+    /// only meaningful as a trend, e.g. to spot a leak.
+    pub const fn paged_pool_outstanding(&self) -> u32 {
+        self.paged_pool_allocs.wrapping_sub(self.paged_pool_frees)
+    }
+
+    /// The non-paged pool allocations that weren't freed yet, see
+    /// [`Self::paged_pool_outstanding`].
+    pub const fn non_paged_pool_outstanding(&self) -> u32 {
+        self.non_paged_pool_allocs
+            .wrapping_sub(self.non_paged_pool_frees)
+    }
+}
@@ -0,0 +1,275 @@
+//! A chunked transfer protocol for downloading large blobs (e.g. firmware images) to a driver over
+//! IOCTLs, shared by the driver and its user mode updater.
+//!
+//! A transfer is started with [`TransferIoctls::begin`], which announces the total length and the
+//! CRC of the whole blob. The blob is then sent in order with [`TransferIoctls::chunk`], and
+//! finished with [`TransferIoctls::end`], which makes the driver check the CRC of the whole blob.
+//! Every chunk carries a sequence number, its offset, and a CRC of its own, so corrupted, repeated
+//! or reordered chunks are rejected instead of being written into the blob.
+//!
+//! The codes return the [`TransferStatus`] of the driver, which tells the client where to continue.
+//! Failed codes return no output, so after an error (or after the updater was restarted), the
+//! client sends `begin` again with the same transfer ID, length, and CRC. This resumes the
+//! transfer where the driver left off instead of starting over. [`TransferSender`] implements the
+//! client side:
+//!
+//! ```rs, ignore
+//! const IOCTLS: TransferIoctls = transfer_ioctls(DeviceType::custom(0x8000), 0x900);
+//!
+//! let sender = TransferSender::new(transfer_id, &image, 64 * 1024);
+//! let mut status = device.ioctl(IOCTLS.begin, &sender.begin())?;
+//! while let Some(len) = sender.encode_chunk(&status, &mut buf)? {
+//!     status = match device.ioctl_raw(IOCTLS.chunk, &buf[..len]) {
+//!         Ok(status) => status,
+//!         // resynchronize, e.g. after a chunk was corrupted
+//!         Err(_) => device.ioctl(IOCTLS.begin, &sender.begin())?,
+//!     };
+//! }
+//! device.ioctl(IOCTLS.end, &transfer_id)?;
+//! ```
+//!
+//! The driver side is implemented by `km::transfer`. It fails the codes with these statuses:
+//! - `STATUS_INVALID_BUFFER_SIZE` if the blob doesn't fit into the driver's buffer, or a chunk is
+//!   larger than the driver accepts.
+//! - `STATUS_INVALID_PARAMETER` if the input is too short, or a chunk's data doesn't match its
+//!   length.
+//! - `STATUS_INVALID_DEVICE_STATE` if there's no transfer with the given ID.
+//! - `STATUS_REQUEST_OUT_OF_SEQUENCE` if a chunk isn't the next one the driver expects, or `end` is
+//!   sent before the whole blob was received.
+//! - `STATUS_CRC_ERROR` if a chunk is corrupted, or the whole blob is at `end`. In the latter case,
+//!   the transfer is discarded and has to be started over.
+//!
+//! All CRCs are [`CRC32_ISO_HDLC`], the CRC-32 used by zlib and Ethernet.
+
+use crate::{
+    checksum::CRC32_ISO_HDLC,
+    ioctl::{
+        DeviceType, FixedLayout, IoControlCode, IoCtlAccess, IoCtlTransferType, TypedIoControlCode,
+    },
+    utils::CapacityError,
+};
+use bytemuck::{Pod, Zeroable};
+use core::mem::size_of;
+
+/// The input of [`TransferIoctls::begin`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransferBegin {
+    /// Chosen by the client, e.g. randomly or from a hash of the blob. A `begin` with another ID
+    /// discards the transfer in progress.
+    pub transfer_id: u32,
+    /// The CRC of the whole blob.
+    pub crc32: u32,
+    /// The length of the whole blob in bytes.
+    pub total_len: u64,
+}
+
+// SAFETY: `TransferBegin` is `repr(C)`, consists of two `u32`s followed by a `u64` without
+// padding, and any bit pattern is valid for it.
+unsafe impl Zeroable for TransferBegin {}
+// SAFETY: See above.
+unsafe impl Pod for TransferBegin {}
+// SAFETY: `TransferBegin` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for TransferBegin {}
+
+/// The start of the input of [`TransferIoctls::chunk`], directly followed by `len` bytes of data.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkHeader {
+    pub transfer_id: u32,
+    /// The index of the chunk within the transfer, starting at zero.
+    pub sequence: u32,
+    /// The offset of the data within the blob.
+    pub offset: u64,
+    /// The length of the data following the header.
+    pub len: u32,
+    /// The CRC of the data following the header.
+    pub crc32: u32,
+}
+
+// SAFETY: `ChunkHeader` is `repr(C)`, consists of two `u32`s, a `u64` and two `u32`s without
+// padding, and any bit pattern is valid for it.
+unsafe impl Zeroable for ChunkHeader {}
+// SAFETY: See above.
+unsafe impl Pod for ChunkHeader {}
+// SAFETY: `ChunkHeader` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for ChunkHeader {}
+
+/// The state of a driver's side of a transfer, see [`TransferStatus`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransferState(pub u32);
+
+impl TransferState {
+    /// No transfer was started, or it was aborted or discarded.
+    pub const IDLE: Self = Self(0);
+    /// The transfer was started, and is waiting for chunks or `end`.
+    pub const RECEIVING: Self = Self(1);
+    /// The whole blob was received and its CRC matched.
+    pub const COMPLETE: Self = Self(2);
+}
+
+// SAFETY: `TransferState` is a transparent `u32`, any bit pattern is valid for it.
+unsafe impl Zeroable for TransferState {}
+// SAFETY: See above.
+unsafe impl Pod for TransferState {}
+// SAFETY: `TransferState` is a transparent `u32`.
+unsafe impl FixedLayout for TransferState {}
+
+/// The output of all [`TransferIoctls`], describing the driver's side of the transfer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransferStatus {
+    /// The ID of the current transfer, only meaningful if it isn't idle.
+    pub transfer_id: u32,
+    pub state: TransferState,
+    /// The sequence number of the next chunk the driver accepts.
+    pub next_sequence: u32,
+    /// The largest chunk the driver accepts, in bytes of data.
+    pub max_chunk_len: u32,
+    /// The offset of the next chunk the driver accepts, i.e. the number of bytes received so far.
+    pub next_offset: u64,
+    pub total_len: u64,
+}
+
+// SAFETY: `TransferStatus` is `repr(C)`, consists of four `u32`s followed by two `u64`s without
+// padding, and any bit pattern is valid for it.
+unsafe impl Zeroable for TransferStatus {}
+// SAFETY: See above.
+unsafe impl Pod for TransferStatus {}
+// SAFETY: `TransferStatus` is `repr(C)`, and consists of fixed-size integers.
+unsafe impl FixedLayout for TransferStatus {}
+
+impl TransferStatus {
+    /// The status of a driver without a transfer.
+    pub const fn idle(max_chunk_len: u32) -> Self {
+        Self {
+            transfer_id: 0,
+            state: TransferState::IDLE,
+            next_sequence: 0,
+            max_chunk_len,
+            next_offset: 0,
+            total_len: 0,
+        }
+    }
+
+    /// Whether the status describes the transfer `transfer_id`, in any state but idle.
+    pub fn is_transfer(&self, transfer_id: u32) -> bool {
+        self.state != TransferState::IDLE && self.transfer_id == transfer_id
+    }
+}
+
+/// The codes of the transfer protocol, see [`transfer_ioctls`].
+pub struct TransferIoctls {
+    /// Starts or resumes a transfer.
+    pub begin: TypedIoControlCode<TransferBegin, TransferStatus>,
+    /// Sends a chunk. The input is a [`ChunkHeader`] directly followed by the data, so it has no
+    /// fixed type.
+    pub chunk: IoControlCode,
+    /// Finishes the transfer with the given ID, checking the CRC of the whole blob.
+    pub end: TypedIoControlCode<u32, TransferStatus>,
+    /// Discards the transfer with the given ID.
+    pub abort: TypedIoControlCode<u32, TransferStatus>,
+}
+
+/// The codes of the transfer protocol for a driver using `device_type` for its codes, with the
+/// four function codes starting at `first_function`.
+///
+/// The codes are buffered and require write access, as they modify the state of the device.
+pub const fn transfer_ioctls(device_type: DeviceType, first_function: u16) -> TransferIoctls {
+    const fn code(device_type: DeviceType, function: u16) -> IoControlCode {
+        IoControlCode::new_custom(
+            device_type,
+            function,
+            IoCtlTransferType::Buffered,
+            IoCtlAccess::WRITE_DATA,
+        )
+    }
+
+    TransferIoctls {
+        begin: TypedIoControlCode::new(code(device_type, first_function)),
+        chunk: code(device_type, first_function + 1),
+        end: TypedIoControlCode::new(code(device_type, first_function + 2)),
+        abort: TypedIoControlCode::new(code(device_type, first_function + 3)),
+    }
+}
+
+/// The client side of a transfer of `data`, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct TransferSender<'a> {
+    transfer_id: u32,
+    data: &'a [u8],
+    chunk_len: u32,
+}
+
+impl<'a> TransferSender<'a> {
+    /// Sends `data` in chunks of at most `chunk_len` bytes, or less if the driver accepts less.
+    ///
+    /// Panics if `chunk_len` is zero.
+    pub fn new(transfer_id: u32, data: &'a [u8], chunk_len: u32) -> Self {
+        assert!(chunk_len > 0, "chunks can't be empty");
+
+        Self {
+            transfer_id,
+            data,
+            chunk_len,
+        }
+    }
+
+    /// The input of [`TransferIoctls::begin`], which starts or resumes the transfer.
+    pub fn begin(&self) -> TransferBegin {
+        TransferBegin {
+            transfer_id: self.transfer_id,
+            crc32: CRC32_ISO_HDLC.checksum(self.data),
+            total_len: self.data.len() as u64,
+        }
+    }
+
+    /// Encodes the input of the next [`TransferIoctls::chunk`] the driver expects according to
+    /// its `status` into `buf`, returning its length, or `None` if all data was sent and the
+    /// transfer can be ended.
+    ///
+    /// Fails if `buf` can't hold the header and the chunk. A buffer of [`Self::max_input_len`]
+    /// bytes is always large enough.
+    pub fn encode_chunk(
+        &self,
+        status: &TransferStatus,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, CapacityError> {
+        let offset = status.next_offset as usize;
+        if !status.is_transfer(self.transfer_id) || offset >= self.data.len() {
+            return Ok(None);
+        }
+
+        let chunk_len = self.chunk_len.min(status.max_chunk_len.max(1)) as usize;
+        let data = &self.data[offset..][..chunk_len.min(self.data.len() - offset)];
+
+        let header = ChunkHeader {
+            transfer_id: self.transfer_id,
+            sequence: status.next_sequence,
+            offset: offset as u64,
+            len: data.len() as u32,
+            crc32: CRC32_ISO_HDLC.checksum(data),
+        };
+
+        let len = size_of::<ChunkHeader>() + data.len();
+        let buf = buf.get_mut(..len).ok_or(CapacityError(()))?;
+        let (header_bytes, data_bytes) = buf.split_at_mut(size_of::<ChunkHeader>());
+        header_bytes.copy_from_slice(bytemuck::bytes_of(&header));
+        data_bytes.copy_from_slice(data);
+
+        Ok(Some(len))
+    }
+
+    /// The largest input [`Self::encode_chunk`] encodes.
+    pub fn max_input_len(&self) -> usize {
+        size_of::<ChunkHeader>() + self.chunk_len as usize
+    }
+}
+
+// compile-time check of the payloads
+const _: () = assert!(size_of::<TransferBegin>() == 16);
+const _: () = assert!(size_of::<ChunkHeader>() == 24);
+const _: () = assert!(size_of::<TransferStatus>() == 32);
+crate::assert_ioctl_fixed_layout!(transfer_ioctls(DeviceType::FIRST_CUSTOM, 0x800).begin);
+crate::assert_ioctl_fixed_layout!(transfer_ioctls(DeviceType::FIRST_CUSTOM, 0x800).end);
@@ -1,5 +1,13 @@
 use core::ptr;
 
+mod array_string;
+mod array_vec;
+mod fixed;
+
+pub use array_string::*;
+pub use array_vec::*;
+pub use fixed::*;
+
 pub trait AsRawPtr {
     type Pointee;
     fn as_raw_ptr(&self) -> *const Self::Pointee;
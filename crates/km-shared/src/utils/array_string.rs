@@ -0,0 +1,160 @@
+use super::CapacityError;
+use core::{fmt, hash, ops::Deref, str};
+
+/// A string with a fixed capacity of `N` bytes of UTF-8, stored inline.
+///
+/// Like [`ArrayVec`](super::ArrayVec), it never allocates, and stores its length as a `u32`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ArrayString<const N: usize> {
+    len: u32,
+    bytes: [u8; N],
+}
+
+// SAFETY: A zeroed `ArrayString` is empty.
+unsafe impl<const N: usize> bytemuck::Zeroable for ArrayString<N> {}
+
+impl<const N: usize> ArrayString<N> {
+    const CAPACITY_FITS_LEN: () = assert!(N <= u32::MAX as usize, "capacity must fit into a u32");
+
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_FITS_LEN;
+
+        Self {
+            len: 0,
+            bytes: [0; N],
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends `s`, or fails without appending anything if it doesn't fit.
+    pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let start = self.len();
+        let end = start + s.len();
+        if end > N {
+            return Err(CapacityError(()));
+        }
+
+        self.bytes[start..end].copy_from_slice(s.as_bytes());
+        self.len = end as u32;
+        Ok(())
+    }
+
+    /// Appends `c`, or returns it back if it doesn't fit.
+    pub fn push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+            .map_err(|_| CapacityError(c))
+    }
+
+    /// Appends as much of `s` as fits, without splitting characters. Returns whether all of `s`
+    /// was appended.
+    pub fn push_str_truncating(&mut self, s: &str) -> bool {
+        let mut end = usize::min(s.len(), N - self.len());
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        // Can't fail, as `end` was limited to the remaining capacity.
+        let _ = self.push_str(&s[..end]);
+        end == s.len()
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.len -= c.len_utf8() as u32;
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes.
+    ///
+    /// Panics if `len` isn't on a character boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.as_str().is_char_boundary(len), "not a char boundary");
+            self.len = len as u32;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Only valid UTF-8 is ever written into the first `len` bytes.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len()]) }
+    }
+}
+
+impl<const N: usize> Default for ArrayString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for ArrayString<N> {
+    type Error = CapacityError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut string = Self::new();
+        string.push_str(s)?;
+        Ok(string)
+    }
+}
+
+impl<const N: usize> fmt::Write for ArrayString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> PartialEq for ArrayString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> PartialEq<str> for ArrayString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> hash::Hash for ArrayString<N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
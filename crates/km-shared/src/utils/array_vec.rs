@@ -0,0 +1,283 @@
+use core::{
+    fmt,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+/// The error returned when a fixed-capacity container is full, holding the element that couldn't
+/// be added.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()>(pub T);
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CapacityError")
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+/// A vector with a fixed capacity of `N` elements, stored inline.
+///
+/// It never allocates, so it can be used at any IRQL, as long as the memory it's stored in can be
+/// accessed. The length is stored as a `u32`, so the layout is the same for 32- and 64-bit
+/// processes.
+#[repr(C)]
+pub struct ArrayVec<T, const N: usize> {
+    len: u32,
+    items: [MaybeUninit<T>; N],
+}
+
+// SAFETY: A zeroed `ArrayVec` is empty, and the items are `MaybeUninit`.
+unsafe impl<T, const N: usize> bytemuck::Zeroable for ArrayVec<T, N> {}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    const CAPACITY_FITS_LEN: () = assert!(N <= u32::MAX as usize, "capacity must fit into a u32");
+
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_FITS_LEN;
+
+        Self {
+            len: 0,
+            // SAFETY: An array of `MaybeUninit` doesn't need initialization.
+            items: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Appends `item`, or returns it back if the vector is full.
+    pub fn push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError(item));
+        }
+
+        self.items[self.len()].write(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: The item was initialized, and isn't part of the vector anymore.
+        Some(unsafe { self.items[self.len()].assume_init_read() })
+    }
+
+    /// Inserts `item` at `index`, shifting all items after it to the right, or returns it back if
+    /// the vector is full.
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) -> Result<(), CapacityError<T>> {
+        assert!(index <= self.len(), "insertion index out of bounds");
+        if self.is_full() {
+            return Err(CapacityError(item));
+        }
+
+        // SAFETY: There is space for one more item, so shifting the items after `index` is in
+        // bounds.
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            ptr::copy(p, p.add(1), self.len() - index);
+            p.write(item);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the item at `index`, shifting all items after it to the left.
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "removal index out of bounds");
+
+        // SAFETY: `index` is in bounds, and the item is moved out before the gap is closed.
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            let item = p.read();
+            ptr::copy(p.add(1), p, self.len() - index - 1);
+            self.len -= 1;
+            item
+        }
+    }
+
+    /// Removes the item at `index`, replacing it with the last item.
+    ///
+    /// Panics if `index >= len`.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let last = self.len() - 1;
+        self.swap(index, last);
+        // The vector is non-empty, as `swap` panics otherwise.
+        self.pop().unwrap()
+    }
+
+    /// Shortens the vector to `len` items, dropping the rest.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the items matching `f`.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut i = 0;
+        while i < self.len() {
+            if f(&self[i]) {
+                i += 1;
+            } else {
+                self.remove(i);
+            }
+        }
+    }
+
+    /// Appends all items of `items`, or fails without appending any if they don't fit.
+    pub fn extend_from_slice(&mut self, items: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        if N - self.len() < items.len() {
+            return Err(CapacityError(()));
+        }
+
+        for item in items {
+            // Can't fail, as there is enough capacity left.
+            let _ = self.push(item.clone());
+        }
+        Ok(())
+    }
+
+    /// Converts a full vector into an array, or returns it back if it isn't full.
+    pub fn into_array(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+
+        let this = ManuallyDrop::new(self);
+        // SAFETY: All items are initialized, and are moved out of the vector, which isn't
+        // dropped. `MaybeUninit<T>` has the same layout as `T`.
+        Ok(unsafe { ptr::read(this.items.as_ptr().cast()) })
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` items are initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` items are initialized.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.items.as_ptr().cast()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.items.as_mut_ptr().cast()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: The first `len` items are initialized, and aren't used afterwards.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        // Can't fail, as the clone has the same capacity.
+        let _ = clone.extend_from_slice(self);
+        clone
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for ArrayVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_from(items: &[T]) -> Result<Self, Self::Error> {
+        let mut vec = Self::new();
+        vec.extend_from_slice(items)?;
+        Ok(vec)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,325 @@
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A signed Q16.16 fixed-point number, i.e. an `i32` counting 1/65536ths.
+///
+/// Fixed-point math only uses integer instructions, so it can be used in kernel mode without
+/// saving the floating point state, and gives bit-exact results in the driver and in user mode,
+/// e.g. for evaluating the same fan curve on both sides. The range is `-32768..32768` with a
+/// resolution of about 0.000015.
+///
+/// Arithmetic never panics: the operators saturate at [`Self::MIN`] and [`Self::MAX`], like the
+/// `saturating_*` methods, and the `checked_*` methods return `None` instead. Multiplication
+/// rounds to the nearest representable value, division truncates towards zero.
+///
+/// ```rs, ignore
+/// let celsius = Fixed::from_ratio(millidegrees, 1000).unwrap_or(Fixed::MAX);
+/// let duty = interpolate(&FAN_CURVE, celsius).map_or(100, Fixed::round);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i32);
+
+// SAFETY: `Fixed` is a transparent wrapper around an `i32`, for which all bit patterns are valid.
+unsafe impl bytemuck::Zeroable for Fixed {}
+// SAFETY: See above.
+unsafe impl bytemuck::Pod for Fixed {}
+
+impl Fixed {
+    /// The number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << Self::FRAC_BITS);
+    pub const MIN: Fixed = Fixed(i32::MIN);
+    pub const MAX: Fixed = Fixed(i32::MAX);
+    /// The smallest positive value, 1/65536.
+    pub const EPSILON: Fixed = Fixed(1);
+
+    /// Creates a number from its raw representation, in 1/65536ths.
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// The raw representation, in 1/65536ths.
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Converts an integer, which always fits.
+    pub const fn from_int(n: i16) -> Self {
+        Self((n as i32) << Self::FRAC_BITS)
+    }
+
+    /// Converts an integer, saturating if it's out of range.
+    pub const fn saturating_from_int(n: i32) -> Self {
+        Self::saturate((n as i64) << Self::FRAC_BITS)
+    }
+
+    /// Converts `numerator / denominator`, e.g. `from_ratio(millidegrees, 1000)`, rounding to the
+    /// nearest value. Returns `None` if the denominator is zero, or the result is out of range.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let scaled = (numerator as i64) << Self::FRAC_BITS;
+        let denominator = denominator as i64;
+        // round half away from zero
+        let half = denominator.abs() / 2;
+        let rounded = if (scaled < 0) == (denominator < 0) {
+            (scaled + half * denominator.signum()) / denominator
+        } else {
+            (scaled - half * denominator.signum()) / denominator
+        };
+
+        Self::checked(rounded)
+    }
+
+    /// Converts a float, saturating if it's out of range. NaN converts to zero.
+    ///
+    /// Uses floating point, see `km::fpu` for using it in kernel mode.
+    pub fn from_f32(f: f32) -> Self {
+        // `as` saturates, and maps NaN to 0
+        Self((f * Self::ONE.0 as f32) as i32)
+    }
+
+    /// Converts to a float, which may lose precision.
+    ///
+    /// Uses floating point, see `km::fpu` for using it in kernel mode.
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// The largest integer less than or equal to `self`.
+    pub const fn floor(self) -> i32 {
+        self.0 >> Self::FRAC_BITS
+    }
+
+    /// The smallest integer greater than or equal to `self`.
+    pub const fn ceil(self) -> i32 {
+        ((self.0 as i64 + Self::ONE.0 as i64 - 1) >> Self::FRAC_BITS) as i32
+    }
+
+    /// The nearest integer, rounding half away from zero.
+    pub const fn round(self) -> i32 {
+        let half = (Self::ONE.0 / 2) as i64;
+        if self.0 < 0 {
+            -((-(self.0 as i64) + half) >> Self::FRAC_BITS) as i32
+        } else {
+            ((self.0 as i64 + half) >> Self::FRAC_BITS) as i32
+        }
+    }
+
+    /// The fractional part, i.e. `self - floor(self)`, which is never negative.
+    pub const fn fract(self) -> Self {
+        Self(self.0 & (Self::ONE.0 - 1))
+    }
+
+    pub const fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked(Self::mul_bits(self, rhs))
+    }
+
+    /// Returns `None` if `rhs` is zero, or the result is out of range.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        Self::checked(((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64)
+    }
+
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        Self::saturate(Self::mul_bits(self, rhs))
+    }
+
+    /// Division by zero saturates to [`Self::MAX`] or [`Self::MIN`] depending on the sign of
+    /// `self`, or returns zero if `self` is zero.
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            return match self.0 {
+                0 => Self::ZERO,
+                n if n > 0 => Self::MAX,
+                _ => Self::MIN,
+            };
+        }
+        Self::saturate(((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64)
+    }
+
+    /// Linearly interpolates between `self` at `t = 0` and `other` at `t = 1`. `t` isn't
+    /// clamped, so values outside of `0..=1` extrapolate.
+    pub const fn lerp(self, other: Self, t: Self) -> Self {
+        let delta = other.0 as i64 - self.0 as i64;
+        // only saturates when extrapolating far out of range
+        let offset = delta
+            .saturating_mul(t.0 as i64)
+            .saturating_add(1 << (Self::FRAC_BITS - 1))
+            >> Self::FRAC_BITS;
+        Self::saturate(self.0 as i64 + offset)
+    }
+
+    /// The product of the raw values, rounded to the nearest value in 1/65536ths.
+    const fn mul_bits(self, rhs: Self) -> i64 {
+        (self.0 as i64 * rhs.0 as i64 + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS
+    }
+
+    const fn checked(bits: i64) -> Option<Self> {
+        if bits < i32::MIN as i64 || bits > i32::MAX as i64 {
+            None
+        } else {
+            Some(Self(bits as i32))
+        }
+    }
+
+    const fn saturate(bits: i64) -> Self {
+        if bits < i32::MIN as i64 {
+            Self::MIN
+        } else if bits > i32::MAX as i64 {
+            Self::MAX
+        } else {
+            Self(bits as i32)
+        }
+    }
+}
+
+/// Evaluates the piecewise linear function through `points` at `x`, e.g. a fan curve mapping
+/// temperatures to duty cycles.
+///
+/// The points have to be sorted by their `x` coordinate. Below the first and above the last point,
+/// the curve is flat. Returns `None` if there are no points.
+pub fn interpolate(points: &[(Fixed, Fixed)], x: Fixed) -> Option<Fixed> {
+    let (&(first_x, first_y), &(last_x, last_y)) = (points.first()?, points.last()?);
+    if x <= first_x {
+        return Some(first_y);
+    }
+    if x >= last_x {
+        return Some(last_y);
+    }
+
+    // the first segment ending at or after `x`, which exists as `x < last_x`
+    let end = points.iter().position(|&(px, _)| px >= x)?;
+    let ((x0, y0), (x1, y1)) = (points[end - 1], points[end]);
+    if x1 == x0 {
+        return Some(y1);
+    }
+
+    // `y0 + (y1 - y0) * (x - x0) / (x1 - x0)`, rounded, in `i128` as the product of the
+    // differences may not even fit in an `i64`
+    let numerator = (y1.0 as i128 - y0.0 as i128) * (x.0 as i128 - x0.0 as i128);
+    let denominator = x1.0 as i128 - x0.0 as i128;
+    let half = denominator.abs() / 2 * (numerator.signum() * denominator.signum());
+    let offset = (numerator + half) / denominator;
+
+    let y = (y0.0 as i128 + offset).clamp(i32::MIN as i128, i32::MAX as i128);
+    Some(Fixed(y as i32))
+}
+
+impl From<i16> for Fixed {
+    fn from(n: i16) -> Self {
+        Self::from_int(n)
+    }
+}
+
+impl From<u8> for Fixed {
+    fn from(n: u8) -> Self {
+        Self::from_int(n as i16)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.saturating_add(rhs)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.saturating_div(rhs)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+/// Formats the number in decimal, without using floating point. The precision defaults to 4
+/// fractional digits.
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(4).min(9) as u32;
+        let scale = 10u64.pow(precision);
+
+        let magnitude = (self.0 as i64).unsigned_abs();
+        // round the fraction to `precision` digits, which may carry into the integer part
+        let scaled = ((magnitude * scale) + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS;
+        let (int, frac) = (scaled / scale, scaled % scale);
+
+        let sign = if self.0 < 0 && scaled != 0 { "-" } else { "" };
+        if precision == 0 {
+            write!(f, "{sign}{int}")
+        } else {
+            write!(f, "{sign}{int}.{frac:0width$}", width = precision as usize)
+        }
+    }
+}
+
+impl fmt::Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,290 @@
+//! A compact, explicitly little-endian wire format for I/O control payloads, shared by drivers and
+//! their user mode clients.
+//!
+//! Unlike `#[repr(C)]` structs, payloads encoded with [`WireFormat`] don't depend on padding or
+//! alignment, can contain variable-length fields (e.g. [`ArrayVec`]), and can be extended over
+//! time without breaking older clients. Structs implement the trait with the [`WireFormat`]
+//! derive:
+//!
+//! ```rs, ignore
+//! #[derive(WireFormat, Default)]
+//! #[wire(version = 2)]
+//! struct FanStatus {
+//!     fan: u8,
+//!     rpm: u32,
+//!     #[wire(big_endian)]
+//!     raw_tach: u16,
+//!     #[wire(since = 2)]
+//!     duty_percent: Option<u8>,
+//! }
+//!
+//! let len = status.to_bytes(output)?;
+//! let status = FanStatus::from_bytes(input)?;
+//! ```
+//!
+//! The derive supports these attributes:
+//! - `#[wire(crate = "km::shared")]` on the struct sets the path to this crate, if it's only
+//!   available through a re-export.
+//! - `#[wire(big_endian)]` on a field encodes it in big endian byte order, e.g. to match a
+//!   hardware register layout. The field's type must implement [`BigEndianWire`].
+//! - `#[wire(version = N)]` on the struct makes it versioned: the fields are prefixed with the
+//!   version (`u16`) and their total length (`u32`). Decoding skips trailing fields added by
+//!   newer versions, so newer payloads can be decoded by older code.
+//! - `#[wire(since = N)]` on a field of a versioned struct marks it as added in version `N`. When
+//!   decoding an older payload, the field is set to its [`Default`] instead.
+//!
+//! Without attributes, a struct is encoded as its fields in declaration order, without any
+//! framing.
+
+use crate::{
+    guid::Guid,
+    io::{ByteReader, ByteWriter, CursorError},
+    utils::{ArrayString, ArrayVec, Fixed},
+};
+use core::mem::size_of;
+
+pub use km_shared_derive::WireFormat;
+
+/// A type that can be encoded into and decoded from a byte buffer. See the [module
+/// documentation](self) for the format and the derive.
+///
+/// Decoding never trusts the input: lengths are checked against the remaining input and the
+/// capacity of the decoded type, and invalid values (e.g. non-UTF-8 strings) are rejected with
+/// [`CursorError::InvalidValue`].
+pub trait WireFormat: Sized {
+    /// The number of bytes [`Self::encode`] writes.
+    fn encoded_len(&self) -> usize;
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError>;
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError>;
+
+    /// Encodes `self` at the start of `buf`, returning the number of bytes written.
+    fn to_bytes(&self, buf: &mut [u8]) -> Result<usize, CursorError> {
+        let mut writer = ByteWriter::new(buf);
+        self.encode(&mut writer)?;
+        Ok(writer.position())
+    }
+
+    /// Decodes a value from the start of `bytes`. Trailing bytes are ignored.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, CursorError> {
+        Self::decode(&mut ByteReader::new(bytes))
+    }
+}
+
+/// A type that can also be encoded in big endian byte order, with `#[wire(big_endian)]`.
+pub trait BigEndianWire: WireFormat {
+    fn encoded_len_be(&self) -> usize {
+        self.encoded_len()
+    }
+
+    fn encode_be(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError>;
+
+    fn decode_be(reader: &mut ByteReader<'_>) -> Result<Self, CursorError>;
+}
+
+/// Implements the wire traits for primitive numbers, using their byte conversions.
+macro_rules! wire_numbers {
+    ($($t:ty),*) => {
+        $(
+            impl WireFormat for $t {
+                fn encoded_len(&self) -> usize {
+                    size_of::<$t>()
+                }
+
+                fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+                    writer.write_bytes(&self.to_le_bytes())
+                }
+
+                fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+                    reader.read_array().map(<$t>::from_le_bytes)
+                }
+            }
+
+            impl BigEndianWire for $t {
+                fn encode_be(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+                    writer.write_bytes(&self.to_be_bytes())
+                }
+
+                fn decode_be(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+                    reader.read_array().map(<$t>::from_be_bytes)
+                }
+            }
+        )*
+    };
+}
+
+wire_numbers!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl WireFormat for bool {
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        writer.write_u8(*self as u8)
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        let offset = reader.position();
+        match reader.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(CursorError::InvalidValue { offset }),
+        }
+    }
+}
+
+impl WireFormat for () {
+    fn encoded_len(&self) -> usize {
+        0
+    }
+
+    fn encode(&self, _writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        Ok(())
+    }
+
+    fn decode(_reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        Ok(())
+    }
+}
+
+impl<T: WireFormat, const N: usize> WireFormat for [T; N] {
+    fn encoded_len(&self) -> usize {
+        self.iter().map(WireFormat::encoded_len).sum()
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        self.iter().try_for_each(|item| item.encode(writer))
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        let mut items = ArrayVec::<T, N>::new();
+        for _ in 0..N {
+            // Can't fail, as exactly `N` items are pushed.
+            let _ = items.push(T::decode(reader)?);
+        }
+
+        Ok(items.into_array().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+/// Encoded as a `u8` tag (0 for `None`, 1 for `Some`), followed by the value, if any.
+impl<T: WireFormat> WireFormat for Option<T> {
+    fn encoded_len(&self) -> usize {
+        1 + self.as_ref().map_or(0, WireFormat::encoded_len)
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        self.is_some().encode(writer)?;
+        match self {
+            Some(value) => value.encode(writer),
+            None => Ok(()),
+        }
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        match bool::decode(reader)? {
+            true => T::decode(reader).map(Some),
+            false => Ok(None),
+        }
+    }
+}
+
+/// Encoded as the number of items (`u32`), followed by the items.
+impl<T: WireFormat, const N: usize> WireFormat for ArrayVec<T, N> {
+    fn encoded_len(&self) -> usize {
+        size_of::<u32>() + self.iter().map(WireFormat::encoded_len).sum::<usize>()
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        (self.len() as u32).encode(writer)?;
+        self.iter().try_for_each(|item| item.encode(writer))
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        let offset = reader.position();
+        let len = u32::decode(reader)? as usize;
+        if len > N {
+            return Err(CursorError::InvalidValue { offset });
+        }
+
+        let mut items = Self::new();
+        for _ in 0..len {
+            // Can't fail, as the length was checked above.
+            let _ = items.push(T::decode(reader)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Encoded as the length in bytes (`u32`), followed by the UTF-8 bytes.
+impl<const N: usize> WireFormat for ArrayString<N> {
+    fn encoded_len(&self) -> usize {
+        size_of::<u32>() + self.len()
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        (self.len() as u32).encode(writer)?;
+        writer.write_bytes(self.as_bytes())
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        let offset = reader.position();
+        let len = u32::decode(reader)? as usize;
+        let bytes = reader.read_bytes(len)?;
+
+        core::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| Self::try_from(s).ok())
+            .ok_or(CursorError::InvalidValue { offset })
+    }
+}
+
+/// Encoded like the Windows `GUID` struct, i.e. with little endian `data1`-`data3`.
+impl WireFormat for Guid {
+    fn encoded_len(&self) -> usize {
+        16
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        self.data1.encode(writer)?;
+        self.data2.encode(writer)?;
+        self.data3.encode(writer)?;
+        self.data4.encode(writer)
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        Ok(Guid::from_fields(
+            u32::decode(reader)?,
+            u16::decode(reader)?,
+            u16::decode(reader)?,
+            <[u8; 8]>::decode(reader)?,
+        ))
+    }
+}
+
+/// Encoded as its raw `i32` representation.
+impl WireFormat for Fixed {
+    fn encoded_len(&self) -> usize {
+        size_of::<i32>()
+    }
+
+    fn encode(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        self.to_bits().encode(writer)
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        i32::decode(reader).map(Fixed::from_bits)
+    }
+}
+
+impl BigEndianWire for Fixed {
+    fn encode_be(&self, writer: &mut ByteWriter<'_>) -> Result<(), CursorError> {
+        self.to_bits().encode_be(writer)
+    }
+
+    fn decode_be(reader: &mut ByteReader<'_>) -> Result<Self, CursorError> {
+        i32::decode_be(reader).map(Fixed::from_bits)
+    }
+}
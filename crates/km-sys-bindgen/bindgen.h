@@ -19,6 +19,18 @@
 
 // Windows Driver Model
 #include <wdm.h>
+// WNODE_* structures for WMI queries
+#include <wmistr.h>
+// HID class driver IOCTLs and report descriptor parsing
+#include <hidclass.h>
+#include <hidpi.h>
+// storage property queries and SMART
+#include <ntddstor.h>
+#include <ntdddisk.h>
+// serial port IOCTLs
+#include <ntddser.h>
+// ACPI method evaluation
+#include <acpiioct.h>
 // Windows Driver Framework
 #include <wdf.h>
 #include <wdfdriver.h>
@@ -1,12 +1,23 @@
 #![deny(rust_2018_idioms)]
 
 use serde::Deserialize;
-use std::env;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::Path,
+};
+
+mod ntstatus;
+mod wdf_ffi;
 
 #[derive(Deserialize)]
 struct BindgenConfig {
     enums: BindgenEnumConfig,
+    abi: BindgenAbiConfig,
     allowlists: BindgenAllowlists,
+    /// Additional modules, see the end of `bindgen.toml`.
+    #[serde(default)]
+    modules: BTreeMap<String, BindgenAllowlists>,
 }
 
 #[derive(Deserialize)]
@@ -18,42 +29,127 @@ struct BindgenEnumConfig {
 }
 
 #[derive(Deserialize)]
+struct BindgenAbiConfig {
+    /// Function pointer types generated as `extern "system"`.
+    system: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
 struct BindgenAllowlists {
     allowed_functions: Vec<String>,
     allowed_vars: Vec<String>,
     allowed_types: Vec<String>,
 }
 
+const USAGE: &str =
+    "USAGE: km-sys-bindgen.exe [--ntstatus] <outfile> | --wdf-ffi <generated/wdf.rs> <outfile>";
+
 fn main() {
-    let out_file = env::args()
-        .nth(1)
-        .expect("USAGE: km-sys-bindgen.exe <outfile>");
+    let mut args = env::args().skip(1);
+    let mut out_file = args.next().expect(USAGE);
+
+    if out_file == "--wdf-ffi" {
+        let bindings_file = args.next().expect(USAGE);
+        let out_file = args.next().expect(USAGE);
+
+        let bindings = fs::read_to_string(bindings_file).expect("Couldn't read bindings");
+        let functions = wdf_ffi::parse(&bindings);
+
+        fs::write(out_file, wdf_ffi::generate(&bindings, &functions))
+            .expect("Couldn't write WDF functions");
+
+        println!(
+            "\n\n{} WDF function wrappers generated successfully",
+            functions.len()
+        );
+        return;
+    }
+
+    let generate_ntstatus = out_file == "--ntstatus";
+    if generate_ntstatus {
+        out_file = args.next().expect(USAGE);
+    }
 
     dotenvy::dotenv().ok();
 
     let shared_includes =
         env::var("KM_RS_WDK_INCLUDE_SHARED").expect("`KM_RS_WDK_INCLUDE_SHARED` was not set");
+
+    if generate_ntstatus {
+        let header = fs::read_to_string(Path::new(&shared_includes).join("ntstatus.h"))
+            .expect("Couldn't read `ntstatus.h`");
+        let statuses = ntstatus::parse(&header);
+
+        fs::write(out_file, ntstatus::generate(&statuses)).expect("Couldn't write constants");
+
+        println!(
+            "\n\n{} NTSTATUS constants generated successfully",
+            statuses.len()
+        );
+        return;
+    }
+
     let km_includes = env::var("KM_RS_WDK_INCLUDE_KM").expect("`KM_RS_WDK_INCLUDE_KM` was not set");
     let kmdf_includes =
         env::var("KM_RS_WDK_INCLUDE_WDM_KMDF").expect("`KM_RS_WDK_INCLUDE_WDM_KMDF` was not set");
 
     let BindgenConfig {
-        allowlists:
-            BindgenAllowlists {
-                allowed_functions,
-                allowed_types,
-                allowed_vars,
-            },
-        enums:
-            BindgenEnumConfig {
-                bitfield_enums,
-                constified_enums,
-                rustified_enums,
-                newtype_enums,
-            },
+        allowlists,
+        enums,
+        abi,
+        modules,
     } = toml::from_str(include_str!("../bindgen.toml"))
         .expect("Could not deserialize `bindgen.toml`");
 
+    let includes = [shared_includes, km_includes, kmdf_includes];
+    let config = BuilderConfig {
+        includes: &includes,
+        enums: &enums,
+        abi: &abi,
+    };
+
+    let bindings = builder(&config, allowlists)
+        .generate()
+        .expect("Unable to generate bindings");
+
+    let main_items = defined_items(&bindings.to_string());
+    bindings
+        .write_to_file(&out_file)
+        .expect("Couldn't write bindings");
+
+    // modules go next to the main bindings, e.g. `generated/storage.rs` for `generated.rs`
+    let modules_dir = Path::new(&out_file).with_extension("");
+    for (name, allowlists) in modules {
+        let mut builder = builder(&config, allowlists).raw_line("use super::*;");
+        // use the items of the main bindings instead of redefining them
+        for item in &main_items {
+            builder = builder.blocklist_item(item);
+        }
+
+        let bindings = builder
+            .generate()
+            .unwrap_or_else(|_| panic!("Unable to generate bindings for module `{name}`"));
+
+        fs::create_dir_all(&modules_dir).expect("Couldn't create modules directory");
+        bindings
+            .write_to_file(modules_dir.join(format!("{name}.rs")))
+            .unwrap_or_else(|_| panic!("Couldn't write bindings for module `{name}`"));
+    }
+
+    println!("\n\nBindings generated successfully");
+}
+
+struct BuilderConfig<'a> {
+    includes: &'a [String; 3],
+    enums: &'a BindgenEnumConfig,
+    abi: &'a BindgenAbiConfig,
+}
+
+fn builder(config: &BuilderConfig<'_>, allowlists: BindgenAllowlists) -> bindgen::Builder {
+    let [shared_includes, km_includes, kmdf_includes] = config.includes;
+    let enums = config.enums;
+
     let mut builder = bindgen::Builder::default()
         .use_core()
         .ctypes_prefix("::libc")
@@ -70,39 +166,67 @@ fn main() {
         .layout_tests(false)
         .formatter(bindgen::Formatter::Prettyplease);
 
-    for f in allowed_functions {
+    for f in allowlists.allowed_functions {
         builder = builder.allowlist_function(f);
     }
 
-    for t in allowed_types {
+    for t in allowlists.allowed_types {
         builder = builder.allowlist_type(t);
     }
 
-    for v in allowed_vars {
+    for v in allowlists.allowed_vars {
         builder = builder.allowlist_var(v);
     }
 
-    for e in bitfield_enums {
+    for t in &config.abi.system {
+        builder = builder.override_abi(bindgen::Abi::System, t);
+    }
+
+    for e in &enums.bitfield_enums {
         builder = builder.bitfield_enum(e);
     }
 
-    for e in constified_enums {
+    for e in &enums.constified_enums {
         builder = builder.constified_enum(e);
     }
 
-    for e in rustified_enums {
+    for e in &enums.rustified_enums {
         builder = builder.rustified_enum(e);
     }
 
-    for e in newtype_enums {
+    for e in &enums.newtype_enums {
         builder = builder.newtype_enum(e);
     }
 
-    let bindings = builder.generate().expect("Unable to generate bindings");
+    builder
+}
 
+/// The names of the items defined by bindings, to exclude them from the modules.
+fn defined_items(bindings: &str) -> BTreeSet<String> {
     bindings
-        .write_to_file(out_file)
-        .expect("Couldn't write bindings");
-
-    println!("\n\nBindings generated successfully");
+        .lines()
+        .filter_map(|line| {
+            // items in `extern` blocks are indented, but so are the constants of newtype enums
+            let item = match line.strip_prefix("    ") {
+                Some(item) if item.starts_with("pub fn ") || item.starts_with("pub static ") => {
+                    item
+                }
+                Some(_) => return None,
+                None => line,
+            };
+
+            let item = item.strip_prefix("pub ")?;
+            let name = if let Some(alias) = item.strip_prefix("use self::") {
+                alias.split_once(" as ")?.1
+            } else {
+                let (_, rest) = item.split_once(' ')?;
+                rest.strip_prefix("mut ").unwrap_or(rest)
+            };
+
+            let end = name
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(name.len());
+            (end > 0).then(|| name[..end].to_owned())
+        })
+        .collect()
 }
@@ -0,0 +1,92 @@
+//! Generates the `NTSTATUS` constants of `km-shared` from the WDK's `ntstatus.h`.
+//!
+//! bindgen can't be used for this, as the values are defined as casts
+//! (`#define STATUS_SUCCESS ((NTSTATUS)0x00000000L)`), which it doesn't evaluate.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+/// Parses all `#define STATUS_* ((NTSTATUS)0x...L)` lines of `ntstatus.h`.
+pub fn parse(header: &str) -> BTreeMap<String, u32> {
+    header
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().strip_prefix("#define")?.trim_start();
+            let (name, value) = line.split_once(char::is_whitespace)?;
+            if !name.starts_with("STATUS_") {
+                return None;
+            }
+
+            let value = value.trim_start().strip_prefix("((NTSTATUS)")?;
+            let value = value
+                .strip_prefix("0x")
+                .or_else(|| value.strip_prefix("0X"))?;
+            let end = value.find(|c: char| !c.is_ascii_hexdigit())?;
+            let value = u32::from_str_radix(&value[..end], 16).ok()?;
+
+            Some((name.to_owned(), value))
+        })
+        .collect()
+}
+
+/// Generates the `ntstatus/consts.rs` module of `km-shared`.
+///
+/// Error statuses become `NtStatusError` constants, all other statuses `NtStatus` constants. The
+/// name lookup table maps each value to the alphabetically first of its names.
+pub fn generate(statuses: &BTreeMap<String, u32>) -> String {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// @generated by `km-sys-bindgen --ntstatus` from `ntstatus.h`."
+    )
+    .unwrap();
+    writeln!(out, "// Do not edit manually.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use super::{{NtStatus, NtStatusError}};").unwrap();
+
+    let is_error = |value: u32| value >> 30 == 0b11;
+
+    writeln!(out).unwrap();
+    writeln!(out, "impl NtStatus {{").unwrap();
+    for (name, value) in statuses.iter().filter(|(_, v)| !is_error(**v)) {
+        writeln!(
+            out,
+            "    pub const {name}: NtStatus = NtStatus::from_u32(0x{value:08X});"
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    writeln!(out).unwrap();
+    writeln!(out, "impl NtStatusError {{").unwrap();
+    for (name, value) in statuses.iter().filter(|(_, v)| is_error(**v)) {
+        writeln!(
+            out,
+            "    pub const {name}: NtStatusError = NtStatusError::from_u32(0x{value:08X});"
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    let mut names = BTreeMap::new();
+    for (name, value) in statuses {
+        names.entry(*value).or_insert(name);
+    }
+
+    writeln!(out).unwrap();
+    writeln!(out, "#[cfg(feature = \"ntstatus-names\")]").unwrap();
+    writeln!(
+        out,
+        "pub(super) const fn name(status: u32) -> Option<&'static str> {{"
+    )
+    .unwrap();
+    writeln!(out, "    Some(match status {{").unwrap();
+    for (value, name) in names {
+        writeln!(out, "        0x{value:08X} => \"{name}\",").unwrap();
+    }
+    writeln!(out, "        _ => return None,").unwrap();
+    writeln!(out, "    }})").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    out
+}
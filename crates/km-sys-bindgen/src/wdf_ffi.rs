@@ -0,0 +1,280 @@
+//! Generates the `wdf_function!` declarations of `km`'s WDF ffi layer from the bindgen output.
+//!
+//! WDF functions aren't exported, but called through a function table indexed by `WDFFUNCENUM`,
+//! with their signatures given by the `PFN_WDF*` types. Every `PFN_WDF*` type in the bindings
+//! (i.e. allowed in `bindgen.toml`) gets a wrapper, named after its table index
+//! (`WdfRequestGetStatusTableIndex` becomes `request_get_status`), with the signature of the type.
+//!
+//! Handles (e.g. `WDFREQUEST`) are passed as `WdfObjectReference`s, except for the optional ones
+//! listed in [`RAW_HANDLE_PARAMS`], and `NTSTATUS`es as `NtStatus`.
+
+use std::{collections::BTreeSet, fmt::Write};
+
+/// Handle parameters that are optional, and stay raw handles so they can be null, as
+/// `(function, parameter)`.
+const RAW_HANDLE_PARAMS: &[(&str, &str)] = &[
+    ("WdfControlDeviceInitAllocate", "Driver"),
+    ("WdfDriverIsVersionAvailable", "Driver"),
+    ("WdfIoTargetSendIoctlSynchronously", "Request"),
+    ("WdfIoTargetSendInternalIoctlSynchronously", "Request"),
+    ("WdfIoTargetSendReadSynchronously", "Request"),
+    ("WdfIoTargetSendWriteSynchronously", "Request"),
+    ("WdfIoTargetFormatRequestForIoctl", "InputBuffer"),
+    ("WdfIoTargetFormatRequestForIoctl", "OutputBuffer"),
+    ("WdfIoQueueFindRequest", "FoundRequest"),
+    ("WdfIoQueueFindRequest", "FileObject"),
+    ("WdfRequestCreate", "IoTarget"),
+    (
+        "WdfUsbTargetDeviceSendControlTransferSynchronously",
+        "Request",
+    ),
+    ("WdfUsbTargetPipeReadSynchronously", "Request"),
+    ("WdfUsbTargetPipeResetSynchronously", "Request"),
+    ("WdfUsbTargetPipeWriteSynchronously", "Request"),
+];
+
+/// Function parameters, as `(name, type)`.
+type Params = Vec<(String, String)>;
+
+/// A WDF function, parsed from its `PFN_WDF*` type.
+#[derive(Debug)]
+pub struct WdfFunction {
+    /// The `PFN_WDF*` type.
+    pub pfn: String,
+    /// The function's name, e.g. `WdfRequestGetStatus`.
+    pub name: String,
+    /// The parameters after `DriverGlobals`.
+    pub params: Params,
+    /// The return type, or `None` for `void`.
+    pub ret: Option<String>,
+}
+
+/// Parses all WDF functions, in table order, from the bindgen output.
+pub fn parse(bindings: &str) -> Vec<WdfFunction> {
+    let indices = table_indices(bindings);
+
+    let mut functions: Vec<_> = pfn_types(bindings)
+        .filter_map(|(pfn, signature)| {
+            let name = indices
+                .iter()
+                .find(|name| name.to_ascii_uppercase() == pfn["PFN_".len()..])?;
+            let (params, ret) = parse_signature(signature)?;
+
+            // skip callbacks, which don't take the driver globals
+            let (first, _) = params.first()?;
+            if first != "DriverGlobals" {
+                return None;
+            }
+
+            Some(WdfFunction {
+                pfn: pfn.to_owned(),
+                name: name.clone(),
+                params: params.into_iter().skip(1).collect(),
+                ret,
+            })
+        })
+        .collect();
+
+    functions.sort_by_key(|f| indices.iter().position(|name| *name == f.name));
+    functions
+}
+
+/// Generates the `ffi/generated.rs` module of `km::wdf`.
+///
+/// `bindings` is used to find the handle types.
+pub fn generate(bindings: &str, functions: &[WdfFunction]) -> String {
+    let handles = handle_types(bindings);
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// @generated by `km-sys-bindgen --wdf-ffi` from `km-sys/src/generated/wdf.rs`."
+    )
+    .unwrap();
+    writeln!(out, "// Do not edit manually.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "use super::{{function_table, is_function_available, Inner}};"
+    )
+    .unwrap();
+    writeln!(out, "use crate::wdf::{{RawWdfObject, WdfObjectReference}};").unwrap();
+    writeln!(out, "use km_shared::ntstatus::NtStatus;").unwrap();
+    writeln!(out, "use km_sys::*;").unwrap();
+
+    for function in functions {
+        let raw_params: BTreeSet<_> = RAW_HANDLE_PARAMS
+            .iter()
+            .filter(|(f, _)| *f == function.name)
+            .map(|(_, param)| *param)
+            .collect();
+
+        let mut references = 0;
+        let params: Vec<_> = function
+            .params
+            .iter()
+            .map(|(name, ty)| {
+                let ty = if raw_params.contains(name.as_str()) {
+                    ty.clone()
+                } else {
+                    map_type(ty, &handles)
+                };
+                if ty.starts_with("WdfObjectReference") {
+                    references += 1;
+                }
+                (snake_case(name), ty)
+            })
+            .collect();
+
+        // returned handles borrow from the only handle parameter, if there's one
+        let ret = match &function.ret {
+            None => "()".to_owned(),
+            Some(ret) if references == 1 => map_type(ret, &handles),
+            Some(ret) if ret == "NTSTATUS" => map_type(ret, &handles),
+            Some(ret) => ret.clone(),
+        };
+
+        let name = function.name.strip_prefix("Wdf").unwrap_or(&function.name);
+
+        writeln!(out).unwrap();
+        writeln!(out, "wdf_function! {{").unwrap();
+        writeln!(
+            out,
+            "    ({}, WDFFUNCENUM::{}TableIndex):",
+            function.pfn, function.name
+        )
+        .unwrap();
+        if ret != "()" {
+            writeln!(out, "    #[must_use]").unwrap();
+        }
+        writeln!(out, "    pub unsafe fn {}(", snake_case(name)).unwrap();
+        for (name, ty) in params {
+            writeln!(out, "        {name}: {ty},").unwrap();
+        }
+        writeln!(out, "    ) -> {ret}").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    out
+}
+
+/// The names of the `WDFFUNCENUM` table indices, without the `TableIndex` suffix, in table order.
+fn table_indices(bindings: &str) -> Vec<String> {
+    bindings
+        .split("pub const ")
+        .skip(1)
+        .filter_map(|item| {
+            let (name, rest) = item.split_once(':')?;
+            if !rest.trim_start().starts_with("_WDFFUNCENUM") {
+                return None;
+            }
+            Some(name.strip_suffix("TableIndex")?.to_owned())
+        })
+        .collect()
+}
+
+/// The `PFN_WDF*` function types, as `(name, signature)`, where the signature starts after
+/// `fn(`.
+fn pfn_types(bindings: &str) -> impl Iterator<Item = (&str, &str)> {
+    bindings.split("pub type ").skip(1).filter_map(|item| {
+        let (name, rest) = item.split_once('=')?;
+        let name = name.trim();
+        let is_function = name
+            .strip_prefix("PFN_WDF")
+            .is_some_and(|rest| rest.chars().all(|c| c.is_ascii_alphanumeric()));
+        if !is_function {
+            return None;
+        }
+
+        let (_, signature) = rest.split_once("unsafe extern \"system\" fn(")?;
+        Some((name, signature))
+    })
+}
+
+/// Parses the parameters and return type of a function type, starting after `fn(`.
+fn parse_signature(signature: &str) -> Option<(Params, Option<String>)> {
+    let mut depth = 0;
+    let end = signature.find(|c| {
+        match c {
+            '(' | '<' => depth += 1,
+            ')' | '>' if depth == 0 => return true,
+            ')' | '>' => depth -= 1,
+            _ => {}
+        }
+        false
+    })?;
+
+    let params = signature[..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, ty) = param.split_once(':')?;
+            Some((name.trim().to_owned(), normalize(ty)))
+        })
+        .collect::<Option<_>>()?;
+
+    let rest = signature[end + 1..].trim_start();
+    let ret = rest.strip_prefix("->").map(|ret| {
+        let ret = ret.trim_start();
+        let end = ret.find([',', '\n']).unwrap_or(ret.len());
+        normalize(&ret[..end])
+    });
+
+    Some((params, ret))
+}
+
+/// Collapses whitespace in a type.
+fn normalize(ty: &str) -> String {
+    ty.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The handle types, e.g. `WDFREQUEST`, which are pointers to opaque types like `WDFREQUEST__`.
+fn handle_types(bindings: &str) -> BTreeSet<String> {
+    bindings
+        .split("pub type ")
+        .skip(1)
+        .filter_map(|item| {
+            let (name, rest) = item.split_once('=')?;
+            let target = rest.split(';').next()?.trim();
+            let name = name.trim();
+            (target == format!("*mut {name}__")).then(|| name.to_owned())
+        })
+        .collect()
+}
+
+fn map_type(ty: &str, handles: &BTreeSet<String>) -> String {
+    match ty {
+        "NTSTATUS" => "NtStatus".to_owned(),
+        "WDFOBJECT" => "WdfObjectReference<'_, RawWdfObject>".to_owned(),
+        handle if handles.contains(handle) => format!("WdfObjectReference<'_, {handle}__>"),
+        ty => ty.to_owned(),
+    }
+}
+
+/// Converts a `PascalCase` name to `snake_case`, keeping acronyms together (`SDDLString` becomes
+/// `sddl_string`).
+fn snake_case(name: &str) -> String {
+    let chars: Vec<_> = name.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        // numbers are words of their own (`From32Bit` becomes `from_32_bit`)
+        if c.is_ascii_digit() && i > 0 && chars[i - 1].is_ascii_alphabetic() {
+            out.push('_');
+        }
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out
+}
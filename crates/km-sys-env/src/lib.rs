@@ -1,4 +1,9 @@
-use std::{env, path::Path};
+use std::{
+    env,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 /// Adds the necessary linker arguments to link to the WDK libraries, optionally loading the closest
 /// `.env` file through [`dotenvy::dotenv()`]. See `.env.sample` for an example.
@@ -16,3 +21,48 @@ pub fn link_env(load_env_file: bool) {
     println!("cargo:rustc-link-search={}", Path::new(&lib_km).display());
     println!("cargo:rustc-link-search={}", Path::new(&lib_kmdf).display());
 }
+
+/// Sets the environment variables read by `km::build_info!` when compiling the crate whose build
+/// script calls this:
+/// - `KM_BUILD_GIT_COMMIT`: the commit checked out in the git working tree of the crate, if any.
+/// - `KM_BUILD_GIT_DIRTY`: set if the working tree has uncommitted changes.
+/// - `KM_BUILD_TIMESTAMP`: the time the build script ran, in seconds since the Unix epoch, or
+///   `SOURCE_DATE_EPOCH` if set, for reproducible builds.
+///
+/// The build script is rerun when the checked out commit or the index changes, but not on every
+/// build, so the timestamp is that of the last rerun.
+pub fn emit_build_info() {
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    if let Some(git_dir) = git(&["rev-parse", "--absolute-git-dir"]) {
+        println!("cargo:rerun-if-changed={git_dir}/HEAD");
+        println!("cargo:rerun-if-changed={git_dir}/index");
+
+        if let Some(commit) = git(&["rev-parse", "HEAD"]) {
+            println!("cargo:rustc-env=KM_BUILD_GIT_COMMIT={commit}");
+        }
+        if git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|s| !s.is_empty()) {
+            println!("cargo:rustc-env=KM_BUILD_GIT_DIRTY=1");
+        }
+    }
+
+    let timestamp = match env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => epoch
+            .parse::<u64>()
+            .unwrap_or_else(|_| panic!("`SOURCE_DATE_EPOCH` is not a number: {epoch:?}")),
+        Err(_) => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time before the Unix epoch")
+            .as_secs(),
+    };
+    println!("cargo:rustc-env=KM_BUILD_TIMESTAMP={timestamp}");
+}
+
+/// Runs git in the crate's directory, returning its trimmed output if it succeeded.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_owned())
+}
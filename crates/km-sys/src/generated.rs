@@ -1,4 +1,4 @@
-/* automatically generated by rust-bindgen 0.69.4 */
+/* automatically generated by rust-bindgen 0.69.4 */
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -98,7 +98,43 @@ pub const IPI_LEVEL: u32 = 14;
 pub const DRS_LEVEL: u32 = 14;
 pub const POWER_LEVEL: u32 = 14;
 pub const PROFILE_LEVEL: u32 = 15;
+pub const SE_MIN_WELL_KNOWN_PRIVILEGE: u32 = 2;
+pub const SE_CREATE_TOKEN_PRIVILEGE: u32 = 2;
+pub const SE_ASSIGNPRIMARYTOKEN_PRIVILEGE: u32 = 3;
+pub const SE_LOCK_MEMORY_PRIVILEGE: u32 = 4;
+pub const SE_INCREASE_QUOTA_PRIVILEGE: u32 = 5;
+pub const SE_MACHINE_ACCOUNT_PRIVILEGE: u32 = 6;
+pub const SE_TCB_PRIVILEGE: u32 = 7;
+pub const SE_SECURITY_PRIVILEGE: u32 = 8;
+pub const SE_TAKE_OWNERSHIP_PRIVILEGE: u32 = 9;
 pub const SE_LOAD_DRIVER_PRIVILEGE: u32 = 10;
+pub const SE_SYSTEM_PROFILE_PRIVILEGE: u32 = 11;
+pub const SE_SYSTEMTIME_PRIVILEGE: u32 = 12;
+pub const SE_PROF_SINGLE_PROCESS_PRIVILEGE: u32 = 13;
+pub const SE_INC_BASE_PRIORITY_PRIVILEGE: u32 = 14;
+pub const SE_CREATE_PAGEFILE_PRIVILEGE: u32 = 15;
+pub const SE_CREATE_PERMANENT_PRIVILEGE: u32 = 16;
+pub const SE_BACKUP_PRIVILEGE: u32 = 17;
+pub const SE_RESTORE_PRIVILEGE: u32 = 18;
+pub const SE_SHUTDOWN_PRIVILEGE: u32 = 19;
+pub const SE_DEBUG_PRIVILEGE: u32 = 20;
+pub const SE_AUDIT_PRIVILEGE: u32 = 21;
+pub const SE_SYSTEM_ENVIRONMENT_PRIVILEGE: u32 = 22;
+pub const SE_CHANGE_NOTIFY_PRIVILEGE: u32 = 23;
+pub const SE_REMOTE_SHUTDOWN_PRIVILEGE: u32 = 24;
+pub const SE_UNDOCK_PRIVILEGE: u32 = 25;
+pub const SE_SYNC_AGENT_PRIVILEGE: u32 = 26;
+pub const SE_ENABLE_DELEGATION_PRIVILEGE: u32 = 27;
+pub const SE_MANAGE_VOLUME_PRIVILEGE: u32 = 28;
+pub const SE_IMPERSONATE_PRIVILEGE: u32 = 29;
+pub const SE_CREATE_GLOBAL_PRIVILEGE: u32 = 30;
+pub const SE_TRUSTED_CREDMAN_ACCESS_PRIVILEGE: u32 = 31;
+pub const SE_RELABEL_PRIVILEGE: u32 = 32;
+pub const SE_INC_WORKING_SET_PRIVILEGE: u32 = 33;
+pub const SE_TIME_ZONE_PRIVILEGE: u32 = 34;
+pub const SE_CREATE_SYMBOLIC_LINK_PRIVILEGE: u32 = 35;
+pub const SE_DELEGATE_SESSION_USER_IMPERSONATE_PRIVILEGE: u32 = 36;
+pub const SE_MAX_WELL_KNOWN_PRIVILEGE: u32 = 36;
 pub const FILE_DEVICE_BEEP: u32 = 1;
 pub const FILE_DEVICE_CD_ROM: u32 = 2;
 pub const FILE_DEVICE_CD_ROM_FILE_SYSTEM: u32 = 3;
@@ -535,6 +571,79 @@ pub const FILE_PIPE_COMPUTER_NAME_LENGTH: u32 = 15;
 pub const FILE_PIPE_SYMLINK_FLAG_GLOBAL: u32 = 1;
 pub const FILE_PIPE_SYMLINK_FLAG_RELATIVE: u32 = 2;
 pub const FILE_PIPE_SYMLINK_VALID_FLAGS: u32 = 3;
+pub const WNODE_FLAG_ALL_DATA: u32 = 1;
+pub const WNODE_FLAG_SINGLE_INSTANCE: u32 = 2;
+pub const WNODE_FLAG_SINGLE_ITEM: u32 = 4;
+pub const WNODE_FLAG_EVENT_ITEM: u32 = 8;
+pub const WNODE_FLAG_FIXED_INSTANCE_SIZE: u32 = 16;
+pub const WNODE_FLAG_TOO_SMALL: u32 = 32;
+pub const WNODE_FLAG_INSTANCES_SAME: u32 = 64;
+pub const WNODE_FLAG_STATIC_INSTANCE_NAMES: u32 = 128;
+pub const WNODE_FLAG_INTERNAL: u32 = 256;
+pub const WNODE_FLAG_USE_TIMESTAMP: u32 = 512;
+pub const WNODE_FLAG_PERSIST_EVENT: u32 = 1024;
+pub const WNODE_FLAG_EVENT_REFERENCE: u32 = 8192;
+pub const WNODE_FLAG_ANSI_INSTANCENAMES: u32 = 16384;
+pub const WNODE_FLAG_METHOD_ITEM: u32 = 32768;
+pub const WNODE_FLAG_PDO_INSTANCE_NAMES: u32 = 65536;
+pub const WMIGUID_QUERY: u32 = 1;
+pub const WMIGUID_SET: u32 = 2;
+pub const WMIGUID_NOTIFICATION: u32 = 4;
+pub const WMIGUID_READ_DESCRIPTION: u32 = 8;
+pub const WMIGUID_EXECUTE: u32 = 16;
+pub const GENERIC_READ: u32 = 2147483648;
+pub const GENERIC_WRITE: u32 = 1073741824;
+pub const GENERIC_EXECUTE: u32 = 536870912;
+pub const GENERIC_ALL: u32 = 268435456;
+pub const SE_PRIVILEGE_ENABLED: u32 = 2;
+pub const VER_EQUAL: u32 = 1;
+pub const VER_GREATER: u32 = 2;
+pub const VER_GREATER_EQUAL: u32 = 3;
+pub const VER_LESS: u32 = 4;
+pub const VER_LESS_EQUAL: u32 = 5;
+pub const VER_AND: u32 = 6;
+pub const VER_OR: u32 = 7;
+pub const VER_CONDITION_MASK: u32 = 7;
+pub const VER_NUM_BITS_PER_CONDITION_MASK: u32 = 3;
+pub const VER_MINORVERSION: u32 = 1;
+pub const VER_MAJORVERSION: u32 = 2;
+pub const VER_BUILDNUMBER: u32 = 4;
+pub const VER_PLATFORMID: u32 = 8;
+pub const VER_SERVICEPACKMINOR: u32 = 16;
+pub const VER_SERVICEPACKMAJOR: u32 = 32;
+pub const VER_SUITENAME: u32 = 64;
+pub const VER_PRODUCT_TYPE: u32 = 128;
+pub const VER_NT_WORKSTATION: u32 = 1;
+pub const VER_NT_DOMAIN_CONTROLLER: u32 = 2;
+pub const VER_NT_SERVER: u32 = 3;
+pub const OBJ_CASE_INSENSITIVE: u32 = 64;
+pub const PO_CB_SYSTEM_POWER_POLICY: u32 = 0;
+pub const PO_CB_AC_STATUS: u32 = 1;
+pub const PO_CB_BUTTON_COLLISION: u32 = 2;
+pub const PO_CB_SYSTEM_STATE_LOCK: u32 = 3;
+pub const PO_CB_LID_SWITCH_STATE: u32 = 4;
+pub const PO_CB_PROCESSOR_POWER_POLICY: u32 = 5;
+pub const MM_ANY_NODE_OK: u32 = 2147483648;
+pub const ALL_PROCESSOR_GROUPS: u32 = 65535;
+pub const LTP_PC_SMT: u32 = 1;
+pub const KEY_READ: u32 = 131097;
+pub const REG_NONE: u32 = 0;
+pub const REG_SZ: u32 = 1;
+pub const REG_EXPAND_SZ: u32 = 2;
+pub const REG_BINARY: u32 = 3;
+pub const REG_DWORD: u32 = 4;
+pub const REG_MULTI_SZ: u32 = 7;
+pub const REG_QWORD: u32 = 11;
+pub const REG_NOTIFY_CHANGE_NAME: u32 = 1;
+pub const REG_NOTIFY_CHANGE_LAST_SET: u32 = 4;
+pub const SECTION_QUERY: u32 = 1;
+pub const SECTION_MAP_WRITE: u32 = 2;
+pub const SECTION_MAP_READ: u32 = 4;
+pub const SEC_COMMIT: u32 = 134217728;
+pub const MdlMappingNoWrite: u32 = 2147483648;
+pub const MdlMappingNoExecute: u32 = 1073741824;
+pub const MDL_MAPPED_TO_SYSTEM_VA: u32 = 1;
+pub const MDL_SOURCE_IS_NONPAGED_POOL: u32 = 4;
 pub type wchar_t = ::libc::c_ushort;
 pub type LONG_PTR = ::libc::c_longlong;
 pub type ULONG_PTR = ::libc::c_ulonglong;
@@ -548,7 +657,6 @@ pub type LONG = ::libc::c_long;
 pub type WCHAR = wchar_t;
 pub type PWCH = *mut WCHAR;
 pub type PCHAR = *mut CHAR;
-pub type LPCSTR = *const CHAR;
 pub type PCSTR = *const CHAR;
 pub type UCHAR = ::libc::c_uchar;
 pub type USHORT = ::libc::c_ushort;
@@ -558,6 +666,15 @@ pub type CCHAR = ::libc::c_char;
 pub type CSHORT = ::libc::c_short;
 pub type LCID = ULONG;
 pub type NTSTATUS = LONG;
+pub type PHANDLE = *mut HANDLE;
+pub type PSIZE_T = *mut ULONG_PTR;
+pub type NODE_REQUIREMENT = ULONG;
+pub type DWORD = ::libc::c_ulong;
+pub type PUCHAR = *mut UCHAR;
+pub type ULONGLONG = ::libc::c_ulonglong;
+pub type ULONG64 = ::libc::c_ulonglong;
+pub type PULONG = *mut ULONG;
+pub type LPCGUID = *const GUID;
 pub type LONGLONG = ::libc::c_longlong;
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -647,6 +764,7 @@ pub struct _KTHREAD {
     _unused: [u8; 0],
 }
 pub type PETHREAD = *mut _KTHREAD;
+pub type PKTHREAD = *mut _KTHREAD;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _IO_TIMER {
@@ -736,7 +854,7 @@ pub union _IO_STATUS_BLOCK__bindgen_ty_1 {
 pub type IO_STATUS_BLOCK = _IO_STATUS_BLOCK;
 pub type PIO_STATUS_BLOCK = *mut _IO_STATUS_BLOCK;
 pub type PIO_APC_ROUTINE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         ApcContext: PVOID,
         IoStatusBlock: PIO_STATUS_BLOCK,
         Reserved: ULONG,
@@ -1319,10 +1437,10 @@ impl _INTERFACE_TYPE {
 pub struct _INTERFACE_TYPE(pub ::libc::c_int);
 pub use self::_INTERFACE_TYPE as INTERFACE_TYPE;
 pub type PINTERFACE_REFERENCE = ::core::option::Option<
-    unsafe extern "C" fn(Context: PVOID),
+    unsafe extern "system" fn(Context: PVOID),
 >;
 pub type PINTERFACE_DEREFERENCE = ::core::option::Option<
-    unsafe extern "C" fn(Context: PVOID),
+    unsafe extern "system" fn(Context: PVOID),
 >;
 impl _SYSTEM_POWER_STATE {
     pub const PowerSystemUnspecified: _SYSTEM_POWER_STATE = _SYSTEM_POWER_STATE(0);
@@ -2524,7 +2642,7 @@ pub struct _KAPC {
 }
 pub type KAPC = _KAPC;
 pub type KDEFERRED_ROUTINE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         Dpc: *mut _KDPC,
         DeferredContext: PVOID,
         SystemArgument1: PVOID,
@@ -3797,37 +3915,37 @@ pub struct _SCSI_REQUEST_BLOCK {
     _unused: [u8; 0],
 }
 pub type DRIVER_INITIALIZE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         DriverObject: *mut _DRIVER_OBJECT,
         RegistryPath: PUNICODE_STRING,
     ) -> NTSTATUS,
 >;
 pub type PDRIVER_INITIALIZE = DRIVER_INITIALIZE;
 pub type DRIVER_CANCEL = ::core::option::Option<
-    unsafe extern "C" fn(DeviceObject: *mut _DEVICE_OBJECT, Irp: *mut _IRP),
+    unsafe extern "system" fn(DeviceObject: *mut _DEVICE_OBJECT, Irp: *mut _IRP),
 >;
 pub type PDRIVER_CANCEL = DRIVER_CANCEL;
 pub type DRIVER_DISPATCH = ::core::option::Option<
-    unsafe extern "C" fn(DeviceObject: *mut _DEVICE_OBJECT, Irp: *mut _IRP) -> NTSTATUS,
+    unsafe extern "system" fn(DeviceObject: *mut _DEVICE_OBJECT, Irp: *mut _IRP) -> NTSTATUS,
 >;
 pub type PDRIVER_DISPATCH = DRIVER_DISPATCH;
 pub type DRIVER_STARTIO = ::core::option::Option<
-    unsafe extern "C" fn(DeviceObject: *mut _DEVICE_OBJECT, Irp: *mut _IRP),
+    unsafe extern "system" fn(DeviceObject: *mut _DEVICE_OBJECT, Irp: *mut _IRP),
 >;
 pub type PDRIVER_STARTIO = DRIVER_STARTIO;
 pub type DRIVER_UNLOAD = ::core::option::Option<
-    unsafe extern "C" fn(DriverObject: *mut _DRIVER_OBJECT),
+    unsafe extern "system" fn(DriverObject: *mut _DRIVER_OBJECT),
 >;
 pub type PDRIVER_UNLOAD = DRIVER_UNLOAD;
 pub type DRIVER_ADD_DEVICE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         DriverObject: *mut _DRIVER_OBJECT,
         PhysicalDeviceObject: *mut _DEVICE_OBJECT,
     ) -> NTSTATUS,
 >;
 pub type PDRIVER_ADD_DEVICE = DRIVER_ADD_DEVICE;
 pub type FAST_IO_CHECK_IF_POSSIBLE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -3840,7 +3958,7 @@ pub type FAST_IO_CHECK_IF_POSSIBLE = ::core::option::Option<
 >;
 pub type PFAST_IO_CHECK_IF_POSSIBLE = FAST_IO_CHECK_IF_POSSIBLE;
 pub type FAST_IO_READ = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -3853,7 +3971,7 @@ pub type FAST_IO_READ = ::core::option::Option<
 >;
 pub type PFAST_IO_READ = FAST_IO_READ;
 pub type FAST_IO_WRITE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -3866,7 +3984,7 @@ pub type FAST_IO_WRITE = ::core::option::Option<
 >;
 pub type PFAST_IO_WRITE = FAST_IO_WRITE;
 pub type FAST_IO_QUERY_BASIC_INFO = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         Wait: BOOLEAN,
         Buffer: PFILE_BASIC_INFORMATION,
@@ -3876,7 +3994,7 @@ pub type FAST_IO_QUERY_BASIC_INFO = ::core::option::Option<
 >;
 pub type PFAST_IO_QUERY_BASIC_INFO = FAST_IO_QUERY_BASIC_INFO;
 pub type FAST_IO_QUERY_STANDARD_INFO = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         Wait: BOOLEAN,
         Buffer: PFILE_STANDARD_INFORMATION,
@@ -3886,7 +4004,7 @@ pub type FAST_IO_QUERY_STANDARD_INFO = ::core::option::Option<
 >;
 pub type PFAST_IO_QUERY_STANDARD_INFO = FAST_IO_QUERY_STANDARD_INFO;
 pub type FAST_IO_LOCK = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: PLARGE_INTEGER,
@@ -3900,7 +4018,7 @@ pub type FAST_IO_LOCK = ::core::option::Option<
 >;
 pub type PFAST_IO_LOCK = FAST_IO_LOCK;
 pub type FAST_IO_UNLOCK_SINGLE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: PLARGE_INTEGER,
@@ -3912,7 +4030,7 @@ pub type FAST_IO_UNLOCK_SINGLE = ::core::option::Option<
 >;
 pub type PFAST_IO_UNLOCK_SINGLE = FAST_IO_UNLOCK_SINGLE;
 pub type FAST_IO_UNLOCK_ALL = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         ProcessId: PEPROCESS,
         IoStatus: PIO_STATUS_BLOCK,
@@ -3921,7 +4039,7 @@ pub type FAST_IO_UNLOCK_ALL = ::core::option::Option<
 >;
 pub type PFAST_IO_UNLOCK_ALL = FAST_IO_UNLOCK_ALL;
 pub type FAST_IO_UNLOCK_ALL_BY_KEY = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         ProcessId: PVOID,
         Key: ULONG,
@@ -3931,7 +4049,7 @@ pub type FAST_IO_UNLOCK_ALL_BY_KEY = ::core::option::Option<
 >;
 pub type PFAST_IO_UNLOCK_ALL_BY_KEY = FAST_IO_UNLOCK_ALL_BY_KEY;
 pub type FAST_IO_DEVICE_CONTROL = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         Wait: BOOLEAN,
         InputBuffer: PVOID,
@@ -3945,22 +4063,22 @@ pub type FAST_IO_DEVICE_CONTROL = ::core::option::Option<
 >;
 pub type PFAST_IO_DEVICE_CONTROL = FAST_IO_DEVICE_CONTROL;
 pub type FAST_IO_ACQUIRE_FILE = ::core::option::Option<
-    unsafe extern "C" fn(FileObject: *mut _FILE_OBJECT),
+    unsafe extern "system" fn(FileObject: *mut _FILE_OBJECT),
 >;
 pub type PFAST_IO_ACQUIRE_FILE = FAST_IO_ACQUIRE_FILE;
 pub type FAST_IO_RELEASE_FILE = ::core::option::Option<
-    unsafe extern "C" fn(FileObject: *mut _FILE_OBJECT),
+    unsafe extern "system" fn(FileObject: *mut _FILE_OBJECT),
 >;
 pub type PFAST_IO_RELEASE_FILE = FAST_IO_RELEASE_FILE;
 pub type FAST_IO_DETACH_DEVICE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         SourceDevice: *mut _DEVICE_OBJECT,
         TargetDevice: *mut _DEVICE_OBJECT,
     ),
 >;
 pub type PFAST_IO_DETACH_DEVICE = FAST_IO_DETACH_DEVICE;
 pub type FAST_IO_QUERY_NETWORK_OPEN_INFO = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         Wait: BOOLEAN,
         Buffer: *mut _FILE_NETWORK_OPEN_INFORMATION,
@@ -3970,7 +4088,7 @@ pub type FAST_IO_QUERY_NETWORK_OPEN_INFO = ::core::option::Option<
 >;
 pub type PFAST_IO_QUERY_NETWORK_OPEN_INFO = FAST_IO_QUERY_NETWORK_OPEN_INFO;
 pub type FAST_IO_MDL_READ = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -3982,7 +4100,7 @@ pub type FAST_IO_MDL_READ = ::core::option::Option<
 >;
 pub type PFAST_IO_MDL_READ = FAST_IO_MDL_READ;
 pub type FAST_IO_MDL_READ_COMPLETE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         MdlChain: PMDL,
         DeviceObject: *mut _DEVICE_OBJECT,
@@ -3990,7 +4108,7 @@ pub type FAST_IO_MDL_READ_COMPLETE = ::core::option::Option<
 >;
 pub type PFAST_IO_MDL_READ_COMPLETE = FAST_IO_MDL_READ_COMPLETE;
 pub type FAST_IO_PREPARE_MDL_WRITE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -4002,7 +4120,7 @@ pub type FAST_IO_PREPARE_MDL_WRITE = ::core::option::Option<
 >;
 pub type PFAST_IO_PREPARE_MDL_WRITE = FAST_IO_PREPARE_MDL_WRITE;
 pub type FAST_IO_MDL_WRITE_COMPLETE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         MdlChain: PMDL,
@@ -4011,7 +4129,7 @@ pub type FAST_IO_MDL_WRITE_COMPLETE = ::core::option::Option<
 >;
 pub type PFAST_IO_MDL_WRITE_COMPLETE = FAST_IO_MDL_WRITE_COMPLETE;
 pub type FAST_IO_ACQUIRE_FOR_MOD_WRITE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         EndingOffset: PLARGE_INTEGER,
         ResourceToRelease: *mut *mut _ERESOURCE,
@@ -4020,7 +4138,7 @@ pub type FAST_IO_ACQUIRE_FOR_MOD_WRITE = ::core::option::Option<
 >;
 pub type PFAST_IO_ACQUIRE_FOR_MOD_WRITE = FAST_IO_ACQUIRE_FOR_MOD_WRITE;
 pub type FAST_IO_RELEASE_FOR_MOD_WRITE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         ResourceToRelease: *mut _ERESOURCE,
         DeviceObject: *mut _DEVICE_OBJECT,
@@ -4028,14 +4146,14 @@ pub type FAST_IO_RELEASE_FOR_MOD_WRITE = ::core::option::Option<
 >;
 pub type PFAST_IO_RELEASE_FOR_MOD_WRITE = FAST_IO_RELEASE_FOR_MOD_WRITE;
 pub type FAST_IO_ACQUIRE_FOR_CCFLUSH = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         DeviceObject: *mut _DEVICE_OBJECT,
     ) -> NTSTATUS,
 >;
 pub type PFAST_IO_ACQUIRE_FOR_CCFLUSH = FAST_IO_ACQUIRE_FOR_CCFLUSH;
 pub type FAST_IO_RELEASE_FOR_CCFLUSH = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         DeviceObject: *mut _DEVICE_OBJECT,
     ) -> NTSTATUS,
@@ -4047,7 +4165,7 @@ pub struct _COMPRESSED_DATA_INFO {
     _unused: [u8; 0],
 }
 pub type FAST_IO_READ_COMPRESSED = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -4062,7 +4180,7 @@ pub type FAST_IO_READ_COMPRESSED = ::core::option::Option<
 >;
 pub type PFAST_IO_READ_COMPRESSED = FAST_IO_READ_COMPRESSED;
 pub type FAST_IO_WRITE_COMPRESSED = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         Length: ULONG,
@@ -4077,7 +4195,7 @@ pub type FAST_IO_WRITE_COMPRESSED = ::core::option::Option<
 >;
 pub type PFAST_IO_WRITE_COMPRESSED = FAST_IO_WRITE_COMPRESSED;
 pub type FAST_IO_MDL_READ_COMPLETE_COMPRESSED = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         MdlChain: PMDL,
         DeviceObject: *mut _DEVICE_OBJECT,
@@ -4085,7 +4203,7 @@ pub type FAST_IO_MDL_READ_COMPLETE_COMPRESSED = ::core::option::Option<
 >;
 pub type PFAST_IO_MDL_READ_COMPLETE_COMPRESSED = FAST_IO_MDL_READ_COMPLETE_COMPRESSED;
 pub type FAST_IO_MDL_WRITE_COMPLETE_COMPRESSED = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         FileObject: *mut _FILE_OBJECT,
         FileOffset: PLARGE_INTEGER,
         MdlChain: PMDL,
@@ -4094,7 +4212,7 @@ pub type FAST_IO_MDL_WRITE_COMPLETE_COMPRESSED = ::core::option::Option<
 >;
 pub type PFAST_IO_MDL_WRITE_COMPLETE_COMPRESSED = FAST_IO_MDL_WRITE_COMPLETE_COMPRESSED;
 pub type FAST_IO_QUERY_OPEN = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         Irp: *mut _IRP,
         NetworkInformation: PFILE_NETWORK_OPEN_INFORMATION,
         DeviceObject: *mut _DEVICE_OBJECT,
@@ -4150,7 +4268,7 @@ impl _IO_ALLOCATION_ACTION {
 pub struct _IO_ALLOCATION_ACTION(pub ::libc::c_int);
 pub use self::_IO_ALLOCATION_ACTION as IO_ALLOCATION_ACTION;
 pub type DRIVER_CONTROL = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         DeviceObject: *mut _DEVICE_OBJECT,
         Irp: *mut _IRP,
         MapRegisterBase: PVOID,
@@ -4573,7 +4691,7 @@ pub union _IRP__bindgen_ty_4__bindgen_ty_1__bindgen_ty_2__bindgen_ty_1 {
 pub type IRP = _IRP;
 pub type PIRP = *mut IRP;
 pub type IO_COMPLETION_ROUTINE = ::core::option::Option<
-    unsafe extern "C" fn(
+    unsafe extern "system" fn(
         DeviceObject: PDEVICE_OBJECT,
         Irp: PIRP,
         Context: PVOID,
@@ -5818,2210 +5936,1819 @@ extern "C" {
 extern "C" {
     pub static FILE_TYPE_NOTIFICATION_GUID_CRASHDUMP_FILE: GUID;
 }
-pub type WDFFUNC = ::core::option::Option<unsafe extern "C" fn()>;
 extern "C" {
-    pub static mut WdfFunctions_01015: *const WDFFUNC;
-}
-impl _WDF_TRI_STATE {
-    pub const WdfFalse: _WDF_TRI_STATE = _WDF_TRI_STATE(0);
-}
-impl _WDF_TRI_STATE {
-    pub const WdfTrue: _WDF_TRI_STATE = _WDF_TRI_STATE(1);
+    pub static SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_RW_RES_R: UNICODE_STRING;
 }
-impl _WDF_TRI_STATE {
-    pub const WdfUseDefault: _WDF_TRI_STATE = _WDF_TRI_STATE(2);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WNODE_HEADER {
+    pub BufferSize: ULONG,
+    pub ProviderId: ULONG,
+    pub __bindgen_anon_1: _WNODE_HEADER__bindgen_ty_1,
+    pub __bindgen_anon_2: _WNODE_HEADER__bindgen_ty_2,
+    pub Guid: GUID,
+    pub ClientContext: ULONG,
+    pub Flags: ULONG,
 }
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_TRI_STATE(pub ::libc::c_int);
-pub use self::_WDF_TRI_STATE as WDF_TRI_STATE;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct WDFDEVICE_INIT {
-    _unused: [u8; 0],
+#[derive(Copy, Clone)]
+pub union _WNODE_HEADER__bindgen_ty_1 {
+    pub HistoricalContext: ULONG64,
+    pub __bindgen_anon_1: _WNODE_HEADER__bindgen_ty_1__bindgen_ty_1,
 }
-pub type PWDFDEVICE_INIT = *mut WDFDEVICE_INIT;
-pub type PWDF_OBJECT_ATTRIBUTES = *mut _WDF_OBJECT_ATTRIBUTES;
-pub type WDFOBJECT = HANDLE;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct WDFDRIVER__ {
-    pub unused: ::libc::c_int,
+pub struct _WNODE_HEADER__bindgen_ty_1__bindgen_ty_1 {
+    pub Version: ULONG,
+    pub Linkage: ULONG,
 }
-pub type WDFDRIVER = *mut WDFDRIVER__;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct WDFDEVICE__ {
-    pub unused: ::libc::c_int,
+#[derive(Copy, Clone)]
+pub union _WNODE_HEADER__bindgen_ty_2 {
+    pub CountLost: ULONG,
+    pub KernelHandle: HANDLE,
+    pub TimeStamp: LARGE_INTEGER,
 }
-pub type WDFDEVICE = *mut WDFDEVICE__;
+pub type WNODE_HEADER = _WNODE_HEADER;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct WDFQUEUE__ {
-    pub unused: ::libc::c_int,
+pub struct OFFSETINSTANCEDATAANDLENGTH {
+    pub OffsetInstanceData: ULONG,
+    pub LengthInstanceData: ULONG,
 }
-pub type WDFQUEUE = *mut WDFQUEUE__;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct WDFREQUEST__ {
-    pub unused: ::libc::c_int,
+#[derive(Copy, Clone)]
+pub struct tagWNODE_ALL_DATA {
+    pub WnodeHeader: _WNODE_HEADER,
+    pub DataBlockOffset: ULONG,
+    pub InstanceCount: ULONG,
+    pub OffsetInstanceNameOffsets: ULONG,
+    pub __bindgen_anon_1: tagWNODE_ALL_DATA__bindgen_ty_1,
 }
-pub type WDFREQUEST = *mut WDFREQUEST__;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct WDFFILEOBJECT__ {
-    pub unused: ::libc::c_int,
+#[derive(Copy, Clone)]
+pub union tagWNODE_ALL_DATA__bindgen_ty_1 {
+    pub FixedInstanceSize: ULONG,
+    pub OffsetInstanceDataAndLength: [OFFSETINSTANCEDATAANDLENGTH; 1usize],
 }
-pub type WDFFILEOBJECT = *mut WDFFILEOBJECT__;
+pub type WNODE_ALL_DATA = tagWNODE_ALL_DATA;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _WDF_DRIVER_GLOBALS {
-    pub Driver: WDFDRIVER,
-    pub DriverFlags: ULONG,
-    pub DriverTag: ULONG,
-    pub DriverName: [CHAR; 32usize],
-    pub DisplaceDriverUnload: BOOLEAN,
+#[derive(Copy, Clone)]
+pub struct tagWNODE_TOO_SMALL {
+    pub WnodeHeader: _WNODE_HEADER,
+    pub SizeNeeded: ULONG,
 }
-pub type PWDF_DRIVER_GLOBALS = *mut _WDF_DRIVER_GLOBALS;
+pub type WNODE_TOO_SMALL = tagWNODE_TOO_SMALL;
 extern "C" {
-    pub static mut WdfDriverGlobals: PWDF_DRIVER_GLOBALS;
+    pub fn ObfDereferenceObject(Object: PVOID) -> LONG_PTR;
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(0);
-}
-impl _WDFFUNCENUM {
-    pub const WdfChildListGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(1);
+extern "C" {
+    pub fn IoWMIOpenBlock(
+        Guid: LPCGUID,
+        DesiredAccess: ULONG,
+        DataBlockObject: *mut PVOID,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListRetrievePdoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(2);
+extern "C" {
+    pub fn IoWMIQueryAllData(
+        DataBlockObject: PVOID,
+        InOutBufferSize: PULONG,
+        OutBuffer: PVOID,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListRetrieveAddressDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        3,
-    );
+impl _POOL_TYPE {
+    pub const NonPagedPool: _POOL_TYPE = _POOL_TYPE(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListBeginScanTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(4);
+impl _POOL_TYPE {
+    pub const NonPagedPoolExecute: _POOL_TYPE = _POOL_TYPE(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListEndScanTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(5);
+impl _POOL_TYPE {
+    pub const PagedPool: _POOL_TYPE = _POOL_TYPE(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListBeginIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(6);
+impl _POOL_TYPE {
+    pub const NonPagedPoolMustSucceed: _POOL_TYPE = _POOL_TYPE(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListRetrieveNextDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(7);
+impl _POOL_TYPE {
+    pub const DontUseThisType: _POOL_TYPE = _POOL_TYPE(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListEndIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(8);
+impl _POOL_TYPE {
+    pub const NonPagedPoolCacheAligned: _POOL_TYPE = _POOL_TYPE(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListAddOrUpdateChildDescriptionAsPresentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        9,
-    );
+impl _POOL_TYPE {
+    pub const PagedPoolCacheAligned: _POOL_TYPE = _POOL_TYPE(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListUpdateChildDescriptionAsMissingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        10,
-    );
+impl _POOL_TYPE {
+    pub const NonPagedPoolCacheAlignedMustS: _POOL_TYPE = _POOL_TYPE(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListUpdateAllChildDescriptionsAsPresentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        11,
-    );
+impl _POOL_TYPE {
+    pub const MaxPoolType: _POOL_TYPE = _POOL_TYPE(7);
 }
-impl _WDFFUNCENUM {
-    pub const WdfChildListRequestChildEjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(12);
+impl _POOL_TYPE {
+    pub const NonPagedPoolBase: _POOL_TYPE = _POOL_TYPE(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(13);
+impl _POOL_TYPE {
+    pub const NonPagedPoolBaseMustSucceed: _POOL_TYPE = _POOL_TYPE(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(14);
+impl _POOL_TYPE {
+    pub const NonPagedPoolBaseCacheAligned: _POOL_TYPE = _POOL_TYPE(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionAddTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(15);
+impl _POOL_TYPE {
+    pub const NonPagedPoolBaseCacheAlignedMustS: _POOL_TYPE = _POOL_TYPE(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(16);
+impl _POOL_TYPE {
+    pub const NonPagedPoolSession: _POOL_TYPE = _POOL_TYPE(32);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionRemoveItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(17);
+impl _POOL_TYPE {
+    pub const PagedPoolSession: _POOL_TYPE = _POOL_TYPE(33);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionGetItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(18);
+impl _POOL_TYPE {
+    pub const NonPagedPoolMustSucceedSession: _POOL_TYPE = _POOL_TYPE(34);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionGetFirstItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(19);
+impl _POOL_TYPE {
+    pub const DontUseThisTypeSession: _POOL_TYPE = _POOL_TYPE(35);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCollectionGetLastItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(20);
+impl _POOL_TYPE {
+    pub const NonPagedPoolCacheAlignedSession: _POOL_TYPE = _POOL_TYPE(36);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCommonBufferCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(21);
+impl _POOL_TYPE {
+    pub const PagedPoolCacheAlignedSession: _POOL_TYPE = _POOL_TYPE(37);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCommonBufferGetAlignedVirtualAddressTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        22,
-    );
+impl _POOL_TYPE {
+    pub const NonPagedPoolCacheAlignedMustSSession: _POOL_TYPE = _POOL_TYPE(38);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCommonBufferGetAlignedLogicalAddressTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        23,
-    );
+impl _POOL_TYPE {
+    pub const NonPagedPoolNx: _POOL_TYPE = _POOL_TYPE(512);
 }
-impl _WDFFUNCENUM {
-    pub const WdfCommonBufferGetLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(24);
+impl _POOL_TYPE {
+    pub const NonPagedPoolNxCacheAligned: _POOL_TYPE = _POOL_TYPE(516);
 }
-impl _WDFFUNCENUM {
-    pub const WdfControlDeviceInitAllocateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(25);
+impl _POOL_TYPE {
+    pub const NonPagedPoolSessionNx: _POOL_TYPE = _POOL_TYPE(544);
 }
-impl _WDFFUNCENUM {
-    pub const WdfControlDeviceInitSetShutdownNotificationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        26,
-    );
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _POOL_TYPE(pub ::libc::c_int);
+pub use self::_POOL_TYPE as POOL_TYPE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _EX_RUNDOWN_REF {
+    pub __bindgen_anon_1: _EX_RUNDOWN_REF__bindgen_ty_1,
 }
-impl _WDFFUNCENUM {
-    pub const WdfControlFinishInitializingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(27);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _EX_RUNDOWN_REF__bindgen_ty_1 {
+    pub Count: ULONG_PTR,
+    pub Ptr: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetDeviceStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(28);
+pub type EX_RUNDOWN_REF = _EX_RUNDOWN_REF;
+pub type PEX_RUNDOWN_REF = *mut _EX_RUNDOWN_REF;
+extern "C" {
+    pub fn ExInitializeRundownProtection(RunRef: PEX_RUNDOWN_REF);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetDeviceStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(29);
+extern "C" {
+    pub fn ExAcquireRundownProtection(RunRef: PEX_RUNDOWN_REF) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfWdmDeviceGetWdfDeviceHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(30);
+extern "C" {
+    pub fn ExReleaseRundownProtection(RunRef: PEX_RUNDOWN_REF);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmGetDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(31);
+extern "C" {
+    pub fn ExWaitForRundownProtectionRelease(RunRef: PEX_RUNDOWN_REF);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmGetAttachedDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(32);
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct _SLIST_ENTRY {
+    pub Next: *mut _SLIST_ENTRY,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmGetPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(33);
+pub type SLIST_ENTRY = _SLIST_ENTRY;
+pub type PSLIST_ENTRY = *mut _SLIST_ENTRY;
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Copy, Clone)]
+pub union _SLIST_HEADER {
+    pub __bindgen_anon_1: _SLIST_HEADER__bindgen_ty_1,
+    pub HeaderX64: _SLIST_HEADER__bindgen_ty_2,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmDispatchPreprocessedIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        34,
-    );
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SLIST_HEADER__bindgen_ty_1 {
+    pub Alignment: ULONGLONG,
+    pub Region: ULONGLONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAddDependentUsageDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        35,
-    );
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Copy, Clone)]
+pub struct _SLIST_HEADER__bindgen_ty_2 {
+    pub _bitfield_align_1: [u64; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 16usize]>,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAddRemovalRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        36,
-    );
+impl _SLIST_HEADER__bindgen_ty_2 {
+    #[inline]
+    pub fn Depth(&self) -> ULONGLONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 16u8) as u64) }
+    }
+    #[inline]
+    pub fn set_Depth(&mut self, val: ULONGLONG) {
+        unsafe {
+            let val: u64 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 16u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn Sequence(&self) -> ULONGLONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(16usize, 48u8) as u64) }
+    }
+    #[inline]
+    pub fn set_Sequence(&mut self, val: ULONGLONG) {
+        unsafe {
+            let val: u64 = ::core::mem::transmute(val);
+            self._bitfield_1.set(16usize, 48u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn Reserved(&self) -> ULONGLONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(64usize, 4u8) as u64) }
+    }
+    #[inline]
+    pub fn set_Reserved(&mut self, val: ULONGLONG) {
+        unsafe {
+            let val: u64 = ::core::mem::transmute(val);
+            self._bitfield_1.set(64usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn NextEntry(&self) -> ULONGLONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(68usize, 60u8) as u64) }
+    }
+    #[inline]
+    pub fn set_NextEntry(&mut self, val: ULONGLONG) {
+        unsafe {
+            let val: u64 = ::core::mem::transmute(val);
+            self._bitfield_1.set(68usize, 60u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        Depth: ULONGLONG,
+        Sequence: ULONGLONG,
+        Reserved: ULONGLONG,
+        NextEntry: ULONGLONG,
+    ) -> __BindgenBitfieldUnit<[u8; 16usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 16usize]> = Default::default();
+        __bindgen_bitfield_unit
+            .set(
+                0usize,
+                16u8,
+                {
+                    let Depth: u64 = unsafe { ::core::mem::transmute(Depth) };
+                    Depth as u64
+                },
+            );
+        __bindgen_bitfield_unit
+            .set(
+                16usize,
+                48u8,
+                {
+                    let Sequence: u64 = unsafe { ::core::mem::transmute(Sequence) };
+                    Sequence as u64
+                },
+            );
+        __bindgen_bitfield_unit
+            .set(
+                64usize,
+                4u8,
+                {
+                    let Reserved: u64 = unsafe { ::core::mem::transmute(Reserved) };
+                    Reserved as u64
+                },
+            );
+        __bindgen_bitfield_unit
+            .set(
+                68usize,
+                60u8,
+                {
+                    let NextEntry: u64 = unsafe { ::core::mem::transmute(NextEntry) };
+                    NextEntry as u64
+                },
+            );
+        __bindgen_bitfield_unit
+    }
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceRemoveRemovalRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        37,
-    );
+pub type SLIST_HEADER = _SLIST_HEADER;
+pub type PSLIST_HEADER = *mut _SLIST_HEADER;
+extern "C" {
+    pub fn ExpInterlockedPushEntrySList(
+        ListHead: PSLIST_HEADER,
+        ListEntry: PSLIST_ENTRY,
+    ) -> PSLIST_ENTRY;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceClearRemovalRelationsDevicesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        38,
-    );
+extern "C" {
+    pub fn ExpInterlockedPopEntrySList(ListHead: PSLIST_HEADER) -> PSLIST_ENTRY;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetDriverTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(39);
+extern "C" {
+    pub fn ExpInterlockedFlushSList(ListHead: PSLIST_HEADER) -> PSLIST_ENTRY;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceRetrieveDeviceNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(40);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _ULARGE_INTEGER {
+    pub __bindgen_anon_1: _ULARGE_INTEGER__bindgen_ty_1,
+    pub u: _ULARGE_INTEGER__bindgen_ty_2,
+    pub QuadPart: ULONGLONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAssignMofResourceNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(41);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ULARGE_INTEGER__bindgen_ty_1 {
+    pub LowPart: ULONG,
+    pub HighPart: ULONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetIoTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(42);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ULARGE_INTEGER__bindgen_ty_2 {
+    pub LowPart: ULONG,
+    pub HighPart: ULONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetDevicePnpStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(43);
+pub type ULARGE_INTEGER = _ULARGE_INTEGER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _KSEMAPHORE {
+    pub Header: DISPATCHER_HEADER,
+    pub Limit: LONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetDevicePowerStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(44);
+pub type KSEMAPHORE = _KSEMAPHORE;
+pub type PKSEMAPHORE = *mut _KSEMAPHORE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _KTIMER {
+    pub Header: DISPATCHER_HEADER,
+    pub DueTime: ULARGE_INTEGER,
+    pub TimerListEntry: LIST_ENTRY,
+    pub Dpc: *mut _KDPC,
+    pub Processor: ULONG,
+    pub Period: ULONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetDevicePowerPolicyStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        45,
-    );
+pub type KTIMER = _KTIMER;
+pub type PKTIMER = *mut _KTIMER;
+impl _KWAIT_REASON {
+    pub const Executive: _KWAIT_REASON = _KWAIT_REASON(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAssignS0IdleSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(46);
+impl _KWAIT_REASON {
+    pub const FreePage: _KWAIT_REASON = _KWAIT_REASON(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAssignSxWakeSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(47);
+impl _KWAIT_REASON {
+    pub const PageIn: _KWAIT_REASON = _KWAIT_REASON(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceOpenRegistryKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(48);
+impl _KWAIT_REASON {
+    pub const PoolAllocation: _KWAIT_REASON = _KWAIT_REASON(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetSpecialFileSupportTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(49);
+impl _KWAIT_REASON {
+    pub const DelayExecution: _KWAIT_REASON = _KWAIT_REASON(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetCharacteristicsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(50);
+impl _KWAIT_REASON {
+    pub const Suspended: _KWAIT_REASON = _KWAIT_REASON(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetCharacteristicsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(51);
+impl _KWAIT_REASON {
+    pub const UserRequest: _KWAIT_REASON = _KWAIT_REASON(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetAlignmentRequirementTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        52,
-    );
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _KWAIT_REASON(pub ::libc::c_int);
+pub use self::_KWAIT_REASON as KWAIT_REASON;
+extern "C" {
+    pub fn KeWaitForSingleObject(
+        Object: PVOID,
+        WaitReason: KWAIT_REASON,
+        WaitMode: KPROCESSOR_MODE,
+        Alertable: BOOLEAN,
+        Timeout: PLARGE_INTEGER,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetAlignmentRequirementTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        53,
-    );
+extern "C" {
+    pub fn KeQueryPerformanceCounter(PerformanceFrequency: PLARGE_INTEGER) -> LARGE_INTEGER;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitFreeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(54);
+extern "C" {
+    pub fn KeQuerySystemTimePrecise(CurrentTime: PLARGE_INTEGER);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetPnpPowerEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        55,
-    );
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PROCESSOR_NUMBER {
+    pub Group: USHORT,
+    pub Number: UCHAR,
+    pub Reserved: UCHAR,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetPowerPolicyEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        56,
-    );
+pub type PROCESSOR_NUMBER = _PROCESSOR_NUMBER;
+pub type PPROCESSOR_NUMBER = *mut _PROCESSOR_NUMBER;
+extern "C" {
+    pub fn KeQueryActiveProcessorCountEx(GroupNumber: USHORT) -> ULONG;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetPowerPolicyOwnershipTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        57,
-    );
+extern "C" {
+    pub fn KeGetCurrentProcessorNumberEx(ProcNumber: PPROCESSOR_NUMBER) -> ULONG;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitRegisterPnpStateChangeCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        58,
-    );
+extern "C" {
+    pub fn KeQueryMaximumProcessorCountEx(GroupNumber: USHORT) -> ULONG;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitRegisterPowerStateChangeCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        59,
-    );
+extern "C" {
+    pub fn KeQueryActiveGroupCount() -> USHORT;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitRegisterPowerPolicyStateChangeCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        60,
-    );
+extern "C" {
+    pub fn KeQueryMaximumGroupCount() -> USHORT;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetIoTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(61);
+extern "C" {
+    pub fn KeQueryGroupAffinity(GroupNumber: USHORT) -> KAFFINITY;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetExclusiveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(62);
+extern "C" {
+    pub fn KeQueryHighestNodeNumber() -> USHORT;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetPowerNotPageableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        63,
-    );
+pub type BYTE = ::libc::c_uchar;
+pub type WORD = ::libc::c_ushort;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _GROUP_AFFINITY {
+    pub Mask: KAFFINITY,
+    pub Group: WORD,
+    pub Reserved: [WORD; 3usize],
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetPowerPageableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(64);
+pub type GROUP_AFFINITY = _GROUP_AFFINITY;
+pub type PGROUP_AFFINITY = *mut _GROUP_AFFINITY;
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationProcessorCore: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetPowerInrushTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(65);
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationNumaNode: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetDeviceTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(66);
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationCache: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitAssignNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(67);
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationProcessorPackage: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitAssignSDDLStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(68);
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationGroup: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetDeviceClassTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(69);
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationProcessorDie: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetCharacteristicsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(70);
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationNumaNodeEx: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetFileObjectConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        71,
-    );
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationProcessorModule: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(7);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetRequestAttributesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        72,
-    );
+impl _LOGICAL_PROCESSOR_RELATIONSHIP {
+    pub const RelationAll: _LOGICAL_PROCESSOR_RELATIONSHIP = _LOGICAL_PROCESSOR_RELATIONSHIP(65535);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitAssignWdmIrpPreprocessCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        73,
-    );
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _LOGICAL_PROCESSOR_RELATIONSHIP(pub ::libc::c_int);
+pub use self::_LOGICAL_PROCESSOR_RELATIONSHIP as LOGICAL_PROCESSOR_RELATIONSHIP;
+impl _PROCESSOR_CACHE_TYPE {
+    pub const CacheUnified: _PROCESSOR_CACHE_TYPE = _PROCESSOR_CACHE_TYPE(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetIoInCallerContextCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        74,
-    );
+impl _PROCESSOR_CACHE_TYPE {
+    pub const CacheInstruction: _PROCESSOR_CACHE_TYPE = _PROCESSOR_CACHE_TYPE(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(75);
+impl _PROCESSOR_CACHE_TYPE {
+    pub const CacheData: _PROCESSOR_CACHE_TYPE = _PROCESSOR_CACHE_TYPE(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetStaticStopRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(76);
+impl _PROCESSOR_CACHE_TYPE {
+    pub const CacheTrace: _PROCESSOR_CACHE_TYPE = _PROCESSOR_CACHE_TYPE(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceCreateDeviceInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(77);
+impl _PROCESSOR_CACHE_TYPE {
+    pub const CacheUnknown: _PROCESSOR_CACHE_TYPE = _PROCESSOR_CACHE_TYPE(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetDeviceInterfaceStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        78,
-    );
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _PROCESSOR_CACHE_TYPE(pub ::libc::c_int);
+pub use self::_PROCESSOR_CACHE_TYPE as PROCESSOR_CACHE_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PROCESSOR_RELATIONSHIP {
+    pub Flags: BYTE,
+    pub EfficiencyClass: BYTE,
+    pub Reserved: [BYTE; 20usize],
+    pub GroupCount: WORD,
+    pub GroupMask: [GROUP_AFFINITY; 1usize],
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceRetrieveDeviceInterfaceStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        79,
-    );
+pub type PROCESSOR_RELATIONSHIP = _PROCESSOR_RELATIONSHIP;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _NUMA_NODE_RELATIONSHIP {
+    pub NodeNumber: DWORD,
+    pub Reserved: [BYTE; 18usize],
+    pub GroupCount: WORD,
+    pub __bindgen_anon_1: _NUMA_NODE_RELATIONSHIP__bindgen_ty_1,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceCreateSymbolicLinkTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(80);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _NUMA_NODE_RELATIONSHIP__bindgen_ty_1 {
+    pub GroupMask: GROUP_AFFINITY,
+    pub GroupMasks: [GROUP_AFFINITY; 1usize],
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(81);
+pub type NUMA_NODE_RELATIONSHIP = _NUMA_NODE_RELATIONSHIP;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CACHE_RELATIONSHIP {
+    pub Level: BYTE,
+    pub Associativity: BYTE,
+    pub LineSize: WORD,
+    pub CacheSize: DWORD,
+    pub Type: PROCESSOR_CACHE_TYPE,
+    pub Reserved: [BYTE; 18usize],
+    pub GroupCount: WORD,
+    pub __bindgen_anon_1: _CACHE_RELATIONSHIP__bindgen_ty_1,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAllocAndQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(82);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _CACHE_RELATIONSHIP__bindgen_ty_1 {
+    pub GroupMask: GROUP_AFFINITY,
+    pub GroupMasks: [GROUP_AFFINITY; 1usize],
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetPnpCapabilitiesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(83);
+pub type CACHE_RELATIONSHIP = _CACHE_RELATIONSHIP;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PROCESSOR_GROUP_INFO {
+    pub MaximumProcessorCount: BYTE,
+    pub ActiveProcessorCount: BYTE,
+    pub Reserved: [BYTE; 38usize],
+    pub ActiveProcessorMask: KAFFINITY,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetPowerCapabilitiesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(84);
+pub type PROCESSOR_GROUP_INFO = _PROCESSOR_GROUP_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _GROUP_RELATIONSHIP {
+    pub MaximumGroupCount: WORD,
+    pub ActiveGroupCount: WORD,
+    pub Reserved: [BYTE; 20usize],
+    pub GroupInfo: [PROCESSOR_GROUP_INFO; 1usize],
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetBusInformationForChildrenTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        85,
-    );
+pub type GROUP_RELATIONSHIP = _GROUP_RELATIONSHIP;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX {
+    pub Relationship: LOGICAL_PROCESSOR_RELATIONSHIP,
+    pub Size: DWORD,
+    pub __bindgen_anon_1: _SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX__bindgen_ty_1,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceIndicateWakeStatusTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(86);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX__bindgen_ty_1 {
+    pub Processor: PROCESSOR_RELATIONSHIP,
+    pub NumaNode: NUMA_NODE_RELATIONSHIP,
+    pub Cache: CACHE_RELATIONSHIP,
+    pub Group: GROUP_RELATIONSHIP,
+}
+pub type SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX = _SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX;
+pub type PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX = *mut _SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX;
+extern "C" {
+    pub fn KeQueryLogicalProcessorRelationship(
+        ProcessorNumber: PPROCESSOR_NUMBER,
+        RelationshipType: LOGICAL_PROCESSOR_RELATIONSHIP,
+        Information: PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+        Length: PULONG,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceSetFailedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(87);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackInvalid: _KBUGCHECK_CALLBACK_REASON = _KBUGCHECK_CALLBACK_REASON(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceStopIdleNoTrackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(88);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackReserved1: _KBUGCHECK_CALLBACK_REASON = _KBUGCHECK_CALLBACK_REASON(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceResumeIdleNoTrackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(89);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackSecondaryDumpData: _KBUGCHECK_CALLBACK_REASON =
+        _KBUGCHECK_CALLBACK_REASON(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(90);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackDumpIo: _KBUGCHECK_CALLBACK_REASON = _KBUGCHECK_CALLBACK_REASON(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceEnqueueRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(91);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackAddPages: _KBUGCHECK_CALLBACK_REASON = _KBUGCHECK_CALLBACK_REASON(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetDefaultQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(92);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackSecondaryMultiPartDumpData: _KBUGCHECK_CALLBACK_REASON =
+        _KBUGCHECK_CALLBACK_REASON(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceConfigureRequestDispatchingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        93,
-    );
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackRemovePages: _KBUGCHECK_CALLBACK_REASON = _KBUGCHECK_CALLBACK_REASON(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(94);
+impl _KBUGCHECK_CALLBACK_REASON {
+    pub const KbCallbackTriageDumpData: _KBUGCHECK_CALLBACK_REASON = _KBUGCHECK_CALLBACK_REASON(7);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerGetMaximumLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(95);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _KBUGCHECK_CALLBACK_REASON(pub ::libc::c_int);
+pub use self::_KBUGCHECK_CALLBACK_REASON as KBUGCHECK_CALLBACK_REASON;
+pub type KBUGCHECK_REASON_CALLBACK_ROUTINE = ::core::option::Option<
+    unsafe extern "system" fn(
+        Reason: KBUGCHECK_CALLBACK_REASON,
+        Record: *mut _KBUGCHECK_REASON_CALLBACK_RECORD,
+        ReasonSpecificData: PVOID,
+        ReasonSpecificDataLength: ULONG,
+    ),
+>;
+pub type PKBUGCHECK_REASON_CALLBACK_ROUTINE = KBUGCHECK_REASON_CALLBACK_ROUTINE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _KBUGCHECK_REASON_CALLBACK_RECORD {
+    pub Entry: LIST_ENTRY,
+    pub CallbackRoutine: PKBUGCHECK_REASON_CALLBACK_ROUTINE,
+    pub Component: PUCHAR,
+    pub Checksum: ULONG_PTR,
+    pub Reason: KBUGCHECK_CALLBACK_REASON,
+    pub State: UCHAR,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerGetMaximumScatterGatherElementsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        96,
-    );
+pub type KBUGCHECK_REASON_CALLBACK_RECORD = _KBUGCHECK_REASON_CALLBACK_RECORD;
+pub type PKBUGCHECK_REASON_CALLBACK_RECORD = *mut _KBUGCHECK_REASON_CALLBACK_RECORD;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _KBUGCHECK_SECONDARY_DUMP_DATA {
+    pub InBuffer: PVOID,
+    pub InBufferLength: ULONG,
+    pub MaximumAllowed: ULONG,
+    pub Guid: GUID,
+    pub OutBuffer: PVOID,
+    pub OutBufferLength: ULONG,
+}
+pub type KBUGCHECK_SECONDARY_DUMP_DATA = _KBUGCHECK_SECONDARY_DUMP_DATA;
+pub type PKBUGCHECK_SECONDARY_DUMP_DATA = *mut _KBUGCHECK_SECONDARY_DUMP_DATA;
+extern "C" {
+    pub fn KeRegisterBugCheckReasonCallback(
+        CallbackRecord: PKBUGCHECK_REASON_CALLBACK_RECORD,
+        CallbackRoutine: PKBUGCHECK_REASON_CALLBACK_ROUTINE,
+        Reason: KBUGCHECK_CALLBACK_REASON,
+        Component: PUCHAR,
+    ) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerSetMaximumScatterGatherElementsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        97,
-    );
+extern "C" {
+    pub fn KeDeregisterBugCheckReasonCallback(
+        CallbackRecord: PKBUGCHECK_REASON_CALLBACK_RECORD,
+    ) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(98);
+pub type KBUGCHECK_CALLBACK_ROUTINE =
+    ::core::option::Option<unsafe extern "system" fn(Buffer: PVOID, Length: ULONG)>;
+pub type PKBUGCHECK_CALLBACK_ROUTINE = KBUGCHECK_CALLBACK_ROUTINE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _KBUGCHECK_CALLBACK_RECORD {
+    pub Entry: LIST_ENTRY,
+    pub CallbackRoutine: PKBUGCHECK_CALLBACK_ROUTINE,
+    pub Buffer: PVOID,
+    pub Length: ULONG,
+    pub Component: PUCHAR,
+    pub Checksum: ULONG_PTR,
+    pub State: UCHAR,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionInitializeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(99);
+pub type KBUGCHECK_CALLBACK_RECORD = _KBUGCHECK_CALLBACK_RECORD;
+pub type PKBUGCHECK_CALLBACK_RECORD = *mut _KBUGCHECK_CALLBACK_RECORD;
+extern "C" {
+    pub fn KeRegisterBugCheckCallback(
+        CallbackRecord: PKBUGCHECK_CALLBACK_RECORD,
+        CallbackRoutine: PKBUGCHECK_CALLBACK_ROUTINE,
+        Buffer: PVOID,
+        Length: ULONG,
+        Component: PUCHAR,
+    ) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionInitializeUsingRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        100,
-    );
+extern "C" {
+    pub fn KeDeregisterBugCheckCallback(CallbackRecord: PKBUGCHECK_CALLBACK_RECORD) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionExecuteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(101);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenUser: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionReleaseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(102);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenGroups: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionDmaCompletedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(103);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenPrivileges: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionDmaCompletedWithLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        104,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenOwner: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionDmaCompletedFinalTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        105,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenPrimaryGroup: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionGetBytesTransferredTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        106,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenDefaultDacl: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionSetMaximumLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        107,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenSource: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(7);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionGetRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(108);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenType: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(8);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionGetCurrentDmaTransferLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        109,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenImpersonationLevel: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(9);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(110);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenStatistics: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(10);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDpcCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(111);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenRestrictedSids: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(11);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDpcEnqueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(112);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenSessionId: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(12);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDpcCancelTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(113);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenGroupsAndPrivileges: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(13);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDpcGetParentObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(114);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenSessionReference: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(14);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDpcWdmGetDpcTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(115);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenSandBoxInert: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(15);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(116);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenAuditPolicy: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(16);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverGetRegistryPathTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(117);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenOrigin: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(17);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverWdmGetDriverObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(118);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenElevationType: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(18);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverOpenParametersRegistryKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        119,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenLinkedToken: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(19);
 }
-impl _WDFFUNCENUM {
-    pub const WdfWdmDriverGetWdfDriverHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(120);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenElevation: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(20);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverRegisterTraceInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(121);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenHasRestrictions: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(21);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverRetrieveVersionStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(122);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenAccessInformation: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(22);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverIsVersionAvailableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(123);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenVirtualizationAllowed: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(23);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitWdmGetPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(124);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenVirtualizationEnabled: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(24);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitOpenRegistryKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(125);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenIntegrityLevel: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(25);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(126);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenUIAccess: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(26);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitAllocAndQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        127,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenMandatoryPolicy: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(27);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitSetEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(128);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenLogonSid: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(28);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitSetFilterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(129);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenIsAppContainer: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(29);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitSetDefaultChildListConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        130,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenCapabilities: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(30);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoQueryForInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(131);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenAppContainerSid: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(31);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoGetDefaultChildListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(132);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenAppContainerNumber: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(32);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoAddStaticChildTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(133);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenUserClaimAttributes: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(33);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoLockStaticChildListForIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        134,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenDeviceClaimAttributes: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(34);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoRetrieveNextStaticChildTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(135);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenRestrictedUserClaimAttributes: _TOKEN_INFORMATION_CLASS =
+        _TOKEN_INFORMATION_CLASS(35);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFdoUnlockStaticChildListFromIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        136,
-    );
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenRestrictedDeviceClaimAttributes: _TOKEN_INFORMATION_CLASS =
+        _TOKEN_INFORMATION_CLASS(36);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFileObjectGetFileNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(137);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenDeviceGroups: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(37);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFileObjectGetFlagsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(138);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenRestrictedDeviceGroups: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(38);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFileObjectGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(139);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenSecurityAttributes: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(39);
 }
-impl _WDFFUNCENUM {
-    pub const WdfFileObjectWdmGetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(140);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenIsRestricted: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(40);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(141);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenProcessTrustLevel: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(41);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptQueueDpcForIsrTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(142);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenPrivateNameSpace: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(42);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptSynchronizeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(143);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenSingletonAttributes: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(43);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptAcquireLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(144);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenBnoIsolation: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(44);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptReleaseLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(145);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenChildProcessFlags: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(45);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptEnableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(146);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenIsLessPrivilegedAppContainer: _TOKEN_INFORMATION_CLASS =
+        _TOKEN_INFORMATION_CLASS(46);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptDisableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(147);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenIsSandboxed: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(47);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptWdmGetInterruptTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(148);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenIsAppSilo: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(48);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptGetInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(149);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const TokenLoggingInformation: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(49);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptSetPolicyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(150);
+impl _TOKEN_INFORMATION_CLASS {
+    pub const MaxTokenInfoClass: _TOKEN_INFORMATION_CLASS = _TOKEN_INFORMATION_CLASS(50);
 }
-impl _WDFFUNCENUM {
-    pub const WdfInterruptGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(151);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _TOKEN_INFORMATION_CLASS(pub ::libc::c_int);
+pub use self::_TOKEN_INFORMATION_CLASS as TOKEN_INFORMATION_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_PRIVILEGES {
+    pub PrivilegeCount: DWORD,
+    pub Privileges: [LUID_AND_ATTRIBUTES; 1usize],
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(152);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueGetStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(153);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueStartTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(154);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueStopTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(155);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueStopSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(156);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(157);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueRetrieveNextRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(158);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueRetrieveRequestByFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        159,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueFindRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(160);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueRetrieveFoundRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(161);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueDrainSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(162);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueDrainTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(163);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueuePurgeSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(164);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueuePurgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(165);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueReadyNotifyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(166);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(167);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetOpenTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(168);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetCloseForQueryRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(169);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetCloseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(170);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetStartTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(171);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetStopTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(172);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetGetStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(173);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(174);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetQueryTargetPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(175);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetAllocAndQueryTargetPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        176,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetQueryForInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(177);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetWdmGetTargetDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        178,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetWdmGetTargetPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        179,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetWdmGetTargetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        180,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetWdmGetTargetFileHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        181,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetSendReadSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        182,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetFormatRequestForReadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        183,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetSendWriteSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        184,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetFormatRequestForWriteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        185,
-    );
+pub type TOKEN_PRIVILEGES = _TOKEN_PRIVILEGES;
+pub type PTOKEN_PRIVILEGES = *mut _TOKEN_PRIVILEGES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_ELEVATION {
+    pub TokenIsElevated: DWORD,
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetSendIoctlSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        186,
-    );
+pub type TOKEN_ELEVATION = _TOKEN_ELEVATION;
+pub type PTOKEN_ELEVATION = *mut _TOKEN_ELEVATION;
+extern "C" {
+    pub fn ExFreePoolWithTag(P: PVOID, Tag: ULONG);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetFormatRequestForIoctlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        187,
-    );
+extern "C" {
+    pub fn IoGetRequestorProcess(Irp: PIRP) -> PEPROCESS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetSendInternalIoctlSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        188,
-    );
+extern "C" {
+    pub fn PsReferencePrimaryToken(Process: PEPROCESS) -> PACCESS_TOKEN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetFormatRequestForInternalIoctlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        189,
-    );
+extern "C" {
+    pub fn SeQueryInformationToken(
+        Token: PACCESS_TOKEN,
+        TokenInformationClass: TOKEN_INFORMATION_CLASS,
+        TokenInformation: *mut PVOID,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetSendInternalIoctlOthersSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        190,
-    );
+extern "C" {
+    pub fn SeTokenIsAdmin(Token: PACCESS_TOKEN) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetFormatRequestForInternalIoctlOthersTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        191,
-    );
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OSVERSIONINFOW {
+    pub dwOSVersionInfoSize: ULONG,
+    pub dwMajorVersion: ULONG,
+    pub dwMinorVersion: ULONG,
+    pub dwBuildNumber: ULONG,
+    pub dwPlatformId: ULONG,
+    pub szCSDVersion: [WCHAR; 128usize],
+}
+pub type OSVERSIONINFOW = _OSVERSIONINFOW;
+pub type POSVERSIONINFOW = *mut _OSVERSIONINFOW;
+pub type LPOSVERSIONINFOW = *mut _OSVERSIONINFOW;
+pub type RTL_OSVERSIONINFOW = _OSVERSIONINFOW;
+pub type PRTL_OSVERSIONINFOW = *mut _OSVERSIONINFOW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OSVERSIONINFOEXW {
+    pub dwOSVersionInfoSize: ULONG,
+    pub dwMajorVersion: ULONG,
+    pub dwMinorVersion: ULONG,
+    pub dwBuildNumber: ULONG,
+    pub dwPlatformId: ULONG,
+    pub szCSDVersion: [WCHAR; 128usize],
+    pub wServicePackMajor: USHORT,
+    pub wServicePackMinor: USHORT,
+    pub wSuiteMask: USHORT,
+    pub wProductType: UCHAR,
+    pub wReserved: UCHAR,
+}
+pub type OSVERSIONINFOEXW = _OSVERSIONINFOEXW;
+pub type POSVERSIONINFOEXW = *mut _OSVERSIONINFOEXW;
+pub type LPOSVERSIONINFOEXW = *mut _OSVERSIONINFOEXW;
+pub type RTL_OSVERSIONINFOEXW = _OSVERSIONINFOEXW;
+pub type PRTL_OSVERSIONINFOEXW = *mut _OSVERSIONINFOEXW;
+extern "C" {
+    pub fn RtlGetVersion(lpVersionInformation: PRTL_OSVERSIONINFOW) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(192);
+extern "C" {
+    pub fn RtlVerifyVersionInfo(
+        VersionInfo: PRTL_OSVERSIONINFOEXW,
+        TypeMask: ULONG,
+        ConditionMask: ULONGLONG,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryCreatePreallocatedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(193);
+extern "C" {
+    pub fn VerSetConditionMask(
+        ConditionMask: ULONGLONG,
+        TypeMask: ULONG,
+        Condition: UCHAR,
+    ) -> ULONGLONG;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryGetBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(194);
+extern "C" {
+    pub fn MmGetSystemRoutineAddress(SystemRoutineName: PUNICODE_STRING) -> PVOID;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryAssignBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(195);
+pub type PCALLBACK_OBJECT = *mut _CALLBACK_OBJECT;
+pub type CALLBACK_FUNCTION = ::core::option::Option<
+    unsafe extern "system" fn(CallbackContext: PVOID, Argument1: PVOID, Argument2: PVOID),
+>;
+pub type PCALLBACK_FUNCTION = CALLBACK_FUNCTION;
+extern "C" {
+    pub fn ExCreateCallback(
+        CallbackObject: *mut PCALLBACK_OBJECT,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        Create: BOOLEAN,
+        AllowMultipleCallbacks: BOOLEAN,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryCopyToBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(196);
+extern "C" {
+    pub fn ExRegisterCallback(
+        CallbackObject: PCALLBACK_OBJECT,
+        CallbackFunction: PCALLBACK_FUNCTION,
+        CallbackContext: PVOID,
+    ) -> PVOID;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryCopyFromBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(197);
+extern "C" {
+    pub fn ExUnregisterCallback(CallbackRegistration: PVOID);
 }
-impl _WDFFUNCENUM {
-    pub const WdfLookasideListCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(198);
+pub type POWER_SETTING_CALLBACK = ::core::option::Option<
+    unsafe extern "system" fn(
+        SettingGuid: LPCGUID,
+        Value: PVOID,
+        ValueLength: ULONG,
+        Context: PVOID,
+    ) -> NTSTATUS,
+>;
+pub type PPOWER_SETTING_CALLBACK = POWER_SETTING_CALLBACK;
+extern "C" {
+    pub fn PoRegisterPowerSettingCallback(
+        DeviceObject: PDEVICE_OBJECT,
+        SettingGuid: LPCGUID,
+        Callback: PPOWER_SETTING_CALLBACK,
+        Context: PVOID,
+        Handle: *mut PVOID,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfMemoryCreateFromLookasideTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(199);
+extern "C" {
+    pub fn PoUnregisterPowerSettingCallback(Handle: PVOID) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceMiniportCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(200);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _KFLOATING_SAVE {
+    pub Dummy: ULONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfDriverMiniportUnloadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(201);
+pub type KFLOATING_SAVE = _KFLOATING_SAVE;
+pub type PKFLOATING_SAVE = *mut _KFLOATING_SAVE;
+extern "C" {
+    pub fn RtlEqualUnicodeString(
+        String1: PCUNICODE_STRING,
+        String2: PCUNICODE_STRING,
+        CaseInSensitive: BOOLEAN,
+    ) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectGetTypedContextWorkerTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(202);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmNonCached: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectAllocateContextTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(203);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmCached: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectContextGetObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(204);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmWriteCombined: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectReferenceActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(205);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmHardwareCoherentCached: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectDereferenceActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(206);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmNonCachedUnordered: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(207);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmUSWCCached: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectDeleteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(208);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmMaximumCacheType: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectQueryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(209);
+impl _MEMORY_CACHING_TYPE {
+    pub const MmNotMapped: _MEMORY_CACHING_TYPE = _MEMORY_CACHING_TYPE(-1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAllocateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(210);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _MEMORY_CACHING_TYPE(pub ::libc::c_int);
+pub use self::_MEMORY_CACHING_TYPE as MEMORY_CACHING_TYPE;
+extern "C" {
+    pub fn MmAllocateContiguousMemorySpecifyCacheNode(
+        NumberOfBytes: SIZE_T,
+        LowestAcceptableAddress: PHYSICAL_ADDRESS,
+        HighestAcceptableAddress: PHYSICAL_ADDRESS,
+        BoundaryAddressMultiple: PHYSICAL_ADDRESS,
+        CacheType: MEMORY_CACHING_TYPE,
+        PreferredNode: NODE_REQUIREMENT,
+    ) -> PVOID;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitSetEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(211);
+extern "C" {
+    pub fn MmFreeContiguousMemory(BaseAddress: PVOID);
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAssignDeviceIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(212);
+extern "C" {
+    pub fn MmGetPhysicalAddress(BaseAddress: PVOID) -> PHYSICAL_ADDRESS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAssignInstanceIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(213);
+pub type POBJECT_TYPE = *mut _OBJECT_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OBJECT_HANDLE_INFORMATION {
+    pub HandleAttributes: ULONG,
+    pub GrantedAccess: ACCESS_MASK,
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAddHardwareIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(214);
+pub type OBJECT_HANDLE_INFORMATION = _OBJECT_HANDLE_INFORMATION;
+pub type POBJECT_HANDLE_INFORMATION = *mut _OBJECT_HANDLE_INFORMATION;
+impl _SECTION_INHERIT {
+    pub const ViewShare: _SECTION_INHERIT = _SECTION_INHERIT(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAddCompatibleIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(215);
+impl _SECTION_INHERIT {
+    pub const ViewUnmap: _SECTION_INHERIT = _SECTION_INHERIT(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAddDeviceTextTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(216);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _SECTION_INHERIT(pub ::libc::c_int);
+pub use self::_SECTION_INHERIT as SECTION_INHERIT;
+extern "C" {
+    pub static mut MmSectionObjectType: *mut POBJECT_TYPE;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitSetDefaultLocaleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(217);
+extern "C" {
+    pub fn ZwClose(Handle: HANDLE) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAssignRawDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(218);
+extern "C" {
+    pub fn ZwCreateSection(
+        SectionHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        MaximumSize: PLARGE_INTEGER,
+        SectionPageProtection: ULONG,
+        AllocationAttributes: ULONG,
+        FileHandle: HANDLE,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoMarkMissingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(219);
+extern "C" {
+    pub fn ZwMapViewOfSection(
+        SectionHandle: HANDLE,
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        ZeroBits: ULONG_PTR,
+        CommitSize: SIZE_T,
+        SectionOffset: PLARGE_INTEGER,
+        ViewSize: PSIZE_T,
+        InheritDisposition: SECTION_INHERIT,
+        AllocationType: ULONG,
+        Win32Protect: ULONG,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoRequestEjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(220);
+extern "C" {
+    pub fn ZwUnmapViewOfSection(ProcessHandle: HANDLE, BaseAddress: PVOID) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoGetParentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(221);
+extern "C" {
+    pub fn ObReferenceObjectByHandle(
+        Handle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectType: POBJECT_TYPE,
+        AccessMode: KPROCESSOR_MODE,
+        Object: *mut PVOID,
+        HandleInformation: POBJECT_HANDLE_INFORMATION,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoRetrieveIdentificationDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        222,
-    );
+extern "C" {
+    pub fn ObOpenObjectByPointer(
+        Object: PVOID,
+        HandleAttributes: ULONG,
+        PassedAccessState: PACCESS_STATE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectType: POBJECT_TYPE,
+        AccessMode: KPROCESSOR_MODE,
+        Handle: PHANDLE,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoRetrieveAddressDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        223,
-    );
+extern "C" {
+    pub fn MmMapViewInSystemSpace(
+        Section: PVOID,
+        MappedBase: *mut PVOID,
+        ViewSize: PSIZE_T,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoUpdateAddressDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(224);
+extern "C" {
+    pub fn MmUnmapViewInSystemSpace(MappedBase: PVOID) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoAddEjectionRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        225,
-    );
+impl _MM_PAGE_PRIORITY {
+    pub const LowPagePriority: _MM_PAGE_PRIORITY = _MM_PAGE_PRIORITY(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoRemoveEjectionRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        226,
-    );
+impl _MM_PAGE_PRIORITY {
+    pub const NormalPagePriority: _MM_PAGE_PRIORITY = _MM_PAGE_PRIORITY(16);
 }
-impl _WDFFUNCENUM {
-    pub const WdfPdoClearEjectionRelationsDevicesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        227,
-    );
+impl _MM_PAGE_PRIORITY {
+    pub const HighPagePriority: _MM_PAGE_PRIORITY = _MM_PAGE_PRIORITY(32);
 }
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAddQueryInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(228);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _MM_PAGE_PRIORITY(pub ::libc::c_int);
+pub use self::_MM_PAGE_PRIORITY as MM_PAGE_PRIORITY;
+extern "C" {
+    pub fn MmMapLockedPagesSpecifyCache(
+        MemoryDescriptorList: PMDL,
+        AccessMode: KPROCESSOR_MODE,
+        CacheType: MEMORY_CACHING_TYPE,
+        RequestedAddress: PVOID,
+        BugCheckOnFailure: ULONG,
+        Priority: ULONG,
+    ) -> PVOID;
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryOpenKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(229);
+extern "C" {
+    pub fn MmUnmapLockedPages(BaseAddress: PVOID, MemoryDescriptorList: PMDL);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryCreateKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(230);
+extern "C" {
+    pub fn ZwNotifyChangeKey(
+        KeyHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        CompletionFilter: ULONG,
+        WatchTree: BOOLEAN,
+        Buffer: PVOID,
+        BufferSize: ULONG,
+        Asynchronous: BOOLEAN,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryCloseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(231);
+impl _WORK_QUEUE_TYPE {
+    pub const CriticalWorkQueue: _WORK_QUEUE_TYPE = _WORK_QUEUE_TYPE(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryWdmGetHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(232);
+impl _WORK_QUEUE_TYPE {
+    pub const DelayedWorkQueue: _WORK_QUEUE_TYPE = _WORK_QUEUE_TYPE(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryRemoveKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(233);
+impl _WORK_QUEUE_TYPE {
+    pub const HyperCriticalWorkQueue: _WORK_QUEUE_TYPE = _WORK_QUEUE_TYPE(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryRemoveValueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(234);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WORK_QUEUE_TYPE(pub ::libc::c_int);
+pub use self::_WORK_QUEUE_TYPE as WORK_QUEUE_TYPE;
+pub type WORKER_THREAD_ROUTINE = ::core::option::Option<
+    unsafe extern "system" fn(Parameter: PVOID),
+>;
+pub type PWORKER_THREAD_ROUTINE = WORKER_THREAD_ROUTINE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WORK_QUEUE_ITEM {
+    pub List: LIST_ENTRY,
+    pub WorkerRoutine: PWORKER_THREAD_ROUTINE,
+    pub Parameter: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryQueryValueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(235);
+pub type WORK_QUEUE_ITEM = _WORK_QUEUE_ITEM;
+pub type PWORK_QUEUE_ITEM = *mut _WORK_QUEUE_ITEM;
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreDeleteKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryQueryMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(236);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreSetValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryQueryMultiStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(237);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreDeleteValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryQueryUnicodeStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(238);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreSetInformationKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryQueryStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(239);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreRenameKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryQueryULongTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(240);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreEnumerateKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(5);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryAssignValueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(241);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreEnumerateValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryAssignMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(242);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreQueryKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(7);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryAssignMultiStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(243);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreQueryValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(8);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryAssignUnicodeStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(244);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreQueryMultipleValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(9);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryAssignStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(245);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreCreateKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(10);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRegistryAssignULongTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(246);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostCreateKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(11);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(247);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreOpenKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(12);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestCreateFromIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(248);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostOpenKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(13);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestReuseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(249);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreKeyHandleClose: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(14);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestChangeTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(250);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostDeleteKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(15);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestFormatRequestUsingCurrentTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        251,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostSetValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(16);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestWdmFormatUsingStackLocationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        252,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostDeleteValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(17);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestSendTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(253);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostSetInformationKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(18);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetStatusTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(254);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostRenameKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(19);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestMarkCancelableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(255);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostEnumerateKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(20);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestUnmarkCancelableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(256);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostEnumerateValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(21);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestIsCanceledTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(257);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostQueryKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(22);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestCancelSentRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(258);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostQueryValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(23);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestIsFrom32BitProcessTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(259);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostQueryMultipleValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(24);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestSetCompletionRoutineTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(260);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostKeyHandleClose: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(25);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetCompletionParamsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(261);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreCreateKeyEx: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(26);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestAllocateTimerTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(262);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostCreateKeyEx: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(27);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestCompleteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(263);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreOpenKeyEx: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(28);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestCompleteWithPriorityBoostTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        264,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostOpenKeyEx: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(29);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestCompleteWithInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        265,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreFlushKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(30);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetParametersTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(266);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostFlushKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(31);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveInputMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(267);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreLoadKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(32);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveOutputMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(268);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostLoadKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(33);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveInputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(269);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreUnLoadKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(34);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveOutputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(270);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostUnLoadKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(35);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveInputWdmMdlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(271);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreQueryKeySecurity: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(36);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveOutputWdmMdlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(272);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostQueryKeySecurity: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(37);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveUnsafeUserInputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        273,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreSetKeySecurity: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(38);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRetrieveUnsafeUserOutputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        274,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostSetKeySecurity: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(39);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestSetInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(275);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtCallbackObjectContextCleanup: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(40);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(276);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreRestoreKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(41);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(277);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostRestoreKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(42);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestProbeAndLockUserBufferForReadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        278,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreSaveKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(43);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestProbeAndLockUserBufferForWriteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        279,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostSaveKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(44);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetRequestorModeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(280);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreReplaceKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(45);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestForwardToIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(281);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostReplaceKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(46);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestGetIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(282);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPreQueryKeyName: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(47);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestRequeueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(283);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtPostQueryKeyName: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(48);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestStopAcknowledgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(284);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtDeleteKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfRequestWdmGetIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(285);
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtSetValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListSetSlotNumberTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        286,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtDeleteValueKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(2);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListSetInterfaceTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        287,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtSetInformationKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(3);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListAppendIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        288,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtRenameKey: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListInsertIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        289,
-    );
+impl _REG_NOTIFY_CLASS {
+    pub const RegNtKeyHandleClose: _REG_NOTIFY_CLASS = _REG_NOTIFY_CLASS(14);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        290,
-    );
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _REG_NOTIFY_CLASS(pub ::libc::c_int);
+pub use self::_REG_NOTIFY_CLASS as REG_NOTIFY_CLASS;
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeyWriteTimeInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(0);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListGetIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        291,
-    );
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeyWow64FlagsInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(1);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        292,
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeyControlFlagsInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(
+        2,
     );
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceRequirementsListRemoveByIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        293,
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeySetVirtualizationInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(
+        3,
     );
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(294);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListAppendDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        295,
-    );
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeySetDebugInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(4);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListInsertDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        296,
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeySetHandleTagsInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(
+        5,
     );
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListUpdateDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        297,
-    );
+impl _KEY_SET_INFORMATION_CLASS {
+    pub const KeySetLayerInformation: _KEY_SET_INFORMATION_CLASS = _KEY_SET_INFORMATION_CLASS(6);
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(298);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _KEY_SET_INFORMATION_CLASS(pub ::libc::c_int);
+pub use self::_KEY_SET_INFORMATION_CLASS as KEY_SET_INFORMATION_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_DELETE_KEY_INFORMATION {
+    pub Object: PVOID,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListGetDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(299);
+pub type REG_DELETE_KEY_INFORMATION = _REG_DELETE_KEY_INFORMATION;
+pub type PREG_DELETE_KEY_INFORMATION = *mut _REG_DELETE_KEY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_SET_VALUE_KEY_INFORMATION {
+    pub Object: PVOID,
+    pub ValueName: PUNICODE_STRING,
+    pub TitleIndex: ULONG,
+    pub Type: ULONG,
+    pub Data: PVOID,
+    pub DataSize: ULONG,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(300);
+pub type REG_SET_VALUE_KEY_INFORMATION = _REG_SET_VALUE_KEY_INFORMATION;
+pub type PREG_SET_VALUE_KEY_INFORMATION = *mut _REG_SET_VALUE_KEY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_DELETE_VALUE_KEY_INFORMATION {
+    pub Object: PVOID,
+    pub ValueName: PUNICODE_STRING,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfIoResourceListRemoveByDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        301,
-    );
+pub type REG_DELETE_VALUE_KEY_INFORMATION = _REG_DELETE_VALUE_KEY_INFORMATION;
+pub type PREG_DELETE_VALUE_KEY_INFORMATION = *mut _REG_DELETE_VALUE_KEY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_SET_INFORMATION_KEY_INFORMATION {
+    pub Object: PVOID,
+    pub KeySetInformationClass: KEY_SET_INFORMATION_CLASS,
+    pub KeySetInformation: PVOID,
+    pub KeySetInformationLength: ULONG,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfCmResourceListAppendDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        302,
-    );
+pub type REG_SET_INFORMATION_KEY_INFORMATION = _REG_SET_INFORMATION_KEY_INFORMATION;
+pub type PREG_SET_INFORMATION_KEY_INFORMATION = *mut _REG_SET_INFORMATION_KEY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_RENAME_KEY_INFORMATION {
+    pub Object: PVOID,
+    pub NewName: PUNICODE_STRING,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfCmResourceListInsertDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        303,
-    );
+pub type REG_RENAME_KEY_INFORMATION = _REG_RENAME_KEY_INFORMATION;
+pub type PREG_RENAME_KEY_INFORMATION = *mut _REG_RENAME_KEY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_CREATE_KEY_INFORMATION {
+    pub CompleteName: PUNICODE_STRING,
+    pub RootObject: PVOID,
+    pub ObjectType: PVOID,
+    pub CreateOptions: ULONG,
+    pub Class: PUNICODE_STRING,
+    pub SecurityDescriptor: PVOID,
+    pub SecurityQualityOfService: PVOID,
+    pub DesiredAccess: ACCESS_MASK,
+    pub GrantedAccess: ACCESS_MASK,
+    pub Disposition: PULONG,
+    pub ResultObject: *mut PVOID,
+    pub CallContext: PVOID,
+    pub RootObjectContext: PVOID,
+    pub Transaction: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfCmResourceListGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(304);
+pub type REG_CREATE_KEY_INFORMATION = _REG_CREATE_KEY_INFORMATION;
+pub type PREG_CREATE_KEY_INFORMATION = *mut _REG_CREATE_KEY_INFORMATION;
+pub type REG_OPEN_KEY_INFORMATION = _REG_CREATE_KEY_INFORMATION;
+pub type PREG_OPEN_KEY_INFORMATION = *mut _REG_CREATE_KEY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_SET_KEY_SECURITY_INFORMATION {
+    pub Object: PVOID,
+    pub SecurityInformation: *mut SECURITY_INFORMATION,
+    pub SecurityDescriptor: PSECURITY_DESCRIPTOR,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfCmResourceListGetDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(305);
+pub type REG_SET_KEY_SECURITY_INFORMATION = _REG_SET_KEY_SECURITY_INFORMATION;
+pub type PREG_SET_KEY_SECURITY_INFORMATION = *mut _REG_SET_KEY_SECURITY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REG_POST_OPERATION_INFORMATION {
+    pub Object: PVOID,
+    pub Status: NTSTATUS,
+    pub PreInformation: PVOID,
+    pub ReturnStatus: NTSTATUS,
+    pub CallContext: PVOID,
+    pub ObjectContext: PVOID,
+    pub Reserved: PVOID,
 }
-impl _WDFFUNCENUM {
-    pub const WdfCmResourceListRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(306);
+pub type REG_POST_OPERATION_INFORMATION = _REG_POST_OPERATION_INFORMATION;
+pub type PREG_POST_OPERATION_INFORMATION = *mut _REG_POST_OPERATION_INFORMATION;
+pub type EX_CALLBACK_FUNCTION = ::core::option::Option<
+    unsafe extern "system" fn(
+        CallbackContext: PVOID,
+        Argument1: PVOID,
+        Argument2: PVOID,
+    ) -> NTSTATUS,
+>;
+pub type PEX_CALLBACK_FUNCTION = EX_CALLBACK_FUNCTION;
+extern "C" {
+    pub fn CmRegisterCallbackEx(
+        Function: PEX_CALLBACK_FUNCTION,
+        Altitude: PCUNICODE_STRING,
+        Driver: PVOID,
+        Context: PVOID,
+        Cookie: PLARGE_INTEGER,
+        Reserved: PVOID,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfCmResourceListRemoveByDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        307,
-    );
+extern "C" {
+    pub fn CmUnRegisterCallback(Cookie: LARGE_INTEGER) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfStringCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(308);
+extern "C" {
+    pub fn CmCallbackGetKeyObjectIDEx(
+        Cookie: PLARGE_INTEGER,
+        Object: PVOID,
+        ObjectID: *mut ULONG_PTR,
+        ObjectName: *mut PCUNICODE_STRING,
+        Flags: ULONG,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfStringGetUnicodeStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(309);
+extern "C" {
+    pub fn CmCallbackReleaseKeyObjectIDEx(ObjectName: PCUNICODE_STRING);
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectAcquireLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(310);
+extern "C" {
+    pub fn RtlPrefixUnicodeString(
+        String1: PCUNICODE_STRING,
+        String2: PCUNICODE_STRING,
+        CaseInSensitive: BOOLEAN,
+    ) -> BOOLEAN;
 }
-impl _WDFFUNCENUM {
-    pub const WdfObjectReleaseLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(311);
+extern "C" {
+    pub fn ExGetPreviousMode() -> KPROCESSOR_MODE;
 }
-impl _WDFFUNCENUM {
-    pub const WdfWaitLockCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(312);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CLIENT_ID {
+    pub UniqueProcess: HANDLE,
+    pub UniqueThread: HANDLE,
 }
-impl _WDFFUNCENUM {
-    pub const WdfWaitLockAcquireTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(313);
+pub type CLIENT_ID = _CLIENT_ID;
+pub type PCLIENT_ID = *mut _CLIENT_ID;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _PS_CREATE_NOTIFY_INFO {
+    pub Size: SIZE_T,
+    pub __bindgen_anon_1: _PS_CREATE_NOTIFY_INFO__bindgen_ty_1,
+    pub ParentProcessId: HANDLE,
+    pub CreatingThreadId: CLIENT_ID,
+    pub FileObject: *mut _FILE_OBJECT,
+    pub ImageFileName: PCUNICODE_STRING,
+    pub CommandLine: PCUNICODE_STRING,
+    pub CreationStatus: NTSTATUS,
 }
-impl _WDFFUNCENUM {
-    pub const WdfWaitLockReleaseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(314);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _PS_CREATE_NOTIFY_INFO__bindgen_ty_1 {
+    pub Flags: ULONG,
+    pub __bindgen_anon_1: _PS_CREATE_NOTIFY_INFO__bindgen_ty_1__bindgen_ty_1,
 }
-impl _WDFFUNCENUM {
-    pub const WdfSpinLockCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(315);
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct _PS_CREATE_NOTIFY_INFO__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
 }
-impl _WDFFUNCENUM {
-    pub const WdfSpinLockAcquireTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(316);
+impl _PS_CREATE_NOTIFY_INFO__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub fn FileOpenNameAvailable(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_FileOpenNameAvailable(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn IsSubsystemProcess(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_IsSubsystemProcess(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn Reserved(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 30u8) as u32) }
+    }
+    #[inline]
+    pub fn set_Reserved(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(2usize, 30u8, val as u64)
+        }
+    }
 }
-impl _WDFFUNCENUM {
-    pub const WdfSpinLockReleaseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(317);
+pub type PS_CREATE_NOTIFY_INFO = _PS_CREATE_NOTIFY_INFO;
+pub type PPS_CREATE_NOTIFY_INFO = *mut _PS_CREATE_NOTIFY_INFO;
+pub type CREATE_PROCESS_NOTIFY_ROUTINE_EX = ::core::option::Option<
+    unsafe extern "system" fn(
+        Process: PEPROCESS,
+        ProcessId: HANDLE,
+        CreateInfo: PPS_CREATE_NOTIFY_INFO,
+    ),
+>;
+pub type PCREATE_PROCESS_NOTIFY_ROUTINE_EX = CREATE_PROCESS_NOTIFY_ROUTINE_EX;
+pub type CREATE_THREAD_NOTIFY_ROUTINE = ::core::option::Option<
+    unsafe extern "system" fn(ProcessId: HANDLE, ThreadId: HANDLE, Create: BOOLEAN),
+>;
+pub type PCREATE_THREAD_NOTIFY_ROUTINE = CREATE_THREAD_NOTIFY_ROUTINE;
+extern "C" {
+    pub fn PsSetCreateProcessNotifyRoutineEx(
+        NotifyRoutine: PCREATE_PROCESS_NOTIFY_ROUTINE_EX,
+        Remove: BOOLEAN,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfTimerCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(318);
+extern "C" {
+    pub fn PsSetCreateThreadNotifyRoutine(NotifyRoutine: PCREATE_THREAD_NOTIFY_ROUTINE) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfTimerStartTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(319);
+extern "C" {
+    pub fn PsRemoveCreateThreadNotifyRoutine(
+        NotifyRoutine: PCREATE_THREAD_NOTIFY_ROUTINE,
+    ) -> NTSTATUS;
 }
-impl _WDFFUNCENUM {
-    pub const WdfTimerStopTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(320);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _IMAGE_INFO {
+    pub __bindgen_anon_1: _IMAGE_INFO__bindgen_ty_1,
+    pub ImageBase: PVOID,
+    pub ImageSelector: ULONG,
+    pub ImageSize: SIZE_T,
+    pub ImageSectionNumber: ULONG,
 }
-impl _WDFFUNCENUM {
-    pub const WdfTimerGetParentObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(321);
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _IMAGE_INFO__bindgen_ty_1 {
+    pub Properties: ULONG,
+    pub __bindgen_anon_1: _IMAGE_INFO__bindgen_ty_1__bindgen_ty_1,
 }
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(322);
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct _IMAGE_INFO__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
 }
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceRetrieveInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        323,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceGetDeviceDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        324,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceRetrieveConfigDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        325,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceQueryStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(326);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceAllocAndQueryStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        327,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceFormatRequestForStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        328,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceGetNumInterfacesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        329,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceSelectConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(330);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceWdmGetConfigurationHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        331,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceRetrieveCurrentFrameNumberTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        332,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceSendControlTransferSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        333,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceFormatRequestForControlTransferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        334,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceIsConnectedSynchronousTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        335,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceResetPortSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        336,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceCyclePortSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        337,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceFormatRequestForCyclePortTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        338,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceSendUrbSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        339,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceFormatRequestForUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        340,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeGetInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(341);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeIsInEndpointTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(342);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeIsOutEndpointTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(343);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeGetTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(344);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeSetNoMaximumPacketSizeCheckTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        345,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeWriteSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        346,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeFormatRequestForWriteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        347,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeReadSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        348,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeFormatRequestForReadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        349,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeConfigContinuousReaderTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        350,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeAbortSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        351,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeFormatRequestForAbortTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        352,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeResetSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        353,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeFormatRequestForResetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        354,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeSendUrbSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        355,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeFormatRequestForUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        356,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetInterfaceNumberTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        357,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetNumEndpointsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(358);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(359);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceSelectSettingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(360);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetEndpointInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        361,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceGetInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(362);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetConfiguredSettingIndexTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        363,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetNumConfiguredPipesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        364,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetConfiguredPipeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        365,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetPipeWdmGetPipeHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        366,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfVerifierDbgBreakPointTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(367);
-}
-impl _WDFFUNCENUM {
-    pub const WdfVerifierKeBugCheckTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(368);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiProviderCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(369);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiProviderGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(370);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiProviderIsEnabledTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(371);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiProviderGetTracingHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(372);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiInstanceCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(373);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiInstanceRegisterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(374);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiInstanceDeregisterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(375);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiInstanceGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(376);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiInstanceGetProviderTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(377);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWmiInstanceFireEventTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(378);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWorkItemCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(379);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWorkItemEnqueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(380);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWorkItemGetParentObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(381);
-}
-impl _WDFFUNCENUM {
-    pub const WdfWorkItemFlushTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(382);
-}
-impl _WDFFUNCENUM {
-    pub const WdfCommonBufferCreateWithConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        383,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerGetFragmentLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(384);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerWdmGetDmaAdapterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(385);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbInterfaceGetNumSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(386);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceRemoveDependentUsageDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        387,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetSystemPowerActionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(388);
-}
-impl _WDFFUNCENUM {
-    pub const WdfInterruptSetExtendedPolicyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(389);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueAssignForwardProgressPolicyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        390,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAssignContainerIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(391);
-}
-impl _WDFFUNCENUM {
-    pub const WdfPdoInitAllowForwardingRequestToParentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        392,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfRequestMarkCancelableExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(393);
-}
-impl _WDFFUNCENUM {
-    pub const WdfRequestIsReservedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(394);
-}
-impl _WDFFUNCENUM {
-    pub const WdfRequestForwardToParentDeviceIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        395,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfCxDeviceInitAllocateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(396);
-}
-impl _WDFFUNCENUM {
-    pub const WdfCxDeviceInitAssignWdmIrpPreprocessCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        397,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfCxDeviceInitSetIoInCallerContextCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        398,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfCxDeviceInitSetRequestAttributesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        399,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfCxDeviceInitSetFileObjectConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        400,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmDispatchIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(401);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmDispatchIrpToIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        402,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetRemoveLockOptionsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        403,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceConfigureWdmIrpDispatchCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        404,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaEnablerConfigureSystemProfileTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        405,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionInitializeUsingOffsetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        406,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionGetTransferInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        407,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionSetChannelConfigurationCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        408,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionSetTransferCompleteCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        409,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionSetImmediateExecutionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        410,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionAllocateResourcesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        411,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionSetDeviceAddressOffsetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        412,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionFreeResourcesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(413);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionCancelTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(414);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionWdmGetTransferContextTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        415,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfInterruptQueueWorkItemForIsrTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        416,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfInterruptTryToAcquireLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(417);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueStopAndPurgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(418);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoQueueStopAndPurgeSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        419,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetPurgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(420);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceCreateWithParametersTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        421,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceQueryUsbCapabilityTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        422,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceCreateUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(423);
-}
-impl _WDFFUNCENUM {
-    pub const WdfUsbTargetDeviceCreateIsochUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        424,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceWdmAssignPowerFrameworkSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        425,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDmaTransactionStopSystemTransferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        426,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfCxVerifierKeBugCheckTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(427);
-}
-impl _WDFFUNCENUM {
-    pub const WdfInterruptReportActiveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(428);
-}
-impl _WDFFUNCENUM {
-    pub const WdfInterruptReportInactiveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(429);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetReleaseHardwareOrderOnFailureTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        430,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfGetTriageInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(431);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitSetIoTypeExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(432);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(433);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAllocAndQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        434,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceAssignPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(435);
-}
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(436);
-}
-impl _WDFFUNCENUM {
-    pub const WdfFdoInitAllocAndQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        437,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceStopIdleActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(438);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceResumeIdleActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(439);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceGetSelfIoTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(440);
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceInitAllowSelfIoTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(441);
-}
-impl _WDFFUNCENUM {
-    pub const WdfIoTargetSelfAssignDefaultIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
-        442,
-    );
-}
-impl _WDFFUNCENUM {
-    pub const WdfDeviceOpenDevicemapKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(443);
-}
-impl _WDFFUNCENUM {
-    pub const WdfFunctionTableNumEntries: _WDFFUNCENUM = _WDFFUNCENUM(444);
-}
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDFFUNCENUM(pub ::libc::c_int);
-pub use self::_WDFFUNCENUM as WDFFUNCENUM;
-impl _WDF_EXECUTION_LEVEL {
-    pub const WdfExecutionLevelInvalid: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(0);
-}
-impl _WDF_EXECUTION_LEVEL {
-    pub const WdfExecutionLevelInheritFromParent: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(
-        1,
-    );
-}
-impl _WDF_EXECUTION_LEVEL {
-    pub const WdfExecutionLevelPassive: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(2);
-}
-impl _WDF_EXECUTION_LEVEL {
-    pub const WdfExecutionLevelDispatch: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(3);
-}
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_EXECUTION_LEVEL(pub ::libc::c_int);
-pub use self::_WDF_EXECUTION_LEVEL as WDF_EXECUTION_LEVEL;
-impl _WDF_SYNCHRONIZATION_SCOPE {
-    pub const WdfSynchronizationScopeInvalid: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
-        0,
-    );
-}
-impl _WDF_SYNCHRONIZATION_SCOPE {
-    pub const WdfSynchronizationScopeInheritFromParent: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
-        1,
-    );
-}
-impl _WDF_SYNCHRONIZATION_SCOPE {
-    pub const WdfSynchronizationScopeDevice: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
-        2,
-    );
-}
-impl _WDF_SYNCHRONIZATION_SCOPE {
-    pub const WdfSynchronizationScopeQueue: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
-        3,
-    );
-}
-impl _WDF_SYNCHRONIZATION_SCOPE {
-    pub const WdfSynchronizationScopeNone: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
-        4,
-    );
-}
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_SYNCHRONIZATION_SCOPE(pub ::libc::c_int);
-pub use self::_WDF_SYNCHRONIZATION_SCOPE as WDF_SYNCHRONIZATION_SCOPE;
-pub type EVT_WDF_OBJECT_CONTEXT_CLEANUP = ::core::option::Option<
-    unsafe extern "C" fn(Object: WDFOBJECT),
->;
-pub type PFN_WDF_OBJECT_CONTEXT_CLEANUP = EVT_WDF_OBJECT_CONTEXT_CLEANUP;
-pub type EVT_WDF_OBJECT_CONTEXT_DESTROY = ::core::option::Option<
-    unsafe extern "C" fn(Object: WDFOBJECT),
->;
-pub type PFN_WDF_OBJECT_CONTEXT_DESTROY = EVT_WDF_OBJECT_CONTEXT_DESTROY;
-pub type PCWDF_OBJECT_CONTEXT_TYPE_INFO = *const _WDF_OBJECT_CONTEXT_TYPE_INFO;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _WDF_OBJECT_ATTRIBUTES {
-    pub Size: ULONG,
-    pub EvtCleanupCallback: PFN_WDF_OBJECT_CONTEXT_CLEANUP,
-    pub EvtDestroyCallback: PFN_WDF_OBJECT_CONTEXT_DESTROY,
-    pub ExecutionLevel: WDF_EXECUTION_LEVEL,
-    pub SynchronizationScope: WDF_SYNCHRONIZATION_SCOPE,
-    pub ParentObject: WDFOBJECT,
-    pub ContextSizeOverride: usize,
-    pub ContextTypeInfo: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
-}
-pub type WDF_OBJECT_ATTRIBUTES = _WDF_OBJECT_ATTRIBUTES;
-pub type PFN_GET_UNIQUE_CONTEXT_TYPE = ::core::option::Option<
-    unsafe extern "C" fn() -> PCWDF_OBJECT_CONTEXT_TYPE_INFO,
->;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _WDF_OBJECT_CONTEXT_TYPE_INFO {
-    pub Size: ULONG,
-    pub ContextName: LPCSTR,
-    pub ContextSize: usize,
-    pub UniqueType: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
-    pub EvtDriverGetUniqueContextType: PFN_GET_UNIQUE_CONTEXT_TYPE,
-}
-pub type WDF_OBJECT_CONTEXT_TYPE_INFO = _WDF_OBJECT_CONTEXT_TYPE_INFO;
-pub type PFN_WDFOBJECTGETTYPEDCONTEXTWORKER = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Handle: WDFOBJECT,
-        TypeInfo: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
-    ) -> PVOID,
->;
-pub type PFN_WDFOBJECTREFERENCEACTUAL = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Handle: WDFOBJECT,
-        Tag: PVOID,
-        Line: LONG,
-        File: PCHAR,
-    ),
->;
-pub type PFN_WDFOBJECTDEREFERENCEACTUAL = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Handle: WDFOBJECT,
-        Tag: PVOID,
-        Line: LONG,
-        File: PCHAR,
-    ),
->;
-impl _WDF_DRIVER_INIT_FLAGS {
-    pub const WdfDriverInitNonPnpDriver: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(
-        1,
-    );
-}
-impl _WDF_DRIVER_INIT_FLAGS {
-    pub const WdfDriverInitNoDispatchOverride: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(
-        2,
-    );
-}
-impl _WDF_DRIVER_INIT_FLAGS {
-    pub const WdfVerifyOn: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(4);
-}
-impl _WDF_DRIVER_INIT_FLAGS {
-    pub const WdfVerifierOn: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(8);
-}
-impl ::core::ops::BitOr<_WDF_DRIVER_INIT_FLAGS> for _WDF_DRIVER_INIT_FLAGS {
-    type Output = Self;
+impl _IMAGE_INFO__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
-    fn bitor(self, other: Self) -> Self {
-        _WDF_DRIVER_INIT_FLAGS(self.0 | other.0)
+    pub fn ImageAddressingMode(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 8u8) as u32) }
     }
-}
-impl ::core::ops::BitOrAssign for _WDF_DRIVER_INIT_FLAGS {
     #[inline]
-    fn bitor_assign(&mut self, rhs: _WDF_DRIVER_INIT_FLAGS) {
-        self.0 |= rhs.0;
+    pub fn set_ImageAddressingMode(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 8u8, val as u64)
+        }
     }
-}
-impl ::core::ops::BitAnd<_WDF_DRIVER_INIT_FLAGS> for _WDF_DRIVER_INIT_FLAGS {
-    type Output = Self;
     #[inline]
-    fn bitand(self, other: Self) -> Self {
-        _WDF_DRIVER_INIT_FLAGS(self.0 & other.0)
+    pub fn SystemModeImage(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_SystemModeImage(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(8usize, 1u8, val as u64)
+        }
     }
-}
-impl ::core::ops::BitAndAssign for _WDF_DRIVER_INIT_FLAGS {
     #[inline]
-    fn bitand_assign(&mut self, rhs: _WDF_DRIVER_INIT_FLAGS) {
-        self.0 &= rhs.0;
+    pub fn ImageMappedToAllPids(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(9usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_ImageMappedToAllPids(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(9usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn ExtendedInfoPresent(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(10usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_ExtendedInfoPresent(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(10usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn MachineTypeMismatch(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(11usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_MachineTypeMismatch(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(11usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn ImageSignatureLevel(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(12usize, 4u8) as u32) }
+    }
+    #[inline]
+    pub fn set_ImageSignatureLevel(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(12usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn ImageSignatureType(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(16usize, 3u8) as u32) }
+    }
+    #[inline]
+    pub fn set_ImageSignatureType(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(16usize, 3u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn ImagePartialMap(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(19usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_ImagePartialMap(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(19usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn Reserved(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(20usize, 12u8) as u32) }
+    }
+    #[inline]
+    pub fn set_Reserved(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(20usize, 12u8, val as u64)
+        }
     }
 }
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_DRIVER_INIT_FLAGS(pub ::libc::c_int);
-pub use self::_WDF_DRIVER_INIT_FLAGS as WDF_DRIVER_INIT_FLAGS;
-pub type EVT_WDF_DRIVER_DEVICE_ADD = ::core::option::Option<
-    unsafe extern "C" fn(Driver: WDFDRIVER, DeviceInit: PWDFDEVICE_INIT) -> NTSTATUS,
->;
-pub type PFN_WDF_DRIVER_DEVICE_ADD = EVT_WDF_DRIVER_DEVICE_ADD;
-pub type EVT_WDF_DRIVER_UNLOAD = ::core::option::Option<
-    unsafe extern "C" fn(Driver: WDFDRIVER),
->;
-pub type PFN_WDF_DRIVER_UNLOAD = EVT_WDF_DRIVER_UNLOAD;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _WDF_DRIVER_CONFIG {
-    pub Size: ULONG,
-    pub EvtDriverDeviceAdd: PFN_WDF_DRIVER_DEVICE_ADD,
-    pub EvtDriverUnload: PFN_WDF_DRIVER_UNLOAD,
-    pub DriverInitFlags: ULONG,
-    pub DriverPoolTag: ULONG,
-}
-pub type WDF_DRIVER_CONFIG = _WDF_DRIVER_CONFIG;
-pub type PWDF_DRIVER_CONFIG = *mut _WDF_DRIVER_CONFIG;
-pub type PFN_WDFDRIVERCREATE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        DriverObject: PDRIVER_OBJECT,
-        RegistryPath: PCUNICODE_STRING,
-        DriverAttributes: PWDF_OBJECT_ATTRIBUTES,
-        DriverConfig: PWDF_DRIVER_CONFIG,
-        Driver: *mut WDFDRIVER,
-    ) -> NTSTATUS,
+pub type IMAGE_INFO = _IMAGE_INFO;
+pub type PIMAGE_INFO = *mut _IMAGE_INFO;
+pub type LOAD_IMAGE_NOTIFY_ROUTINE = ::core::option::Option<
+    unsafe extern "system" fn(
+        FullImageName: PUNICODE_STRING,
+        ProcessId: HANDLE,
+        ImageInfo: PIMAGE_INFO,
+    ),
 >;
-impl _WDF_DEVICE_IO_TYPE {
-    pub const WdfDeviceIoUndefined: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(0);
+pub type PLOAD_IMAGE_NOTIFY_ROUTINE = LOAD_IMAGE_NOTIFY_ROUTINE;
+extern "C" {
+    pub fn PsSetLoadImageNotifyRoutine(NotifyRoutine: PLOAD_IMAGE_NOTIFY_ROUTINE) -> NTSTATUS;
 }
-impl _WDF_DEVICE_IO_TYPE {
-    pub const WdfDeviceIoNeither: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(1);
+extern "C" {
+    pub fn PsRemoveLoadImageNotifyRoutine(NotifyRoutine: PLOAD_IMAGE_NOTIFY_ROUTINE) -> NTSTATUS;
 }
-impl _WDF_DEVICE_IO_TYPE {
-    pub const WdfDeviceIoBuffered: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(2);
+extern "C" {
+    pub static mut PsProcessType: *mut POBJECT_TYPE;
 }
-impl _WDF_DEVICE_IO_TYPE {
-    pub const WdfDeviceIoDirect: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(3);
+extern "C" {
+    pub static mut PsThreadType: *mut POBJECT_TYPE;
 }
-impl _WDF_DEVICE_IO_TYPE {
-    pub const WdfDeviceIoBufferedOrDirect: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(4);
+extern "C" {
+    pub static mut IoFileObjectType: *mut POBJECT_TYPE;
 }
-impl _WDF_DEVICE_IO_TYPE {
-    pub const WdfDeviceIoMaximum: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(5);
+extern "C" {
+    pub fn ObfReferenceObject(Object: PVOID) -> LONG_PTR;
 }
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_DEVICE_IO_TYPE(pub ::libc::c_int);
-pub use self::_WDF_DEVICE_IO_TYPE as WDF_DEVICE_IO_TYPE;
-impl _WDF_FILEOBJECT_CLASS {
-    pub const WdfFileObjectInvalid: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(0);
+extern "C" {
+    pub fn PsLookupProcessByProcessId(ProcessId: HANDLE, Process: *mut PEPROCESS) -> NTSTATUS;
 }
-impl _WDF_FILEOBJECT_CLASS {
-    pub const WdfFileObjectNotRequired: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(1);
+extern "C" {
+    pub fn PsLookupThreadByThreadId(ThreadId: HANDLE, Thread: *mut PETHREAD) -> NTSTATUS;
 }
-impl _WDF_FILEOBJECT_CLASS {
-    pub const WdfFileObjectWdfCanUseFsContext: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
-        2,
-    );
+extern "C" {
+    pub fn PsGetProcessId(Process: PEPROCESS) -> HANDLE;
 }
-impl _WDF_FILEOBJECT_CLASS {
-    pub const WdfFileObjectWdfCanUseFsContext2: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
-        3,
-    );
+extern "C" {
+    pub fn PsGetThreadId(Thread: PETHREAD) -> HANDLE;
 }
-impl _WDF_FILEOBJECT_CLASS {
-    pub const WdfFileObjectWdfCannotUseFsContexts: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
-        4,
-    );
+extern "C" {
+    pub fn PsGetThreadProcessId(Thread: PETHREAD) -> HANDLE;
 }
-impl _WDF_FILEOBJECT_CLASS {
-    pub const WdfFileObjectCanBeOptional: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
-        -2147483648,
-    );
+extern "C" {
+    pub fn IoGetCurrentProcess() -> PEPROCESS;
 }
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_FILEOBJECT_CLASS(pub ::libc::c_int);
-pub use self::_WDF_FILEOBJECT_CLASS as WDF_FILEOBJECT_CLASS;
 extern "C" {
-    pub static SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_RW_RES_R: UNICODE_STRING;
+    pub fn KeGetCurrentThread() -> PKTHREAD;
 }
-pub type EVT_WDF_DEVICE_FILE_CREATE = ::core::option::Option<
-    unsafe extern "C" fn(
-        Device: WDFDEVICE,
-        Request: WDFREQUEST,
-        FileObject: WDFFILEOBJECT,
-    ),
->;
-pub type PFN_WDF_DEVICE_FILE_CREATE = EVT_WDF_DEVICE_FILE_CREATE;
-pub type EVT_WDF_FILE_CLOSE = ::core::option::Option<
-    unsafe extern "C" fn(FileObject: WDFFILEOBJECT),
->;
-pub type PFN_WDF_FILE_CLOSE = EVT_WDF_FILE_CLOSE;
-pub type EVT_WDF_FILE_CLEANUP = ::core::option::Option<
-    unsafe extern "C" fn(FileObject: WDFFILEOBJECT),
->;
-pub type PFN_WDF_FILE_CLEANUP = EVT_WDF_FILE_CLEANUP;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _WDF_FILEOBJECT_CONFIG {
-    pub Size: ULONG,
-    pub EvtDeviceFileCreate: PFN_WDF_DEVICE_FILE_CREATE,
-    pub EvtFileClose: PFN_WDF_FILE_CLOSE,
-    pub EvtFileCleanup: PFN_WDF_FILE_CLEANUP,
-    pub AutoForwardCleanupClose: WDF_TRI_STATE,
-    pub FileObjectClass: WDF_FILEOBJECT_CLASS,
-}
-pub type WDF_FILEOBJECT_CONFIG = _WDF_FILEOBJECT_CONFIG;
-pub type PWDF_FILEOBJECT_CONFIG = *mut _WDF_FILEOBJECT_CONFIG;
-pub type PFN_WDFDEVICEINITFREE = ::core::option::Option<
-    unsafe extern "C" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, DeviceInit: PWDFDEVICE_INIT),
->;
-pub type PFN_WDFDEVICEINITSETEXCLUSIVE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        DeviceInit: PWDFDEVICE_INIT,
-        IsExclusive: BOOLEAN,
-    ),
->;
-pub type PFN_WDFDEVICEINITSETIOTYPE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        DeviceInit: PWDFDEVICE_INIT,
-        IoType: WDF_DEVICE_IO_TYPE,
-    ),
->;
-pub type PFN_WDFDEVICEINITASSIGNNAME = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        DeviceInit: PWDFDEVICE_INIT,
-        DeviceName: PCUNICODE_STRING,
-    ) -> NTSTATUS,
->;
-pub type PFN_WDFDEVICEINITSETFILEOBJECTCONFIG = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        DeviceInit: PWDFDEVICE_INIT,
-        FileObjectConfig: PWDF_FILEOBJECT_CONFIG,
-        FileObjectAttributes: PWDF_OBJECT_ATTRIBUTES,
-    ),
->;
-pub type PFN_WDFDEVICECREATE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        DeviceInit: *mut PWDFDEVICE_INIT,
-        DeviceAttributes: PWDF_OBJECT_ATTRIBUTES,
-        Device: *mut WDFDEVICE,
-    ) -> NTSTATUS,
->;
-pub type PFN_WDFDEVICECREATESYMBOLICLINK = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Device: WDFDEVICE,
-        SymbolicLinkName: PCUNICODE_STRING,
-    ) -> NTSTATUS,
->;
-pub type PFN_WDFREQUESTCOMPLETE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Request: WDFREQUEST,
-        Status: NTSTATUS,
-    ),
->;
-pub type PFN_WDFREQUESTRETRIEVEINPUTBUFFER = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Request: WDFREQUEST,
-        MinimumRequiredLength: usize,
-        Buffer: *mut PVOID,
-        Length: *mut usize,
-    ) -> NTSTATUS,
->;
-pub type PFN_WDFREQUESTRETRIEVEOUTPUTBUFFER = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Request: WDFREQUEST,
-        MinimumRequiredSize: usize,
-        Buffer: *mut PVOID,
-        Length: *mut usize,
-    ) -> NTSTATUS,
->;
-pub type PFN_WDFREQUESTSETINFORMATION = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Request: WDFREQUEST,
-        Information: ULONG_PTR,
-    ),
->;
-pub type PFN_WDFREQUESTGETREQUESTORMODE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Request: WDFREQUEST,
-    ) -> KPROCESSOR_MODE,
->;
-impl _WDF_IO_QUEUE_DISPATCH_TYPE {
-    pub const WdfIoQueueDispatchInvalid: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
-        0,
-    );
-}
-impl _WDF_IO_QUEUE_DISPATCH_TYPE {
-    pub const WdfIoQueueDispatchSequential: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
-        1,
-    );
-}
-impl _WDF_IO_QUEUE_DISPATCH_TYPE {
-    pub const WdfIoQueueDispatchParallel: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
-        2,
-    );
-}
-impl _WDF_IO_QUEUE_DISPATCH_TYPE {
-    pub const WdfIoQueueDispatchManual: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
-        3,
-    );
-}
-impl _WDF_IO_QUEUE_DISPATCH_TYPE {
-    pub const WdfIoQueueDispatchMax: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
-        4,
-    );
-}
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct _WDF_IO_QUEUE_DISPATCH_TYPE(pub ::libc::c_int);
-pub use self::_WDF_IO_QUEUE_DISPATCH_TYPE as WDF_IO_QUEUE_DISPATCH_TYPE;
-pub type EVT_WDF_IO_QUEUE_IO_DEFAULT = ::core::option::Option<
-    unsafe extern "C" fn(Queue: WDFQUEUE, Request: WDFREQUEST),
->;
-pub type PFN_WDF_IO_QUEUE_IO_DEFAULT = EVT_WDF_IO_QUEUE_IO_DEFAULT;
-pub type EVT_WDF_IO_QUEUE_IO_STOP = ::core::option::Option<
-    unsafe extern "C" fn(Queue: WDFQUEUE, Request: WDFREQUEST, ActionFlags: ULONG),
->;
-pub type PFN_WDF_IO_QUEUE_IO_STOP = EVT_WDF_IO_QUEUE_IO_STOP;
-pub type EVT_WDF_IO_QUEUE_IO_RESUME = ::core::option::Option<
-    unsafe extern "C" fn(Queue: WDFQUEUE, Request: WDFREQUEST),
->;
-pub type PFN_WDF_IO_QUEUE_IO_RESUME = EVT_WDF_IO_QUEUE_IO_RESUME;
-pub type EVT_WDF_IO_QUEUE_IO_READ = ::core::option::Option<
-    unsafe extern "C" fn(Queue: WDFQUEUE, Request: WDFREQUEST, Length: usize),
->;
-pub type PFN_WDF_IO_QUEUE_IO_READ = EVT_WDF_IO_QUEUE_IO_READ;
-pub type EVT_WDF_IO_QUEUE_IO_WRITE = ::core::option::Option<
-    unsafe extern "C" fn(Queue: WDFQUEUE, Request: WDFREQUEST, Length: usize),
->;
-pub type PFN_WDF_IO_QUEUE_IO_WRITE = EVT_WDF_IO_QUEUE_IO_WRITE;
-pub type EVT_WDF_IO_QUEUE_IO_DEVICE_CONTROL = ::core::option::Option<
-    unsafe extern "C" fn(
-        Queue: WDFQUEUE,
-        Request: WDFREQUEST,
-        OutputBufferLength: usize,
-        InputBufferLength: usize,
-        IoControlCode: ULONG,
-    ),
->;
-pub type PFN_WDF_IO_QUEUE_IO_DEVICE_CONTROL = EVT_WDF_IO_QUEUE_IO_DEVICE_CONTROL;
-pub type EVT_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL = ::core::option::Option<
-    unsafe extern "C" fn(
-        Queue: WDFQUEUE,
-        Request: WDFREQUEST,
-        OutputBufferLength: usize,
-        InputBufferLength: usize,
-        IoControlCode: ULONG,
-    ),
->;
-pub type PFN_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL = EVT_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL;
-pub type EVT_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE = ::core::option::Option<
-    unsafe extern "C" fn(Queue: WDFQUEUE, Request: WDFREQUEST),
->;
-pub type PFN_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE = EVT_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE;
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct _WDF_IO_QUEUE_CONFIG {
-    pub Size: ULONG,
-    pub DispatchType: WDF_IO_QUEUE_DISPATCH_TYPE,
-    pub PowerManaged: WDF_TRI_STATE,
-    pub AllowZeroLengthRequests: BOOLEAN,
-    pub DefaultQueue: BOOLEAN,
-    pub EvtIoDefault: PFN_WDF_IO_QUEUE_IO_DEFAULT,
-    pub EvtIoRead: PFN_WDF_IO_QUEUE_IO_READ,
-    pub EvtIoWrite: PFN_WDF_IO_QUEUE_IO_WRITE,
-    pub EvtIoDeviceControl: PFN_WDF_IO_QUEUE_IO_DEVICE_CONTROL,
-    pub EvtIoInternalDeviceControl: PFN_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL,
-    pub EvtIoStop: PFN_WDF_IO_QUEUE_IO_STOP,
-    pub EvtIoResume: PFN_WDF_IO_QUEUE_IO_RESUME,
-    pub EvtIoCanceledOnQueue: PFN_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE,
-    pub Settings: _WDF_IO_QUEUE_CONFIG__bindgen_ty_1,
-    pub Driver: WDFDRIVER,
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub union _WDF_IO_QUEUE_CONFIG__bindgen_ty_1 {
-    pub Parallel: _WDF_IO_QUEUE_CONFIG__bindgen_ty_1__bindgen_ty_1,
+pub struct _EPROCESS {
+    pub _address: u8,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _WDF_IO_QUEUE_CONFIG__bindgen_ty_1__bindgen_ty_1 {
-    pub NumberOfPresentedRequests: ULONG,
+pub struct _IOP_FILE_OBJECT_EXTENSION {
+    pub _address: u8,
 }
-pub type WDF_IO_QUEUE_CONFIG = _WDF_IO_QUEUE_CONFIG;
-pub type PWDF_IO_QUEUE_CONFIG = *mut _WDF_IO_QUEUE_CONFIG;
-pub type PFN_WDFIOQUEUECREATE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Device: WDFDEVICE,
-        Config: PWDF_IO_QUEUE_CONFIG,
-        QueueAttributes: PWDF_OBJECT_ATTRIBUTES,
-        Queue: *mut WDFQUEUE,
-    ) -> NTSTATUS,
->;
-pub type PFN_WDFIOQUEUEGETDEVICE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Queue: WDFQUEUE,
-    ) -> WDFDEVICE,
->;
-pub type PFN_WDFCONTROLDEVICEINITALLOCATE = ::core::option::Option<
-    unsafe extern "C" fn(
-        DriverGlobals: PWDF_DRIVER_GLOBALS,
-        Driver: WDFDRIVER,
-        SDDLString: *const UNICODE_STRING,
-    ) -> PWDFDEVICE_INIT,
->;
-pub type PFN_WDFCONTROLFINISHINITIALIZING = ::core::option::Option<
-    unsafe extern "C" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Device: WDFDEVICE),
->;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _EPROCESS {
+pub struct _IORING_OBJECT {
     pub _address: u8,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _IOP_FILE_OBJECT_EXTENSION {
+pub struct _CALLBACK_OBJECT {
     pub _address: u8,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _IORING_OBJECT {
+pub struct _OBJECT_TYPE {
     pub _address: u8,
 }
@@ -0,0 +1,48 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+use super::*;
+
+pub const ACPI_METHOD_ARGUMENT_INTEGER: u32 = 0;
+pub const ACPI_METHOD_ARGUMENT_STRING: u32 = 1;
+pub const ACPI_METHOD_ARGUMENT_BUFFER: u32 = 2;
+pub const ACPI_METHOD_ARGUMENT_PACKAGE: u32 = 3;
+pub const ACPI_METHOD_ARGUMENT_PACKAGE_EX: u32 = 4;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ACPI_EVAL_INPUT_BUFFER_V1 {
+    pub Signature: ULONG,
+    pub __bindgen_anon_1: _ACPI_EVAL_INPUT_BUFFER_V1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _ACPI_EVAL_INPUT_BUFFER_V1__bindgen_ty_1 {
+    pub MethodName: [UCHAR; 4usize],
+    pub MethodNameAsUlong: ULONG,
+}
+pub type ACPI_EVAL_INPUT_BUFFER_V1 = _ACPI_EVAL_INPUT_BUFFER_V1;
+pub type ACPI_EVAL_INPUT_BUFFER = ACPI_EVAL_INPUT_BUFFER_V1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ACPI_METHOD_ARGUMENT_V1 {
+    pub Type: USHORT,
+    pub DataLength: USHORT,
+    pub __bindgen_anon_1: _ACPI_METHOD_ARGUMENT_V1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _ACPI_METHOD_ARGUMENT_V1__bindgen_ty_1 {
+    pub Argument: ULONG,
+    pub Data: [UCHAR; 1usize],
+}
+pub type ACPI_METHOD_ARGUMENT_V1 = _ACPI_METHOD_ARGUMENT_V1;
+pub type ACPI_METHOD_ARGUMENT = ACPI_METHOD_ARGUMENT_V1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ACPI_EVAL_OUTPUT_BUFFER_V1 {
+    pub Signature: ULONG,
+    pub Length: ULONG,
+    pub Count: ULONG,
+    pub Argument: [ACPI_METHOD_ARGUMENT_V1; 1usize],
+}
+pub type ACPI_EVAL_OUTPUT_BUFFER_V1 = _ACPI_EVAL_OUTPUT_BUFFER_V1;
+pub type ACPI_EVAL_OUTPUT_BUFFER = ACPI_EVAL_OUTPUT_BUFFER_V1;
@@ -0,0 +1,87 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+use super::*;
+
+pub const EVENT_CONTROL_CODE_DISABLE_PROVIDER: u32 = 0;
+pub const EVENT_CONTROL_CODE_ENABLE_PROVIDER: u32 = 1;
+pub const EVENT_CONTROL_CODE_CAPTURE_STATE: u32 = 2;
+pub type REGHANDLE = ULONGLONG;
+pub type PREGHANDLE = *mut REGHANDLE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _EVENT_DATA_DESCRIPTOR {
+    pub Ptr: ULONGLONG,
+    pub Size: ULONG,
+    pub __bindgen_anon_1: _EVENT_DATA_DESCRIPTOR__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _EVENT_DATA_DESCRIPTOR__bindgen_ty_1 {
+    pub Reserved: ULONG,
+    pub __bindgen_anon_1: _EVENT_DATA_DESCRIPTOR__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _EVENT_DATA_DESCRIPTOR__bindgen_ty_1__bindgen_ty_1 {
+    pub Type: UCHAR,
+    pub Reserved1: UCHAR,
+    pub Reserved2: USHORT,
+}
+pub type EVENT_DATA_DESCRIPTOR = _EVENT_DATA_DESCRIPTOR;
+pub type PEVENT_DATA_DESCRIPTOR = *mut _EVENT_DATA_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _EVENT_DESCRIPTOR {
+    pub Id: USHORT,
+    pub Version: UCHAR,
+    pub Channel: UCHAR,
+    pub Level: UCHAR,
+    pub Opcode: UCHAR,
+    pub Task: USHORT,
+    pub Keyword: ULONGLONG,
+}
+pub type EVENT_DESCRIPTOR = _EVENT_DESCRIPTOR;
+pub type PCEVENT_DESCRIPTOR = *const EVENT_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _EVENT_FILTER_DESCRIPTOR {
+    pub Ptr: ULONGLONG,
+    pub Size: ULONG,
+    pub Type: ULONG,
+}
+pub type EVENT_FILTER_DESCRIPTOR = _EVENT_FILTER_DESCRIPTOR;
+pub type PEVENT_FILTER_DESCRIPTOR = *mut _EVENT_FILTER_DESCRIPTOR;
+pub type PETWENABLECALLBACK = ::core::option::Option<
+    unsafe extern "system" fn(
+        SourceId: LPCGUID,
+        IsEnabled: ULONG,
+        Level: UCHAR,
+        MatchAnyKeyword: ULONGLONG,
+        MatchAllKeyword: ULONGLONG,
+        FilterData: PEVENT_FILTER_DESCRIPTOR,
+        CallbackContext: PVOID,
+    ),
+>;
+extern "C" {
+    pub fn EtwRegister(
+        ProviderId: LPCGUID,
+        EnableCallback: PETWENABLECALLBACK,
+        CallbackContext: PVOID,
+        RegHandle: PREGHANDLE,
+    ) -> NTSTATUS;
+}
+extern "C" {
+    pub fn EtwUnregister(RegHandle: REGHANDLE) -> NTSTATUS;
+}
+extern "C" {
+    pub fn EtwEventEnabled(RegHandle: REGHANDLE, EventDescriptor: PCEVENT_DESCRIPTOR) -> BOOLEAN;
+}
+extern "C" {
+    pub fn EtwWrite(
+        RegHandle: REGHANDLE,
+        EventDescriptor: PCEVENT_DESCRIPTOR,
+        ActivityId: LPCGUID,
+        UserDataCount: ULONG,
+        UserData: PEVENT_DATA_DESCRIPTOR,
+    ) -> NTSTATUS;
+}
@@ -0,0 +1,48 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+use super::*;
+
+pub type USAGE = USHORT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _HID_COLLECTION_INFORMATION {
+    pub DescriptorSize: ULONG,
+    pub Polled: BOOLEAN,
+    pub Reserved1: [UCHAR; 1usize],
+    pub VendorID: USHORT,
+    pub ProductID: USHORT,
+    pub VersionNumber: USHORT,
+}
+pub type HID_COLLECTION_INFORMATION = _HID_COLLECTION_INFORMATION;
+pub type PHID_COLLECTION_INFORMATION = *mut _HID_COLLECTION_INFORMATION;
+pub type PHIDP_PREPARSED_DATA = *mut _HIDP_PREPARSED_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _HIDP_CAPS {
+    pub Usage: USAGE,
+    pub UsagePage: USAGE,
+    pub InputReportByteLength: USHORT,
+    pub OutputReportByteLength: USHORT,
+    pub FeatureReportByteLength: USHORT,
+    pub Reserved: [USHORT; 17usize],
+    pub NumberLinkCollectionNodes: USHORT,
+    pub NumberInputButtonCaps: USHORT,
+    pub NumberInputValueCaps: USHORT,
+    pub NumberInputDataIndices: USHORT,
+    pub NumberOutputButtonCaps: USHORT,
+    pub NumberOutputValueCaps: USHORT,
+    pub NumberOutputDataIndices: USHORT,
+    pub NumberFeatureButtonCaps: USHORT,
+    pub NumberFeatureValueCaps: USHORT,
+    pub NumberFeatureDataIndices: USHORT,
+}
+pub type HIDP_CAPS = _HIDP_CAPS;
+pub type PHIDP_CAPS = *mut _HIDP_CAPS;
+extern "C" {
+    pub fn HidP_GetCaps(PreparsedData: PHIDP_PREPARSED_DATA, Capabilities: PHIDP_CAPS) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _HIDP_PREPARSED_DATA {
+    pub _address: u8,
+}
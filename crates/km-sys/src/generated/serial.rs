@@ -0,0 +1,43 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+use super::*;
+
+pub const SERIAL_PURGE_TXABORT: u32 = 1;
+pub const SERIAL_PURGE_RXABORT: u32 = 2;
+pub const SERIAL_PURGE_TXCLEAR: u32 = 4;
+pub const SERIAL_PURGE_RXCLEAR: u32 = 8;
+pub const STOP_BIT_1: u32 = 0;
+pub const STOP_BITS_1_5: u32 = 1;
+pub const STOP_BITS_2: u32 = 2;
+pub const NO_PARITY: u32 = 0;
+pub const ODD_PARITY: u32 = 1;
+pub const EVEN_PARITY: u32 = 2;
+pub const MARK_PARITY: u32 = 3;
+pub const SPACE_PARITY: u32 = 4;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SERIAL_BAUD_RATE {
+    pub BaudRate: ULONG,
+}
+pub type SERIAL_BAUD_RATE = _SERIAL_BAUD_RATE;
+pub type PSERIAL_BAUD_RATE = *mut _SERIAL_BAUD_RATE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SERIAL_LINE_CONTROL {
+    pub StopBits: UCHAR,
+    pub Parity: UCHAR,
+    pub WordLength: UCHAR,
+}
+pub type SERIAL_LINE_CONTROL = _SERIAL_LINE_CONTROL;
+pub type PSERIAL_LINE_CONTROL = *mut _SERIAL_LINE_CONTROL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SERIAL_TIMEOUTS {
+    pub ReadIntervalTimeout: ULONG,
+    pub ReadTotalTimeoutMultiplier: ULONG,
+    pub ReadTotalTimeoutConstant: ULONG,
+    pub WriteTotalTimeoutMultiplier: ULONG,
+    pub WriteTotalTimeoutConstant: ULONG,
+}
+pub type SERIAL_TIMEOUTS = _SERIAL_TIMEOUTS;
+pub type PSERIAL_TIMEOUTS = *mut _SERIAL_TIMEOUTS;
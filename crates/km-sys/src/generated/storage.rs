@@ -0,0 +1,312 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+use super::*;
+
+pub type DEVICE_TYPE = ULONG;
+pub const IOCTL_STORAGE_BASE: u32 = 45;
+pub const IOCTL_DISK_BASE: u32 = 7;
+pub const CAP_ATA_ID_CMD: u32 = 1;
+pub const CAP_ATAPI_ID_CMD: u32 = 2;
+pub const CAP_SMART_CMD: u32 = 4;
+pub const ID_CMD: u32 = 236;
+pub const SMART_CMD: u32 = 176;
+pub const SMART_CYL_LOW: u32 = 79;
+pub const SMART_CYL_HI: u32 = 194;
+pub const READ_ATTRIBUTE_BUFFER_SIZE: u32 = 512;
+pub const IDENTIFY_BUFFER_SIZE: u32 = 512;
+pub const READ_THRESHOLD_BUFFER_SIZE: u32 = 512;
+pub const READ_ATTRIBUTES: u32 = 208;
+pub const READ_THRESHOLDS: u32 = 209;
+pub const ENABLE_SMART: u32 = 216;
+pub const DISABLE_SMART: u32 = 217;
+pub const RETURN_SMART_STATUS: u32 = 218;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_DEVICE_NUMBER {
+    pub DeviceType: DEVICE_TYPE,
+    pub DeviceNumber: ULONG,
+    pub PartitionNumber: ULONG,
+}
+pub type STORAGE_DEVICE_NUMBER = _STORAGE_DEVICE_NUMBER;
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeUnknown: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(0);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeScsi: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(1);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeAtapi: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(2);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeAta: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(3);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusType1394: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(4);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeSsa: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(5);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeFibre: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(6);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeUsb: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(7);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeRAID: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(8);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeiScsi: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(9);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeSas: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(10);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeSata: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(11);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeSd: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(12);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeMmc: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(13);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeVirtual: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(14);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeFileBackedVirtual: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(15);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeSpaces: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(16);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeNvme: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(17);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeSCM: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(18);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeUfs: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(19);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeMax: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(20);
+}
+impl _STORAGE_BUS_TYPE {
+    pub const BusTypeMaxReserved: _STORAGE_BUS_TYPE = _STORAGE_BUS_TYPE(127);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _STORAGE_BUS_TYPE(pub ::libc::c_int);
+pub use self::_STORAGE_BUS_TYPE as STORAGE_BUS_TYPE;
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(0);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(1);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceIdProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(2);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceUniqueIdProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(3);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceWriteCacheProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(4);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageMiniportProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(5);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAccessAlignmentProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(6);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceSeekPenaltyProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(7);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceTrimProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(8);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceWriteAggregationProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(9);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceDeviceTelemetryProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(10);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceLBProvisioningProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(11);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDevicePowerProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(12);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceCopyOffloadProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(13);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceResiliencyProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(14);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceMediumProductType: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(15);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterRpmbProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(16);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterCryptoProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(17);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceIoCapabilityProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(48);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterProtocolSpecificProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(
+        49,
+    );
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceProtocolSpecificProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(
+        50,
+    );
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterTemperatureProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(51);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceTemperatureProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(52);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterPhysicalTopologyProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(
+        53,
+    );
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDevicePhysicalTopologyProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(
+        54,
+    );
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceAttributesProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(55);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceManagementStatus: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(56);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageAdapterSerialNumberProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(57);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceLocationProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(58);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceNumaProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(59);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceZonedDeviceProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(60);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceUnsafeShutdownCount: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(61);
+}
+impl _STORAGE_PROPERTY_ID {
+    pub const StorageDeviceEnduranceProperty: _STORAGE_PROPERTY_ID = _STORAGE_PROPERTY_ID(62);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _STORAGE_PROPERTY_ID(pub ::libc::c_int);
+pub use self::_STORAGE_PROPERTY_ID as STORAGE_PROPERTY_ID;
+impl _STORAGE_QUERY_TYPE {
+    pub const PropertyStandardQuery: _STORAGE_QUERY_TYPE = _STORAGE_QUERY_TYPE(0);
+}
+impl _STORAGE_QUERY_TYPE {
+    pub const PropertyExistsQuery: _STORAGE_QUERY_TYPE = _STORAGE_QUERY_TYPE(1);
+}
+impl _STORAGE_QUERY_TYPE {
+    pub const PropertyMaskQuery: _STORAGE_QUERY_TYPE = _STORAGE_QUERY_TYPE(2);
+}
+impl _STORAGE_QUERY_TYPE {
+    pub const PropertyQueryMaxDefined: _STORAGE_QUERY_TYPE = _STORAGE_QUERY_TYPE(3);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _STORAGE_QUERY_TYPE(pub ::libc::c_int);
+pub use self::_STORAGE_QUERY_TYPE as STORAGE_QUERY_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_PROPERTY_QUERY {
+    pub PropertyId: STORAGE_PROPERTY_ID,
+    pub QueryType: STORAGE_QUERY_TYPE,
+    pub AdditionalParameters: [UCHAR; 1usize],
+}
+pub type STORAGE_PROPERTY_QUERY = _STORAGE_PROPERTY_QUERY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_DESCRIPTOR_HEADER {
+    pub Version: ULONG,
+    pub Size: ULONG,
+}
+pub type STORAGE_DESCRIPTOR_HEADER = _STORAGE_DESCRIPTOR_HEADER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_DEVICE_DESCRIPTOR {
+    pub Version: ULONG,
+    pub Size: ULONG,
+    pub DeviceType: UCHAR,
+    pub DeviceTypeModifier: UCHAR,
+    pub RemovableMedia: BOOLEAN,
+    pub CommandQueueing: BOOLEAN,
+    pub VendorIdOffset: ULONG,
+    pub ProductIdOffset: ULONG,
+    pub ProductRevisionOffset: ULONG,
+    pub SerialNumberOffset: ULONG,
+    pub BusType: STORAGE_BUS_TYPE,
+    pub RawPropertiesLength: ULONG,
+    pub RawDeviceProperties: [UCHAR; 1usize],
+}
+pub type STORAGE_DEVICE_DESCRIPTOR = _STORAGE_DEVICE_DESCRIPTOR;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _GETVERSIONINPARAMS {
+    pub bVersion: UCHAR,
+    pub bRevision: UCHAR,
+    pub bReserved: UCHAR,
+    pub bIDEDeviceMap: UCHAR,
+    pub fCapabilities: ULONG,
+    pub dwReserved: [ULONG; 4usize],
+}
+pub type GETVERSIONINPARAMS = _GETVERSIONINPARAMS;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IDEREGS {
+    pub bFeaturesReg: UCHAR,
+    pub bSectorCountReg: UCHAR,
+    pub bSectorNumberReg: UCHAR,
+    pub bCylLowReg: UCHAR,
+    pub bCylHighReg: UCHAR,
+    pub bDriveHeadReg: UCHAR,
+    pub bCommandReg: UCHAR,
+    pub bReserved: UCHAR,
+}
+pub type IDEREGS = _IDEREGS;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SENDCMDINPARAMS {
+    pub cBufferSize: ULONG,
+    pub irDriveRegs: IDEREGS,
+    pub bDriveNumber: UCHAR,
+    pub bReserved: [UCHAR; 3usize],
+    pub dwReserved: [ULONG; 4usize],
+    pub bBuffer: [UCHAR; 1usize],
+}
+pub type SENDCMDINPARAMS = _SENDCMDINPARAMS;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _DRIVERSTATUS {
+    pub bDriverError: UCHAR,
+    pub bIDEError: UCHAR,
+    pub bReserved: [UCHAR; 2usize],
+    pub dwReserved: [ULONG; 2usize],
+}
+pub type DRIVERSTATUS = _DRIVERSTATUS;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SENDCMDOUTPARAMS {
+    pub cBufferSize: ULONG,
+    pub DriverStatus: DRIVERSTATUS,
+    pub bBuffer: [UCHAR; 1usize],
+}
+pub type SENDCMDOUTPARAMS = _SENDCMDOUTPARAMS;
@@ -0,0 +1,3612 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+use super::*;
+
+pub type LPCSTR = *const CHAR;
+pub type WDFCONTEXT = PVOID;
+pub type PULONG_PTR = *mut ULONG_PTR;
+pub type PLONGLONG = *mut LONGLONG;
+pub type WDFFUNC = ::core::option::Option<unsafe extern "system" fn()>;
+extern "C" {
+    pub static mut WdfFunctions_01015: *const WDFFUNC;
+}
+impl _WDF_TRI_STATE {
+    pub const WdfFalse: _WDF_TRI_STATE = _WDF_TRI_STATE(0);
+}
+impl _WDF_TRI_STATE {
+    pub const WdfTrue: _WDF_TRI_STATE = _WDF_TRI_STATE(1);
+}
+impl _WDF_TRI_STATE {
+    pub const WdfUseDefault: _WDF_TRI_STATE = _WDF_TRI_STATE(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_TRI_STATE(pub ::libc::c_int);
+pub use self::_WDF_TRI_STATE as WDF_TRI_STATE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFDEVICE_INIT {
+    _unused: [u8; 0],
+}
+pub type PWDFDEVICE_INIT = *mut WDFDEVICE_INIT;
+pub type PWDF_OBJECT_ATTRIBUTES = *mut _WDF_OBJECT_ATTRIBUTES;
+pub type WDFOBJECT = HANDLE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFDRIVER__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFDRIVER = *mut WDFDRIVER__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFDEVICE__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFDEVICE = *mut WDFDEVICE__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFQUEUE__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFQUEUE = *mut WDFQUEUE__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFREQUEST__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFREQUEST = *mut WDFREQUEST__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFFILEOBJECT__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFFILEOBJECT = *mut WDFFILEOBJECT__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_DRIVER_GLOBALS {
+    pub Driver: WDFDRIVER,
+    pub DriverFlags: ULONG,
+    pub DriverTag: ULONG,
+    pub DriverName: [CHAR; 32usize],
+    pub DisplaceDriverUnload: BOOLEAN,
+}
+pub type PWDF_DRIVER_GLOBALS = *mut _WDF_DRIVER_GLOBALS;
+extern "C" {
+    pub static mut WdfDriverGlobals: PWDF_DRIVER_GLOBALS;
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(0);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(1);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListRetrievePdoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(2);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListRetrieveAddressDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        3,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListBeginScanTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(4);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListEndScanTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(5);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListBeginIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(6);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListRetrieveNextDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(7);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListEndIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(8);
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListAddOrUpdateChildDescriptionAsPresentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        9,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListUpdateChildDescriptionAsMissingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        10,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListUpdateAllChildDescriptionsAsPresentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        11,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfChildListRequestChildEjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(12);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(13);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(14);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionAddTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(15);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(16);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionRemoveItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(17);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionGetItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(18);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionGetFirstItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(19);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCollectionGetLastItemTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(20);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCommonBufferCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(21);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCommonBufferGetAlignedVirtualAddressTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        22,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCommonBufferGetAlignedLogicalAddressTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        23,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCommonBufferGetLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(24);
+}
+impl _WDFFUNCENUM {
+    pub const WdfControlDeviceInitAllocateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(25);
+}
+impl _WDFFUNCENUM {
+    pub const WdfControlDeviceInitSetShutdownNotificationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        26,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfControlFinishInitializingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(27);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetDeviceStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(28);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetDeviceStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(29);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWdmDeviceGetWdfDeviceHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(30);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmGetDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(31);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmGetAttachedDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(32);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmGetPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(33);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmDispatchPreprocessedIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        34,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAddDependentUsageDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        35,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAddRemovalRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        36,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceRemoveRemovalRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        37,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceClearRemovalRelationsDevicesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        38,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetDriverTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(39);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceRetrieveDeviceNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(40);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAssignMofResourceNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(41);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetIoTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(42);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetDevicePnpStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(43);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetDevicePowerStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(44);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetDevicePowerPolicyStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        45,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAssignS0IdleSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(46);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAssignSxWakeSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(47);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceOpenRegistryKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(48);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetSpecialFileSupportTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(49);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetCharacteristicsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(50);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetCharacteristicsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(51);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetAlignmentRequirementTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        52,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetAlignmentRequirementTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        53,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitFreeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(54);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetPnpPowerEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        55,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetPowerPolicyEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        56,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetPowerPolicyOwnershipTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        57,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitRegisterPnpStateChangeCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        58,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitRegisterPowerStateChangeCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        59,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitRegisterPowerPolicyStateChangeCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        60,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetIoTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(61);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetExclusiveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(62);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetPowerNotPageableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        63,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetPowerPageableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(64);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetPowerInrushTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(65);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetDeviceTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(66);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitAssignNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(67);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitAssignSDDLStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(68);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetDeviceClassTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(69);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetCharacteristicsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(70);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetFileObjectConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        71,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetRequestAttributesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        72,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitAssignWdmIrpPreprocessCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        73,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetIoInCallerContextCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        74,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(75);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetStaticStopRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(76);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceCreateDeviceInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(77);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetDeviceInterfaceStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        78,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceRetrieveDeviceInterfaceStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        79,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceCreateSymbolicLinkTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(80);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(81);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAllocAndQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(82);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetPnpCapabilitiesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(83);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetPowerCapabilitiesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(84);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetBusInformationForChildrenTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        85,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceIndicateWakeStatusTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(86);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceSetFailedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(87);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceStopIdleNoTrackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(88);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceResumeIdleNoTrackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(89);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(90);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceEnqueueRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(91);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetDefaultQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(92);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceConfigureRequestDispatchingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        93,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(94);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerGetMaximumLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(95);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerGetMaximumScatterGatherElementsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        96,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerSetMaximumScatterGatherElementsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        97,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(98);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionInitializeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(99);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionInitializeUsingRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        100,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionExecuteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(101);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionReleaseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(102);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionDmaCompletedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(103);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionDmaCompletedWithLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        104,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionDmaCompletedFinalTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        105,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionGetBytesTransferredTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        106,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionSetMaximumLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        107,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionGetRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(108);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionGetCurrentDmaTransferLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        109,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(110);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDpcCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(111);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDpcEnqueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(112);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDpcCancelTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(113);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDpcGetParentObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(114);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDpcWdmGetDpcTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(115);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(116);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverGetRegistryPathTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(117);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverWdmGetDriverObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(118);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverOpenParametersRegistryKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        119,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfWdmDriverGetWdfDriverHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(120);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverRegisterTraceInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(121);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverRetrieveVersionStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(122);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverIsVersionAvailableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(123);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitWdmGetPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(124);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitOpenRegistryKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(125);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(126);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitAllocAndQueryPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        127,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitSetEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(128);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitSetFilterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(129);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitSetDefaultChildListConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        130,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoQueryForInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(131);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoGetDefaultChildListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(132);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoAddStaticChildTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(133);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoLockStaticChildListForIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        134,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoRetrieveNextStaticChildTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(135);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoUnlockStaticChildListFromIterationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        136,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfFileObjectGetFileNameTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(137);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFileObjectGetFlagsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(138);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFileObjectGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(139);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFileObjectWdmGetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(140);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(141);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptQueueDpcForIsrTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(142);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptSynchronizeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(143);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptAcquireLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(144);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptReleaseLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(145);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptEnableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(146);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptDisableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(147);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptWdmGetInterruptTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(148);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptGetInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(149);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptSetPolicyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(150);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(151);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(152);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueGetStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(153);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueStartTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(154);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueStopTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(155);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueStopSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(156);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(157);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueRetrieveNextRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(158);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueRetrieveRequestByFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        159,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueFindRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(160);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueRetrieveFoundRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(161);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueDrainSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(162);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueDrainTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(163);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueuePurgeSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(164);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueuePurgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(165);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueReadyNotifyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(166);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(167);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetOpenTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(168);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetCloseForQueryRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(169);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetCloseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(170);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetStartTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(171);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetStopTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(172);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetGetStateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(173);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(174);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetQueryTargetPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(175);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetAllocAndQueryTargetPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        176,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetQueryForInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(177);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetWdmGetTargetDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        178,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetWdmGetTargetPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        179,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetWdmGetTargetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        180,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetWdmGetTargetFileHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        181,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetSendReadSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        182,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetFormatRequestForReadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        183,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetSendWriteSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        184,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetFormatRequestForWriteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        185,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetSendIoctlSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        186,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetFormatRequestForIoctlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        187,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetSendInternalIoctlSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        188,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetFormatRequestForInternalIoctlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        189,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetSendInternalIoctlOthersSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        190,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetFormatRequestForInternalIoctlOthersTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        191,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(192);
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryCreatePreallocatedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(193);
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryGetBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(194);
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryAssignBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(195);
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryCopyToBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(196);
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryCopyFromBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(197);
+}
+impl _WDFFUNCENUM {
+    pub const WdfLookasideListCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(198);
+}
+impl _WDFFUNCENUM {
+    pub const WdfMemoryCreateFromLookasideTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(199);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceMiniportCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(200);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDriverMiniportUnloadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(201);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectGetTypedContextWorkerTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(202);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectAllocateContextTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(203);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectContextGetObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(204);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectReferenceActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(205);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectDereferenceActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(206);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(207);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectDeleteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(208);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectQueryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(209);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAllocateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(210);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitSetEventCallbacksTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(211);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAssignDeviceIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(212);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAssignInstanceIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(213);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAddHardwareIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(214);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAddCompatibleIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(215);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAddDeviceTextTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(216);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitSetDefaultLocaleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(217);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAssignRawDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(218);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoMarkMissingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(219);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoRequestEjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(220);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoGetParentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(221);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoRetrieveIdentificationDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        222,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoRetrieveAddressDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        223,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoUpdateAddressDescriptionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(224);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoAddEjectionRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        225,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoRemoveEjectionRelationsPhysicalDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        226,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoClearEjectionRelationsDevicesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        227,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAddQueryInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(228);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryOpenKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(229);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryCreateKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(230);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryCloseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(231);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryWdmGetHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(232);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryRemoveKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(233);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryRemoveValueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(234);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryQueryValueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(235);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryQueryMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(236);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryQueryMultiStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(237);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryQueryUnicodeStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(238);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryQueryStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(239);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryQueryULongTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(240);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryAssignValueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(241);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryAssignMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(242);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryAssignMultiStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(243);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryAssignUnicodeStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(244);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryAssignStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(245);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRegistryAssignULongTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(246);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(247);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestCreateFromIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(248);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestReuseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(249);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestChangeTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(250);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestFormatRequestUsingCurrentTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        251,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestWdmFormatUsingStackLocationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        252,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestSendTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(253);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetStatusTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(254);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestMarkCancelableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(255);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestUnmarkCancelableTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(256);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestIsCanceledTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(257);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestCancelSentRequestTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(258);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestIsFrom32BitProcessTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(259);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestSetCompletionRoutineTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(260);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetCompletionParamsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(261);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestAllocateTimerTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(262);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestCompleteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(263);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestCompleteWithPriorityBoostTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        264,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestCompleteWithInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        265,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetParametersTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(266);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveInputMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(267);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveOutputMemoryTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(268);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveInputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(269);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveOutputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(270);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveInputWdmMdlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(271);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveOutputWdmMdlTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(272);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveUnsafeUserInputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        273,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRetrieveUnsafeUserOutputBufferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        274,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestSetInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(275);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(276);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetFileObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(277);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestProbeAndLockUserBufferForReadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        278,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestProbeAndLockUserBufferForWriteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        279,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetRequestorModeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(280);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestForwardToIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(281);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestGetIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(282);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestRequeueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(283);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestStopAcknowledgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(284);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestWdmGetIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(285);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListSetSlotNumberTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        286,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListSetInterfaceTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        287,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListAppendIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        288,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListInsertIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        289,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        290,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListGetIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        291,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        292,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceRequirementsListRemoveByIoResListTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        293,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(294);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListAppendDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        295,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListInsertDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        296,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListUpdateDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        297,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(298);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListGetDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(299);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(300);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoResourceListRemoveByDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        301,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCmResourceListAppendDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        302,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCmResourceListInsertDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        303,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCmResourceListGetCountTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(304);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCmResourceListGetDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(305);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCmResourceListRemoveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(306);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCmResourceListRemoveByDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        307,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfStringCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(308);
+}
+impl _WDFFUNCENUM {
+    pub const WdfStringGetUnicodeStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(309);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectAcquireLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(310);
+}
+impl _WDFFUNCENUM {
+    pub const WdfObjectReleaseLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(311);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWaitLockCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(312);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWaitLockAcquireTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(313);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWaitLockReleaseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(314);
+}
+impl _WDFFUNCENUM {
+    pub const WdfSpinLockCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(315);
+}
+impl _WDFFUNCENUM {
+    pub const WdfSpinLockAcquireTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(316);
+}
+impl _WDFFUNCENUM {
+    pub const WdfSpinLockReleaseTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(317);
+}
+impl _WDFFUNCENUM {
+    pub const WdfTimerCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(318);
+}
+impl _WDFFUNCENUM {
+    pub const WdfTimerStartTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(319);
+}
+impl _WDFFUNCENUM {
+    pub const WdfTimerStopTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(320);
+}
+impl _WDFFUNCENUM {
+    pub const WdfTimerGetParentObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(321);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(322);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceRetrieveInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        323,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceGetDeviceDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        324,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceRetrieveConfigDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        325,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceQueryStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(326);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceAllocAndQueryStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        327,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceFormatRequestForStringTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        328,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceGetNumInterfacesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        329,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceSelectConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(330);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceWdmGetConfigurationHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        331,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceRetrieveCurrentFrameNumberTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        332,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceSendControlTransferSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        333,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceFormatRequestForControlTransferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        334,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceIsConnectedSynchronousTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        335,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceResetPortSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        336,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceCyclePortSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        337,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceFormatRequestForCyclePortTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        338,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceSendUrbSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        339,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceFormatRequestForUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        340,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeGetInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(341);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeIsInEndpointTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(342);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeIsOutEndpointTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(343);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeGetTypeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(344);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeSetNoMaximumPacketSizeCheckTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        345,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeWriteSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        346,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeFormatRequestForWriteTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        347,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeReadSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        348,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeFormatRequestForReadTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        349,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeConfigContinuousReaderTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        350,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeAbortSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        351,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeFormatRequestForAbortTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        352,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeResetSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        353,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeFormatRequestForResetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        354,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeSendUrbSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        355,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeFormatRequestForUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        356,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetInterfaceNumberTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        357,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetNumEndpointsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(358);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetDescriptorTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(359);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceSelectSettingTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(360);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetEndpointInformationTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        361,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceGetInterfaceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(362);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetConfiguredSettingIndexTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        363,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetNumConfiguredPipesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        364,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetConfiguredPipeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        365,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetPipeWdmGetPipeHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        366,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfVerifierDbgBreakPointTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(367);
+}
+impl _WDFFUNCENUM {
+    pub const WdfVerifierKeBugCheckTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(368);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiProviderCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(369);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiProviderGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(370);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiProviderIsEnabledTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(371);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiProviderGetTracingHandleTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(372);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiInstanceCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(373);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiInstanceRegisterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(374);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiInstanceDeregisterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(375);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiInstanceGetDeviceTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(376);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiInstanceGetProviderTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(377);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWmiInstanceFireEventTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(378);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWorkItemCreateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(379);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWorkItemEnqueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(380);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWorkItemGetParentObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(381);
+}
+impl _WDFFUNCENUM {
+    pub const WdfWorkItemFlushTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(382);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCommonBufferCreateWithConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        383,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerGetFragmentLengthTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(384);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerWdmGetDmaAdapterTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(385);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbInterfaceGetNumSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(386);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceRemoveDependentUsageDeviceObjectTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        387,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetSystemPowerActionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(388);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptSetExtendedPolicyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(389);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueAssignForwardProgressPolicyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        390,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAssignContainerIDTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(391);
+}
+impl _WDFFUNCENUM {
+    pub const WdfPdoInitAllowForwardingRequestToParentTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        392,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestMarkCancelableExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(393);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestIsReservedTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(394);
+}
+impl _WDFFUNCENUM {
+    pub const WdfRequestForwardToParentDeviceIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        395,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCxDeviceInitAllocateTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(396);
+}
+impl _WDFFUNCENUM {
+    pub const WdfCxDeviceInitAssignWdmIrpPreprocessCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        397,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCxDeviceInitSetIoInCallerContextCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        398,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCxDeviceInitSetRequestAttributesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        399,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCxDeviceInitSetFileObjectConfigTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        400,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmDispatchIrpTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(401);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmDispatchIrpToIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        402,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetRemoveLockOptionsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        403,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceConfigureWdmIrpDispatchCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        404,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaEnablerConfigureSystemProfileTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        405,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionInitializeUsingOffsetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        406,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionGetTransferInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        407,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionSetChannelConfigurationCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        408,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionSetTransferCompleteCallbackTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        409,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionSetImmediateExecutionTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        410,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionAllocateResourcesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        411,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionSetDeviceAddressOffsetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        412,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionFreeResourcesTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(413);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionCancelTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(414);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionWdmGetTransferContextTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        415,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptQueueWorkItemForIsrTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        416,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptTryToAcquireLockTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(417);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueStopAndPurgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(418);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoQueueStopAndPurgeSynchronouslyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        419,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetPurgeTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(420);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceCreateWithParametersTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        421,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceQueryUsbCapabilityTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        422,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceCreateUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(423);
+}
+impl _WDFFUNCENUM {
+    pub const WdfUsbTargetDeviceCreateIsochUrbTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        424,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceWdmAssignPowerFrameworkSettingsTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        425,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDmaTransactionStopSystemTransferTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        426,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfCxVerifierKeBugCheckTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(427);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptReportActiveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(428);
+}
+impl _WDFFUNCENUM {
+    pub const WdfInterruptReportInactiveTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(429);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetReleaseHardwareOrderOnFailureTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        430,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfGetTriageInfoTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(431);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitSetIoTypeExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(432);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(433);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAllocAndQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        434,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceAssignPropertyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(435);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(436);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFdoInitAllocAndQueryPropertyExTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        437,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceStopIdleActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(438);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceResumeIdleActualTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(439);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceGetSelfIoTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(440);
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceInitAllowSelfIoTargetTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(441);
+}
+impl _WDFFUNCENUM {
+    pub const WdfIoTargetSelfAssignDefaultIoQueueTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(
+        442,
+    );
+}
+impl _WDFFUNCENUM {
+    pub const WdfDeviceOpenDevicemapKeyTableIndex: _WDFFUNCENUM = _WDFFUNCENUM(443);
+}
+impl _WDFFUNCENUM {
+    pub const WdfFunctionTableNumEntries: _WDFFUNCENUM = _WDFFUNCENUM(444);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDFFUNCENUM(pub ::libc::c_int);
+pub use self::_WDFFUNCENUM as WDFFUNCENUM;
+impl _WDF_EXECUTION_LEVEL {
+    pub const WdfExecutionLevelInvalid: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(0);
+}
+impl _WDF_EXECUTION_LEVEL {
+    pub const WdfExecutionLevelInheritFromParent: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(
+        1,
+    );
+}
+impl _WDF_EXECUTION_LEVEL {
+    pub const WdfExecutionLevelPassive: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(2);
+}
+impl _WDF_EXECUTION_LEVEL {
+    pub const WdfExecutionLevelDispatch: _WDF_EXECUTION_LEVEL = _WDF_EXECUTION_LEVEL(3);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_EXECUTION_LEVEL(pub ::libc::c_int);
+pub use self::_WDF_EXECUTION_LEVEL as WDF_EXECUTION_LEVEL;
+impl _WDF_SYNCHRONIZATION_SCOPE {
+    pub const WdfSynchronizationScopeInvalid: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
+        0,
+    );
+}
+impl _WDF_SYNCHRONIZATION_SCOPE {
+    pub const WdfSynchronizationScopeInheritFromParent: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
+        1,
+    );
+}
+impl _WDF_SYNCHRONIZATION_SCOPE {
+    pub const WdfSynchronizationScopeDevice: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
+        2,
+    );
+}
+impl _WDF_SYNCHRONIZATION_SCOPE {
+    pub const WdfSynchronizationScopeQueue: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
+        3,
+    );
+}
+impl _WDF_SYNCHRONIZATION_SCOPE {
+    pub const WdfSynchronizationScopeNone: _WDF_SYNCHRONIZATION_SCOPE = _WDF_SYNCHRONIZATION_SCOPE(
+        4,
+    );
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_SYNCHRONIZATION_SCOPE(pub ::libc::c_int);
+pub use self::_WDF_SYNCHRONIZATION_SCOPE as WDF_SYNCHRONIZATION_SCOPE;
+pub type EVT_WDF_OBJECT_CONTEXT_CLEANUP = ::core::option::Option<
+    unsafe extern "system" fn(Object: WDFOBJECT),
+>;
+pub type PFN_WDF_OBJECT_CONTEXT_CLEANUP = EVT_WDF_OBJECT_CONTEXT_CLEANUP;
+pub type EVT_WDF_OBJECT_CONTEXT_DESTROY = ::core::option::Option<
+    unsafe extern "system" fn(Object: WDFOBJECT),
+>;
+pub type PFN_WDF_OBJECT_CONTEXT_DESTROY = EVT_WDF_OBJECT_CONTEXT_DESTROY;
+pub type PCWDF_OBJECT_CONTEXT_TYPE_INFO = *const _WDF_OBJECT_CONTEXT_TYPE_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_OBJECT_ATTRIBUTES {
+    pub Size: ULONG,
+    pub EvtCleanupCallback: PFN_WDF_OBJECT_CONTEXT_CLEANUP,
+    pub EvtDestroyCallback: PFN_WDF_OBJECT_CONTEXT_DESTROY,
+    pub ExecutionLevel: WDF_EXECUTION_LEVEL,
+    pub SynchronizationScope: WDF_SYNCHRONIZATION_SCOPE,
+    pub ParentObject: WDFOBJECT,
+    pub ContextSizeOverride: usize,
+    pub ContextTypeInfo: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+}
+pub type WDF_OBJECT_ATTRIBUTES = _WDF_OBJECT_ATTRIBUTES;
+pub type PFN_GET_UNIQUE_CONTEXT_TYPE = ::core::option::Option<
+    unsafe extern "system" fn() -> PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_OBJECT_CONTEXT_TYPE_INFO {
+    pub Size: ULONG,
+    pub ContextName: LPCSTR,
+    pub ContextSize: usize,
+    pub UniqueType: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+    pub EvtDriverGetUniqueContextType: PFN_GET_UNIQUE_CONTEXT_TYPE,
+}
+pub type WDF_OBJECT_CONTEXT_TYPE_INFO = _WDF_OBJECT_CONTEXT_TYPE_INFO;
+pub type PFN_WDFOBJECTGETTYPEDCONTEXTWORKER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Handle: WDFOBJECT,
+        TypeInfo: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+    ) -> PVOID,
+>;
+pub type PFN_WDFOBJECTREFERENCEACTUAL = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Handle: WDFOBJECT,
+        Tag: PVOID,
+        Line: LONG,
+        File: PCHAR,
+    ),
+>;
+pub type PFN_WDFOBJECTDEREFERENCEACTUAL = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Handle: WDFOBJECT,
+        Tag: PVOID,
+        Line: LONG,
+        File: PCHAR,
+    ),
+>;
+pub type PFN_WDFOBJECTDELETE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Object: WDFOBJECT),
+>;
+impl _WDF_DRIVER_INIT_FLAGS {
+    pub const WdfDriverInitNonPnpDriver: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(
+        1,
+    );
+}
+impl _WDF_DRIVER_INIT_FLAGS {
+    pub const WdfDriverInitNoDispatchOverride: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(
+        2,
+    );
+}
+impl _WDF_DRIVER_INIT_FLAGS {
+    pub const WdfVerifyOn: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(4);
+}
+impl _WDF_DRIVER_INIT_FLAGS {
+    pub const WdfVerifierOn: _WDF_DRIVER_INIT_FLAGS = _WDF_DRIVER_INIT_FLAGS(8);
+}
+impl ::core::ops::BitOr<_WDF_DRIVER_INIT_FLAGS> for _WDF_DRIVER_INIT_FLAGS {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        _WDF_DRIVER_INIT_FLAGS(self.0 | other.0)
+    }
+}
+impl ::core::ops::BitOrAssign for _WDF_DRIVER_INIT_FLAGS {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: _WDF_DRIVER_INIT_FLAGS) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::core::ops::BitAnd<_WDF_DRIVER_INIT_FLAGS> for _WDF_DRIVER_INIT_FLAGS {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        _WDF_DRIVER_INIT_FLAGS(self.0 & other.0)
+    }
+}
+impl ::core::ops::BitAndAssign for _WDF_DRIVER_INIT_FLAGS {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: _WDF_DRIVER_INIT_FLAGS) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_DRIVER_INIT_FLAGS(pub ::libc::c_int);
+pub use self::_WDF_DRIVER_INIT_FLAGS as WDF_DRIVER_INIT_FLAGS;
+pub type EVT_WDF_DRIVER_DEVICE_ADD = ::core::option::Option<
+    unsafe extern "system" fn(Driver: WDFDRIVER, DeviceInit: PWDFDEVICE_INIT) -> NTSTATUS,
+>;
+pub type PFN_WDF_DRIVER_DEVICE_ADD = EVT_WDF_DRIVER_DEVICE_ADD;
+pub type EVT_WDF_DRIVER_UNLOAD = ::core::option::Option<
+    unsafe extern "system" fn(Driver: WDFDRIVER),
+>;
+pub type PFN_WDF_DRIVER_UNLOAD = EVT_WDF_DRIVER_UNLOAD;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_DRIVER_CONFIG {
+    pub Size: ULONG,
+    pub EvtDriverDeviceAdd: PFN_WDF_DRIVER_DEVICE_ADD,
+    pub EvtDriverUnload: PFN_WDF_DRIVER_UNLOAD,
+    pub DriverInitFlags: ULONG,
+    pub DriverPoolTag: ULONG,
+}
+pub type WDF_DRIVER_CONFIG = _WDF_DRIVER_CONFIG;
+pub type PWDF_DRIVER_CONFIG = *mut _WDF_DRIVER_CONFIG;
+pub type PFN_WDFDRIVERCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DriverObject: PDRIVER_OBJECT,
+        RegistryPath: PCUNICODE_STRING,
+        DriverAttributes: PWDF_OBJECT_ATTRIBUTES,
+        DriverConfig: PWDF_DRIVER_CONFIG,
+        Driver: *mut WDFDRIVER,
+    ) -> NTSTATUS,
+>;
+impl _WDF_DEVICE_IO_TYPE {
+    pub const WdfDeviceIoUndefined: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(0);
+}
+impl _WDF_DEVICE_IO_TYPE {
+    pub const WdfDeviceIoNeither: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(1);
+}
+impl _WDF_DEVICE_IO_TYPE {
+    pub const WdfDeviceIoBuffered: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(2);
+}
+impl _WDF_DEVICE_IO_TYPE {
+    pub const WdfDeviceIoDirect: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(3);
+}
+impl _WDF_DEVICE_IO_TYPE {
+    pub const WdfDeviceIoBufferedOrDirect: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(4);
+}
+impl _WDF_DEVICE_IO_TYPE {
+    pub const WdfDeviceIoMaximum: _WDF_DEVICE_IO_TYPE = _WDF_DEVICE_IO_TYPE(5);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_DEVICE_IO_TYPE(pub ::libc::c_int);
+pub use self::_WDF_DEVICE_IO_TYPE as WDF_DEVICE_IO_TYPE;
+impl _WDF_FILEOBJECT_CLASS {
+    pub const WdfFileObjectInvalid: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(0);
+}
+impl _WDF_FILEOBJECT_CLASS {
+    pub const WdfFileObjectNotRequired: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(1);
+}
+impl _WDF_FILEOBJECT_CLASS {
+    pub const WdfFileObjectWdfCanUseFsContext: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
+        2,
+    );
+}
+impl _WDF_FILEOBJECT_CLASS {
+    pub const WdfFileObjectWdfCanUseFsContext2: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
+        3,
+    );
+}
+impl _WDF_FILEOBJECT_CLASS {
+    pub const WdfFileObjectWdfCannotUseFsContexts: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
+        4,
+    );
+}
+impl _WDF_FILEOBJECT_CLASS {
+    pub const WdfFileObjectCanBeOptional: _WDF_FILEOBJECT_CLASS = _WDF_FILEOBJECT_CLASS(
+        -2147483648,
+    );
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_FILEOBJECT_CLASS(pub ::libc::c_int);
+pub use self::_WDF_FILEOBJECT_CLASS as WDF_FILEOBJECT_CLASS;
+pub type EVT_WDF_DEVICE_FILE_CREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        Device: WDFDEVICE,
+        Request: WDFREQUEST,
+        FileObject: WDFFILEOBJECT,
+    ),
+>;
+pub type PFN_WDF_DEVICE_FILE_CREATE = EVT_WDF_DEVICE_FILE_CREATE;
+pub type EVT_WDF_FILE_CLOSE = ::core::option::Option<
+    unsafe extern "system" fn(FileObject: WDFFILEOBJECT),
+>;
+pub type PFN_WDF_FILE_CLOSE = EVT_WDF_FILE_CLOSE;
+pub type EVT_WDF_FILE_CLEANUP = ::core::option::Option<
+    unsafe extern "system" fn(FileObject: WDFFILEOBJECT),
+>;
+pub type PFN_WDF_FILE_CLEANUP = EVT_WDF_FILE_CLEANUP;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_FILEOBJECT_CONFIG {
+    pub Size: ULONG,
+    pub EvtDeviceFileCreate: PFN_WDF_DEVICE_FILE_CREATE,
+    pub EvtFileClose: PFN_WDF_FILE_CLOSE,
+    pub EvtFileCleanup: PFN_WDF_FILE_CLEANUP,
+    pub AutoForwardCleanupClose: WDF_TRI_STATE,
+    pub FileObjectClass: WDF_FILEOBJECT_CLASS,
+}
+pub type WDF_FILEOBJECT_CONFIG = _WDF_FILEOBJECT_CONFIG;
+pub type PWDF_FILEOBJECT_CONFIG = *mut _WDF_FILEOBJECT_CONFIG;
+pub type PFN_WDFDEVICEINITFREE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, DeviceInit: PWDFDEVICE_INIT),
+>;
+pub type PFN_WDFDEVICEINITSETEXCLUSIVE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        IsExclusive: BOOLEAN,
+    ),
+>;
+pub type PFN_WDFDEVICEINITSETIOTYPE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        IoType: WDF_DEVICE_IO_TYPE,
+    ),
+>;
+pub type PFN_WDFDEVICEINITASSIGNNAME = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        DeviceName: PCUNICODE_STRING,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFDEVICEINITSETFILEOBJECTCONFIG = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        FileObjectConfig: PWDF_FILEOBJECT_CONFIG,
+        FileObjectAttributes: PWDF_OBJECT_ATTRIBUTES,
+    ),
+>;
+pub type PFN_WDFDEVICEINITSETREQUESTATTRIBUTES = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        RequestAttributes: PWDF_OBJECT_ATTRIBUTES,
+    ),
+>;
+pub type PFN_WDFDEVICECREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: *mut PWDFDEVICE_INIT,
+        DeviceAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Device: *mut WDFDEVICE,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFDEVICECREATESYMBOLICLINK = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        SymbolicLinkName: PCUNICODE_STRING,
+    ) -> NTSTATUS,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFSTRING__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFSTRING = *mut WDFSTRING__;
+pub type PFN_WDFDEVICERETRIEVEDEVICENAME = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        String: WDFSTRING,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFSTRINGCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UnicodeString: PCUNICODE_STRING,
+        StringAttributes: PWDF_OBJECT_ATTRIBUTES,
+        String: *mut WDFSTRING,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFSTRINGGETUNICODESTRING = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        String: WDFSTRING,
+        UnicodeString: PUNICODE_STRING,
+    ),
+>;
+pub type PFN_WDFREQUESTCOMPLETE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Status: NTSTATUS,
+    ),
+>;
+pub type PFN_WDFREQUESTRETRIEVEINPUTBUFFER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        MinimumRequiredLength: usize,
+        Buffer: *mut PVOID,
+        Length: *mut usize,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTRETRIEVEOUTPUTBUFFER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        MinimumRequiredSize: usize,
+        Buffer: *mut PVOID,
+        Length: *mut usize,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTRETRIEVEINPUTWDMMDL = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Mdl: *mut PMDL,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTRETRIEVEOUTPUTWDMMDL = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Mdl: *mut PMDL,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTSETINFORMATION = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Information: ULONG_PTR,
+    ),
+>;
+pub type PFN_WDFREQUESTGETREQUESTORMODE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+    ) -> KPROCESSOR_MODE,
+>;
+pub type PFN_WDFREQUESTISFROM32BITPROCESS = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Request: WDFREQUEST) -> BOOLEAN,
+>;
+impl _WDF_IO_QUEUE_DISPATCH_TYPE {
+    pub const WdfIoQueueDispatchInvalid: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
+        0,
+    );
+}
+impl _WDF_IO_QUEUE_DISPATCH_TYPE {
+    pub const WdfIoQueueDispatchSequential: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
+        1,
+    );
+}
+impl _WDF_IO_QUEUE_DISPATCH_TYPE {
+    pub const WdfIoQueueDispatchParallel: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
+        2,
+    );
+}
+impl _WDF_IO_QUEUE_DISPATCH_TYPE {
+    pub const WdfIoQueueDispatchManual: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
+        3,
+    );
+}
+impl _WDF_IO_QUEUE_DISPATCH_TYPE {
+    pub const WdfIoQueueDispatchMax: _WDF_IO_QUEUE_DISPATCH_TYPE = _WDF_IO_QUEUE_DISPATCH_TYPE(
+        4,
+    );
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_IO_QUEUE_DISPATCH_TYPE(pub ::libc::c_int);
+pub use self::_WDF_IO_QUEUE_DISPATCH_TYPE as WDF_IO_QUEUE_DISPATCH_TYPE;
+pub type EVT_WDF_IO_QUEUE_IO_DEFAULT = ::core::option::Option<
+    unsafe extern "system" fn(Queue: WDFQUEUE, Request: WDFREQUEST),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_DEFAULT = EVT_WDF_IO_QUEUE_IO_DEFAULT;
+pub type EVT_WDF_IO_QUEUE_IO_STOP = ::core::option::Option<
+    unsafe extern "system" fn(Queue: WDFQUEUE, Request: WDFREQUEST, ActionFlags: ULONG),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_STOP = EVT_WDF_IO_QUEUE_IO_STOP;
+pub type EVT_WDF_IO_QUEUE_IO_RESUME = ::core::option::Option<
+    unsafe extern "system" fn(Queue: WDFQUEUE, Request: WDFREQUEST),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_RESUME = EVT_WDF_IO_QUEUE_IO_RESUME;
+pub type EVT_WDF_IO_QUEUE_IO_READ = ::core::option::Option<
+    unsafe extern "system" fn(Queue: WDFQUEUE, Request: WDFREQUEST, Length: usize),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_READ = EVT_WDF_IO_QUEUE_IO_READ;
+pub type EVT_WDF_IO_QUEUE_IO_WRITE = ::core::option::Option<
+    unsafe extern "system" fn(Queue: WDFQUEUE, Request: WDFREQUEST, Length: usize),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_WRITE = EVT_WDF_IO_QUEUE_IO_WRITE;
+pub type EVT_WDF_IO_QUEUE_IO_DEVICE_CONTROL = ::core::option::Option<
+    unsafe extern "system" fn(
+        Queue: WDFQUEUE,
+        Request: WDFREQUEST,
+        OutputBufferLength: usize,
+        InputBufferLength: usize,
+        IoControlCode: ULONG,
+    ),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_DEVICE_CONTROL = EVT_WDF_IO_QUEUE_IO_DEVICE_CONTROL;
+pub type EVT_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL = ::core::option::Option<
+    unsafe extern "system" fn(
+        Queue: WDFQUEUE,
+        Request: WDFREQUEST,
+        OutputBufferLength: usize,
+        InputBufferLength: usize,
+        IoControlCode: ULONG,
+    ),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL = EVT_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL;
+pub type EVT_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE = ::core::option::Option<
+    unsafe extern "system" fn(Queue: WDFQUEUE, Request: WDFREQUEST),
+>;
+pub type PFN_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE = EVT_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WDF_IO_QUEUE_CONFIG {
+    pub Size: ULONG,
+    pub DispatchType: WDF_IO_QUEUE_DISPATCH_TYPE,
+    pub PowerManaged: WDF_TRI_STATE,
+    pub AllowZeroLengthRequests: BOOLEAN,
+    pub DefaultQueue: BOOLEAN,
+    pub EvtIoDefault: PFN_WDF_IO_QUEUE_IO_DEFAULT,
+    pub EvtIoRead: PFN_WDF_IO_QUEUE_IO_READ,
+    pub EvtIoWrite: PFN_WDF_IO_QUEUE_IO_WRITE,
+    pub EvtIoDeviceControl: PFN_WDF_IO_QUEUE_IO_DEVICE_CONTROL,
+    pub EvtIoInternalDeviceControl: PFN_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL,
+    pub EvtIoStop: PFN_WDF_IO_QUEUE_IO_STOP,
+    pub EvtIoResume: PFN_WDF_IO_QUEUE_IO_RESUME,
+    pub EvtIoCanceledOnQueue: PFN_WDF_IO_QUEUE_IO_CANCELED_ON_QUEUE,
+    pub Settings: _WDF_IO_QUEUE_CONFIG__bindgen_ty_1,
+    pub Driver: WDFDRIVER,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_IO_QUEUE_CONFIG__bindgen_ty_1 {
+    pub Parallel: _WDF_IO_QUEUE_CONFIG__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_IO_QUEUE_CONFIG__bindgen_ty_1__bindgen_ty_1 {
+    pub NumberOfPresentedRequests: ULONG,
+}
+pub type WDF_IO_QUEUE_CONFIG = _WDF_IO_QUEUE_CONFIG;
+pub type PWDF_IO_QUEUE_CONFIG = *mut _WDF_IO_QUEUE_CONFIG;
+pub type PFN_WDFIOQUEUECREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        Config: PWDF_IO_QUEUE_CONFIG,
+        QueueAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Queue: *mut WDFQUEUE,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOQUEUEGETDEVICE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+    ) -> WDFDEVICE,
+>;
+pub type PFN_WDFCONTROLDEVICEINITALLOCATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Driver: WDFDRIVER,
+        SDDLString: *const UNICODE_STRING,
+    ) -> PWDFDEVICE_INIT,
+>;
+pub type PFN_WDFCONTROLFINISHINITIALIZING = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Device: WDFDEVICE),
+>;
+impl _WDF_DEVICE_SHUTDOWN_FLAGS {
+    pub const WdfDeviceShutdown: _WDF_DEVICE_SHUTDOWN_FLAGS = _WDF_DEVICE_SHUTDOWN_FLAGS(1);
+}
+impl _WDF_DEVICE_SHUTDOWN_FLAGS {
+    pub const WdfDeviceLastChanceShutdown: _WDF_DEVICE_SHUTDOWN_FLAGS =
+        _WDF_DEVICE_SHUTDOWN_FLAGS(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_DEVICE_SHUTDOWN_FLAGS(pub ::libc::c_int);
+pub use self::_WDF_DEVICE_SHUTDOWN_FLAGS as WDF_DEVICE_SHUTDOWN_FLAGS;
+pub type EVT_WDF_DEVICE_SHUTDOWN_NOTIFICATION =
+    ::core::option::Option<unsafe extern "system" fn(Device: WDFDEVICE)>;
+pub type PFN_WDF_DEVICE_SHUTDOWN_NOTIFICATION = EVT_WDF_DEVICE_SHUTDOWN_NOTIFICATION;
+pub type PFN_WDFCONTROLDEVICEINITSETSHUTDOWNNOTIFICATION = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        Notification: PFN_WDF_DEVICE_SHUTDOWN_NOTIFICATION,
+        Flags: UCHAR,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFMEMORY__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFMEMORY = *mut WDFMEMORY__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFIOTARGET__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFIOTARGET = *mut WDFIOTARGET__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDFMEMORY_OFFSET {
+    pub BufferOffset: usize,
+    pub BufferLength: usize,
+}
+pub type WDFMEMORY_OFFSET = _WDFMEMORY_OFFSET;
+pub type PWDFMEMORY_OFFSET = *mut _WDFMEMORY_OFFSET;
+impl _WDF_MEMORY_DESCRIPTOR_TYPE {
+    pub const WdfMemoryDescriptorTypeInvalid: _WDF_MEMORY_DESCRIPTOR_TYPE =
+        _WDF_MEMORY_DESCRIPTOR_TYPE(0);
+}
+impl _WDF_MEMORY_DESCRIPTOR_TYPE {
+    pub const WdfMemoryDescriptorTypeBuffer: _WDF_MEMORY_DESCRIPTOR_TYPE =
+        _WDF_MEMORY_DESCRIPTOR_TYPE(1);
+}
+impl _WDF_MEMORY_DESCRIPTOR_TYPE {
+    pub const WdfMemoryDescriptorTypeMdl: _WDF_MEMORY_DESCRIPTOR_TYPE =
+        _WDF_MEMORY_DESCRIPTOR_TYPE(2);
+}
+impl _WDF_MEMORY_DESCRIPTOR_TYPE {
+    pub const WdfMemoryDescriptorTypeHandle: _WDF_MEMORY_DESCRIPTOR_TYPE =
+        _WDF_MEMORY_DESCRIPTOR_TYPE(3);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_MEMORY_DESCRIPTOR_TYPE(pub ::libc::c_int);
+pub use self::_WDF_MEMORY_DESCRIPTOR_TYPE as WDF_MEMORY_DESCRIPTOR_TYPE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WDF_MEMORY_DESCRIPTOR {
+    pub Type: WDF_MEMORY_DESCRIPTOR_TYPE,
+    pub u: _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1 {
+    pub BufferType: _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1__bindgen_ty_1,
+    pub MdlType: _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1__bindgen_ty_2,
+    pub HandleType: _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1__bindgen_ty_3,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1__bindgen_ty_1 {
+    pub Buffer: PVOID,
+    pub Length: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1__bindgen_ty_2 {
+    pub Mdl: PMDL,
+    pub BufferLength: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_MEMORY_DESCRIPTOR__bindgen_ty_1__bindgen_ty_3 {
+    pub Memory: WDFMEMORY,
+    pub Offsets: PWDFMEMORY_OFFSET,
+}
+pub type WDF_MEMORY_DESCRIPTOR = _WDF_MEMORY_DESCRIPTOR;
+pub type PWDF_MEMORY_DESCRIPTOR = *mut _WDF_MEMORY_DESCRIPTOR;
+impl _WDF_REQUEST_SEND_OPTIONS_FLAGS {
+    pub const WDF_REQUEST_SEND_OPTION_TIMEOUT: _WDF_REQUEST_SEND_OPTIONS_FLAGS =
+        _WDF_REQUEST_SEND_OPTIONS_FLAGS(1);
+}
+impl _WDF_REQUEST_SEND_OPTIONS_FLAGS {
+    pub const WDF_REQUEST_SEND_OPTION_SYNCHRONOUS: _WDF_REQUEST_SEND_OPTIONS_FLAGS =
+        _WDF_REQUEST_SEND_OPTIONS_FLAGS(2);
+}
+impl _WDF_REQUEST_SEND_OPTIONS_FLAGS {
+    pub const WDF_REQUEST_SEND_OPTION_IGNORE_TARGET_STATE: _WDF_REQUEST_SEND_OPTIONS_FLAGS =
+        _WDF_REQUEST_SEND_OPTIONS_FLAGS(4);
+}
+impl _WDF_REQUEST_SEND_OPTIONS_FLAGS {
+    pub const WDF_REQUEST_SEND_OPTION_SEND_AND_FORGET: _WDF_REQUEST_SEND_OPTIONS_FLAGS =
+        _WDF_REQUEST_SEND_OPTIONS_FLAGS(8);
+}
+impl _WDF_REQUEST_SEND_OPTIONS_FLAGS {
+    pub const WDF_REQUEST_SEND_OPTION_IMPERSONATE_CLIENT: _WDF_REQUEST_SEND_OPTIONS_FLAGS =
+        _WDF_REQUEST_SEND_OPTIONS_FLAGS(16);
+}
+impl _WDF_REQUEST_SEND_OPTIONS_FLAGS {
+    pub const WDF_REQUEST_SEND_OPTION_IMPERSONATION_IGNORE_FAILURE:
+        _WDF_REQUEST_SEND_OPTIONS_FLAGS = _WDF_REQUEST_SEND_OPTIONS_FLAGS(32);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_REQUEST_SEND_OPTIONS_FLAGS(pub ::libc::c_int);
+pub use self::_WDF_REQUEST_SEND_OPTIONS_FLAGS as WDF_REQUEST_SEND_OPTIONS_FLAGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_SEND_OPTIONS {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub Timeout: LONGLONG,
+}
+pub type WDF_REQUEST_SEND_OPTIONS = _WDF_REQUEST_SEND_OPTIONS;
+pub type PWDF_REQUEST_SEND_OPTIONS = *mut _WDF_REQUEST_SEND_OPTIONS;
+impl _WDF_IO_TARGET_OPEN_TYPE {
+    pub const WdfIoTargetOpenUndefined: _WDF_IO_TARGET_OPEN_TYPE = _WDF_IO_TARGET_OPEN_TYPE(0);
+}
+impl _WDF_IO_TARGET_OPEN_TYPE {
+    pub const WdfIoTargetOpenUseExistingDevice: _WDF_IO_TARGET_OPEN_TYPE =
+        _WDF_IO_TARGET_OPEN_TYPE(1);
+}
+impl _WDF_IO_TARGET_OPEN_TYPE {
+    pub const WdfIoTargetOpenByName: _WDF_IO_TARGET_OPEN_TYPE = _WDF_IO_TARGET_OPEN_TYPE(2);
+}
+impl _WDF_IO_TARGET_OPEN_TYPE {
+    pub const WdfIoTargetOpenReopen: _WDF_IO_TARGET_OPEN_TYPE = _WDF_IO_TARGET_OPEN_TYPE(3);
+}
+impl _WDF_IO_TARGET_OPEN_TYPE {
+    pub const WdfIoTargetOpenLocalTargetByFile: _WDF_IO_TARGET_OPEN_TYPE =
+        _WDF_IO_TARGET_OPEN_TYPE(4);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_IO_TARGET_OPEN_TYPE(pub ::libc::c_int);
+pub use self::_WDF_IO_TARGET_OPEN_TYPE as WDF_IO_TARGET_OPEN_TYPE;
+pub type PFN_WDF_IO_TARGET_QUERY_REMOVE =
+    ::core::option::Option<unsafe extern "system" fn(IoTarget: WDFIOTARGET) -> NTSTATUS>;
+pub type PFN_WDF_IO_TARGET_REMOVE_CANCELED =
+    ::core::option::Option<unsafe extern "system" fn(IoTarget: WDFIOTARGET)>;
+pub type PFN_WDF_IO_TARGET_REMOVE_COMPLETE =
+    ::core::option::Option<unsafe extern "system" fn(IoTarget: WDFIOTARGET)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_IO_TARGET_OPEN_PARAMS {
+    pub Size: ULONG,
+    pub Type: WDF_IO_TARGET_OPEN_TYPE,
+    pub EvtIoTargetQueryRemove: PFN_WDF_IO_TARGET_QUERY_REMOVE,
+    pub EvtIoTargetRemoveCanceled: PFN_WDF_IO_TARGET_REMOVE_CANCELED,
+    pub EvtIoTargetRemoveComplete: PFN_WDF_IO_TARGET_REMOVE_COMPLETE,
+    pub TargetDeviceObject: PDEVICE_OBJECT,
+    pub TargetFileObject: PFILE_OBJECT,
+    pub TargetDeviceName: UNICODE_STRING,
+    pub DesiredAccess: ACCESS_MASK,
+    pub ShareAccess: ULONG,
+    pub FileAttributes: ULONG,
+    pub CreateDisposition: ULONG,
+    pub CreateOptions: ULONG,
+    pub EaBuffer: PVOID,
+    pub EaBufferLength: ULONG,
+    pub AllocationSize: PLONGLONG,
+    pub FileInformation: ULONG,
+    pub FileName: UNICODE_STRING,
+}
+pub type WDF_IO_TARGET_OPEN_PARAMS = _WDF_IO_TARGET_OPEN_PARAMS;
+pub type PWDF_IO_TARGET_OPEN_PARAMS = *mut _WDF_IO_TARGET_OPEN_PARAMS;
+pub type PFN_WDFDEVICEGETIOTARGET = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Device: WDFDEVICE) -> WDFIOTARGET,
+>;
+pub type PFN_WDFIOTARGETCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        IoTargetAttributes: PWDF_OBJECT_ATTRIBUTES,
+        IoTarget: *mut WDFIOTARGET,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOTARGETOPEN = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        IoTarget: WDFIOTARGET,
+        OpenParams: PWDF_IO_TARGET_OPEN_PARAMS,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOTARGETCLOSE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, IoTarget: WDFIOTARGET),
+>;
+pub type PFN_WDFIOTARGETSENDIOCTLSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        IoTarget: WDFIOTARGET,
+        Request: WDFREQUEST,
+        IoctlCode: ULONG,
+        InputBuffer: PWDF_MEMORY_DESCRIPTOR,
+        OutputBuffer: PWDF_MEMORY_DESCRIPTOR,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        BytesReturned: PULONG_PTR,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOTARGETSENDINTERNALIOCTLSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        IoTarget: WDFIOTARGET,
+        Request: WDFREQUEST,
+        IoctlCode: ULONG,
+        InputBuffer: PWDF_MEMORY_DESCRIPTOR,
+        OutputBuffer: PWDF_MEMORY_DESCRIPTOR,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        BytesReturned: PULONG_PTR,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOTARGETFORMATREQUESTFORIOCTL = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        IoTarget: WDFIOTARGET,
+        Request: WDFREQUEST,
+        IoctlCode: ULONG,
+        InputBuffer: WDFMEMORY,
+        InputBufferOffset: PWDFMEMORY_OFFSET,
+        OutputBuffer: WDFMEMORY,
+        OutputBufferOffset: PWDFMEMORY_OFFSET,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        RequestAttributes: PWDF_OBJECT_ATTRIBUTES,
+        IoTarget: WDFIOTARGET,
+        Request: *mut WDFREQUEST,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTSEND = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Target: WDFIOTARGET,
+        Options: PWDF_REQUEST_SEND_OPTIONS,
+    ) -> BOOLEAN,
+>;
+pub type PFN_WDFREQUESTGETSTATUS = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Request: WDFREQUEST) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTFORMATREQUESTUSINGCURRENTTYPE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Request: WDFREQUEST),
+>;
+pub type PFN_WDFMEMORYCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Attributes: PWDF_OBJECT_ATTRIBUTES,
+        PoolType: POOL_TYPE,
+        PoolTag: ULONG,
+        BufferSize: usize,
+        Memory: *mut WDFMEMORY,
+        Buffer: *mut PVOID,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFMEMORYGETBUFFER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Memory: WDFMEMORY,
+        BufferSize: *mut usize,
+    ) -> PVOID,
+>;
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeCreate: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(0);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeCreateNamedPipe: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(1);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeClose: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(2);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeRead: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(3);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeWrite: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(4);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeQueryInformation: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(5);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeSetInformation: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(6);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeQueryEA: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(7);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeSetEA: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(8);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeFlushBuffers: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(9);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeQueryVolumeInformation: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(10);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeSetVolumeInformation: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(11);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeDirectoryControl: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(12);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeFileSystemControl: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(13);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeDeviceControl: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(14);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeDeviceControlInternal: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(15);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeShutdown: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(16);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeLockControl: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(17);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeCleanup: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(18);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeCreateMailSlot: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(19);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeQuerySecurity: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(20);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeSetSecurity: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(21);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypePower: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(22);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeSystemControl: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(23);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeDeviceChange: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(24);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeQueryQuota: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(25);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeSetQuota: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(26);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypePnp: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(27);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeOther: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(28);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeUsb: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(64);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeNoFormat: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(255);
+}
+impl _WDF_REQUEST_TYPE {
+    pub const WdfRequestTypeMax: _WDF_REQUEST_TYPE = _WDF_REQUEST_TYPE(256);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_REQUEST_TYPE(pub ::libc::c_int);
+pub use self::_WDF_REQUEST_TYPE as WDF_REQUEST_TYPE;
+pub type PWDF_USB_REQUEST_COMPLETION_PARAMS = *mut _WDF_USB_REQUEST_COMPLETION_PARAMS;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS {
+    pub Size: ULONG,
+    pub Type: WDF_REQUEST_TYPE,
+    pub IoStatus: IO_STATUS_BLOCK,
+    pub Parameters: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1 {
+    pub Write: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_1,
+    pub Read: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_2,
+    pub Ioctl: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_3,
+    pub Others: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4,
+    pub Usb: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_5,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_1 {
+    pub Buffer: WDFMEMORY,
+    pub Length: usize,
+    pub Offset: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_2 {
+    pub Buffer: WDFMEMORY,
+    pub Length: usize,
+    pub Offset: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_3 {
+    pub IoControlCode: ULONG,
+    pub Input: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_3__bindgen_ty_1,
+    pub Output: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_3__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_3__bindgen_ty_1 {
+    pub Buffer: WDFMEMORY,
+    pub Offset: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_3__bindgen_ty_2 {
+    pub Buffer: WDFMEMORY,
+    pub Offset: usize,
+    pub Length: usize,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4 {
+    pub Argument1: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_1,
+    pub Argument2: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_2,
+    pub Argument3: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_3,
+    pub Argument4: _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_4,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_1 {
+    pub Ptr: PVOID,
+    pub Value: ULONG_PTR,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_2 {
+    pub Ptr: PVOID,
+    pub Value: ULONG_PTR,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_3 {
+    pub Ptr: PVOID,
+    pub Value: ULONG_PTR,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_4__bindgen_ty_4 {
+    pub Ptr: PVOID,
+    pub Value: ULONG_PTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_COMPLETION_PARAMS__bindgen_ty_1__bindgen_ty_5 {
+    pub Completion: PWDF_USB_REQUEST_COMPLETION_PARAMS,
+}
+pub type WDF_REQUEST_COMPLETION_PARAMS = _WDF_REQUEST_COMPLETION_PARAMS;
+pub type PWDF_REQUEST_COMPLETION_PARAMS = *mut _WDF_REQUEST_COMPLETION_PARAMS;
+pub type PFN_WDF_REQUEST_COMPLETION_ROUTINE = ::core::option::Option<
+    unsafe extern "system" fn(
+        Request: WDFREQUEST,
+        Target: WDFIOTARGET,
+        Params: PWDF_REQUEST_COMPLETION_PARAMS,
+        Context: WDFCONTEXT,
+    ),
+>;
+pub type PFN_WDFREQUESTSETCOMPLETIONROUTINE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        CompletionRoutine: PFN_WDF_REQUEST_COMPLETION_ROUTINE,
+        CompletionContext: WDFCONTEXT,
+    ),
+>;
+pub type PFN_WDFREQUESTGETFILEOBJECT = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+    ) -> WDFFILEOBJECT,
+>;
+pub type PFN_WDFFILEOBJECTGETDEVICE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        FileObject: WDFFILEOBJECT,
+    ) -> WDFDEVICE,
+>;
+pub type PFN_WDFREQUESTGETIOQUEUE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Request: WDFREQUEST) -> WDFQUEUE,
+>;
+pub type PFN_WDFREQUESTFORWARDTOIOQUEUE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        DestinationQueue: WDFQUEUE,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOQUEUERETRIEVENEXTREQUEST = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        OutRequest: *mut WDFREQUEST,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOQUEUEPURGESYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Queue: WDFQUEUE),
+>;
+impl _WDF_IO_QUEUE_STATE {
+    pub const WdfIoQueueAcceptRequests: _WDF_IO_QUEUE_STATE = _WDF_IO_QUEUE_STATE(1);
+}
+impl _WDF_IO_QUEUE_STATE {
+    pub const WdfIoQueueDispatchRequests: _WDF_IO_QUEUE_STATE = _WDF_IO_QUEUE_STATE(2);
+}
+impl _WDF_IO_QUEUE_STATE {
+    pub const WdfIoQueueNoRequests: _WDF_IO_QUEUE_STATE = _WDF_IO_QUEUE_STATE(4);
+}
+impl _WDF_IO_QUEUE_STATE {
+    pub const WdfIoQueueDriverNoRequests: _WDF_IO_QUEUE_STATE = _WDF_IO_QUEUE_STATE(8);
+}
+impl _WDF_IO_QUEUE_STATE {
+    pub const WdfIoQueuePnpHeld: _WDF_IO_QUEUE_STATE = _WDF_IO_QUEUE_STATE(16);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_IO_QUEUE_STATE(pub ::libc::c_int);
+pub use self::_WDF_IO_QUEUE_STATE as WDF_IO_QUEUE_STATE;
+pub type PFN_WDFIOQUEUEGETSTATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        QueueRequests: PULONG,
+        DriverRequests: PULONG,
+    ) -> WDF_IO_QUEUE_STATE,
+>;
+pub type PFN_WDFIOQUEUEFINDREQUEST = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        FoundRequest: WDFREQUEST,
+        FileObject: WDFFILEOBJECT,
+        Parameters: PWDF_REQUEST_PARAMETERS,
+        OutRequest: *mut WDFREQUEST,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOQUEUERETRIEVEFOUNDREQUEST = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        FoundRequest: WDFREQUEST,
+        OutRequest: *mut WDFREQUEST,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDF_IO_QUEUE_STATE =
+    ::core::option::Option<unsafe extern "system" fn(Queue: WDFQUEUE, Context: WDFCONTEXT)>;
+pub type PFN_WDFIOQUEUESTART = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Queue: WDFQUEUE),
+>;
+pub type PFN_WDFIOQUEUESTOP = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        StopComplete: PFN_WDF_IO_QUEUE_STATE,
+        Context: WDFCONTEXT,
+    ),
+>;
+pub type PFN_WDFIOQUEUESTOPSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Queue: WDFQUEUE),
+>;
+pub type PFN_WDFIOQUEUEDRAIN = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        DrainComplete: PFN_WDF_IO_QUEUE_STATE,
+        Context: WDFCONTEXT,
+    ),
+>;
+pub type PFN_WDFIOQUEUEDRAINSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Queue: WDFQUEUE),
+>;
+pub type PFN_WDFIOQUEUEPURGE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Queue: WDFQUEUE,
+        PurgeComplete: PFN_WDF_IO_QUEUE_STATE,
+        Context: WDFCONTEXT,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFSPINLOCK__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFSPINLOCK = *mut WDFSPINLOCK__;
+pub type PFN_WDFSPINLOCKCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        SpinLockAttributes: PWDF_OBJECT_ATTRIBUTES,
+        SpinLock: *mut WDFSPINLOCK,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFSPINLOCKACQUIRE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, SpinLock: WDFSPINLOCK),
+>;
+pub type PFN_WDFSPINLOCKRELEASE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, SpinLock: WDFSPINLOCK),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFWAITLOCK__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFWAITLOCK = *mut WDFWAITLOCK__;
+pub type PFN_WDFWAITLOCKCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        LockAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Lock: *mut WDFWAITLOCK,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFWAITLOCKACQUIRE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Lock: WDFWAITLOCK,
+        Timeout: PLONGLONG,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFWAITLOCKRELEASE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Lock: WDFWAITLOCK),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFCOLLECTION__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFCOLLECTION = *mut WDFCOLLECTION__;
+pub type PFN_WDFCOLLECTIONCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        CollectionAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Collection: *mut WDFCOLLECTION,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFCOLLECTIONGETCOUNT = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Collection: WDFCOLLECTION) -> ULONG,
+>;
+pub type PFN_WDFCOLLECTIONADD = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Collection: WDFCOLLECTION,
+        Object: WDFOBJECT,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFCOLLECTIONREMOVE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Collection: WDFCOLLECTION,
+        Item: WDFOBJECT,
+    ),
+>;
+pub type PFN_WDFCOLLECTIONREMOVEITEM = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Collection: WDFCOLLECTION,
+        Index: ULONG,
+    ),
+>;
+pub type PFN_WDFCOLLECTIONGETITEM = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Collection: WDFCOLLECTION,
+        Index: ULONG,
+    ) -> WDFOBJECT,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFTIMER__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFTIMER = *mut WDFTIMER__;
+pub type EVT_WDF_TIMER = ::core::option::Option<unsafe extern "system" fn(Timer: WDFTIMER)>;
+pub type PFN_WDF_TIMER = EVT_WDF_TIMER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_TIMER_CONFIG {
+    pub Size: ULONG,
+    pub EvtTimerFunc: PFN_WDF_TIMER,
+    pub Period: ULONG,
+    pub AutomaticSerialization: BOOLEAN,
+    pub TolerableDelay: ULONG,
+    pub UseHighResolutionTimer: BOOLEAN,
+}
+pub type WDF_TIMER_CONFIG = _WDF_TIMER_CONFIG;
+pub type PWDF_TIMER_CONFIG = *mut _WDF_TIMER_CONFIG;
+pub type PFN_WDFTIMERCREATE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Config: PWDF_TIMER_CONFIG,
+        Attributes: PWDF_OBJECT_ATTRIBUTES,
+        Timer: *mut WDFTIMER,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFTIMERSTART = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Timer: WDFTIMER,
+        DueTime: LONGLONG,
+    ) -> BOOLEAN,
+>;
+pub type PFN_WDFTIMERSTOP = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Timer: WDFTIMER,
+        Wait: BOOLEAN,
+    ) -> BOOLEAN,
+>;
+pub type PFN_WDFTIMERGETPARENTOBJECT = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Timer: WDFTIMER) -> WDFOBJECT,
+>;
+pub type PFN_WDFIOTARGETSENDREADSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        IoTarget: WDFIOTARGET,
+        Request: WDFREQUEST,
+        OutputBuffer: PWDF_MEMORY_DESCRIPTOR,
+        DeviceOffset: PLONGLONG,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        BytesRead: PULONG_PTR,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFIOTARGETSENDWRITESYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        IoTarget: WDFIOTARGET,
+        Request: WDFREQUEST,
+        InputBuffer: PWDF_MEMORY_DESCRIPTOR,
+        DeviceOffset: PLONGLONG,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        BytesWritten: PULONG_PTR,
+    ) -> NTSTATUS,
+>;
+pub type EVT_WDF_IO_IN_CALLER_CONTEXT =
+    ::core::option::Option<unsafe extern "system" fn(Device: WDFDEVICE, Request: WDFREQUEST)>;
+pub type PFN_WDF_IO_IN_CALLER_CONTEXT = EVT_WDF_IO_IN_CALLER_CONTEXT;
+pub type PFN_WDFDEVICEINITSETIOINCALLERCONTEXTCALLBACK = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        DeviceInit: PWDFDEVICE_INIT,
+        EvtIoInCallerContext: PFN_WDF_IO_IN_CALLER_CONTEXT,
+    ),
+>;
+pub type PFN_WDFDEVICEENQUEUEREQUEST = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        Request: WDFREQUEST,
+    ) -> NTSTATUS,
+>;
+impl _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES {
+    pub const IdleCapsInvalid: _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES = _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES(0);
+}
+impl _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES {
+    pub const IdleCannotWakeFromS0: _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES = _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES(1);
+}
+impl _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES {
+    pub const IdleCanWakeFromS0: _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES = _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES(2);
+}
+impl _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES {
+    pub const IdleUsbSelectiveSuspend: _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES = _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES(3);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_POWER_POLICY_S0_IDLE_CAPABILITIES(pub ::libc::c_int);
+pub use self::_WDF_POWER_POLICY_S0_IDLE_CAPABILITIES as WDF_POWER_POLICY_S0_IDLE_CAPABILITIES;
+impl _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL {
+    pub const IdleUserControlInvalid: _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL = _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL(0);
+}
+impl _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL {
+    pub const IdleDoNotAllowUserControl: _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL = _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL(1);
+}
+impl _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL {
+    pub const IdleAllowUserControl: _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL = _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_POWER_POLICY_S0_IDLE_USER_CONTROL(pub ::libc::c_int);
+pub use self::_WDF_POWER_POLICY_S0_IDLE_USER_CONTROL as WDF_POWER_POLICY_S0_IDLE_USER_CONTROL;
+impl _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE {
+    pub const DriverManagedIdleTimeout: _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE = _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE(0);
+}
+impl _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE {
+    pub const SystemManagedIdleTimeout: _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE = _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE(1);
+}
+impl _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE {
+    pub const SystemManagedIdleTimeoutWithHint: _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE = _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE(pub ::libc::c_int);
+pub use self::_WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE as WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE;
+impl _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL {
+    pub const WakeUserControlInvalid: _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL = _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL(0);
+}
+impl _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL {
+    pub const WakeDoNotAllowUserControl: _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL = _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL(1);
+}
+impl _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL {
+    pub const WakeAllowUserControl: _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL = _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_POWER_POLICY_SX_WAKE_USER_CONTROL(pub ::libc::c_int);
+pub use self::_WDF_POWER_POLICY_SX_WAKE_USER_CONTROL as WDF_POWER_POLICY_SX_WAKE_USER_CONTROL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS {
+    pub Size: ULONG,
+    pub IdleCaps: WDF_POWER_POLICY_S0_IDLE_CAPABILITIES,
+    pub DxState: DEVICE_POWER_STATE,
+    pub IdleTimeout: ULONG,
+    pub UserControlOfIdleSettings: WDF_POWER_POLICY_S0_IDLE_USER_CONTROL,
+    pub Enabled: WDF_TRI_STATE,
+    pub PowerUpIdleDeviceOnSystemWake: WDF_TRI_STATE,
+    pub IdleTimeoutType: WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE,
+    pub ExcludeD3Cold: WDF_TRI_STATE,
+}
+pub type WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS = _WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS;
+pub type PWDF_DEVICE_POWER_POLICY_IDLE_SETTINGS = *mut _WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS {
+    pub Size: ULONG,
+    pub DxState: DEVICE_POWER_STATE,
+    pub UserControlOfWakeSettings: WDF_POWER_POLICY_SX_WAKE_USER_CONTROL,
+    pub Enabled: WDF_TRI_STATE,
+    pub ArmForWakeIfChildrenAreArmedForWake: BOOLEAN,
+    pub IndicateChildWakeOnParentWake: BOOLEAN,
+}
+pub type WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS = _WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS;
+pub type PWDF_DEVICE_POWER_POLICY_WAKE_SETTINGS = *mut _WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS;
+pub type PFN_WDFDEVICEASSIGNS0IDLESETTINGS = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        Settings: PWDF_DEVICE_POWER_POLICY_IDLE_SETTINGS,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFDEVICEASSIGNSXWAKESETTINGS = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        Settings: PWDF_DEVICE_POWER_POLICY_WAKE_SETTINGS,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFOBJECTALLOCATECONTEXT = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Handle: WDFOBJECT,
+        ContextAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Context: *mut PVOID,
+    ) -> NTSTATUS,
+>;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WDF_REQUEST_PARAMETERS {
+    pub Size: USHORT,
+    pub MinorFunction: UCHAR,
+    pub Type: WDF_REQUEST_TYPE,
+    pub Parameters: _WDF_REQUEST_PARAMETERS__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_REQUEST_PARAMETERS__bindgen_ty_1 {
+    pub Create: _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_1,
+    pub Read: _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_2,
+    pub Write: _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_3,
+    pub DeviceIoControl: _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_4,
+    pub Others: _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_5,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_1 {
+    pub SecurityContext: PIO_SECURITY_CONTEXT,
+    pub Options: ULONG,
+    pub __bindgen_padding_0: [u16; 2usize],
+    pub FileAttributes: USHORT,
+    pub ShareAccess: USHORT,
+    pub __bindgen_padding_1: u32,
+    pub EaLength: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_2 {
+    pub Length: usize,
+    pub Key: ULONG,
+    pub DeviceOffset: LONGLONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_3 {
+    pub Length: usize,
+    pub Key: ULONG,
+    pub DeviceOffset: LONGLONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_4 {
+    pub OutputBufferLength: usize,
+    pub InputBufferLength: usize,
+    pub IoControlCode: ULONG,
+    pub Type3InputBuffer: PVOID,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_REQUEST_PARAMETERS__bindgen_ty_1__bindgen_ty_5 {
+    pub Arg1: PVOID,
+    pub Arg2: PVOID,
+    pub IoControlCode: ULONG,
+    pub Arg4: PVOID,
+}
+pub type WDF_REQUEST_PARAMETERS = _WDF_REQUEST_PARAMETERS;
+pub type PWDF_REQUEST_PARAMETERS = *mut _WDF_REQUEST_PARAMETERS;
+pub type PFN_WDFREQUESTGETPARAMETERS = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Parameters: PWDF_REQUEST_PARAMETERS,
+    ),
+>;
+pub type PFN_WDFREQUESTRETRIEVEUNSAFEUSERINPUTBUFFER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        MinimumRequiredLength: usize,
+        InputBuffer: *mut PVOID,
+        Length: *mut usize,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTRETRIEVEUNSAFEUSEROUTPUTBUFFER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        MinimumRequiredLength: usize,
+        OutputBuffer: *mut PVOID,
+        Length: *mut usize,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTPROBEANDLOCKUSERBUFFERFORREAD = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Buffer: PVOID,
+        Length: usize,
+        MemoryObject: *mut WDFMEMORY,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTPROBEANDLOCKUSERBUFFERFORWRITE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Request: WDFREQUEST,
+        Buffer: PVOID,
+        Length: usize,
+        MemoryObject: *mut WDFMEMORY,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREQUESTWDMGETIRP = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Request: WDFREQUEST) -> PIRP,
+>;
+extern "C" {
+    pub static mut WdfFunctionCount: ULONG;
+}
+extern "C" {
+    pub static mut WdfStructureCount: ULONG;
+}
+extern "C" {
+    pub static mut WdfClientVersionHigherThanFramework: BOOLEAN;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_DRIVER_VERSION_AVAILABLE_PARAMS {
+    pub Size: ULONG,
+    pub MajorVersion: ULONG,
+    pub MinorVersion: ULONG,
+}
+pub type WDF_DRIVER_VERSION_AVAILABLE_PARAMS = _WDF_DRIVER_VERSION_AVAILABLE_PARAMS;
+pub type PWDF_DRIVER_VERSION_AVAILABLE_PARAMS = *mut _WDF_DRIVER_VERSION_AVAILABLE_PARAMS;
+pub type PFN_WDFDRIVERISVERSIONAVAILABLE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Driver: WDFDRIVER,
+        VersionAvailableParams: PWDF_DRIVER_VERSION_AVAILABLE_PARAMS,
+    ) -> BOOLEAN,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFKEY__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFKEY = *mut WDFKEY__;
+pub type PFN_WDFDRIVEROPENPARAMETERSREGISTRYKEY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Driver: WDFDRIVER,
+        DesiredAccess: ACCESS_MASK,
+        KeyAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Key: *mut WDFKEY,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFREGISTRYCLOSE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Key: WDFKEY),
+>;
+pub type PFN_WDFREGISTRYWDMGETHANDLE = ::core::option::Option<
+    unsafe extern "system" fn(DriverGlobals: PWDF_DRIVER_GLOBALS, Key: WDFKEY) -> HANDLE,
+>;
+pub type PFN_WDFREGISTRYQUERYVALUE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Key: WDFKEY,
+        ValueName: PCUNICODE_STRING,
+        ValueLength: ULONG,
+        Value: PVOID,
+        ValueLengthQueried: PULONG,
+        ValueType: PULONG,
+    ) -> NTSTATUS,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_REQUEST_COMPLETION_PARAMS {
+    pub _address: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFUSBDEVICE__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFUSBDEVICE = *mut WDFUSBDEVICE__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFUSBINTERFACE__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFUSBINTERFACE = *mut WDFUSBINTERFACE__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WDFUSBPIPE__ {
+    pub unused: ::libc::c_int,
+}
+pub type WDFUSBPIPE = *mut WDFUSBPIPE__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _URB {
+    _unused: [u8; 0],
+}
+pub type PURB = *mut _URB;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _USB_DEVICE_DESCRIPTOR {
+    pub bLength: UCHAR,
+    pub bDescriptorType: UCHAR,
+    pub bcdUSB: USHORT,
+    pub bDeviceClass: UCHAR,
+    pub bDeviceSubClass: UCHAR,
+    pub bDeviceProtocol: UCHAR,
+    pub bMaxPacketSize0: UCHAR,
+    pub idVendor: USHORT,
+    pub idProduct: USHORT,
+    pub bcdDevice: USHORT,
+    pub iManufacturer: UCHAR,
+    pub iProduct: UCHAR,
+    pub iSerialNumber: UCHAR,
+    pub bNumConfigurations: UCHAR,
+}
+pub type USB_DEVICE_DESCRIPTOR = _USB_DEVICE_DESCRIPTOR;
+pub type PUSB_DEVICE_DESCRIPTOR = *mut _USB_DEVICE_DESCRIPTOR;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _USB_CONFIGURATION_DESCRIPTOR {
+    pub bLength: UCHAR,
+    pub bDescriptorType: UCHAR,
+    pub wTotalLength: USHORT,
+    pub bNumInterfaces: UCHAR,
+    pub bConfigurationValue: UCHAR,
+    pub iConfiguration: UCHAR,
+    pub bmAttributes: UCHAR,
+    pub MaxPower: UCHAR,
+}
+pub type USB_CONFIGURATION_DESCRIPTOR = _USB_CONFIGURATION_DESCRIPTOR;
+pub type PUSB_CONFIGURATION_DESCRIPTOR = *mut _USB_CONFIGURATION_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _USB_INTERFACE_DESCRIPTOR {
+    pub bLength: UCHAR,
+    pub bDescriptorType: UCHAR,
+    pub bInterfaceNumber: UCHAR,
+    pub bAlternateSetting: UCHAR,
+    pub bNumEndpoints: UCHAR,
+    pub bInterfaceClass: UCHAR,
+    pub bInterfaceSubClass: UCHAR,
+    pub bInterfaceProtocol: UCHAR,
+    pub iInterface: UCHAR,
+}
+pub type USB_INTERFACE_DESCRIPTOR = _USB_INTERFACE_DESCRIPTOR;
+pub type PUSB_INTERFACE_DESCRIPTOR = *mut _USB_INTERFACE_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_DEVICE_CREATE_CONFIG {
+    pub Size: ULONG,
+    pub USBDClientContractVersion: ULONG,
+}
+pub type WDF_USB_DEVICE_CREATE_CONFIG = _WDF_USB_DEVICE_CREATE_CONFIG;
+pub type PWDF_USB_DEVICE_CREATE_CONFIG = *mut _WDF_USB_DEVICE_CREATE_CONFIG;
+impl _WDF_USB_PIPE_TYPE {
+    pub const WdfUsbPipeTypeInvalid: _WDF_USB_PIPE_TYPE = _WDF_USB_PIPE_TYPE(0);
+}
+impl _WDF_USB_PIPE_TYPE {
+    pub const WdfUsbPipeTypeControl: _WDF_USB_PIPE_TYPE = _WDF_USB_PIPE_TYPE(1);
+}
+impl _WDF_USB_PIPE_TYPE {
+    pub const WdfUsbPipeTypeIsochronous: _WDF_USB_PIPE_TYPE = _WDF_USB_PIPE_TYPE(2);
+}
+impl _WDF_USB_PIPE_TYPE {
+    pub const WdfUsbPipeTypeBulk: _WDF_USB_PIPE_TYPE = _WDF_USB_PIPE_TYPE(3);
+}
+impl _WDF_USB_PIPE_TYPE {
+    pub const WdfUsbPipeTypeInterrupt: _WDF_USB_PIPE_TYPE = _WDF_USB_PIPE_TYPE(4);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WDF_USB_PIPE_TYPE(pub ::libc::c_int);
+pub use self::_WDF_USB_PIPE_TYPE as WDF_USB_PIPE_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_PIPE_INFORMATION {
+    pub Size: ULONG,
+    pub MaximumPacketSize: ULONG,
+    pub EndpointAddress: UCHAR,
+    pub Interval: UCHAR,
+    pub SettingIndex: UCHAR,
+    pub PipeType: WDF_USB_PIPE_TYPE,
+    pub MaximumTransferSize: ULONG,
+}
+pub type WDF_USB_PIPE_INFORMATION = _WDF_USB_PIPE_INFORMATION;
+pub type PWDF_USB_PIPE_INFORMATION = *mut _WDF_USB_PIPE_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_INTERFACE_SETTING_PAIR {
+    pub UsbInterface: WDFUSBINTERFACE,
+    pub SettingIndex: UCHAR,
+}
+pub type WDF_USB_INTERFACE_SETTING_PAIR = _WDF_USB_INTERFACE_SETTING_PAIR;
+pub type PWDF_USB_INTERFACE_SETTING_PAIR = *mut _WDF_USB_INTERFACE_SETTING_PAIR;
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeInvalid: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(0);
+}
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeDeconfig: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(1);
+}
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeSingleInterface: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(2);
+}
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeMultiInterface: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(3);
+}
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeInterfacesPairsDescriptor: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(4);
+}
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeInterfacesDescriptor: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(5);
+}
+impl _WdfUsbTargetDeviceSelectConfigType {
+    pub const WdfUsbTargetDeviceSelectConfigTypeUrb: _WdfUsbTargetDeviceSelectConfigType = _WdfUsbTargetDeviceSelectConfigType(6);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct _WdfUsbTargetDeviceSelectConfigType(pub ::libc::c_int);
+pub use self::_WdfUsbTargetDeviceSelectConfigType as WDF_USB_SELECT_CONFIG_TYPE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS {
+    pub Size: ULONG,
+    pub Type: WDF_USB_SELECT_CONFIG_TYPE,
+    pub Types: _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1 {
+    pub Descriptor: _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_1,
+    pub Urb: _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_2,
+    pub SingleInterface: _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_3,
+    pub MultiInterface: _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_4,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_1 {
+    pub ConfigurationDescriptor: PUSB_CONFIGURATION_DESCRIPTOR,
+    pub InterfaceDescriptors: *mut PUSB_INTERFACE_DESCRIPTOR,
+    pub NumInterfaceDescriptors: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_2 {
+    pub Urb: PURB,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_3 {
+    pub NumberConfiguredPipes: UCHAR,
+    pub ConfiguredUsbInterface: WDFUSBINTERFACE,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS__bindgen_ty_1__bindgen_ty_4 {
+    pub NumberInterfaces: UCHAR,
+    pub Pairs: PWDF_USB_INTERFACE_SETTING_PAIR,
+    pub NumberOfConfiguredInterfaces: UCHAR,
+}
+pub type WDF_USB_DEVICE_SELECT_CONFIG_PARAMS = _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS;
+pub type PWDF_USB_DEVICE_SELECT_CONFIG_PARAMS = *mut _WDF_USB_DEVICE_SELECT_CONFIG_PARAMS;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _WDF_USB_CONTROL_SETUP_PACKET {
+    pub Packet: _WDF_USB_CONTROL_SETUP_PACKET__bindgen_ty_1,
+    pub Generic: _WDF_USB_CONTROL_SETUP_PACKET__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_CONTROL_SETUP_PACKET__bindgen_ty_1 {
+    pub bm: UCHAR,
+    pub bRequest: UCHAR,
+    pub wValue: USHORT,
+    pub wIndex: USHORT,
+    pub wLength: USHORT,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WDF_USB_CONTROL_SETUP_PACKET__bindgen_ty_2 {
+    pub Bytes: [UCHAR; 8usize],
+}
+pub type WDF_USB_CONTROL_SETUP_PACKET = _WDF_USB_CONTROL_SETUP_PACKET;
+pub type PWDF_USB_CONTROL_SETUP_PACKET = *mut _WDF_USB_CONTROL_SETUP_PACKET;
+pub type PFN_WDFUSBTARGETDEVICECREATEWITHPARAMETERS = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Device: WDFDEVICE,
+        Config: PWDF_USB_DEVICE_CREATE_CONFIG,
+        Attributes: PWDF_OBJECT_ATTRIBUTES,
+        UsbDevice: *mut WDFUSBDEVICE,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFUSBTARGETDEVICEGETDEVICEDESCRIPTOR = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbDevice: WDFUSBDEVICE,
+        UsbDeviceDescriptor: PUSB_DEVICE_DESCRIPTOR,
+    ),
+>;
+pub type PFN_WDFUSBTARGETDEVICESELECTCONFIG = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbDevice: WDFUSBDEVICE,
+        PipeAttributes: PWDF_OBJECT_ATTRIBUTES,
+        Params: PWDF_USB_DEVICE_SELECT_CONFIG_PARAMS,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFUSBTARGETDEVICEGETNUMINTERFACES = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbDevice: WDFUSBDEVICE,
+    ) -> UCHAR,
+>;
+pub type PFN_WDFUSBTARGETDEVICEGETINTERFACE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbDevice: WDFUSBDEVICE,
+        InterfaceIndex: UCHAR,
+    ) -> WDFUSBINTERFACE,
+>;
+pub type PFN_WDFUSBTARGETDEVICESENDCONTROLTRANSFERSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbDevice: WDFUSBDEVICE,
+        Request: WDFREQUEST,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        SetupPacket: PWDF_USB_CONTROL_SETUP_PACKET,
+        MemoryDescriptor: PWDF_MEMORY_DESCRIPTOR,
+        BytesTransferred: PULONG,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFUSBINTERFACEGETINTERFACENUMBER = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbInterface: WDFUSBINTERFACE,
+    ) -> UCHAR,
+>;
+pub type PFN_WDFUSBINTERFACEGETNUMCONFIGUREDPIPES = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbInterface: WDFUSBINTERFACE,
+    ) -> UCHAR,
+>;
+pub type PFN_WDFUSBINTERFACEGETCONFIGUREDPIPE = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        UsbInterface: WDFUSBINTERFACE,
+        PipeIndex: UCHAR,
+        PipeInfo: PWDF_USB_PIPE_INFORMATION,
+    ) -> WDFUSBPIPE,
+>;
+pub type PFN_WDFUSBTARGETPIPEGETINFORMATION = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Pipe: WDFUSBPIPE,
+        PipeInformation: PWDF_USB_PIPE_INFORMATION,
+    ),
+>;
+pub type PFN_WDFUSBTARGETPIPEWRITESYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Pipe: WDFUSBPIPE,
+        Request: WDFREQUEST,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        MemoryDescriptor: PWDF_MEMORY_DESCRIPTOR,
+        BytesWritten: PULONG,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFUSBTARGETPIPEREADSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Pipe: WDFUSBPIPE,
+        Request: WDFREQUEST,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+        MemoryDescriptor: PWDF_MEMORY_DESCRIPTOR,
+        BytesRead: PULONG,
+    ) -> NTSTATUS,
+>;
+pub type PFN_WDFUSBTARGETPIPESETNOMAXIMUMPACKETSIZECHECK = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Pipe: WDFUSBPIPE,
+    ),
+>;
+pub type PFN_WDFUSBTARGETPIPERESETSYNCHRONOUSLY = ::core::option::Option<
+    unsafe extern "system" fn(
+        DriverGlobals: PWDF_DRIVER_GLOBALS,
+        Pipe: WDFUSBPIPE,
+        Request: WDFREQUEST,
+        RequestOptions: PWDF_REQUEST_SEND_OPTIONS,
+    ) -> NTSTATUS,
+>;
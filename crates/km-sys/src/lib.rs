@@ -6,9 +6,61 @@
 #![allow(clippy::useless_transmute)]
 #![allow(clippy::unnecessary_cast)]
 
+// The bindings are generated for x64 only; 32-bit (i686) drivers are out of scope, as are the x86
+// WDK headers. 32-bit user-mode clients use `km-shared` without its `km-sys` feature instead.
+#[cfg(not(target_arch = "x86_64"))]
+compile_error!("`km-sys` only has bindings for x64");
+
+#[cfg(feature = "wdm-core")]
 mod generated;
+#[cfg(feature = "wdm-core")]
 pub use generated::*;
 
+// The areas generated from `[modules.*]` in `bindgen.toml`, behind the features of the same name.
+// They're also re-exported at the root, so the features don't change any paths.
+
+/// Kernel-Mode Driver Framework, from `wdf.h`.
+#[cfg(feature = "wdf")]
+#[path = "generated/wdf.rs"]
+pub mod wdf;
+#[cfg(feature = "wdf")]
+pub use wdf::*;
+
+/// Event Tracing for Windows providers, from `wdm.h`.
+#[cfg(feature = "etw")]
+#[path = "generated/etw.rs"]
+pub mod etw;
+#[cfg(feature = "etw")]
+pub use etw::*;
+
+/// ACPI method evaluation, from `acpiioct.h`.
+#[cfg(feature = "acpi")]
+#[path = "generated/acpi.rs"]
+pub mod acpi;
+#[cfg(feature = "acpi")]
+pub use acpi::*;
+
+/// HID class driver IOCTLs and report descriptor parsing, from `hidclass.h` and `hidpi.h`.
+#[cfg(feature = "hid")]
+#[path = "generated/hid.rs"]
+pub mod hid;
+#[cfg(feature = "hid")]
+pub use hid::*;
+
+/// Storage property queries and SMART, from `ntddstor.h` and `ntdddisk.h`.
+#[cfg(feature = "storage")]
+#[path = "generated/storage.rs"]
+pub mod storage;
+#[cfg(feature = "storage")]
+pub use storage::*;
+
+/// Serial port IOCTLs, from `ntddser.h`.
+#[cfg(feature = "serial")]
+#[path = "generated/serial.rs"]
+pub mod serial;
+#[cfg(feature = "serial")]
+pub use serial::*;
+
 #[cfg(feature = "linking")]
 const _: () = {
     // The linker includes below are the same, and in the same order as the C driver samples have them
@@ -31,9 +83,14 @@ const _: () = {
     extern "C" {}
     #[link(name = "wmilib")]
     extern "C" {}
+    #[cfg(feature = "hid")]
+    #[link(name = "hidparse")]
+    extern "C" {}
 
+    #[cfg(feature = "wdf")]
     #[link(name = "WdfLdr")]
     extern "C" {}
+    #[cfg(feature = "wdf")]
     #[link(name = "WdfDriverEntry")]
     extern "C" {}
 
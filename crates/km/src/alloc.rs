@@ -0,0 +1,250 @@
+//! Memory allocations that the pool allocator doesn't cover.
+//!
+//! [`ContiguousBuffer`] allocates physically contiguous memory, e.g. for devices that access a
+//! buffer by its physical address, but aren't set up for WDF's DMA support:
+//!
+//! ```rs, ignore
+//! let buffer = ContiguousBuffer::allocate(&ContiguousBufferConfig {
+//!     // the device only takes 32-bit addresses
+//!     highest_address: u32::MAX.into(),
+//!     ..ContiguousBufferConfig::new(4096)
+//! })?;
+//!
+//! regs.dma_address.write(buffer.physical_address_u64() as u32);
+//! ```
+//!
+//! The [`CacheType`] of a buffer also selects how [MDLs](crate::mdl::MdlMappingConfig) and
+//! [mapped I/O space](crate::io_mmap::MappedIoSpace::create_mapping) are cached.
+
+use crate::{verify, PhysicalAddress};
+use core::{ptr::NonNull, slice};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{
+    MmAllocateContiguousMemorySpecifyCacheNode, MmFreeContiguousMemory, MmGetPhysicalAddress,
+    APC_LEVEL, KIRQL, LARGE_INTEGER, MEMORY_CACHING_TYPE, MM_ANY_NODE_OK, NODE_REQUIREMENT, SIZE_T,
+};
+
+/// How the CPU caches a [`ContiguousBuffer`] or other mapping, which has to match how the device
+/// accesses it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheType {
+    /// Not cached, for buffers that the device accesses without snooping the CPU caches.
+    NonCached,
+    /// Cached, for buffers that the device accesses coherently, or that are flushed manually.
+    Cached,
+    /// Not cached, but writes may be combined and reordered, e.g. for frame buffers.
+    WriteCombined,
+}
+
+impl CacheType {
+    pub(crate) fn as_raw(self) -> MEMORY_CACHING_TYPE {
+        match self {
+            Self::NonCached => MEMORY_CACHING_TYPE::MmNonCached,
+            Self::Cached => MEMORY_CACHING_TYPE::MmCached,
+            Self::WriteCombined => MEMORY_CACHING_TYPE::MmWriteCombined,
+        }
+    }
+}
+
+/// The requirements of a [`ContiguousBuffer`].
+#[must_use]
+pub struct ContiguousBufferConfig {
+    /// The size of the buffer in bytes, which is rounded up to whole pages.
+    pub len: usize,
+    /// The lowest physical address the buffer may start at.
+    pub lowest_address: u64,
+    /// The highest physical address the buffer may end at, e.g. `u32::MAX` for devices that only
+    /// take 32-bit addresses.
+    pub highest_address: u64,
+    /// If not zero, the buffer doesn't cross a multiple of this physical address, which has to be
+    /// a power of two that's at least `len`.
+    pub boundary_multiple: u64,
+    pub cache_type: CacheType,
+    /// The NUMA node to allocate the buffer from, e.g. the one the device is attached to. If it
+    /// has no memory left, the buffer is allocated from any node.
+    pub preferred_node: Option<u32>,
+    /// Whether the buffer is allocated so that it can be mapped with large pages, to reduce TLB
+    /// misses for big buffers that are accessed often. The length is rounded up to whole
+    /// [large pages](LARGE_PAGE_SIZE), and the buffer is aligned to the next power of two of it,
+    /// overriding `boundary_multiple`. Whether large pages are used is up to the memory manager.
+    pub large_pages: bool,
+}
+
+impl ContiguousBufferConfig {
+    /// A non-cached buffer of `len` bytes anywhere in physical memory.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            lowest_address: 0,
+            highest_address: u64::MAX,
+            boundary_multiple: 0,
+            cache_type: CacheType::NonCached,
+            preferred_node: None,
+            large_pages: false,
+        }
+    }
+}
+
+/// The size of a large page, on all supported architectures.
+pub const LARGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// A physically contiguous buffer in non-paged memory, freed on drop.
+///
+/// The buffer is zeroed on allocation. As the device may access it at any time, the buffer is
+/// best accessed through [`Self::as_ptr`] with volatile operations, or through the slices while
+/// the device is known not to access it.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-mmallocatecontiguousmemoryspecifycachenode
+pub struct ContiguousBuffer {
+    ptr: NonNull<u8>,
+    len: usize,
+    physical_address: u64,
+}
+
+// SAFETY: The buffer is non-paged memory that isn't tied to a thread, and is only accessed through
+// the references the methods take.
+unsafe impl Send for ContiguousBuffer {}
+// SAFETY: See above.
+unsafe impl Sync for ContiguousBuffer {}
+
+impl core::fmt::Debug for ContiguousBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ContiguousBuffer")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .field("physical_address", &self.physical_address)
+            .finish()
+    }
+}
+
+impl ContiguousBuffer {
+    /// Allocates a buffer meeting the requirements of `config`. Fails with
+    /// `STATUS_INVALID_PARAMETER` if the length is zero, and with
+    /// `STATUS_INSUFFICIENT_RESOURCES` if no such memory is available.
+    ///
+    /// Contiguous memory gets scarce as physical memory fragments, so buffers should be allocated
+    /// early, e.g. when the device is created, and then be reused.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn allocate(config: &ContiguousBufferConfig) -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        if config.len == 0 {
+            return Err(NtStatusError::STATUS_INVALID_PARAMETER);
+        }
+
+        let (len, boundary_multiple) = if config.large_pages {
+            let len = config
+                .len
+                .checked_next_multiple_of(LARGE_PAGE_SIZE)
+                .ok_or(NtStatusError::STATUS_INVALID_PARAMETER)?;
+            // staying within one naturally aligned block of the power of two aligns the buffer to
+            // it
+            let boundary = len
+                .checked_next_power_of_two()
+                .ok_or(NtStatusError::STATUS_INVALID_PARAMETER)?;
+            (len, boundary as u64)
+        } else {
+            (config.len, config.boundary_multiple)
+        };
+
+        let preferred_node = config
+            .preferred_node
+            .map_or(MM_ANY_NODE_OK, |node| node | MM_ANY_NODE_OK);
+
+        // SAFETY: FFI call. All parameters are just numbers, no additional requirements here.
+        let ptr = unsafe {
+            MmAllocateContiguousMemorySpecifyCacheNode(
+                len as SIZE_T,
+                physical_address(config.lowest_address),
+                physical_address(config.highest_address),
+                physical_address(boundary_multiple),
+                config.cache_type.as_raw(),
+                preferred_node as NODE_REQUIREMENT,
+            )
+        };
+        let ptr =
+            NonNull::new(ptr.cast::<u8>()).ok_or(NtStatusError::STATUS_INSUFFICIENT_RESOURCES)?;
+
+        // SAFETY: The buffer was just allocated with `len` bytes.
+        unsafe { ptr.as_ptr().write_bytes(0, len) };
+
+        // SAFETY: The buffer is valid and non-paged, so it has a physical address.
+        let physical_address = unsafe { MmGetPhysicalAddress(ptr.as_ptr().cast()) };
+
+        Ok(Self {
+            ptr,
+            len,
+            // SAFETY: `QuadPart` is always valid. Physical addresses are never negative.
+            physical_address: unsafe { physical_address.QuadPart } as u64,
+        })
+    }
+
+    /// The virtual address of the buffer. It stays valid until the buffer is dropped.
+    pub fn as_ptr(&self) -> NonNull<u8> {
+        self.ptr
+    }
+
+    /// The physical address of the start of the buffer, e.g. for the device's registers.
+    pub fn physical_address(&self) -> PhysicalAddress {
+        physical_address(self.physical_address)
+    }
+
+    /// The physical address of the start of the buffer as a number.
+    pub fn physical_address_u64(&self) -> u64 {
+        self.physical_address
+    }
+
+    /// The size of the buffer in bytes, as requested, or rounded up to whole large pages.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Always `false`, as empty buffers can't be allocated.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The contents of the buffer.
+    ///
+    /// # Safety
+    /// The device mustn't write the buffer while the slice is borrowed.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        // SAFETY: The buffer is valid and initialized for `len` bytes until it's dropped, and the
+        // caller guarantees that the device doesn't write it.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// The contents of the buffer.
+    ///
+    /// # Safety
+    /// The device mustn't access the buffer while the slice is borrowed.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: The buffer is valid and initialized for `len` bytes until it's dropped, borrowed
+        // mutably, and the caller guarantees that the device doesn't access it.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for ContiguousBuffer {
+    /// Frees the buffer, which the device mustn't access anymore.
+    ///
+    /// Must be called at `IRQL <= APC_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn drop(&mut self) {
+        verify::irql_at_most(APC_LEVEL as KIRQL);
+
+        // SAFETY: The buffer was allocated with `MmAllocateContiguousMemorySpecifyCacheNode`, and
+        // isn't used anymore.
+        unsafe { MmFreeContiguousMemory(self.ptr.as_ptr().cast()) }
+    }
+}
+
+fn physical_address(address: u64) -> PhysicalAddress {
+    LARGE_INTEGER {
+        QuadPart: address as i64,
+    }
+}
@@ -0,0 +1,291 @@
+//! Callbacks run when the system bugchecks, for whatever reason, e.g. to add device state to crash
+//! dumps.
+//!
+//! Callbacks run at `HIGH_LEVEL` after all other processors were stopped, so they must not
+//! allocate, take locks, wait, or touch paged memory, and should be short. The callbacks are
+//! registered on `static`s, which have to stay in place while registered, and have to be
+//! deregistered before the driver unloads.
+//!
+//! ```rs, ignore
+//! static DUMP_CALLBACK: SecondaryDumpCallback<AtomicU32> =
+//!     SecondaryDumpCallback::new(c"MyDriver", AtomicU32::new(0), |fan_rpm, dump| {
+//!         let bytes = fan_rpm.load(Ordering::Relaxed).to_le_bytes();
+//!         if let Some(buf) = dump.buffer().get_mut(..bytes.len()) {
+//!             buf.copy_from_slice(&bytes);
+//!             dump.write(DUMP_GUID, bytes.len());
+//!         }
+//!     });
+//!
+//! // in `DriverEntry`, and `deregister` on unload
+//! DUMP_CALLBACK.register();
+//! ```
+//!
+//! See [`crate::crash`] for a breadcrumb built on top of this.
+
+use crate::shared::guid::Guid;
+use core::{
+    cell::UnsafeCell,
+    ffi::CStr,
+    mem::{offset_of, size_of},
+    slice,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use km_sys::{
+    KeDeregisterBugCheckCallback, KeDeregisterBugCheckReasonCallback, KeRegisterBugCheckCallback,
+    KeRegisterBugCheckReasonCallback, KBUGCHECK_CALLBACK_REASON, KBUGCHECK_CALLBACK_RECORD,
+    KBUGCHECK_REASON_CALLBACK_RECORD, KBUGCHECK_SECONDARY_DUMP_DATA, PVOID, ULONG,
+};
+
+/// A callback run when the system bugchecks, with a context that's shown by the `!bugdump`
+/// debugger command.
+///
+/// The callback can e.g. put the hardware into a safe state, or refresh the context, which needs
+/// interior mutability (e.g. atomics) for that. Use [`SecondaryDumpCallback`] to add data to crash
+/// dumps instead.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/writing-a-bug-check-callback-routine
+pub struct BugCheckCallback<C> {
+    record: UnsafeCell<KBUGCHECK_CALLBACK_RECORD>,
+    component: &'static CStr,
+    callback: fn(&C),
+    context: C,
+    registered: AtomicBool,
+}
+
+// SAFETY: The record is only accessed by the kernel, and while (de)registering, which is
+// synchronized by `registered`. The context is only accessed through shared references.
+unsafe impl<C: Sync> Sync for BugCheckCallback<C> {}
+
+impl<C: Sync> BugCheckCallback<C> {
+    /// `component` identifies the driver to the debugger, e.g. in `!bugdump`.
+    pub const fn new(component: &'static CStr, context: C, callback: fn(&C)) -> Self {
+        Self {
+            // SAFETY: The record is a plain C struct, for which all zeroes is a valid
+            // (unregistered) state. It's initialized by `KeRegisterBugCheckCallback`.
+            record: UnsafeCell::new(unsafe { core::mem::zeroed() }),
+            component,
+            callback,
+            context,
+            registered: AtomicBool::new(false),
+        }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the callback. Returns `false` if it's already registered, or registering failed.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-keregisterbugcheckcallback
+    pub fn register(&'static self) -> bool {
+        if self.registered.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+
+        // SAFETY: The record and the context are `'static`, and the record is only passed to the
+        // kernel once, as checked above. The callback expects the buffer to be the context of a
+        // `BugCheckCallback<C>`, which it is.
+        let registered = unsafe {
+            KeRegisterBugCheckCallback(
+                self.record.get(),
+                Some(bugcheck_callback::<C>),
+                (&self.context as *const C).cast_mut().cast(),
+                size_of::<C>() as ULONG,
+                self.component.as_ptr().cast_mut().cast(),
+            )
+        } != 0;
+
+        if !registered {
+            self.registered.store(false, Ordering::Release);
+        }
+        registered
+    }
+
+    /// Deregisters the callback, if it's registered.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kederegisterbugcheckcallback
+    pub fn deregister(&self) {
+        if self.registered.swap(false, Ordering::AcqRel) {
+            // SAFETY: The record was registered by `Self::register`.
+            unsafe { KeDeregisterBugCheckCallback(self.record.get()) };
+        }
+    }
+}
+
+/// # Safety
+/// `buffer` must point to the context of a `BugCheckCallback<C>`.
+unsafe extern "system" fn bugcheck_callback<C>(buffer: PVOID, _length: ULONG) {
+    // SAFETY: The caller guarantees that `buffer` is the context of a `BugCheckCallback<C>`.
+    let callback = unsafe {
+        &*buffer
+            .byte_sub(offset_of!(BugCheckCallback<C>, context))
+            .cast::<BugCheckCallback<C>>()
+    };
+
+    (callback.callback)(&callback.context);
+}
+
+/// A callback adding data to crash dumps (`KbCallbackSecondaryDumpData`).
+///
+/// The data is tagged with a GUID, and can be extracted from the dump with the `.enumtag`
+/// debugger command.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/writing-a-bug-check-callback-routine-that-writes-secondary-data
+pub struct SecondaryDumpCallback<C> {
+    record: UnsafeCell<KBUGCHECK_REASON_CALLBACK_RECORD>,
+    component: &'static CStr,
+    callback: fn(&C, &mut SecondaryDumpData<'_>),
+    context: C,
+    registered: AtomicBool,
+}
+
+// SAFETY: The record is only accessed by the kernel, and while (de)registering, which is
+// synchronized by `registered`. The context is only accessed through shared references.
+unsafe impl<C: Sync> Sync for SecondaryDumpCallback<C> {}
+
+impl<C: Sync> SecondaryDumpCallback<C> {
+    /// `component` identifies the driver to the debugger.
+    pub const fn new(
+        component: &'static CStr,
+        context: C,
+        callback: fn(&C, &mut SecondaryDumpData<'_>),
+    ) -> Self {
+        Self {
+            // SAFETY: The record is a plain C struct, for which all zeroes is a valid
+            // (unregistered) state. It's initialized by `KeRegisterBugCheckReasonCallback`.
+            record: UnsafeCell::new(unsafe { core::mem::zeroed() }),
+            component,
+            callback,
+            context,
+            registered: AtomicBool::new(false),
+        }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the callback. Returns `false` if it's already registered, or registering failed.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-keregisterbugcheckreasoncallback
+    pub fn register(&'static self) -> bool {
+        if self.registered.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+
+        // SAFETY: The record is `'static` and only passed to the kernel once, as checked above.
+        // The callback expects the record to be the one of a `SecondaryDumpCallback<C>`, which it
+        // is.
+        let registered = unsafe {
+            KeRegisterBugCheckReasonCallback(
+                self.record.get(),
+                Some(secondary_dump_callback::<C>),
+                KBUGCHECK_CALLBACK_REASON::KbCallbackSecondaryDumpData,
+                self.component.as_ptr().cast_mut().cast(),
+            )
+        } != 0;
+
+        if !registered {
+            self.registered.store(false, Ordering::Release);
+        }
+        registered
+    }
+
+    /// Deregisters the callback, if it's registered.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kederegisterbugcheckreasoncallback
+    pub fn deregister(&self) {
+        if self.registered.swap(false, Ordering::AcqRel) {
+            // SAFETY: The record was registered by `Self::register`.
+            unsafe { KeDeregisterBugCheckReasonCallback(self.record.get()) };
+        }
+    }
+}
+
+/// # Safety
+/// `record` must be the record of a `SecondaryDumpCallback<C>`, and the other parameters have to
+/// be the ones passed by the kernel.
+unsafe extern "system" fn secondary_dump_callback<C>(
+    reason: KBUGCHECK_CALLBACK_REASON,
+    record: *mut KBUGCHECK_REASON_CALLBACK_RECORD,
+    reason_specific_data: PVOID,
+    reason_specific_data_length: ULONG,
+) {
+    if reason != KBUGCHECK_CALLBACK_REASON::KbCallbackSecondaryDumpData
+        || (reason_specific_data_length as usize) < size_of::<KBUGCHECK_SECONDARY_DUMP_DATA>()
+    {
+        return;
+    }
+
+    // SAFETY: The caller guarantees that `record` is embedded in a `SecondaryDumpCallback<C>`.
+    let callback = unsafe {
+        &*record
+            .byte_sub(offset_of!(SecondaryDumpCallback<C>, record))
+            .cast::<SecondaryDumpCallback<C>>()
+    };
+    // SAFETY: The kernel passes a valid `KBUGCHECK_SECONDARY_DUMP_DATA` for this reason, and its
+    // length was checked above.
+    let raw = unsafe { &mut *reason_specific_data.cast::<KBUGCHECK_SECONDARY_DUMP_DATA>() };
+
+    (callback.callback)(&callback.context, &mut SecondaryDumpData(raw));
+}
+
+/// The data a [`SecondaryDumpCallback`] adds to the crash dump.
+pub struct SecondaryDumpData<'a>(&'a mut KBUGCHECK_SECONDARY_DUMP_DATA);
+
+impl SecondaryDumpData<'_> {
+    /// The maximum number of bytes that can be added.
+    pub fn max_len(&self) -> usize {
+        self.0.MaximumAllowed as usize
+    }
+
+    /// A buffer provided by the system, which the data can be written to.
+    pub fn buffer(&mut self) -> &mut [u8] {
+        let len = self.0.InBufferLength.min(self.0.MaximumAllowed) as usize;
+        if self.0.InBuffer.is_null() || len == 0 {
+            return &mut [];
+        }
+
+        // SAFETY: The system provides a valid buffer of `InBufferLength` bytes, which isn't
+        // accessed otherwise until the callback returns.
+        unsafe { slice::from_raw_parts_mut(self.0.InBuffer.cast(), len) }
+    }
+
+    /// Adds the first `len` bytes of [`Self::buffer`] to the dump, tagged with `guid`.
+    pub fn write(&mut self, guid: Guid, len: usize) {
+        let len = len.min(self.buffer().len());
+
+        self.0.Guid = guid.to_raw();
+        self.0.OutBuffer = self.0.InBuffer;
+        self.0.OutBufferLength = len as ULONG;
+    }
+
+    /// Adds `len` bytes at `data` to the dump, tagged with `guid`, without copying them into
+    /// [`Self::buffer`]. Returns `false` if `len` exceeds [`Self::max_len`].
+    ///
+    /// # Safety
+    /// `data` must point to `len` bytes of non-paged memory that stay valid until the dump is
+    /// written, e.g. a `static` in a driver that doesn't page itself out.
+    pub unsafe fn write_static(&mut self, guid: Guid, data: *const u8, len: usize) -> bool {
+        if len > self.max_len() {
+            return false;
+        }
+
+        self.0.Guid = guid.to_raw();
+        self.0.OutBuffer = data.cast_mut().cast();
+        self.0.OutBufferLength = len as ULONG;
+        true
+    }
+}
@@ -0,0 +1,122 @@
+//! Build metadata embedded into the driver binary, see [`build_info!`](crate::build_info!).
+//!
+//! The driver's build script sets the metadata with `km_sys_env::emit_build_info`, and the driver
+//! embeds it as a [`BuildInfo`] constant, e.g. to answer
+//! [`ioctl_km_build_info`](km_shared::ioctl::build_info::ioctl_km_build_info) through
+//! [`DiagnosticsResponder`](crate::diagnostics::DiagnosticsResponder), or to log it when loaded:
+//!
+//! ```rs, ignore
+//! // build.rs
+//! fn main() {
+//!     km_sys_env::link_env(true);
+//!     km_sys_env::emit_build_info();
+//! }
+//!
+//! // the driver
+//! static BUILD_INFO: BuildInfo = km::build_info!();
+//!
+//! km_info!("driver {} ({})", BUILD_INFO.driver_version(), BUILD_INFO.git_commit());
+//! ```
+
+use crate::wdf::driver::KmdfVersion;
+pub use km_shared::ioctl::build_info::{BuildFlags, BuildInfo};
+
+/// Creates the [`BuildInfo`] of the crate it's used in, as a constant:
+/// - The driver version is the crate's version, with a revision of zero.
+/// - The KMDF version is the one `km` is [built against](KmdfVersion::BUILT_AGAINST).
+/// - The git commit and build time are set by `km_sys_env::emit_build_info` in the build script,
+///   and left zero otherwise.
+#[macro_export]
+macro_rules! build_info {
+    () => {
+        $crate::build_info::from_env(
+            [
+                env!("CARGO_PKG_VERSION_MAJOR"),
+                env!("CARGO_PKG_VERSION_MINOR"),
+                env!("CARGO_PKG_VERSION_PATCH"),
+            ],
+            option_env!("KM_BUILD_GIT_COMMIT"),
+            option_env!("KM_BUILD_GIT_DIRTY").is_some(),
+            option_env!("KM_BUILD_TIMESTAMP"),
+            cfg!(debug_assertions),
+        )
+    };
+}
+
+/// Not to be used directly. Used by [`build_info!`](crate::build_info!) to parse the environment
+/// variables at compile time.
+#[doc(hidden)]
+pub const fn from_env(
+    version: [&str; 3],
+    git_commit: Option<&str>,
+    git_dirty: bool,
+    timestamp: Option<&str>,
+    debug: bool,
+) -> BuildInfo {
+    let mut flags = BuildFlags::empty();
+    if git_dirty {
+        flags.0 |= BuildFlags::GIT_DIRTY.0;
+    }
+    if debug {
+        flags.0 |= BuildFlags::DEBUG.0;
+    }
+
+    BuildInfo {
+        driver_version: [
+            parse_decimal(version[0]) as u16,
+            parse_decimal(version[1]) as u16,
+            parse_decimal(version[2]) as u16,
+            0,
+        ],
+        kmdf_built_against: [
+            KmdfVersion::BUILT_AGAINST.major as u16,
+            KmdfVersion::BUILT_AGAINST.minor as u16,
+        ],
+        kmdf_loaded: [0; 2],
+        build_timestamp: match timestamp {
+            Some(timestamp) => parse_decimal(timestamp),
+            None => 0,
+        },
+        git_commit: match git_commit {
+            Some(commit) => parse_commit(commit),
+            None => [0; 20],
+        },
+        flags,
+    }
+}
+
+const fn parse_decimal(s: &str) -> u64 {
+    let bytes = s.as_bytes();
+    assert!(!bytes.is_empty(), "expected a decimal number");
+
+    let mut value = 0u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "expected a decimal number");
+        value = value * 10 + (bytes[i] - b'0') as u64;
+        i += 1;
+    }
+    value
+}
+
+const fn parse_commit(s: &str) -> [u8; 20] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("expected a hex git commit"),
+        }
+    }
+
+    let bytes = s.as_bytes();
+    assert!(bytes.len() == 40, "expected a full SHA-1 git commit");
+
+    let mut commit = [0; 20];
+    let mut i = 0;
+    while i < commit.len() {
+        commit[i] = nibble(bytes[2 * i]) << 4 | nibble(bytes[2 * i + 1]);
+        i += 1;
+    }
+    commit
+}
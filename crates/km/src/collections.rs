@@ -0,0 +1,7 @@
+//! Kernel data structures.
+
+mod list;
+mod slist;
+
+pub use list::*;
+pub use slist::*;
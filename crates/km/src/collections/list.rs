@@ -0,0 +1,310 @@
+use core::{cell::UnsafeCell, marker::PhantomData, ptr::NonNull};
+use km_sys::LIST_ENTRY;
+
+/// A [`LIST_ENTRY`] embedded in items of type `T`, so that they can be linked into a
+/// [`LinkedList`].
+///
+/// The list accesses the item through the entry, which requires a [`ListAdapter`] declared with
+/// [`list_adapter!`](crate::list_adapter!).
+#[repr(transparent)]
+pub struct ListEntry<T> {
+    raw: UnsafeCell<LIST_ENTRY>,
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> ListEntry<T> {
+    pub const fn new() -> Self {
+        Self {
+            raw: UnsafeCell::new(LIST_ENTRY {
+                Flink: core::ptr::null_mut(),
+                Blink: core::ptr::null_mut(),
+            }),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ListEntry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Describes where the [`ListEntry`] is located in a list item, which is used to get from an
+/// entry back to its item (like `CONTAINING_RECORD`).
+///
+/// Declare adapters with [`list_adapter!`](crate::list_adapter!) instead of implementing this
+/// trait manually.
+///
+/// # Safety
+/// `OFFSET` must be the offset of a `ListEntry<Self::Item>` field in `Self::Item`.
+pub unsafe trait ListAdapter {
+    type Item;
+    const OFFSET: usize;
+
+    fn entry(item: NonNull<Self::Item>) -> *mut LIST_ENTRY {
+        // `ListEntry` is `repr(transparent)`, and the link is behind an `UnsafeCell`, so it may be
+        // mutated through shared references to the item.
+        item.as_ptr().wrapping_byte_add(Self::OFFSET).cast()
+    }
+
+    /// # Safety
+    /// `entry` must be the entry of an item.
+    unsafe fn item(entry: *mut LIST_ENTRY) -> NonNull<Self::Item> {
+        // SAFETY: Guaranteed by the trait's contract and the caller.
+        unsafe { NonNull::new_unchecked(entry.byte_sub(Self::OFFSET).cast()) }
+    }
+}
+
+/// Declares a [`ListAdapter`] for a [`ListEntry`] field of a struct.
+///
+/// Example:
+/// ```rs, ignore
+/// struct PendingRequest {
+///     request: Request,
+///     entry: ListEntry<PendingRequest>,
+/// }
+///
+/// list_adapter!(pub PendingRequestAdapter = PendingRequest { entry });
+///
+/// let mut pending = LinkedList::<PendingRequestAdapter>::new();
+/// ```
+#[macro_export]
+macro_rules! list_adapter {
+    ($vis:vis $adapter:ident = $item:ty { $field:ident }) => {
+        $vis struct $adapter;
+
+        // SAFETY: The offset is the offset of the field, whose type is checked below.
+        unsafe impl $crate::collections::ListAdapter for $adapter {
+            type Item = $item;
+            const OFFSET: usize = ::core::mem::offset_of!($item, $field);
+        }
+
+        const _: fn(&$item) -> &$crate::collections::ListEntry<$item> = |item| &item.$field;
+    };
+}
+
+/// An intrusive, doubly linked list of items linked through a [`ListEntry`], like the
+/// `InitializeListHead`/`InsertTailList`/`RemoveEntryList` family of WDK functions.
+///
+/// The list doesn't own its items. Instead, the caller guarantees that items stay valid and in
+/// place while they are linked (see [`Self::push_back`]). Like in the WDK, the links are checked
+/// for corruption on every modification, which panics instead of corrupting memory.
+///
+/// The list head points to itself while empty, so the list can't be moved while it is non-empty.
+/// An empty list may be moved freely, as it is re-initialized lazily.
+pub struct LinkedList<A: ListAdapter> {
+    head: LIST_ENTRY,
+    len: usize,
+    _adapter: PhantomData<A>,
+}
+
+impl<A: ListAdapter> LinkedList<A> {
+    pub const fn new() -> Self {
+        Self {
+            head: LIST_ENTRY {
+                Flink: core::ptr::null_mut(),
+                Blink: core::ptr::null_mut(),
+            },
+            len: 0,
+            _adapter: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the list head, initializing it if the list is empty (see `InitializeListHead`).
+    fn head(&mut self) -> *mut LIST_ENTRY {
+        let head: *mut LIST_ENTRY = &mut self.head;
+        if self.len == 0 {
+            self.head.Flink = head;
+            self.head.Blink = head;
+        }
+        head
+    }
+
+    /// Inserts `item` at the end of the list (see `InsertTailList`).
+    ///
+    /// # Safety
+    /// - `item` must be valid, and must not be moved or freed until it is removed from the list.
+    /// - `item` must not be linked into any list already.
+    /// - The list must not be moved until it is empty again.
+    pub unsafe fn push_back(&mut self, item: NonNull<A::Item>) {
+        let head = self.head();
+        // SAFETY: The head is initialized, and its links are valid.
+        let prev = unsafe { (*head).Blink };
+        // SAFETY: Guaranteed by the caller.
+        unsafe { link_between(prev, A::entry(item), head) };
+        self.len += 1;
+    }
+
+    /// Inserts `item` at the start of the list (see `InsertHeadList`).
+    ///
+    /// # Safety
+    /// See [`Self::push_back`].
+    pub unsafe fn push_front(&mut self, item: NonNull<A::Item>) {
+        let head = self.head();
+        // SAFETY: The head is initialized, and its links are valid.
+        let next = unsafe { (*head).Flink };
+        // SAFETY: Guaranteed by the caller.
+        unsafe { link_between(head, A::entry(item), next) };
+        self.len += 1;
+    }
+
+    /// Removes the first item of the list (see `RemoveHeadList`).
+    pub fn pop_front(&mut self) -> Option<NonNull<A::Item>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // SAFETY: The list is non-empty, so the first entry is an item's entry.
+        unsafe {
+            let entry = self.head.Flink;
+            self.unlink(entry);
+            Some(A::item(entry))
+        }
+    }
+
+    /// Removes the last item of the list (see `RemoveTailList`).
+    pub fn pop_back(&mut self) -> Option<NonNull<A::Item>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // SAFETY: The list is non-empty, so the last entry is an item's entry.
+        unsafe {
+            let entry = self.head.Blink;
+            self.unlink(entry);
+            Some(A::item(entry))
+        }
+    }
+
+    /// Removes `item` from the list (see `RemoveEntryList`).
+    ///
+    /// # Safety
+    /// `item` must be linked into this list.
+    pub unsafe fn remove(&mut self, item: NonNull<A::Item>) {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { self.unlink(A::entry(item)) };
+    }
+
+    /// Removes the first item matching `predicate`.
+    pub fn remove_first(
+        &mut self,
+        mut predicate: impl FnMut(&A::Item) -> bool,
+    ) -> Option<NonNull<A::Item>> {
+        let item = self
+            .iter()
+            .find(|item| predicate(item))
+            .map(NonNull::from)?;
+
+        // SAFETY: The item was just found in this list.
+        unsafe { self.remove(item) };
+        Some(item)
+    }
+
+    /// Iterates over the items, from the start to the end of the list.
+    pub fn iter(&self) -> Iter<'_, A> {
+        Iter {
+            next: self.head.Flink,
+            remaining: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    /// # Safety
+    /// `entry` must be linked into this list.
+    unsafe fn unlink(&mut self, entry: *mut LIST_ENTRY) {
+        // SAFETY: Guaranteed by the caller.
+        unsafe {
+            let prev = (*entry).Blink;
+            let next = (*entry).Flink;
+            check_links(prev, entry, next);
+
+            (*prev).Flink = next;
+            (*next).Blink = prev;
+            (*entry).Flink = core::ptr::null_mut();
+            (*entry).Blink = core::ptr::null_mut();
+        }
+        self.len -= 1;
+    }
+}
+
+impl<A: ListAdapter> Default for LinkedList<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A: ListAdapter> IntoIterator for &'a LinkedList<A> {
+    type Item = &'a A::Item;
+    type IntoIter = Iter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Links `entry` between the adjacent entries `prev` and `next`.
+///
+/// # Safety
+/// All pointers must be valid, and `prev` and `next` must be adjacent.
+unsafe fn link_between(prev: *mut LIST_ENTRY, entry: *mut LIST_ENTRY, next: *mut LIST_ENTRY) {
+    // SAFETY: Guaranteed by the caller.
+    unsafe {
+        let adjacent = (*prev).Flink == next && (*next).Blink == prev;
+        assert!(adjacent, "corrupted LIST_ENTRY links");
+
+        (*entry).Flink = next;
+        (*entry).Blink = prev;
+        (*prev).Flink = entry;
+        (*next).Blink = entry;
+    }
+}
+
+/// Checks that `prev` and `next` are linked to `entry`, like the `RtlpCheckListEntry` checks of
+/// the WDK list functions, which fail fast on corrupted lists.
+///
+/// # Safety
+/// All pointers must be valid.
+unsafe fn check_links(prev: *mut LIST_ENTRY, entry: *mut LIST_ENTRY, next: *mut LIST_ENTRY) {
+    // SAFETY: Guaranteed by the caller.
+    let linked = unsafe { (*prev).Flink == entry && (*next).Blink == entry };
+    assert!(linked, "corrupted LIST_ENTRY links");
+}
+
+/// An iterator over the items of a [`LinkedList`].
+pub struct Iter<'a, A: ListAdapter> {
+    next: *mut LIST_ENTRY,
+    remaining: usize,
+    _list: PhantomData<&'a LinkedList<A>>,
+}
+
+impl<'a, A: ListAdapter> Iterator for Iter<'a, A> {
+    type Item = &'a A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // SAFETY: There are items left, so `next` is an item's entry. The items are guaranteed to
+        // be valid while they are linked, and the list is borrowed.
+        unsafe {
+            let item = A::item(self.next);
+            self.next = (*self.next).Flink;
+            self.remaining -= 1;
+            Some(item.as_ref())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
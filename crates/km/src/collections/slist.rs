@@ -0,0 +1,148 @@
+use core::{
+    cell::UnsafeCell,
+    marker::PhantomData,
+    mem::align_of,
+    ptr::NonNull,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use km_sys::{
+    _SLIST_HEADER__bindgen_ty_1, ExpInterlockedFlushSList, ExpInterlockedPopEntrySList,
+    ExpInterlockedPushEntrySList, SLIST_ENTRY, SLIST_HEADER,
+};
+
+/// A node of an [`InterlockedStack`], holding a value of type `T`.
+///
+/// The `SLIST_ENTRY` comes first, so nodes are 16-byte aligned, as required by the interlocked
+/// list functions. Nodes are provided by the caller, e.g. preallocated or allocated from a pool.
+#[repr(C)]
+pub struct SListNode<T> {
+    entry: UnsafeCell<SLIST_ENTRY>,
+    pub value: T,
+}
+
+impl<T> SListNode<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            entry: UnsafeCell::new(SLIST_ENTRY {
+                Next: core::ptr::null_mut(),
+            }),
+            value,
+        }
+    }
+}
+
+/// A lock-free stack of [`SListNode`]s, wrapping an interlocked singly linked list
+/// (`SLIST_HEADER`).
+///
+/// Pushing and popping can be done concurrently at any IRQL, so this can be used to pass data
+/// between ISRs/DPCs and worker threads without spinlocks. See [Using Singly and Doubly Linked
+/// Lists][MSDN] for more information.
+///
+/// The stack doesn't own its nodes. Instead, the caller guarantees that nodes stay valid and in
+/// place while they are pushed (see [`Self::push`]).
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/singly-and-doubly-linked-lists
+pub struct InterlockedStack<T> {
+    header: UnsafeCell<SLIST_HEADER>,
+    _marker: PhantomData<NonNull<SListNode<T>>>,
+}
+
+// SAFETY: The header is only accessed through the interlocked functions, and values are sent
+// between threads through the stack.
+unsafe impl<T: Send> Send for InterlockedStack<T> {}
+// SAFETY: See above.
+unsafe impl<T: Send> Sync for InterlockedStack<T> {}
+
+// The interlocked functions require 16-byte aligned headers and entries.
+const _: () = assert!(align_of::<SLIST_HEADER>() == 16);
+const _: () = assert!(align_of::<SLIST_ENTRY>() == 16);
+
+impl<T> InterlockedStack<T> {
+    /// Creates an empty stack.
+    pub const fn new() -> Self {
+        // `InitializeSListHead` only zeroes the header, so this doesn't need to call it, and can
+        // be used to initialize statics.
+        Self {
+            header: UnsafeCell::new(SLIST_HEADER {
+                __bindgen_anon_1: _SLIST_HEADER__bindgen_ty_1 {
+                    Alignment: 0,
+                    Region: 0,
+                },
+            }),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Pushes `node` onto the stack (see `ExInterlockedPushEntrySList`). Returns whether the
+    /// stack was empty before.
+    ///
+    /// # Safety
+    /// - `node` must be valid, and must not be moved or freed until it was popped again.
+    /// - `node` must not be pushed onto any stack already.
+    pub unsafe fn push(&self, node: NonNull<SListNode<T>>) -> bool {
+        // SAFETY: Guaranteed by the caller. The header is valid and 16-byte aligned.
+        let previous = unsafe {
+            ExpInterlockedPushEntrySList(self.header.get(), (*node.as_ptr()).entry.get())
+        };
+
+        previous.is_null()
+    }
+
+    /// Pops the most recently pushed node (see `ExInterlockedPopEntrySList`).
+    pub fn pop(&self) -> Option<NonNull<SListNode<T>>> {
+        // SAFETY: The header is valid and 16-byte aligned.
+        let entry = unsafe { ExpInterlockedPopEntrySList(self.header.get()) };
+
+        // The entry is the first field of the node.
+        NonNull::new(entry.cast())
+    }
+
+    /// Pops all nodes at once (see `ExInterlockedFlushSList`), returning them from the most to
+    /// the least recently pushed.
+    pub fn flush(&self) -> Flushed<T> {
+        // SAFETY: The header is valid and 16-byte aligned.
+        let first = unsafe { ExpInterlockedFlushSList(self.header.get()) };
+
+        Flushed {
+            next: NonNull::new(first.cast()),
+        }
+    }
+
+    /// The number of nodes on the stack (see `ExQueryDepthSList`). This is only a snapshot, as
+    /// nodes may be pushed and popped concurrently.
+    pub fn depth(&self) -> u16 {
+        // SAFETY: The header is valid and aligned. The depth is the lowest 16 bits of the header,
+        // which are only modified atomically by the interlocked functions.
+        let alignment = unsafe { AtomicU64::from_ptr(self.header.get().cast()) };
+        alignment.load(Ordering::Relaxed) as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.depth() == 0
+    }
+}
+
+impl<T> Default for InterlockedStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The nodes popped by [`InterlockedStack::flush`].
+pub struct Flushed<T> {
+    next: Option<NonNull<SListNode<T>>>,
+}
+
+impl<T> Iterator for Flushed<T> {
+    type Item = NonNull<SListNode<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+
+        // SAFETY: Flushed nodes are guaranteed to be valid by the contract of `push`, and are
+        // exclusively owned by this iterator now.
+        self.next = NonNull::new(unsafe { (*(*node.as_ptr()).entry.get()).Next }.cast());
+
+        Some(node)
+    }
+}
@@ -0,0 +1,241 @@
+//! The processor topology, e.g. to size per-processor structures.
+//!
+//! Windows organizes processors in groups of up to 64 processors each. A processor is identified
+//! by its [`ProcessorNumber`], i.e. its group and its number within the group, or by its
+//! system-wide index, which ranges from 0 up to the number of active processors:
+//!
+//! ```rs, ignore
+//! let topology = cpu::topology();
+//! let slots = vec_with_len(topology.active_processors as usize);
+//!
+//! // later, at any IRQL
+//! let slot = &slots[cpu::current_processor_index() as usize % slots.len()];
+//! ```
+//!
+//! Processors can be added at runtime, so per-processor structures that are sized once either
+//! have to be sized for [`Topology::maximum_processors`], or have to handle indices beyond their
+//! size, e.g. by sharing slots.
+
+use crate::verify;
+use core::{
+    mem::{size_of, zeroed},
+    ptr::null_mut,
+};
+use km_shared::ntstatus::{NtStatus, NtStatusError};
+use km_sys::{
+    KeGetCurrentProcessorNumberEx, KeQueryActiveGroupCount, KeQueryActiveProcessorCountEx,
+    KeQueryGroupAffinity, KeQueryHighestNodeNumber, KeQueryLogicalProcessorRelationship,
+    KeQueryMaximumGroupCount, KeQueryMaximumProcessorCountEx, ALL_PROCESSOR_GROUPS, APC_LEVEL,
+    KIRQL, LOGICAL_PROCESSOR_RELATIONSHIP, LTP_PC_SMT, PROCESSOR_NUMBER,
+    SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX, ULONG,
+};
+
+/// The processor counts of the system, returned from [`topology`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Topology {
+    /// The number of active processors in all groups.
+    pub active_processors: u32,
+    /// The number of processors there can be in all groups, including ones that may be added at
+    /// runtime.
+    pub maximum_processors: u32,
+    /// The number of groups with active processors.
+    pub active_groups: u16,
+    /// The number of groups there can be, including ones that may be added at runtime.
+    pub maximum_groups: u16,
+    /// The number of NUMA nodes. Node numbers range from 0 to one less than this, though not all
+    /// of them necessarily have processors or memory.
+    pub numa_nodes: u16,
+}
+
+/// Queries the processor counts of the system.
+///
+/// Can be called at any IRQL.
+pub fn topology() -> Topology {
+    // SAFETY: Just FFI calls, which can be called at any IRQL.
+    unsafe {
+        Topology {
+            active_processors: KeQueryActiveProcessorCountEx(ALL_PROCESSOR_GROUPS as u16),
+            maximum_processors: KeQueryMaximumProcessorCountEx(ALL_PROCESSOR_GROUPS as u16),
+            active_groups: KeQueryActiveGroupCount(),
+            maximum_groups: KeQueryMaximumGroupCount(),
+            numa_nodes: KeQueryHighestNodeNumber() + 1,
+        }
+    }
+}
+
+/// The number of active processors in all groups, like [`Topology::active_processors`].
+///
+/// Can be called at any IRQL.
+pub fn active_processor_count() -> u32 {
+    // SAFETY: Just an FFI call, which can be called at any IRQL.
+    unsafe { KeQueryActiveProcessorCountEx(ALL_PROCESSOR_GROUPS as u16) }
+}
+
+/// A group of processors, returned from [`group`] and [`groups`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessorGroup {
+    pub group: u16,
+    /// The number of active processors in the group.
+    pub active_processors: u32,
+    /// The number of processors there can be in the group.
+    pub maximum_processors: u32,
+    /// The active processors of the group, with bit `n` set if processor number `n` is active.
+    pub active_mask: u64,
+}
+
+/// Queries the processors of group `group`, or returns `None` if there's no such active group.
+///
+/// Can be called at any IRQL.
+pub fn group(group: u16) -> Option<ProcessorGroup> {
+    // SAFETY: Just an FFI call, which can be called at any IRQL.
+    if group >= unsafe { KeQueryActiveGroupCount() } {
+        return None;
+    }
+
+    // SAFETY: Just FFI calls, which can be called at any IRQL.
+    unsafe {
+        Some(ProcessorGroup {
+            group,
+            active_processors: KeQueryActiveProcessorCountEx(group),
+            maximum_processors: KeQueryMaximumProcessorCountEx(group),
+            active_mask: KeQueryGroupAffinity(group),
+        })
+    }
+}
+
+/// Queries all active processor groups, see [`group`].
+///
+/// Can be called at any IRQL.
+pub fn groups() -> impl Iterator<Item = ProcessorGroup> {
+    // SAFETY: Just an FFI call, which can be called at any IRQL.
+    let count = unsafe { KeQueryActiveGroupCount() };
+    (0..count).filter_map(group)
+}
+
+/// Identifies a processor by its group and its number within the group.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/miniport/ns-miniport-_processor_number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcessorNumber {
+    pub group: u16,
+    pub number: u8,
+}
+
+impl ProcessorNumber {
+    fn as_raw(self) -> PROCESSOR_NUMBER {
+        PROCESSOR_NUMBER {
+            Group: self.group,
+            Number: self.number,
+            Reserved: 0,
+        }
+    }
+}
+
+/// The system-wide index of the processor the caller runs on, e.g. to select its slot of a
+/// per-processor structure.
+///
+/// Unless the caller runs at `DISPATCH_LEVEL` or above, it may be moved to another processor
+/// right after this returns.
+///
+/// Can be called at any IRQL.
+pub fn current_processor_index() -> u32 {
+    // SAFETY: Just an FFI call, which can be called at any IRQL.
+    unsafe { KeGetCurrentProcessorNumberEx(null_mut()) }
+}
+
+/// The number of the processor the caller runs on, see [`current_processor_index`].
+///
+/// Can be called at any IRQL.
+pub fn current_processor() -> ProcessorNumber {
+    let mut number = PROCESSOR_NUMBER {
+        Group: 0,
+        Number: 0,
+        Reserved: 0,
+    };
+    // SAFETY: `number` is valid for writes. Can be called at any IRQL.
+    unsafe { KeGetCurrentProcessorNumberEx(&mut number) };
+
+    ProcessorNumber {
+        group: number.Group,
+        number: number.Number,
+    }
+}
+
+/// The physical core a processor belongs to, returned from [`core_of`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessorCore {
+    /// Whether the core runs more than one logical processor, i.e. hyper-threading.
+    pub simultaneous_multithreading: bool,
+    /// The efficiency class of the core. Cores with a higher class are faster, but less power
+    /// efficient.
+    pub efficiency_class: u8,
+    /// The group of the logical processors of the core.
+    pub group: u16,
+    /// The logical processors of the core within `group`.
+    pub mask: u64,
+}
+
+/// Queries the physical core that `processor` belongs to.
+///
+/// Must be called at `IRQL <= APC_LEVEL`.
+///
+/// See [MSDN] for more details on the underlying function.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kequerylogicalprocessorrelationship
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn core_of(processor: ProcessorNumber) -> Result<ProcessorCore, NtStatusError> {
+    let info = query_relationship(
+        processor,
+        LOGICAL_PROCESSOR_RELATIONSHIP::RelationProcessorCore,
+    )?;
+    // SAFETY: The relationship was queried for processor cores.
+    let core = unsafe { info.__bindgen_anon_1.Processor };
+    let group_mask = core.GroupMask[0];
+
+    Ok(ProcessorCore {
+        simultaneous_multithreading: u32::from(core.Flags) & LTP_PC_SMT != 0,
+        efficiency_class: core.EfficiencyClass,
+        group: group_mask.Group,
+        mask: group_mask.Mask,
+    })
+}
+
+/// Queries the NUMA node that `processor` belongs to, e.g. to allocate memory near it.
+///
+/// Must be called at `IRQL <= APC_LEVEL`.
+///
+/// See [MSDN] for more details on the underlying function.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kequerylogicalprocessorrelationship
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn numa_node_of(processor: ProcessorNumber) -> Result<u32, NtStatusError> {
+    let info = query_relationship(processor, LOGICAL_PROCESSOR_RELATIONSHIP::RelationNumaNode)?;
+    // SAFETY: The relationship was queried for NUMA nodes.
+    Ok(unsafe { info.__bindgen_anon_1.NumaNode }.NodeNumber)
+}
+
+/// Queries the single entry of `relationship` for `processor`.
+#[cfg_attr(feature = "verification", track_caller)]
+fn query_relationship(
+    processor: ProcessorNumber,
+    relationship: LOGICAL_PROCESSOR_RELATIONSHIP,
+) -> Result<SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX, NtStatusError> {
+    verify::irql_at_most(APC_LEVEL as KIRQL);
+
+    let mut processor = processor.as_raw();
+    // SAFETY: The structure is plain data, for which all zeroes are valid.
+    let mut info: SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX = unsafe { zeroed() };
+    let mut len = size_of::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>() as ULONG;
+
+    // SAFETY: All pointers are valid, and `len` is the size of `info`. For a single processor,
+    // there's a single entry of the relationship, with a single group mask, which fits the
+    // structure.
+    NtStatus::from(unsafe {
+        KeQueryLogicalProcessorRelationship(&mut processor, relationship, &mut info, &mut len)
+    })
+    .result_for("KeQueryLogicalProcessorRelationship")?;
+
+    Ok(info)
+}
@@ -0,0 +1,199 @@
+//! Driver-side context for crash dumps.
+//!
+//! A [`CrashArea`] holds a small, driver-defined breadcrumb (e.g. the last operation and the last
+//! sensor readings) in non-paged memory. When the system bugchecks, the area is added to the crash
+//! dump as secondary dump data tagged with the area's GUID, from where it can be extracted with
+//! `.enumtag` in WinDbg. Panics handled by [`crate::panic::bugcheck_panic_with`] also record their
+//! location in the area.
+//!
+//! Registry writes or any other I/O can't be done while bugchecking, so the area is only ever
+//! written while the system is running, and merely handed to the dump writer by the callback.
+//!
+//! ```rs, ignore
+//! #[repr(C)]
+//! #[derive(Clone, Copy)]
+//! struct Breadcrumb {
+//!     last_ioctl: u32,
+//!     temperatures: [i16; 4],
+//! }
+//!
+//! static CRASH_AREA: CrashArea<Breadcrumb> = CrashArea::new(
+//!     guid!("0E7B1B1C-9F3A-4F7E-8A52-3C0E5D6B7A01"),
+//!     c"MyDriver",
+//!     Breadcrumb { last_ioctl: 0, temperatures: [0; 4] },
+//! );
+//!
+//! // in `DriverEntry`, and `deregister` on unload
+//! CRASH_AREA.register();
+//!
+//! CRASH_AREA.update(|b| b.last_ioctl = code.0);
+//! ```
+
+use crate::{
+    bugcheck::{SecondaryDumpCallback, SecondaryDumpData},
+    shared::guid::Guid,
+};
+use core::{
+    cell::UnsafeCell,
+    ffi::CStr,
+    mem::size_of,
+    panic::PanicInfo,
+    ptr::{addr_of_mut, write_volatile},
+    sync::atomic::{compiler_fence, AtomicBool, Ordering},
+};
+
+/// The length of [`CrashData::panic_file`].
+pub const PANIC_FILE_LEN: usize = 64;
+
+/// The contents of a [`CrashArea`], as written to the crash dump.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CrashData<T> {
+    /// Incremented before and after each update, so it's odd if the crash happened during an
+    /// update and `state` may be inconsistent.
+    pub sequence: u32,
+    /// The line of the panic's location, or `0` if the driver didn't panic.
+    pub panic_line: u32,
+    pub panic_column: u32,
+    /// The file of the panic's location, truncated and padded with NULs.
+    pub panic_file: [u8; PANIC_FILE_LEN],
+    /// The driver's breadcrumb.
+    pub state: T,
+}
+
+/// A breadcrumb added to crash dumps, see the [module documentation](self).
+///
+/// This has to be a `static`, as it has to stay in place while it's registered.
+pub struct CrashArea<T> {
+    callback: SecondaryDumpCallback<CrashState<T>>,
+}
+
+struct CrashState<T> {
+    guid: Guid,
+    updating: AtomicBool,
+    data: UnsafeCell<CrashData<T>>,
+}
+
+// SAFETY: The data is only written by one thread at a time, guarded by `updating`, and read by the
+// dump writer after all other processors were stopped.
+unsafe impl<T: Send> Sync for CrashState<T> {}
+
+impl<T: Copy + Send> CrashArea<T> {
+    /// Creates an area tagged with `guid` in crash dumps, with `state` as the initial breadcrumb.
+    ///
+    /// `component` identifies the driver to the debugger.
+    pub const fn new(guid: Guid, component: &'static CStr, state: T) -> Self {
+        let state = CrashState {
+            guid,
+            updating: AtomicBool::new(false),
+            data: UnsafeCell::new(CrashData {
+                sequence: 0,
+                panic_line: 0,
+                panic_column: 0,
+                panic_file: [0; PANIC_FILE_LEN],
+                state,
+            }),
+        };
+
+        Self {
+            callback: SecondaryDumpCallback::new(component, state, write_crash_data::<T>),
+        }
+    }
+
+    fn state(&self) -> &CrashState<T> {
+        self.callback.context()
+    }
+
+    /// Registers the bugcheck callback adding the area to crash dumps. Returns `false` if it's
+    /// already registered, or registering failed.
+    ///
+    /// The area must be [deregistered](Self::deregister) before the driver unloads.
+    pub fn register(&'static self) -> bool {
+        self.callback.register()
+    }
+
+    /// Deregisters the bugcheck callback, if it's registered.
+    pub fn deregister(&self) {
+        self.callback.deregister();
+    }
+
+    /// Updates the breadcrumb with `f`. Returns `false` without calling `f` if another thread is
+    /// updating it, as waiting would make this unusable at high IRQLs.
+    ///
+    /// `f` should be short and must not panic, as the area is inconsistent while it runs.
+    pub fn update(&self, f: impl FnOnce(&mut T)) -> bool {
+        let state = self.state();
+        if state
+            .updating
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return false;
+        }
+
+        // SAFETY: `updating` guarantees that no other thread is accessing the data. The bugcheck
+        // callback only passes a pointer to it to the dump writer, which reads it after all other
+        // processors were stopped.
+        unsafe {
+            let data = state.data.get();
+            bump_sequence(data);
+            f(&mut *addr_of_mut!((*data).state));
+            bump_sequence(data);
+        }
+
+        state.updating.store(false, Ordering::Release);
+        true
+    }
+
+    /// Records the location of a panic, which is about to bugcheck the system.
+    ///
+    /// This doesn't wait for updates by other threads, as the data only needs to be consistent
+    /// when the dump is written, after all other processors were stopped.
+    pub fn record_panic(&self, info: &PanicInfo<'_>) {
+        let Some(location) = info.location() else {
+            return;
+        };
+
+        let mut file = [0; PANIC_FILE_LEN];
+        // keep the end of the path, which has the file name, and the terminating NUL
+        let path = location.file().as_bytes();
+        let path = &path[path.len().saturating_sub(PANIC_FILE_LEN - 1)..];
+        file[..path.len()].copy_from_slice(path);
+
+        let data = self.state().data.get();
+        // SAFETY: The pointers are valid, and volatile writes don't create references that could
+        // alias with an update in progress.
+        unsafe {
+            write_volatile(addr_of_mut!((*data).panic_file), file);
+            write_volatile(addr_of_mut!((*data).panic_line), location.line());
+            write_volatile(addr_of_mut!((*data).panic_column), location.column());
+        }
+    }
+}
+
+/// # Safety
+/// `data` must be valid, and the caller must hold `updating`.
+unsafe fn bump_sequence<T>(data: *mut CrashData<T>) {
+    compiler_fence(Ordering::SeqCst);
+    // SAFETY: The caller guarantees that `data` is valid and exclusively accessed.
+    unsafe {
+        write_volatile(
+            addr_of_mut!((*data).sequence),
+            (*data).sequence.wrapping_add(1),
+        )
+    };
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// The bugcheck callback of a [`CrashArea<T>`], handing the area to the dump writer.
+fn write_crash_data<T>(state: &CrashState<T>, dump: &mut SecondaryDumpData<'_>) {
+    // SAFETY: The data is in the driver image, which is non-paged unless the driver pages itself
+    // out, and `CrashArea`s are `'static` while registered.
+    unsafe {
+        dump.write_static(
+            state.guid,
+            state.data.get().cast(),
+            size_of::<CrashData<T>>(),
+        )
+    };
+}
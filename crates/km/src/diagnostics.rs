@@ -0,0 +1,288 @@
+//! The driver side of the standard self-diagnostics code of
+//! [`km_shared::ioctl::diagnostics`].
+//!
+//! A [`DiagnosticsResponder`] records the driver's errors as they happen, and answers
+//! [`ioctl_km_diagnostics`] and [`ioctl_km_build_info`] ahead of the driver's own codes, with the
+//! diagnostics report completed by the driver's counters and queue depths:
+//!
+//! ```rs, ignore
+//! static DIAGNOSTICS: DiagnosticsResponder =
+//!     DiagnosticsResponder::new(DeviceType::custom(0x8000), INTERFACE_VERSION, km::build_info!());
+//!
+//! // in `DriverEntry`
+//! DIAGNOSTICS.start(&driver);
+//!
+//! // in the queue's `EvtIoDeviceControl`
+//! // SAFETY: The output buffer isn't retrieved anywhere else.
+//! let Some(request) = (unsafe {
+//!     DIAGNOSTICS.dispatch(request, code, |report| report.set_counters(&STATS.snapshot()))
+//! }) else {
+//!     return;
+//! };
+//! match code {
+//!     // the driver's own codes, recording their errors
+//!     IOCTL_READ_SENSOR => match read_sensor(&request) {
+//!         Ok(()) => request.complete(NtStatus::STATUS_SUCCESS),
+//!         Err(e) => {
+//!             DIAGNOSTICS.record_error(code.function().into(), e.status());
+//!             request.complete(e.into());
+//!         }
+//!     },
+//!     // ...
+//! }
+//! ```
+
+use crate::{
+    sync::OnceCell,
+    time::{Instant, SystemTime},
+    verify,
+    wdf::{
+        driver::{Driver, KmdfVersion},
+        request::{Request, RetrieveOutputBufferError},
+    },
+};
+use core::{
+    mem::size_of,
+    sync::atomic::{fence, AtomicI64, AtomicU32, AtomicU64, Ordering},
+};
+use km_shared::{
+    ioctl::{
+        build_info::{ioctl_km_build_info, BuildInfo},
+        diagnostics::{ioctl_km_diagnostics, Diagnostics, LastError, MAX_DIAGNOSTICS_ERRORS},
+        DeviceType, IoControlCode, ProtocolVersion, TypedIoControlCode,
+    },
+    ntstatus::{NtStatus, NtStatusError},
+};
+
+/// A recorded error, guarded by a sequence number that's odd while the error is written.
+struct ErrorSlot {
+    sequence: AtomicU32,
+    timestamp: AtomicI64,
+    status: AtomicU32,
+    source: AtomicU32,
+}
+
+impl ErrorSlot {
+    const fn new() -> Self {
+        Self {
+            sequence: AtomicU32::new(0),
+            timestamp: AtomicI64::new(0),
+            status: AtomicU32::new(0),
+            source: AtomicU32::new(0),
+        }
+    }
+
+    /// Writes the error, unless another error is being written to the slot, in which case the
+    /// error is dropped.
+    fn write(&self, error: LastError) {
+        let sequence = self.sequence.load(Ordering::Relaxed);
+        if sequence % 2 == 1
+            || self
+                .sequence
+                .compare_exchange(sequence, sequence + 1, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+
+        self.timestamp.store(error.timestamp, Ordering::Relaxed);
+        self.status.store(error.status as u32, Ordering::Relaxed);
+        self.source.store(error.source, Ordering::Relaxed);
+        self.sequence.store(sequence + 2, Ordering::Release);
+    }
+
+    /// Reads the error, unless the slot was never written, or is being written.
+    fn read(&self) -> Option<LastError> {
+        let sequence = self.sequence.load(Ordering::Acquire);
+        if sequence == 0 || sequence % 2 == 1 {
+            return None;
+        }
+
+        let error = LastError {
+            timestamp: self.timestamp.load(Ordering::Relaxed),
+            status: self.status.load(Ordering::Relaxed) as i32,
+            source: self.source.load(Ordering::Relaxed),
+        };
+
+        fence(Ordering::Acquire);
+        (self.sequence.load(Ordering::Relaxed) == sequence).then_some(error)
+    }
+}
+
+struct Started {
+    at: Instant,
+    kmdf_loaded: KmdfVersion,
+}
+
+/// Records a driver's errors, and answers [`ioctl_km_diagnostics`] and [`ioctl_km_build_info`].
+/// See the [module documentation](self).
+pub struct DiagnosticsResponder {
+    ioctl: TypedIoControlCode<(), Diagnostics>,
+    build_info_ioctl: TypedIoControlCode<(), BuildInfo>,
+    interface_version: ProtocolVersion,
+    build: BuildInfo,
+    started: OnceCell<Started>,
+    errors: [ErrorSlot; MAX_DIAGNOSTICS_ERRORS],
+    error_total: AtomicU64,
+}
+
+impl DiagnosticsResponder {
+    /// Creates a responder for a driver using `device_type` for its codes, usually with the
+    /// `build` from [`build_info!`](crate::build_info!).
+    pub const fn new(
+        device_type: DeviceType,
+        interface_version: ProtocolVersion,
+        build: BuildInfo,
+    ) -> Self {
+        Self {
+            ioctl: ioctl_km_diagnostics(device_type),
+            build_info_ioctl: ioctl_km_build_info(device_type),
+            interface_version,
+            build,
+            started: OnceCell::new(),
+            errors: [const { ErrorSlot::new() }; MAX_DIAGNOSTICS_ERRORS],
+            error_total: AtomicU64::new(0),
+        }
+    }
+
+    /// Records the time the driver started, from which the uptime is reported, and the version of
+    /// the loaded framework. Calls after the first one do nothing.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn start(&self, driver: &Driver) {
+        verify::at_passive_level();
+
+        if self.started.get().is_none() {
+            self.started
+                .set(Started {
+                    at: Instant::now(),
+                    kmdf_loaded: driver.kmdf_version(),
+                })
+                .ok();
+        }
+    }
+
+    /// Records an error with `status`, where `source` identifies what failed, e.g. the function
+    /// code of a failed IOCTL.
+    ///
+    /// Only the last [`MAX_DIAGNOSTICS_ERRORS`] errors are kept. Errors recorded concurrently
+    /// with one that's still being written to the same entry are only counted.
+    ///
+    /// Can be called at any IRQL.
+    pub fn record_error(&self, source: u32, status: NtStatus) {
+        let index = self.error_total.fetch_add(1, Ordering::Relaxed);
+        self.errors[(index % MAX_DIAGNOSTICS_ERRORS as u64) as usize].write(LastError {
+            timestamp: SystemTime::now().as_raw(),
+            status: status.0,
+            source,
+        });
+    }
+
+    /// Records `error`, see [`Self::record_error`].
+    ///
+    /// Can be called at any IRQL.
+    pub fn record(&self, source: u32, error: &NtStatusError) {
+        self.record_error(source, error.status());
+    }
+
+    /// The diagnostics code answered by the responder.
+    pub fn ioctl(&self) -> &TypedIoControlCode<(), Diagnostics> {
+        &self.ioctl
+    }
+
+    /// The build information, with the version of the loaded framework once
+    /// [started](Self::start).
+    ///
+    /// Can be called at any IRQL.
+    pub fn build_info(&self) -> BuildInfo {
+        let mut build = self.build;
+        if let Some(started) = self.started.get() {
+            build.kmdf_loaded = [
+                started.kmdf_loaded.major as u16,
+                started.kmdf_loaded.minor as u16,
+            ];
+        }
+        build
+    }
+
+    /// Creates a report with the versions, build information, uptime and errors, which `fill` can complete with the
+    /// driver's counters and queue depths.
+    ///
+    /// Can be called at any IRQL.
+    pub fn report(&self, fill: impl FnOnce(&mut Diagnostics)) -> Diagnostics {
+        let mut report = Diagnostics::new(self.interface_version, self.build_info());
+
+        if let Some(started) = self.started.get() {
+            report.uptime_ms = started.at.elapsed().as_millis() as u64;
+        }
+
+        // newest first, starting from the last written entry
+        let error_total = self.error_total.load(Ordering::Relaxed);
+        report.error_total = error_total;
+        let recent = error_total.min(MAX_DIAGNOSTICS_ERRORS as u64);
+        for age in 1..=recent {
+            let index = ((error_total - age) % MAX_DIAGNOSTICS_ERRORS as u64) as usize;
+            if let Some(error) = self.errors[index].read() {
+                report.errors[report.error_count as usize] = error;
+                report.error_count += 1;
+            }
+        }
+
+        fill(&mut report);
+        report
+    }
+
+    /// Answers `request` with a [report](Self::report) or the [build information](Self::build_info)
+    /// if `code` is one of the responder's codes, or returns it for the driver to handle otherwise.
+    ///
+    /// The report is truncated to the output buffer, as long as that holds at least its version
+    /// fields, so that clients built against older versions of the report keep working.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Request::retrieve_output_buffer`] apply.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn dispatch(
+        &self,
+        request: Request,
+        code: IoControlCode,
+        fill: impl FnOnce(&mut Diagnostics),
+    ) -> Option<Request> {
+        verify::at_most_dispatch_level();
+
+        let report;
+        let build;
+        let (bytes, min_len) = if self.ioctl == code {
+            report = self.report(fill);
+            (
+                bytemuck::bytes_of(&report),
+                size_of::<u32>() + size_of::<ProtocolVersion>(),
+            )
+        } else if self.build_info_ioctl == code {
+            build = self.build_info();
+            (bytemuck::bytes_of(&build), size_of::<BuildInfo>())
+        } else {
+            return Some(request);
+        };
+
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        let status = match unsafe { request.retrieve_output_buffer(min_len) } {
+            Ok(mut output) => {
+                let len = output.len().min(bytes.len());
+                output[..len].copy_from_slice(&bytes[..len]);
+                drop(output);
+
+                request.set_information(len as u64);
+                NtStatus::STATUS_SUCCESS
+            }
+            Err(RetrieveOutputBufferError::NtStatus { source }) => source.into(),
+            Err(RetrieveOutputBufferError::OutputBufferAlreadyBorrowed) => {
+                NtStatusError::STATUS_INVALID_DEVICE_REQUEST.into()
+            }
+        };
+        request.complete(status);
+        None
+    }
+}
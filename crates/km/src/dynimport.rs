@@ -0,0 +1,145 @@
+//! Optional kernel exports, resolved by name at runtime.
+//!
+//! Linking against an export that doesn't exist on the running Windows version prevents the
+//! driver from loading at all. Exports that are only available on newer versions can instead be
+//! declared with [`dynimport!`](crate::dynimport!), and are resolved with
+//! `MmGetSystemRoutineAddress` on first use:
+//!
+//! ```rs, ignore
+//! km::dynimport! {
+//!     /// Available since Windows 10 2004.
+//!     pub static EX_ALLOCATE_POOL2: unsafe extern "system" fn(
+//!         flags: u64,
+//!         size: usize,
+//!         tag: u32,
+//!     ) -> PVOID = "ExAllocatePool2";
+//! }
+//!
+//! // in `DriverEntry`, which runs at `PASSIVE_LEVEL`
+//! EX_ALLOCATE_POOL2.resolve();
+//!
+//! if let Some(ex_allocate_pool2) = EX_ALLOCATE_POOL2.get() {
+//!     // SAFETY: ...
+//!     let buffer = unsafe { ex_allocate_pool2(POOL_FLAG_NON_PAGED, size, tag) };
+//! }
+//! ```
+
+use crate::verify;
+use core::{
+    ffi::c_void,
+    marker::PhantomData,
+    mem::{size_of, transmute_copy},
+    ptr::null_mut,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+use km_sys::{MmGetSystemRoutineAddress, UNICODE_STRING, WCHAR};
+
+/// Marks an export that was looked up, but doesn't exist.
+const NOT_FOUND: *mut c_void = usize::MAX as *mut c_void;
+
+/// An export resolved at runtime, see the [module documentation](self).
+///
+/// `F` is the function pointer type of the export.
+pub struct DynImport<F> {
+    /// The NUL-terminated name of the export.
+    name: &'static [WCHAR],
+    /// The cached address of the export: null if it wasn't looked up yet, or [`NOT_FOUND`].
+    address: AtomicPtr<c_void>,
+    _fn: PhantomData<F>,
+}
+
+impl<F: Copy> DynImport<F> {
+    #[doc(hidden)]
+    /// Use [`dynimport!`](crate::dynimport!) instead.
+    ///
+    /// # Safety
+    /// `F` has to be an `unsafe` function pointer type matching the signature of the export named
+    /// `name`, which has to be NUL-terminated.
+    pub const unsafe fn _internal_new(name: &'static [WCHAR]) -> Self {
+        assert!(size_of::<F>() == size_of::<*mut c_void>());
+
+        Self {
+            name,
+            address: AtomicPtr::new(null_mut()),
+            _fn: PhantomData,
+        }
+    }
+
+    /// Looks up the export, if it wasn't looked up yet. Returns whether it exists.
+    ///
+    /// Looking up exports requires `PASSIVE_LEVEL`, so this should be called during
+    /// initialization for exports that are used at higher IRQLs.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-mmgetsystemroutineaddress
+    pub fn resolve(&self) -> bool {
+        self.address() != NOT_FOUND
+    }
+
+    /// Gets the export, looking it up if necessary (see [`Self::resolve`]). Returns `None` if it
+    /// doesn't exist on the running OS.
+    pub fn get(&self) -> Option<F> {
+        let address = self.address();
+        if address == NOT_FOUND {
+            return None;
+        }
+
+        // SAFETY: `F` is a function pointer type matching the export, as guaranteed by the caller
+        // of `Self::_internal_new`.
+        Some(unsafe { transmute_copy::<*mut c_void, F>(&address) })
+    }
+
+    fn address(&self) -> *mut c_void {
+        let address = self.address.load(Ordering::Acquire);
+        if !address.is_null() {
+            return address;
+        }
+
+        verify::at_passive_level();
+
+        let len_bytes = (self.name.len() - 1) * size_of::<WCHAR>();
+        let mut name = UNICODE_STRING {
+            Length: len_bytes as u16,
+            MaximumLength: (len_bytes + size_of::<WCHAR>()) as u16,
+            Buffer: self.name.as_ptr().cast_mut(),
+        };
+
+        // SAFETY: `name` is a valid `UNICODE_STRING`, which isn't modified.
+        let mut address = unsafe { MmGetSystemRoutineAddress(&mut name) };
+        if address.is_null() {
+            address = NOT_FOUND;
+        }
+        // racing lookups all store the same address
+        self.address.store(address, Ordering::Release);
+        address
+    }
+}
+
+/// Declares kernel exports that are resolved at runtime, see the
+/// [module documentation](crate::dynimport).
+///
+/// The declared signature can't be checked, and has to match the export's exactly.
+#[macro_export]
+macro_rules! dynimport {
+    {
+        $(
+            $(#[$attr:meta])*
+            $vis:vis static $name:ident: unsafe extern "system" fn(
+                $($arg:ident: $arg_ty:ty),* $(,)?
+            ) $(-> $ret:ty)? = $export:literal;
+        )*
+    } => {
+        $(
+            $(#[$attr])*
+            $vis static $name: $crate::dynimport::DynImport<
+                unsafe extern "system" fn($($arg: $arg_ty),*) $(-> $ret)?
+            > =
+                // SAFETY: Macro generated; the type is an `unsafe` function pointer, and the name
+                // is NUL-terminated.
+                unsafe {
+                    $crate::dynimport::DynImport::_internal_new($crate::shared::wchz!($export))
+                };
+        )*
+    };
+}
@@ -0,0 +1,226 @@
+//! Access to the ACPI embedded controller (EC) through its I/O ports.
+//!
+//! The EC exposes a 256-byte register space (fan speeds, temperatures, battery state, ...) through
+//! a simple handshake on two ports: commands are written to the command port (usually `0x66`),
+//! whose reads return the status, and addresses and data are exchanged through the data port
+//! (usually `0x62`). See [ACPI Embedded Controller Interface Specification][spec] for details.
+//!
+//! ```rs, ignore
+//! let fan_duty = ec::STANDARD.read_byte(0x93)?;
+//!
+//! // several registers in one critical section, e.g. a 16-bit value split across two
+//! let ec = ec::STANDARD.lock()?;
+//! let rpm = u16::from_le_bytes([ec.read_byte(0xA0)?, ec.read_byte(0xA1)?]);
+//! ```
+//!
+//! Note that the EC is shared with the ACPI driver and the firmware, which don't take the lock of
+//! [`EmbeddedController`]. It only serializes the transactions of this driver.
+//!
+//! [spec]: https://uefi.org/specs/ACPI/6.5/12_ACPI_Embedded_Controller_Interface_Specification.html
+
+use crate::{
+    port::Port,
+    time::{sleep_km, Instant},
+};
+use core::{
+    hint::spin_loop,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use snafu::Snafu;
+
+// Status register bits.
+/// Output buffer full: the EC wrote data for the host.
+const EC_OBF: u8 = 0x01;
+/// Input buffer full: the EC didn't consume the last command or data byte yet.
+const EC_IBF: u8 = 0x02;
+
+// Commands.
+const RD_EC: u8 = 0x80;
+const WR_EC: u8 = 0x81;
+
+/// How often the status is polled without sleeping. ECs usually respond within a few
+/// microseconds, while sleeping takes at least a timer tick.
+const SPIN_POLLS: u32 = 1000;
+
+/// An error returned by [`EmbeddedController`].
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+pub enum EcError {
+    /// The EC didn't respond in time.
+    #[snafu(display("timed out waiting for the EC to {waiting_for}"))]
+    Timeout { waiting_for: &'static str },
+    /// Another transaction of this driver didn't finish in time.
+    #[snafu(display("timed out waiting for the EC lock"))]
+    LockTimeout,
+}
+
+/// An embedded controller, accessed through its data and command ports.
+///
+/// All transactions are serialized by a lock, which is held by [`EcGuard`]s. Transactions may
+/// sleep, so all methods must be called at `PASSIVE_LEVEL`.
+#[derive(Debug)]
+pub struct EmbeddedController {
+    data_port: u16,
+    command_port: u16,
+    timeout: Duration,
+    locked: AtomicBool,
+}
+
+/// The EC at the standard ports `0x62`/`0x66`.
+///
+/// Most systems have a single EC at these ports. The actual ports are listed in the `_CRS` of
+/// the EC device (`PNP0C09`) or in the `ECDT` table.
+// SAFETY: These are the ports the ACPI specification reserves for the EC.
+pub static STANDARD: EmbeddedController = unsafe { EmbeddedController::new(0x62, 0x66) };
+
+impl EmbeddedController {
+    /// # Safety
+    /// `data_port` and `command_port` must be the ports of an ACPI EC.
+    pub const unsafe fn new(data_port: u16, command_port: u16) -> Self {
+        Self {
+            data_port,
+            command_port,
+            timeout: Duration::from_millis(100),
+            locked: AtomicBool::new(false),
+        }
+    }
+
+    /// Sets how long each step of a transaction may take, and how long to wait for the lock (100
+    /// ms by default).
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Locks the EC for several transactions.
+    pub fn lock(&self) -> Result<EcGuard<'_>, EcError> {
+        let acquired = poll_until(self.timeout, || {
+            self.locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        });
+
+        match acquired {
+            true => Ok(EcGuard(self)),
+            false => Err(EcError::LockTimeout),
+        }
+    }
+
+    /// Reads the EC register at `address`.
+    pub fn read_byte(&self, address: u8) -> Result<u8, EcError> {
+        self.lock()?.read_byte(address)
+    }
+
+    /// Writes `value` to the EC register at `address`.
+    pub fn write_byte(&self, address: u8, value: u8) -> Result<(), EcError> {
+        self.lock()?.write_byte(address, value)
+    }
+}
+
+/// Exclusive access to an [`EmbeddedController`], released on drop.
+#[must_use = "the EC is unlocked immediately if the guard is dropped"]
+#[derive(Debug)]
+pub struct EcGuard<'a>(&'a EmbeddedController);
+
+impl EcGuard<'_> {
+    fn status(&self) -> u8 {
+        // SAFETY: The port is the EC's command port, as guaranteed by the contract of
+        // `EmbeddedController::new`. Reading it has no side effects.
+        unsafe { Port::<u8>::new(self.0.command_port).read() }
+    }
+
+    /// Waits until the EC consumed the last byte written.
+    fn wait_input_empty(&self) -> Result<(), EcError> {
+        match poll_until(self.0.timeout, || self.status() & EC_IBF == 0) {
+            true => Ok(()),
+            false => Err(EcError::Timeout {
+                waiting_for: "accept input",
+            }),
+        }
+    }
+
+    /// Waits until the EC wrote a byte for the host.
+    fn wait_output_full(&self) -> Result<(), EcError> {
+        match poll_until(self.0.timeout, || self.status() & EC_OBF != 0) {
+            true => Ok(()),
+            false => Err(EcError::Timeout {
+                waiting_for: "send output",
+            }),
+        }
+    }
+
+    fn write_command(&self, command: u8) -> Result<(), EcError> {
+        self.wait_input_empty()?;
+        // SAFETY: The port is the EC's command port, as guaranteed by the contract of
+        // `EmbeddedController::new`. The lock is held.
+        unsafe { Port::<u8>::new(self.0.command_port).write(command) };
+        Ok(())
+    }
+
+    fn write_data(&self, data: u8) -> Result<(), EcError> {
+        self.wait_input_empty()?;
+        // SAFETY: The port is the EC's data port, as guaranteed by the contract of
+        // `EmbeddedController::new`. The lock is held.
+        unsafe { Port::<u8>::new(self.0.data_port).write(data) };
+        Ok(())
+    }
+
+    fn read_data(&self) -> Result<u8, EcError> {
+        self.wait_output_full()?;
+        // SAFETY: The port is the EC's data port, as guaranteed by the contract of
+        // `EmbeddedController::new`. The lock is held.
+        Ok(unsafe { Port::<u8>::new(self.0.data_port).read() })
+    }
+
+    /// Reads the EC register at `address` (`RD_EC`).
+    pub fn read_byte(&self, address: u8) -> Result<u8, EcError> {
+        self.write_command(RD_EC)?;
+        self.write_data(address)?;
+        self.read_data()
+    }
+
+    /// Writes `value` to the EC register at `address` (`WR_EC`).
+    pub fn write_byte(&self, address: u8, value: u8) -> Result<(), EcError> {
+        self.write_command(WR_EC)?;
+        self.write_data(address)?;
+        self.write_data(value)?;
+        self.wait_input_empty()
+    }
+
+    /// Reads consecutive EC registers starting at `address` into `buf`.
+    pub fn read_bytes(&self, address: u8, buf: &mut [u8]) -> Result<(), EcError> {
+        for (byte, address) in buf.iter_mut().zip(address..=u8::MAX) {
+            *byte = self.read_byte(address)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for EcGuard<'_> {
+    fn drop(&mut self) {
+        self.0.locked.store(false, Ordering::Release);
+    }
+}
+
+/// Polls `condition` until it's true or `timeout` elapsed, spinning first and sleeping afterwards.
+/// Returns whether the condition became true.
+fn poll_until(timeout: Duration, mut condition: impl FnMut() -> bool) -> bool {
+    for _ in 0..SPIN_POLLS {
+        if condition() {
+            return true;
+        }
+        spin_loop();
+    }
+
+    let start = Instant::now();
+    loop {
+        if condition() {
+            return true;
+        }
+        if start.elapsed() > timeout {
+            return false;
+        }
+        sleep_km(Duration::from_millis(1));
+    }
+}
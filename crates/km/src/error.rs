@@ -0,0 +1,116 @@
+//! The crate-wide [`Error`] type.
+//!
+//! Wrappers whose failures are more than a failed kernel or framework call return an [`Error`],
+//! so that callers can tell the causes apart, e.g. to log an actionable message during hardware
+//! bring-up, while still being able to complete a request with [`Error::nt_status`]:
+//!
+//! ```rs, ignore
+//! fn create_control_device(driver: &mut Driver) -> Result<Device, Error> {
+//!     let init = driver.allocate_control_device_init(&SDDL)?;
+//!     let regs = unsafe { MappedIoSpace::<Regs, ReadWrite>::create_mapping(BASE, mods) }?;
+//!     // ...
+//! }
+//!
+//! if let Err(e) = create_control_device(&mut driver) {
+//!     km_error!("creating the control device failed: {e}");
+//!     return e.nt_status().into();
+//! }
+//! ```
+//!
+//! Wrappers that only fail with the status of the underlying call keep returning a bare
+//! [`NtStatusError`], which converts into an [`Error`] with `?`.
+
+use crate::{
+    io_mmap::MapIoSpaceError,
+    wdf::{
+        ioctl_policy::PolicyViolation,
+        request::{IoCtlError, RetrieveOutputBufferError},
+        throttle::Throttled,
+    },
+};
+use km_shared::ntstatus::NtStatusError;
+use snafu::Snafu;
+
+/// An error returned by the wrappers of this crate. See the [module documentation](self).
+#[derive(Debug, Snafu)]
+#[snafu(module, visibility(pub(crate)))]
+#[non_exhaustive]
+pub enum Error {
+    /// A kernel or framework call failed.
+    #[snafu(context(false), display("{source}"))]
+    NtStatus { source: NtStatusError },
+    /// Mapping I/O space into system space failed.
+    #[snafu(context(false), display("{source}"))]
+    MapIoSpace { source: MapIoSpaceError },
+    /// The output buffer of a request was already borrowed.
+    #[snafu(display("output buffer already borrowed"))]
+    OutputBufferAlreadyBorrowed,
+    /// A buffer has the wrong size or contents for the type it's accessed as.
+    #[snafu(display("buffer doesn't hold a valid value"))]
+    InvalidBuffer,
+    /// Field `field` of an input buffer violates its
+    /// [constraints](km_shared::ioctl::Validate).
+    #[snafu(display("input field {field} is invalid"))]
+    InvalidInput { field: u32 },
+    /// A request doesn't meet the [policy](crate::wdf::ioctl_policy::IoctlPolicy) of its code.
+    #[snafu(context(false), display("{source}"))]
+    PolicyViolation { source: PolicyViolation },
+    /// A request was [throttled](crate::wdf::throttle) for its handle.
+    #[snafu(context(false), display("{source}"))]
+    Throttled { source: Throttled },
+    /// The kernel or framework couldn't allocate `what`.
+    #[snafu(display("allocating {what} failed"))]
+    AllocationFailed { what: &'static str },
+    /// A string would exceed the length a `UNICODE_STRING` can hold.
+    #[snafu(display("string too long for a UNICODE_STRING"))]
+    StringTooLong,
+}
+
+impl Error {
+    /// The status to complete a request that failed with this error with.
+    pub fn nt_status(&self) -> NtStatusError {
+        match self {
+            Self::NtStatus { source } => *source,
+            Self::MapIoSpace { source } => match source {
+                MapIoSpaceError::ZeroSized { .. } => NtStatusError::STATUS_INVALID_PARAMETER,
+                MapIoSpaceError::MapFailed { .. } => NtStatusError::STATUS_INSUFFICIENT_RESOURCES,
+                MapIoSpaceError::Misaligned { .. } => {
+                    NtStatusError::STATUS_DATATYPE_MISALIGNMENT_ERROR
+                }
+            },
+            Self::PolicyViolation { source } => source.nt_status(),
+            Self::Throttled { source } => source.nt_status(),
+            Self::AllocationFailed { .. } => NtStatusError::STATUS_INSUFFICIENT_RESOURCES,
+            Self::StringTooLong => NtStatusError::STATUS_NAME_TOO_LONG,
+            Self::InvalidBuffer | Self::InvalidInput { .. } => {
+                NtStatusError::STATUS_INVALID_PARAMETER
+            }
+            Self::OutputBufferAlreadyBorrowed => NtStatusError::STATUS_INVALID_DEVICE_REQUEST,
+        }
+    }
+}
+
+impl From<RetrieveOutputBufferError> for Error {
+    fn from(e: RetrieveOutputBufferError) -> Self {
+        match e {
+            RetrieveOutputBufferError::OutputBufferAlreadyBorrowed => {
+                Self::OutputBufferAlreadyBorrowed
+            }
+            RetrieveOutputBufferError::NtStatus { source } => Self::NtStatus { source },
+        }
+    }
+}
+
+impl From<IoCtlError> for Error {
+    fn from(e: IoCtlError) -> Self {
+        match e {
+            IoCtlError::OutputBufferAlreadyBorrowed => Self::OutputBufferAlreadyBorrowed,
+            IoCtlError::NtStatus { source } => Self::NtStatus { source },
+            IoCtlError::Cast { .. } => Self::InvalidBuffer,
+            IoCtlError::InvalidInput { source } => Self::InvalidInput {
+                field: source.index,
+            },
+            IoCtlError::PolicyViolation { source } => Self::PolicyViolation { source },
+        }
+    }
+}
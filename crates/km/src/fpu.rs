@@ -0,0 +1,67 @@
+//! Using floating point in kernel mode.
+//!
+//! Kernel code can't use floating point (`f32`/`f64`) without care, as the kernel doesn't always
+//! preserve the registers of the floating point unit for it (see [MSDN]). On x64, floating point
+//! code is compiled to SSE instructions, whose state the kernel preserves, so saving it is a no-op
+//! there. Code compiled with AVX enabled still needs `KeSaveExtendedProcessorState`.
+//!
+//! Floating point code should still be wrapped in [`with_fpu`] (or hold a [`FloatGuard`] while it
+//! runs), which keeps it from being interleaved with other code:
+//!
+//! ```rs, ignore
+//! let duty = fpu::with_fpu(|| curve.interpolate(temperature as f32) as u8)?;
+//! ```
+//!
+//! Floating point values can be passed out of the closure, but shouldn't be used outside of it.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/using-floating-point-or-mmx-in-a-wdm-driver
+
+use crate::verify;
+use core::marker::PhantomData;
+use km_shared::ntstatus::NtStatusError;
+
+/// Saves the floating point state when created, and restores it when dropped. Floating point
+/// code can be used while the guard is alive.
+///
+/// Guards have to be dropped in the reverse order they were created in, on the thread that
+/// created them, which the borrow checker can't enforce, so prefer [`with_fpu`].
+///
+/// See [MSDN] for more details on the underlying functions.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kesavefloatingpointstate
+#[must_use = "the floating point state is restored when the guard is dropped"]
+pub struct FloatGuard {
+    // the state belongs to the current thread
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl FloatGuard {
+    /// Saves the floating point state of the current thread.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn save() -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        // SSE state is preserved by the kernel, see the module documentation
+        Ok(Self {
+            _not_send: PhantomData,
+        })
+    }
+}
+
+/// Runs `f` with the floating point state saved, see the [module documentation](self).
+///
+/// Must be called at `IRQL <= DISPATCH_LEVEL`.
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn with_fpu<R>(f: impl FnOnce() -> R) -> Result<R, NtStatusError> {
+    let _guard = FloatGuard::save()?;
+    Ok(run(f))
+}
+
+/// Keeps the floating point code of `f` from being inlined into, and moved around the saving and
+/// restoring of the state in [`with_fpu`].
+#[inline(never)]
+fn run<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
@@ -0,0 +1,174 @@
+//! Driver-wide state, created in `DriverEntry` and torn down on unload.
+//!
+//! Instead of a `static mut` (or a `static` with an `Option` behind a lock), the state is put into
+//! a [`DriverGlobals`], which can only be initialized once, hands out references that keep the
+//! state alive while they're held, and waits for all of them to be dropped before tearing it down:
+//!
+//! ```rs, ignore
+//! static STATE: DriverGlobals<DriverState> = DriverGlobals::new();
+//!
+//! // in `DriverEntry`
+//! STATE.init(DriverState::new(&registry_path)?).ok();
+//!
+//! // anywhere, at `IRQL <= DISPATCH_LEVEL`
+//! if let Some(state) = STATE.get() {
+//!     state.fan_curve.lock().apply(temperature);
+//! }
+//!
+//! // in the unload routine, after the devices the state refers to are torn down
+//! // SAFETY: The driver doesn't use its devices anymore.
+//! unsafe { Driver::from(driver).devices().teardown() };
+//! STATE.teardown();
+//! ```
+
+use crate::{
+    unload::{Rundown, RundownGuard},
+    verify,
+};
+use core::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+const EMPTY: u8 = 0;
+const INITIALIZING: u8 = 1;
+const READY: u8 = 2;
+const TORN_DOWN: u8 = 3;
+
+/// A container for driver-wide state, which is initialized once and torn down once. See the
+/// [module documentation](self).
+pub struct DriverGlobals<T> {
+    state: AtomicU8,
+    rundown: Rundown,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: The value is only written before it's published by `state`, and only dropped after the
+// rundown was waited for, i.e. while no references to it exist. It's shared between threads, and
+// may be dropped on a different thread than it was created on.
+unsafe impl<T: Send + Sync> Sync for DriverGlobals<T> {}
+// SAFETY: See above.
+unsafe impl<T: Send> Send for DriverGlobals<T> {}
+
+impl<T> DriverGlobals<T> {
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(EMPTY),
+            rundown: Rundown::new(),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Stores the state, unless it was already initialized or torn down, in which case `value` is
+    /// returned back.
+    pub fn init(&self, value: T) -> Result<(), T> {
+        if self
+            .state
+            .compare_exchange(EMPTY, INITIALIZING, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return Err(value);
+        }
+
+        // SAFETY: Only the caller that moved the state to `INITIALIZING` writes the value, and it
+        // isn't read before the state is `READY`.
+        unsafe { (*self.value.get()).write(value) };
+        self.state.store(READY, Ordering::Release);
+        Ok(())
+    }
+
+    /// Whether the state was initialized, and not torn down yet.
+    pub fn is_initialized(&self) -> bool {
+        self.state.load(Ordering::Acquire) == READY
+    }
+
+    /// Returns a reference to the state, or `None` if it's not initialized, or torn down.
+    ///
+    /// [`Self::teardown`] waits for the reference to be dropped, so it mustn't be held for long,
+    /// or by the unloading thread itself.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn get(&self) -> Option<DriverGlobalsRef<'_, T>> {
+        if !self.is_initialized() {
+            return None;
+        }
+
+        // Fails once `teardown` waited for the rundown. If it starts waiting after this, it waits
+        // for the guard to be dropped before dropping the value.
+        let guard = self.rundown.acquire()?;
+
+        Some(DriverGlobalsRef {
+            // SAFETY: The state was `READY`, so the value was initialized, and the guard keeps it
+            // from being dropped.
+            value: unsafe { (*self.value.get()).assume_init_ref() },
+            _guard: guard,
+        })
+    }
+
+    /// Makes further [`Self::get`] and [`Self::init`] calls fail, waits for all references to be
+    /// dropped, and drops the state. Does nothing if it's already torn down.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn teardown(&self) {
+        verify::at_passive_level();
+
+        let previous = loop {
+            match self.state.load(Ordering::Acquire) {
+                // `init` is about to finish
+                INITIALIZING => core::hint::spin_loop(),
+                state => {
+                    if self
+                        .state
+                        .compare_exchange(state, TORN_DOWN, Ordering::AcqRel, Ordering::Acquire)
+                        .is_ok()
+                    {
+                        break state;
+                    }
+                }
+            }
+        };
+
+        self.rundown.wait_for_release();
+
+        if previous == READY {
+            // SAFETY: The value was initialized, and no references to it exist anymore, as the
+            // rundown was waited for. Only the caller that moved the state from `READY` to
+            // `TORN_DOWN` drops it.
+            unsafe { (*self.value.get()).assume_init_drop() };
+        }
+    }
+}
+
+impl<T> Default for DriverGlobals<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DriverGlobals<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == READY {
+            // SAFETY: The value was initialized, and not dropped by `teardown`. References can't
+            // exist anymore, as they borrow `self`.
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+/// A reference to the state of a [`DriverGlobals`], which keeps it from being torn down.
+pub struct DriverGlobalsRef<'a, T> {
+    value: &'a T,
+    _guard: RundownGuard<'a>,
+}
+
+impl<T> Deref for DriverGlobalsRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
@@ -0,0 +1,533 @@
+//! Kernel-mode access to HID collections, through the HID class driver.
+//!
+//! A [`HidCollection`] wraps an [`IoTarget`] opened on a HID collection (e.g. by the symbolic link
+//! of its device interface), and sends the same requests `hid.dll` sends on behalf of user mode
+//! clients. Reports can be exchanged as plain byte buffers, or as [`Report`]s with a fixed layout:
+//!
+//! ```rs, ignore
+//! #[repr(C, packed)]
+//! #[derive(Clone, Copy)]
+//! struct LedFeature {
+//!     report_id: u8,
+//!     mode: u8,
+//!     color: [u8; 3],
+//! }
+//!
+//! impl Report for LedFeature {
+//!     const TYPE: ReportType = ReportType::Feature;
+//!     const ID: u8 = 0x21;
+//! }
+//!
+//! let collection = HidCollection::open(&device, &link, FILE_GENERIC_READ | FILE_GENERIC_WRITE)?;
+//! let mut led: LedFeature = collection.get_report()?;
+//! led.mode = 1;
+//! collection.set_report(&led)?;
+//! ```
+//!
+//! See [HID Clients][MSDN] for more information.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/hid/hid-clients
+
+use crate::wdf::{
+    device::Device,
+    io_target::{IoTarget, IoTargetOpenParamsInit, RequestSendOptions},
+    memory::Memory,
+    object_attributes::ObjectAttributes,
+};
+use bytemuck::{checked::CheckedCastError, CheckedBitPattern, NoUninit};
+use core::{
+    mem::{size_of, MaybeUninit},
+    slice,
+};
+use km_shared::{
+    ioctl::IoControlCode,
+    ntstatus::{NtStatus, NtStatusError},
+    strings::UnicodeString,
+};
+use km_sys::{
+    HidP_GetCaps, ACCESS_MASK, FILE_ANY_ACCESS, FILE_DEVICE_KEYBOARD, HIDP_CAPS,
+    HID_COLLECTION_INFORMATION, METHOD_BUFFERED, METHOD_IN_DIRECT, METHOD_NEITHER,
+    METHOD_OUT_DIRECT,
+};
+use snafu::{ensure, ResultExt, Snafu};
+
+/// Mimicks the `HID_CTL_CODE` family of macros from `hidclass.h`.
+const fn hid_ctl_code(function: u32, method: u32) -> IoControlCode {
+    IoControlCode((FILE_DEVICE_KEYBOARD << 16) | (FILE_ANY_ACCESS << 14) | (function << 2) | method)
+}
+
+const IOCTL_HID_GET_COLLECTION_INFORMATION: IoControlCode = hid_ctl_code(106, METHOD_BUFFERED);
+const IOCTL_HID_GET_COLLECTION_DESCRIPTOR: IoControlCode = hid_ctl_code(100, METHOD_NEITHER);
+const IOCTL_HID_GET_FEATURE: IoControlCode = hid_ctl_code(100, METHOD_OUT_DIRECT);
+const IOCTL_HID_SET_FEATURE: IoControlCode = hid_ctl_code(100, METHOD_IN_DIRECT);
+const IOCTL_HID_SET_OUTPUT_REPORT: IoControlCode = hid_ctl_code(101, METHOD_IN_DIRECT);
+const IOCTL_HID_GET_INPUT_REPORT: IoControlCode = hid_ctl_code(104, METHOD_OUT_DIRECT);
+
+/// The pool tag of the buffer holding the preparsed data of a collection.
+const PREPARSED_DATA_TAG: u32 = u32::from_le_bytes(*b"KmHp");
+
+/// The type of a HID report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportType {
+    /// Sent from the device to the host, either periodically/on change (read), or on request.
+    Input,
+    /// Sent from the host to the device.
+    Output,
+    /// Configuration data, which can be read and written by the host.
+    Feature,
+}
+
+/// A HID report with a fixed layout, including the leading report ID byte.
+///
+/// The size of the type must equal the report length of its [type](Self::TYPE) in the
+/// collection's [capabilities](Capabilities), i.e. the length of the longest report of that type,
+/// as the HID class driver rejects shorter buffers. Shorter reports have to be padded.
+pub trait Report: NoUninit + CheckedBitPattern {
+    const TYPE: ReportType;
+    /// The report ID, which is the first byte of the report. Collections without report IDs use
+    /// an ID of 0.
+    const ID: u8;
+}
+
+/// Information about a HID collection and the device it belongs to (see
+/// `HID_COLLECTION_INFORMATION`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionInformation {
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub version_number: u16,
+    /// Whether the device has to be polled for input reports, instead of sending them on its own.
+    pub polled: bool,
+}
+
+/// The capabilities of a HID collection, parsed from its report descriptor (see `HIDP_CAPS`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    pub usage_page: u16,
+    pub usage: u16,
+    /// The length of the longest input report, including the report ID byte.
+    pub input_report_len: usize,
+    /// The length of the longest output report, including the report ID byte.
+    pub output_report_len: usize,
+    /// The length of the longest feature report, including the report ID byte.
+    pub feature_report_len: usize,
+}
+
+impl Capabilities {
+    /// The length of the longest report of type `ty`, which report buffers need to hold.
+    pub fn report_len(&self, ty: ReportType) -> usize {
+        match ty {
+            ReportType::Input => self.input_report_len,
+            ReportType::Output => self.output_report_len,
+            ReportType::Feature => self.feature_report_len,
+        }
+    }
+}
+
+impl From<HIDP_CAPS> for Capabilities {
+    fn from(caps: HIDP_CAPS) -> Self {
+        Self {
+            usage_page: caps.UsagePage,
+            usage: caps.Usage,
+            input_report_len: caps.InputReportByteLength.into(),
+            output_report_len: caps.OutputReportByteLength.into(),
+            feature_report_len: caps.FeatureReportByteLength.into(),
+        }
+    }
+}
+
+/// An error returned by [`HidCollection`].
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum HidError {
+    #[snafu(display("{operation} failed"))]
+    NtStatus {
+        operation: &'static str,
+        source: NtStatusError,
+    },
+    #[snafu(display("report buffer has {len} bytes, but {report_type:?} reports need {needed}"))]
+    ReportLength {
+        report_type: ReportType,
+        len: usize,
+        needed: usize,
+    },
+    #[snafu(display("{report_type:?} reports can't be {operation}"))]
+    UnsupportedReportType {
+        report_type: ReportType,
+        operation: &'static str,
+    },
+    #[snafu(display("report starts with ID {actual}, but should have ID {expected}"))]
+    ReportId { expected: u8, actual: u8 },
+    #[snafu(display("the device returned an invalid report"))]
+    Cast { inner: CheckedCastError },
+}
+
+/// An opened HID collection, with its preparsed data and capabilities.
+#[derive(Debug)]
+pub struct HidCollection {
+    target: IoTarget,
+    information: CollectionInformation,
+    /// The preparsed data, as used by the `HidP_Xxx` functions. It is a child object of the
+    /// target.
+    preparsed_data: Memory,
+    capabilities: Capabilities,
+}
+
+impl HidCollection {
+    /// Opens the HID collection with the given name (usually the symbolic link of its device
+    /// interface) as a remote I/O target of `device`.
+    ///
+    /// `desired_access` has to include write access to send output or feature reports.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn open(
+        device: &Device,
+        name: &UnicodeString,
+        desired_access: ACCESS_MASK,
+    ) -> Result<Self, HidError> {
+        let mut target = IoTarget::create(device, None).context(hid_error::NtStatusSnafu {
+            operation: "creating the I/O target",
+        })?;
+
+        let init = IoTargetOpenParamsInit::ByName {
+            device_name: name,
+            desired_access,
+        };
+        // SAFETY: The target is opened by name, so there are no pointers to keep valid.
+        let params = unsafe { init.build() };
+        target.open(&params).context(hid_error::NtStatusSnafu {
+            operation: "opening the collection",
+        })?;
+
+        Self::from_io_target(target)
+    }
+
+    /// Uses an already opened I/O target of a HID collection, e.g. the default I/O target of a
+    /// filter driver on a collection's stack.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn from_io_target(target: IoTarget) -> Result<Self, HidError> {
+        let options = RequestSendOptions::default();
+
+        // SAFETY: All-zero is a valid bit pattern for the information.
+        let mut information: HID_COLLECTION_INFORMATION = unsafe { core::mem::zeroed() };
+        // SAFETY: The struct only consists of integers, so it may be written as bytes.
+        let information_bytes = unsafe {
+            slice::from_raw_parts_mut(
+                (&mut information as *mut HID_COLLECTION_INFORMATION).cast::<u8>(),
+                size_of::<HID_COLLECTION_INFORMATION>(),
+            )
+        };
+        target
+            .send_ioctl_synchronously(
+                None,
+                IOCTL_HID_GET_COLLECTION_INFORMATION,
+                None,
+                Some(information_bytes),
+                &options,
+            )
+            .context(hid_error::NtStatusSnafu {
+                operation: "IOCTL_HID_GET_COLLECTION_INFORMATION",
+            })?;
+
+        let mut attributes = ObjectAttributes::default();
+        attributes.set_parent(&target);
+        let mut preparsed_data = Memory::create(
+            information.DescriptorSize as usize,
+            PREPARSED_DATA_TAG,
+            Some(&mut attributes),
+        )
+        .context(hid_error::NtStatusSnafu {
+            operation: "allocating the preparsed data",
+        })?;
+        target
+            .send_ioctl_synchronously(
+                None,
+                IOCTL_HID_GET_COLLECTION_DESCRIPTOR,
+                None,
+                Some(&mut preparsed_data[..]),
+                &options,
+            )
+            .context(hid_error::NtStatusSnafu {
+                operation: "IOCTL_HID_GET_COLLECTION_DESCRIPTOR",
+            })?;
+
+        // SAFETY: All-zero is a valid bit pattern for the capabilities.
+        let mut caps: HIDP_CAPS = unsafe { core::mem::zeroed() };
+        // SAFETY: The preparsed data was just filled in by the HID class driver, and isn't
+        // modified by `HidP_GetCaps`.
+        let status = unsafe { HidP_GetCaps(preparsed_data.as_mut_ptr().cast(), &mut caps) };
+        NtStatus::from(status)
+            .result_for("HidP_GetCaps")
+            .context(hid_error::NtStatusSnafu {
+                operation: "HidP_GetCaps",
+            })?;
+
+        Ok(Self {
+            target,
+            information: CollectionInformation {
+                vendor_id: information.VendorID,
+                product_id: information.ProductID,
+                version_number: information.VersionNumber,
+                polled: information.Polled != 0,
+            },
+            preparsed_data,
+            capabilities: caps.into(),
+        })
+    }
+
+    pub fn information(&self) -> &CollectionInformation {
+        &self.information
+    }
+
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
+    /// The preparsed data of the collection, for use with other `HidP_Xxx` functions (as a
+    /// `PHIDP_PREPARSED_DATA`).
+    pub fn preparsed_data(&self) -> &[u8] {
+        &self.preparsed_data
+    }
+
+    /// The underlying I/O target.
+    pub fn io_target(&self) -> &IoTarget {
+        &self.target
+    }
+
+    /// Checks that `len` is enough for reports of type `report_type`.
+    fn check_len(&self, report_type: ReportType, len: usize) -> Result<(), HidError> {
+        let needed = self.capabilities.report_len(report_type);
+        ensure!(
+            len >= needed,
+            hid_error::ReportLengthSnafu {
+                report_type,
+                len,
+                needed,
+            }
+        );
+        Ok(())
+    }
+
+    /// Gets a feature report from the device (see `HidD_GetFeature`). The first byte of `report`
+    /// has to be set to the report ID. Returns the number of bytes written to `report`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn get_feature(&self, report: &mut [u8]) -> Result<usize, HidError> {
+        self.check_len(ReportType::Feature, report.len())?;
+
+        self.target
+            .send_ioctl_synchronously(
+                None,
+                IOCTL_HID_GET_FEATURE,
+                None,
+                Some(report),
+                &RequestSendOptions::default(),
+            )
+            .context(hid_error::NtStatusSnafu {
+                operation: "IOCTL_HID_GET_FEATURE",
+            })
+    }
+
+    /// Sends a feature report to the device (see `HidD_SetFeature`). The first byte of `report`
+    /// is the report ID.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_feature(&self, report: &[u8]) -> Result<(), HidError> {
+        self.check_len(ReportType::Feature, report.len())?;
+
+        self.target
+            .send_ioctl_synchronously(
+                None,
+                IOCTL_HID_SET_FEATURE,
+                Some(report),
+                None,
+                &RequestSendOptions::default(),
+            )
+            .context(hid_error::NtStatusSnafu {
+                operation: "IOCTL_HID_SET_FEATURE",
+            })?;
+
+        Ok(())
+    }
+
+    /// Requests an input report from the device (see `HidD_GetInputReport`), instead of waiting
+    /// for the next one to be [read](Self::read_input_report). The first byte of `report` has to
+    /// be set to the report ID. Returns the number of bytes written to `report`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn get_input_report(&self, report: &mut [u8]) -> Result<usize, HidError> {
+        self.check_len(ReportType::Input, report.len())?;
+
+        self.target
+            .send_ioctl_synchronously(
+                None,
+                IOCTL_HID_GET_INPUT_REPORT,
+                None,
+                Some(report),
+                &RequestSendOptions::default(),
+            )
+            .context(hid_error::NtStatusSnafu {
+                operation: "IOCTL_HID_GET_INPUT_REPORT",
+            })
+    }
+
+    /// Sends an output report to the device through the control pipe (see
+    /// `HidD_SetOutputReport`). The first byte of `report` is the report ID.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_output_report(&self, report: &[u8]) -> Result<(), HidError> {
+        self.check_len(ReportType::Output, report.len())?;
+
+        self.target
+            .send_ioctl_synchronously(
+                None,
+                IOCTL_HID_SET_OUTPUT_REPORT,
+                Some(report),
+                None,
+                &RequestSendOptions::default(),
+            )
+            .context(hid_error::NtStatusSnafu {
+                operation: "IOCTL_HID_SET_OUTPUT_REPORT",
+            })?;
+
+        Ok(())
+    }
+
+    /// Reads the next input report(s) sent by the device. This waits until the device sends a
+    /// report, unless `options` has a timeout. If `report` can hold several reports, several
+    /// buffered reports may be returned at once. Returns the number of bytes read.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn read_input_report(
+        &self,
+        report: &mut [u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, HidError> {
+        self.check_len(ReportType::Input, report.len())?;
+
+        self.target
+            .send_read_synchronously(None, report, options)
+            .context(hid_error::NtStatusSnafu {
+                operation: "reading an input report",
+            })
+    }
+
+    /// Sends an output report to the device, through the interrupt pipe if it has one. The first
+    /// byte of `report` is the report ID.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn write_output_report(
+        &self,
+        report: &[u8],
+        options: &RequestSendOptions,
+    ) -> Result<(), HidError> {
+        self.check_len(ReportType::Output, report.len())?;
+
+        self.target
+            .send_write_synchronously(None, report, options)
+            .context(hid_error::NtStatusSnafu {
+                operation: "writing an output report",
+            })?;
+
+        Ok(())
+    }
+
+    /// Gets a typed input or feature report from the device, see [`Self::get_input_report`] and
+    /// [`Self::get_feature`].
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn get_report<R: Report>(&self) -> Result<R, HidError> {
+        self.check_typed::<R>()?;
+
+        with_report_buffer(|buffer| match R::TYPE {
+            ReportType::Input => self.get_input_report(buffer),
+            ReportType::Feature => self.get_feature(buffer),
+            ReportType::Output => hid_error::UnsupportedReportTypeSnafu {
+                report_type: R::TYPE,
+                operation: "read",
+            }
+            .fail(),
+        })
+    }
+
+    /// Sends a typed output or feature report to the device, see [`Self::set_output_report`] and
+    /// [`Self::set_feature`].
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_report<R: Report>(&self, report: &R) -> Result<(), HidError> {
+        self.check_typed::<R>()?;
+        let bytes = bytemuck::bytes_of(report);
+        ensure!(
+            bytes[0] == R::ID,
+            hid_error::ReportIdSnafu {
+                expected: R::ID,
+                actual: bytes[0],
+            }
+        );
+
+        match R::TYPE {
+            ReportType::Output => self.set_output_report(bytes),
+            ReportType::Feature => self.set_feature(bytes),
+            ReportType::Input => hid_error::UnsupportedReportTypeSnafu {
+                report_type: R::TYPE,
+                operation: "written",
+            }
+            .fail(),
+        }
+    }
+
+    /// Reads the next typed input report sent by the device, see [`Self::read_input_report`].
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn read_report<R: Report>(&self, options: &RequestSendOptions) -> Result<R, HidError> {
+        self.check_typed::<R>()?;
+        ensure!(
+            R::TYPE == ReportType::Input,
+            hid_error::UnsupportedReportTypeSnafu {
+                report_type: R::TYPE,
+                operation: "read",
+            }
+        );
+
+        with_report_buffer(|buffer| self.read_input_report(buffer, options))
+    }
+
+    /// Checks that the size of `R` matches the report length of its type.
+    fn check_typed<R: Report>(&self) -> Result<(), HidError> {
+        let needed = self.capabilities.report_len(R::TYPE);
+        ensure!(
+            size_of::<R>() == needed && needed > 0,
+            hid_error::ReportLengthSnafu {
+                report_type: R::TYPE,
+                len: size_of::<R>(),
+                needed,
+            }
+        );
+        Ok(())
+    }
+}
+
+/// Fills a zeroed buffer for `R`, with the report ID as its first byte, and reads it as `R`.
+fn with_report_buffer<R: Report>(
+    fill: impl FnOnce(&mut [u8]) -> Result<usize, HidError>,
+) -> Result<R, HidError> {
+    let mut report = MaybeUninit::<R>::zeroed();
+    // SAFETY: The value is zero-initialized, so all of its bytes are initialized.
+    let buffer =
+        unsafe { slice::from_raw_parts_mut(report.as_mut_ptr().cast::<u8>(), size_of::<R>()) };
+    buffer[0] = R::ID;
+
+    let len = fill(buffer)?;
+    ensure!(
+        len >= size_of::<R>(),
+        hid_error::ReportLengthSnafu {
+            report_type: R::TYPE,
+            len,
+            needed: size_of::<R>(),
+        }
+    );
+
+    bytemuck::checked::try_pod_read_unaligned(buffer)
+        .map_err(|inner| hid_error::CastSnafu { inner }.build())
+}
@@ -2,18 +2,34 @@
 //!
 //! See [`MappedIoSpace`] for the main type handling mapping, unmapping, and giving access.
 
-use crate::{private::Sealed, PhysicalAddress};
+use crate::{alloc::CacheType, private::Sealed, PhysicalAddress};
 use bitflags::bitflags;
 use core::{
     fmt::Debug,
     marker::PhantomData,
-    mem::size_of,
+    mem::{size_of, MaybeUninit},
     ptr::{read_volatile, write_volatile, NonNull},
 };
 use km_sys::{
     MmMapIoSpaceEx, MmUnmapIoSpace, PAGE_EXECUTE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE,
     PAGE_NOCACHE, PAGE_READONLY, PAGE_READWRITE, PAGE_WRITECOMBINE, SIZE_T, ULONG,
 };
+use snafu::{ensure, OptionExt, Snafu};
+
+/// Why [`MappedIoSpace::create_mapping`] failed, with the physical address it was called with.
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+#[snafu(module)]
+pub enum MapIoSpaceError {
+    /// The mapped type is zero-sized, so there's nothing to map.
+    #[snafu(display("can't map a zero-sized type at {address:#x}"))]
+    ZeroSized { address: u64 },
+    /// `MmMapIoSpaceEx` failed, e.g. because there's not enough system space left to map into.
+    #[snafu(display("mapping {size} bytes at {address:#x} failed"))]
+    MapFailed { address: u64, size: usize },
+    /// The mapping isn't aligned enough for the mapped type.
+    #[snafu(display("mapping of {address:#x} isn't aligned to {align} bytes"))]
+    Misaligned { address: u64, align: usize },
+}
 
 /// Helper struct to give volatile access to a [mapped I/O space](MappedIoSpace).
 ///
@@ -41,6 +57,19 @@ impl<T, A> Debug for VolatileAccess<'_, T, A> {
 }
 
 impl<'a, T, A> VolatileAccess<'a, T, A> {
+    /// Creates volatile access to `ptr`, e.g. for a [section view](crate::section::SectionView).
+    ///
+    /// # Safety
+    /// The pointer must follow the same rules as the ones of [`MappedIoSpace::create_mapping`],
+    /// and stay valid for `'a`.
+    pub(crate) unsafe fn new(ptr: NonNull<T>) -> Self {
+        VolatileAccess {
+            ptr,
+            _access: PhantomData,
+            _tied_to: PhantomData,
+        }
+    }
+
     /// Returns the raw pointer to the mapped region.
     ///
     /// Note that this is *not* bound to the lifetime of this `VolatileAccess` value, so extreme
@@ -79,6 +108,85 @@ impl<T: Copy, A: ReadAccess> VolatileAccess<'_, T, A> {
         // (`MappedIoSpace::create_mapping` in particular)
         unsafe { read_volatile(self.ptr.as_ptr()) }
     }
+
+    /// Copies the whole `T` into a normal value with volatile reads of 32 bits each, or of 16 or 8
+    /// bits if `T` isn't aligned to and sized in 32-bit words, e.g. to poll a block of registers.
+    ///
+    /// Unlike [`Self::read`], which leaves the width of the reads to the compiler, this only reads
+    /// in widths that registers usually support. The registers are read one after another, so the
+    /// snapshot isn't atomic, and the device may change registers while they're read.
+    pub fn snapshot(&self) -> T {
+        let mut value = MaybeUninit::<T>::uninit();
+        // SAFETY:
+        // - `VolatileAccess` inherits all necessary guarantees from `MappedIoSpace`
+        //   (`MappedIoSpace::create_mapping` in particular), so the whole `T` can be read.
+        // - `value` is valid for writing a `T`.
+        unsafe {
+            volatile_copy(
+                self.ptr.as_ptr().cast(),
+                value.as_mut_ptr().cast(),
+                size_of::<T>(),
+            );
+        }
+        // SAFETY: All bytes were copied from the mapping, which `create_mapping` requires to hold a
+        // valid `T` for all byte combinations.
+        unsafe { value.assume_init() }
+    }
+
+    /// Copies `buf.len()` bytes starting `offset` bytes into the `T` into `buf`, with volatile
+    /// reads like [`Self::snapshot`], e.g. for a range of registers of a big block.
+    ///
+    /// Panics if the range isn't within the `T`.
+    pub fn snapshot_bytes(&self, offset: usize, buf: &mut [u8]) {
+        let end = offset
+            .checked_add(buf.len())
+            .expect("snapshot range overflows");
+        assert!(end <= size_of::<T>(), "snapshot range is out of bounds");
+
+        // SAFETY:
+        // - The range was checked to be within the `T`, which can be read, see `Self::snapshot`.
+        // - `buf` is valid for writing its length.
+        unsafe {
+            volatile_copy(
+                self.ptr.as_ptr().cast::<u8>().add(offset),
+                buf.as_mut_ptr(),
+                buf.len(),
+            );
+        }
+    }
+}
+
+/// Copies `len` bytes from `src` to `dst` with volatile reads of the widest of 32, 16 or 8 bits
+/// that `src` and `len` are aligned to.
+///
+/// # Safety
+/// `src` must be valid for volatile reads of `len` bytes, and `dst` for writes of `len` bytes.
+unsafe fn volatile_copy(src: *const u8, dst: *mut u8, len: usize) {
+    let alignment = (src as usize | len) & 0b11;
+    let width = if alignment == 0 {
+        4
+    } else if alignment & 0b1 == 0 {
+        2
+    } else {
+        1
+    };
+
+    for offset in (0..len).step_by(width) {
+        // SAFETY: `offset` is within `len`, and `src + offset` is aligned to `width`, as both
+        // `src` and `len` are. `dst` isn't necessarily aligned, so it's written unaligned.
+        unsafe {
+            let (src, dst) = (src.add(offset), dst.add(offset));
+            match width {
+                4 => dst
+                    .cast::<u32>()
+                    .write_unaligned(read_volatile(src.cast::<u32>())),
+                2 => dst
+                    .cast::<u16>()
+                    .write_unaligned(read_volatile(src.cast::<u16>())),
+                _ => dst.write(read_volatile(src)),
+            }
+        }
+    }
 }
 
 impl<T: Copy, A: WriteAccess> VolatileAccess<'_, T, A> {
@@ -138,18 +246,19 @@ impl<T: Copy, A: Access> MappedIoSpace<T, A> {
     /// Maps space for the given `T` at the specified physical address to non-paged system space
     /// using the specified page protection.
     ///
-    /// Returns `None` whenever no proper mapping could be established, in one of the following
-    /// cases:
-    ///
-    /// - the space for mapping is insufficient (see MSDN docs in Remarks below)
-    /// - the pointer returned wouldn't be aligned enough for `T`
-    /// - `T` is zero-sized
+    /// Fails with a [`MapIoSpaceError`] if no proper mapping could be established, i.e. if `T` is
+    /// zero-sized, the space for mapping is insufficient (see MSDN docs in Remarks below), or the
+    /// pointer returned wouldn't be aligned enough for `T`. It converts into an [`Error`] with `?`.
     ///
     /// # Remarks
     ///
     /// The valid access types here are [`ReadOnly`], [`ReadWrite`], [`Execute`], [`ExecuteRead`]
     /// and [`ExecuteReadWrite`].
     ///
+    /// The modifiers can also be created from a [`CacheType`], like the one of a
+    /// [`ContiguousBuffer`](crate::alloc::ContiguousBuffer) whose physical address is mapped:
+    /// `CacheType::WriteCombined.into()`.
+    ///
     /// See [`MmMapIoSpaceEx` on
     /// MSDN](https://docs.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-mmmapiospaceex)
     /// for more information about the underlying kernel API call.
@@ -168,12 +277,12 @@ impl<T: Copy, A: Access> MappedIoSpace<T, A> {
     pub unsafe fn create_mapping(
         physical_address: PhysicalAddress,
         protection_modifiers: PageProtectionModifiers,
-    ) -> Option<Self> {
+    ) -> Result<Self, MapIoSpaceError> {
         let size = size_of::<T>();
+        // SAFETY: `QuadPart` is always valid.
+        let address = unsafe { physical_address.QuadPart } as u64;
 
-        if size == 0 {
-            return None;
-        }
+        ensure!(size != 0, map_io_space_error::ZeroSizedSnafu { address });
 
         let page_protection = PageProtection {
             access: A::PROTECTION,
@@ -181,25 +290,26 @@ impl<T: Copy, A: Access> MappedIoSpace<T, A> {
         };
 
         // SAFETY: The caller provides all guarantees needed here.
-        NonNull::new(unsafe {
+        let ptr = NonNull::new(unsafe {
             MmMapIoSpaceEx(physical_address, size as SIZE_T, page_protection.as_raw())
         })
-        .and_then(|ptr| {
-            // since `MmMapIoSpaceEx` always works on page boundaries, I don't think that this
-            // pointer could ever be not aligned enough, but better safe than sorry
-            if ptr.as_ptr().align_offset(core::mem::align_of::<T>()) == 0 {
-                Some(MappedIoSpace {
-                    ptr: ptr.cast(),
-                    _access: PhantomData,
-                })
-            } else {
-                // SAFETY: `ptr` comes straight from `MmMapIoSpaceEx`, and we're using the same size
-                // as with that call.
-                unsafe {
-                    MmUnmapIoSpace(ptr.as_ptr(), size as SIZE_T);
-                }
-                None
+        .context(map_io_space_error::MapFailedSnafu { address, size })?;
+
+        // since `MmMapIoSpaceEx` always works on page boundaries, I don't think that this
+        // pointer could ever be not aligned enough, but better safe than sorry
+        let align = core::mem::align_of::<T>();
+        if ptr.as_ptr().align_offset(align) != 0 {
+            // SAFETY: `ptr` comes straight from `MmMapIoSpaceEx`, and we're using the same size
+            // as with that call.
+            unsafe {
+                MmUnmapIoSpace(ptr.as_ptr(), size as SIZE_T);
             }
+            return map_io_space_error::MisalignedSnafu { address, align }.fail();
+        }
+
+        Ok(MappedIoSpace {
+            ptr: ptr.cast(),
+            _access: PhantomData,
         })
     }
 
@@ -213,6 +323,13 @@ impl<T: Copy, A: Access> MappedIoSpace<T, A> {
     }
 }
 
+impl<T: Copy, A: ReadAccess> MappedIoSpace<T, A> {
+    /// Copies the whole mapped `T` into a normal value, see [`VolatileAccess::snapshot`].
+    pub fn snapshot(&self) -> T {
+        self.access().snapshot()
+    }
+}
+
 impl<T, A> Drop for MappedIoSpace<T, A> {
     fn drop(&mut self) {
         // SAFETY:
@@ -258,6 +375,16 @@ bitflags! {
     }
 }
 
+impl From<CacheType> for PageProtectionModifiers {
+    fn from(cache_type: CacheType) -> Self {
+        match cache_type {
+            CacheType::NonCached => Self::PAGE_NOCACHE,
+            CacheType::Cached => Self::empty(),
+            CacheType::WriteCombined => Self::PAGE_WRITECOMBINE,
+        }
+    }
+}
+
 #[doc(hidden)]
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -6,7 +6,108 @@ use km_sys::{
 };
 use log::Log;
 
-pub struct KernelLogger;
+/// A logger printing to the kernel debugger with `DbgPrintEx`.
+///
+/// Each message is printed with a component ID and a level, which the kernel uses to filter the
+/// output: a message is only printed if its level is enabled in the component's mask, which is set
+/// with the `Kd_<component>_Mask` value in
+/// `HKLM\SYSTEM\CurrentControlSet\Control\Session Manager\Debug Print Filter`, or with
+/// `ed nt!Kd_<component>_Mask` in the debugger. Levels up to 31 are bit numbers in the mask, larger
+/// values are masks themselves.
+///
+/// By default, all messages are printed for [`DPFLTR_IHVDRIVER_ID`](_DPFLTR_TYPE), with the levels
+/// of [`Self::default_level`]. Both can be changed, also for the messages of specific modules:
+///
+/// ```rs, ignore
+/// static LOGGER: KernelLogger = KernelLogger::new()
+///     .with_level_map(|level| match level {
+///         log::Level::Error => DPFLTR_ERROR_LEVEL,
+///         // only printed with bit 4 set in `Kd_IHVDRIVER_Mask`
+///         _ => 4,
+///     })
+///     .with_overrides(&[TargetOverride {
+///         target: "my_driver::fans",
+///         component: _DPFLTR_TYPE::DPFLTR_IHVVIDEO_ID,
+///         level_map: None,
+///     }]);
+/// ```
+pub struct KernelLogger {
+    component: DPFLTR_TYPE,
+    level_map: fn(log::Level) -> ULONG,
+    overrides: &'static [TargetOverride],
+}
+
+/// Prints the messages of a module with a different component ID or levels, see
+/// [`KernelLogger::with_overrides`].
+pub struct TargetOverride {
+    /// The target of the messages, which is the module path for the `log` and `km_*!` macros.
+    /// Also applies to the submodules of the module.
+    pub target: &'static str,
+    pub component: DPFLTR_TYPE,
+    /// The level mapping for the messages, or `None` to use the one of the logger.
+    pub level_map: Option<fn(log::Level) -> ULONG>,
+}
+
+impl KernelLogger {
+    /// A logger printing all messages for `DPFLTR_IHVDRIVER_ID`, with the levels of
+    /// [`Self::default_level`].
+    pub const fn new() -> Self {
+        Self {
+            component: _DPFLTR_TYPE::DPFLTR_IHVDRIVER_ID,
+            level_map: Self::default_level,
+            overrides: &[],
+        }
+    }
+
+    /// Prints messages for the given component ID.
+    #[must_use]
+    pub const fn with_component(mut self, component: DPFLTR_TYPE) -> Self {
+        self.component = component;
+        self
+    }
+
+    /// Prints messages with the levels returned by `level_map`.
+    #[must_use]
+    pub const fn with_level_map(mut self, level_map: fn(log::Level) -> ULONG) -> Self {
+        self.level_map = level_map;
+        self
+    }
+
+    /// Prints the messages of some modules differently. The first override whose target matches
+    /// is used.
+    #[must_use]
+    pub const fn with_overrides(mut self, overrides: &'static [TargetOverride]) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Maps the levels to the `DPFLTR_*_LEVEL` constants, which are enabled by default for
+    /// errors only. Debug messages are printed at trace level, as there's no matching constant.
+    pub fn default_level(level: log::Level) -> ULONG {
+        match level {
+            log::Level::Error => DPFLTR_ERROR_LEVEL,
+            log::Level::Warn => DPFLTR_WARNING_LEVEL,
+            log::Level::Info => DPFLTR_INFO_LEVEL,
+            log::Level::Trace => DPFLTR_TRACE_LEVEL,
+            // debug is not inherently supported by `DPFLTR` constants, fall back to trace level
+            log::Level::Debug => DPFLTR_TRACE_LEVEL,
+        }
+    }
+
+    fn find_override(&self, target: &str) -> Option<&TargetOverride> {
+        self.overrides.iter().find(|o| {
+            target
+                .strip_prefix(o.target)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+}
+
+impl Default for KernelLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Log for KernelLogger {
     fn enabled(&self, _: &log::Metadata<'_>) -> bool {
@@ -14,16 +115,14 @@ impl Log for KernelLogger {
     }
 
     fn log(&self, record: &log::Record<'_>) {
+        let (component, level_map) = match self.find_override(record.target()) {
+            Some(o) => (o.component, o.level_map.unwrap_or(self.level_map)),
+            None => (self.component, self.level_map),
+        };
+
         let mut dbgprint_writer = DbgPrintWriter {
-            component: _DPFLTR_TYPE::DPFLTR_IHVDRIVER_ID,
-            level: match record.level() {
-                log::Level::Error => DPFLTR_ERROR_LEVEL,
-                log::Level::Warn => DPFLTR_WARNING_LEVEL,
-                log::Level::Info => DPFLTR_INFO_LEVEL,
-                log::Level::Trace => DPFLTR_TRACE_LEVEL,
-                // debug is not inherently supported by `DPFLTR` constants, fall back to trace level
-                log::Level::Debug => DPFLTR_TRACE_LEVEL,
-            },
+            component,
+            level: level_map(record.level()),
         };
 
         let _ = writeln!(dbgprint_writer, "{}", *record.args());
@@ -7,17 +7,52 @@
 // False positives on compile-time checks: https://github.com/rust-lang/rust-clippy/issues/8159
 #![allow(clippy::assertions_on_constants)]
 
+pub mod alloc;
 pub mod assert;
+pub mod bugcheck;
+pub mod build_info;
+pub mod collections;
+pub mod cpu;
+pub mod crash;
+pub mod diagnostics;
+pub mod dynimport;
+pub mod ec;
+pub mod error;
+pub mod fpu;
+pub mod global;
+pub mod hid;
 pub mod io_mmap;
 pub mod kdprint;
+pub mod logging;
+pub mod mdl;
 pub mod mode;
+pub mod object;
 pub mod object_attributes;
+pub mod osversion;
 pub mod panic;
+pub mod poll;
 pub mod port;
+pub mod power;
 pub mod privileges;
+pub mod psnotify;
+pub mod regfilter;
+pub mod section;
+pub mod serial;
+pub mod smbus;
+pub mod stats;
+pub mod strings;
+pub mod sync;
+pub mod sysinfo;
 pub mod time;
+pub mod transfer;
+pub mod unload;
+pub mod verify;
+pub mod wait;
+pub mod watchdog;
 pub mod wdf;
+pub mod wmi;
 
+pub use error::Error;
 pub use km_shared as shared;
 pub use km_sys;
 pub use km_sys::PHYSICAL_ADDRESS as PhysicalAddress;
@@ -0,0 +1,103 @@
+//! Logging macros that record where a message was logged, like WPP tracing does.
+//!
+//! [`km_error!`](crate::km_error), [`km_warn!`](crate::km_warn), [`km_info!`](crate::km_info),
+//! [`km_debug!`](crate::km_debug) and [`km_trace!`](crate::km_trace) take the same arguments as
+//! the macros of the `log` crate, and prefix the message with the module path, file, and line:
+//!
+//! ```rs, ignore
+//! km_warn!("fan {index} stalled");
+//! // [my_driver::fans] src/fans.rs:42: fan 3 stalled
+//! ```
+//!
+//! In release builds (without `debug_assertions`), messages more verbose than
+//! [`RELEASE_MAX_LEVEL`] are compiled out entirely, including the formatting of their arguments.
+//! It's [`Level::Info`] by default, and can be lowered with the `log-release-max-level-warn`,
+//! `log-release-max-level-error`, and `log-release-max-level-off` features. Unlike the
+//! `release_max_level_*` features of the `log` crate, this only affects these macros, and not the
+//! logging of other crates.
+//!
+//! The messages are logged with the `log` crate, e.g. to a
+//! [`KernelLogger`](crate::kdprint::KernelLogger).
+
+#[doc(hidden)]
+pub use log;
+pub use log::{Level, LevelFilter};
+
+/// The most verbose level that is logged by the macros in release builds, see the
+/// [module documentation](self).
+pub const RELEASE_MAX_LEVEL: LevelFilter = if cfg!(feature = "log-release-max-level-off") {
+    LevelFilter::Off
+} else if cfg!(feature = "log-release-max-level-error") {
+    LevelFilter::Error
+} else if cfg!(feature = "log-release-max-level-warn") {
+    LevelFilter::Warn
+} else {
+    LevelFilter::Info
+};
+
+/// Whether messages at `level` are compiled in, given whether `debug_assertions` are enabled in
+/// the crate using the macros.
+#[doc(hidden)]
+#[inline(always)]
+pub const fn __enabled(level: Level, debug_assertions: bool) -> bool {
+    debug_assertions || level as usize <= RELEASE_MAX_LEVEL as usize
+}
+
+/// Logs a message at the given [`Level`], prefixed with the module path, file, and line. See the
+/// [module documentation](crate::logging).
+#[macro_export]
+macro_rules! km_log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level: $crate::logging::Level = $level;
+        if $crate::logging::__enabled(level, cfg!(debug_assertions)) {
+            $crate::logging::log::log!(
+                level,
+                "[{}] {}:{}: {}",
+                ::core::module_path!(),
+                ::core::file!(),
+                ::core::line!(),
+                ::core::format_args!($($arg)+)
+            );
+        }
+    }};
+}
+
+/// Logs an error, see [`km_log!`](crate::km_log).
+#[macro_export]
+macro_rules! km_error {
+    ($($arg:tt)+) => {
+        $crate::km_log!($crate::logging::Level::Error, $($arg)+)
+    };
+}
+
+/// Logs a warning, see [`km_log!`](crate::km_log).
+#[macro_export]
+macro_rules! km_warn {
+    ($($arg:tt)+) => {
+        $crate::km_log!($crate::logging::Level::Warn, $($arg)+)
+    };
+}
+
+/// Logs an informational message, see [`km_log!`](crate::km_log).
+#[macro_export]
+macro_rules! km_info {
+    ($($arg:tt)+) => {
+        $crate::km_log!($crate::logging::Level::Info, $($arg)+)
+    };
+}
+
+/// Logs a debug message, see [`km_log!`](crate::km_log).
+#[macro_export]
+macro_rules! km_debug {
+    ($($arg:tt)+) => {
+        $crate::km_log!($crate::logging::Level::Debug, $($arg)+)
+    };
+}
+
+/// Logs a trace message, see [`km_log!`](crate::km_log).
+#[macro_export]
+macro_rules! km_trace {
+    ($($arg:tt)+) => {
+        $crate::km_log!($crate::logging::Level::Trace, $($arg)+)
+    };
+}
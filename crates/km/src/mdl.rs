@@ -0,0 +1,231 @@
+//! Memory descriptor lists (MDLs), which describe the locked physical pages of a buffer, e.g. the
+//! buffer of a direct I/O request.
+//!
+//! Mapping an [`Mdl`] into system space gives the driver access to the buffer without copying it,
+//! which matters for large buffers like firmware images:
+//!
+//! ```rs, ignore
+//! let mut mdl = request.retrieve_output_mdl()?;
+//! let mapping = mdl.map(&MdlMappingConfig::default())?;
+//! // SAFETY: The requestor doesn't access the buffer while the request is pending.
+//! let image = unsafe { mapping.as_slice() };
+//! ```
+
+use crate::{alloc::CacheType, mode::ProcessorMode, verify};
+use core::{fmt, marker::PhantomData, ptr::NonNull, slice};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{
+    MdlMappingNoExecute, MdlMappingNoWrite, MmMapLockedPagesSpecifyCache, MmUnmapLockedPages, _MDL,
+    CSHORT, MDL_MAPPED_TO_SYSTEM_VA, MDL_SOURCE_IS_NONPAGED_POOL, MM_PAGE_PRIORITY, PMDL, ULONG,
+};
+
+/// How likely [mapping](Mdl::map) an MDL is to succeed when system resources are low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PagePriority {
+    /// Fails first, for mappings that can be retried later.
+    Low,
+    Normal,
+    /// Only fails when no system resources are left at all, for mappings that are critical.
+    High,
+}
+
+impl PagePriority {
+    fn as_raw(self) -> MM_PAGE_PRIORITY {
+        match self {
+            Self::Low => MM_PAGE_PRIORITY::LowPagePriority,
+            Self::Normal => MM_PAGE_PRIORITY::NormalPagePriority,
+            Self::High => MM_PAGE_PRIORITY::HighPagePriority,
+        }
+    }
+}
+
+/// How an [`Mdl`] is [mapped](Mdl::map).
+#[must_use]
+pub struct MdlMappingConfig {
+    /// Has to match how the pages are mapped elsewhere. Buffers from user mode are cached.
+    pub cache_type: CacheType,
+    pub priority: PagePriority,
+    /// Whether the mapping can't be written, e.g. for the input buffer of a request.
+    pub read_only: bool,
+}
+
+impl Default for MdlMappingConfig {
+    /// A cached, writable mapping with normal priority.
+    fn default() -> Self {
+        Self {
+            cache_type: CacheType::Cached,
+            priority: PagePriority::Normal,
+            read_only: false,
+        }
+    }
+}
+
+/// A borrowed MDL, describing locked pages that stay locked for `'a`.
+///
+/// Only the first MDL of a chain is described, which is all there is for the buffers of requests.
+pub struct Mdl<'a> {
+    mdl: NonNull<_MDL>,
+    _pages: PhantomData<&'a _MDL>,
+}
+
+// SAFETY: The MDL describes locked pages, which can be mapped from any thread.
+unsafe impl Send for Mdl<'_> {}
+// SAFETY: The MDL is only modified while mapping it, which requires a `&mut Mdl`.
+unsafe impl Sync for Mdl<'_> {}
+
+impl fmt::Debug for Mdl<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mdl")
+            .field("mdl", &self.mdl)
+            .field("byte_count", &self.byte_count())
+            .finish()
+    }
+}
+
+impl<'a> Mdl<'a> {
+    /// Wraps a raw MDL, or returns `None` if it's null.
+    ///
+    /// # Safety
+    /// The MDL must describe locked pages, and stay valid with the pages locked for `'a`. While
+    /// this exists, the MDL mustn't be mapped into system space elsewhere, except through
+    /// [`Self::map`].
+    pub unsafe fn from_raw(mdl: PMDL) -> Option<Self> {
+        NonNull::new(mdl).map(|mdl| Self {
+            mdl,
+            _pages: PhantomData,
+        })
+    }
+
+    pub fn as_raw(&self) -> PMDL {
+        self.mdl.as_ptr()
+    }
+
+    /// The size of the described buffer in bytes, like the `MmGetMdlByteCount` macro.
+    pub fn byte_count(&self) -> usize {
+        // SAFETY: The MDL is valid for `'a`.
+        unsafe { (*self.mdl.as_ptr()).ByteCount as usize }
+    }
+
+    /// Maps the described buffer into system space, where it can be accessed from any process
+    /// context, until the mapping is dropped. Fails with `STATUS_INVALID_DEVICE_STATE` if the MDL
+    /// is mapped into system space already, e.g. because the buffer was retrieved with
+    /// [`Request::retrieve_output_buffer`](crate::wdf::request::Request::retrieve_output_buffer),
+    /// and with `STATUS_INSUFFICIENT_RESOURCES` if no system space is available.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-mmmaplockedpagesspecifycache
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn map(&mut self, config: &MdlMappingConfig) -> Result<MappedMdl<'_>, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The MDL is valid for `'a`.
+        let flags = unsafe { (*self.mdl.as_ptr()).MdlFlags };
+        if flags & (MDL_MAPPED_TO_SYSTEM_VA | MDL_SOURCE_IS_NONPAGED_POOL) as CSHORT != 0 {
+            return Err(NtStatusError::STATUS_INVALID_DEVICE_STATE);
+        }
+
+        let mut priority = config.priority.as_raw().0 as ULONG | MdlMappingNoExecute;
+        if config.read_only {
+            priority |= MdlMappingNoWrite;
+        }
+
+        // SAFETY: The MDL describes locked pages, and isn't mapped into system space yet. Kernel
+        // mode mappings return null on failure instead of raising an exception.
+        let ptr = unsafe {
+            MmMapLockedPagesSpecifyCache(
+                self.mdl.as_ptr(),
+                ProcessorMode::KernelMode.into(),
+                config.cache_type.as_raw(),
+                core::ptr::null_mut(),
+                false.into(),
+                priority,
+            )
+        };
+
+        Ok(MappedMdl {
+            ptr: NonNull::new(ptr.cast()).ok_or(NtStatusError::STATUS_INSUFFICIENT_RESOURCES)?,
+            len: self.byte_count(),
+            read_only: config.read_only,
+            mdl: self.mdl,
+            _mdl: PhantomData,
+        })
+    }
+}
+
+/// An [`Mdl`] mapped into system space, unmapped on drop. See [`Mdl::map`].
+///
+/// Must be dropped at `IRQL <= DISPATCH_LEVEL`.
+pub struct MappedMdl<'a> {
+    ptr: NonNull<u8>,
+    len: usize,
+    read_only: bool,
+    mdl: NonNull<_MDL>,
+    _mdl: PhantomData<&'a mut ()>,
+}
+
+// SAFETY: The mapping is in system space, which is valid in any process context.
+unsafe impl Send for MappedMdl<'_> {}
+// SAFETY: Shared references only give out the pointer, and shared slices.
+unsafe impl Sync for MappedMdl<'_> {}
+
+impl fmt::Debug for MappedMdl<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedMdl")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .field("read_only", &self.read_only)
+            .finish()
+    }
+}
+
+impl MappedMdl<'_> {
+    /// The system address of the buffer. It stays valid until the mapping is dropped.
+    pub fn as_ptr(&self) -> NonNull<u8> {
+        self.ptr
+    }
+
+    /// The size of the buffer in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The contents of the buffer.
+    ///
+    /// # Safety
+    /// Nothing else (e.g. the requestor, or a device) may write the buffer while the slice is
+    /// borrowed.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        // SAFETY: The mapping is valid for `len` bytes until it's dropped, and the caller
+        // guarantees that nothing else writes it.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// The contents of the buffer, or `None` if it was mapped read-only.
+    ///
+    /// # Safety
+    /// Nothing else (e.g. the requestor, or a device) may access the buffer while the slice is
+    /// borrowed.
+    pub unsafe fn as_mut_slice(&mut self) -> Option<&mut [u8]> {
+        // SAFETY: The mapping is valid and writable for `len` bytes until it's dropped, borrowed
+        // mutably, and the caller guarantees that nothing else accesses it.
+        (!self.read_only).then(|| unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) })
+    }
+}
+
+impl Drop for MappedMdl<'_> {
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn drop(&mut self) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The pages were mapped by `MmMapLockedPagesSpecifyCache` for the MDL, and are
+        // only unmapped once by virtue of being a `Drop` implementation.
+        unsafe { MmUnmapLockedPages(self.ptr.as_ptr().cast(), self.mdl.as_ptr()) }
+    }
+}
@@ -0,0 +1,305 @@
+//! Counted references to kernel objects, i.e. [`Process`]es, [`Thread`]s and [`FileObject`]s,
+//! e.g. to keep a process alive after a notification about it returned.
+//!
+//! An [`ObjectReference`] holds a reference to an object of its type, which keeps the object from
+//! being deleted until it's dropped. References are taken from handles, from IDs, or from objects
+//! passed to callbacks, e.g. by [`ProcessCreated::process`]:
+//!
+//! ```rs, ignore
+//! // in `EvtIoInCallerContext`
+//! let process = ObjectReference::<Process>::from_handle(
+//!     handle,
+//!     0x1000, // PROCESS_QUERY_LIMITED_INFORMATION
+//!     request.requestor_mode(),
+//! )?;
+//!
+//! // later, e.g. in a work item
+//! let pid = process.process_id();
+//! ```
+//!
+//! An object being referenced doesn't mean it's still in use, e.g. a referenced process may have
+//! exited already.
+//!
+//! [`ProcessCreated::process`]: crate::psnotify::ProcessCreated::process
+
+use crate::{
+    mode::ProcessorMode,
+    psnotify::{ProcessId, ThreadId},
+    shared::ntstatus::{NtStatus, NtStatusError},
+    verify, Sealed,
+};
+use core::{
+    fmt,
+    marker::PhantomData,
+    ptr::{null_mut, NonNull},
+};
+use km_sys::{
+    IoFileObjectType, IoGetCurrentProcess, KeGetCurrentThread, ObReferenceObjectByHandle,
+    ObfDereferenceObject, ObfReferenceObject, PsGetProcessId, PsGetThreadId, PsGetThreadProcessId,
+    PsLookupProcessByProcessId, PsLookupThreadByThreadId, PsProcessType, PsThreadType,
+    _FILE_OBJECT, _KPROCESS, _KTHREAD, ACCESS_MASK, APC_LEVEL, HANDLE, KIRQL, POBJECT_TYPE,
+};
+
+/// The type of a kernel object an [`ObjectReference`] can refer to, i.e. [`Process`],
+/// [`Thread`] or [`FileObject`].
+pub trait ObjectType: Sealed {
+    /// The raw object, e.g. `EPROCESS`.
+    type Raw;
+
+    /// The object type checked when referencing a handle, e.g. `PsProcessType`.
+    fn object_type() -> POBJECT_TYPE;
+}
+
+/// A process object (`EPROCESS`).
+#[derive(Debug)]
+pub struct Process;
+impl Sealed for Process {}
+
+impl ObjectType for Process {
+    type Raw = _KPROCESS;
+
+    fn object_type() -> POBJECT_TYPE {
+        // SAFETY: The object type is initialized before any driver loads, and never changes.
+        unsafe { *PsProcessType }
+    }
+}
+
+/// A thread object (`ETHREAD`).
+#[derive(Debug)]
+pub struct Thread;
+impl Sealed for Thread {}
+
+impl ObjectType for Thread {
+    type Raw = _KTHREAD;
+
+    fn object_type() -> POBJECT_TYPE {
+        // SAFETY: The object type is initialized before any driver loads, and never changes.
+        unsafe { *PsThreadType }
+    }
+}
+
+/// A file object (`FILE_OBJECT`), i.e. an open instance of a file or device.
+#[derive(Debug)]
+pub struct FileObject;
+impl Sealed for FileObject {}
+
+impl ObjectType for FileObject {
+    type Raw = _FILE_OBJECT;
+
+    fn object_type() -> POBJECT_TYPE {
+        // SAFETY: The object type is initialized before any driver loads, and never changes.
+        unsafe { *IoFileObjectType }
+    }
+}
+
+/// A counted reference to a kernel object of type `T`, which is dereferenced on drop. See the
+/// [module documentation](self).
+///
+/// Cloning takes another reference to the same object.
+pub struct ObjectReference<T: ObjectType> {
+    object: NonNull<T::Raw>,
+    _type: PhantomData<T>,
+}
+
+// SAFETY: Referenced objects can be used and dereferenced from any thread.
+unsafe impl<T: ObjectType> Send for ObjectReference<T> {}
+// SAFETY: Shared references only query the object, which the kernel synchronizes.
+unsafe impl<T: ObjectType> Sync for ObjectReference<T> {}
+
+impl<T: ObjectType> ObjectReference<T> {
+    /// References the object of `handle`, checking that it's of type `T`, and for
+    /// [`ProcessorMode::UserMode`], that the handle grants `desired_access`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, in the context of the process the handle belongs to.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-obreferenceobjectbyhandle
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn from_handle(
+        handle: usize,
+        desired_access: ACCESS_MASK,
+        requestor_mode: ProcessorMode,
+    ) -> Result<Self, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut object = null_mut();
+
+        // SAFETY: The handle is validated by the object manager, and checked to be of type `T`
+        // granting the access for the requestor.
+        NtStatus::from(unsafe {
+            ObReferenceObjectByHandle(
+                handle as HANDLE,
+                desired_access,
+                T::object_type(),
+                requestor_mode.into(),
+                &mut object,
+                null_mut(),
+            )
+        })
+        .result_for("ObReferenceObjectByHandle")?;
+
+        NonNull::new(object.cast())
+            .map(|object| Self {
+                object,
+                _type: PhantomData,
+            })
+            .ok_or(NtStatusError::STATUS_UNSUCCESSFUL)
+    }
+
+    /// Takes another reference to `object`.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// # Safety
+    /// `object` must be an object of type `T` which is valid for the call, e.g. one passed to a
+    /// callback.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn from_raw(object: NonNull<T::Raw>) -> Self {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The caller guarantees that the object is valid.
+        unsafe { ObfReferenceObject(object.as_ptr().cast()) };
+
+        Self {
+            object,
+            _type: PhantomData,
+        }
+    }
+
+    /// Takes over a reference from a function that referenced the object, e.g.
+    /// `PsLookupProcessByProcessId`.
+    ///
+    /// # Safety
+    /// `object` must be a referenced object of type `T`, whose reference is owned by the caller.
+    unsafe fn from_referenced(object: NonNull<T::Raw>) -> Self {
+        Self {
+            object,
+            _type: PhantomData,
+        }
+    }
+
+    /// The raw object, which is valid while this reference is held.
+    pub fn as_raw(&self) -> *mut T::Raw {
+        self.object.as_ptr()
+    }
+}
+
+impl ObjectReference<Process> {
+    /// References the process with ID `process_id`, or fails with `STATUS_INVALID_PARAMETER` if
+    /// there's no such process.
+    ///
+    /// Must be called at `IRQL <= APC_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/nf-ntifs-pslookupprocessbyprocessid
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn from_process_id(process_id: ProcessId) -> Result<Self, NtStatusError> {
+        verify::irql_at_most(APC_LEVEL as KIRQL);
+
+        let mut process = null_mut();
+
+        // SAFETY: `process` is an out parameter.
+        NtStatus::from(unsafe { PsLookupProcessByProcessId(process_id.0 as HANDLE, &mut process) })
+            .result_for("PsLookupProcessByProcessId")?;
+
+        let process = NonNull::new(process).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?;
+        // SAFETY: The process was referenced by the lookup.
+        Ok(unsafe { Self::from_referenced(process) })
+    }
+
+    /// References the process the caller runs in.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn current() -> Self {
+        // SAFETY: The current process is valid while the caller runs in it.
+        let process = unsafe { IoGetCurrentProcess() };
+        // SAFETY: See above. There's always a current process.
+        unsafe { Self::from_raw(NonNull::new_unchecked(process)) }
+    }
+
+    /// Can be called at any IRQL.
+    pub fn process_id(&self) -> ProcessId {
+        // SAFETY: The process is valid while referenced.
+        ProcessId(unsafe { PsGetProcessId(self.as_raw()) } as usize)
+    }
+}
+
+impl ObjectReference<Thread> {
+    /// References the thread with ID `thread_id`, or fails with `STATUS_INVALID_PARAMETER` if
+    /// there's no such thread.
+    ///
+    /// Must be called at `IRQL <= APC_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/nf-ntifs-pslookupthreadbythreadid
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn from_thread_id(thread_id: ThreadId) -> Result<Self, NtStatusError> {
+        verify::irql_at_most(APC_LEVEL as KIRQL);
+
+        let mut thread = null_mut();
+
+        // SAFETY: `thread` is an out parameter.
+        NtStatus::from(unsafe { PsLookupThreadByThreadId(thread_id.0 as HANDLE, &mut thread) })
+            .result_for("PsLookupThreadByThreadId")?;
+
+        let thread = NonNull::new(thread).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?;
+        // SAFETY: The thread was referenced by the lookup.
+        Ok(unsafe { Self::from_referenced(thread) })
+    }
+
+    /// References the thread of the caller.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn current() -> Self {
+        // SAFETY: The current thread is valid while it runs.
+        let thread = unsafe { KeGetCurrentThread() };
+        // SAFETY: See above. There's always a current thread.
+        unsafe { Self::from_raw(NonNull::new_unchecked(thread)) }
+    }
+
+    /// Can be called at any IRQL.
+    pub fn thread_id(&self) -> ThreadId {
+        // SAFETY: The thread is valid while referenced.
+        ThreadId(unsafe { PsGetThreadId(self.as_raw()) } as usize)
+    }
+
+    /// The ID of the process the thread belongs to.
+    ///
+    /// Can be called at any IRQL.
+    pub fn process_id(&self) -> ProcessId {
+        // SAFETY: The thread is valid while referenced.
+        ProcessId(unsafe { PsGetThreadProcessId(self.as_raw()) } as usize)
+    }
+}
+
+impl<T: ObjectType> Clone for ObjectReference<T> {
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    fn clone(&self) -> Self {
+        // SAFETY: The object is valid while referenced.
+        unsafe { Self::from_raw(self.object) }
+    }
+}
+
+impl<T: ObjectType> fmt::Debug for ObjectReference<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ObjectReference")
+            .field(&self.object)
+            .finish()
+    }
+}
+
+impl<T: ObjectType> Drop for ObjectReference<T> {
+    /// Dereferences the object, which is deleted if this was its last reference.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The object was referenced when this was created, and is only dereferenced once
+        // by virtue of being a `Drop` implementation.
+        unsafe { ObfDereferenceObject(self.object.as_ptr().cast()) };
+    }
+}
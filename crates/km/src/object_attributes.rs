@@ -1,10 +1,10 @@
-use crate::AsRawPtr;
+use crate::{AsRawMutPtr, AsRawPtr};
 use bitflags::bitflags;
 use core::{marker::PhantomData, mem::size_of, ptr::null_mut};
 use km_shared::strings::UnicodeString;
 use km_sys::{
-    HANDLE, OBJECT_ATTRIBUTES, OBJ_FORCE_ACCESS_CHECK, OBJ_KERNEL_HANDLE, OBJ_OPENIF,
-    SECURITY_DESCRIPTOR, ULONG,
+    HANDLE, OBJECT_ATTRIBUTES, OBJ_CASE_INSENSITIVE, OBJ_FORCE_ACCESS_CHECK, OBJ_KERNEL_HANDLE,
+    OBJ_OPENIF, SECURITY_DESCRIPTOR, ULONG,
 };
 
 /// A strongly typed [`OBJECT_ATTRIBUTES`][msdn] structure.
@@ -56,6 +56,14 @@ impl<'a, 'b> ObjectAttributes<'a, 'b> {
     }
 }
 
+impl AsRawMutPtr for ObjectAttributes<'_, '_> {
+    type Pointee = OBJECT_ATTRIBUTES;
+
+    fn as_raw_mut_ptr(&mut self) -> *mut OBJECT_ATTRIBUTES {
+        &mut self.0
+    }
+}
+
 bitflags! {
     /// Object flags, see the [MSDN Documentation][msdn].
     ///
@@ -76,6 +84,8 @@ bitflags! {
         /// routine creating the object returns an NTSTATUS code of
         /// [`crate::ntstatus::STATUS_OBJECT_NAME_COLLISION`].
         const OBJ_OPENIF = OBJ_OPENIF;
+        /// Names are compared case-insensitively when looking up the object.
+        const OBJ_CASE_INSENSITIVE = OBJ_CASE_INSENSITIVE;
     }
 }
 
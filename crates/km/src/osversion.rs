@@ -0,0 +1,130 @@
+//! Detection of the running OS version, e.g. to decide at runtime whether newer kernel APIs (like
+//! `ExAllocatePool2`, available since Windows 10 2004) can be used.
+//!
+//! ```rs, ignore
+//! if osversion::is_build_or_greater(osversion::build::WINDOWS_10_2004) {
+//!     // use `ExAllocatePool2`
+//! } else {
+//!     // fall back to `ExAllocatePoolWithTag`
+//! }
+//! ```
+
+use core::mem::{size_of, zeroed};
+use km_shared::ntstatus::NtStatus;
+use km_sys::{
+    RtlGetVersion, RtlVerifyVersionInfo, VerSetConditionMask, RTL_OSVERSIONINFOEXW, ULONG,
+    VER_BUILDNUMBER, VER_GREATER_EQUAL, VER_MAJORVERSION, VER_MINORVERSION, VER_NT_WORKSTATION,
+};
+
+/// Build numbers of Windows releases, which can be passed to [`is_build_or_greater`].
+pub mod build {
+    pub const WINDOWS_10_1507: u32 = 10240;
+    pub const WINDOWS_10_1607: u32 = 14393;
+    pub const WINDOWS_10_1703: u32 = 15063;
+    pub const WINDOWS_10_1709: u32 = 16299;
+    pub const WINDOWS_10_1803: u32 = 17134;
+    pub const WINDOWS_10_1809: u32 = 17763;
+    pub const WINDOWS_10_1903: u32 = 18362;
+    pub const WINDOWS_10_1909: u32 = 18363;
+    pub const WINDOWS_10_2004: u32 = 19041;
+    pub const WINDOWS_10_20H2: u32 = 19042;
+    pub const WINDOWS_10_21H1: u32 = 19043;
+    pub const WINDOWS_10_21H2: u32 = 19044;
+    pub const WINDOWS_10_22H2: u32 = 19045;
+    pub const WINDOWS_11_21H2: u32 = 22000;
+    pub const WINDOWS_11_22H2: u32 = 22621;
+    pub const WINDOWS_11_23H2: u32 = 22631;
+    pub const WINDOWS_11_24H2: u32 = 26100;
+}
+
+/// The version of the running OS, see [`current`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OsVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub build: u32,
+    pub service_pack_major: u16,
+    pub service_pack_minor: u16,
+    /// Whether this is a client (workstation) edition, as opposed to a server edition.
+    pub is_workstation: bool,
+}
+
+/// Gets the version of the running OS.
+///
+/// Unlike its user mode counterpart, this isn't affected by compatibility shims.
+///
+/// See [MSDN] for more details on the underlying function.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-rtlgetversion
+pub fn current() -> OsVersion {
+    // SAFETY: The version info is a plain C struct, for which all zeroes is a valid value.
+    let mut info: RTL_OSVERSIONINFOEXW = unsafe { zeroed() };
+    info.dwOSVersionInfoSize = size_of::<RTL_OSVERSIONINFOEXW>() as ULONG;
+
+    // SAFETY: `info` is a valid `RTL_OSVERSIONINFOEXW` with its size set, which `RtlGetVersion`
+    // accepts in place of an `RTL_OSVERSIONINFOW`.
+    let status =
+        NtStatus::from(unsafe { RtlGetVersion((&mut info as *mut RTL_OSVERSIONINFOEXW).cast()) });
+    // `RtlGetVersion` always succeeds.
+    debug_assert!(status.result().is_ok());
+
+    OsVersion {
+        major: info.dwMajorVersion,
+        minor: info.dwMinorVersion,
+        build: info.dwBuildNumber,
+        service_pack_major: info.wServicePackMajor,
+        service_pack_minor: info.wServicePackMinor,
+        is_workstation: u32::from(info.wProductType) == VER_NT_WORKSTATION,
+    }
+}
+
+/// Gets the build number of the running OS, see [`build`] for known values.
+pub fn build_number() -> u32 {
+    current().build
+}
+
+/// Checks whether the running OS is at least version `major.minor`, with a build number of at
+/// least `build`.
+///
+/// See [MSDN] for more details on the underlying function.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-rtlverifyversioninfo
+pub fn is_version_or_greater(major: u32, minor: u32, build: u32) -> bool {
+    // SAFETY: The version info is a plain C struct, for which all zeroes is a valid value.
+    let mut info: RTL_OSVERSIONINFOEXW = unsafe { zeroed() };
+    info.dwOSVersionInfoSize = size_of::<RTL_OSVERSIONINFOEXW>() as ULONG;
+    info.dwMajorVersion = major;
+    info.dwMinorVersion = minor;
+    info.dwBuildNumber = build;
+
+    let type_mask = VER_MAJORVERSION | VER_MINORVERSION | VER_BUILDNUMBER;
+    let condition = VER_GREATER_EQUAL as u8;
+    // SAFETY: `VerSetConditionMask` only computes a value.
+    let condition_mask = unsafe {
+        let mask = VerSetConditionMask(0, VER_MAJORVERSION, condition);
+        let mask = VerSetConditionMask(mask, VER_MINORVERSION, condition);
+        VerSetConditionMask(mask, VER_BUILDNUMBER, condition)
+    };
+
+    // SAFETY: `info` is a valid `RTL_OSVERSIONINFOEXW` with its size set.
+    let status = unsafe { RtlVerifyVersionInfo(&mut info, type_mask, condition_mask) };
+    // fails with `STATUS_REVISION_MISMATCH` if the version is lower
+    NtStatus::from(status).result().is_ok()
+}
+
+/// Checks whether the running OS is Windows 10 (or Windows 11, which reports as 10.0) with a
+/// build number of at least `build`.
+pub fn is_build_or_greater(build: u32) -> bool {
+    is_version_or_greater(10, 0, build)
+}
+
+/// Checks whether the running OS is Windows 10, Windows Server 2016, or newer.
+pub fn is_windows_10_or_greater() -> bool {
+    is_version_or_greater(10, 0, 0)
+}
+
+/// Checks whether the running OS is Windows 11 or newer. Windows Server 2022 has a lower build
+/// number, and doesn't count.
+pub fn is_windows_11_or_greater() -> bool {
+    is_build_or_greater(build::WINDOWS_11_21H2)
+}
@@ -1,5 +1,6 @@
+use crate::crash::CrashArea;
 use core::panic::PanicInfo;
-use km_sys::ULONG;
+use km_sys::{ULONG, ULONG_PTR};
 
 const BUGCHECK_RUST_PANIC: ULONG = u32::from_be_bytes(*b"Rust");
 
@@ -13,10 +14,24 @@ pub fn bugcheck_panic(info: &PanicInfo<'_>) -> ! {
     unsafe {
         km_sys::KeBugCheckEx(
             BUGCHECK_RUST_PANIC,
-            file as u64,
-            line as u64,
-            column as u64,
+            file as ULONG_PTR,
+            line as ULONG_PTR,
+            column as ULONG_PTR,
             0,
         );
     }
 }
+
+/// Like [`bugcheck_panic`], but records the panic's location in `crash_area` first, so that it's
+/// in the crash dump even without the driver's symbols.
+///
+/// ```rs, ignore
+/// #[panic_handler]
+/// fn panic(info: &PanicInfo<'_>) -> ! {
+///     km::panic::bugcheck_panic_with(info, &CRASH_AREA)
+/// }
+/// ```
+pub fn bugcheck_panic_with<T: Copy + Send>(info: &PanicInfo<'_>, crash_area: &CrashArea<T>) -> ! {
+    crash_area.record_panic(info);
+    bugcheck_panic(info)
+}
@@ -0,0 +1,244 @@
+//! Periodic polling, e.g. of hardware sensors.
+//!
+//! A [`Poller`] calls a callback periodically on a framework timer belonging to a device, and
+//! can be started and stopped at any time, e.g. from I/O control requests:
+//!
+//! ```rs, ignore
+//! fn poll_sensors(device: &Device) {
+//!     device.with_context(&DEVICE_CONTEXT, |ctx| ctx.sample());
+//! }
+//!
+//! let poller = Poller::create(&device, PollerConfig::new(Duration::from_millis(250)), poll_sensors)?;
+//! poller.start();
+//! ```
+//!
+//! The timer is deleted together with the device. Each poll holds the driver-wide
+//! [rundown](crate::unload), so polls don't start anymore once the driver is unloading, and
+//! [`wait_for_quiescence`](crate::unload::wait_for_quiescence) waits for a running poll to finish.
+
+use crate::{
+    declare_wdf_object_context_type,
+    time::Instant,
+    unload, verify,
+    wdf::{
+        device::Device,
+        object_attributes::{ObjectAttributes, ObjectAttributesInit},
+        spin_lock::SpinLock,
+        timer::{Timer, TimerConfig, TimerConfigInit},
+        ExecutionLevel, RawWdfDevice, RawWdfTimer, WdfObjectReference,
+    },
+};
+use core::{cell::UnsafeCell, time::Duration};
+use km_shared::ntstatus::NtStatusError;
+
+/// The callback of a [`Poller`], called with the device the poller was created for.
+pub type PollCallback = fn(device: &Device);
+
+#[must_use]
+pub struct PollerConfig {
+    /// The time between the starts of two polls.
+    pub period: Duration,
+    /// How much later than scheduled a poll may start, which allows the system to coalesce timers
+    /// to save power. Regardless of this, polls are scheduled relative to when the previous poll
+    /// was due, so delays don't accumulate.
+    pub tolerable_delay: Duration,
+    /// Whether the callback is called at `PASSIVE_LEVEL` instead of `DISPATCH_LEVEL`, which is
+    /// needed for e.g. waiting on the hardware or accessing pageable memory.
+    pub passive_level: bool,
+}
+
+impl PollerConfig {
+    /// Polls every `period` at `PASSIVE_LEVEL`, without tolerable delay.
+    pub fn new(period: Duration) -> Self {
+        Self {
+            period,
+            tolerable_delay: Duration::ZERO,
+            passive_level: true,
+        }
+    }
+}
+
+struct PollerState {
+    running: bool,
+    /// When the next poll is due.
+    next: Instant,
+}
+
+struct PollerContext {
+    callback: PollCallback,
+    period: Duration,
+    lock: SpinLock,
+    /// Only accessed while holding `lock`.
+    state: UnsafeCell<PollerState>,
+}
+
+// SAFETY: The state is only accessed while holding the lock.
+unsafe impl Sync for PollerContext {}
+
+declare_wdf_object_context_type! {
+    static KM_POLLER_CONTEXT => PollerContext;
+}
+
+impl PollerContext {
+    /// Runs `f` with the state, while holding the lock.
+    fn with_state<R>(&self, f: impl FnOnce(&mut PollerState) -> R) -> R {
+        let _guard = self.lock.acquire();
+        // SAFETY: The lock is held.
+        f(unsafe { &mut *self.state.get() })
+    }
+}
+
+/// Calls a [`PollCallback`] periodically. See the [module documentation](self) for an overview.
+#[derive(Clone)]
+pub struct Poller(Timer);
+
+impl Poller {
+    /// Creates a stopped poller for `device`. Fails with `STATUS_INVALID_PARAMETER` if the period
+    /// is zero.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn create(
+        device: &Device,
+        config: PollerConfig,
+        callback: PollCallback,
+    ) -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        if config.period.is_zero() {
+            return Err(NtStatusError::STATUS_INVALID_PARAMETER);
+        }
+
+        let mut attributes = ObjectAttributes::new_with_context(
+            ObjectAttributesInit {
+                execution_level: if config.passive_level {
+                    ExecutionLevel::WdfExecutionLevelPassive
+                } else {
+                    ExecutionLevel::WdfExecutionLevelDispatch
+                },
+                ..Default::default()
+            },
+            &KM_POLLER_CONTEXT,
+        );
+        attributes.set_parent(device);
+
+        let timer = Timer::create(
+            &mut TimerConfig::new(TimerConfigInit {
+                tolerable_delay: config.tolerable_delay,
+                ..TimerConfigInit::one_shot(evt_poll)
+            }),
+            &mut attributes,
+        )?;
+
+        let mut lock_attributes = ObjectAttributes::default();
+        lock_attributes.set_parent(&timer);
+        let lock = SpinLock::create(Some(&mut lock_attributes))?;
+
+        let context = PollerContext {
+            callback,
+            period: config.period,
+            lock,
+            state: UnsafeCell::new(PollerState {
+                running: false,
+                next: Instant::now(),
+            }),
+        };
+        // The timer was just created, so its context can't be initialized already.
+        let _ = KM_POLLER_CONTEXT.initialize(&timer, context);
+
+        Ok(Self(timer))
+    }
+
+    /// Starts polling, with the first poll one period from now. Does nothing if the poller is
+    /// already running.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn start(&self) {
+        verify::at_most_dispatch_level();
+
+        self.with_context(|ctx| {
+            ctx.with_state(|state| {
+                if !state.running {
+                    state.running = true;
+                    state.next = Instant::now().saturating_add(ctx.period);
+                    self.0.start(ctx.period);
+                }
+            })
+        });
+    }
+
+    /// Stops polling.
+    ///
+    /// If `wait` is set, this also waits for a running poll to finish. In that case, this must be
+    /// called at `PASSIVE_LEVEL`, and not from the poll callback. Otherwise, it must be called at
+    /// `IRQL <= DISPATCH_LEVEL`, and a running poll may still finish afterwards.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn stop(&self, wait: bool) {
+        if wait {
+            verify::at_passive_level();
+        } else {
+            verify::at_most_dispatch_level();
+        }
+
+        self.with_context(|ctx| ctx.with_state(|state| state.running = false));
+
+        // The timer is stopped after clearing the flag, so a poll that re-armed it before is
+        // canceled, and all later polls see the flag.
+        self.0.stop(wait);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.with_context(|ctx| ctx.with_state(|state| state.running))
+    }
+
+    fn with_context<R>(&self, f: impl FnOnce(&PollerContext) -> R) -> R {
+        self.0
+            .with_context(&KM_POLLER_CONTEXT, f)
+            .expect("poller context is initialized on creation")
+    }
+}
+
+unsafe extern "system" fn evt_poll(timer: WdfObjectReference<'_, RawWdfTimer>) {
+    // timer callbacks run at `DISPATCH_LEVEL`, or `PASSIVE_LEVEL` for passive-level pollers
+    verify::at_most_dispatch_level();
+
+    let poller = Poller(timer.into());
+
+    let Some(_guard) = unload::acquire() else {
+        // The driver is unloading, so stop polling.
+        poller.with_context(|ctx| ctx.with_state(|state| state.running = false));
+        return;
+    };
+
+    poller.with_context(|ctx| {
+        if !ctx.with_state(|state| state.running) {
+            return;
+        }
+
+        // SAFETY: Pollers are always created with a device as the timer's parent.
+        let device =
+            unsafe { Device::new(poller.0.parent().downcast::<RawWdfDevice>().to_owned()) };
+        (ctx.callback)(&device);
+
+        ctx.with_state(|state| {
+            if !state.running {
+                return;
+            }
+
+            // Schedule relative to the due time instead of now, skipping polls that were missed
+            // entirely (e.g. because the callback took longer than a period).
+            let now = Instant::now();
+            // saturating, as a long period or stall would otherwise overflow
+            let periods = now.duration_since(state.next).as_nanos() / ctx.period.as_nanos() + 1;
+            let skipped = ctx
+                .period
+                .checked_mul(u32::try_from(periods).unwrap_or(u32::MAX))
+                .unwrap_or(Duration::MAX);
+            let next = state.next.saturating_add(skipped);
+
+            state.next = next;
+            poller.0.start(next - now);
+        });
+    });
+}
@@ -0,0 +1,441 @@
+//! Notifications about power settings and the system power state, e.g. to throttle polling while
+//! on battery, or when the system runs hot.
+//!
+//! Two sources of notifications are wrapped:
+//! - [`PowerSettingCallback`], for the power settings the system tracks by GUID, e.g. the
+//!   [power source](ACDC_POWER_SOURCE) or the [cooling policy](SYSTEM_COOLING_POLICY). The
+//!   callback is called with the current value when registering, and whenever it changes.
+//! - [`PowerStateCallback`], for the `\Callback\PowerState` callback object, which is notified
+//!   e.g. before the system sleeps, and after it resumes.
+//!
+//! Like [bugcheck callbacks](crate::bugcheck), the callbacks are registered on `static`s. The
+//! returned registrations unregister the callback when dropped, which has to happen before the
+//! driver unloads.
+//!
+//! ```rs, ignore
+//! static POWER_SOURCE: PowerSettingCallback<PowerSource, AtomicBool> =
+//!     PowerSettingCallback::new(ACDC_POWER_SOURCE, AtomicBool::new(false), |on_battery, source| {
+//!         on_battery.store(source != PowerSource::Ac, Ordering::Relaxed);
+//!     });
+//!
+//! // in `EvtDeviceAdd`, kept in the device context
+//! let registration = POWER_SOURCE.register()?;
+//! ```
+
+use crate::{
+    object_attributes::{ObjectAttributes, ObjectAttributesFlags},
+    shared::{
+        guid,
+        guid::Guid,
+        ntstatus::{NtStatus, NtStatusError},
+        unicode_string,
+    },
+    verify, AsRawMutPtr,
+};
+use core::{
+    ffi::c_void,
+    ptr::{null_mut, NonNull},
+    slice,
+};
+use km_sys::{
+    ExCreateCallback, ExRegisterCallback, ExUnregisterCallback, ObfDereferenceObject,
+    PoRegisterPowerSettingCallback, PoUnregisterPowerSettingCallback, LPCGUID, NTSTATUS,
+    PCALLBACK_OBJECT, PO_CB_AC_STATUS, PO_CB_PROCESSOR_POWER_POLICY, PO_CB_SYSTEM_POWER_POLICY,
+    PO_CB_SYSTEM_STATE_LOCK, PVOID, ULONG,
+};
+
+/// A power setting, identified by its GUID, with a parser for its value.
+///
+/// Settings not defined here can be declared with [`PowerSetting::new`], see the [list of
+/// GUIDs][MSDN] for the defined ones and their values.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/power/power-setting-guids
+#[derive(Debug, Clone, Copy)]
+pub struct PowerSetting<T> {
+    pub guid: Guid,
+    parse: fn(&[u8]) -> Option<T>,
+}
+
+impl<T> PowerSetting<T> {
+    /// `parse` is called with the raw value, and returns `None` if it's malformed, in which case
+    /// the notification is ignored.
+    pub const fn new(guid: Guid, parse: fn(&[u8]) -> Option<T>) -> Self {
+        Self { guid, parse }
+    }
+}
+
+/// The source the system is powered from (`GUID_ACDC_POWER_SOURCE`).
+pub const ACDC_POWER_SOURCE: PowerSetting<PowerSource> = PowerSetting::new(
+    guid!("5D3E9A59-E9D5-4B00-A6BD-FF34FF516548"),
+    PowerSource::parse,
+);
+
+/// The remaining battery capacity in percent (`GUID_BATTERY_PERCENTAGE_REMAINING`).
+pub const BATTERY_PERCENTAGE_REMAINING: PowerSetting<u32> =
+    PowerSetting::new(guid!("A7AD8041-B45A-4CAE-87A3-EECBB468A9E1"), parse_u32);
+
+/// Whether battery saver is on (`GUID_POWER_SAVING_STATUS`).
+pub const POWER_SAVING_STATUS: PowerSetting<bool> =
+    PowerSetting::new(guid!("E00958C0-C213-4ACE-AC77-FECCED2EEEA5"), parse_bool);
+
+/// Whether the system is cooled by throttling processors, or by fans first
+/// (`GUID_SYSTEM_COOLING_POLICY`).
+pub const SYSTEM_COOLING_POLICY: PowerSetting<CoolingPolicy> = PowerSetting::new(
+    guid!("94D3A615-A899-4AC5-AE2B-E4D8F634367F"),
+    CoolingPolicy::parse,
+);
+
+/// The maximum processor performance state in percent of the active power plan
+/// (`GUID_PROCESSOR_THROTTLE_MAXIMUM`).
+pub const PROCESSOR_THROTTLE_MAXIMUM: PowerSetting<u32> =
+    PowerSetting::new(guid!("BC5038F7-23E0-4960-96DA-33ABAF5935EC"), parse_u32);
+
+/// The personality of the active power plan (`GUID_POWERSCHEME_PERSONALITY`).
+pub const POWERSCHEME_PERSONALITY: PowerSetting<PowerSchemePersonality> = PowerSetting::new(
+    guid!("245D8541-3943-4422-B025-13A784F679B7"),
+    PowerSchemePersonality::parse,
+);
+
+fn parse_u32(value: &[u8]) -> Option<u32> {
+    Some(u32::from_ne_bytes(value.try_into().ok()?))
+}
+
+fn parse_bool(value: &[u8]) -> Option<bool> {
+    parse_u32(value).map(|v| v != 0)
+}
+
+fn parse_guid(value: &[u8]) -> Option<Guid> {
+    let value: &[u8; 16] = value.try_into().ok()?;
+    let [a, b, c, d, e, f, g, h, data4 @ ..] = *value;
+
+    Some(Guid::from_fields(
+        u32::from_ne_bytes([a, b, c, d]),
+        u16::from_ne_bytes([e, f]),
+        u16::from_ne_bytes([g, h]),
+        data4,
+    ))
+}
+
+/// The value of [`ACDC_POWER_SOURCE`] (`SYSTEM_POWER_CONDITION`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    /// Powered by an AC power source.
+    Ac,
+    /// Powered by a battery.
+    Dc,
+    /// Powered by a short-term source, e.g. a UPS.
+    ShortTerm,
+}
+
+impl PowerSource {
+    fn parse(value: &[u8]) -> Option<Self> {
+        match parse_u32(value)? {
+            0 => Some(Self::Ac),
+            1 => Some(Self::Dc),
+            2 => Some(Self::ShortTerm),
+            _ => None,
+        }
+    }
+}
+
+/// The value of [`SYSTEM_COOLING_POLICY`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoolingPolicy {
+    /// Processors are throttled before fans are sped up.
+    Passive,
+    /// Fans are sped up before processors are throttled.
+    Active,
+}
+
+impl CoolingPolicy {
+    fn parse(value: &[u8]) -> Option<Self> {
+        match parse_u32(value)? {
+            0 => Some(Self::Passive),
+            1 => Some(Self::Active),
+            _ => None,
+        }
+    }
+}
+
+/// The value of [`POWERSCHEME_PERSONALITY`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSchemePersonality {
+    /// `GUID_MAX_POWER_SAVINGS`
+    PowerSaver,
+    /// `GUID_MIN_POWER_SAVINGS`
+    HighPerformance,
+    /// `GUID_TYPICAL_POWER_SAVINGS`
+    Balanced,
+    /// Any other personality.
+    Other(Guid),
+}
+
+impl PowerSchemePersonality {
+    fn parse(value: &[u8]) -> Option<Self> {
+        let guid = parse_guid(value)?;
+
+        Some(match guid {
+            g if g == guid!("A1841308-3541-4FAB-BC81-F71556F20B4A") => Self::PowerSaver,
+            g if g == guid!("8C5E7FDA-E8BF-4A96-9A85-A6E23A8C635C") => Self::HighPerformance,
+            g if g == guid!("381B4222-F694-41F0-9685-FF5BB260DF2E") => Self::Balanced,
+            g => Self::Other(g),
+        })
+    }
+}
+
+/// A callback called with the value of a [`PowerSetting`] when it's registered, and whenever the
+/// value changes.
+///
+/// Callbacks are called at `PASSIVE_LEVEL`, and the context needs interior mutability (e.g.
+/// atomics) to record the value.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/power-setting-callback
+pub struct PowerSettingCallback<T, C> {
+    setting: PowerSetting<T>,
+    context: C,
+    callback: fn(&C, T),
+}
+
+impl<T, C: Sync> PowerSettingCallback<T, C> {
+    pub const fn new(setting: PowerSetting<T>, context: C, callback: fn(&C, T)) -> Self {
+        Self {
+            setting,
+            context,
+            callback,
+        }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the callback, which is called with the current value before this returns.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/nf-ntifs-poregisterpowersettingcallback
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn register(&'static self) -> Result<PowerSettingRegistration, NtStatusError> {
+        verify::at_passive_level();
+
+        let guid = self.setting.guid.to_raw();
+        let mut handle = null_mut();
+
+        // SAFETY: `guid` is only read during the call, and `handle` is an out parameter. The
+        // callback expects the context to be a `PowerSettingCallback<T, C>`, which is `'static`.
+        NtStatus::from(unsafe {
+            PoRegisterPowerSettingCallback(
+                null_mut(),
+                &guid,
+                Some(power_setting_callback::<T, C>),
+                (self as *const Self).cast_mut().cast(),
+                &mut handle,
+            )
+        })
+        .result_for("PoRegisterPowerSettingCallback")?;
+
+        Ok(PowerSettingRegistration(
+            NonNull::new(handle).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?,
+        ))
+    }
+}
+
+/// # Safety
+/// `context` must point to a `PowerSettingCallback<T, C>`, and the other parameters have to be
+/// the ones passed by the kernel.
+unsafe extern "system" fn power_setting_callback<T, C>(
+    _setting_guid: LPCGUID,
+    value: PVOID,
+    value_length: ULONG,
+    context: PVOID,
+) -> NTSTATUS {
+    // SAFETY: The caller guarantees that `context` is a `PowerSettingCallback<T, C>`.
+    let callback = unsafe { &*context.cast::<PowerSettingCallback<T, C>>() };
+
+    let value = if value.is_null() || value_length == 0 {
+        &[][..]
+    } else {
+        // SAFETY: The kernel passes a buffer of `value_length` bytes, which is valid for the
+        // duration of the callback.
+        unsafe { slice::from_raw_parts(value.cast::<u8>(), value_length as usize) }
+    };
+
+    if let Some(value) = (callback.setting.parse)(value) {
+        (callback.callback)(&callback.context, value);
+    }
+
+    NtStatus::STATUS_SUCCESS.into()
+}
+
+/// A registered [`PowerSettingCallback`], which is unregistered when this is dropped.
+#[derive(Debug)]
+#[must_use = "the callback is unregistered when the registration is dropped"]
+pub struct PowerSettingRegistration(NonNull<c_void>);
+
+// SAFETY: The handle can be unregistered from any thread.
+unsafe impl Send for PowerSettingRegistration {}
+// SAFETY: The handle isn't accessed through shared references.
+unsafe impl Sync for PowerSettingRegistration {}
+
+impl Drop for PowerSettingRegistration {
+    /// Unregisters the callback.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The handle was returned by `PoRegisterPowerSettingCallback`, and is only
+        // unregistered once by virtue of being a `Drop` implementation.
+        unsafe {
+            PoUnregisterPowerSettingCallback(self.0.as_ptr());
+        }
+    }
+}
+
+/// A notification of the `\Callback\PowerState` callback object.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/using-a-driver-supplied-callback-routine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PowerStateEvent {
+    /// The system switched between AC and DC power (`PO_CB_AC_STATUS`).
+    AcStatus { on_ac: bool },
+    /// The system is about to enter a sleep state, or resumed from one
+    /// (`PO_CB_SYSTEM_STATE_LOCK`). Pageable code has to be locked in memory before sleeping,
+    /// when `resumed` is `false`.
+    SystemStateLock { resumed: bool },
+    /// The system power policy changed (`PO_CB_SYSTEM_POWER_POLICY`).
+    SystemPowerPolicy,
+    /// The processor power policy changed (`PO_CB_PROCESSOR_POWER_POLICY`).
+    ProcessorPowerPolicy,
+    /// Any other notification, with the raw arguments.
+    Other { reason: ULONG, argument: usize },
+}
+
+impl PowerStateEvent {
+    fn from_raw(reason: ULONG, argument: usize) -> Self {
+        match reason {
+            PO_CB_AC_STATUS => Self::AcStatus {
+                on_ac: argument != 0,
+            },
+            PO_CB_SYSTEM_STATE_LOCK => Self::SystemStateLock {
+                resumed: argument != 0,
+            },
+            PO_CB_SYSTEM_POWER_POLICY => Self::SystemPowerPolicy,
+            PO_CB_PROCESSOR_POWER_POLICY => Self::ProcessorPowerPolicy,
+            _ => Self::Other { reason, argument },
+        }
+    }
+}
+
+/// A callback registered on the `\Callback\PowerState` callback object.
+///
+/// Callbacks are called at `PASSIVE_LEVEL`, and the context needs interior mutability (e.g.
+/// atomics) to record the state.
+pub struct PowerStateCallback<C> {
+    context: C,
+    callback: fn(&C, PowerStateEvent),
+}
+
+impl<C: Sync> PowerStateCallback<C> {
+    pub const fn new(context: C, callback: fn(&C, PowerStateEvent)) -> Self {
+        Self { context, callback }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Opens the `\Callback\PowerState` callback object, and registers the callback on it.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying
+    /// functions.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-exregistercallback
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn register(&'static self) -> Result<PowerStateRegistration, NtStatusError> {
+        verify::at_passive_level();
+
+        let name = unicode_string!("\\Callback\\PowerState");
+        // SAFETY: `name` outlives the attributes, and no security descriptor is passed.
+        let mut attributes = unsafe {
+            ObjectAttributes::initialize(
+                &name,
+                ObjectAttributesFlags::OBJ_CASE_INSENSITIVE,
+                None,
+                None,
+            )
+        };
+        let mut object: PCALLBACK_OBJECT = null_mut();
+
+        // SAFETY: `attributes` is valid, and `object` is an out parameter. The existing object is
+        // opened, and not created.
+        NtStatus::from(unsafe { ExCreateCallback(&mut object, attributes.as_raw_mut_ptr(), 0, 1) })
+            .result_for("ExCreateCallback")?;
+
+        let object = NonNull::new(object).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?;
+
+        // SAFETY: `object` was opened above. The callback expects the context to be a
+        // `PowerStateCallback<C>`, which is `'static`.
+        let handle = unsafe {
+            ExRegisterCallback(
+                object.as_ptr(),
+                Some(power_state_callback::<C>),
+                (self as *const Self).cast_mut().cast(),
+            )
+        };
+
+        let Some(handle) = NonNull::new(handle) else {
+            // SAFETY: The object was referenced by `ExCreateCallback`.
+            unsafe { ObfDereferenceObject(object.as_ptr().cast()) };
+            return Err(NtStatusError::STATUS_UNSUCCESSFUL);
+        };
+
+        Ok(PowerStateRegistration { object, handle })
+    }
+}
+
+/// # Safety
+/// `context` must point to a `PowerStateCallback<C>`.
+unsafe extern "system" fn power_state_callback<C>(
+    context: PVOID,
+    argument1: PVOID,
+    argument2: PVOID,
+) {
+    // SAFETY: The caller guarantees that `context` is a `PowerStateCallback<C>`.
+    let callback = unsafe { &*context.cast::<PowerStateCallback<C>>() };
+
+    // both arguments are numbers passed as pointers
+    let event = PowerStateEvent::from_raw(argument1 as usize as ULONG, argument2 as usize);
+    (callback.callback)(&callback.context, event);
+}
+
+/// A registered [`PowerStateCallback`], which is unregistered when this is dropped.
+#[derive(Debug)]
+#[must_use = "the callback is unregistered when the registration is dropped"]
+pub struct PowerStateRegistration {
+    object: NonNull<km_sys::_CALLBACK_OBJECT>,
+    handle: NonNull<c_void>,
+}
+
+// SAFETY: The registration can be removed from any thread.
+unsafe impl Send for PowerStateRegistration {}
+// SAFETY: The registration isn't accessed through shared references.
+unsafe impl Sync for PowerStateRegistration {}
+
+impl Drop for PowerStateRegistration {
+    /// Unregisters the callback, and closes the callback object.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The handle was returned by `ExRegisterCallback` for the object, which was
+        // referenced by `ExCreateCallback`. Both are only released once by virtue of being a
+        // `Drop` implementation.
+        unsafe {
+            ExUnregisterCallback(self.handle.as_ptr());
+            ObfDereferenceObject(self.object.as_ptr().cast());
+        }
+    }
+}
@@ -1,10 +1,81 @@
-use crate::mode::ProcessorMode;
-use km_sys::{LARGE_INTEGER, LUID};
+//! Privilege checks and access tokens, e.g. to gate dangerous IOCTLs on the caller's identity.
+//!
+//! [`check_single_privilege`] checks the subject context of the current thread, so it's only
+//! meaningful in the context of the requesting thread, e.g. in an
+//! [`EvtIoInCallerContext`](crate::wdf::device_init::EvtIoInCallerContext) callback. A [`Token`]
+//! of the requesting process can be queried from any context while the request is pending.
 
+use crate::{mode::ProcessorMode, wdf::request::Request};
+use core::{
+    ffi::c_void,
+    ptr::{null_mut, NonNull},
+    slice,
+};
+use km_shared::ntstatus::{NtStatus, NtStatusError};
+use km_sys::{
+    ExFreePoolWithTag, IoGetRequestorProcess, ObfDereferenceObject, PsReferencePrimaryToken,
+    SeQueryInformationToken, SeTokenIsAdmin, LARGE_INTEGER, LUID, LUID_AND_ATTRIBUTES, PVOID,
+    SE_PRIVILEGE_ENABLED, TOKEN_ELEVATION, TOKEN_INFORMATION_CLASS, TOKEN_PRIVILEGES,
+};
+
+#[derive(Debug, Clone, Copy)]
 pub struct Luid(LUID);
 
 impl Luid {
+    pub const SE_CREATE_TOKEN_PRIVILEGE: Self = Self::from_const(km_sys::SE_CREATE_TOKEN_PRIVILEGE);
+    pub const SE_ASSIGNPRIMARYTOKEN_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_ASSIGNPRIMARYTOKEN_PRIVILEGE);
+    pub const SE_LOCK_MEMORY_PRIVILEGE: Self = Self::from_const(km_sys::SE_LOCK_MEMORY_PRIVILEGE);
+    pub const SE_INCREASE_QUOTA_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_INCREASE_QUOTA_PRIVILEGE);
+    pub const SE_MACHINE_ACCOUNT_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_MACHINE_ACCOUNT_PRIVILEGE);
+    pub const SE_TCB_PRIVILEGE: Self = Self::from_const(km_sys::SE_TCB_PRIVILEGE);
+    pub const SE_SECURITY_PRIVILEGE: Self = Self::from_const(km_sys::SE_SECURITY_PRIVILEGE);
+    pub const SE_TAKE_OWNERSHIP_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_TAKE_OWNERSHIP_PRIVILEGE);
     pub const SE_LOAD_DRIVER_PRIVILEGE: Self = Self::from_const(km_sys::SE_LOAD_DRIVER_PRIVILEGE);
+    pub const SE_SYSTEM_PROFILE_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_SYSTEM_PROFILE_PRIVILEGE);
+    pub const SE_SYSTEMTIME_PRIVILEGE: Self = Self::from_const(km_sys::SE_SYSTEMTIME_PRIVILEGE);
+    pub const SE_PROF_SINGLE_PROCESS_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_PROF_SINGLE_PROCESS_PRIVILEGE);
+    pub const SE_INC_BASE_PRIORITY_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_INC_BASE_PRIORITY_PRIVILEGE);
+    pub const SE_CREATE_PAGEFILE_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_CREATE_PAGEFILE_PRIVILEGE);
+    pub const SE_CREATE_PERMANENT_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_CREATE_PERMANENT_PRIVILEGE);
+    pub const SE_BACKUP_PRIVILEGE: Self = Self::from_const(km_sys::SE_BACKUP_PRIVILEGE);
+    pub const SE_RESTORE_PRIVILEGE: Self = Self::from_const(km_sys::SE_RESTORE_PRIVILEGE);
+    pub const SE_SHUTDOWN_PRIVILEGE: Self = Self::from_const(km_sys::SE_SHUTDOWN_PRIVILEGE);
+    pub const SE_DEBUG_PRIVILEGE: Self = Self::from_const(km_sys::SE_DEBUG_PRIVILEGE);
+    pub const SE_AUDIT_PRIVILEGE: Self = Self::from_const(km_sys::SE_AUDIT_PRIVILEGE);
+    pub const SE_SYSTEM_ENVIRONMENT_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_SYSTEM_ENVIRONMENT_PRIVILEGE);
+    pub const SE_CHANGE_NOTIFY_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_CHANGE_NOTIFY_PRIVILEGE);
+    pub const SE_REMOTE_SHUTDOWN_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_REMOTE_SHUTDOWN_PRIVILEGE);
+    pub const SE_UNDOCK_PRIVILEGE: Self = Self::from_const(km_sys::SE_UNDOCK_PRIVILEGE);
+    pub const SE_SYNC_AGENT_PRIVILEGE: Self = Self::from_const(km_sys::SE_SYNC_AGENT_PRIVILEGE);
+    pub const SE_ENABLE_DELEGATION_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_ENABLE_DELEGATION_PRIVILEGE);
+    pub const SE_MANAGE_VOLUME_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_MANAGE_VOLUME_PRIVILEGE);
+    pub const SE_IMPERSONATE_PRIVILEGE: Self = Self::from_const(km_sys::SE_IMPERSONATE_PRIVILEGE);
+    pub const SE_CREATE_GLOBAL_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_CREATE_GLOBAL_PRIVILEGE);
+    pub const SE_TRUSTED_CREDMAN_ACCESS_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_TRUSTED_CREDMAN_ACCESS_PRIVILEGE);
+    pub const SE_RELABEL_PRIVILEGE: Self = Self::from_const(km_sys::SE_RELABEL_PRIVILEGE);
+    pub const SE_INC_WORKING_SET_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_INC_WORKING_SET_PRIVILEGE);
+    pub const SE_TIME_ZONE_PRIVILEGE: Self = Self::from_const(km_sys::SE_TIME_ZONE_PRIVILEGE);
+    pub const SE_CREATE_SYMBOLIC_LINK_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_CREATE_SYMBOLIC_LINK_PRIVILEGE);
+    pub const SE_DELEGATE_SESSION_USER_IMPERSONATE_PRIVILEGE: Self =
+        Self::from_const(km_sys::SE_DELEGATE_SESSION_USER_IMPERSONATE_PRIVILEGE);
 
     const fn from_const(raw: u32) -> Self {
         // The SE_* constants are actually i32/int, bindgen generates u32 though.
@@ -30,7 +101,187 @@ impl From<LUID> for Luid {
     }
 }
 
+impl PartialEq for Luid {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.LowPart == other.0.LowPart && self.0.HighPart == other.0.HighPart
+    }
+}
+
+impl Eq for Luid {}
+
+/// A well-known privilege, see [MSDN] for what each of them allows.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Privilege {
+    CreateToken = km_sys::SE_CREATE_TOKEN_PRIVILEGE,
+    AssignPrimaryToken = km_sys::SE_ASSIGNPRIMARYTOKEN_PRIVILEGE,
+    LockMemory = km_sys::SE_LOCK_MEMORY_PRIVILEGE,
+    IncreaseQuota = km_sys::SE_INCREASE_QUOTA_PRIVILEGE,
+    MachineAccount = km_sys::SE_MACHINE_ACCOUNT_PRIVILEGE,
+    Tcb = km_sys::SE_TCB_PRIVILEGE,
+    Security = km_sys::SE_SECURITY_PRIVILEGE,
+    TakeOwnership = km_sys::SE_TAKE_OWNERSHIP_PRIVILEGE,
+    LoadDriver = km_sys::SE_LOAD_DRIVER_PRIVILEGE,
+    SystemProfile = km_sys::SE_SYSTEM_PROFILE_PRIVILEGE,
+    SystemTime = km_sys::SE_SYSTEMTIME_PRIVILEGE,
+    ProfileSingleProcess = km_sys::SE_PROF_SINGLE_PROCESS_PRIVILEGE,
+    IncreaseBasePriority = km_sys::SE_INC_BASE_PRIORITY_PRIVILEGE,
+    CreatePagefile = km_sys::SE_CREATE_PAGEFILE_PRIVILEGE,
+    CreatePermanent = km_sys::SE_CREATE_PERMANENT_PRIVILEGE,
+    Backup = km_sys::SE_BACKUP_PRIVILEGE,
+    Restore = km_sys::SE_RESTORE_PRIVILEGE,
+    Shutdown = km_sys::SE_SHUTDOWN_PRIVILEGE,
+    Debug = km_sys::SE_DEBUG_PRIVILEGE,
+    Audit = km_sys::SE_AUDIT_PRIVILEGE,
+    SystemEnvironment = km_sys::SE_SYSTEM_ENVIRONMENT_PRIVILEGE,
+    ChangeNotify = km_sys::SE_CHANGE_NOTIFY_PRIVILEGE,
+    RemoteShutdown = km_sys::SE_REMOTE_SHUTDOWN_PRIVILEGE,
+    Undock = km_sys::SE_UNDOCK_PRIVILEGE,
+    SyncAgent = km_sys::SE_SYNC_AGENT_PRIVILEGE,
+    EnableDelegation = km_sys::SE_ENABLE_DELEGATION_PRIVILEGE,
+    ManageVolume = km_sys::SE_MANAGE_VOLUME_PRIVILEGE,
+    Impersonate = km_sys::SE_IMPERSONATE_PRIVILEGE,
+    CreateGlobal = km_sys::SE_CREATE_GLOBAL_PRIVILEGE,
+    TrustedCredManAccess = km_sys::SE_TRUSTED_CREDMAN_ACCESS_PRIVILEGE,
+    Relabel = km_sys::SE_RELABEL_PRIVILEGE,
+    IncreaseWorkingSet = km_sys::SE_INC_WORKING_SET_PRIVILEGE,
+    TimeZone = km_sys::SE_TIME_ZONE_PRIVILEGE,
+    CreateSymbolicLink = km_sys::SE_CREATE_SYMBOLIC_LINK_PRIVILEGE,
+    DelegateSessionUserImpersonate = km_sys::SE_DELEGATE_SESSION_USER_IMPERSONATE_PRIVILEGE,
+}
+
+impl Privilege {
+    pub const fn luid(self) -> Luid {
+        Luid::from_const(self as u32)
+    }
+
+    /// Checks whether the current thread's subject context has the privilege enabled, see
+    /// [`check_single_privilege`].
+    pub fn check(self, previous_mode: ProcessorMode) -> bool {
+        check_single_privilege(self.luid(), previous_mode)
+    }
+}
+
+impl From<Privilege> for Luid {
+    fn from(privilege: Privilege) -> Self {
+        privilege.luid()
+    }
+}
+
 pub fn check_single_privilege(privilege_luid: Luid, previous_mode: ProcessorMode) -> bool {
     // SAFETY: We call the function with the correct parameters.
     unsafe { km_sys::SeSinglePrivilegeCheck(privilege_luid.0, previous_mode.into()) != 0 }
 }
+
+/// A referenced primary access token of a process.
+///
+/// Dereferences the token when dropped.
+#[derive(Debug)]
+pub struct Token(NonNull<c_void>);
+
+// SAFETY: Tokens are reference counted kernel objects, which can be used from any thread.
+unsafe impl Send for Token {}
+// SAFETY: See above; the token is only queried through shared references.
+unsafe impl Sync for Token {}
+
+impl Token {
+    /// Gets the primary token of the process that sent `request`.
+    ///
+    /// Returns `None` if the request wasn't sent by a process, e.g. for requests created by
+    /// drivers. This is the token of the process, not an impersonation token the requesting
+    /// thread may have used.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/nf-ntifs-psreferenceprimarytoken
+    pub fn of_requestor(request: &Request) -> Option<Self> {
+        // SAFETY: The IRP of a pending request is valid.
+        let process = unsafe { IoGetRequestorProcess(request.wdm_irp()) };
+        if process.is_null() {
+            return None;
+        }
+
+        // SAFETY: The requesting process is kept alive by the pending request.
+        NonNull::new(unsafe { PsReferencePrimaryToken(process) }).map(Self)
+    }
+
+    /// Whether the token is a member of the local Administrators group, which is only enabled for
+    /// elevated processes if UAC is enabled.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/nf-ntifs-setokenisadmin
+    pub fn is_admin(&self) -> bool {
+        // SAFETY: The token is valid while referenced.
+        unsafe { SeTokenIsAdmin(self.0.as_ptr()) != 0 }
+    }
+
+    /// Whether the token is elevated, i.e. the process runs with the full token of an
+    /// administrator.
+    pub fn is_elevated(&self) -> Result<bool, NtStatusError> {
+        // SAFETY: The information is a `TOKEN_ELEVATION` for `TokenElevation`.
+        unsafe {
+            self.query(TOKEN_INFORMATION_CLASS::TokenElevation, |info| {
+                (*info.cast::<TOKEN_ELEVATION>()).TokenIsElevated != 0
+            })
+        }
+    }
+
+    /// Whether `privilege` is present and enabled in the token.
+    pub fn has_privilege(&self, privilege: Privilege) -> Result<bool, NtStatusError> {
+        let luid = privilege.luid();
+
+        // SAFETY: The information is a `TOKEN_PRIVILEGES` for `TokenPrivileges`, which is followed
+        // by `PrivilegeCount` entries.
+        unsafe {
+            self.query(TOKEN_INFORMATION_CLASS::TokenPrivileges, |info| {
+                let privileges = info.cast::<TOKEN_PRIVILEGES>();
+                let privileges: &[LUID_AND_ATTRIBUTES] = slice::from_raw_parts(
+                    (*privileges).Privileges.as_ptr(),
+                    (*privileges).PrivilegeCount as usize,
+                );
+
+                privileges
+                    .iter()
+                    .any(|p| Luid(p.Luid) == luid && p.Attributes & SE_PRIVILEGE_ENABLED != 0)
+            })
+        }
+    }
+
+    /// Queries information of the given class, which is passed to `f` and freed afterwards.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    /// `f` must interpret the information as the type that belongs to `class`.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/nf-ntifs-sequeryinformationtoken
+    unsafe fn query<R>(
+        &self,
+        class: TOKEN_INFORMATION_CLASS,
+        f: impl FnOnce(PVOID) -> R,
+    ) -> Result<R, NtStatusError> {
+        let mut info = null_mut();
+        // SAFETY: The token is valid while referenced, and `info` is an out parameter.
+        NtStatus::from(unsafe { SeQueryInformationToken(self.0.as_ptr(), class, &mut info) })
+            .result_for("SeQueryInformationToken")?;
+
+        let result = f(info);
+        // SAFETY: The information was allocated from pool by `SeQueryInformationToken`, and isn't
+        // used anymore.
+        unsafe { ExFreePoolWithTag(info, 0) };
+        Ok(result)
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        // SAFETY: The token was referenced by `PsReferencePrimaryToken`, and is only dereferenced
+        // once by virtue of being a `Drop` implementation.
+        unsafe {
+            ObfDereferenceObject(self.0.as_ptr());
+        }
+    }
+}
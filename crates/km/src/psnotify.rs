@@ -0,0 +1,630 @@
+//! Notifications about processes and threads being created and exiting, and images being loaded,
+//! e.g. to detect when a companion service starts or stops.
+//!
+//! Three kinds of callbacks are wrapped:
+//! - [`ProcessNotifyCallback`], called when a process is created, which it can deny, and when it
+//!   exits.
+//! - [`ThreadNotifyCallback`], called when a thread is created, and when it exits.
+//! - [`ImageNotifyCallback`], called when an executable image, i.e. a driver, or an executable or
+//!   DLL of a process, is mapped.
+//!
+//! Like [power callbacks](crate::power), the callbacks are registered on `static`s. The returned
+//! registrations unregister the callback when dropped, which has to happen before the driver
+//! unloads.
+//!
+//! ```rs, ignore
+//! static SERVICE: ProcessNotifyCallback<AtomicUsize> =
+//!     ProcessNotifyCallback::new(AtomicUsize::new(0), |service_pid, event| match event {
+//!         ProcessEvent::Created(process) if is_service_image(process.image_file_name()) => {
+//!             service_pid.store(process.process_id().0, Ordering::Relaxed);
+//!         }
+//!         ProcessEvent::Exited { process_id } => {
+//!             let _ = service_pid.compare_exchange(
+//!                 process_id.0,
+//!                 0,
+//!                 Ordering::Relaxed,
+//!                 Ordering::Relaxed,
+//!             );
+//!         }
+//!         _ => {}
+//!     });
+//!
+//! // in `DriverEntry`, kept until unload
+//! let registration = SERVICE.register()?;
+//! ```
+//!
+//! The kernel doesn't pass a context to these callbacks, so each registration takes one of a fixed
+//! number of slots per kind, and registering fails with `STATUS_INSUFFICIENT_RESOURCES` once all
+//! of them are taken.
+
+use crate::{
+    object::{FileObject, ObjectReference, Process},
+    shared::{
+        ntstatus::{NtStatus, NtStatusError},
+        strings::UnicodeString,
+    },
+    verify,
+};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    ptr::NonNull,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use km_sys::{
+    PsRemoveCreateThreadNotifyRoutine, PsRemoveLoadImageNotifyRoutine,
+    PsSetCreateProcessNotifyRoutineEx, PsSetCreateThreadNotifyRoutine, PsSetLoadImageNotifyRoutine,
+    _KPROCESS, BOOLEAN, HANDLE, PEPROCESS, PIMAGE_INFO, PPS_CREATE_NOTIFY_INFO,
+    PS_CREATE_NOTIFY_INFO, PUNICODE_STRING,
+};
+
+/// The ID of a process, which is unique while the process exists, but may be reused afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcessId(pub usize);
+
+impl ProcessId {
+    fn from_handle(handle: HANDLE) -> Self {
+        Self(handle as usize)
+    }
+}
+
+/// The ID of a thread, which is unique while the thread exists, but may be reused afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThreadId(pub usize);
+
+impl ThreadId {
+    fn from_handle(handle: HANDLE) -> Self {
+        Self(handle as usize)
+    }
+}
+
+/// A notification passed to a [`ProcessNotifyCallback`].
+#[derive(Debug)]
+pub enum ProcessEvent<'a> {
+    /// A process is being created, which can still be denied.
+    Created(ProcessCreated<'a>),
+    /// A process exited, after its last thread exited.
+    Exited { process_id: ProcessId },
+}
+
+/// A process being created, passed to a [`ProcessNotifyCallback`].
+///
+/// The callback is called in the context of the thread creating the process, before the initial
+/// thread of the new process is created.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/ns-ntddk-_ps_create_notify_info
+pub struct ProcessCreated<'a> {
+    process: NonNull<_KPROCESS>,
+    process_id: ProcessId,
+    info: &'a mut PS_CREATE_NOTIFY_INFO,
+}
+
+impl ProcessCreated<'_> {
+    /// References the process, e.g. to keep it after the callback returned.
+    ///
+    /// Can be called at any IRQL the callback is called at.
+    pub fn process(&self) -> ObjectReference<Process> {
+        // SAFETY: The process is valid while the callback is called.
+        unsafe { ObjectReference::from_raw(self.process) }
+    }
+
+    pub fn process_id(&self) -> ProcessId {
+        self.process_id
+    }
+
+    /// The process the new process inherits from, which usually is the creating process.
+    pub fn parent_process_id(&self) -> ProcessId {
+        ProcessId::from_handle(self.info.ParentProcessId)
+    }
+
+    /// The process of the thread creating the process.
+    pub fn creating_process_id(&self) -> ProcessId {
+        ProcessId::from_handle(self.info.CreatingThreadId.UniqueProcess)
+    }
+
+    /// The thread creating the process.
+    pub fn creating_thread_id(&self) -> ThreadId {
+        ThreadId::from_handle(self.info.CreatingThreadId.UniqueThread)
+    }
+
+    /// The name of the executable of the process, which is the full path if
+    /// [`ProcessCreated::is_full_image_name`] is `true`, and the name it was opened with
+    /// otherwise.
+    pub fn image_file_name(&self) -> Option<&UnicodeString> {
+        // SAFETY: The name is either null, or valid while the callback is called.
+        unsafe { self.info.ImageFileName.as_ref() }
+    }
+
+    /// Whether [`ProcessCreated::image_file_name`] is the full path of the executable.
+    pub fn is_full_image_name(&self) -> bool {
+        // SAFETY: All bit patterns of the flags are valid.
+        unsafe { self.info.__bindgen_anon_1.Flags & 1 != 0 }
+    }
+
+    /// References the file object of the executable of the process.
+    ///
+    /// Can be called at any IRQL the callback is called at.
+    pub fn file_object(&self) -> Option<ObjectReference<FileObject>> {
+        let file_object = NonNull::new(self.info.FileObject)?;
+        // SAFETY: The file object is valid while the callback is called.
+        Some(unsafe { ObjectReference::from_raw(file_object) })
+    }
+
+    /// The command line the process was started with, if any.
+    pub fn command_line(&self) -> Option<&UnicodeString> {
+        // SAFETY: The command line is either null, or valid while the callback is called.
+        unsafe { self.info.CommandLine.as_ref() }
+    }
+
+    /// Denies the creation of the process, which fails with `status`.
+    pub fn deny(&mut self, status: NtStatusError) {
+        self.info.CreationStatus = NtStatus::from(status).into();
+    }
+}
+
+impl fmt::Debug for ProcessCreated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProcessCreated")
+            .field("process_id", &self.process_id)
+            .field("parent_process_id", &self.parent_process_id())
+            .field("creating_thread_id", &self.creating_thread_id())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A callback called when a process is created, and when it exits.
+///
+/// Callbacks are called at `PASSIVE_LEVEL`, in a critical region, and the context needs interior
+/// mutability (e.g. atomics) to record state.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/nc-ntddk-pcreate_process_notify_routine_ex
+pub struct ProcessNotifyCallback<C> {
+    context: C,
+    callback: fn(&C, ProcessEvent<'_>),
+}
+
+impl<C: Sync> ProcessNotifyCallback<C> {
+    pub const fn new(context: C, callback: fn(&C, ProcessEvent<'_>)) -> Self {
+        Self { context, callback }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the callback, which is called for all processes created and exiting from then
+    /// on.
+    ///
+    /// Fails with `STATUS_ACCESS_DENIED` if the driver isn't linked with `/INTEGRITYCHECK`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/nf-ntddk-pssetcreateprocessnotifyroutineex
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn register(&'static self) -> Result<ProcessNotifyRegistration, NtStatusError> {
+        verify::at_passive_level();
+
+        let slot = PROCESS_SLOTS
+            .claim(self)
+            .ok_or(NtStatusError::STATUS_INSUFFICIENT_RESOURCES)?;
+
+        // SAFETY: The routine of the slot dispatches to `self`, which was stored in the slot
+        // above, and is `'static`.
+        let status =
+            NtStatus::from(unsafe { PsSetCreateProcessNotifyRoutineEx(PROCESS_ROUTINES[slot], 0) })
+                .result_for("PsSetCreateProcessNotifyRoutineEx");
+
+        if let Err(e) = status {
+            // SAFETY: The routine failed to register.
+            unsafe { PROCESS_SLOTS.release(slot) };
+            return Err(e);
+        }
+
+        Ok(ProcessNotifyRegistration(slot))
+    }
+}
+
+/// The type-erased [`ProcessNotifyCallback`] stored in a slot.
+trait ProcessNotify: Sync {
+    fn notify(&self, event: ProcessEvent<'_>);
+}
+
+impl<C: Sync> ProcessNotify for ProcessNotifyCallback<C> {
+    fn notify(&self, event: ProcessEvent<'_>) {
+        (self.callback)(&self.context, event);
+    }
+}
+
+/// # Safety
+/// Slot `I` must hold the registered callback, and the parameters have to be the ones passed by
+/// the kernel.
+unsafe extern "system" fn process_notify<const I: usize>(
+    process: PEPROCESS,
+    process_id: HANDLE,
+    create_info: PPS_CREATE_NOTIFY_INFO,
+) {
+    // SAFETY: The caller guarantees that the slot holds the callback.
+    let callback = unsafe { PROCESS_SLOTS.get(I) };
+    let process_id = ProcessId::from_handle(process_id);
+
+    // SAFETY: The info is either null for exiting processes, or valid while the callback is
+    // called.
+    let event = match unsafe { create_info.as_mut() } {
+        Some(info) => ProcessEvent::Created(ProcessCreated {
+            // SAFETY: The kernel always passes the process.
+            process: unsafe { NonNull::new_unchecked(process) },
+            process_id,
+            info,
+        }),
+        None => ProcessEvent::Exited { process_id },
+    };
+
+    callback.notify(event);
+}
+
+static PROCESS_SLOTS: Slots<dyn ProcessNotify> = Slots::new();
+
+/// The routine registered for each slot of [`PROCESS_SLOTS`].
+const PROCESS_ROUTINES: [km_sys::PCREATE_PROCESS_NOTIFY_ROUTINE_EX; SLOTS] = [
+    Some(process_notify::<0>),
+    Some(process_notify::<1>),
+    Some(process_notify::<2>),
+    Some(process_notify::<3>),
+    Some(process_notify::<4>),
+    Some(process_notify::<5>),
+    Some(process_notify::<6>),
+    Some(process_notify::<7>),
+];
+
+/// A registered [`ProcessNotifyCallback`], which is unregistered when this is dropped.
+#[derive(Debug)]
+#[must_use = "the callback is unregistered when the registration is dropped"]
+pub struct ProcessNotifyRegistration(usize);
+
+impl Drop for ProcessNotifyRegistration {
+    /// Unregisters the callback, waiting for running calls of it to return.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The routine of the slot was registered, and is only unregistered once by virtue
+        // of being a `Drop` implementation.
+        unsafe { PsSetCreateProcessNotifyRoutineEx(PROCESS_ROUTINES[self.0], 1) };
+
+        // SAFETY: The slot is owned by the registration, and its routine was unregistered.
+        unsafe { PROCESS_SLOTS.release(self.0) };
+    }
+}
+
+/// A notification passed to a [`ThreadNotifyCallback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadEvent {
+    /// A thread was created, and hasn't run yet.
+    Created {
+        process_id: ProcessId,
+        thread_id: ThreadId,
+    },
+    /// A thread is exiting.
+    Exited {
+        process_id: ProcessId,
+        thread_id: ThreadId,
+    },
+}
+
+/// A callback called when a thread is created, and when it exits.
+///
+/// Callbacks are called at `PASSIVE_LEVEL` or `APC_LEVEL`, and the context needs interior
+/// mutability (e.g. atomics) to record state.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/nc-ntddk-pcreate_thread_notify_routine
+pub struct ThreadNotifyCallback<C> {
+    context: C,
+    callback: fn(&C, ThreadEvent),
+}
+
+impl<C: Sync> ThreadNotifyCallback<C> {
+    pub const fn new(context: C, callback: fn(&C, ThreadEvent)) -> Self {
+        Self { context, callback }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the callback, which is called for all threads created and exiting from then on.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/nf-ntddk-pssetcreatethreadnotifyroutine
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn register(&'static self) -> Result<ThreadNotifyRegistration, NtStatusError> {
+        verify::at_passive_level();
+
+        let slot = THREAD_SLOTS
+            .claim(self)
+            .ok_or(NtStatusError::STATUS_INSUFFICIENT_RESOURCES)?;
+
+        // SAFETY: The routine of the slot dispatches to `self`, which was stored in the slot
+        // above, and is `'static`.
+        let status =
+            NtStatus::from(unsafe { PsSetCreateThreadNotifyRoutine(THREAD_ROUTINES[slot]) })
+                .result_for("PsSetCreateThreadNotifyRoutine");
+
+        if let Err(e) = status {
+            // SAFETY: The routine failed to register.
+            unsafe { THREAD_SLOTS.release(slot) };
+            return Err(e);
+        }
+
+        Ok(ThreadNotifyRegistration(slot))
+    }
+}
+
+/// The type-erased [`ThreadNotifyCallback`] stored in a slot.
+trait ThreadNotify: Sync {
+    fn notify(&self, event: ThreadEvent);
+}
+
+impl<C: Sync> ThreadNotify for ThreadNotifyCallback<C> {
+    fn notify(&self, event: ThreadEvent) {
+        (self.callback)(&self.context, event);
+    }
+}
+
+/// # Safety
+/// Slot `I` must hold the registered callback.
+unsafe extern "system" fn thread_notify<const I: usize>(
+    process_id: HANDLE,
+    thread_id: HANDLE,
+    create: BOOLEAN,
+) {
+    // SAFETY: The caller guarantees that the slot holds the callback.
+    let callback = unsafe { THREAD_SLOTS.get(I) };
+    let process_id = ProcessId::from_handle(process_id);
+    let thread_id = ThreadId::from_handle(thread_id);
+
+    callback.notify(if create != 0 {
+        ThreadEvent::Created {
+            process_id,
+            thread_id,
+        }
+    } else {
+        ThreadEvent::Exited {
+            process_id,
+            thread_id,
+        }
+    });
+}
+
+static THREAD_SLOTS: Slots<dyn ThreadNotify> = Slots::new();
+
+/// The routine registered for each slot of [`THREAD_SLOTS`].
+const THREAD_ROUTINES: [km_sys::PCREATE_THREAD_NOTIFY_ROUTINE; SLOTS] = [
+    Some(thread_notify::<0>),
+    Some(thread_notify::<1>),
+    Some(thread_notify::<2>),
+    Some(thread_notify::<3>),
+    Some(thread_notify::<4>),
+    Some(thread_notify::<5>),
+    Some(thread_notify::<6>),
+    Some(thread_notify::<7>),
+];
+
+/// A registered [`ThreadNotifyCallback`], which is unregistered when this is dropped.
+#[derive(Debug)]
+#[must_use = "the callback is unregistered when the registration is dropped"]
+pub struct ThreadNotifyRegistration(usize);
+
+impl Drop for ThreadNotifyRegistration {
+    /// Unregisters the callback, waiting for running calls of it to return.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The routine of the slot was registered, and is only unregistered once by virtue
+        // of being a `Drop` implementation.
+        unsafe { PsRemoveCreateThreadNotifyRoutine(THREAD_ROUTINES[self.0]) };
+
+        // SAFETY: The slot is owned by the registration, and its routine was unregistered.
+        unsafe { THREAD_SLOTS.release(self.0) };
+    }
+}
+
+/// An image being mapped, passed to an [`ImageNotifyCallback`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImageLoaded<'a> {
+    /// The full path of the image, if it could be determined.
+    pub image_name: Option<&'a UnicodeString>,
+    /// The process the image is mapped into, or `None` for drivers.
+    pub process_id: Option<ProcessId>,
+    /// The address the image is mapped at.
+    pub base: usize,
+    /// The size of the mapping in bytes.
+    pub size: usize,
+}
+
+/// A callback called when an image is mapped, i.e. a driver is loaded, or an executable or DLL is
+/// mapped into a process.
+///
+/// Callbacks are called at `PASSIVE_LEVEL`, and the context needs interior mutability (e.g.
+/// atomics) to record state. For processes, the callback is called in the context of the process
+/// the image is mapped into, before the image runs.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/nc-ntddk-pload_image_notify_routine
+pub struct ImageNotifyCallback<C> {
+    context: C,
+    callback: fn(&C, &ImageLoaded<'_>),
+}
+
+impl<C: Sync> ImageNotifyCallback<C> {
+    pub const fn new(context: C, callback: fn(&C, &ImageLoaded<'_>)) -> Self {
+        Self { context, callback }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the callback, which is called for all images mapped from then on.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntddk/nf-ntddk-pssetloadimagenotifyroutine
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn register(&'static self) -> Result<ImageNotifyRegistration, NtStatusError> {
+        verify::at_passive_level();
+
+        let slot = IMAGE_SLOTS
+            .claim(self)
+            .ok_or(NtStatusError::STATUS_INSUFFICIENT_RESOURCES)?;
+
+        // SAFETY: The routine of the slot dispatches to `self`, which was stored in the slot
+        // above, and is `'static`.
+        let status = NtStatus::from(unsafe { PsSetLoadImageNotifyRoutine(IMAGE_ROUTINES[slot]) })
+            .result_for("PsSetLoadImageNotifyRoutine");
+
+        if let Err(e) = status {
+            // SAFETY: The routine failed to register.
+            unsafe { IMAGE_SLOTS.release(slot) };
+            return Err(e);
+        }
+
+        Ok(ImageNotifyRegistration(slot))
+    }
+}
+
+/// The type-erased [`ImageNotifyCallback`] stored in a slot.
+trait ImageNotify: Sync {
+    fn notify(&self, image: &ImageLoaded<'_>);
+}
+
+impl<C: Sync> ImageNotify for ImageNotifyCallback<C> {
+    fn notify(&self, image: &ImageLoaded<'_>) {
+        (self.callback)(&self.context, image);
+    }
+}
+
+/// # Safety
+/// Slot `I` must hold the registered callback, and the parameters have to be the ones passed by
+/// the kernel.
+unsafe extern "system" fn image_notify<const I: usize>(
+    full_image_name: PUNICODE_STRING,
+    process_id: HANDLE,
+    image_info: PIMAGE_INFO,
+) {
+    // SAFETY: The caller guarantees that the slot holds the callback.
+    let callback = unsafe { IMAGE_SLOTS.get(I) };
+    // SAFETY: The info is valid while the callback is called.
+    let info = unsafe { &*image_info };
+
+    let image = ImageLoaded {
+        // SAFETY: The name is either null, or valid while the callback is called.
+        image_name: unsafe { full_image_name.as_ref() },
+        process_id: (!process_id.is_null()).then(|| ProcessId::from_handle(process_id)),
+        base: info.ImageBase as usize,
+        size: info.ImageSize as usize,
+    };
+
+    callback.notify(&image);
+}
+
+static IMAGE_SLOTS: Slots<dyn ImageNotify> = Slots::new();
+
+/// The routine registered for each slot of [`IMAGE_SLOTS`].
+const IMAGE_ROUTINES: [km_sys::PLOAD_IMAGE_NOTIFY_ROUTINE; SLOTS] = [
+    Some(image_notify::<0>),
+    Some(image_notify::<1>),
+    Some(image_notify::<2>),
+    Some(image_notify::<3>),
+    Some(image_notify::<4>),
+    Some(image_notify::<5>),
+    Some(image_notify::<6>),
+    Some(image_notify::<7>),
+];
+
+/// A registered [`ImageNotifyCallback`], which is unregistered when this is dropped.
+#[derive(Debug)]
+#[must_use = "the callback is unregistered when the registration is dropped"]
+pub struct ImageNotifyRegistration(usize);
+
+impl Drop for ImageNotifyRegistration {
+    /// Unregisters the callback, waiting for running calls of it to return.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The routine of the slot was registered, and is only unregistered once by virtue
+        // of being a `Drop` implementation.
+        unsafe { PsRemoveLoadImageNotifyRoutine(IMAGE_ROUTINES[self.0]) };
+
+        // SAFETY: The slot is owned by the registration, and its routine was unregistered.
+        unsafe { IMAGE_SLOTS.release(self.0) };
+    }
+}
+
+/// The number of callbacks of each kind that can be registered at the same time.
+const SLOTS: usize = 8;
+
+/// The callbacks of one kind, each of which is dispatched to by the routine of its slot.
+struct Slots<T: ?Sized + 'static>([Slot<T>; SLOTS]);
+
+struct Slot<T: ?Sized + 'static> {
+    taken: AtomicBool,
+    /// Only written while `taken` is set and the routine of the slot isn't registered.
+    callback: UnsafeCell<Option<&'static T>>,
+}
+
+// SAFETY: The callbacks are `Sync`, and `callback` is only written by the owner of the slot, while
+// it's not read.
+unsafe impl<T: ?Sized + Sync + 'static> Sync for Slots<T> {}
+
+impl<T: ?Sized + 'static> Slots<T> {
+    const fn new() -> Self {
+        Self(
+            [const {
+                Slot {
+                    taken: AtomicBool::new(false),
+                    callback: UnsafeCell::new(None),
+                }
+            }; SLOTS],
+        )
+    }
+
+    /// Stores `callback` in a free slot, before its routine is registered.
+    fn claim(&self, callback: &'static T) -> Option<usize> {
+        let index = self
+            .0
+            .iter()
+            .position(|slot| !slot.taken.swap(true, Ordering::Acquire))?;
+
+        // SAFETY: The slot was just taken, and its routine isn't registered.
+        unsafe { *self.0[index].callback.get() = Some(callback) };
+
+        Some(index)
+    }
+
+    /// # Safety
+    /// The routine of slot `index` must be registered.
+    unsafe fn get(&self, index: usize) -> &'static T {
+        // SAFETY: The caller guarantees that the routine is registered, which happens after the
+        // callback is stored, and before it's cleared.
+        unsafe { (*self.0[index].callback.get()).unwrap_unchecked() }
+    }
+
+    /// Frees slot `index`.
+    ///
+    /// # Safety
+    /// The slot must be owned by the caller, and its routine must not be registered.
+    unsafe fn release(&self, index: usize) {
+        let slot = &self.0[index];
+        // SAFETY: The caller guarantees that the routine isn't registered, so the callback isn't
+        // read.
+        unsafe { *slot.callback.get() = None };
+        slot.taken.store(false, Ordering::Release);
+    }
+}
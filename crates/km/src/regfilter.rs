@@ -0,0 +1,557 @@
+//! Registry filtering, e.g. to watch the driver's configuration keys, or to protect them from
+//! being tampered with.
+//!
+//! A [`RegistryFilter`] is called before each registry operation of the system with a
+//! [`PreOperation`], and can deny it by returning [`Decision::Deny`]. After an operation, it's
+//! called with a [`PostOperation`] carrying the outcome. The filter is called in the context of
+//! the thread performing the operation, whose [`Notification::requestor_mode`] tells whether the
+//! operation was requested from user mode.
+//!
+//! Like [power callbacks](crate::power), filters are registered on `static`s. The returned
+//! registration unregisters the filter when dropped, which has to happen before the driver
+//! unloads.
+//!
+//! ```rs, ignore
+//! const SERVICE_KEY: UnicodeString =
+//!     unicode_string!("\\REGISTRY\\MACHINE\\SYSTEM\\CurrentControlSet\\Services\\MyDriver");
+//!
+//! static PROTECT_CONFIG: RegistryFilter<()> =
+//!     RegistryFilter::new(unicode_string!("385200"), (), |_, notification| {
+//!         if notification.requestor_mode != ProcessorMode::UserMode {
+//!             return Decision::Allow;
+//!         }
+//!
+//!         let key = match &notification.operation {
+//!             Operation::Pre(PreOperation::SetValueKey { key, .. })
+//!             | Operation::Pre(PreOperation::DeleteValueKey { key, .. })
+//!             | Operation::Pre(PreOperation::DeleteKey { key }) => key,
+//!             _ => return Decision::Allow,
+//!         };
+//!
+//!         match key.name() {
+//!             Ok(name) if name.is_under(&SERVICE_KEY) => {
+//!                 Decision::Deny(NtStatusError::STATUS_ACCESS_DENIED)
+//!             }
+//!             _ => Decision::Allow,
+//!         }
+//!     });
+//!
+//! // in `DriverEntry`, kept until unload
+//! let registration = PROTECT_CONFIG.register(&driver_object)?;
+//! ```
+//!
+//! The filter is called for every registry operation of the system, so it should return quickly
+//! for operations it isn't interested in, before querying key names.
+//!
+//! See [MSDN] for more details.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/filtering-registry-calls
+
+use crate::{
+    mode::ProcessorMode,
+    shared::{
+        ntstatus::{NtStatus, NtStatusError},
+        strings::UnicodeString,
+    },
+    verify,
+    wdf::registry::ValueType,
+    DriverObjectHandle,
+};
+use core::{
+    ffi::c_void,
+    fmt,
+    marker::PhantomData,
+    ptr::{null_mut, NonNull},
+    slice,
+    sync::atomic::{AtomicBool, AtomicI64, Ordering},
+};
+use km_sys::{
+    CmCallbackGetKeyObjectIDEx, CmCallbackReleaseKeyObjectIDEx, CmRegisterCallbackEx,
+    CmUnRegisterCallback, ExGetPreviousMode, RtlPrefixUnicodeString, LARGE_INTEGER, NTSTATUS,
+    PCUNICODE_STRING, PVOID, REG_CREATE_KEY_INFORMATION, REG_DELETE_KEY_INFORMATION,
+    REG_DELETE_VALUE_KEY_INFORMATION, REG_NOTIFY_CLASS, REG_POST_OPERATION_INFORMATION,
+    REG_RENAME_KEY_INFORMATION, REG_SET_INFORMATION_KEY_INFORMATION,
+    REG_SET_KEY_SECURITY_INFORMATION, REG_SET_VALUE_KEY_INFORMATION,
+};
+
+/// A notification passed to a [`RegistryFilter`].
+#[derive(Debug)]
+pub struct Notification<'a> {
+    /// The mode the operation was requested from, i.e. [`ProcessorMode::UserMode`] for
+    /// operations of user-mode processes.
+    pub requestor_mode: ProcessorMode,
+    pub operation: Operation<'a>,
+}
+
+/// The operation a [`Notification`] is about.
+#[derive(Debug)]
+pub enum Operation<'a> {
+    /// The operation is about to be performed, and can still be denied.
+    Pre(PreOperation<'a>),
+    /// The operation was performed.
+    Post(PostOperation<'a>),
+}
+
+/// A registry operation about to be performed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PreOperation<'a> {
+    /// `key` is about to be deleted (`RegNtPreDeleteKey`).
+    DeleteKey { key: KeyObject<'a> },
+    /// Value `value_name` of `key` is about to be set (`RegNtPreSetValueKey`).
+    ///
+    /// The data itself isn't exposed, as it may still be in user-mode memory.
+    SetValueKey {
+        key: KeyObject<'a>,
+        value_name: &'a UnicodeString,
+        value_type: ValueType,
+        /// The length of the data in bytes.
+        data_len: usize,
+    },
+    /// Value `value_name` of `key` is about to be deleted (`RegNtPreDeleteValueKey`).
+    DeleteValueKey {
+        key: KeyObject<'a>,
+        value_name: &'a UnicodeString,
+    },
+    /// Metadata of `key` is about to be changed (`RegNtPreSetInformationKey`).
+    SetInformationKey { key: KeyObject<'a> },
+    /// `key` is about to be renamed to `new_name` (`RegNtPreRenameKey`).
+    RenameKey {
+        key: KeyObject<'a>,
+        new_name: &'a UnicodeString,
+    },
+    /// The security descriptor of `key` is about to be changed (`RegNtPreSetKeySecurity`).
+    SetKeySecurity { key: KeyObject<'a> },
+    /// A key is about to be created or opened (`RegNtPreCreateKeyEx`), see [`KeyPath`].
+    CreateKey(KeyPath<'a>),
+    /// A key is about to be opened (`RegNtPreOpenKeyEx`), see [`KeyPath`].
+    OpenKey(KeyPath<'a>),
+    /// Any other operation, with the raw `REG_NOTIFY_CLASS`.
+    Other { class: i32 },
+}
+
+/// The path of a key about to be created or opened.
+#[derive(Debug)]
+pub struct KeyPath<'a> {
+    /// The path of the key, which is relative to `root` unless it starts with a backslash.
+    pub complete_name: &'a UnicodeString,
+    /// The key the path is relative to, if any.
+    pub root: Option<KeyObject<'a>>,
+    /// The access requested for the key, e.g. `KEY_WRITE`.
+    pub desired_access: u32,
+}
+
+/// A registry operation that was performed.
+#[derive(Debug)]
+pub struct PostOperation<'a> {
+    /// The `REG_NOTIFY_CLASS` of the operation, e.g. `RegNtPostSetValueKey`.
+    pub class: i32,
+    /// The key the operation was performed on, or for created and opened keys, the new key. Is
+    /// `None` if the operation failed, as the key is only valid if it succeeded.
+    pub key: Option<KeyObject<'a>>,
+    /// The outcome of the operation.
+    pub status: NtStatus,
+}
+
+/// What a [`RegistryFilter`] decides for an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// The operation is performed, or for [`Operation::Post`], its outcome is left unchanged.
+    Allow,
+    /// The operation isn't performed, and fails with the status instead. Is ignored for
+    /// [`Operation::Post`].
+    Deny(NtStatusError),
+}
+
+/// A registry key object passed to a [`RegistryFilter`], which is only valid during the call.
+pub struct KeyObject<'a> {
+    object: NonNull<c_void>,
+    cookie: &'a AtomicI64,
+}
+
+impl<'a> KeyObject<'a> {
+    fn new(object: PVOID, cookie: &'a AtomicI64) -> Option<Self> {
+        Some(Self {
+            object: NonNull::new(object)?,
+            cookie,
+        })
+    }
+
+    /// Queries the full path of the key, e.g. `\REGISTRY\MACHINE\SOFTWARE\Vendor`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-cmcallbackgetkeyobjectidex
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn name(&self) -> Result<KeyName<'a>, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut name: PCUNICODE_STRING = null_mut();
+
+        // SAFETY: The cookie is the one of the filter the object was passed to, which is
+        // registered while it's called. `name` is an out parameter, and the object ID isn't
+        // requested.
+        NtStatus::from(unsafe {
+            CmCallbackGetKeyObjectIDEx(
+                self.cookie.as_ptr().cast(),
+                self.object.as_ptr(),
+                null_mut(),
+                &mut name,
+                0,
+            )
+        })
+        .result_for("CmCallbackGetKeyObjectIDEx")?;
+
+        Ok(KeyName {
+            name: NonNull::new(name.cast_mut()).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?,
+            _key: PhantomData,
+        })
+    }
+}
+
+impl fmt::Debug for KeyObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("KeyObject").field(&self.object).finish()
+    }
+}
+
+/// The full path of a [`KeyObject`], which is released when this is dropped.
+pub struct KeyName<'a> {
+    name: NonNull<UnicodeString>,
+    _key: PhantomData<&'a ()>,
+}
+
+impl KeyName<'_> {
+    pub fn as_unicode_string(&self) -> &UnicodeString {
+        // SAFETY: The name is valid until it's released on drop.
+        unsafe { self.name.as_ref() }
+    }
+
+    /// Returns whether the key is `path` or one of its subkeys, comparing case-insensitively.
+    ///
+    /// `path` has to be a full path without a trailing backslash, e.g.
+    /// `\REGISTRY\MACHINE\SOFTWARE\Vendor`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn is_under(&self, path: &UnicodeString) -> bool {
+        verify::at_passive_level();
+
+        // SAFETY: Both strings are valid for the call.
+        if unsafe { RtlPrefixUnicodeString(path, self.name.as_ptr(), 1) } == 0 {
+            return false;
+        }
+
+        // `\Vendor` is a prefix of `\VendorTools`, which isn't a subkey
+        let prefix_len = usize::from(path.Length) / 2;
+        matches!(
+            wide(self.as_unicode_string()).get(prefix_len),
+            None | Some(&0x5c)
+        )
+    }
+}
+
+impl fmt::Display for KeyName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in char::decode_utf16(wide(self.as_unicode_string()).iter().copied()) {
+            fmt::Write::write_char(f, c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for KeyName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+impl Drop for KeyName<'_> {
+    fn drop(&mut self) {
+        // SAFETY: The name was returned by `CmCallbackGetKeyObjectIDEx`, and is only released
+        // once by virtue of being a `Drop` implementation.
+        unsafe { CmCallbackReleaseKeyObjectIDEx(self.name.as_ptr()) };
+    }
+}
+
+fn wide(s: &UnicodeString) -> &[u16] {
+    if s.Buffer.is_null() {
+        &[]
+    } else {
+        // SAFETY: The strings passed to filters are valid for the call, and their buffer holds
+        // `Length` bytes.
+        unsafe { slice::from_raw_parts(s.Buffer, usize::from(s.Length) / 2) }
+    }
+}
+
+/// A filter called for registry operations, see the [module documentation](self).
+///
+/// The filter is called at `PASSIVE_LEVEL`, and the context needs interior mutability (e.g.
+/// atomics) to record state.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nc-wdm-ex_callback_function
+pub struct RegistryFilter<C> {
+    altitude: UnicodeString,
+    context: C,
+    callback: fn(&C, &Notification<'_>) -> Decision,
+    /// The `LARGE_INTEGER` cookie identifying the registration.
+    cookie: AtomicI64,
+    registered: AtomicBool,
+}
+
+// SAFETY: The altitude is only ever read, and the rest is synchronized.
+unsafe impl<C: Sync> Sync for RegistryFilter<C> {}
+
+impl<C: Sync> RegistryFilter<C> {
+    /// `altitude` is the position of the filter relative to other filters, which has to be
+    /// [allocated by Microsoft][MSDN] for production drivers.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ifs/load-order-groups-and-altitudes-for-minifilter-drivers
+    pub const fn new(
+        altitude: UnicodeString,
+        context: C,
+        callback: fn(&C, &Notification<'_>) -> Decision,
+    ) -> Self {
+        Self {
+            altitude,
+            context,
+            callback,
+            cookie: AtomicI64::new(0),
+            registered: AtomicBool::new(false),
+        }
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Registers the filter, which is called for all registry operations from then on.
+    ///
+    /// Fails with `STATUS_ALREADY_REGISTERED` if the filter is already registered.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-cmregistercallbackex
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn register(
+        &'static self,
+        driver_object: &DriverObjectHandle,
+    ) -> Result<RegistryFilterRegistration, NtStatusError> {
+        verify::at_passive_level();
+
+        if self.registered.swap(true, Ordering::AcqRel) {
+            return Err(NtStatusError::STATUS_ALREADY_REGISTERED);
+        }
+
+        // SAFETY: The altitude is only read during the call, and the cookie is written to
+        // `self.cookie`, which is `'static` like `self`. The callback expects the context to be a
+        // `RegistryFilter<C>`.
+        let status = NtStatus::from(unsafe {
+            CmRegisterCallbackEx(
+                Some(registry_callback::<C>),
+                &self.altitude,
+                driver_object.0.cast(),
+                (self as *const Self).cast_mut().cast(),
+                self.cookie.as_ptr().cast::<LARGE_INTEGER>(),
+                null_mut(),
+            )
+        })
+        .result_for("CmRegisterCallbackEx");
+
+        if let Err(e) = status {
+            self.registered.store(false, Ordering::Release);
+            return Err(e);
+        }
+
+        Ok(RegistryFilterRegistration {
+            cookie: &self.cookie,
+            registered: &self.registered,
+        })
+    }
+}
+
+/// # Safety
+/// `context` must point to a `RegistryFilter<C>`, and the other parameters have to be the ones
+/// passed by the kernel.
+unsafe extern "system" fn registry_callback<C>(
+    context: PVOID,
+    argument1: PVOID,
+    argument2: PVOID,
+) -> NTSTATUS {
+    // SAFETY: The caller guarantees that `context` is a `RegistryFilter<C>`.
+    let filter = unsafe { &*context.cast::<RegistryFilter<C>>() };
+
+    // SAFETY: The filter is called in the context of the thread performing the operation.
+    let requestor_mode =
+        unsafe { ProcessorMode::from_kprocessor_mode_unchecked(ExGetPreviousMode()) };
+
+    // the class is a number passed as a pointer
+    let class = REG_NOTIFY_CLASS(argument1 as usize as i32);
+    // SAFETY: The kernel passes the structure matching `class`.
+    let Some(operation) = (unsafe { Operation::from_raw(class, argument2, &filter.cookie) }) else {
+        return NtStatus::STATUS_SUCCESS.into();
+    };
+
+    let notification = Notification {
+        requestor_mode,
+        operation,
+    };
+
+    match (
+        (filter.callback)(&filter.context, &notification),
+        notification.operation,
+    ) {
+        (Decision::Deny(status), Operation::Pre(_)) => NtStatus::from(status).into(),
+        _ => NtStatus::STATUS_SUCCESS.into(),
+    }
+}
+
+impl<'a> Operation<'a> {
+    /// Returns `None` for notifications that aren't about an operation, i.e.
+    /// `RegNtCallbackObjectContextCleanup`.
+    ///
+    /// # Safety
+    /// `info` must point to the structure matching `class`, which is valid for `'a`.
+    unsafe fn from_raw(
+        class: REG_NOTIFY_CLASS,
+        info: PVOID,
+        cookie: &'a AtomicI64,
+    ) -> Option<Self> {
+        let key = |object| KeyObject::new(object, cookie);
+
+        // SAFETY: The caller guarantees that `info` is the structure of `class` in all arms.
+        // The strings it points to are captured by the kernel.
+        let pre = unsafe {
+            match class {
+                REG_NOTIFY_CLASS::RegNtPreDeleteKey => {
+                    let info = &*info.cast::<REG_DELETE_KEY_INFORMATION>();
+                    PreOperation::DeleteKey {
+                        key: key(info.Object)?,
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtPreSetValueKey => {
+                    let info = &*info.cast::<REG_SET_VALUE_KEY_INFORMATION>();
+                    PreOperation::SetValueKey {
+                        key: key(info.Object)?,
+                        value_name: &*info.ValueName,
+                        value_type: ValueType(info.Type),
+                        data_len: info.DataSize as usize,
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtPreDeleteValueKey => {
+                    let info = &*info.cast::<REG_DELETE_VALUE_KEY_INFORMATION>();
+                    PreOperation::DeleteValueKey {
+                        key: key(info.Object)?,
+                        value_name: &*info.ValueName,
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtPreSetInformationKey => {
+                    let info = &*info.cast::<REG_SET_INFORMATION_KEY_INFORMATION>();
+                    PreOperation::SetInformationKey {
+                        key: key(info.Object)?,
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtPreRenameKey => {
+                    let info = &*info.cast::<REG_RENAME_KEY_INFORMATION>();
+                    PreOperation::RenameKey {
+                        key: key(info.Object)?,
+                        new_name: &*info.NewName,
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtPreSetKeySecurity => {
+                    let info = &*info.cast::<REG_SET_KEY_SECURITY_INFORMATION>();
+                    PreOperation::SetKeySecurity {
+                        key: key(info.Object)?,
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtPreCreateKeyEx | REG_NOTIFY_CLASS::RegNtPreOpenKeyEx => {
+                    let info = &*info.cast::<REG_CREATE_KEY_INFORMATION>();
+                    let path = KeyPath {
+                        complete_name: &*info.CompleteName,
+                        root: key(info.RootObject),
+                        desired_access: info.DesiredAccess,
+                    };
+
+                    if class == REG_NOTIFY_CLASS::RegNtPreCreateKeyEx {
+                        PreOperation::CreateKey(path)
+                    } else {
+                        PreOperation::OpenKey(path)
+                    }
+                }
+                REG_NOTIFY_CLASS::RegNtCallbackObjectContextCleanup => return None,
+                _ if is_post(class) => {
+                    let info = &*info.cast::<REG_POST_OPERATION_INFORMATION>();
+                    // `Object` is only valid if the operation succeeded (`NT_SUCCESS`)
+                    let succeeded = info.Status >= 0;
+                    return Some(Self::Post(PostOperation {
+                        class: class.0,
+                        key: succeeded.then(|| key(info.Object)).flatten(),
+                        status: NtStatus::from(info.Status),
+                    }));
+                }
+                _ => PreOperation::Other { class: class.0 },
+            }
+        };
+
+        Some(Self::Pre(pre))
+    }
+}
+
+/// Whether `class` is a post-operation notification, which are passed a
+/// `REG_POST_OPERATION_INFORMATION`.
+fn is_post(class: REG_NOTIFY_CLASS) -> bool {
+    matches!(
+        class,
+        REG_NOTIFY_CLASS::RegNtPostDeleteKey
+            | REG_NOTIFY_CLASS::RegNtPostSetValueKey
+            | REG_NOTIFY_CLASS::RegNtPostDeleteValueKey
+            | REG_NOTIFY_CLASS::RegNtPostSetInformationKey
+            | REG_NOTIFY_CLASS::RegNtPostRenameKey
+            | REG_NOTIFY_CLASS::RegNtPostEnumerateKey
+            | REG_NOTIFY_CLASS::RegNtPostEnumerateValueKey
+            | REG_NOTIFY_CLASS::RegNtPostQueryKey
+            | REG_NOTIFY_CLASS::RegNtPostQueryValueKey
+            | REG_NOTIFY_CLASS::RegNtPostQueryMultipleValueKey
+            | REG_NOTIFY_CLASS::RegNtPostKeyHandleClose
+            | REG_NOTIFY_CLASS::RegNtPostCreateKeyEx
+            | REG_NOTIFY_CLASS::RegNtPostOpenKeyEx
+            | REG_NOTIFY_CLASS::RegNtPostFlushKey
+            | REG_NOTIFY_CLASS::RegNtPostLoadKey
+            | REG_NOTIFY_CLASS::RegNtPostUnLoadKey
+            | REG_NOTIFY_CLASS::RegNtPostQueryKeySecurity
+            | REG_NOTIFY_CLASS::RegNtPostSetKeySecurity
+            | REG_NOTIFY_CLASS::RegNtPostRestoreKey
+            | REG_NOTIFY_CLASS::RegNtPostSaveKey
+            | REG_NOTIFY_CLASS::RegNtPostReplaceKey
+            | REG_NOTIFY_CLASS::RegNtPostQueryKeyName
+    )
+}
+
+/// A registered [`RegistryFilter`], which is unregistered when this is dropped.
+#[derive(Debug)]
+#[must_use = "the filter is unregistered when the registration is dropped"]
+pub struct RegistryFilterRegistration {
+    cookie: &'static AtomicI64,
+    registered: &'static AtomicBool,
+}
+
+impl Drop for RegistryFilterRegistration {
+    /// Unregisters the filter, waiting for running calls of it to return.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        let cookie = LARGE_INTEGER {
+            QuadPart: self.cookie.load(Ordering::Acquire),
+        };
+
+        // SAFETY: The cookie was returned by `CmRegisterCallbackEx`, and is only unregistered
+        // once by virtue of being a `Drop` implementation.
+        unsafe { CmUnRegisterCallback(cookie) };
+
+        self.registered.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,452 @@
+//! Section objects, i.e. memory shared between the driver and user mode.
+//!
+//! A [`Section`] is either created by the driver with a name that user mode opens (with
+//! `OpenFileMapping`), or opened from a handle that user mode passes in a request. Either way, the
+//! driver accesses the memory through a typed [`SectionView`], which is mapped into system space,
+//! so it can be used from any process context:
+//!
+//! ```rs, ignore
+//! #[derive(Clone, Copy, Pod, Zeroable)]
+//! #[repr(C)]
+//! struct Telemetry {
+//!     sequence: u32,
+//!     fan_rpm: [u32; 8],
+//! }
+//!
+//! let section = Section::create(&name, size_of::<Telemetry>(), Some(&security_descriptor))?;
+//! let view = section.map::<Telemetry>()?;
+//! view.access().modify(|mut telemetry| {
+//!     telemetry.sequence += 1;
+//!     telemetry
+//! });
+//! ```
+//!
+//! As user mode can write the memory at any time, views only give
+//! [volatile access](VolatileAccess) to types that are valid for any bytes ([`Pod`]). The memory
+//! is pageable, so views can only be accessed at `IRQL <= APC_LEVEL`.
+
+use crate::{
+    io_mmap::{Access, ReadOnly, ReadWrite, VolatileAccess},
+    mode::ProcessorMode,
+    object_attributes::{ObjectAttributes, ObjectAttributesFlags},
+    verify, AsRawMutPtr,
+};
+use bytemuck::Pod;
+use core::{
+    ffi::c_void,
+    fmt,
+    marker::PhantomData,
+    mem::size_of,
+    ptr::{null_mut, NonNull},
+};
+use km_shared::{
+    ntstatus::{NtStatus, NtStatusError},
+    strings::UnicodeString,
+};
+use km_sys::{
+    MmMapViewInSystemSpace, MmSectionObjectType, MmUnmapViewInSystemSpace, ObOpenObjectByPointer,
+    ObReferenceObjectByHandle, ObfDereferenceObject, ZwClose, ZwCreateSection, ZwMapViewOfSection,
+    ZwUnmapViewOfSection, ACCESS_MASK, APC_LEVEL, HANDLE, KIRQL, LARGE_INTEGER, OBJ_KERNEL_HANDLE,
+    PAGE_READONLY, PAGE_READWRITE, SECTION_INHERIT, SECTION_MAP_READ, SECTION_MAP_WRITE,
+    SECTION_QUERY, SECURITY_DESCRIPTOR, SEC_COMMIT, SIZE_T, ULONG,
+};
+
+/// The access to a [`Section`] and its views, either [`ReadOnly`] or [`ReadWrite`].
+pub trait ViewAccess: Access {
+    /// The access requested for the section object.
+    const DESIRED_ACCESS: ACCESS_MASK;
+    /// The protection of views mapped into a process.
+    const PAGE_PROTECTION: ULONG;
+}
+
+impl ViewAccess for ReadOnly {
+    const DESIRED_ACCESS: ACCESS_MASK = SECTION_MAP_READ | SECTION_QUERY;
+    const PAGE_PROTECTION: ULONG = PAGE_READONLY;
+}
+
+impl ViewAccess for ReadWrite {
+    const DESIRED_ACCESS: ACCESS_MASK = SECTION_MAP_READ | SECTION_MAP_WRITE | SECTION_QUERY;
+    const PAGE_PROTECTION: ULONG = PAGE_READWRITE;
+}
+
+/// A referenced section object with a kernel handle, closed on drop. See the
+/// [module documentation](self) for an overview.
+///
+/// Must be dropped at `PASSIVE_LEVEL`, after all of its views.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/section-objects-and-views
+pub struct Section<A> {
+    handle: HANDLE,
+    object: NonNull<c_void>,
+    _access: PhantomData<A>,
+}
+
+// SAFETY: The handle is a kernel handle, which is valid in any process context, and the object is
+// referenced until the section is dropped.
+unsafe impl<A> Send for Section<A> {}
+// SAFETY: See above. Shared references only map views, which the kernel synchronizes.
+unsafe impl<A> Sync for Section<A> {}
+
+// manual implementation because the `A`ccess type is not necessarily `Debug`
+impl<A> fmt::Debug for Section<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Section")
+            .field("handle", &self.handle)
+            .field("object", &self.object)
+            .finish()
+    }
+}
+
+impl Section<ReadWrite> {
+    /// Creates a section of `size` bytes backed by the paging file, named e.g.
+    /// `\BaseNamedObjects\MyDriverTelemetry`, which user mode can open as
+    /// `Global\MyDriverTelemetry`. The memory is zeroed.
+    ///
+    /// Without a `security_descriptor`, the section gets the default security of the object
+    /// directory, which usually doesn't allow user mode to open it.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-zwcreatesection
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn create(
+        name: &UnicodeString,
+        size: usize,
+        security_descriptor: Option<&SECURITY_DESCRIPTOR>,
+    ) -> Result<Self, NtStatusError> {
+        verify::at_passive_level();
+
+        // SAFETY: The name and the security descriptor outlive the attributes.
+        let mut attributes = unsafe {
+            ObjectAttributes::initialize(
+                name,
+                ObjectAttributesFlags::default(),
+                None,
+                security_descriptor,
+            )
+        };
+        let mut maximum_size = LARGE_INTEGER {
+            QuadPart: size as i64,
+        };
+        let mut handle: HANDLE = null_mut();
+
+        // SAFETY: All pointers are valid, and a null file handle creates a section backed by the
+        // paging file.
+        NtStatus::from(unsafe {
+            ZwCreateSection(
+                &mut handle,
+                ReadWrite::DESIRED_ACCESS,
+                attributes.as_raw_mut_ptr(),
+                &mut maximum_size,
+                PAGE_READWRITE,
+                SEC_COMMIT,
+                null_mut(),
+            )
+        })
+        .result_for("ZwCreateSection")?;
+
+        // SAFETY: The handle was just created as a kernel handle of a section.
+        unsafe { Self::from_kernel_handle(handle) }
+    }
+}
+
+impl<A: ViewAccess> Section<A> {
+    /// Opens the section of `handle`, which user mode passed e.g. in the input buffer of an I/O
+    /// control request, with the access `A`. The handle is checked like the requestor would be,
+    /// i.e. for user mode requestors, it has to be a section handle granting that access.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, in the context of the process the handle belongs to,
+    /// e.g. in an [`EvtIoInCallerContext`](crate::wdf::device_init::EvtIoInCallerContext)
+    /// callback.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-obreferenceobjectbyhandle
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn from_user_handle(
+        handle: usize,
+        requestor_mode: ProcessorMode,
+    ) -> Result<Self, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut object = null_mut();
+
+        // SAFETY: The handle is validated by the object manager, and checked to be a section
+        // granting the access for the requestor.
+        NtStatus::from(unsafe {
+            ObReferenceObjectByHandle(
+                handle as HANDLE,
+                A::DESIRED_ACCESS,
+                *MmSectionObjectType,
+                requestor_mode.into(),
+                &mut object,
+                null_mut(),
+            )
+        })
+        .result_for("ObReferenceObjectByHandle")?;
+
+        let object = NonNull::new(object).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?;
+
+        // The user handle can be closed at any time, so a kernel handle is opened for mapping.
+        let mut kernel_handle: HANDLE = null_mut();
+
+        // SAFETY: The object is a referenced section, and the handle is an out parameter.
+        let status = NtStatus::from(unsafe {
+            ObOpenObjectByPointer(
+                object.as_ptr(),
+                OBJ_KERNEL_HANDLE,
+                null_mut(),
+                A::DESIRED_ACCESS,
+                *MmSectionObjectType,
+                ProcessorMode::KernelMode.into(),
+                &mut kernel_handle,
+            )
+        })
+        .result_for("ObOpenObjectByPointer");
+
+        if let Err(e) = status {
+            // SAFETY: The object was referenced above.
+            unsafe { ObfDereferenceObject(object.as_ptr()) };
+            return Err(e);
+        }
+
+        Ok(Self {
+            handle: kernel_handle,
+            object,
+            _access: PhantomData,
+        })
+    }
+
+    /// Maps the whole section into system space, for accessing it as a `T`. Fails with
+    /// `STATUS_SECTION_TOO_BIG` if `T` is larger than the section.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-mmmapviewinsystemspace
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn map<T: Pod>(&self) -> Result<SectionView<'_, T, A>, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut base = null_mut();
+        // zero maps the whole section
+        let mut size: SIZE_T = 0;
+
+        // SAFETY: The object is a referenced section, and the base and size are out parameters.
+        NtStatus::from(unsafe {
+            MmMapViewInSystemSpace(self.object.as_ptr(), &mut base, &mut size)
+        })
+        .result_for("MmMapViewInSystemSpace")?;
+
+        let view = SectionView {
+            ptr: NonNull::new(base.cast()).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?,
+            len: size as usize,
+            _section: PhantomData,
+        };
+
+        // Views are page-aligned, so only the size has to be checked.
+        if view.len < size_of::<T>() {
+            return Err(NtStatusError::STATUS_SECTION_TOO_BIG);
+        }
+
+        Ok(view)
+    }
+
+    /// Maps the whole section into the current process, e.g. to return its address to the
+    /// requestor of an I/O control request. The view belongs to the process, which unmaps it with
+    /// `UnmapViewOfFile`, or when it exits.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, in the context of the process, e.g. in an
+    /// [`EvtIoInCallerContext`](crate::wdf::device_init::EvtIoInCallerContext) callback.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-zwmapviewofsection
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn map_into_current_process(&self) -> Result<UserView, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut base = null_mut();
+        let mut size: SIZE_T = 0;
+
+        // SAFETY: The handle is a kernel handle of a section, and the base and size are out
+        // parameters. The view isn't inherited by child processes.
+        NtStatus::from(unsafe {
+            ZwMapViewOfSection(
+                self.handle,
+                current_process(),
+                &mut base,
+                0,
+                0,
+                null_mut(),
+                &mut size,
+                SECTION_INHERIT::ViewUnmap,
+                0,
+                A::PAGE_PROTECTION,
+            )
+        })
+        .result_for("ZwMapViewOfSection")?;
+
+        Ok(UserView {
+            address: base as usize,
+            len: size as usize,
+        })
+    }
+}
+
+impl<A> Section<A> {
+    /// # Safety
+    /// `handle` must be a kernel handle of a section, which is closed when this is dropped.
+    unsafe fn from_kernel_handle(handle: HANDLE) -> Result<Self, NtStatusError> {
+        let mut object = null_mut();
+
+        // SAFETY: The caller guarantees that the handle is a kernel handle of a section, so no
+        // access checks are needed.
+        let status = NtStatus::from(unsafe {
+            ObReferenceObjectByHandle(
+                handle,
+                0,
+                *MmSectionObjectType,
+                ProcessorMode::KernelMode.into(),
+                &mut object,
+                null_mut(),
+            )
+        })
+        .result_for("ObReferenceObjectByHandle");
+
+        match status.map(|_| NonNull::new(object)) {
+            Ok(Some(object)) => Ok(Self {
+                handle,
+                object,
+                _access: PhantomData,
+            }),
+            status => {
+                // SAFETY: The caller transferred ownership of the handle.
+                unsafe { ZwClose(handle) };
+                Err(status.err().unwrap_or(NtStatusError::STATUS_UNSUCCESSFUL))
+            }
+        }
+    }
+}
+
+impl<A> Drop for Section<A> {
+    /// Releases the section, which is deleted once user mode closed its handles and views, too.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn drop(&mut self) {
+        // SAFETY: The object was referenced, and the handle opened, when the section was created.
+        // Both are only released once by virtue of being a `Drop` implementation.
+        unsafe {
+            ObfDereferenceObject(self.object.as_ptr());
+            ZwClose(self.handle);
+        }
+    }
+}
+
+/// A view of a [`Section`] in system space, unmapped on drop. See [`Section::map`].
+///
+/// Must be dropped at `PASSIVE_LEVEL`.
+pub struct SectionView<'a, T, A> {
+    ptr: NonNull<T>,
+    len: usize,
+    _section: PhantomData<&'a Section<A>>,
+}
+
+// SAFETY: The view is in system space, which is valid in any process context, and only accessed
+// volatilely.
+unsafe impl<T: Send, A> Send for SectionView<'_, T, A> {}
+// SAFETY: See above.
+unsafe impl<T: Sync, A> Sync for SectionView<'_, T, A> {}
+
+// manual implementation because the `A`ccess type is not necessarily `Debug`
+impl<T, A> fmt::Debug for SectionView<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SectionView")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T, A> SectionView<'_, T, A> {
+    /// Returns the raw pointer to the view.
+    ///
+    /// Note that the returned pointer is *not* bound to the lifetime of this value, so extreme
+    /// caution has to be taken when using this pointer.
+    pub fn ptr(&self) -> NonNull<T> {
+        self.ptr
+    }
+
+    /// The size of the view, which is the size of the section rounded up to whole pages, and at
+    /// least the size of `T`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Always `false`, as views are at least a page.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Pod, A: Access> SectionView<'_, T, A> {
+    /// Gives volatile access to the view.
+    ///
+    /// The view is backed by pageable memory, so it (and the returned access) must only be used at
+    /// `IRQL <= APC_LEVEL`, e.g. not from DPCs or spin lock guarded code.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn access(&self) -> VolatileAccess<'_, T, A> {
+        verify::irql_at_most(APC_LEVEL as KIRQL);
+
+        // SAFETY: The view is valid and large enough for a `T` until it's dropped, and `T` is
+        // valid for any bytes, so user mode writes and tearing can't produce invalid values.
+        unsafe { VolatileAccess::new(self.ptr) }
+    }
+}
+
+impl<T, A> Drop for SectionView<'_, T, A> {
+    fn drop(&mut self) {
+        // SAFETY: The view was mapped by `MmMapViewInSystemSpace`, and is only unmapped once by
+        // virtue of being a `Drop` implementation.
+        unsafe { MmUnmapViewInSystemSpace(self.ptr.as_ptr().cast()) };
+    }
+}
+
+/// A view of a [`Section`] in a process, see [`Section::map_into_current_process`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserView {
+    /// The user mode address of the view.
+    pub address: usize,
+    /// The size of the view, which is the size of the section rounded up to whole pages.
+    pub len: usize,
+}
+
+impl UserView {
+    /// Unmaps the view from the current process, e.g. if the request it was mapped for failed.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// # Safety
+    /// The current process must be the one the view was mapped into, and the view mustn't have
+    /// been unmapped already.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn unmap(self) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        // SAFETY: The caller guarantees that the view is mapped into the current process.
+        NtStatus::from(unsafe { ZwUnmapViewOfSection(current_process(), self.address as _) })
+            .result_for("ZwUnmapViewOfSection")?;
+
+        Ok(())
+    }
+}
+
+/// The `ZwCurrentProcess()` pseudo handle.
+fn current_process() -> HANDLE {
+    -1isize as HANDLE
+}
@@ -0,0 +1,233 @@
+//! Kernel-mode access to serial ports, e.g. for embedded controllers behind a UART.
+//!
+//! A [`SerialPort`] wraps an [`IoTarget`] opened on a serial port (e.g. `\Device\Serial0`, or
+//! `\DosDevices\COM3` for a port by its COM name), and configures it with the standard serial
+//! IOCTLs of [`km_shared::ioctl::serial`]. Data is exchanged with plain reads and writes:
+//!
+//! ```rs, ignore
+//! let port = SerialPort::open(&device, &name)?;
+//! port.set_baud_rate(115_200)?;
+//! port.set_line_control(SerialLineControl::EIGHT_N_ONE)?;
+//! // reads return after 50 ms without new data
+//! port.set_timeouts(&SerialTimeouts {
+//!     read_interval_timeout: 50,
+//!     read_total_timeout_constant: 500,
+//!     ..SerialTimeouts::default()
+//! })?;
+//! port.purge(SerialPurgeMask::ALL)?;
+//!
+//! port.write(b"STATUS\r\n", &RequestSendOptions::default())?;
+//! let len = port.read(&mut response, &RequestSendOptions::default())?;
+//! ```
+//!
+//! Serial ports are opened exclusively, so opening a port fails with `STATUS_ACCESS_DENIED` while
+//! another client (in kernel or user mode) has it open.
+//!
+//! See [Serial Controller Drivers][MSDN] for more information.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/serports/
+
+use crate::wdf::{
+    device::Device,
+    io_target::{IoTarget, IoTargetOpenParamsInit, RequestSendOptions, SendIoctlError},
+};
+use bytemuck::{CheckedBitPattern, NoUninit};
+use km_shared::{
+    ioctl::{serial::*, TypedIoControlCode},
+    ntstatus::NtStatusError,
+    strings::UnicodeString,
+};
+use km_sys::{GENERIC_READ, GENERIC_WRITE};
+use snafu::{ResultExt, Snafu};
+
+pub use km_shared::ioctl::serial::{
+    Parity, SerialLineControl, SerialPurgeMask, SerialTimeouts, StopBits,
+};
+
+/// An error returned by [`SerialPort`].
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum SerialError {
+    #[snafu(display("{operation} failed"))]
+    NtStatus {
+        operation: &'static str,
+        source: NtStatusError,
+    },
+    #[snafu(display("{operation} failed"))]
+    Ioctl {
+        operation: &'static str,
+        source: SendIoctlError,
+    },
+}
+
+/// An opened serial port.
+#[derive(Debug)]
+pub struct SerialPort {
+    target: IoTarget,
+}
+
+impl SerialPort {
+    /// Opens the serial port with the given name for reading and writing, as a remote I/O target
+    /// of `device`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn open(device: &Device, name: &UnicodeString) -> Result<Self, SerialError> {
+        let mut target = IoTarget::create(device, None).context(serial_error::NtStatusSnafu {
+            operation: "creating the I/O target",
+        })?;
+
+        let init = IoTargetOpenParamsInit::ByName {
+            device_name: name,
+            desired_access: GENERIC_READ | GENERIC_WRITE,
+        };
+        // SAFETY: The target is opened by name, so there are no pointers to keep valid.
+        let params = unsafe { init.build() };
+        target.open(&params).context(serial_error::NtStatusSnafu {
+            operation: "opening the serial port",
+        })?;
+
+        Ok(Self::from_io_target(target))
+    }
+
+    /// Uses an already opened I/O target of a serial port, e.g. the default I/O target of a filter
+    /// driver on a port's stack.
+    pub fn from_io_target(target: IoTarget) -> Self {
+        Self { target }
+    }
+
+    /// The I/O target of the port, e.g. for sending other serial IOCTLs.
+    pub fn io_target(&self) -> &IoTarget {
+        &self.target
+    }
+
+    /// Sets the baud rate, in bits per second.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_baud_rate(&self, baud_rate: u32) -> Result<(), SerialError> {
+        self.ioctl(
+            IOCTL_SERIAL_SET_BAUD_RATE,
+            &SerialBaudRate { baud_rate },
+            "IOCTL_SERIAL_SET_BAUD_RATE",
+        )
+    }
+
+    /// Gets the baud rate, in bits per second.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn baud_rate(&self) -> Result<u32, SerialError> {
+        self.ioctl(
+            IOCTL_SERIAL_GET_BAUD_RATE,
+            &(),
+            "IOCTL_SERIAL_GET_BAUD_RATE",
+        )
+        .map(|rate| rate.baud_rate)
+    }
+
+    /// Sets the word length, parity and stop bits.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_line_control(&self, line_control: SerialLineControl) -> Result<(), SerialError> {
+        self.ioctl(
+            IOCTL_SERIAL_SET_LINE_CONTROL,
+            &line_control,
+            "IOCTL_SERIAL_SET_LINE_CONTROL",
+        )
+    }
+
+    /// Gets the word length, parity and stop bits.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn line_control(&self) -> Result<SerialLineControl, SerialError> {
+        self.ioctl(
+            IOCTL_SERIAL_GET_LINE_CONTROL,
+            &(),
+            "IOCTL_SERIAL_GET_LINE_CONTROL",
+        )
+    }
+
+    /// Sets the timeouts of [reads](Self::read) and [writes](Self::write).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_timeouts(&self, timeouts: &SerialTimeouts) -> Result<(), SerialError> {
+        self.ioctl(
+            IOCTL_SERIAL_SET_TIMEOUTS,
+            timeouts,
+            "IOCTL_SERIAL_SET_TIMEOUTS",
+        )
+    }
+
+    /// Gets the timeouts of [reads](Self::read) and [writes](Self::write).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn timeouts(&self) -> Result<SerialTimeouts, SerialError> {
+        self.ioctl(IOCTL_SERIAL_GET_TIMEOUTS, &(), "IOCTL_SERIAL_GET_TIMEOUTS")
+    }
+
+    /// Cancels pending requests and/or discards buffered data, e.g. stale bytes before sending a
+    /// command.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn purge(&self, mask: SerialPurgeMask) -> Result<(), SerialError> {
+        self.ioctl(IOCTL_SERIAL_PURGE, &mask, "IOCTL_SERIAL_PURGE")
+    }
+
+    /// Sets or clears the data terminal ready (DTR) line, which some devices use as a reset line.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_dtr(&self, asserted: bool) -> Result<(), SerialError> {
+        if asserted {
+            self.ioctl(IOCTL_SERIAL_SET_DTR, &(), "IOCTL_SERIAL_SET_DTR")
+        } else {
+            self.ioctl(IOCTL_SERIAL_CLR_DTR, &(), "IOCTL_SERIAL_CLR_DTR")
+        }
+    }
+
+    /// Sets or clears the request to send (RTS) line.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn set_rts(&self, asserted: bool) -> Result<(), SerialError> {
+        if asserted {
+            self.ioctl(IOCTL_SERIAL_SET_RTS, &(), "IOCTL_SERIAL_SET_RTS")
+        } else {
+            self.ioctl(IOCTL_SERIAL_CLR_RTS, &(), "IOCTL_SERIAL_CLR_RTS")
+        }
+    }
+
+    /// Reads received data into `buffer`, and returns the number of bytes read. When a read
+    /// completes depends on the port's [timeouts](Self::set_timeouts).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn read(
+        &self,
+        buffer: &mut [u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, SerialError> {
+        self.target
+            .send_read_synchronously(None, buffer, options)
+            .context(serial_error::NtStatusSnafu {
+                operation: "reading from the serial port",
+            })
+    }
+
+    /// Writes `data` to the port, and returns the number of bytes written.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn write(&self, data: &[u8], options: &RequestSendOptions) -> Result<usize, SerialError> {
+        self.target
+            .send_write_synchronously(None, data, options)
+            .context(serial_error::NtStatusSnafu {
+                operation: "writing to the serial port",
+            })
+    }
+
+    fn ioctl<I: NoUninit, O: CheckedBitPattern>(
+        &self,
+        ioctl: TypedIoControlCode<I, O>,
+        input: &I,
+        operation: &'static str,
+    ) -> Result<O, SerialError> {
+        self.target
+            .send_typed_ioctl_synchronously(ioctl, input, &RequestSendOptions::default())
+            .context(serial_error::IoctlSnafu { operation })
+    }
+}
@@ -0,0 +1,442 @@
+//! Issuing SMBus transactions, either directly through an SMBus host controller, or through
+//! another driver owning the bus.
+//!
+//! All buses implement [`SmBus`], which provides the usual transactions on top of
+//! [`SmBus::execute`]:
+//!
+//! ```rs, ignore
+//! const SENSOR: Address = Address::new(0x2E);
+//!
+//! // SAFETY: The base address was read from the SMBus controller's PCI config space.
+//! let mut host = Piix4Host::new(unsafe { PortRegisters::new(smbus_base) }, HostVariant::Piix4);
+//! let temperature = host.read_byte_data(SENSOR, 0x25)?;
+//! host.write_word_data(SENSOR, 0x30, 1200)?;
+//! ```
+//!
+//! A bus must only be used by one driver at a time, so usually one driver owns the host
+//! controller, and serves [`ioctl_smbus_execute`] for the others (see [`SmBusRequest::execute`]),
+//! which use an [`IoTargetSmBus`].
+
+pub use km_shared::smbus::*;
+
+use crate::{
+    io_mmap::{MappedIoSpace, ReadWrite},
+    port::Port,
+    time::{sleep_km, Instant},
+    wdf::io_target::{IoTarget, RequestSendOptions, SendIoctlError},
+};
+use core::time::Duration;
+use km_shared::ioctl::{IoControlCode, TypedIoControlCode};
+
+/// An SMBus, on which transactions can be executed.
+///
+/// Implementations only need to provide [`Self::execute`]. Transactions may sleep, so all
+/// methods must be called at `PASSIVE_LEVEL`.
+pub trait SmBus {
+    /// Executes `operation` on the device at `address`.
+    fn execute(&mut self, address: Address, operation: Operation<'_>) -> Result<(), SmBusError>;
+
+    fn quick(&mut self, address: Address, direction: Direction) -> Result<(), SmBusError> {
+        self.execute(address, Operation::Quick(direction))
+    }
+
+    fn receive_byte(&mut self, address: Address) -> Result<u8, SmBusError> {
+        let mut data = 0;
+        self.execute(address, Operation::ReceiveByte(&mut data))?;
+        Ok(data)
+    }
+
+    fn send_byte(&mut self, address: Address, data: u8) -> Result<(), SmBusError> {
+        self.execute(address, Operation::SendByte(data))
+    }
+
+    fn read_byte_data(&mut self, address: Address, command: u8) -> Result<u8, SmBusError> {
+        let mut data = 0;
+        self.execute(
+            address,
+            Operation::ReadByteData {
+                command,
+                data: &mut data,
+            },
+        )?;
+        Ok(data)
+    }
+
+    fn write_byte_data(
+        &mut self,
+        address: Address,
+        command: u8,
+        data: u8,
+    ) -> Result<(), SmBusError> {
+        self.execute(address, Operation::WriteByteData { command, data })
+    }
+
+    fn read_word_data(&mut self, address: Address, command: u8) -> Result<u16, SmBusError> {
+        let mut data = 0;
+        self.execute(
+            address,
+            Operation::ReadWordData {
+                command,
+                data: &mut data,
+            },
+        )?;
+        Ok(data)
+    }
+
+    fn write_word_data(
+        &mut self,
+        address: Address,
+        command: u8,
+        data: u16,
+    ) -> Result<(), SmBusError> {
+        self.execute(address, Operation::WriteWordData { command, data })
+    }
+
+    fn read_block_data(&mut self, address: Address, command: u8) -> Result<Block, SmBusError> {
+        let mut data = Block::new();
+        self.execute(
+            address,
+            Operation::ReadBlockData {
+                command,
+                data: &mut data,
+            },
+        )?;
+        Ok(data)
+    }
+
+    /// Writes a block of up to [`BLOCK_MAX`] bytes.
+    fn write_block_data(
+        &mut self,
+        address: Address,
+        command: u8,
+        data: &[u8],
+    ) -> Result<(), SmBusError> {
+        self.execute(address, Operation::WriteBlockData { command, data })
+    }
+}
+
+/// Access to the 8-bit registers of an SMBus host controller, by their offset from its base.
+pub trait HostRegisters {
+    fn read(&mut self, offset: u8) -> u8;
+    fn write(&mut self, offset: u8, value: u8);
+}
+
+/// The registers of a host controller in I/O port space, e.g. at the `SMBBA` of a PIIX4 or ICH
+/// controller.
+#[derive(Debug)]
+pub struct PortRegisters {
+    base: u16,
+}
+
+impl PortRegisters {
+    /// # Safety
+    /// `base` must be the base port of an SMBus host controller with the register layout of
+    /// [`Piix4Host`], and the ports must not be accessed by anyone else while this value exists.
+    pub unsafe fn new(base: u16) -> Self {
+        Self { base }
+    }
+}
+
+impl HostRegisters for PortRegisters {
+    fn read(&mut self, offset: u8) -> u8 {
+        // SAFETY: Guaranteed by the contract of `new`.
+        unsafe { Port::<u8>::new(self.base + u16::from(offset)).read() }
+    }
+
+    fn write(&mut self, offset: u8, value: u8) {
+        // SAFETY: Guaranteed by the contract of `new`.
+        unsafe { Port::<u8>::new(self.base + u16::from(offset)).write(value) }
+    }
+}
+
+/// The registers of a host controller mapped into memory space (e.g. the MMIO `SMBus` block of
+/// AMD FCHs).
+#[derive(Debug)]
+pub struct MappedRegisters(MappedIoSpace<[u8; HOST_REGISTERS_LEN], ReadWrite>);
+
+/// The size of the register block of a host controller.
+pub const HOST_REGISTERS_LEN: usize = 0x10;
+
+impl MappedRegisters {
+    /// # Safety
+    /// `space` must be the mapped registers of an SMBus host controller with the register layout
+    /// of [`Piix4Host`], and they must not be accessed by anyone else while this value exists.
+    pub unsafe fn new(space: MappedIoSpace<[u8; HOST_REGISTERS_LEN], ReadWrite>) -> Self {
+        Self(space)
+    }
+}
+
+impl HostRegisters for MappedRegisters {
+    fn read(&mut self, offset: u8) -> u8 {
+        let offset = usize::from(offset).min(HOST_REGISTERS_LEN - 1);
+        // SAFETY: The offset is in bounds of the mapped array, and bytes are always valid.
+        unsafe { self.0.access().map(|p| p.cast::<u8>().add(offset)) }.read()
+    }
+
+    fn write(&mut self, offset: u8, value: u8) {
+        let offset = usize::from(offset).min(HOST_REGISTERS_LEN - 1);
+        // SAFETY: The offset is in bounds of the mapped array, and bytes are always valid.
+        unsafe { self.0.access().map(|p| p.cast::<u8>().add(offset)) }.write(value)
+    }
+}
+
+// Register offsets of PIIX4-style host controllers.
+const HST_STS: u8 = 0x00;
+const HST_CNT: u8 = 0x02;
+const HST_CMD: u8 = 0x03;
+const XMIT_SLVA: u8 = 0x04;
+const HST_D0: u8 = 0x05;
+const HST_D1: u8 = 0x06;
+const HOST_BLOCK_DB: u8 = 0x07;
+/// Auxiliary control of ICH controllers.
+const AUX_CTL: u8 = 0x0D;
+
+// `HST_STS` bits.
+const STS_HOST_BUSY: u8 = 0x01;
+const STS_INTR: u8 = 0x02;
+const STS_DEV_ERR: u8 = 0x04;
+const STS_BUS_ERR: u8 = 0x08;
+const STS_FAILED: u8 = 0x10;
+const STS_ERRORS: u8 = STS_DEV_ERR | STS_BUS_ERR | STS_FAILED;
+/// The bits cleared by writing 1 to them after a transaction.
+const STS_CLEAR: u8 = STS_INTR | STS_ERRORS;
+
+// `HST_CNT` bits.
+const CNT_KILL: u8 = 0x02;
+const CNT_QUICK: u8 = 0x00;
+const CNT_BYTE: u8 = 0x04;
+const CNT_BYTE_DATA: u8 = 0x08;
+const CNT_WORD_DATA: u8 = 0x0C;
+const CNT_BLOCK_DATA: u8 = 0x14;
+const CNT_START: u8 = 0x40;
+
+/// `AUX_CTL` bit enabling the 32-byte block buffer of ICH controllers.
+const AUX_CTL_E32B: u8 = 0x02;
+
+/// The interval in which the status is polled while a transaction is running. A byte transaction
+/// takes about 0.5 ms at 100 kHz.
+const POLL_INTERVAL: Duration = Duration::from_micros(250);
+
+/// The register set variant of a [`Piix4Host`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostVariant {
+    /// Intel PIIX4 and compatibles (e.g. AMD SB/FCH), which always use a 32-byte block buffer.
+    Piix4,
+    /// Intel ICH/PCH, whose block buffer has to be enabled in `AUX_CTL`.
+    Ich,
+}
+
+/// An SMBus host controller with the PIIX4/ICH register interface, driven by polling.
+#[derive(Debug)]
+pub struct Piix4Host<R> {
+    registers: R,
+    variant: HostVariant,
+    timeout: Duration,
+}
+
+impl<R: HostRegisters> Piix4Host<R> {
+    pub fn new(registers: R, variant: HostVariant) -> Self {
+        Self {
+            registers,
+            variant,
+            timeout: Duration::from_millis(25),
+        }
+    }
+
+    /// Sets how long a transaction may take before it is killed (25 ms by default, the SMBus
+    /// clock low timeout).
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn into_registers(self) -> R {
+        self.registers
+    }
+
+    /// Clears the status of a previous transaction, failing if the controller is still busy.
+    fn prepare(&mut self) -> Result<(), SmBusError> {
+        let status = self.registers.read(HST_STS);
+        if status & STS_HOST_BUSY != 0 {
+            return Err(SmBusError::Busy);
+        }
+        if status & STS_CLEAR != 0 {
+            self.registers.write(HST_STS, status & STS_CLEAR);
+        }
+        Ok(())
+    }
+
+    /// Starts the transaction and waits for it to finish, killing it on timeout.
+    fn run(&mut self, control: u8) -> Result<(), SmBusError> {
+        self.registers.write(HST_CNT, control | CNT_START);
+
+        let start = Instant::now();
+        let status = loop {
+            let status = self.registers.read(HST_STS);
+            if status & STS_HOST_BUSY == 0 && status & STS_CLEAR != 0 {
+                break status;
+            }
+
+            if start.elapsed() > self.timeout {
+                self.registers.write(HST_CNT, CNT_KILL);
+                sleep_km(POLL_INTERVAL);
+                self.registers.write(HST_CNT, 0);
+                let status = self.registers.read(HST_STS);
+                self.registers.write(HST_STS, status & STS_CLEAR);
+                return Err(SmBusError::Timeout);
+            }
+            sleep_km(POLL_INTERVAL);
+        };
+        self.registers.write(HST_STS, status & STS_CLEAR);
+
+        if status & STS_FAILED != 0 {
+            Err(SmBusError::Failed)
+        } else if status & STS_BUS_ERR != 0 {
+            Err(SmBusError::BusCollision)
+        } else if status & STS_DEV_ERR != 0 {
+            Err(SmBusError::DeviceError)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn enable_block_buffer(&mut self) {
+        if self.variant == HostVariant::Ich {
+            let aux = self.registers.read(AUX_CTL);
+            self.registers.write(AUX_CTL, aux | AUX_CTL_E32B);
+        }
+    }
+}
+
+impl<R: HostRegisters> SmBus for Piix4Host<R> {
+    fn execute(&mut self, address: Address, operation: Operation<'_>) -> Result<(), SmBusError> {
+        if let Operation::WriteBlockData { data, .. } = operation {
+            if data.is_empty() || data.len() > BLOCK_MAX {
+                return Err(SmBusError::InvalidBlockLength { len: data.len() });
+            }
+        }
+
+        self.prepare()?;
+        self.registers
+            .write(XMIT_SLVA, address.with_direction(operation.direction()));
+        if let Some(command) = operation.command() {
+            self.registers.write(HST_CMD, command);
+        }
+
+        let control = match &operation {
+            Operation::Quick(_) => CNT_QUICK,
+            Operation::ReceiveByte(_) => CNT_BYTE,
+            Operation::SendByte(data) => {
+                // There is no command, the data byte is sent in its place.
+                self.registers.write(HST_CMD, *data);
+                CNT_BYTE
+            }
+            Operation::ReadByteData { .. } => CNT_BYTE_DATA,
+            Operation::WriteByteData { data, .. } => {
+                self.registers.write(HST_D0, *data);
+                CNT_BYTE_DATA
+            }
+            Operation::ReadWordData { .. } => CNT_WORD_DATA,
+            Operation::WriteWordData { data, .. } => {
+                let [low, high] = data.to_le_bytes();
+                self.registers.write(HST_D0, low);
+                self.registers.write(HST_D1, high);
+                CNT_WORD_DATA
+            }
+            Operation::ReadBlockData { .. } => {
+                self.enable_block_buffer();
+                CNT_BLOCK_DATA
+            }
+            Operation::WriteBlockData { data, .. } => {
+                self.enable_block_buffer();
+                self.registers.write(HST_D0, data.len() as u8);
+                // Reading the control register resets the index of the block buffer.
+                self.registers.read(HST_CNT);
+                for byte in data.iter() {
+                    self.registers.write(HOST_BLOCK_DB, *byte);
+                }
+                CNT_BLOCK_DATA
+            }
+        };
+
+        self.run(control)?;
+
+        match operation {
+            Operation::ReceiveByte(data) | Operation::ReadByteData { data, .. } => {
+                *data = self.registers.read(HST_D0);
+            }
+            Operation::ReadWordData { data, .. } => {
+                *data =
+                    u16::from_le_bytes([self.registers.read(HST_D0), self.registers.read(HST_D1)]);
+            }
+            Operation::ReadBlockData { data, .. } => {
+                let len = usize::from(self.registers.read(HST_D0));
+                if len == 0 || len > BLOCK_MAX {
+                    return Err(SmBusError::InvalidBlockLength { len });
+                }
+
+                data.clear();
+                // Reading the control register resets the index of the block buffer.
+                self.registers.read(HST_CNT);
+                for _ in 0..len {
+                    // Can't fail, as the length was checked above.
+                    let _ = data.push(self.registers.read(HOST_BLOCK_DB));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// An SMBus owned by another driver, which serves [`ioctl_smbus_execute`] through an I/O target.
+pub struct IoTargetSmBus {
+    target: IoTarget,
+    ioctl: IoControlCode,
+    options: RequestSendOptions,
+}
+
+impl IoTargetSmBus {
+    /// Uses the opened `target` of a bus driver, whose execute code is `ioctl` (usually
+    /// [`ioctl_smbus_execute`] with the bus driver's device type).
+    pub fn new(
+        target: IoTarget,
+        ioctl: TypedIoControlCode<SmBusRequest, SmBusResponse>,
+        options: RequestSendOptions,
+    ) -> Self {
+        Self {
+            target,
+            ioctl: ioctl.code,
+            options,
+        }
+    }
+
+    pub fn io_target(&self) -> &IoTarget {
+        &self.target
+    }
+}
+
+impl SmBus for IoTargetSmBus {
+    fn execute(
+        &mut self,
+        address: Address,
+        mut operation: Operation<'_>,
+    ) -> Result<(), SmBusError> {
+        let request = SmBusRequest::new(address, &operation)?;
+        let ioctl = TypedIoControlCode::<SmBusRequest, SmBusResponse>::new(self.ioctl);
+
+        let response = self
+            .target
+            .send_typed_ioctl_synchronously(ioctl, &request, &self.options)
+            .map_err(|e| match e {
+                SendIoctlError::NtStatus { source } => source.into(),
+                SendIoctlError::OutputTooShort { .. } | SendIoctlError::Cast { .. } => {
+                    SmBusError::Invalid
+                }
+            })?;
+
+        response.complete(&mut operation)
+    }
+}
@@ -0,0 +1,145 @@
+//! Cheap runtime statistics, e.g. requests served, errors, or the total latency of polls.
+//!
+//! [`PerCpuCounters`] keeps a slot of counters per processor, each on its own cache line, so
+//! incrementing a counter only touches the current processor's slot, and processors incrementing
+//! the same counter don't bounce the cache line between them. Reading a counter sums up all slots:
+//!
+//! ```rs, ignore
+//! const REQUESTS: usize = 0;
+//! const ERRORS: usize = 1;
+//! const POLL_LATENCY_US: usize = 2;
+//! const POLLS: usize = 3;
+//!
+//! let stats = PerCpuCounters::<4>::new(u32::from_le_bytes(*b"Stat"))?;
+//!
+//! // at any IRQL, e.g. in an ISR
+//! stats.increment(REQUESTS);
+//! stats.add(POLL_LATENCY_US, elapsed_us);
+//!
+//! // in the handler of a statistics IOCTL
+//! *unsafe { request.typed_output::<[u64; 4]>() }? = stats.snapshot();
+//! ```
+//!
+//! Averages (like the average poll latency) are best computed by whoever reads the snapshot, from
+//! a sum and a count.
+
+use crate::{cpu, verify, wdf::memory::Memory};
+use core::{
+    mem::{align_of, size_of},
+    ptr::NonNull,
+    slice,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use km_shared::ntstatus::NtStatusError;
+
+/// The counters of one processor, aligned to a cache line so that no two processors share one.
+#[repr(C, align(64))]
+struct Slot<const N: usize>([AtomicU64; N]);
+
+/// `N` counters, with a slot per processor. See the [module documentation](self).
+///
+/// Counters are identified by their index, which has to be less than `N`. The counters have to be
+/// dropped at `IRQL <= DISPATCH_LEVEL`.
+#[derive(Debug)]
+pub struct PerCpuCounters<const N: usize> {
+    // owns the slots
+    _memory: Memory,
+    slots: NonNull<Slot<N>>,
+    slot_count: usize,
+}
+
+// SAFETY: The slots are owned by the memory object, and only accessed through atomics.
+unsafe impl<const N: usize> Send for PerCpuCounters<N> {}
+// SAFETY: See above.
+unsafe impl<const N: usize> Sync for PerCpuCounters<N> {}
+
+impl<const N: usize> PerCpuCounters<N> {
+    /// Allocates zeroed counters, with a slot for each active processor, from non-paged pool
+    /// tagged with `pool_tag`.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn new(pool_tag: u32) -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let slot_count = (cpu::active_processor_count() as usize).max(1);
+
+        // the pool only aligns to 16 bytes, so there's room to align the slots to a cache line
+        let size = slot_count * size_of::<Slot<N>>() + align_of::<Slot<N>>();
+        let mut memory = Memory::create(size, pool_tag, None)?;
+        let buffer = memory.as_mut_ptr();
+        let offset = buffer.align_offset(align_of::<Slot<N>>());
+        // SAFETY: `offset` is less than the alignment, so the slots are within the buffer. As the
+        // buffer is zeroed, all counters start at zero.
+        let slots = unsafe { NonNull::new_unchecked(buffer.add(offset).cast::<Slot<N>>()) };
+
+        Ok(Self {
+            _memory: memory,
+            slots,
+            slot_count,
+        })
+    }
+
+    /// Increments the counter `counter` by one.
+    ///
+    /// Can be called at any IRQL.
+    pub fn increment(&self, counter: usize) {
+        self.add(counter, 1);
+    }
+
+    /// Increments the counter `counter` by `value`.
+    ///
+    /// Can be called at any IRQL.
+    pub fn add(&self, counter: usize, value: u64) {
+        // The thread may be moved to another processor before the counter is incremented (unless
+        // it runs at `DISPATCH_LEVEL` or above), so the slot is still updated atomically. That's
+        // cheap while the slot's cache line stays on the processor it belongs to.
+        self.current_slot().0[counter].fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// The sum of the counter `counter` over all processors.
+    ///
+    /// Can be called at any IRQL.
+    pub fn get(&self, counter: usize) -> u64 {
+        self.slots()
+            .iter()
+            .map(|slot| slot.0[counter].load(Ordering::Relaxed))
+            .fold(0, u64::wrapping_add)
+    }
+
+    /// The sums of all counters over all processors, e.g. to be returned from an IOCTL.
+    ///
+    /// The counters keep being incremented while they're read, so the snapshot isn't consistent
+    /// between counters, e.g. a request may be counted in one counter but not yet in another.
+    ///
+    /// Can be called at any IRQL.
+    pub fn snapshot(&self) -> [u64; N] {
+        let mut sums = [0u64; N];
+        for slot in self.slots() {
+            for (sum, value) in sums.iter_mut().zip(&slot.0) {
+                *sum = sum.wrapping_add(value.load(Ordering::Relaxed));
+            }
+        }
+        sums
+    }
+
+    /// Sets all counters back to zero. Increments concurrent to the reset may or may not be lost.
+    ///
+    /// Can be called at any IRQL.
+    pub fn reset(&self) {
+        for value in self.slots().iter().flat_map(|slot| &slot.0) {
+            value.store(0, Ordering::Relaxed);
+        }
+    }
+
+    fn slots(&self) -> &[Slot<N>] {
+        // SAFETY: The slots were allocated and zeroed in `new`, and are owned by `self`.
+        unsafe { slice::from_raw_parts(self.slots.as_ptr(), self.slot_count) }
+    }
+
+    fn current_slot(&self) -> &Slot<N> {
+        let processor = cpu::current_processor_index() as usize;
+        // processors that were added since the counters were allocated share the slots of others
+        &self.slots()[processor % self.slot_count]
+    }
+}
@@ -0,0 +1,228 @@
+//! Strings composed at runtime, complementing the constant ones of [`km_shared::strings`].
+//!
+//! [`UnicodeStringOwned`] owns a buffer allocated from non-paged pool, which grows as the string
+//! is appended to, e.g. for registry paths or device names that include a runtime value:
+//!
+//! ```rs, ignore
+//! use core::fmt::Write;
+//!
+//! let mut name = UnicodeStringOwned::from_str("\\Device\\", POOL_TAG)?;
+//! name.push_wide(&controller_name)?;
+//! write!(name, "-Port{index}").map_err(|_| Error::StringTooLong)?;
+//!
+//! let init = init.assign_name(&name.as_unicode_string())?;
+//! ```
+
+use crate::{
+    error::{error, Error},
+    verify,
+    wdf::memory::Memory,
+};
+use core::{fmt, mem::size_of, ptr::null_mut, slice};
+use km_shared::strings::UnicodeString;
+use km_sys::{UNICODE_STRING, WCHAR};
+use snafu::ensure;
+
+/// A [`UnicodeString`] owning its buffer, which is allocated from non-paged pool, grows as the
+/// string is appended to, and is freed on drop. See the [module documentation](self).
+///
+/// The string is always NUL-terminated, so it can also be passed where a `PCWSTR` is expected.
+pub struct UnicodeStringOwned {
+    memory: Option<Memory>,
+    /// The buffer of `memory`, or null if there's none yet.
+    buffer: *mut WCHAR,
+    /// The `WCHAR`s the buffer holds, without the terminating NUL.
+    capacity: usize,
+    /// The length in `WCHAR`s, without the terminating NUL.
+    len: usize,
+    pool_tag: u32,
+}
+
+// SAFETY: The buffer is owned by the string, and only accessed through it.
+unsafe impl Send for UnicodeStringOwned {}
+// SAFETY: The buffer is only modified through `&mut self`.
+unsafe impl Sync for UnicodeStringOwned {}
+
+impl UnicodeStringOwned {
+    /// The most `WCHAR`s a string can hold, as the length of a `UNICODE_STRING` is limited to
+    /// `u16::MAX` bytes, including the terminating NUL.
+    pub const MAX_LEN: usize = u16::MAX as usize / size_of::<WCHAR>() - 1;
+
+    /// Creates an empty string, which allocates from non-paged pool tagged with `pool_tag` once
+    /// it's appended to.
+    ///
+    /// Can be called at any IRQL.
+    pub const fn new(pool_tag: u32) -> Self {
+        Self {
+            memory: None,
+            buffer: null_mut(),
+            capacity: 0,
+            len: 0,
+            pool_tag,
+        }
+    }
+
+    /// Creates an empty string with room for `capacity` `WCHAR`s, see [`Self::new`].
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn with_capacity(capacity: usize, pool_tag: u32) -> Result<Self, Error> {
+        let mut this = Self::new(pool_tag);
+        this.reserve(capacity)?;
+        Ok(this)
+    }
+
+    /// Creates a string holding `s`, see [`Self::new`].
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn from_str(s: &str, pool_tag: u32) -> Result<Self, Error> {
+        let mut this = Self::new(pool_tag);
+        this.push_str(s)?;
+        Ok(this)
+    }
+
+    /// The length in `WCHAR`s, without the terminating NUL.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of `WCHAR`s the string can hold without growing its buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The string, without the terminating NUL.
+    pub fn as_wide(&self) -> &[WCHAR] {
+        if self.buffer.is_null() {
+            return &[];
+        }
+
+        // SAFETY: The buffer holds at least `len` initialized `WCHAR`s, and is aligned for them,
+        // as the pool aligns allocations to at least 8 bytes.
+        unsafe { slice::from_raw_parts(self.buffer, self.len) }
+    }
+
+    /// Creates a [`UnicodeString`] referencing the string, e.g. to pass it to a kernel function.
+    ///
+    /// The `UnicodeString` mustn't be used after `self` is changed or dropped.
+    pub fn as_unicode_string(&self) -> UnicodeString {
+        let max_len = if self.buffer.is_null() {
+            0
+        } else {
+            self.capacity + 1
+        };
+
+        UNICODE_STRING {
+            Length: (self.len * size_of::<WCHAR>()) as u16,
+            MaximumLength: (max_len * size_of::<WCHAR>()) as u16,
+            Buffer: self.buffer,
+        }
+    }
+
+    /// Makes sure the string can hold `additional` more `WCHAR`s, growing its buffer if needed.
+    /// Fails with [`Error::StringTooLong`] if the string would exceed [`Self::MAX_LEN`].
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn reserve(&mut self, additional: usize) -> Result<(), Error> {
+        let required = self.len.saturating_add(additional);
+        ensure!(required <= Self::MAX_LEN, error::StringTooLongSnafu);
+        if required <= self.capacity && !self.buffer.is_null() {
+            return Ok(());
+        }
+
+        verify::at_most_dispatch_level();
+
+        // grow at least by doubling, to append in amortized constant time
+        let capacity = required.max(self.capacity * 2).min(Self::MAX_LEN);
+        let mut memory = Memory::create((capacity + 1) * size_of::<WCHAR>(), self.pool_tag, None)?;
+        let buffer = memory.as_mut_ptr().cast::<WCHAR>();
+        if !self.buffer.is_null() {
+            // SAFETY: Both buffers hold at least `len` `WCHAR`s, and don't overlap. The rest of
+            // the new buffer is zeroed, so the string stays terminated.
+            unsafe { buffer.copy_from_nonoverlapping(self.buffer, self.len) };
+        }
+
+        self.memory = Some(memory);
+        self.buffer = buffer;
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    /// Appends `s`, converted to UTF-16.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn push_str(&mut self, s: &str) -> Result<(), Error> {
+        self.reserve(s.encode_utf16().count())?;
+        for unit in s.encode_utf16() {
+            self.push_unchecked(unit);
+        }
+        self.terminate();
+        Ok(())
+    }
+
+    /// Appends `s`, which is already UTF-16.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn push_wide(&mut self, s: &[WCHAR]) -> Result<(), Error> {
+        self.reserve(s.len())?;
+        for &unit in s {
+            self.push_unchecked(unit);
+        }
+        self.terminate();
+        Ok(())
+    }
+
+    /// Removes the contents, keeping the buffer.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.terminate();
+    }
+
+    /// Appends `unit`, for which room was [reserved](Self::reserve) already, without terminating
+    /// the string.
+    fn push_unchecked(&mut self, unit: WCHAR) {
+        debug_assert!(self.len < self.capacity);
+        // SAFETY: The buffer has room for `capacity` `WCHAR`s, which is more than `len`.
+        unsafe { self.buffer.add(self.len).write(unit) };
+        self.len += 1;
+    }
+
+    fn terminate(&mut self) {
+        if !self.buffer.is_null() {
+            // SAFETY: The buffer has room for `capacity + 1` `WCHAR`s, including the NUL.
+            unsafe { self.buffer.add(self.len).write(0) };
+        }
+    }
+}
+
+/// Allows composing the string with `write!`, which fails if the string would get too long or
+/// its buffer can't be grown. Must be used at `IRQL <= DISPATCH_LEVEL`.
+impl fmt::Write for UnicodeStringOwned {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+/// Formats the string, replacing invalid UTF-16 with `U+FFFD`.
+impl fmt::Display for UnicodeStringOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in char::decode_utf16(self.as_wide().iter().copied()) {
+            fmt::Write::write_char(f, c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UnicodeStringOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
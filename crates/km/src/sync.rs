@@ -0,0 +1,220 @@
+//! Synchronization primitives that don't depend on WDF objects.
+//!
+//! [`OnceCell`] and [`Lazy`] cache values that are computed once and never change afterwards, e.g.
+//! the performance counter frequency, a resolved routine, or a mapped register block:
+//!
+//! ```rs, ignore
+//! static TSC_FREQUENCY: Lazy<u64> = Lazy::new(|| measure_tsc_frequency());
+//!
+//! let ticks_per_us = *TSC_FREQUENCY / 1_000_000;
+//! ```
+//!
+//! # IRQL constraints
+//!
+//! Both are built on atomics, so they can be read at any IRQL, and initialized at
+//! `IRQL <= DISPATCH_LEVEL`. While the initializer runs, other callers that need the value spin
+//! until it's done, as they can't wait at `DISPATCH_LEVEL`. The initializer therefore
+//! - has to be short, and mustn't access the cell it's initializing, which would spin forever.
+//! - runs at the IRQL of the caller that first needs the value, so it's limited by the lowest
+//!   IRQL the cell is used at, e.g. it can't call pageable code if the cell is used by a DPC.
+//! - mustn't be interrupted by a caller on the same processor, which would spin forever, as the
+//!   initializer can't continue on that processor. Cells that are used at `DISPATCH_LEVEL` and
+//!   below have to be initialized before the `DISPATCH_LEVEL` code can run (e.g. in
+//!   `DriverEntry`, with [`OnceCell::set`] or [`Lazy::force`]), or only at `DISPATCH_LEVEL`.
+//!
+//! Initializers that can fail can be used with [`OnceCell::get_or_try_init`], which leaves the
+//! cell empty on failure, so the next caller tries again.
+//!
+//! See [`atomic`] for atomics with the semantics of the kernel's `Interlocked*` routines.
+
+pub mod atomic;
+
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+const EMPTY: u8 = 0;
+const RUNNING: u8 = 1;
+const READY: u8 = 2;
+
+/// A cell that's written at most once, see the [module documentation](self) for the IRQL
+/// constraints.
+pub struct OnceCell<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: The value is only written by the caller that moved the state to `RUNNING`, and only read
+// after it's `READY`. It may be written and dropped by a different thread than it's read by.
+unsafe impl<T: Send + Sync> Sync for OnceCell<T> {}
+// SAFETY: See above.
+unsafe impl<T: Send> Send for OnceCell<T> {}
+
+impl<T> OnceCell<T> {
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(EMPTY),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns the value, or `None` if it wasn't initialized yet, or is being initialized.
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == READY {
+            // SAFETY: The value was initialized, and is never written again.
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Stores `value`, or returns it back if the cell was already initialized, or is being
+    /// initialized.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        if !self.begin_init() {
+            return Err(value);
+        }
+
+        // SAFETY: This caller moved the state to `RUNNING`, so it's the only one writing.
+        unsafe { self.finish_init(value) };
+        Ok(())
+    }
+
+    /// Returns the value, initializing it with `f` if needed.
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        match self.get_or_try_init(|| Ok::<T, core::convert::Infallible>(f())) {
+            Ok(value) => value,
+            Err(infallible) => match infallible {},
+        }
+    }
+
+    /// Returns the value, initializing it with `f` if needed. If `f` fails, the cell stays empty,
+    /// and the error is returned.
+    pub fn get_or_try_init<E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<&T, E> {
+        loop {
+            if let Some(value) = self.get() {
+                return Ok(value);
+            }
+            if self.begin_init() {
+                break;
+            }
+
+            // another caller is initializing the value, and may fail, in which case this one
+            // tries again
+            while self.state.load(Ordering::Acquire) == RUNNING {
+                core::hint::spin_loop();
+            }
+        }
+
+        match f() {
+            // SAFETY: This caller moved the state to `RUNNING`, so it's the only one writing.
+            Ok(value) => Ok(unsafe { self.finish_init(value) }),
+            Err(e) => {
+                self.state.store(EMPTY, Ordering::Release);
+                Err(e)
+            }
+        }
+    }
+
+    /// Returns the value, if it was initialized, leaving the cell empty.
+    pub fn take(&mut self) -> Option<T> {
+        if *self.state.get_mut() == READY {
+            *self.state.get_mut() = EMPTY;
+            // SAFETY: The value was initialized, and the state says it isn't anymore.
+            Some(unsafe { self.value.get_mut().assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Moves the state from `EMPTY` to `RUNNING`, returning whether this caller did so.
+    fn begin_init(&self) -> bool {
+        self.state
+            .compare_exchange(EMPTY, RUNNING, Ordering::Acquire, Ordering::Acquire)
+            .is_ok()
+    }
+
+    /// # Safety
+    /// The caller must have moved the state to `RUNNING` with [`Self::begin_init`].
+    unsafe fn finish_init(&self, value: T) -> &T {
+        // SAFETY: The caller guarantees that it's the only one accessing the value.
+        let value = unsafe { (*self.value.get()).write(value) };
+        self.state.store(READY, Ordering::Release);
+        value
+    }
+}
+
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceCell<T> {
+    fn drop(&mut self) {
+        drop(self.take());
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceCell").field(value).finish(),
+            None => f.write_str("OnceCell(<uninitialized>)"),
+        }
+    }
+}
+
+/// A value that's initialized on first access, see the [module documentation](self) for the
+/// IRQL constraints.
+pub struct Lazy<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: UnsafeCell<Option<F>>,
+}
+
+// SAFETY: `init` is only accessed by the caller initializing the cell, which `OnceCell`
+// guarantees to be the only one. It may run on any thread.
+unsafe impl<T: Send + Sync, F: Send> Sync for Lazy<T, F> {}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceCell::new(),
+            init: UnsafeCell::new(Some(init)),
+        }
+    }
+
+    /// Initializes the value if needed, and returns it.
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(|| {
+            // SAFETY: Only the caller initializing the cell gets here, and only once, as
+            // initializing can't fail.
+            let init = unsafe { (*this.init.get()).take() };
+            match init {
+                Some(init) => init(),
+                None => unreachable!("`Lazy` initialized twice"),
+            }
+        })
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        Lazy::force(self)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell.get() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(<uninitialized>)"),
+        }
+    }
+}
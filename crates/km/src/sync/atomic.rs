@@ -0,0 +1,252 @@
+//! Atomics with the semantics of the kernel's `Interlocked*` routines.
+//!
+//! The types wrap the `core::sync::atomic` types, but name their operations after the interlocked
+//! routines, and give them the same memory ordering, so code ported from C behaves the same:
+//! - read-modify-write operations (`Interlocked*`) are full barriers, i.e. `SeqCst`.
+//! - [`load`](AtomicLong::load) and [`store`](AtomicLong::store) are `ReadAcquire` and
+//!   `WriteRelease`; the `_no_fence` variants are `ReadNoFence` and `WriteNoFence`.
+//! - [`increment`](AtomicLong::increment) and [`decrement`](AtomicLong::decrement) return the
+//!   *new* value, like `InterlockedIncrement`, while all other operations return the previous
+//!   value. All arithmetic wraps around.
+//!
+//! On x86 and x64, these compile to the same `lock`-prefixed instructions as the intrinsics, also
+//! for the 64-bit types on x86 (`lock cmpxchg8b`). [`Atomic128`] is only available on x64, where
+//! it uses `lock cmpxchg16b` like `InterlockedCompareExchange128`.
+//!
+//! The values are laid out like the `volatile LONG`s the kernel routines take, so
+//! [`as_ptr`](AtomicLong::as_ptr) can be passed to them.
+
+use core::sync::atomic::{AtomicI32, AtomicI64, AtomicU32, AtomicU64, Ordering};
+use km_sys::{LONG, LONG64, ULONG, ULONG64};
+
+/// Declares an interlocked wrapper around a `core` atomic.
+macro_rules! interlocked {
+    ($(#[$meta:meta])* $name:ident($atomic:ty, $t:ty)) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Default)]
+        pub struct $name($atomic);
+
+        impl $name {
+            pub const fn new(value: $t) -> Self {
+                Self(<$atomic>::new(value))
+            }
+
+            /// Reads the value with acquire semantics (`ReadAcquire`).
+            #[inline]
+            pub fn load(&self) -> $t {
+                self.0.load(Ordering::Acquire)
+            }
+
+            /// Reads the value without ordering other accesses (`ReadNoFence`).
+            #[inline]
+            pub fn load_no_fence(&self) -> $t {
+                self.0.load(Ordering::Relaxed)
+            }
+
+            /// Writes the value with release semantics (`WriteRelease`).
+            #[inline]
+            pub fn store(&self, value: $t) {
+                self.0.store(value, Ordering::Release)
+            }
+
+            /// Writes the value without ordering other accesses (`WriteNoFence`).
+            #[inline]
+            pub fn store_no_fence(&self, value: $t) {
+                self.0.store(value, Ordering::Relaxed)
+            }
+
+            /// Adds one, returning the *new* value (`InterlockedIncrement`).
+            #[inline]
+            pub fn increment(&self) -> $t {
+                self.0.fetch_add(1, Ordering::SeqCst).wrapping_add(1)
+            }
+
+            /// Subtracts one, returning the *new* value (`InterlockedDecrement`).
+            #[inline]
+            pub fn decrement(&self) -> $t {
+                self.0.fetch_sub(1, Ordering::SeqCst).wrapping_sub(1)
+            }
+
+            /// Adds `value`, returning the previous value (`InterlockedExchangeAdd`).
+            #[inline]
+            pub fn exchange_add(&self, value: $t) -> $t {
+                self.0.fetch_add(value, Ordering::SeqCst)
+            }
+
+            /// Replaces the value, returning the previous one (`InterlockedExchange`).
+            #[inline]
+            pub fn exchange(&self, value: $t) -> $t {
+                self.0.swap(value, Ordering::SeqCst)
+            }
+
+            /// Replaces the value with `new` if it's `current` (`InterlockedCompareExchange`).
+            ///
+            /// Returns the previous value, as `Ok` if it was replaced, and as `Err` otherwise.
+            #[inline]
+            pub fn compare_exchange(&self, current: $t, new: $t) -> Result<$t, $t> {
+                self.0
+                    .compare_exchange(current, new, Ordering::SeqCst, Ordering::SeqCst)
+            }
+
+            /// Bitwise ANDs the value, returning the previous one (`InterlockedAnd`).
+            #[inline]
+            pub fn and(&self, value: $t) -> $t {
+                self.0.fetch_and(value, Ordering::SeqCst)
+            }
+
+            /// Bitwise ORs the value, returning the previous one (`InterlockedOr`).
+            #[inline]
+            pub fn or(&self, value: $t) -> $t {
+                self.0.fetch_or(value, Ordering::SeqCst)
+            }
+
+            /// Bitwise XORs the value, returning the previous one (`InterlockedXor`).
+            #[inline]
+            pub fn xor(&self, value: $t) -> $t {
+                self.0.fetch_xor(value, Ordering::SeqCst)
+            }
+
+            /// Sets bit `bit` (modulo the width), returning whether it was set before
+            /// (`InterlockedBitTestAndSet`).
+            #[inline]
+            pub fn bit_test_and_set(&self, bit: u32) -> bool {
+                let mask = (1 as $t).wrapping_shl(bit);
+                self.or(mask) & mask != 0
+            }
+
+            /// Clears bit `bit` (modulo the width), returning whether it was set before
+            /// (`InterlockedBitTestAndReset`).
+            #[inline]
+            pub fn bit_test_and_reset(&self, bit: u32) -> bool {
+                let mask = (1 as $t).wrapping_shl(bit);
+                self.and(!mask) & mask != 0
+            }
+
+            pub fn get_mut(&mut self) -> &mut $t {
+                self.0.get_mut()
+            }
+
+            pub fn into_inner(self) -> $t {
+                self.0.into_inner()
+            }
+
+            /// A pointer to the value, e.g. for passing it to kernel routines. It must only be
+            /// accessed atomically while `self` is alive.
+            pub const fn as_ptr(&self) -> *mut $t {
+                self.0.as_ptr()
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.load(), f)
+            }
+        }
+    };
+}
+
+interlocked! {
+    /// A `LONG` accessed with the `Interlocked*` semantics, see the [module documentation](self).
+    AtomicLong(AtomicI32, LONG)
+}
+
+interlocked! {
+    /// A `ULONG` accessed with the `Interlocked*` semantics, see the [module documentation](self).
+    AtomicULong(AtomicU32, ULONG)
+}
+
+interlocked! {
+    /// A `LONG64` accessed with the `Interlocked*64` semantics, see the
+    /// [module documentation](self).
+    AtomicLong64(AtomicI64, LONG64)
+}
+
+interlocked! {
+    /// A `ULONG64` accessed with the `Interlocked*64` semantics, see the
+    /// [module documentation](self).
+    AtomicULong64(AtomicU64, ULONG64)
+}
+
+#[cfg(target_arch = "x86_64")]
+pub use x64::Atomic128;
+
+#[cfg(target_arch = "x86_64")]
+mod x64 {
+    use core::{arch::asm, cell::UnsafeCell};
+
+    /// A 128-bit value accessed with `InterlockedCompareExchange128`, e.g. a pointer with a
+    /// counter to avoid the ABA problem in lock-free lists.
+    ///
+    /// The value is stored as two `u64`s, the low half first, like the `LONG64[2]` the routine
+    /// takes. `cmpxchg16b`, which is required by all 64-bit versions of Windows since 8.1, is the
+    /// only 128-bit atomic operation, so all operations are full barriers.
+    #[repr(C, align(16))]
+    #[derive(Default)]
+    pub struct Atomic128(UnsafeCell<[u64; 2]>);
+
+    // SAFETY: The value is only accessed atomically.
+    unsafe impl Sync for Atomic128 {}
+
+    impl Atomic128 {
+        pub const fn new(value: u128) -> Self {
+            Self(UnsafeCell::new([value as u64, (value >> 64) as u64]))
+        }
+
+        /// Reads the value, by comparing and exchanging it with itself.
+        #[inline]
+        pub fn load(&self) -> u128 {
+            match self.compare_exchange(0, 0) {
+                Ok(value) | Err(value) => value,
+            }
+        }
+
+        /// Replaces the value with `new` if it's `current` (`InterlockedCompareExchange128`).
+        ///
+        /// Returns the previous value, as `Ok` if it was replaced, and as `Err` otherwise.
+        #[inline]
+        pub fn compare_exchange(&self, current: u128, new: u128) -> Result<u128, u128> {
+            let (previous_low, previous_high): (u64, u64);
+
+            // SAFETY: The value is 16-byte aligned as required by `cmpxchg16b`, and only accessed
+            // atomically. `rbx` is reserved by LLVM, so the low half of `new` is swapped into it
+            // for the instruction, and restored afterwards.
+            unsafe {
+                asm!(
+                    "xchg {new_low}, rbx",
+                    "lock cmpxchg16b xmmword ptr [{ptr}]",
+                    "mov rbx, {new_low}",
+                    ptr = in(reg) self.0.get(),
+                    new_low = inout(reg) new as u64 => _,
+                    in("rcx") (new >> 64) as u64,
+                    inout("rax") current as u64 => previous_low,
+                    inout("rdx") (current >> 64) as u64 => previous_high,
+                    options(nostack),
+                );
+            }
+
+            let previous = (previous_high as u128) << 64 | previous_low as u128;
+            if previous == current {
+                Ok(previous)
+            } else {
+                Err(previous)
+            }
+        }
+
+        pub fn get_mut(&mut self) -> &mut [u64; 2] {
+            self.0.get_mut()
+        }
+
+        /// A pointer to the value, e.g. for passing it to kernel routines. It must only be
+        /// accessed atomically while `self` is alive.
+        pub const fn as_ptr(&self) -> *mut [u64; 2] {
+            self.0.get()
+        }
+    }
+
+    impl core::fmt::Debug for Atomic128 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(&self.load(), f)
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! System information, e.g. for monitoring IOCTLs.
+//!
+//! [`cpu_times`] fills in the [`CpuTimes`] of the processors of a group, which can be returned to
+//! user mode as is:
+//!
+//! ```rs, ignore
+//! // in the handler of `IOCTL_CPU_TIMES`, which takes the group and returns `[CpuTimes; 64]`
+//! let group = request.typed_input::<u16>()?;
+//! // SAFETY: The output buffer isn't retrieved anywhere else.
+//! let mut output = unsafe { request.typed_output::<[CpuTimes; 64]>() }?;
+//! let count = sysinfo::cpu_times(group, &mut *output)?;
+//! request.set_information((count * size_of::<CpuTimes>()) as u64);
+//! ```
+//!
+//! [`memory_info`] likewise returns the [`MemoryInfo`] of the system.
+
+use crate::verify;
+use core::{mem::size_of, ptr::null_mut};
+use km_shared::ntstatus::{NtStatus, NtStatusError};
+pub use km_shared::sysinfo::{CpuTimes, MemoryInfo};
+use km_sys::{NTSTATUS, PULONG, PVOID, ULONG};
+
+crate::dynimport! {
+    /// Not declared in the WDK headers, but exported by all supported versions.
+    static ZW_QUERY_SYSTEM_INFORMATION: unsafe extern "system" fn(
+        system_information_class: ULONG,
+        system_information: PVOID,
+        system_information_length: ULONG,
+        return_length: PULONG,
+    ) -> NTSTATUS = "ZwQuerySystemInformation";
+    /// Not declared in the WDK headers, but exported since Windows 7.
+    static ZW_QUERY_SYSTEM_INFORMATION_EX: unsafe extern "system" fn(
+        system_information_class: ULONG,
+        input_buffer: PVOID,
+        input_buffer_length: ULONG,
+        system_information: PVOID,
+        system_information_length: ULONG,
+        return_length: PULONG,
+    ) -> NTSTATUS = "ZwQuerySystemInformationEx";
+}
+
+/// `SystemBasicInformation` of `SYSTEM_INFORMATION_CLASS`.
+const SYSTEM_BASIC_INFORMATION: ULONG = 0;
+/// `SystemPerformanceInformation` of `SYSTEM_INFORMATION_CLASS`.
+const SYSTEM_PERFORMANCE_INFORMATION: ULONG = 2;
+/// `SystemProcessorPerformanceInformation` of `SYSTEM_INFORMATION_CLASS`.
+const SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION: ULONG = 8;
+
+/// `SYSTEM_BASIC_INFORMATION`, which has to be queried with its exact size.
+#[repr(C)]
+#[derive(Default)]
+struct SystemBasicInformation {
+    reserved: ULONG,
+    timer_resolution: ULONG,
+    page_size: ULONG,
+    number_of_physical_pages: ULONG,
+    lowest_physical_page_number: ULONG,
+    highest_physical_page_number: ULONG,
+    allocation_granularity: ULONG,
+    minimum_user_mode_address: usize,
+    maximum_user_mode_address: usize,
+    active_processors_affinity_mask: usize,
+    number_of_processors: i8,
+}
+
+/// The start of `SYSTEM_PERFORMANCE_INFORMATION`, up to the last field that is used. The structure
+/// grew over time, so it's queried into a [larger buffer](PerformanceInformationBuffer).
+#[repr(C)]
+struct SystemPerformanceInformation {
+    idle_process_time: i64,
+    io_read_transfer_count: i64,
+    io_write_transfer_count: i64,
+    io_other_transfer_count: i64,
+    io_read_operation_count: ULONG,
+    io_write_operation_count: ULONG,
+    io_other_operation_count: ULONG,
+    available_pages: ULONG,
+    committed_pages: ULONG,
+    commit_limit: ULONG,
+    peak_commitment: ULONG,
+    page_fault_count: ULONG,
+    copy_on_write_count: ULONG,
+    transition_count: ULONG,
+    cache_transition_count: ULONG,
+    demand_zero_count: ULONG,
+    page_read_count: ULONG,
+    page_read_io_count: ULONG,
+    cache_read_count: ULONG,
+    cache_io_count: ULONG,
+    dirty_pages_write_count: ULONG,
+    dirty_write_io_count: ULONG,
+    mapped_pages_write_count: ULONG,
+    mapped_write_io_count: ULONG,
+    paged_pool_pages: ULONG,
+    non_paged_pool_pages: ULONG,
+    paged_pool_allocs: ULONG,
+    paged_pool_frees: ULONG,
+    non_paged_pool_allocs: ULONG,
+    non_paged_pool_frees: ULONG,
+}
+
+/// Room for the whole `SYSTEM_PERFORMANCE_INFORMATION` of current and future versions.
+#[repr(C, align(8))]
+struct PerformanceInformationBuffer([u8; 1024]);
+
+/// Fills `times` with the [`CpuTimes`] of the active processors of group `group`, in the order of
+/// their numbers, returning how many were filled in.
+///
+/// `times` needs room for all active processors of the group, see
+/// [`ProcessorGroup::active_processors`](crate::cpu::ProcessorGroup::active_processors), or the
+/// query fails with `STATUS_INFO_LENGTH_MISMATCH`. 64 entries are enough for any group.
+///
+/// Must be called at `PASSIVE_LEVEL`.
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn cpu_times(group: u16, times: &mut [CpuTimes]) -> Result<usize, NtStatusError> {
+    verify::at_passive_level();
+
+    let query = ZW_QUERY_SYSTEM_INFORMATION_EX
+        .get()
+        .ok_or(NtStatusError::STATUS_NOT_IMPLEMENTED)?;
+
+    let mut group = group;
+    let mut len = 0;
+    // SAFETY: All pointers are valid for the lengths passed with them, and `CpuTimes` has the
+    // layout of `SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION`, which any bit pattern is valid for.
+    NtStatus::from(unsafe {
+        query(
+            SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION,
+            (&mut group as *mut u16).cast(),
+            size_of::<u16>() as ULONG,
+            times.as_mut_ptr().cast(),
+            size_of_val(times) as ULONG,
+            &mut len,
+        )
+    })
+    .result_for("ZwQuerySystemInformationEx")?;
+
+    Ok(len as usize / size_of::<CpuTimes>())
+}
+
+/// Queries the memory usage of the system.
+///
+/// Must be called at `PASSIVE_LEVEL`.
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn memory_info() -> Result<MemoryInfo, NtStatusError> {
+    verify::at_passive_level();
+
+    let query = ZW_QUERY_SYSTEM_INFORMATION
+        .get()
+        .ok_or(NtStatusError::STATUS_NOT_IMPLEMENTED)?;
+
+    let mut basic = SystemBasicInformation::default();
+    // SAFETY: `basic` is valid for its size, and has the layout of `SYSTEM_BASIC_INFORMATION`.
+    NtStatus::from(unsafe {
+        query(
+            SYSTEM_BASIC_INFORMATION,
+            (&mut basic as *mut SystemBasicInformation).cast(),
+            size_of::<SystemBasicInformation>() as ULONG,
+            null_mut(),
+        )
+    })
+    .result_for("ZwQuerySystemInformation")?;
+
+    let mut buffer = PerformanceInformationBuffer([0; 1024]);
+    let mut len = 0;
+    // SAFETY: `buffer` is valid for its size.
+    NtStatus::from(unsafe {
+        query(
+            SYSTEM_PERFORMANCE_INFORMATION,
+            buffer.0.as_mut_ptr().cast(),
+            size_of::<PerformanceInformationBuffer>() as ULONG,
+            &mut len,
+        )
+    })
+    .result_for("ZwQuerySystemInformation")?;
+
+    if (len as usize) < size_of::<SystemPerformanceInformation>() {
+        return Err(NtStatusError::STATUS_INFO_LENGTH_MISMATCH);
+    }
+    // SAFETY: The kernel filled in at least the start of the structure, which is aligned by the
+    // buffer, and any bit pattern is valid for it.
+    let performance = unsafe { &*buffer.0.as_ptr().cast::<SystemPerformanceInformation>() };
+
+    Ok(MemoryInfo {
+        page_size: basic.page_size,
+        reserved: 0,
+        physical_pages: basic.number_of_physical_pages.into(),
+        available_pages: performance.available_pages.into(),
+        committed_pages: performance.committed_pages.into(),
+        commit_limit: performance.commit_limit.into(),
+        peak_commitment: performance.peak_commitment.into(),
+        paged_pool_pages: performance.paged_pool_pages.into(),
+        non_paged_pool_pages: performance.non_paged_pool_pages.into(),
+        paged_pool_allocs: performance.paged_pool_allocs,
+        paged_pool_frees: performance.paged_pool_frees,
+        non_paged_pool_allocs: performance.non_paged_pool_allocs,
+        non_paged_pool_frees: performance.non_paged_pool_frees,
+    })
+}
@@ -1,29 +1,258 @@
 use crate::mode::ProcessorMode;
-use core::time::Duration;
-use km_sys::{KeDelayExecutionThread, LARGE_INTEGER};
+use core::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use km_sys::{
+    KeDelayExecutionThread, KeQueryPerformanceCounter, KeQuerySystemTimePrecise, LARGE_INTEGER,
+};
 
 /// Sleep in kernel-mode, non-alertable.
 ///
+/// Sleeps for at least `d`, rounded down to units of 100ns, and up to the granularity of the
+/// system timer.
+///
 /// > Where possible, Alertable should be set to FALSE and WaitMode should be set to KernelMode, in
 /// > order to reduce driver complexity. The principal exception to this guideline is when the wait
 /// > is a long-term wait.
 pub fn sleep_km(d: Duration) {
-    // the API needs units of 100ns.
-    let ns100 = i64::try_from(
+    let mut time = LARGE_INTEGER {
+        QuadPart: relative_timeout(d),
+    };
+
+    // SAFETY: Just an FFI call, nothing special here since both processor mode and alertability are pre-set.
+    let _ = unsafe {
+        KeDelayExecutionThread(ProcessorMode::KernelMode.into(), false.into(), &mut time)
+    };
+}
+
+/// Converts a duration to a relative timeout value as used by kernel and WDF APIs, i.e. the
+/// negated number of 100ns units, saturating at the longest timeout.
+pub(crate) fn relative_timeout(d: Duration) -> i64 {
+    // the APIs need units of 100ns.
+    i64::try_from(
         d.as_secs()
             .saturating_mul(10_000_000)
-            .saturating_add((d.subsec_nanos() / 10) as u64),
+            .saturating_add((d.subsec_nanos() / 100) as u64),
     )
-    // Positive values mean that the sleep duration is converted to a date/time, meaning that it
-    // will be affected by system time changes. Negative values mean that the sleep duration is
-    // fully relative, and will not be affected by system time changes.
+    // Positive values mean that the duration is converted to a date/time, meaning that it will be
+    // affected by system time changes. Negative values mean that the duration is fully relative,
+    // and will not be affected by system time changes.
     .map(|v| v.saturating_neg())
-    .unwrap_or(i64::MIN);
+    .unwrap_or(i64::MIN)
+}
 
-    let mut time = LARGE_INTEGER { QuadPart: ns100 };
+const NANOS_PER_SEC: u128 = 1_000_000_000;
 
-    // SAFETY: Just an FFI call, nothing special here since both processor mode and alertability are pre-set.
-    let _ = unsafe {
-        KeDelayExecutionThread(ProcessorMode::KernelMode.into(), false.into(), &mut time)
-    };
+/// The frequency of the performance counter in ticks per second, or 0 if not queried yet. It is
+/// fixed at boot, so it only needs to be queried once.
+static PERFORMANCE_FREQUENCY: AtomicU64 = AtomicU64::new(0);
+
+/// A monotonic timestamp of the performance counter, for measuring elapsed time (e.g. latencies),
+/// like `std::time::Instant`.
+///
+/// Can be used at any IRQL. See [`KeQueryPerformanceCounter`][MSDN] for more information.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kequeryperformancecounter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(u64);
+
+impl Instant {
+    pub fn now() -> Self {
+        let mut frequency = LARGE_INTEGER { QuadPart: 0 };
+        let query_frequency = PERFORMANCE_FREQUENCY.load(Ordering::Relaxed) == 0;
+
+        // SAFETY: The frequency is either null or a valid pointer.
+        let counter = unsafe {
+            KeQueryPerformanceCounter(if query_frequency {
+                &mut frequency
+            } else {
+                core::ptr::null_mut()
+            })
+        };
+
+        if query_frequency {
+            // SAFETY: `QuadPart` is always valid.
+            let frequency = unsafe { frequency.QuadPart };
+            PERFORMANCE_FREQUENCY.store(frequency as u64, Ordering::Relaxed);
+        }
+
+        // SAFETY: `QuadPart` is always valid. The counter is never negative.
+        Self(unsafe { counter.QuadPart } as u64)
+    }
+
+    /// The frequency of the performance counter in ticks per second.
+    pub fn frequency() -> u64 {
+        match PERFORMANCE_FREQUENCY.load(Ordering::Relaxed) {
+            0 => {
+                Self::now();
+                PERFORMANCE_FREQUENCY.load(Ordering::Relaxed)
+            }
+            frequency => frequency,
+        }
+    }
+
+    /// The raw value of the performance counter, in [ticks](Self::frequency).
+    pub fn ticks(self) -> u64 {
+        self.0
+    }
+
+    /// The time elapsed since `earlier`, or zero if `earlier` is later than `self`.
+    pub fn duration_since(self, earlier: Instant) -> Duration {
+        ticks_to_duration(self.0.saturating_sub(earlier.0))
+    }
+
+    /// The time elapsed since `self`.
+    pub fn elapsed(self) -> Duration {
+        Self::now().duration_since(self)
+    }
+
+    pub fn checked_add(self, duration: Duration) -> Option<Instant> {
+        self.0.checked_add(duration_to_ticks(duration)?).map(Self)
+    }
+
+    pub fn checked_sub(self, duration: Duration) -> Option<Instant> {
+        self.0.checked_sub(duration_to_ticks(duration)?).map(Self)
+    }
+
+    /// Adds `duration`, saturating at the latest representable instant instead of overflowing.
+    pub fn saturating_add(self, duration: Duration) -> Instant {
+        self.checked_add(duration).unwrap_or(Self(u64::MAX))
+    }
+}
+
+fn ticks_to_duration(ticks: u64) -> Duration {
+    let nanos = ticks as u128 * NANOS_PER_SEC / Instant::frequency() as u128;
+
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+fn duration_to_ticks(duration: Duration) -> Option<u64> {
+    (duration.as_nanos() * Instant::frequency() as u128 / NANOS_PER_SEC)
+        .try_into()
+        .ok()
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, rhs: Instant) -> Self::Output {
+        self.duration_since(rhs)
+    }
+}
+
+/// The system time (UTC), for timestamping e.g. sensor samples, like `std::time::SystemTime`.
+///
+/// Unlike [`Instant`], it isn't monotonic, as the system time may be changed. It is stored in
+/// units of 100ns since January 1, 1601 (like a `FILETIME`).
+///
+/// Can be used at any IRQL. See [`KeQuerySystemTimePrecise`][MSDN] for more information.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kequerysystemtimeprecise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemTime(i64);
+
+impl SystemTime {
+    /// January 1, 1970 (UTC).
+    pub const UNIX_EPOCH: SystemTime = SystemTime(116_444_736_000_000_000);
+
+    pub fn now() -> Self {
+        let mut time = LARGE_INTEGER { QuadPart: 0 };
+
+        // SAFETY: `time` is a valid pointer.
+        unsafe { KeQuerySystemTimePrecise(&mut time) };
+
+        // SAFETY: `QuadPart` is always valid.
+        Self(unsafe { time.QuadPart })
+    }
+
+    /// Creates a system time from units of 100ns since January 1, 1601.
+    pub const fn from_raw(time: i64) -> Self {
+        Self(time)
+    }
+
+    /// The system time in units of 100ns since January 1, 1601.
+    pub const fn as_raw(self) -> i64 {
+        self.0
+    }
+
+    /// The time elapsed since `earlier`, or `None` if `earlier` is later than `self`.
+    pub fn duration_since(self, earlier: SystemTime) -> Option<Duration> {
+        let units = u64::try_from(self.0.checked_sub(earlier.0)?).ok()?;
+
+        Some(Duration::new(
+            units / 10_000_000,
+            (units % 10_000_000) as u32 * 100,
+        ))
+    }
+
+    /// The time elapsed since the [Unix epoch](Self::UNIX_EPOCH), or `None` if `self` is earlier.
+    pub fn unix_time(self) -> Option<Duration> {
+        self.duration_since(Self::UNIX_EPOCH)
+    }
+
+    pub fn checked_add(self, duration: Duration) -> Option<SystemTime> {
+        self.0.checked_add(duration_to_units(duration)?).map(Self)
+    }
+
+    pub fn checked_sub(self, duration: Duration) -> Option<SystemTime> {
+        self.0.checked_sub(duration_to_units(duration)?).map(Self)
+    }
+}
+
+/// Converts a duration to units of 100ns.
+fn duration_to_units(duration: Duration) -> Option<i64> {
+    (duration.as_nanos() / 100).try_into().ok()
+}
+
+impl Add<Duration> for SystemTime {
+    type Output = SystemTime;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to system time")
+    }
+}
+
+impl Sub<Duration> for SystemTime {
+    type Output = SystemTime;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from system time")
+    }
 }
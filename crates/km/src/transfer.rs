@@ -0,0 +1,283 @@
+//! The driver side of the chunked transfer protocol of [`km_shared::transfer`], for receiving large
+//! blobs (e.g. firmware images) from user mode.
+//!
+//! A [`TransferReceiver`] validates the IOCTLs of the protocol and copies the chunks into its
+//! buffer, e.g. a [`Memory`](crate::wdf::memory::Memory) object allocated from pool, or the slice
+//! of a [`ContiguousBuffer`](crate::alloc::ContiguousBuffer) for devices that read the blob by its
+//! physical address. The receiver doesn't synchronize itself, so the IOCTLs have to come from a
+//! sequential queue, or the receiver has to be locked:
+//!
+//! ```rs, ignore
+//! const IOCTLS: TransferIoctls = transfer_ioctls(DeviceType::custom(0x8000), 0x900);
+//!
+//! // in the queue's `EvtIoDeviceControl`
+//! let input = request.retrieve_input_buffer(0)?;
+//! match receiver.dispatch(&IOCTLS, code, &input) {
+//!     Some(Ok(status)) => {
+//!         // SAFETY: The output buffer isn't retrieved anywhere else.
+//!         let mut output = unsafe { request.retrieve_output_buffer(size_of::<TransferStatus>()) }?;
+//!         output.write_value(0, &status)?;
+//!         request.set_information(size_of::<TransferStatus>() as u64);
+//!         request.complete(NtStatus::STATUS_SUCCESS);
+//!     }
+//!     Some(Err(e)) => request.complete(e.nt_status().into()),
+//!     None => { /* another code */ }
+//! }
+//!
+//! if let Some(image) = receiver.blob() {
+//!     flash(image)?;
+//!     receiver.reset();
+//! }
+//! ```
+
+use core::{mem::size_of, ops::DerefMut};
+use km_shared::{
+    checksum::CRC32_ISO_HDLC,
+    ioctl::IoControlCode,
+    ntstatus::NtStatusError,
+    transfer::{ChunkHeader, TransferBegin, TransferIoctls, TransferState, TransferStatus},
+};
+use snafu::Snafu;
+
+/// An error returned by [`TransferReceiver`], failing the IOCTL that caused it. See
+/// [`Self::nt_status`] for the statuses sent to the client.
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+pub enum TransferError {
+    /// The blob doesn't fit into the buffer.
+    #[snafu(display("blob of {len} bytes doesn't fit into {capacity} bytes"))]
+    TooLarge { len: u64, capacity: usize },
+    /// The chunk has more data than the receiver accepts.
+    #[snafu(display("chunk of {len} bytes is larger than {max_len} bytes"))]
+    ChunkTooLarge { len: u32, max_len: u32 },
+    /// The input is shorter than the code's input type, or a chunk's data doesn't match its length.
+    #[snafu(display("malformed input"))]
+    MalformedInput,
+    /// There is no transfer with the given ID.
+    #[snafu(display("no transfer {transfer_id}"))]
+    UnknownTransfer { transfer_id: u32 },
+    /// The chunk isn't the next one, or the transfer was ended before all chunks were received.
+    #[snafu(display("expected chunk {expected_sequence} at offset {expected_offset}"))]
+    OutOfSequence {
+        expected_sequence: u32,
+        expected_offset: u64,
+    },
+    /// The data of the chunk doesn't match its CRC.
+    #[snafu(display("chunk {sequence} is corrupted"))]
+    ChunkCorrupted { sequence: u32 },
+    /// The whole blob doesn't match the CRC it was announced with. The transfer is discarded.
+    #[snafu(display("blob is corrupted"))]
+    BlobCorrupted,
+}
+
+impl TransferError {
+    /// The status the IOCTL is completed with, as documented by [`km_shared::transfer`].
+    pub fn nt_status(&self) -> NtStatusError {
+        match self {
+            Self::TooLarge { .. } | Self::ChunkTooLarge { .. } => {
+                NtStatusError::STATUS_INVALID_BUFFER_SIZE
+            }
+            Self::MalformedInput => NtStatusError::STATUS_INVALID_PARAMETER,
+            Self::UnknownTransfer { .. } => NtStatusError::STATUS_INVALID_DEVICE_STATE,
+            Self::OutOfSequence { .. } => NtStatusError::STATUS_REQUEST_OUT_OF_SEQUENCE,
+            Self::ChunkCorrupted { .. } | Self::BlobCorrupted => NtStatusError::STATUS_CRC_ERROR,
+        }
+    }
+}
+
+/// Receives blobs into a buffer, see the [module documentation](self).
+///
+/// The buffer only holds one blob at a time. It's exposed through [`Self::blob`] once the whole
+/// blob was received and its CRC matched, until the next transfer is begun.
+#[derive(Debug)]
+pub struct TransferReceiver<B> {
+    buffer: B,
+    status: TransferStatus,
+    crc32: u32,
+}
+
+impl<B: DerefMut<Target = [u8]>> TransferReceiver<B> {
+    /// Receives blobs of up to the length of `buffer`, in chunks of at most `max_chunk_len`
+    /// bytes.
+    ///
+    /// The chunks are copied out of the IOCTLs' buffers, which are allocated by the I/O manager
+    /// for each request, so `max_chunk_len` should be a few pages at most.
+    pub fn new(buffer: B, max_chunk_len: u32) -> Self {
+        Self {
+            buffer,
+            status: TransferStatus::idle(max_chunk_len),
+            crc32: 0,
+        }
+    }
+
+    /// The status of the current transfer, as returned by the IOCTLs.
+    pub fn status(&self) -> TransferStatus {
+        self.status
+    }
+
+    /// The received blob, if the last transfer was completed.
+    pub fn blob(&self) -> Option<&[u8]> {
+        (self.status.state == TransferState::COMPLETE)
+            .then(|| &self.buffer[..self.status.total_len as usize])
+    }
+
+    /// Discards the current transfer, e.g. after its blob was used.
+    pub fn reset(&mut self) {
+        self.status = TransferStatus::idle(self.status.max_chunk_len);
+    }
+
+    /// Returns the buffer, discarding the current transfer.
+    pub fn into_buffer(self) -> B {
+        self.buffer
+    }
+
+    /// Handles [`TransferIoctls::begin`].
+    ///
+    /// Resumes the current transfer if it has the same ID, length, and CRC. Otherwise, the current
+    /// transfer is discarded, and a new one is started.
+    pub fn begin(&mut self, begin: &TransferBegin) -> Result<TransferStatus, TransferError> {
+        if self.status.is_transfer(begin.transfer_id)
+            && self.status.total_len == begin.total_len
+            && self.crc32 == begin.crc32
+        {
+            return Ok(self.status);
+        }
+
+        self.reset();
+        snafu::ensure!(
+            begin.total_len <= self.buffer.len() as u64,
+            TooLargeSnafu {
+                len: begin.total_len,
+                capacity: self.buffer.len(),
+            }
+        );
+
+        self.status = TransferStatus {
+            transfer_id: begin.transfer_id,
+            state: TransferState::RECEIVING,
+            total_len: begin.total_len,
+            ..self.status
+        };
+        self.crc32 = begin.crc32;
+
+        Ok(self.status)
+    }
+
+    /// Handles [`TransferIoctls::chunk`], with `input` being the whole input buffer of the
+    /// request.
+    pub fn chunk(&mut self, input: &[u8]) -> Result<TransferStatus, TransferError> {
+        let (header, data) = input
+            .split_at_checked(size_of::<ChunkHeader>())
+            .ok_or(TransferError::MalformedInput)?;
+        let header: ChunkHeader = bytemuck::pod_read_unaligned(header);
+
+        snafu::ensure!(data.len() == header.len as usize, MalformedInputSnafu);
+        self.ensure_receiving(header.transfer_id)?;
+        snafu::ensure!(
+            header.len <= self.status.max_chunk_len,
+            ChunkTooLargeSnafu {
+                len: header.len,
+                max_len: self.status.max_chunk_len,
+            }
+        );
+        snafu::ensure!(
+            header.sequence == self.status.next_sequence
+                && header.offset == self.status.next_offset
+                && header.offset + u64::from(header.len) <= self.status.total_len,
+            OutOfSequenceSnafu {
+                expected_sequence: self.status.next_sequence,
+                expected_offset: self.status.next_offset,
+            }
+        );
+        snafu::ensure!(
+            CRC32_ISO_HDLC.checksum(data) == header.crc32,
+            ChunkCorruptedSnafu {
+                sequence: header.sequence,
+            }
+        );
+
+        let offset = header.offset as usize;
+        self.buffer[offset..][..data.len()].copy_from_slice(data);
+
+        self.status.next_sequence += 1;
+        self.status.next_offset += u64::from(header.len);
+
+        Ok(self.status)
+    }
+
+    /// Handles [`TransferIoctls::end`], checking the CRC of the whole blob. Ending a completed
+    /// transfer again succeeds.
+    pub fn end(&mut self, transfer_id: u32) -> Result<TransferStatus, TransferError> {
+        if self.status.is_transfer(transfer_id) && self.status.state == TransferState::COMPLETE {
+            return Ok(self.status);
+        }
+
+        self.ensure_receiving(transfer_id)?;
+        snafu::ensure!(
+            self.status.next_offset == self.status.total_len,
+            OutOfSequenceSnafu {
+                expected_sequence: self.status.next_sequence,
+                expected_offset: self.status.next_offset,
+            }
+        );
+
+        let blob = &self.buffer[..self.status.total_len as usize];
+        if CRC32_ISO_HDLC.checksum(blob) != self.crc32 {
+            self.reset();
+            return BlobCorruptedSnafu.fail();
+        }
+
+        self.status.state = TransferState::COMPLETE;
+        Ok(self.status)
+    }
+
+    /// Handles [`TransferIoctls::abort`], discarding the transfer.
+    pub fn abort(&mut self, transfer_id: u32) -> Result<TransferStatus, TransferError> {
+        snafu::ensure!(
+            self.status.is_transfer(transfer_id),
+            UnknownTransferSnafu { transfer_id }
+        );
+
+        self.reset();
+        Ok(self.status)
+    }
+
+    /// Handles `code` with `input` if it's one of `ioctls`, or returns `None` otherwise.
+    ///
+    /// On success, the returned status is the output of the IOCTL.
+    pub fn dispatch(
+        &mut self,
+        ioctls: &TransferIoctls,
+        code: IoControlCode,
+        input: &[u8],
+    ) -> Option<Result<TransferStatus, TransferError>> {
+        let result = if ioctls.begin == code {
+            read_input(input).and_then(|begin| self.begin(&begin))
+        } else if ioctls.chunk == code {
+            self.chunk(input)
+        } else if ioctls.end == code {
+            read_input(input).and_then(|transfer_id| self.end(transfer_id))
+        } else if ioctls.abort == code {
+            read_input(input).and_then(|transfer_id| self.abort(transfer_id))
+        } else {
+            return None;
+        };
+
+        Some(result)
+    }
+
+    fn ensure_receiving(&self, transfer_id: u32) -> Result<(), TransferError> {
+        snafu::ensure!(
+            self.status.is_transfer(transfer_id) && self.status.state == TransferState::RECEIVING,
+            UnknownTransferSnafu { transfer_id }
+        );
+        Ok(())
+    }
+}
+
+/// Reads the fixed-size input of a code, which may be followed by trailing bytes.
+fn read_input<T: bytemuck::Pod>(input: &[u8]) -> Result<T, TransferError> {
+    input
+        .get(..size_of::<T>())
+        .map(bytemuck::pod_read_unaligned)
+        .ok_or(TransferError::MalformedInput)
+}
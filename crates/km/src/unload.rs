@@ -0,0 +1,143 @@
+//! Coordination of driver unload with background activities.
+//!
+//! Threads, timers, DPCs and other work that may still run when the driver unloads have to be
+//! finished before the driver's code is unmapped. Each such activity holds a [`RundownGuard`] of
+//! the driver-wide rundown while it runs, and the driver's unload routine calls
+//! [`wait_for_quiescence`] to stop new activities from starting and wait for all running ones to
+//! finish:
+//!
+//! ```rs, ignore
+//! fn start_polling() {
+//!     // fails once the driver is unloading
+//!     let Some(guard) = km::unload::acquire() else { return };
+//!     spawn_worker(move || {
+//!         poll_sensors();
+//!         drop(guard);
+//!     });
+//! }
+//!
+//! unsafe extern "system" fn driver_unload(_driver: WdfObjectReference<'_, RawWdfDriver>) {
+//!     km::unload::wait_for_quiescence();
+//! }
+//! ```
+//!
+//! See [Run-Down Protection][MSDN] for more information.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/run-down-protection
+
+use crate::verify;
+use core::{cell::UnsafeCell, mem::ManuallyDrop};
+use km_sys::{
+    _EX_RUNDOWN_REF__bindgen_ty_1, ExAcquireRundownProtection, ExReleaseRundownProtection,
+    ExWaitForRundownProtectionRelease, EX_RUNDOWN_REF,
+};
+
+/// The rundown of the whole driver, used by [`acquire`] and [`wait_for_quiescence`].
+static DRIVER_RUNDOWN: Rundown = Rundown::new();
+
+/// Registers a background activity that has to finish before the driver unloads.
+///
+/// Returns `None` if the driver is already unloading, in which case the activity must not be
+/// started. Otherwise, the activity must hold the returned guard until it's done.
+///
+/// Must be called at `IRQL <= DISPATCH_LEVEL`.
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn acquire() -> Option<RundownGuard<'static>> {
+    DRIVER_RUNDOWN.acquire()
+}
+
+/// Prevents new background activities from being [registered](acquire), and waits for all
+/// registered ones to finish.
+///
+/// Call this from the driver's unload routine, before freeing anything the activities use.
+///
+/// Must be called at `PASSIVE_LEVEL`.
+#[cfg_attr(feature = "verification", track_caller)]
+pub fn wait_for_quiescence() {
+    DRIVER_RUNDOWN.wait_for_release();
+}
+
+/// A wrapper around [`EX_RUNDOWN_REF`], tracking accesses to a shared resource that has to be run
+/// down (e.g. freed) at some point.
+///
+/// Most drivers can use the driver-wide rundown through [`acquire`] and
+/// [`wait_for_quiescence`] instead. A separate `Rundown` is useful for resources with a shorter
+/// lifetime, e.g. per-device state.
+pub struct Rundown(UnsafeCell<EX_RUNDOWN_REF>);
+
+// SAFETY: `EX_RUNDOWN_REF` is designed to be used concurrently, and is only accessed through the
+// `ExXxxRundownProtection` functions.
+unsafe impl Sync for Rundown {}
+// SAFETY: See above.
+unsafe impl Send for Rundown {}
+
+impl Rundown {
+    /// Creates a new rundown that can be acquired.
+    pub const fn new() -> Self {
+        // `ExInitializeRundownProtection` initializes the count to 0, so this doesn't need to call
+        // it, and can be used to initialize statics.
+        Self(UnsafeCell::new(EX_RUNDOWN_REF {
+            __bindgen_anon_1: _EX_RUNDOWN_REF__bindgen_ty_1 { Count: 0 },
+        }))
+    }
+
+    /// Acquires run-down protection, or returns `None` if the rundown was already
+    /// [waited for](Self::wait_for_release).
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn acquire(&self) -> Option<RundownGuard<'_>> {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The rundown ref is valid and only accessed through these functions.
+        let acquired = unsafe { ExAcquireRundownProtection(self.0.get()) } != 0;
+
+        acquired.then_some(RundownGuard(self))
+    }
+
+    /// Releases run-down protection acquired by a [leaked](RundownGuard::leak) guard.
+    ///
+    /// # Safety
+    /// Must be called exactly once for each leaked guard of this rundown.
+    pub unsafe fn release_leaked(&self) {
+        // SAFETY: The caller guarantees that run-down protection is held.
+        unsafe { ExReleaseRundownProtection(self.0.get()) }
+    }
+
+    /// Makes all further [`Self::acquire`] calls fail, and waits until all guards were dropped.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn wait_for_release(&self) {
+        verify::at_passive_level();
+
+        // SAFETY: The rundown ref is valid and only accessed through these functions. Waiting
+        // multiple times is fine, as the count can't increase again.
+        unsafe { ExWaitForRundownProtectionRelease(self.0.get()) }
+    }
+}
+
+impl Default for Rundown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run-down protection held on a [`Rundown`], released on drop.
+#[must_use = "the rundown is released immediately if the guard is dropped"]
+pub struct RundownGuard<'a>(&'a Rundown);
+
+impl<'a> RundownGuard<'a> {
+    /// Keeps holding run-down protection without a guard, e.g. to pass it through the context
+    /// pointer of a DPC. It has to be released with [`Rundown::release_leaked`].
+    pub fn leak(self) -> &'a Rundown {
+        ManuallyDrop::new(self).0
+    }
+}
+
+impl Drop for RundownGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: The guard holds run-down protection, which is released exactly once here.
+        unsafe { self.0.release_leaked() }
+    }
+}
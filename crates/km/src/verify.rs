@@ -0,0 +1,221 @@
+//! Runtime checks of the requirements of the safe wrappers, enabled by the `verification` feature.
+//!
+//! Static Driver Verifier and Driver Verifier catch misuse of the underlying kernel and WDF APIs,
+//! but report it inside the framework, far from the wrapper that caused it. With the feature
+//! enabled, the wrappers check the same rules themselves, and report violations with their
+//! location before bugchecking with [`BUGCHECK_VERIFICATION_FAILED`]:
+//! - IRQL requirements (SDV's `Irql*` rules), checked by the wrappers that document them, and by
+//!   `km`'s own callbacks. Drivers can check their callbacks with [`irql_at_most`].
+//! - null handles and uninitialized driver globals, checked before every WDF call (KMDF
+//!   Verifier's handle checks).
+//!
+//! Without the feature, all checks compile to nothing, so they're meant for test builds, e.g.
+//! during HLK runs. Violations are logged with `log::error!` by default; a different
+//! [hook](set_violation_hook) can be used to e.g. record them in a [crash area](crate::crash).
+
+use core::{
+    fmt,
+    panic::Location,
+    ptr::null_mut,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+use km_sys::{
+    KeBugCheckEx, KeGetCurrentIrql, DISPATCH_LEVEL, KIRQL, PASSIVE_LEVEL, ULONG, ULONG_PTR,
+};
+
+/// The bugcheck code used for violations. The parameters are the [`ViolationKind::code`], a
+/// kind-specific value (the current IRQL for [`ViolationKind::IrqlTooHigh`] and
+/// [`ViolationKind::IrqlTooLow`]), and the address
+/// of the file name and the line of the violation's location.
+pub const BUGCHECK_VERIFICATION_FAILED: ULONG = u32::from_be_bytes(*b"RsVf");
+
+/// A rule of the wrappers that was violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// A function was called at an IRQL higher than it allows.
+    IrqlTooHigh { current: KIRQL, max: KIRQL },
+    /// A function was called at an IRQL lower than it requires.
+    IrqlTooLow { current: KIRQL, min: KIRQL },
+    /// A WDF function was called with a null handle.
+    NullHandle { function: &'static str },
+    /// A WDF function was called before the framework initialized the driver globals, i.e.
+    /// before `DriverEntry`.
+    NoDriverGlobals { function: &'static str },
+}
+
+impl ViolationKind {
+    /// The first bugcheck parameter for the violation.
+    pub const fn code(&self) -> ULONG_PTR {
+        match self {
+            Self::IrqlTooHigh { .. } => 1,
+            Self::NullHandle { .. } => 2,
+            Self::NoDriverGlobals { .. } => 3,
+            Self::IrqlTooLow { .. } => 4,
+        }
+    }
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IrqlTooHigh { current, max } => {
+                write!(f, "called at IRQL {current}, but requires IRQL <= {max}")
+            }
+            Self::IrqlTooLow { current, min } => {
+                write!(f, "called at IRQL {current}, but requires IRQL >= {min}")
+            }
+            Self::NullHandle { function } => write!(f, "`{function}` called with a null handle"),
+            Self::NoDriverGlobals { function } => {
+                write!(
+                    f,
+                    "`{function}` called before the driver globals were initialized"
+                )
+            }
+        }
+    }
+}
+
+/// A violation, passed to the [hook](set_violation_hook).
+#[derive(Debug, Clone, Copy)]
+pub struct Violation {
+    pub kind: ViolationKind,
+    /// Where the checked wrapper was called from.
+    pub location: &'static Location<'static>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.kind, self.location)
+    }
+}
+
+/// Called with a violation before bugchecking. It may be called at any IRQL.
+pub type ViolationHook = fn(&Violation);
+
+/// The hook set by [`set_violation_hook`], or null for the default.
+static HOOK: AtomicPtr<()> = AtomicPtr::new(null_mut());
+
+/// Sets the hook called with violations before bugchecking, replacing the default, which logs
+/// them with `log::error!`.
+pub fn set_violation_hook(hook: ViolationHook) {
+    HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+/// Reports a violation to the hook, and bugchecks.
+#[cold]
+#[inline(never)]
+pub(crate) fn violation(kind: ViolationKind, location: &'static Location<'static>) -> ! {
+    let violation = Violation { kind, location };
+
+    let hook = HOOK.load(Ordering::Acquire);
+    if hook.is_null() {
+        log::error!("verification failed: {violation}");
+    } else {
+        // SAFETY: Non-null values are only stored by `set_violation_hook`, from a `ViolationHook`.
+        let hook = unsafe { core::mem::transmute::<*mut (), ViolationHook>(hook) };
+        hook(&violation);
+    }
+
+    let parameter = match kind {
+        ViolationKind::IrqlTooHigh { current, .. } | ViolationKind::IrqlTooLow { current, .. } => {
+            ULONG_PTR::from(current)
+        }
+        _ => 0,
+    };
+
+    // SAFETY: FFI call. All parameters are just numbers, no additional requirements here.
+    unsafe {
+        KeBugCheckEx(
+            BUGCHECK_VERIFICATION_FAILED,
+            kind.code(),
+            parameter,
+            location.file().as_ptr() as ULONG_PTR,
+            location.line() as ULONG_PTR,
+        )
+    }
+}
+
+/// Checks that the current IRQL is at most `max`, e.g. `DISPATCH_LEVEL as KIRQL`.
+///
+/// Does nothing without the `verification` feature.
+#[inline(always)]
+#[track_caller]
+pub fn irql_at_most(max: KIRQL) {
+    if cfg!(feature = "verification") {
+        // SAFETY: FFI call; no further safety requirements
+        let current = unsafe { KeGetCurrentIrql() };
+        if current > max {
+            violation(
+                ViolationKind::IrqlTooHigh { current, max },
+                Location::caller(),
+            );
+        }
+    }
+}
+
+/// Checks that the current IRQL is at most `DISPATCH_LEVEL`, see [`irql_at_most`].
+#[inline(always)]
+#[track_caller]
+pub fn at_most_dispatch_level() {
+    irql_at_most(DISPATCH_LEVEL as KIRQL);
+}
+
+/// Checks that the current IRQL is `PASSIVE_LEVEL`, see [`irql_at_most`].
+#[inline(always)]
+#[track_caller]
+pub fn at_passive_level() {
+    irql_at_most(PASSIVE_LEVEL as KIRQL);
+}
+
+/// Checks that the current IRQL is exactly `DISPATCH_LEVEL`, e.g. for code that spins on state
+/// another thread at `DISPATCH_LEVEL` may hold.
+///
+/// Does nothing without the `verification` feature.
+#[inline(always)]
+#[track_caller]
+pub fn at_dispatch_level() {
+    irql_at_most(DISPATCH_LEVEL as KIRQL);
+
+    if cfg!(feature = "verification") {
+        // SAFETY: FFI call; no further safety requirements
+        let current = unsafe { KeGetCurrentIrql() };
+        if current < DISPATCH_LEVEL as KIRQL {
+            violation(
+                ViolationKind::IrqlTooLow {
+                    current,
+                    min: DISPATCH_LEVEL as KIRQL,
+                },
+                Location::caller(),
+            );
+        }
+    }
+}
+
+/// Checks that the driver globals are initialized before calling the WDF function `function`.
+#[cfg(feature = "verification")]
+#[inline(always)]
+#[track_caller]
+pub(crate) fn driver_globals(function: &'static str) {
+    // SAFETY: The globals are set by the KMDF stub before `DriverEntry`, and are read-only
+    // afterwards.
+    if unsafe { km_sys::WdfDriverGlobals }.is_null() {
+        violation(
+            ViolationKind::NoDriverGlobals { function },
+            Location::caller(),
+        );
+    }
+}
+
+/// Checks an argument of a WDF call, see `wdf_function!`.
+///
+/// Only handles are checked, by the inherent `WdfObjectReference::verify_argument`, which takes
+/// precedence over this blanket implementation for everything else.
+#[cfg(feature = "verification")]
+pub(crate) trait VerifyArgument {
+    #[inline(always)]
+    fn verify_argument(&self, _function: &'static str) {}
+}
+
+#[cfg(feature = "verification")]
+impl<T> VerifyArgument for T {}
@@ -0,0 +1,134 @@
+//! Waiting on dispatcher objects (events, semaphores, timers, threads) with timeouts.
+//!
+//! See [Introduction to Kernel Dispatcher Objects][MSDN] for more information.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/introduction-to-kernel-dispatcher-objects
+
+use crate::{mode::ProcessorMode, time::relative_timeout};
+use core::{cell::UnsafeCell, ffi::c_void, ptr::null_mut, time::Duration};
+use km_shared::ntstatus::NtStatus;
+use km_sys::{
+    KeWaitForSingleObject, _KTHREAD, KEVENT, KSEMAPHORE, KTIMER, KWAIT_REASON, LARGE_INTEGER,
+};
+
+/// A kernel object that can be waited on, i.e. that starts with a `DISPATCHER_HEADER`.
+///
+/// Dispatcher objects are modified by the kernel while being waited on, so they are only
+/// implemented for objects behind an [`UnsafeCell`]. Synchronization wrappers embed their
+/// objects this way, and implement this trait by forwarding to them.
+///
+/// # Safety
+/// [`Self::dispatcher_object`] must return a pointer to an initialized dispatcher object that
+/// stays valid as long as `self` is borrowed.
+pub unsafe trait DispatcherObject {
+    fn dispatcher_object(&self) -> *mut c_void;
+}
+
+// SAFETY: The event is behind an `UnsafeCell`, and is initialized before it can be waited on
+// (`KeInitializeEvent`).
+unsafe impl DispatcherObject for UnsafeCell<KEVENT> {
+    fn dispatcher_object(&self) -> *mut c_void {
+        self.get().cast()
+    }
+}
+
+// SAFETY: See above (`KeInitializeSemaphore`).
+unsafe impl DispatcherObject for UnsafeCell<KSEMAPHORE> {
+    fn dispatcher_object(&self) -> *mut c_void {
+        self.get().cast()
+    }
+}
+
+// SAFETY: See above (`KeInitializeTimer`).
+unsafe impl DispatcherObject for UnsafeCell<KTIMER> {
+    fn dispatcher_object(&self) -> *mut c_void {
+        self.get().cast()
+    }
+}
+
+// SAFETY: Threads are opaque, and only ever accessed through pointers returned by the kernel. A
+// thread is signaled when it terminates.
+unsafe impl DispatcherObject for _KTHREAD {
+    fn dispatcher_object(&self) -> *mut c_void {
+        (self as *const Self).cast_mut().cast()
+    }
+}
+
+/// Why a wait returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitResult {
+    /// The object was signaled.
+    Signaled,
+    /// The timeout expired before the object was signaled.
+    Timeout,
+    /// An alertable wait was interrupted by an alert or a user-mode APC (`STATUS_ALERTED` or
+    /// `STATUS_USER_APC`). The wait has to be retried if the object is still needed.
+    Alerted,
+}
+
+impl WaitResult {
+    fn from_status(status: NtStatus) -> Self {
+        match status {
+            NtStatus::STATUS_SUCCESS | NtStatus::STATUS_ABANDONED => WaitResult::Signaled,
+            NtStatus::STATUS_TIMEOUT => WaitResult::Timeout,
+            NtStatus::STATUS_ALERTED | NtStatus::STATUS_USER_APC => WaitResult::Alerted,
+            status => {
+                debug_assert!(false, "unexpected wait status {status}");
+                WaitResult::Signaled
+            }
+        }
+    }
+}
+
+/// Waits in kernel mode, non-alertable, until `object` is signaled or `timeout` expires. Without
+/// a timeout, this waits indefinitely.
+///
+/// Must be called at `IRQL <= APC_LEVEL`, or at `DISPATCH_LEVEL` with a zero timeout (i.e. only
+/// testing whether the object is signaled).
+///
+/// See [MSDN] for more details on the underlying function.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-kewaitforsingleobject
+pub fn wait(object: &impl DispatcherObject, timeout: Option<Duration>) -> WaitResult {
+    wait_raw(object, ProcessorMode::KernelMode, false, timeout)
+}
+
+/// Like [`wait`], but alertable, which allows the wait to be interrupted (see
+/// [`WaitResult::Alerted`]).
+///
+/// Waiting in [`ProcessorMode::UserMode`] lets user-mode APCs be delivered, and allows the kernel
+/// stack to be paged out, so the object must not be on the stack in this case. This is meant for
+/// long waits on behalf of a user-mode caller, which should be interruptible when the thread
+/// terminates.
+pub fn wait_alertable(
+    object: &impl DispatcherObject,
+    mode: ProcessorMode,
+    timeout: Option<Duration>,
+) -> WaitResult {
+    wait_raw(object, mode, true, timeout)
+}
+
+fn wait_raw(
+    object: &impl DispatcherObject,
+    mode: ProcessorMode,
+    alertable: bool,
+    timeout: Option<Duration>,
+) -> WaitResult {
+    let mut timeout = timeout.map(|d| LARGE_INTEGER {
+        QuadPart: relative_timeout(d),
+    });
+
+    // SAFETY: The object is guaranteed to be a valid dispatcher object, and `timeout` is either
+    // null (waiting indefinitely) or a valid relative timeout.
+    let status = unsafe {
+        KeWaitForSingleObject(
+            object.dispatcher_object(),
+            KWAIT_REASON::Executive,
+            mode.into(),
+            alertable.into(),
+            timeout.as_mut().map_or(null_mut(), |t| t as *mut _),
+        )
+    };
+
+    WaitResult::from_status(NtStatus(status))
+}
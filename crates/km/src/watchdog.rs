@@ -0,0 +1,543 @@
+//! Deadlines for operations that depend on the hardware, so that a device that stops responding
+//! doesn't hang requests indefinitely.
+//!
+//! An operation is registered with a [`Watchdog`] when it starts, along with its deadline and
+//! optionally the request waiting for it. A periodic framework timer checks for operations that
+//! are overdue, and calls the watchdog's [`OverduePolicy`] for each of them, e.g. one of the
+//! [provided policies](policy):
+//!
+//! ```rs, ignore
+//! static WATCHDOG: Watchdog<8> = Watchdog::new(policy::complete_request);
+//!
+//! // in `EvtDriverDeviceAdd`, or wherever the device is created
+//! WATCHDOG.start(&device, Duration::from_millis(500))?;
+//!
+//! // in the I/O control handler, with the firmware answering asynchronously
+//! let guard = WATCHDOG.watch_request("firmware query", Duration::from_secs(2), request)?;
+//! device.with_context(&DEVICE_CONTEXT, |ctx| ctx.set_pending(guard));
+//!
+//! // when the firmware answers
+//! if let Some(request) = pending.finish() {
+//!     request.complete(NtStatus::STATUS_SUCCESS);
+//! }
+//! ```
+//!
+//! The policy runs at most once per operation, at `DISPATCH_LEVEL`, and doesn't cancel the
+//! operation itself, which has to be [finished](WatchdogGuard::finish) as usual.
+//!
+//! I/O control requests that are handled asynchronously can be watched with
+//! [`Watchdog::watch_ioctl`] instead, which completes them with `STATUS_IO_TIMEOUT` regardless of
+//! the policy, and discards the result of work that finishes too late.
+//!
+//! Timeouts are cooperative: the watchdog never interrupts the work itself, which keeps running
+//! (and holds its slot) until it's finished, so long-running work should check
+//! [`PendingIoctl::is_timed_out`] and stop early. Watched requests also aren't cancelable, so if
+//! the client cancels its I/O, the request is only completed once the work finishes or times out.
+
+use crate::{
+    declare_wdf_object_context_type,
+    time::Instant,
+    verify,
+    wdf::{
+        object_attributes::{ObjectAttributes, ObjectAttributesInit},
+        request::{IoCtlError, Request},
+        timer::{Timer, TimerConfig, TimerConfigInit},
+        AsWdfReference, ExecutionLevel, RawWdfTimer, WdfObjectReference,
+    },
+};
+use bytemuck::{CheckedBitPattern, NoUninit};
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicU64, AtomicU8, Ordering},
+    time::Duration,
+};
+use km_shared::{
+    ioctl::{TypedIoControlCode, Validate},
+    ntstatus::{NtStatus, NtStatusError},
+};
+use km_sys::ULONG;
+
+/// The bugcheck code used by [`policy::bugcheck_in_debug`]. The parameters are a pointer to the
+/// name of the operation, the length of the name, and how long the operation was running, in
+/// milliseconds.
+pub const BUGCHECK_WATCHDOG_OVERDUE: ULONG = u32::from_be_bytes(*b"RsWd");
+
+/// Called for each overdue operation, see [`policy`] for the provided ones.
+pub type OverduePolicy = fn(overdue: &mut Overdue<'_>);
+
+/// The slot isn't used.
+const FREE: u8 = 0;
+/// The operation is being registered or finished by its guard, which owns the slot's operation.
+const CLAIMED: u8 = 1;
+/// The operation is running, and the guard or the timer may claim it.
+const ARMED: u8 = 2;
+/// The policy is running for the operation, which the timer owns.
+const FIRING: u8 = 3;
+/// The policy ran, and the operation waits for its guard.
+const FIRED: u8 = 4;
+
+struct Slot {
+    state: AtomicU8,
+    /// The deadline in [ticks](Instant::ticks), which the timer reads without owning the slot.
+    deadline: AtomicU64,
+    /// Only accessed by whoever moved the state to `CLAIMED` or `FIRING`.
+    operation: UnsafeCell<Option<Operation>>,
+}
+
+struct Operation {
+    name: &'static str,
+    started: Instant,
+    request: Option<Request>,
+    /// Whether the request is completed with `STATUS_IO_TIMEOUT` after the policy ran, see
+    /// [`Watchdog::watch_ioctl`].
+    complete_on_timeout: bool,
+}
+
+impl Slot {
+    const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(FREE),
+            deadline: AtomicU64::new(0),
+            operation: UnsafeCell::new(None),
+        }
+    }
+}
+
+/// Watches up to `N` operations at a time, see the [module documentation](self) for an overview.
+///
+/// Watchdogs are meant to be `static`s, as the timer checking them refers to them.
+pub struct Watchdog<const N: usize> {
+    slots: [Slot; N],
+    policy: OverduePolicy,
+}
+
+// SAFETY: The operation of a slot is only accessed by whoever claimed the slot through its state,
+// which may be any thread, and requests can be completed from any thread.
+unsafe impl<const N: usize> Sync for Watchdog<N> {}
+
+impl<const N: usize> Watchdog<N> {
+    pub const fn new(policy: OverduePolicy) -> Self {
+        Self {
+            slots: [const { Slot::new() }; N],
+            policy,
+        }
+    }
+
+    /// Starts checking for overdue operations every `period`, on a timer belonging to `parent`
+    /// (e.g. the device), which is deleted together with it. The period bounds how late the
+    /// policy may run, so it should be a fraction of the shortest timeout.
+    ///
+    /// The policy runs at `DISPATCH_LEVEL`, so it can't wait or touch pageable memory. Fails with
+    /// `STATUS_INVALID_PARAMETER` if the period is zero.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn start(
+        &'static self,
+        parent: &impl AsWdfReference,
+        period: Duration,
+    ) -> Result<Timer, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        if period.is_zero() {
+            return Err(NtStatusError::STATUS_INVALID_PARAMETER);
+        }
+
+        // Periodic timers can't run at `PASSIVE_LEVEL`, so it isn't inherited from the parent.
+        let mut attributes = ObjectAttributes::new_with_context(
+            ObjectAttributesInit {
+                execution_level: ExecutionLevel::WdfExecutionLevelDispatch,
+                ..Default::default()
+            },
+            &KM_WATCHDOG,
+        );
+        attributes.set_parent(parent);
+
+        let timer = Timer::create(
+            &mut TimerConfig::new(TimerConfigInit {
+                period,
+                tolerable_delay: period / 4,
+                ..TimerConfigInit::one_shot(evt_check)
+            }),
+            &mut attributes,
+        )?;
+
+        // The timer was just created, so its context can't be initialized already.
+        let _ = KM_WATCHDOG.initialize(&timer, self);
+
+        timer.start(period);
+        Ok(timer)
+    }
+
+    /// Watches an operation that has to finish within `timeout`, e.g. waiting for the hardware.
+    /// Returns `None` if all `N` slots are in use.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn watch(&self, name: &'static str, timeout: Duration) -> Option<WatchdogGuard<'_>> {
+        verify::at_most_dispatch_level();
+
+        self.arm(name, timeout, None, false).ok()
+    }
+
+    /// Like [`Self::watch`], but for an operation that `request` is pending on, which the policy
+    /// can complete once the operation is overdue. Returns the request back if all `N` slots are
+    /// in use.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn watch_request(
+        &self,
+        name: &'static str,
+        timeout: Duration,
+        request: Request,
+    ) -> Result<WatchdogGuard<'_>, Request> {
+        verify::at_most_dispatch_level();
+
+        self.arm(name, timeout, Some(request), false)
+            .map_err(|request| request.expect("a request was given"))
+    }
+
+    /// Watches an I/O control request whose handler completes it asynchronously, e.g. once the
+    /// hardware answers. If the handler doesn't [complete](PendingIoctl::complete) it within
+    /// `timeout`, the request is completed with `STATUS_IO_TIMEOUT` after the policy ran (unless
+    /// the policy completed it already), and the late result is discarded. Returns the request back
+    /// if all `N` slots are in use.
+    ///
+    /// The timeout only completes the request; the work isn't interrupted, and has to poll
+    /// [`PendingIoctl::is_timed_out`] to abandon it early. The request isn't marked cancelable, so
+    /// if the client cancels it, it's still only completed once the work finishes or times out.
+    ///
+    /// ```rs, ignore
+    /// let pending = WATCHDOG.watch_ioctl("read fan", Duration::from_secs(1), request, IOCTL_READ_FAN)?;
+    /// fans.start_read(pending);
+    ///
+    /// // in the DPC of the fan controller
+    /// pending.complete(|index, rpm| {
+    ///     *rpm = fans.rpm(*index);
+    ///     NtStatus::STATUS_SUCCESS
+    /// });
+    /// ```
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn watch_ioctl<I, O>(
+        &self,
+        name: &'static str,
+        timeout: Duration,
+        request: Request,
+        ioctl: TypedIoControlCode<I, O>,
+    ) -> Result<PendingIoctl<'_, I, O>, Request>
+    where
+        I: CheckedBitPattern + Validate,
+        O: NoUninit + CheckedBitPattern,
+    {
+        verify::at_most_dispatch_level();
+
+        self.arm(name, timeout, Some(request), true)
+            .map(|guard| PendingIoctl { guard, ioctl })
+            .map_err(|request| request.expect("a request was given"))
+    }
+
+    /// Calls the policy for each operation that is overdue and wasn't reported yet. This is done
+    /// by the timer of [`Self::start`], but can be called manually too, e.g. from a DPC before
+    /// waiting for the hardware again.
+    ///
+    /// Must be called at `DISPATCH_LEVEL`: [finishing](WatchdogGuard::finish) an operation spins
+    /// while the policy runs for it, which would never return if it preempted the check on the
+    /// same processor.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn check(&self) {
+        verify::at_dispatch_level();
+
+        let now = Instant::now();
+
+        for slot in &self.slots {
+            // The slot is claimed before reading the deadline, so it can't be of an operation that
+            // was registered in the meantime.
+            if slot
+                .state
+                .compare_exchange(ARMED, FIRING, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                continue;
+            }
+            if slot.deadline.load(Ordering::Relaxed) > now.ticks() {
+                slot.state.store(ARMED, Ordering::Release);
+                continue;
+            }
+
+            // SAFETY: The state was moved to `FIRING`, so the timer owns the operation.
+            if let Some(operation) = unsafe { &mut *slot.operation.get() } {
+                (self.policy)(&mut Overdue {
+                    name: operation.name,
+                    elapsed: now.duration_since(operation.started),
+                    request: &mut operation.request,
+                });
+
+                if operation.complete_on_timeout {
+                    if let Some(request) = operation.request.take() {
+                        request.complete(NtStatusError::STATUS_IO_TIMEOUT.into());
+                    }
+                }
+            }
+
+            slot.state.store(FIRED, Ordering::Release);
+        }
+    }
+
+    /// Claims a free slot for the operation, or returns the request back if there is none.
+    fn arm(
+        &self,
+        name: &'static str,
+        timeout: Duration,
+        request: Option<Request>,
+        complete_on_timeout: bool,
+    ) -> Result<WatchdogGuard<'_>, Option<Request>> {
+        let Some(slot) = self.slots.iter().find(|slot| {
+            slot.state
+                .compare_exchange(FREE, CLAIMED, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        }) else {
+            return Err(request);
+        };
+
+        let started = Instant::now();
+        let deadline = started
+            .checked_add(timeout)
+            .map_or(u64::MAX, Instant::ticks);
+
+        // SAFETY: The state was moved to `CLAIMED`, so this caller owns the operation.
+        unsafe {
+            *slot.operation.get() = Some(Operation {
+                name,
+                started,
+                request,
+                complete_on_timeout,
+            })
+        };
+        slot.deadline.store(deadline, Ordering::Relaxed);
+        slot.state.store(ARMED, Ordering::Release);
+
+        Ok(WatchdogGuard { slot: Some(slot) })
+    }
+}
+
+declare_wdf_object_context_type! {
+    static KM_WATCHDOG => &'static dyn Check;
+}
+
+/// Type-erases the number of slots of a [`Watchdog`] for the timer's context.
+trait Check: Sync {
+    fn check(&self);
+}
+
+impl<const N: usize> Check for Watchdog<N> {
+    fn check(&self) {
+        Watchdog::check(self)
+    }
+}
+
+unsafe extern "system" fn evt_check(timer: WdfObjectReference<'_, RawWdfTimer>) {
+    // the timer is created with `DISPATCH_LEVEL`
+    verify::at_dispatch_level();
+
+    KM_WATCHDOG.with(&timer, |watchdog| watchdog.check());
+}
+
+/// A watched operation, see [`Watchdog::watch`]. The operation is finished when the guard is
+/// [finished](Self::finish) or dropped.
+#[must_use = "the operation is finished immediately if the guard is dropped"]
+pub struct WatchdogGuard<'a> {
+    slot: Option<&'a Slot>,
+}
+
+// SAFETY: The guard only accesses the slot through its state, and may finish it on any thread.
+unsafe impl Send for WatchdogGuard<'_> {}
+
+impl WatchdogGuard<'_> {
+    /// Finishes the operation, returning its request unless the policy completed it.
+    ///
+    /// If the policy is running for the operation right now, this spins until it returns.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn finish(mut self) -> Option<Request> {
+        verify::at_most_dispatch_level();
+
+        self.release()
+    }
+
+    /// Whether the operation is past its deadline, regardless of whether the policy ran yet.
+    pub fn is_overdue(&self) -> bool {
+        self.slot
+            .is_some_and(|slot| slot.deadline.load(Ordering::Relaxed) <= Instant::now().ticks())
+    }
+
+    fn release(&mut self) -> Option<Request> {
+        let slot = self.slot.take()?;
+
+        loop {
+            match slot.state.load(Ordering::Acquire) {
+                // The timer never touches a fired slot again, so only an armed one can race.
+                state @ (ARMED | FIRED) => {
+                    if slot
+                        .state
+                        .compare_exchange(state, CLAIMED, Ordering::Acquire, Ordering::Relaxed)
+                        .is_ok()
+                    {
+                        break;
+                    }
+                }
+                // The policy is running for the operation.
+                _ => core::hint::spin_loop(),
+            }
+        }
+
+        // SAFETY: The state was moved to `CLAIMED`, so this guard owns the operation.
+        let operation = unsafe { (*slot.operation.get()).take() };
+        slot.state.store(FREE, Ordering::Release);
+
+        operation.and_then(|operation| operation.request)
+    }
+}
+
+impl Drop for WatchdogGuard<'_> {
+    /// Finishes the operation, completing its request with `STATUS_CANCELLED` unless the policy
+    /// completed it already, as requests must always be completed.
+    fn drop(&mut self) {
+        if let Some(request) = self.release() {
+            request.complete(NtStatusError::STATUS_CANCELLED.into());
+        }
+    }
+}
+
+/// An I/O control request pending on a watched operation, see [`Watchdog::watch_ioctl`].
+///
+/// Dropping it without completing it finishes the operation, and completes the request with
+/// `STATUS_CANCELLED`, e.g. if the work was canceled.
+#[must_use = "the request is canceled immediately if it's dropped"]
+pub struct PendingIoctl<'a, I, O> {
+    guard: WatchdogGuard<'a>,
+    ioctl: TypedIoControlCode<I, O>,
+}
+
+impl<I, O> PendingIoctl<'_, I, O>
+where
+    I: CheckedBitPattern + Validate,
+    O: NoUninit + CheckedBitPattern,
+{
+    /// Finishes the operation, and completes the request with the status returned by `f`, which
+    /// is called with the typed buffers of the request.
+    ///
+    /// Returns `false` without calling `f` if the request was completed already because the
+    /// operation was overdue, in which case the buffers mustn't be accessed anymore. If the
+    /// buffers don't fit the types, the request is completed with an error status instead.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn complete(self, f: impl FnOnce(&I, &mut O) -> NtStatus) -> bool {
+        let Self { guard, ioctl } = self;
+        let Some(request) = guard.finish() else {
+            return false;
+        };
+
+        // SAFETY: The request was owned by the watchdog until now, so its output buffer can't be
+        // accessed by another `Request`.
+        let status = match unsafe { request.handle_ioctl(ioctl, f) } {
+            Ok(status) => status,
+            Err(IoCtlError::NtStatus { source }) => source.into(),
+            Err(_) => NtStatusError::STATUS_INVALID_PARAMETER.into(),
+        };
+        request.complete(status);
+        true
+    }
+
+    /// Whether the operation is past its deadline, so the work can be abandoned early, as its
+    /// result would be discarded.
+    pub fn is_timed_out(&self) -> bool {
+        self.guard.is_overdue()
+    }
+}
+
+/// An overdue operation, passed to the [`OverduePolicy`].
+pub struct Overdue<'a> {
+    name: &'a str,
+    elapsed: Duration,
+    request: &'a mut Option<Request>,
+}
+
+impl Overdue<'_> {
+    /// The name the operation was registered with.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The time since the operation was registered.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Takes the request pending on the operation, e.g. to complete it, in which case
+    /// [`WatchdogGuard::finish`] returns `None`.
+    pub fn take_request(&mut self) -> Option<Request> {
+        self.request.take()
+    }
+}
+
+/// The provided [`OverduePolicy`]s.
+pub mod policy {
+    use super::{Overdue, BUGCHECK_WATCHDOG_OVERDUE};
+    use crate::{km_error, km_warn};
+    use km_shared::ntstatus::{NtStatus, NtStatusError};
+    use km_sys::ULONG_PTR;
+
+    /// Logs the overdue operation, and leaves it and its request alone.
+    pub fn log(overdue: &mut Overdue<'_>) {
+        km_warn!(
+            "operation {:?} overdue, running for {:?}",
+            overdue.name(),
+            overdue.elapsed()
+        );
+    }
+
+    /// Logs the overdue operation, and completes its request with `STATUS_IO_TIMEOUT`, so the
+    /// caller isn't blocked any longer.
+    pub fn complete_request(overdue: &mut Overdue<'_>) {
+        log(overdue);
+
+        if let Some(request) = overdue.take_request() {
+            request.complete(NtStatus::from(NtStatusError::STATUS_IO_TIMEOUT));
+        }
+    }
+
+    /// Logs the overdue operation, and bugchecks with [`BUGCHECK_WATCHDOG_OVERDUE`] in debug
+    /// builds, so the state of the driver and hardware can be inspected in the crash dump. In
+    /// release builds, it completes the request like [`complete_request`].
+    pub fn bugcheck_in_debug(overdue: &mut Overdue<'_>) {
+        km_error!(
+            "operation {:?} overdue, running for {:?}",
+            overdue.name(),
+            overdue.elapsed()
+        );
+
+        if cfg!(debug_assertions) {
+            let elapsed_ms = overdue.elapsed().as_millis();
+
+            // SAFETY: FFI call. All parameters are just numbers, no additional requirements here.
+            unsafe {
+                km_sys::KeBugCheckEx(
+                    BUGCHECK_WATCHDOG_OVERDUE,
+                    overdue.name().as_ptr() as ULONG_PTR,
+                    overdue.name().len() as ULONG_PTR,
+                    elapsed_ms as ULONG_PTR,
+                    0,
+                );
+            }
+        }
+
+        if let Some(request) = overdue.take_request() {
+            request.complete(NtStatus::from(NtStatusError::STATUS_IO_TIMEOUT));
+        }
+    }
+}
@@ -1,24 +1,44 @@
+pub mod collection;
 pub mod context;
 pub mod device;
 pub mod device_init;
+pub mod device_list;
+pub mod device_name;
 pub mod driver;
 pub mod driver_config;
 mod ffi;
 pub mod file_object;
 pub mod io_queue;
+pub mod io_target;
+pub mod ioctl_policy;
+pub mod memory;
+pub mod notifier;
 mod object;
 pub mod object_attributes;
+pub mod power_policy;
+pub mod registry;
 pub mod request;
 pub mod security;
+pub mod settings;
+pub mod spin_lock;
+pub mod throttle;
+pub mod timer;
+pub mod usb;
+pub mod wait_lock;
 
 pub use km_sys::WDF_DEVICE_IO_TYPE as DeviceIoType;
 pub use km_sys::WDF_EXECUTION_LEVEL as ExecutionLevel;
 pub use km_sys::WDF_SYNCHRONIZATION_SCOPE as SynchronizationScope;
 
 pub use km_sys::{
-    WDFDEVICE__ as RawWdfDevice, WDFDRIVER__ as RawWdfDriver, WDFFILEOBJECT__ as RawWdfFileObject,
-    WDFQUEUE__ as RawWdfQueue, WDFREQUEST__ as RawWdfRequest,
+    WDFCOLLECTION__ as RawWdfCollection, WDFDEVICE__ as RawWdfDevice, WDFDRIVER__ as RawWdfDriver,
+    WDFFILEOBJECT__ as RawWdfFileObject, WDFIOTARGET__ as RawWdfIoTarget, WDFKEY__ as RawWdfKey,
+    WDFMEMORY__ as RawWdfMemory, WDFQUEUE__ as RawWdfQueue, WDFREQUEST__ as RawWdfRequest,
+    WDFSPINLOCK__ as RawWdfSpinLock, WDFSTRING__ as RawWdfString, WDFTIMER__ as RawWdfTimer,
+    WDFUSBDEVICE__ as RawWdfUsbDevice, WDFUSBINTERFACE__ as RawWdfUsbInterface,
+    WDFUSBPIPE__ as RawWdfUsbPipe, WDFWAITLOCK__ as RawWdfWaitLock,
 };
 pub type RawWdfObject = libc::c_void;
 
+pub use ffi::is_function_available;
 pub use object::*;
@@ -0,0 +1,260 @@
+use super::{
+    ffi, object_attributes::ObjectAttributes, AsWdfReference, OwnedWdfObject, RawWdfCollection,
+    RawWdfObject, WdfObjectReference,
+};
+use crate::{verify, AsRawMutPtr, Sealed};
+use core::{marker::PhantomData, ops::Range, ptr::null_mut};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{ULONG, WDFCOLLECTION, WDF_OBJECT_ATTRIBUTES};
+
+/// A framework collection object, an ordered list of framework objects of the type `T` (e.g.
+/// [`RawWdfDevice`](super::RawWdfDevice) or [`RawWdfRequest`](super::RawWdfRequest)).
+///
+/// The collection holds a reference on each of its items, so they stay valid until they're
+/// removed, or the collection is deleted.
+///
+/// The framework doesn't synchronize accesses to a collection, so modifying it requires a
+/// `&mut Collection`, which also guarantees that no references to removed items are held. Shared
+/// collections have to be put behind a lock, e.g. a [`WaitLock`](super::wait_lock::WaitLock).
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/framework-object-collections
+#[derive(Debug)]
+pub struct Collection<T: 'static>(OwnedWdfObject<RawWdfCollection>, PhantomData<T>);
+impl<T> Sealed for Collection<T> {}
+
+impl<T> AsWdfReference for Collection<T> {
+    type ObjectType = RawWdfCollection;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl<T> Collection<T> {
+    /// Creates a new, empty collection. Without a parent set in `attributes`, the collection
+    /// belongs to the driver object.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcollection/nf-wdfcollection-wdfcollectioncreate
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn create(mut attributes: Option<&mut ObjectAttributes>) -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let mut collection: WDFCOLLECTION = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe {
+            ffi::collection_create(
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                &mut collection,
+            )
+        }
+        .result_for("WdfCollectionCreate")?;
+
+        debug_assert!(!collection.is_null());
+
+        Ok(Self(OwnedWdfObject::from_new_raw(collection), PhantomData))
+    }
+
+    /// Deletes the collection before its parent is deleted, releasing the references on its items.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    /// The collection mustn't be used through other references to its handle after this is
+    /// called, e.g. ones [owned](super::WdfObjectReference::to_owned) from
+    /// [`AsWdfReference::as_wdf_ref`].
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfobject/nf-wdfobject-wdfobjectdelete
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn delete(self) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: Collections are created by the driver, which may delete them. The caller
+        // guarantees that the collection isn't used anymore.
+        unsafe { self.0.delete() }
+    }
+
+    /// The number of items in the collection.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn len(&self) -> usize {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The collection is guaranteed to be valid.
+        unsafe { ffi::collection_get_count(self.0.as_wdf_ref()) as usize }
+    }
+
+    /// Whether the collection has no items.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `item` to the end of the collection, taking a reference on it.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcollection/nf-wdfcollection-wdfcollectionadd
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn add(&mut self, item: &impl AsWdfReference<ObjectType = T>) -> Result<(), NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The collection and the item are guaranteed to be valid.
+        unsafe { ffi::collection_add(self.0.as_wdf_ref(), item.as_wdf_ref().upcast()) }
+            .result_for("WdfCollectionAdd")?;
+
+        Ok(())
+    }
+
+    /// Removes `item` from the collection, releasing the reference on it. Returns whether it was
+    /// in the collection.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcollection/nf-wdfcollection-wdfcollectionremove
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn remove(&mut self, item: &impl AsWdfReference<ObjectType = T>) -> bool {
+        let raw = item.as_wdf_ref().raw_obj();
+        let Some(index) = self.iter().position(|i| i.raw_obj() == raw) else {
+            return false;
+        };
+
+        // SAFETY: The collection and the item are guaranteed to be valid, and the item is in the
+        // collection, which the framework requires.
+        unsafe { ffi::collection_remove(self.0.as_wdf_ref(), self.raw_item(index)) };
+        true
+    }
+
+    /// Removes the item at `index`, releasing the reference on it. Returns whether `index` was in
+    /// bounds.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcollection/nf-wdfcollection-wdfcollectionremoveitem
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn remove_at(&mut self, index: usize) -> bool {
+        if index >= self.len() {
+            return false;
+        }
+
+        // SAFETY: The collection is guaranteed to be valid, and the index is in bounds, which the
+        // framework requires.
+        unsafe { ffi::collection_remove_item(self.0.as_wdf_ref(), index as ULONG) };
+        true
+    }
+
+    /// Removes all items, releasing the references on them.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn clear(&mut self) {
+        for index in (0..self.len()).rev() {
+            // SAFETY: The collection is guaranteed to be valid, and the index is in bounds.
+            unsafe { ffi::collection_remove_item(self.0.as_wdf_ref(), index as ULONG) };
+        }
+    }
+
+    /// The item at `index`, or `None` if it's out of bounds.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcollection/nf-wdfcollection-wdfcollectiongetitem
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn get(&self, index: usize) -> Option<WdfObjectReference<'_, T>> {
+        (index < self.len()).then(|| {
+            // SAFETY: Only objects of the type `T` can be added to the collection.
+            unsafe { self.raw_item(index).downcast() }
+        })
+    }
+
+    /// The first item, or `None` if the collection is empty.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn first(&self) -> Option<WdfObjectReference<'_, T>> {
+        self.get(0)
+    }
+
+    /// The last item, or `None` if the collection is empty.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn last(&self) -> Option<WdfObjectReference<'_, T>> {
+        self.len().checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Iterates over the items in order.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`, as must the iterator be advanced.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            collection: self,
+            indices: 0..self.len(),
+        }
+    }
+
+    /// The item at `index`, which must be in bounds.
+    fn raw_item(&self, index: usize) -> WdfObjectReference<'_, RawWdfObject> {
+        // SAFETY: The collection is guaranteed to be valid, and the caller checked the index.
+        let item = unsafe { ffi::collection_get_item(self.0.as_wdf_ref(), index as ULONG) };
+        debug_assert!(!item.raw().is_null());
+        item
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Collection<T> {
+    type Item = WdfObjectReference<'a, T>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the items of a [`Collection`], see [`Collection::iter`].
+pub struct Iter<'a, T: 'static> {
+    collection: &'a Collection<T>,
+    indices: Range<usize>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = WdfObjectReference<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        self.collection.get(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back()?;
+        self.collection.get(index)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
@@ -1,5 +1,17 @@
-use super::{ffi::object_get_typed_context_worker, AsWdfReference};
-use core::marker::PhantomData;
+use super::{
+    ffi::{object_allocate_context, object_get_typed_context_worker},
+    object_attributes::{ObjectAttributes, ObjectAttributesInit, ObjectEventCallback},
+    AsWdfReference, OwnedWdfObject, RawWdfObject, WdfObjectReference,
+};
+use crate::verify;
+use core::{
+    marker::PhantomData,
+    mem::size_of,
+    ops::Deref,
+    ptr::{addr_of_mut, null_mut, NonNull},
+    sync::atomic::{AtomicU8, Ordering},
+};
+use km_shared::ntstatus::NtStatusError;
 pub use km_sys::WDF_OBJECT_CONTEXT_TYPE_INFO;
 
 /// Info for a user-defined context type associated to a WDF object.
@@ -9,19 +21,45 @@ pub use km_sys::WDF_OBJECT_CONTEXT_TYPE_INFO;
 /// Context type info must be declared statically, which is done by using the
 /// [`crate::declare_wdf_object_context_type!`] macro.
 ///
+/// Contexts can either be used raw through [`Self::get`], or be [initialized](Self::initialize)
+/// with a value of `T`, which can then be accessed safely through a [`ContextHandle`] or
+/// [`Self::with`], and is dropped when the object is destroyed.
+///
 /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/framework-object-context-space
-#[repr(transparent)]
-pub struct WdfObjectContextTypeInfo<T>(WDF_OBJECT_CONTEXT_TYPE_INFO, PhantomData<*mut T>);
+// `repr(C)` with the raw info first, as WDF uses the address of the static as the
+// `WDF_OBJECT_CONTEXT_TYPE_INFO` of the type (see `UniqueType`).
+#[repr(C)]
+pub struct WdfObjectContextTypeInfo<T> {
+    info: WDF_OBJECT_CONTEXT_TYPE_INFO,
+    destroy: ObjectEventCallback,
+    _marker: PhantomData<*mut T>,
+}
 
 // SAFETY: Needed to create statics of this type. There is no exposed safe way to create this type.
 unsafe impl<T> Sync for WdfObjectContextTypeInfo<T> {}
 
 impl<T> WdfObjectContextTypeInfo<T> {
+    /// The size of the context allocated by WDF, which is larger than `T` to track whether the
+    /// context was initialized.
+    pub const CONTEXT_SIZE: usize = size_of::<ContextSlot<T>>();
+
     /// # Safety
     /// Not to be used directly. Use the [`crate::declare_wdf_object_context_type!`] macro instead.
     #[must_use]
-    pub const unsafe fn _internal_new(info: WDF_OBJECT_CONTEXT_TYPE_INFO) -> Self {
-        Self(info, PhantomData)
+    pub const unsafe fn _internal_new(
+        info: WDF_OBJECT_CONTEXT_TYPE_INFO,
+        destroy: ObjectEventCallback,
+    ) -> Self {
+        Self {
+            info,
+            destroy,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The destroy callback generated by the macro, which calls [`Self::destroy`].
+    pub(crate) fn destroy_callback(&self) -> ObjectEventCallback {
+        self.destroy
     }
 
     /// Retrieves a pointer to the object's context. On allocation of the context, its memory is
@@ -45,12 +83,275 @@ impl<T> WdfObjectContextTypeInfo<T> {
     #[must_use]
     pub unsafe fn get(&self, object: &impl AsWdfReference) -> *mut T {
         // SAFETY: All the requirements to make this sound are moved onto the caller.
-        unsafe { object_get_typed_context_worker(object.as_wdf_ref().upcast(), &self.0).cast() }
+        unsafe { object_get_typed_context_worker(object.as_wdf_ref().upcast(), &self.info).cast() }
+    }
+
+    /// Retrieves the object's context slot, or `None` if the object has no context of this type.
+    fn slot(
+        &self,
+        object: WdfObjectReference<'_, RawWdfObject>,
+    ) -> Option<NonNull<ContextSlot<T>>> {
+        // SAFETY: The object is guaranteed to be valid, and WDF returns null if the object has no
+        // context of this type.
+        NonNull::new(unsafe { object_get_typed_context_worker(object, &self.info) }.cast())
+    }
+
+    /// Initializes the object's context with `value`, returning a handle to it.
+    ///
+    /// This should be done once, right after creating the object with attributes created by
+    /// [`ObjectAttributes::new_with_context`]. The value is dropped when the object is destroyed.
+    ///
+    /// Returns `value` back if the object has no context of this type, or if the context was
+    /// already initialized.
+    ///
+    /// [`ObjectAttributes::new_with_context`]: super::object_attributes::ObjectAttributes::new_with_context
+    pub fn initialize<R: AsWdfReference>(
+        &'static self,
+        object: &R,
+        value: T,
+    ) -> Result<ContextHandle<R::ObjectType, T>, T> {
+        let Some(slot) = self.slot(object.as_wdf_ref().upcast()) else {
+            return Err(value);
+        };
+
+        // SAFETY: The slot is valid while the object is, and `state` is only ever accessed
+        // atomically.
+        let state = unsafe { &(*slot.as_ptr()).state };
+        if state
+            .compare_exchange(
+                ContextSlot::<T>::UNINITIALIZED,
+                ContextSlot::<T>::INITIALIZING,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_err()
+        {
+            return Err(value);
+        }
+
+        // SAFETY: The slot is valid, and we are the only one accessing the value, as the state
+        // only allows this once. Other accessors wait for the `INITIALIZED` state.
+        unsafe { addr_of_mut!((*slot.as_ptr()).value).write(value) };
+        state.store(ContextSlot::<T>::INITIALIZED, Ordering::Release);
+
+        Ok(ContextHandle {
+            object: object.as_wdf_ref().to_owned(),
+            slot,
+        })
+    }
+
+    /// Allocates a context of this type for an object that was created without it, and
+    /// [initializes](Self::initialize) it with `value`.
+    ///
+    /// This is needed for objects created by the framework, e.g. requests in an
+    /// [`EvtIoInCallerContext`](super::device_init::EvtIoInCallerContext) callback. On failure,
+    /// e.g. if the object already has a context of this type, `value` is returned back.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfobject/nf-wdfobject-wdfobjectallocatecontext
+    pub fn allocate<R: AsWdfReference>(
+        &'static self,
+        object: &R,
+        value: T,
+    ) -> Result<ContextHandle<R::ObjectType, T>, (T, NtStatusError)> {
+        self.allocate_with(object, value, Default::default())
+    }
+
+    /// Like [`Self::allocate`], but with the callbacks in `init`, e.g. a cleanup callback that
+    /// runs when the object is deleted.
+    pub fn allocate_with<R: AsWdfReference>(
+        &'static self,
+        object: &R,
+        value: T,
+        init: ObjectAttributesInit,
+    ) -> Result<ContextHandle<R::ObjectType, T>, (T, NtStatusError)> {
+        let mut attributes = ObjectAttributes::new_with_context(init, self);
+        let mut context = null_mut();
+
+        // SAFETY: The object is guaranteed to be valid, and the attributes describe this context
+        // type, including the destroy callback dropping it.
+        let status = unsafe {
+            object_allocate_context(
+                object.as_wdf_ref().upcast(),
+                &mut attributes.0,
+                &mut context,
+            )
+        };
+        if let Err(e) = status.result_for("WdfObjectAllocateContext") {
+            return Err((value, e));
+        }
+
+        self.initialize(object, value)
+            .map_err(|value| (value, NtStatusError::STATUS_OBJECT_NAME_COLLISION))
+    }
+
+    /// Returns a handle to the object's context, or `None` if the object has no context of this
+    /// type or it wasn't [initialized](Self::initialize) yet.
+    pub fn handle<R: AsWdfReference>(
+        &'static self,
+        object: &R,
+    ) -> Option<ContextHandle<R::ObjectType, T>> {
+        let slot = self.slot(object.as_wdf_ref().upcast())?;
+
+        // SAFETY: The slot is valid while the object is borrowed.
+        unsafe { ContextSlot::value(slot) }?;
+
+        Some(ContextHandle {
+            object: object.as_wdf_ref().to_owned(),
+            slot,
+        })
+    }
+
+    /// Calls `f` with the object's context. Returns `None` if the object has no context of this
+    /// type or it wasn't [initialized](Self::initialize) yet.
+    ///
+    /// Like for [`ContextHandle::get`], `T` has to be `Sync`, as WDF may call into the driver
+    /// concurrently for the same object.
+    pub fn with<R>(
+        &'static self,
+        object: &impl AsWdfReference,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R>
+    where
+        T: Sync,
+    {
+        let slot = self.slot(object.as_wdf_ref().upcast())?;
+
+        // SAFETY: The slot is valid while the object is, which is borrowed for the duration of
+        // `f`. An initialized value is only mutably accessed through the unsafe
+        // `ContextHandle::get_mut`, whose caller ensures exclusive access.
+        unsafe { ContextSlot::value(slot) }.map(f)
+    }
+
+    /// Drops the object's context, if it was [initialized](Self::initialize).
+    ///
+    /// This is called automatically if the object was created with attributes created by
+    /// [`ObjectAttributes::new_with_context`] without a custom destroy callback. Custom destroy
+    /// callbacks have to call this themselves.
+    ///
+    /// # Safety
+    /// May only be called from the object's `EvtDestroyCallback`. No [`ContextHandle`]s or
+    /// references to the context may be used afterwards.
+    ///
+    /// [`ObjectAttributes::new_with_context`]: super::object_attributes::ObjectAttributes::new_with_context
+    pub unsafe fn destroy(&self, object: WdfObjectReference<'_, RawWdfObject>) {
+        // destroy callbacks run at `IRQL <= DISPATCH_LEVEL`
+        verify::at_most_dispatch_level();
+
+        let Some(slot) = self.slot(object) else {
+            return;
+        };
+
+        // SAFETY: The slot is valid until the destroy callback returns, and `state` is only ever
+        // accessed atomically.
+        let state = unsafe { &(*slot.as_ptr()).state };
+        if state.swap(ContextSlot::<T>::UNINITIALIZED, Ordering::Acquire)
+            == ContextSlot::<T>::INITIALIZED
+        {
+            // SAFETY: The value was initialized, and the caller guarantees it isn't used anymore.
+            unsafe { addr_of_mut!((*slot.as_ptr()).value).drop_in_place() };
+        }
     }
 
     #[must_use]
     pub const fn as_ptr(&'static self) -> *const WDF_OBJECT_CONTEXT_TYPE_INFO {
-        &self.0
+        &self.info
+    }
+}
+
+/// The memory WDF allocates for a context of type `T`.
+///
+/// The value comes first, so that a pointer to the slot is also a pointer to the (possibly
+/// uninitialized) value, as returned by [`WdfObjectContextTypeInfo::get`]. The context is
+/// zero-initialized by WDF, which is the `UNINITIALIZED` state.
+#[repr(C)]
+struct ContextSlot<T> {
+    value: T,
+    state: AtomicU8,
+}
+
+impl<T> ContextSlot<T> {
+    const UNINITIALIZED: u8 = 0;
+    const INITIALIZING: u8 = 1;
+    const INITIALIZED: u8 = 2;
+
+    /// Returns the value, if initialized.
+    ///
+    /// # Safety
+    /// `slot` must be valid for the lifetime `'a`.
+    unsafe fn value<'a>(slot: NonNull<Self>) -> Option<&'a T> {
+        // SAFETY: Guaranteed by the caller.
+        let slot = unsafe { slot.as_ref() };
+        (slot.state.load(Ordering::Acquire) == Self::INITIALIZED).then_some(&slot.value)
+    }
+}
+
+/// A typed handle to the [initialized](WdfObjectContextTypeInfo::initialize) context of a WDF
+/// object of type `O`.
+///
+/// The handle keeps a reference to the object, so the context stays valid as long as the handle
+/// exists. Note that the context is still dropped when the object is destroyed, which happens once
+/// all references to it are released *and* it was deleted.
+///
+/// # Synchronization
+/// WDF may call into the driver concurrently for the same object, e.g. from multiple I/O queue
+/// callbacks, so shared access requires `T: Sync` (i.e. use atomics or locks inside the context).
+/// Mutable access is only possible through the unsafe [`Self::get_mut`].
+pub struct ContextHandle<O: 'static, T: 'static> {
+    object: OwnedWdfObject<O>,
+    slot: NonNull<ContextSlot<T>>,
+}
+
+// SAFETY: The handle only gives shared access to the context if `T: Sync`, and the context may be
+// dropped on whichever thread releases the last reference to the object, so it has to be `Send`.
+unsafe impl<O, T: Send + Sync> Send for ContextHandle<O, T> {}
+// SAFETY: See above.
+unsafe impl<O, T: Send + Sync> Sync for ContextHandle<O, T> {}
+
+impl<O, T> ContextHandle<O, T> {
+    /// The object the context belongs to.
+    pub fn object(&self) -> WdfObjectReference<'_, O> {
+        self.object.as_ref()
+    }
+
+    pub fn get(&self) -> &T
+    where
+        T: Sync,
+    {
+        // SAFETY: A handle is only created for initialized contexts, and the slot is valid as
+        // long as the handle holds a reference to the object.
+        unsafe { &(*self.slot.as_ptr()).value }
+    }
+
+    /// Returns a mutable reference to the context.
+    ///
+    /// # Safety
+    /// The caller must ensure that no other references to the context exist while the returned
+    /// reference is used, e.g. because the object's callbacks are serialized by its
+    /// [`SynchronizationScope`](super::SynchronizationScope), or because the object wasn't
+    /// published to WDF callbacks or other threads yet.
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_mut(&self) -> &mut T {
+        // SAFETY: See `get`. Exclusive access is guaranteed by the caller.
+        unsafe { &mut (*self.slot.as_ptr()).value }
+    }
+}
+
+impl<O, T: Sync> Deref for ContextHandle<O, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<O, T> Clone for ContextHandle<O, T> {
+    fn clone(&self) -> Self {
+        Self {
+            object: self.object.clone(),
+            slot: self.slot,
+        }
     }
 }
 
@@ -72,17 +373,26 @@ macro_rules! declare_wdf_object_context_type {
         $(#[$attr])*
         #[no_mangle]
         #[used]
-        $vis static $accessor_name: $crate::wdf::context::WdfObjectContextTypeInfo<$t> =
+        $vis static $accessor_name: $crate::wdf::context::WdfObjectContextTypeInfo<$t> = {
+            unsafe extern "system" fn destroy(
+                object: $crate::wdf::WdfObjectReference<'_, $crate::wdf::RawWdfObject>,
+            ) {
+                // SAFETY: Only registered as the destroy callback of objects with this context.
+                unsafe { $accessor_name.destroy(object) }
+            }
+
             // SAFETY: Macro generated, correct initialization
             unsafe { $crate::wdf::context::WdfObjectContextTypeInfo::_internal_new(
                 $crate::wdf::context::WDF_OBJECT_CONTEXT_TYPE_INFO {
                     Size: ::core::mem::size_of::<$crate::wdf::context::WDF_OBJECT_CONTEXT_TYPE_INFO>()
                         as u32,
                     ContextName: concat!(::core::stringify!($t), "\0").as_ptr() as *mut _,
-                    ContextSize: ::core::mem::size_of::<$t>(),
+                    ContextSize: $crate::wdf::context::WdfObjectContextTypeInfo::<$t>::CONTEXT_SIZE,
                     UniqueType: &$accessor_name as *const _ as *const _,
                     EvtDriverGetUniqueContextType: None,
-                }
-            ) };
+                },
+                destroy,
+            ) }
+        };
     };
 }
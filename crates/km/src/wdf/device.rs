@@ -1,10 +1,15 @@
 use super::{
+    context::WdfObjectContextTypeInfo,
+    device_list::DEVICES,
+    device_name::DeviceNames,
     ffi,
     io_queue::{IoQueue, IoQueueConfig},
     object_attributes::ObjectAttributes,
+    power_policy::{S0IdleSettings, SxWakeSettings},
+    request::Request,
     AsWdfReference, OwnedWdfObject, RawWdfDevice, WdfObjectReference,
 };
-use crate::{AsRawMutPtr, Sealed};
+use crate::{verify, AsRawMutPtr, Sealed};
 use core::ptr::null_mut;
 use km_shared::{
     ntstatus::{NtStatus, NtStatusError},
@@ -49,9 +54,39 @@ impl Device {
         // SAFETY: The wrapped `WDFDEVICE` is guaranteed to be valid, and `symbolic_link_name` is
         // guaranteed to be a valid pointer. `create_symbolic_link` can also be called multiple
         // times.
-        unsafe { ffi::device_create_symbolic_link(self.as_wdf_ref(), symbolic_link_name) }.result()
+        unsafe { ffi::device_create_symbolic_link(self.as_wdf_ref(), symbolic_link_name) }
+            .result_for("WdfDeviceCreateSymbolicLink")
     }
 
+    /// Creates the first symbolic link of `names` that doesn't exist yet, for devices created by
+    /// [`DeviceInit::create_uniquely_named_device`].
+    ///
+    /// Names are tried from the number of the device name on while creating the link fails with
+    /// `STATUS_OBJECT_NAME_COLLISION`. Afterwards, `names` holds the name the link was created
+    /// with, see [`DeviceNames::symbolic_link_index`].
+    ///
+    /// [`DeviceInit::create_uniquely_named_device`]: super::device_init::DeviceInit::create_uniquely_named_device
+    pub fn create_unique_symbolic_link(
+        &mut self,
+        names: &mut DeviceNames,
+    ) -> Result<NtStatus, NtStatusError> {
+        names.catch_up_symbolic_link_name();
+
+        loop {
+            match self.create_symbolic_link(&names.symbolic_link_name()) {
+                Err(e)
+                    if e == NtStatusError::STATUS_OBJECT_NAME_COLLISION
+                        && names.next_symbolic_link_name() => {}
+                result => return result,
+            }
+        }
+    }
+
+    /// Creates a queue for the device. Queues of devices created by
+    /// [`DeviceInit::create_device`](super::device_init::DeviceInit::create_device) are purged by
+    /// [`DeviceList::teardown`](super::device_list::DeviceList::teardown).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
     pub fn create_io_queue(
         &mut self,
         config: &mut IoQueueConfig,
@@ -70,15 +105,125 @@ impl Device {
                 &mut queue,
             )
         }
-        .result()?;
+        .result_for("WdfIoQueueCreate")?;
 
         debug_assert!(!queue.is_null());
 
         // SAFETY: `queue` is guaranteed to be valid here.
-        Ok(unsafe { IoQueue::new(OwnedWdfObject::from_new_raw(queue)) })
+        let queue = unsafe { IoQueue::new(OwnedWdfObject::from_new_raw(queue)) };
+        DEVICES.add_queue(self, &queue)?;
+
+        Ok(queue)
+    }
+
+    /// Deletes the control device, which the driver has to do before it's unloaded, or when the
+    /// device it controls is removed. On unload, [`DeviceList::teardown`] deletes all devices in
+    /// the right order. This also removes it from the
+    /// [devices of the driver](super::driver::Driver::devices).
+    ///
+    /// Other clones of the device keep its memory valid, but mustn't be used anymore.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    /// No other clone of the device, or a queue, file object or request of it, may be used after
+    /// this is called.
+    ///
+    /// [`DeviceList::teardown`]: super::device_list::DeviceList::teardown
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfobject/nf-wdfobject-wdfobjectdelete
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn delete(self) {
+        verify::at_passive_level();
+
+        // SAFETY: Only control devices can be created, which the driver has to delete itself. The
+        // caller guarantees that the device isn't used anymore.
+        unsafe { self.0.delete() }
+    }
+
+    /// Hands a request back to the framework from an
+    /// [`EvtIoInCallerContext`](super::device_init::EvtIoInCallerContext) callback, which then
+    /// dispatches it to the device's queues.
+    ///
+    /// On failure, the request is returned, and has to be completed by the driver.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nf-wdfdevice-wdfdeviceenqueuerequest
+    pub fn enqueue_request(&self, request: Request) -> Result<(), (Request, NtStatusError)> {
+        // SAFETY: The device and request are guaranteed to be valid.
+        let status =
+            unsafe { ffi::device_enqueue_request(self.as_wdf_ref(), request.as_wdf_ref()) };
+
+        match status.result_for("WdfDeviceEnqueueRequest") {
+            Ok(_) => Ok(()),
+            Err(e) => Err((request, e)),
+        }
+    }
+
+    /// Assigns the S0 idle settings of the device, i.e. whether and when the framework powers it
+    /// down while the system is running. Can be called again to change them.
+    ///
+    /// Fails with `STATUS_INVALID_DEVICE_REQUEST` if the driver isn't the power policy owner of a
+    /// PnP device, e.g. for control devices. See the [`power_policy`](super::power_policy) module.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nf-wdfdevice-wdfdeviceassigns0idlesettings
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn assign_s0_idle_settings(
+        &mut self,
+        settings: &mut S0IdleSettings,
+    ) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        // SAFETY: The device and the settings are guaranteed to be valid.
+        unsafe { ffi::device_assign_s_0_idle_settings(self.as_wdf_ref(), &mut settings.0) }
+            .result_for("WdfDeviceAssignS0IdleSettings")?;
+        Ok(())
+    }
+
+    /// Assigns the Sx wake settings of the device, i.e. whether it can wake the system from
+    /// sleep. Can be called again to change them.
+    ///
+    /// Fails with `STATUS_INVALID_DEVICE_REQUEST` if the driver isn't the power policy owner of a
+    /// PnP device, e.g. for control devices, and with `STATUS_POWER_STATE_INVALID` if the device
+    /// can't wake the system. See the [`power_policy`](super::power_policy) module.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nf-wdfdevice-wdfdeviceassignsxwakesettings
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn assign_sx_wake_settings(
+        &mut self,
+        settings: &mut SxWakeSettings,
+    ) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        // SAFETY: The device and the settings are guaranteed to be valid.
+        unsafe { ffi::device_assign_sx_wake_settings(self.as_wdf_ref(), &mut settings.0) }
+            .result_for("WdfDeviceAssignSxWakeSettings")?;
+        Ok(())
+    }
+
+    /// Calls `f` with the device's context of the given type. Returns `None` if the device has no
+    /// such context, or it wasn't [initialized](WdfObjectContextTypeInfo::initialize) yet.
+    pub fn with_context<T: Sync, R>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        context_type.with(self, f)
     }
 }
 
+/// A [`Device`] that didn't finish initializing yet, passed to the `configure` closure of
+/// [`DeviceInit::create_device`](super::device_init::DeviceInit::create_device).
 pub struct DeviceNonInitialized {
     pub(crate) device: Device,
 }
@@ -95,8 +240,10 @@ impl DeviceNonInitialized {
 
     /// Finishes the initialization of the device.
     ///
-    /// The system stops rejecting I/O requests to the device after this function is called.
-    pub fn finish_initialization(self) -> Device {
+    /// The system stops rejecting I/O requests to the device after this function is called. This
+    /// is done by [`DeviceInit::create_device`](super::device_init::DeviceInit::create_device)
+    /// once the device is configured, so it can't be forgotten.
+    pub(crate) fn finish_initialization(self) -> Device {
         // SAFETY: FFI call; the device is guaranteed to be valid.
         unsafe {
             ffi::control_finish_initializing(self.device.as_wdf_ref());
@@ -1,41 +1,109 @@
 use super::{
     device::{Device, DeviceNonInitialized},
+    device_list::DEVICES,
+    device_name::DeviceNames,
     ffi,
     file_object::FileObjectConfig,
     object_attributes::ObjectAttributes,
-    DeviceIoType, OwnedWdfObject,
+    DeviceIoType, OwnedWdfObject, RawWdfDevice, RawWdfRequest, WdfObjectReference,
 };
-use crate::{AsRawMutPtr, AsRawPtr};
-use core::ptr::{null_mut, NonNull};
-use km_shared::{
-    ntstatus::{NtStatus, NtStatusError},
-    strings::UnicodeString,
+use crate::{private::Sealed, AsRawMutPtr};
+use bitflags::bitflags;
+use core::{
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ptr::{null_mut, NonNull},
 };
-use km_sys::{BOOLEAN, WDFDEVICE, WDFDEVICE_INIT, WDF_OBJECT_ATTRIBUTES};
+use km_shared::{ntstatus::NtStatusError, strings::UnicodeString};
+use km_sys::{
+    BOOLEAN, UCHAR, WDFDEVICE, WDFDEVICE_INIT, WDFREQUEST, WDF_DEVICE_SHUTDOWN_FLAGS,
+    WDF_OBJECT_ATTRIBUTES,
+};
+
+/// Whether a name was assigned to a [`DeviceInit`]. See [`Unnamed`] and [`Named`].
+pub trait DeviceInitState: Sealed {}
+
+/// No name was assigned to the [`DeviceInit`] yet.
+pub struct Unnamed;
+impl Sealed for Unnamed {}
+impl DeviceInitState for Unnamed {}
 
-pub struct DeviceInit(pub(crate) NonNull<WDFDEVICE_INIT>);
+/// A name was assigned to the [`DeviceInit`], which is required to create a control device.
+pub struct Named;
+impl Sealed for Named {}
+impl DeviceInitState for Named {}
 
-impl Drop for DeviceInit {
+/// The configuration of a device that is yet to be created.
+///
+/// All configuration has to happen before the device is created, which is enforced by
+/// [`Self::create_device`] consuming the `DeviceInit`. Control devices additionally need a name,
+/// so the device can only be created after [`Self::assign_name`] moved it to the [`Named`] state.
+pub struct DeviceInit<S: DeviceInitState = Unnamed>(
+    pub(crate) NonNull<WDFDEVICE_INIT>,
+    PhantomData<S>,
+);
+
+impl<S: DeviceInitState> Drop for DeviceInit<S> {
     fn drop(&mut self) {
         // SAFETY: A `DeviceInit` is guaranteed to contain a valid pointer to a `WDFDEVICE_INIT`
-        unsafe { Self::free_raw(self.0) };
+        unsafe { free_raw(self.0) };
     }
 }
 
-impl DeviceInit {
-    /// Frees a raw [`WDFDEVICE_INIT`].
-    ///
-    /// ## Safety
+/// Called for every request to the device before it's queued, in the context of the thread that
+/// sent it, e.g. to [probe and lock](super::request::Request::probe_and_lock_user_input_buffer)
+/// the user mode buffers of `METHOD_NEITHER` I/O control codes. See [MSDN] for more details.
+///
+/// The callback has to either complete the request, or hand it back to the framework with
+/// [`Device::enqueue_request`], which dispatches it to the device's queues.
+///
+/// This is FFI-compatible with [`km_sys::PFN_WDF_IO_IN_CALLER_CONTEXT`].
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nc-wdfdevice-evt_wdf_io_in_caller_context
+pub type EvtIoInCallerContext = unsafe extern "system" fn(
+    device: WdfObjectReference<'_, RawWdfDevice>,
+    request: WdfObjectReference<'_, RawWdfRequest>,
+);
+
+/// Called when the system shuts down, see [`DeviceInit::set_shutdown_notification`] and [MSDN]
+/// for more details.
+///
+/// This is FFI-compatible with [`km_sys::PFN_WDF_DEVICE_SHUTDOWN_NOTIFICATION`].
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcontrol/nc-wdfcontrol-evt_wdf_device_shutdown_notification
+pub type EvtDeviceShutdownNotification =
+    unsafe extern "system" fn(device: WdfObjectReference<'_, RawWdfDevice>);
+
+bitflags! {
+    /// When a [shutdown notification](DeviceInit::set_shutdown_notification) is called. See
+    /// [MSDN] for more information.
     ///
-    /// The caller is responsible for ensuring that the pointer is pointing to a
-    /// [`WDFDEVICE_INIT`].
-    unsafe fn free_raw(ptr: NonNull<WDFDEVICE_INIT>) {
-        // SAFETY: see this function's documentation.
-        unsafe {
-            ffi::device_init_free(ptr.as_ptr());
-        }
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcontrol/ne-wdfcontrol-_wdf_device_shutdown_flags
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct ShutdownNotificationFlags: UCHAR {
+        /// Before the file systems are flushed, while all drivers are still running
+        /// (`IRP_MJ_SHUTDOWN`).
+        const SHUTDOWN = WDF_DEVICE_SHUTDOWN_FLAGS::WdfDeviceShutdown.0 as UCHAR;
+        /// After the file systems are flushed, when paged memory may not be accessible anymore.
+        const LAST_CHANCE_SHUTDOWN =
+            WDF_DEVICE_SHUTDOWN_FLAGS::WdfDeviceLastChanceShutdown.0 as UCHAR;
     }
+}
 
+/// Frees a raw [`WDFDEVICE_INIT`].
+///
+/// ## Safety
+///
+/// The caller is responsible for ensuring that the pointer is pointing to a
+/// [`WDFDEVICE_INIT`].
+unsafe fn free_raw(ptr: NonNull<WDFDEVICE_INIT>) {
+    // SAFETY: see this function's documentation.
+    unsafe {
+        ffi::device_init_free(ptr.as_ptr());
+    }
+}
+
+impl DeviceInit<Unnamed> {
     /// Builds a new `DeviceInit` from a raw [`WDFDEVICE_INIT`].
     ///
     /// ## Safety
@@ -44,7 +112,78 @@ impl DeviceInit {
     /// - is pointing to a [`WDFDEVICE_INIT`]
     /// - is not already owned by another `DeviceInit`
     pub(crate) unsafe fn new(ptr: NonNull<WDFDEVICE_INIT>) -> Self {
-        Self(ptr)
+        Self(ptr, PhantomData)
+    }
+
+    /// Assigns a name to the device, e.g. `\Device\MyDevice`.
+    ///
+    /// On failure, the `DeviceInit` is freed, as the device can't be created anymore.
+    pub fn assign_name(
+        self,
+        device_name: &UnicodeString,
+    ) -> Result<DeviceInit<Named>, NtStatusError> {
+        self.assign_name_unchecked(device_name)
+    }
+
+    /// Assigns the first name of `names` that isn't taken by another device yet, and creates the
+    /// device with it, like [`DeviceInit::create_device`]. This allows a driver to create more
+    /// than one device, e.g. when several instances of it are loaded.
+    ///
+    /// Names are tried from the current number of `names` on while creating the device fails with
+    /// `STATUS_OBJECT_NAME_COLLISION`. The framework doesn't allow retrying the creation with the
+    /// same `DeviceInit`, so `device_init` is called for each attempt to allocate and set up a
+    /// fresh one, e.g. with [`Driver::allocate_control_device_init`]. Afterwards, `names` holds the
+    /// name the device was created with, see [`DeviceNames::device_index`].
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// [`Driver::allocate_control_device_init`]: super::driver::Driver::allocate_control_device_init
+    pub fn create_uniquely_named_device<E: From<NtStatusError>>(
+        mut device_init: impl FnMut() -> Result<Self, E>,
+        names: &mut DeviceNames,
+        mut device_attributes: Option<&mut ObjectAttributes>,
+        configure: impl FnOnce(&mut DeviceNonInitialized) -> Result<(), E>,
+    ) -> Result<Device, E> {
+        let device = loop {
+            let named = device_init()?.assign_name_unchecked(&names.device_name())?;
+
+            // a `DeviceInit` that failed to be created with is freed, the next attempt gets a
+            // fresh one
+            match named.create_non_initialized(device_attributes.as_deref_mut()) {
+                Ok(device) => break device,
+                Err(e) if e == NtStatusError::STATUS_OBJECT_NAME_COLLISION => {
+                    if !names.next_device_name() {
+                        return Err(e.into());
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        initialize(device, configure)
+    }
+}
+
+impl<S: DeviceInitState> DeviceInit<S> {
+    fn into_state<T: DeviceInitState>(self) -> DeviceInit<T> {
+        let this = ManuallyDrop::new(self);
+        DeviceInit(this.0, PhantomData)
+    }
+
+    /// Assigns a name to the device, replacing a previously assigned one.
+    ///
+    /// On failure, the `DeviceInit` is freed, as the device can't be created anymore.
+    fn assign_name_unchecked(
+        self,
+        device_name: &UnicodeString,
+    ) -> Result<DeviceInit<Named>, NtStatusError> {
+        // SAFETY:
+        // - A `DeviceInit` is guaranteed to contain a valid pointer to a `WDFDEVICE_INIT`.
+        // - `device_name` is guaranteed to be pointing to a valid value.
+        unsafe { ffi::device_init_assign_name(self.0.as_ptr(), device_name) }
+            .result_for("WdfDeviceInitAssignName")?;
+
+        Ok(self.into_state())
     }
 
     pub fn set_exclusive_access(&mut self, exclusive_access: bool) {
@@ -58,48 +197,122 @@ impl DeviceInit {
         unsafe { ffi::device_init_set_io_type(self.0.as_ptr(), io_type) }
     }
 
-    pub fn assign_name(
-        &mut self,
-        device_name: Option<&UnicodeString>,
-    ) -> Result<NtStatus, NtStatusError> {
-        let unicode_ptr = device_name.as_raw_ptr();
-
-        // SAFETY:
-        // - A `DeviceInit` is guaranteed to contain a valid pointer to a `WDFDEVICE_INIT`.
-        // - `unicode_ptr` is guaranteed to be either `null_ptr` or pointing to a valid value.
-        unsafe { ffi::device_init_assign_name(self.0.as_ptr(), unicode_ptr) }.result()
+    /// Registers a callback that's called for every request before it's queued, in the context of
+    /// the thread that sent it. See [`EvtIoInCallerContext`].
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nf-wdfdevice-wdfdeviceinitsetioincallercontextcallback
+    pub fn set_io_in_caller_context_callback(&mut self, callback: EvtIoInCallerContext) {
+        // SAFETY: A `DeviceInit` is guaranteed to contain a valid pointer to a `WDFDEVICE_INIT`.
+        // `EvtIoInCallerContext` is defined to be compatible to `PFN_WDF_IO_IN_CALLER_CONTEXT` by
+        // using repr(transparent) wrappers.
+        unsafe {
+            ffi::device_init_set_io_in_caller_context_callback(
+                self.0.as_ptr(),
+                Some(core::mem::transmute::<
+                    EvtIoInCallerContext,
+                    unsafe extern "system" fn(WDFDEVICE, WDFREQUEST),
+                >(callback)),
+            )
+        }
     }
 
-    pub fn set_file_object_config(
+    /// Registers a callback that's called when the system shuts down, e.g. to save device state
+    /// to the hardware. Only control devices get shutdown notifications, PnP devices are powered
+    /// down instead. Sleep transitions can be observed with
+    /// [`PowerStateCallback`](crate::power::PowerStateCallback).
+    ///
+    /// The callback is called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying
+    /// function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfcontrol/nf-wdfcontrol-wdfcontroldeviceinitsetshutdownnotification
+    pub fn set_shutdown_notification(
         &mut self,
-        mut file_object_config: FileObjectConfig,
-        mut file_object_attributes: Option<&mut ObjectAttributes>,
+        callback: EvtDeviceShutdownNotification,
+        flags: ShutdownNotificationFlags,
     ) {
+        // SAFETY: A `DeviceInit` is guaranteed to contain a valid pointer to a `WDFDEVICE_INIT`.
+        // `EvtDeviceShutdownNotification` is defined to be compatible to
+        // `PFN_WDF_DEVICE_SHUTDOWN_NOTIFICATION` by using repr(transparent) wrappers.
+        unsafe {
+            ffi::control_device_init_set_shutdown_notification(
+                self.0.as_ptr(),
+                Some(core::mem::transmute::<
+                    EvtDeviceShutdownNotification,
+                    unsafe extern "system" fn(WDFDEVICE),
+                >(callback)),
+                flags.bits(),
+            )
+        }
+    }
+
+    /// Sets the attributes of the requests the framework delivers to the device, e.g. created by
+    /// [`ObjectAttributes::new_with_context`] to allocate a context for each request, which can
+    /// then be [initialized](super::request::Request::initialize_context) when the request is
+    /// received.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nf-wdfdevice-wdfdeviceinitsetrequestattributes
+    pub fn set_request_attributes(&mut self, mut attributes: ObjectAttributes) {
+        // SAFETY: A `DeviceInit` is guaranteed to contain a valid pointer to a `WDFDEVICE_INIT`.
+        // The attributes are copied by the framework.
+        unsafe { ffi::device_init_set_request_attributes(self.0.as_ptr(), &mut attributes.0) }
+    }
+
+    pub fn set_file_object_config(&mut self, file_object_config: FileObjectConfig) {
+        let FileObjectConfig {
+            mut config,
+            mut attributes,
+        } = file_object_config;
+
         // SAFETY: The ffi call happens with guaranteed correct parameters.
         unsafe {
             ffi::device_init_set_file_object_config(
                 self.0.as_ptr(),
-                &mut file_object_config.0,
-                file_object_attributes
+                &mut config,
+                attributes
+                    .as_mut()
                     .as_raw_mut_ptr()
                     .cast::<WDF_OBJECT_ATTRIBUTES>(),
             )
         }
     }
+}
+
+impl DeviceInit<Named> {
+    /// Creates the device, and lets `configure` set it up before finishing its initialization.
+    ///
+    /// The system rejects I/O requests to the device until `configure` returned successfully,
+    /// after which its initialization is finished automatically (`WdfControlFinishInitializing`).
+    /// If `configure` fails, the device is deleted, and the error is returned.
+    ///
+    /// The device is added to the [devices of the driver](super::driver::Driver::devices) before
+    /// `configure` is called, and removed again if it fails.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn create_device<E: From<NtStatusError>>(
+        self,
+        device_attributes: Option<&mut ObjectAttributes>,
+        configure: impl FnOnce(&mut DeviceNonInitialized) -> Result<(), E>,
+    ) -> Result<Device, E> {
+        let device = self.create_non_initialized(device_attributes)?;
+
+        initialize(device, configure)
+    }
 
-    pub fn create_device(
+    /// Creates the device. On failure, the `DeviceInit` is freed, unless the framework already
+    /// did, as the creation can't be retried with it.
+    fn create_non_initialized(
         self,
         mut device_attributes: Option<&mut ObjectAttributes>,
     ) -> Result<DeviceNonInitialized, NtStatusError> {
         // WdfDeviceCreate deallocates our wrapped `WDFDEVICE_INIT` automatically on success,
         // setting the pointer to null, which would be UB for our `DeviceInit` containing a
         // guaranteed valid non-null pointer to a `WDFDEVICE_INIT`.
-        let mut device_init_ptr = {
-            let device_init = self.0.as_ptr();
-            // prevent the `DeviceInit` from being drop-handled
-            core::mem::forget(self);
-            device_init
-        };
+        // `ManuallyDrop` prevents the `DeviceInit` from being drop-handled.
+        let mut device_init_ptr = ManuallyDrop::new(self).0.as_ptr();
 
         let obj_attr_ptr = device_attributes
             .as_raw_mut_ptr()
@@ -109,11 +322,11 @@ impl DeviceInit {
 
         let mut device: WDFDEVICE = null_mut();
 
-        let result =
         // SAFETY:
         // - `device_init_ptr` is guaranteed to be a valid pointer to a `WDFDEVICE_INIT`.
         // - `device` is an out parameter.
-            unsafe { ffi::device_create(&mut device_init_ptr, obj_attr_ptr, &mut device) }.result();
+        let result = unsafe { ffi::device_create(&mut device_init_ptr, obj_attr_ptr, &mut device) }
+            .result_for("WdfDeviceCreate");
 
         match result {
             Ok(_) => {
@@ -129,15 +342,41 @@ impl DeviceInit {
                 // > WdfDeviceCreate.
             }
             Err(e) => {
-                // check if the pointer is not null, and if so, free the memory
-                if let Some(device_init) = NonNull::new(device_init_ptr) {
-                    // SAFETY: The `DeviceInit` is guaranteed to be valid, so we can safely call
-                    // `free_raw`.
-                    unsafe { Self::free_raw(device_init) };
+                // if the pointer is not null, the `WDFDEVICE_INIT` is still valid, and has to be
+                // freed
+                if let Some(ptr) = NonNull::new(device_init_ptr) {
+                    // SAFETY: The framework didn't free it, and it isn't owned by a `DeviceInit`
+                    // anymore.
+                    unsafe { free_raw(ptr) };
                 }
-
                 Err(e)
             }
         }
     }
 }
+
+/// Adds a newly created device to the [devices of the driver](super::driver::Driver::devices),
+/// and finishes its initialization once `configure` set it up.
+///
+/// The device has to be in the list while `configure` runs, so that the queues it creates are
+/// [remembered](super::device_list::DeviceList::add_queue). On failure, the device is deleted,
+/// which also removes it from the list again.
+fn initialize<E: From<NtStatusError>>(
+    mut device: DeviceNonInitialized,
+    configure: impl FnOnce(&mut DeviceNonInitialized) -> Result<(), E>,
+) -> Result<Device, E> {
+    let result = DEVICES
+        .add(&device.device)
+        .map_err(E::from)
+        .and_then(|()| configure(&mut device));
+
+    match result {
+        Ok(()) => Ok(device.finish_initialization()),
+        Err(e) => {
+            // SAFETY: Only control devices can be created, which the driver has to delete
+            // itself. It didn't finish initializing, so no requests were dispatched to it.
+            unsafe { device.device.0.delete() };
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use super::{
+    collection::Collection,
+    device::Device,
+    ffi,
+    io_queue::IoQueue,
+    object_attributes::{ObjectAttributes, ObjectAttributesInit},
+    wait_lock::{WaitLock, WaitLockGuard},
+    AsWdfReference, OwnedWdfObject, RawWdfDevice, RawWdfObject, RawWdfQueue, RawWdfString,
+    WdfObjectReference,
+};
+use crate::{sync::OnceCell, verify};
+use core::{cell::UnsafeCell, mem::zeroed, ptr::null_mut};
+use km_shared::{ntstatus::NtStatusError, strings::UnicodeString};
+use km_sys::{RtlEqualUnicodeString, WDFSTRING};
+
+/// The control devices of the driver, see [`Driver::devices`](super::driver::Driver::devices).
+pub(crate) static DEVICES: DeviceList = DeviceList {
+    inner: OnceCell::new(),
+};
+
+/// The control devices created by the driver, e.g. one per physical unit.
+///
+/// Devices are added when they're created by
+/// [`DeviceInit::create_device`](super::device_init::DeviceInit::create_device), and removed when
+/// they're deleted. The list keeps a reference to each device, so the [`Device`]s it hands out
+/// stay valid, but they may be deleted concurrently.
+///
+/// On unload, all devices have to be deleted, which [`Self::teardown`] does:
+///
+/// ```rs, ignore
+/// unsafe extern "system" fn driver_unload(driver: WdfObjectReference<'_, RawWdfDriver>) {
+///     // SAFETY: The driver doesn't use its devices anymore.
+///     unsafe { Driver::from(driver).devices().teardown() };
+/// }
+/// ```
+///
+/// All functions must be called at `PASSIVE_LEVEL`, as the list is protected by a [`WaitLock`].
+pub struct DeviceList {
+    inner: OnceCell<DeviceListInner>,
+}
+
+struct DeviceListInner {
+    lock: WaitLock,
+    devices: UnsafeCell<Collection<RawWdfDevice>>,
+}
+
+// SAFETY: WDF handles can be used from any thread, and the collection is only accessed while the
+// lock is held.
+unsafe impl Send for DeviceListInner {}
+// SAFETY: See above.
+unsafe impl Sync for DeviceListInner {}
+
+/// The context of each device in the list. The state is only accessed while the lock of the list
+/// is held, and taken once the device is removed from the list.
+struct DeviceListEntry {
+    state: UnsafeCell<Option<DeviceListEntryState>>,
+}
+
+struct DeviceListEntryState {
+    /// Referenced to keep it valid until the device is removed from the list, as children are
+    /// deleted before their parents.
+    name: OwnedWdfObject<RawWdfString>,
+    /// The queues created with [`Device::create_io_queue`], which are purged on
+    /// [teardown](DeviceList::teardown).
+    queues: Collection<RawWdfQueue>,
+}
+
+// SAFETY: WDF handles can be used from any thread, and the state is only accessed while the lock
+// of the list is held.
+unsafe impl Send for DeviceListEntry {}
+// SAFETY: See above.
+unsafe impl Sync for DeviceListEntry {}
+
+crate::declare_wdf_object_context_type! {
+    static KM_DEVICE_LIST_ENTRY => DeviceListEntry;
+}
+
+impl DeviceList {
+    /// Locks the list, e.g. for iterating over the devices. Devices can't be created or deleted by
+    /// other threads while the guard is held, and mustn't be deleted by the current thread, which
+    /// deadlocks.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn lock(&self) -> DeviceListGuard<'_> {
+        verify::at_passive_level();
+
+        DeviceListGuard {
+            inner: self.inner.get().map(|inner| {
+                let guard = inner.lock.acquire();
+                // SAFETY: The lock is held for as long as the guard exists.
+                let devices = unsafe { &*inner.devices.get() };
+                (devices, guard)
+            }),
+        }
+    }
+
+    /// The number of devices.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the driver has no devices.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Finds the device with the given name, e.g. `\Device\MyDevice`, ignoring case.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn find_by_name(&self, name: &UnicodeString) -> Option<Device> {
+        self.lock().find_by_name(name)
+    }
+
+    /// Adds a newly created device to the list, removing it again once it's deleted.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub(crate) fn add(&self, device: &Device) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        let inner = self.inner.get_or_try_init(|| {
+            Ok::<_, NtStatusError>(DeviceListInner {
+                lock: WaitLock::create(None)?,
+                devices: UnsafeCell::new(Collection::create(None)?),
+            })
+        })?;
+
+        let mut queue_attributes = ObjectAttributes::default();
+        queue_attributes.set_parent(device);
+        let state = DeviceListEntryState {
+            name: retrieve_name(device)?,
+            queues: Collection::create(Some(&mut queue_attributes))?,
+        };
+
+        let init = ObjectAttributesInit {
+            object_cleanup_callback: Some(remove_device),
+            ..Default::default()
+        };
+        let entry = DeviceListEntry {
+            state: UnsafeCell::new(Some(state)),
+        };
+        KM_DEVICE_LIST_ENTRY
+            .allocate_with(device, entry, init)
+            .map_err(|(_, e)| e)?;
+
+        let _guard = inner.lock.acquire();
+        // SAFETY: The lock is held.
+        unsafe { (*inner.devices.get()).add(device) }
+    }
+
+    /// Remembers a queue of `device`, to purge it on [teardown](Self::teardown). Does nothing if
+    /// the device isn't in the list.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub(crate) fn add_queue(&self, device: &Device, queue: &IoQueue) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        let Some(inner) = self.inner.get() else {
+            return Ok(());
+        };
+        let _guard = inner.lock.acquire();
+
+        KM_DEVICE_LIST_ENTRY
+            .with(device, |entry| {
+                // SAFETY: The lock is held.
+                match unsafe { &mut *entry.state.get() } {
+                    Some(state) => state.queues.add(queue),
+                    None => Ok(()),
+                }
+            })
+            .unwrap_or(Ok(()))
+    }
+
+    /// Deletes all devices, which every driver with control devices has to do on unload, e.g. in
+    /// its unload routine. For each device, in the order they were created,
+    /// 1. its queues are purged: no new requests are dispatched, queued requests are canceled,
+    ///    and the driver's callbacks are waited for, as are the requests that were dispatched to
+    ///    the driver, which therefore have to be completed (or be cancelable) for this to return.
+    /// 2. the device is deleted, which also removes its symbolic links.
+    ///
+    /// Afterwards, no requests are dispatched to the driver anymore. Devices created concurrently
+    /// are deleted too.
+    ///
+    /// This can't be enforced through ownership, as the framework hands out further handles to
+    /// each device (and its queues) in callbacks. Work items aren't wrapped by this crate, so
+    /// drivers that create them through `km_sys` have to flush them before.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, and not from a queue callback, which would deadlock.
+    ///
+    /// # Safety
+    /// Like after [`Device::delete`], no device of the list, or a queue, timer, file object or
+    /// request of it, may be used after this is called.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn teardown(&self) {
+        verify::at_passive_level();
+
+        while let Some((device, state)) = self.pop_first() {
+            if let Some(state) = &state {
+                for queue in &state.queues {
+                    // SAFETY: The queue is guaranteed to be valid.
+                    unsafe { ffi::io_queue_purge_synchronously(queue) };
+                }
+            }
+            drop(state);
+
+            // SAFETY: The queues of the device were purged, and the caller guarantees that the
+            // device isn't used anymore.
+            unsafe { device.delete() };
+        }
+    }
+
+    /// Removes the first device from the list, returning it along with its state.
+    fn pop_first(&self) -> Option<(Device, Option<DeviceListEntryState>)> {
+        let inner = self.inner.get()?;
+        let _guard = inner.lock.acquire();
+
+        // SAFETY: The lock is held.
+        let devices = unsafe { &mut *inner.devices.get() };
+        // SAFETY: Only devices are added to the list.
+        let device = unsafe { Device::new(devices.first()?.to_owned()) };
+        devices.remove_at(0);
+
+        let state = KM_DEVICE_LIST_ENTRY.with(&device, |entry| {
+            // SAFETY: The lock is held.
+            unsafe { (*entry.state.get()).take() }
+        });
+
+        Some((device, state.flatten()))
+    }
+}
+
+/// Retrieves the name of `device` into a new string object, which is deleted with the device.
+fn retrieve_name(device: &Device) -> Result<OwnedWdfObject<RawWdfString>, NtStatusError> {
+    let mut attributes = ObjectAttributes::default();
+    attributes.set_parent(device);
+
+    let mut string: WDFSTRING = null_mut();
+
+    // SAFETY: All pointers are guaranteed to be valid, and a null string creates an empty string.
+    unsafe { ffi::string_create(null_mut(), &mut attributes.0, &mut string) }
+        .result_for("WdfStringCreate")?;
+
+    debug_assert!(!string.is_null());
+
+    let string = OwnedWdfObject::from_new_raw(string);
+
+    // SAFETY: The device and the string are guaranteed to be valid.
+    unsafe { ffi::device_retrieve_device_name(device.as_wdf_ref(), string.as_ref()) }
+        .result_for("WdfDeviceRetrieveDeviceName")?;
+
+    Ok(string)
+}
+
+/// The cleanup callback of the [`DeviceListEntry`] context, removing the device from the list when
+/// it's deleted.
+unsafe extern "system" fn remove_device(object: WdfObjectReference<'_, RawWdfObject>) {
+    // cleanup callbacks of devices are called at `PASSIVE_LEVEL`
+    let Some(inner) = DEVICES.inner.get() else {
+        return;
+    };
+    let _guard = inner.lock.acquire();
+
+    // SAFETY: The lock is held.
+    let devices = unsafe { &mut *inner.devices.get() };
+    // SAFETY: The callback is only registered for devices.
+    devices.remove(&unsafe { object.downcast::<RawWdfDevice>() });
+
+    // The state is released while the lock is held, so the name isn't used by a concurrent lookup.
+    KM_DEVICE_LIST_ENTRY.with(&object, |entry| {
+        // SAFETY: The lock is held.
+        drop(unsafe { (*entry.state.get()).take() });
+    });
+}
+
+/// A locked [`DeviceList`], see [`DeviceList::lock`].
+#[must_use = "the lock is released immediately if the guard is dropped"]
+pub struct DeviceListGuard<'a> {
+    inner: Option<(&'a Collection<RawWdfDevice>, WaitLockGuard<'a>)>,
+}
+
+impl DeviceListGuard<'_> {
+    /// The number of devices.
+    pub fn len(&self) -> usize {
+        self.inner.as_ref().map_or(0, |(devices, _)| devices.len())
+    }
+
+    /// Whether the driver has no devices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the devices, in the order they were created.
+    pub fn iter(&self) -> impl Iterator<Item = Device> + '_ {
+        self.inner
+            .iter()
+            .flat_map(|(devices, _)| devices.iter())
+            // SAFETY: Only devices are added to the list.
+            .map(|device| unsafe { Device::new(device.to_owned()) })
+    }
+
+    /// Finds the device with the given name, e.g. `\Device\MyDevice`, ignoring case.
+    pub fn find_by_name(&self, name: &UnicodeString) -> Option<Device> {
+        self.iter().find(|device| {
+            KM_DEVICE_LIST_ENTRY
+                .with(device, |entry| {
+                    // SAFETY: The lock is held.
+                    let Some(state) = (unsafe { &*entry.state.get() }) else {
+                        return false;
+                    };
+
+                    // SAFETY: `UNICODE_STRING` is a plain C struct, for which zero is valid.
+                    let mut device_name_string: UnicodeString = unsafe { zeroed() };
+                    // SAFETY: The string object is valid, and fills in the `UNICODE_STRING`,
+                    // whose buffer stays valid while the string object is referenced.
+                    unsafe {
+                        ffi::string_get_unicode_string(state.name.as_ref(), &mut device_name_string)
+                    };
+
+                    // SAFETY: Both strings are valid.
+                    unsafe { RtlEqualUnicodeString(&device_name_string, name, true.into()) != 0 }
+                })
+                .unwrap_or(false)
+        })
+    }
+}
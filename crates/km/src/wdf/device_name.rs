@@ -0,0 +1,197 @@
+//! Device and symbolic link names for drivers that create more than one device, see
+//! [`DeviceNames`].
+//!
+//! A fixed name like `\Device\MyDevice` only works for the first device, the next one fails to be
+//! created with `STATUS_OBJECT_NAME_COLLISION`. Instead, the names are numbered, and the first free
+//! number is picked:
+//!
+//! ```rs, ignore
+//! let mut names = DeviceNames::new("MyDevice");
+//! let mut device = DeviceInit::create_uniquely_named_device(
+//!     || driver.allocate_control_device_init(&SDDL),
+//!     &mut names,
+//!     None,
+//!     configure_device,
+//! )?;
+//! device.create_unique_symbolic_link(&mut names)?;
+//!
+//! // e.g. 1 for `\Device\MyDevice-1` and `\DosDevices\MyDevice-1` if another instance is loaded
+//! km_info!("created device {}", names.device_index());
+//! ```
+
+use core::mem::size_of;
+use km_shared::strings::UnicodeString;
+use km_sys::{UNICODE_STRING, WCHAR};
+
+const DEVICE_PREFIX: &str = "\\Device\\";
+const SYMBOLIC_LINK_PREFIX: &str = "\\DosDevices\\";
+
+/// A NUL-terminated name, e.g. `\Device\MyDevice-0`.
+#[derive(Clone, Copy)]
+struct NameBuffer {
+    buf: [WCHAR; DeviceNames::CAPACITY],
+    len: usize,
+}
+
+impl NameBuffer {
+    const fn new(prefix: &str, base: &str, index: u32) -> Self {
+        let mut this = Self {
+            buf: [0; DeviceNames::CAPACITY],
+            len: 0,
+        };
+        this = this.push(prefix).push(base).push("-");
+
+        // the digits of the index, most significant first
+        let mut digits = [0u8; 10];
+        let mut count = 0;
+        let mut rest = index;
+        loop {
+            digits[count] = b'0' + (rest % 10) as u8;
+            count += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        while count > 0 {
+            count -= 1;
+            this.buf[this.len] = digits[count] as WCHAR;
+            this.len += 1;
+        }
+        this
+    }
+
+    const fn push(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buf[self.len] = bytes[i] as WCHAR;
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    fn unicode_string(&self) -> UnicodeString {
+        UNICODE_STRING {
+            Length: (self.len * size_of::<WCHAR>()) as u16,
+            MaximumLength: ((self.len + 1) * size_of::<WCHAR>()) as u16,
+            Buffer: self.buf.as_ptr().cast_mut(),
+        }
+    }
+}
+
+/// Numbered device and symbolic link names, `\Device\<base>-<n>` and `\DosDevices\<base>-<n>`,
+/// starting from zero. See the [module documentation](self).
+///
+/// [`DeviceInit::create_uniquely_named_device`] and [`Device::create_unique_symbolic_link`] try
+/// successive numbers until one isn't taken yet, after which the chosen names can be read from
+/// here. Both start from the current numbers, so the symbolic link usually gets the number of the
+/// device, but can get a higher one if a stale link of another driver is left over.
+///
+/// [`DeviceInit::create_uniquely_named_device`]: super::device_init::DeviceInit::create_uniquely_named_device
+/// [`Device::create_unique_symbolic_link`]: super::device::Device::create_unique_symbolic_link
+#[derive(Clone, Copy)]
+pub struct DeviceNames {
+    base: &'static str,
+    device_index: u32,
+    symbolic_link_index: u32,
+    device: NameBuffer,
+    symbolic_link: NameBuffer,
+}
+
+impl DeviceNames {
+    /// The most characters a name can have, including the prefix, number and terminating NUL.
+    pub const CAPACITY: usize = 128;
+
+    /// The highest number tried before giving up with `STATUS_OBJECT_NAME_COLLISION`.
+    pub const MAX_INDEX: u32 = 255;
+
+    /// Creates the names for `base`, e.g. `MyDevice`, numbered zero.
+    ///
+    /// Panics if `base` isn't ASCII, contains a backslash, or is too long for [`Self::CAPACITY`].
+    pub const fn new(base: &'static str) -> Self {
+        let bytes = base.as_bytes();
+        let max_len = Self::CAPACITY - SYMBOLIC_LINK_PREFIX.len() - "-4294967295".len() - 1;
+        if bytes.is_empty() || bytes.len() > max_len {
+            panic!("device name base is empty or too long for `DeviceNames::CAPACITY`");
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii() || bytes[i] == b'\\' {
+                panic!("device name base must be ASCII without backslashes");
+            }
+            i += 1;
+        }
+
+        Self {
+            base,
+            device_index: 0,
+            symbolic_link_index: 0,
+            device: NameBuffer::new(DEVICE_PREFIX, base, 0),
+            symbolic_link: NameBuffer::new(SYMBOLIC_LINK_PREFIX, base, 0),
+        }
+    }
+
+    /// The base the names were created from.
+    pub fn base(&self) -> &'static str {
+        self.base
+    }
+
+    /// The number of the device name.
+    pub fn device_index(&self) -> u32 {
+        self.device_index
+    }
+
+    /// The number of the symbolic link name.
+    pub fn symbolic_link_index(&self) -> u32 {
+        self.symbolic_link_index
+    }
+
+    /// The device name, `\Device\<base>-<n>`.
+    ///
+    /// The string references `self`, and mustn't be used after it's moved or changed.
+    pub fn device_name(&self) -> UnicodeString {
+        self.device.unicode_string()
+    }
+
+    /// The symbolic link name, `\DosDevices\<base>-<n>`.
+    ///
+    /// The string references `self`, and mustn't be used after it's moved or changed.
+    pub fn symbolic_link_name(&self) -> UnicodeString {
+        self.symbolic_link.unicode_string()
+    }
+
+    /// Moves the device name to the next number, returning `false` if it's at [`Self::MAX_INDEX`]
+    /// already.
+    pub(crate) fn next_device_name(&mut self) -> bool {
+        if self.device_index >= Self::MAX_INDEX {
+            return false;
+        }
+        self.device_index += 1;
+        self.device = NameBuffer::new(DEVICE_PREFIX, self.base, self.device_index);
+        true
+    }
+
+    /// Moves the symbolic link name to the next number, returning `false` if it's at
+    /// [`Self::MAX_INDEX`] already.
+    pub(crate) fn next_symbolic_link_name(&mut self) -> bool {
+        if self.symbolic_link_index >= Self::MAX_INDEX {
+            return false;
+        }
+        self.symbolic_link_index += 1;
+        self.symbolic_link =
+            NameBuffer::new(SYMBOLIC_LINK_PREFIX, self.base, self.symbolic_link_index);
+        true
+    }
+
+    /// Moves the symbolic link name to the number of the device name, if it's lower.
+    pub(crate) fn catch_up_symbolic_link_name(&mut self) {
+        if self.symbolic_link_index < self.device_index {
+            self.symbolic_link_index = self.device_index;
+            self.symbolic_link =
+                NameBuffer::new(SYMBOLIC_LINK_PREFIX, self.base, self.symbolic_link_index);
+        }
+    }
+}
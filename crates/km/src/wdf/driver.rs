@@ -1,11 +1,25 @@
 use super::{
-    device_init::DeviceInit, driver_config::DriverConfig, ffi, object_attributes::ObjectAttributes,
+    device_init::DeviceInit,
+    device_list::{DeviceList, DEVICES},
+    driver_config::DriverConfig,
+    ffi,
+    object_attributes::ObjectAttributes,
+    registry::RegistryKey,
     AsWdfReference, OwnedWdfObject, RawWdfDriver, WdfObjectReference,
 };
-use crate::{AsRawMutPtr, DriverObjectHandle, Sealed, UnicodeStringHandle};
-use core::ptr::{null_mut, NonNull};
+use crate::{
+    error::{error, Error},
+    verify, AsRawMutPtr, DriverObjectHandle, Sealed, UnicodeStringHandle,
+};
+use core::{
+    mem::size_of,
+    ptr::{null_mut, NonNull},
+};
 use km_shared::{ntstatus::NtStatusError, strings::UnicodeString};
-use km_sys::{WDFDRIVER, WDF_OBJECT_ATTRIBUTES};
+use km_sys::{
+    KEY_READ, ULONG, WDFDRIVER, WDFKEY, WDF_DRIVER_VERSION_AVAILABLE_PARAMS, WDF_OBJECT_ATTRIBUTES,
+};
+use snafu::OptionExt;
 
 #[repr(transparent)]
 #[derive(Clone)]
@@ -48,14 +62,20 @@ impl Driver {
                 &mut driver,
             )
         }
-        .result()?;
+        .result_for("WdfDriverCreate")?;
 
         debug_assert!(!driver.is_null());
 
         Ok(Driver(OwnedWdfObject::from_new_raw(driver)))
     }
 
-    pub fn allocate_control_device_init(&mut self, sddl: &UnicodeString) -> Option<DeviceInit> {
+    /// Allocates the initialization of a control device, which only `sddl` grants access to.
+    ///
+    /// Fails with [`Error::AllocationFailed`] if the framework couldn't allocate it.
+    pub fn allocate_control_device_init(
+        &mut self,
+        sddl: &UnicodeString,
+    ) -> Result<DeviceInit, Error> {
         // SAFETY: sddl is a guaranteed valid pointer to a UnicodeString
         NonNull::new(unsafe { ffi::control_device_init_allocate(self.as_wdf_ref().raw(), sddl) })
             .map(|ptr| {
@@ -63,5 +83,130 @@ impl Driver {
                 // any other `DeviceInit`, satifying the safety contract.
                 unsafe { DeviceInit::new(ptr) }
             })
+            .context(error::AllocationFailedSnafu {
+                what: "WDFDEVICE_INIT",
+            })
+    }
+
+    /// The control devices created by the driver, which are tracked automatically.
+    pub fn devices(&self) -> &'static DeviceList {
+        &DEVICES
+    }
+
+    /// Opens the driver's `Parameters` key for reading, i.e.
+    /// `HKLM\SYSTEM\CurrentControlSet\Services\<driver>\Parameters`, or returns `None` if the
+    /// driver has no such key.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdriver/nf-wdfdriver-wdfdriveropenparametersregistrykey
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn open_parameters_key(&self) -> Result<Option<RegistryKey>, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut key: WDFKEY = null_mut();
+        // SAFETY: The driver is guaranteed to be valid, and `key` is valid for writes. The key is
+        // parented to the driver by default.
+        let result = unsafe {
+            ffi::driver_open_parameters_registry_key(
+                self.as_wdf_ref(),
+                KEY_READ,
+                null_mut(),
+                &mut key,
+            )
+        }
+        .result_for("WdfDriverOpenParametersRegistryKey");
+
+        match result {
+            Ok(_) => {}
+            Err(e) if e == NtStatusError::STATUS_OBJECT_NAME_NOT_FOUND => return Ok(None),
+            Err(e) => return Err(e),
+        }
+
+        debug_assert!(!key.is_null());
+
+        Ok(Some(RegistryKey::from_new_raw(key)))
+    }
+
+    /// Checks whether the loaded framework is at least `version`.
+    ///
+    /// Drivers built against a newer KMDF version than the one that's loaded (which is possible
+    /// down to [`KmdfVersion::MINIMUM`]) can't use the functions added since; see
+    /// [`is_function_available`](super::is_function_available).
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdriver/nf-wdfdriver-wdfdriverisversionavailable
+    pub fn is_version_available(&self, version: KmdfVersion) -> bool {
+        let mut params = WDF_DRIVER_VERSION_AVAILABLE_PARAMS {
+            Size: size_of::<WDF_DRIVER_VERSION_AVAILABLE_PARAMS>() as ULONG,
+            MajorVersion: version.major,
+            MinorVersion: version.minor,
+        };
+
+        // SAFETY: We call the function with all valid parameters.
+        unsafe { ffi::driver_is_version_available(self.as_wdf_ref().raw(), &mut params) != 0 }
+    }
+
+    /// Gets the version of the loaded framework.
+    pub fn kmdf_version(&self) -> KmdfVersion {
+        // there's no function returning the version, so find the highest available one
+        let mut version = KmdfVersion::new(1, 0);
+        while self.is_version_available(KmdfVersion::new(version.major, version.minor + 1)) {
+            version.minor += 1;
+        }
+        version
+    }
+}
+
+/// A KMDF version.
+///
+/// The driver is built against [`KmdfVersion::BUILT_AGAINST`], but may run on an older framework
+/// down to [`KmdfVersion::MINIMUM`], see [`Driver::is_version_available`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KmdfVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl KmdfVersion {
+    /// Windows 7.
+    pub const V1_9: Self = Self::new(1, 9);
+    /// Windows 8.
+    pub const V1_11: Self = Self::new(1, 11);
+    /// Windows 8.1.
+    pub const V1_13: Self = Self::new(1, 13);
+    /// Windows 10, version 1507.
+    pub const V1_15: Self = Self::new(1, 15);
+
+    /// The version of the function table the driver is linked against (`WdfFunctions_010xx`),
+    /// selected by the `kmdf-*` features. Defaults to 1.15.
+    pub const BUILT_AGAINST: Self = if cfg!(feature = "kmdf-1-11") {
+        Self::V1_11
+    } else if cfg!(feature = "kmdf-1-13") {
+        Self::V1_13
+    } else {
+        Self::V1_15
+    };
+
+    /// The oldest version the driver loads on (`WdfMinimumVersionRequired`), selected by the
+    /// `kmdf-minimum-*` features. Defaults to [`KmdfVersion::BUILT_AGAINST`].
+    ///
+    /// Functions added after this version may be missing, see
+    /// [`is_function_available`](super::is_function_available).
+    pub const MINIMUM: Self = if cfg!(feature = "kmdf-minimum-1-9") {
+        Self::V1_9
+    } else if cfg!(feature = "kmdf-minimum-1-11") && Self::BUILT_AGAINST.minor > 11 {
+        Self::V1_11
+    } else if cfg!(feature = "kmdf-minimum-1-13") && Self::BUILT_AGAINST.minor > 13 {
+        Self::V1_13
+    } else {
+        Self::BUILT_AGAINST
+    };
+
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
     }
 }
@@ -2,7 +2,7 @@ use super::WdfObjectReference;
 use core::mem::{size_of, transmute, zeroed};
 use km_sys::{ULONG, WDFDRIVER__, WDF_DRIVER_CONFIG, WDF_DRIVER_INIT_FLAGS};
 
-pub type WdfDriverUnload = unsafe extern "C" fn(WdfObjectReference<'_, WDFDRIVER__>) -> ();
+pub type WdfDriverUnload = unsafe extern "system" fn(WdfObjectReference<'_, WDFDRIVER__>) -> ();
 
 pub enum DriverConfig {
     Pnp {
@@ -15,6 +15,10 @@ pub enum DriverConfig {
         /// Sample][WDKSample].
         ///
         /// [WDKSample]: https://github.com/microsoft/Windows-driver-samples/blob/80c104ad0cef2a4fb55aaee7d494f30af5fb44b4/general/ioctl/kmdf/sys/nonpnp.c#L103-L106
+        ///
+        /// If the driver runs any background activities, the unload routine has to wait for them
+        /// with [`crate::unload::wait_for_quiescence`]. Its control devices have to be deleted with
+        /// [`DeviceList::teardown`](super::device_list::DeviceList::teardown).
         driver_unload: Option<WdfDriverUnload>,
     },
 }
@@ -1,19 +1,6 @@
-use crate::wdf::{RawWdfObject, WdfObjectReference};
-use km_shared::ntstatus::NtStatus;
-use km_sys::{
-    BOOLEAN, HANDLE, KPROCESSOR_MODE, LONG, PCHAR, PCUNICODE_STRING,
-    PCWDF_OBJECT_CONTEXT_TYPE_INFO, PDRIVER_OBJECT, PFN_WDFCONTROLDEVICEINITALLOCATE,
-    PFN_WDFCONTROLFINISHINITIALIZING, PFN_WDFDEVICECREATE, PFN_WDFDEVICECREATESYMBOLICLINK,
-    PFN_WDFDEVICEINITASSIGNNAME, PFN_WDFDEVICEINITFREE, PFN_WDFDEVICEINITSETEXCLUSIVE,
-    PFN_WDFDEVICEINITSETFILEOBJECTCONFIG, PFN_WDFDEVICEINITSETIOTYPE, PFN_WDFDRIVERCREATE,
-    PFN_WDFIOQUEUECREATE, PFN_WDFIOQUEUEGETDEVICE, PFN_WDFOBJECTDEREFERENCEACTUAL,
-    PFN_WDFOBJECTGETTYPEDCONTEXTWORKER, PFN_WDFOBJECTREFERENCEACTUAL, PFN_WDFREQUESTCOMPLETE,
-    PFN_WDFREQUESTGETREQUESTORMODE, PFN_WDFREQUESTRETRIEVEINPUTBUFFER,
-    PFN_WDFREQUESTRETRIEVEOUTPUTBUFFER, PFN_WDFREQUESTSETINFORMATION, PVOID, PWDFDEVICE_INIT,
-    PWDF_DRIVER_CONFIG, PWDF_DRIVER_GLOBALS, PWDF_FILEOBJECT_CONFIG, PWDF_IO_QUEUE_CONFIG,
-    PWDF_OBJECT_ATTRIBUTES, ULONG_PTR, WDFDEVICE, WDFDEVICE__, WDFDRIVER, WDFFUNCENUM, WDFQUEUE,
-    WDFQUEUE__, WDFREQUEST__, WDF_DEVICE_IO_TYPE,
-};
+use core::marker::PhantomData;
+use km_shared::ntstatus::{NtStatus, NtStatusError};
+use km_sys::{WdfClientVersionHigherThanFramework, WdfFunctionCount, ULONG, WDFFUNC, WDFFUNCENUM};
 
 trait Inner {
     type Inner;
@@ -24,6 +11,171 @@ impl<T> Inner for Option<T> {
     type Inner = T;
 }
 
+#[cfg(any(feature = "kmdf-1-11", feature = "kmdf-1-13"))]
+extern "C" {
+    // the oldest selected version wins, see the `kmdf-*` features
+    #[cfg_attr(feature = "kmdf-1-11", link_name = "WdfFunctions_01011")]
+    #[cfg_attr(
+        all(feature = "kmdf-1-13", not(feature = "kmdf-1-11")),
+        link_name = "WdfFunctions_01013"
+    )]
+    static WdfFunctions: *const WDFFUNC;
+}
+
+/// The function table of the KMDF version the driver is built against, see
+/// [`KmdfVersion::BUILT_AGAINST`](super::driver::KmdfVersion::BUILT_AGAINST).
+#[inline(always)]
+fn function_table() -> *const WDFFUNC {
+    // SAFETY: The table is set by the KMDF stub before `DriverEntry`, and is read-only afterwards.
+    unsafe {
+        #[cfg(any(feature = "kmdf-1-11", feature = "kmdf-1-13"))]
+        {
+            WdfFunctions
+        }
+        #[cfg(not(any(feature = "kmdf-1-11", feature = "kmdf-1-13")))]
+        {
+            km_sys::WdfFunctions_01015
+        }
+    }
+}
+
+/// The minor version of the oldest KMDF version the driver loads on, which the KMDF stub checks the
+/// loaded framework against. Without a `kmdf-minimum-*` feature, the stub defaults to the version
+/// the driver is built against.
+#[cfg(any(
+    feature = "kmdf-minimum-1-9",
+    feature = "kmdf-minimum-1-11",
+    feature = "kmdf-minimum-1-13"
+))]
+#[allow(non_upper_case_globals)]
+#[no_mangle]
+#[used]
+static WdfMinimumVersionRequired: ULONG = super::driver::KmdfVersion::MINIMUM.minor;
+
+/// Checks whether the loaded framework provides the function at `index`, which isn't the case if
+/// it's older than the version the driver was built against. This is `WDF_IS_FUNCTION_AVAILABLE`.
+///
+/// Only needed for functions added after
+/// [`KmdfVersion::MINIMUM`](super::driver::KmdfVersion::MINIMUM). Wrappers of unavailable functions
+/// that return an `NTSTATUS` fail with `STATUS_NOT_SUPPORTED`.
+pub fn is_function_available(index: WDFFUNCENUM) -> bool {
+    // SAFETY: The globals are set by the KMDF stub before `DriverEntry`, and are read-only
+    // afterwards.
+    unsafe { WdfClientVersionHigherThanFramework == 0 || (index.0 as ULONG) < WdfFunctionCount }
+}
+
+/// The result of calling an unavailable WDF function, see `wdf_function!`. Called on a reference
+/// to a `PhantomData` of the return type, so that it takes precedence over [`UnavailableOther`].
+pub(crate) trait UnavailableStatus {
+    fn unavailable(&self, function: &'static str) -> NtStatus;
+}
+
+impl UnavailableStatus for PhantomData<NtStatus> {
+    #[cold]
+    #[inline(never)]
+    fn unavailable(&self, function: &'static str) -> NtStatus {
+        crate::km_warn!("{function} isn't available in the loaded KMDF version");
+        NtStatusError::STATUS_NOT_SUPPORTED.into()
+    }
+}
+
+/// The fallback of [`UnavailableStatus`] for functions not returning an `NTSTATUS`, which can't
+/// report the failure.
+///
+/// All of those are available since KMDF 1.9, the oldest
+/// [`KmdfVersion::MINIMUM`](super::driver::KmdfVersion::MINIMUM), so this is only reached if the
+/// stub loaded the driver on an older framework than it allows.
+pub(crate) trait UnavailableOther {
+    type Output;
+
+    #[cold]
+    #[inline(never)]
+    fn unavailable(&self, function: &'static str) -> Self::Output {
+        panic!("{function} isn't available in the loaded KMDF version");
+    }
+}
+
+impl<R> UnavailableOther for &PhantomData<R> {
+    type Output = R;
+}
+
+/// The handle of a WDF call to log with the `trace-ffi` feature, see `wdf_function!`.
+///
+/// Only handles are logged, by the inherent `WdfObjectReference::trace_handle`, which takes
+/// precedence over this blanket implementation for everything else.
+#[cfg(feature = "trace-ffi")]
+pub(crate) trait TraceArgument {
+    #[inline(always)]
+    fn trace_handle(&self) -> Option<km_sys::WDFOBJECT> {
+        None
+    }
+}
+
+#[cfg(feature = "trace-ffi")]
+impl<T> TraceArgument for T {}
+
+/// The status of a WDF call to log with the `trace-ffi` feature, for functions returning an
+/// `NTSTATUS`. Called on a reference to the result, so that it takes precedence over
+/// [`TraceResult`].
+#[cfg(feature = "trace-ffi")]
+pub(crate) trait TraceStatus {
+    fn trace_status(&self) -> Option<km_shared::ntstatus::NtStatus>;
+}
+
+#[cfg(feature = "trace-ffi")]
+impl TraceStatus for km_shared::ntstatus::NtStatus {
+    #[inline(always)]
+    fn trace_status(&self) -> Option<km_shared::ntstatus::NtStatus> {
+        Some(*self)
+    }
+}
+
+/// The fallback of [`TraceStatus`] for functions returning anything else.
+#[cfg(feature = "trace-ffi")]
+pub(crate) trait TraceResult {
+    #[inline(always)]
+    fn trace_status(&self) -> Option<km_shared::ntstatus::NtStatus> {
+        None
+    }
+}
+
+#[cfg(feature = "trace-ffi")]
+impl<T> TraceResult for &T {}
+
+/// Logs a WDF call with the `trace-ffi` feature, see `wdf_function!`.
+#[cfg(feature = "trace-ffi")]
+#[inline(never)]
+pub(crate) fn trace_call(
+    function: &'static str,
+    handle: Option<km_sys::WDFOBJECT>,
+    status: Option<km_shared::ntstatus::NtStatus>,
+    caller: &'static core::panic::Location<'static>,
+) {
+    struct Handle(Option<km_sys::WDFOBJECT>);
+
+    impl core::fmt::Display for Handle {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.0 {
+                Some(handle) => write!(f, "{handle:p}"),
+                None => f.write_str("-"),
+            }
+        }
+    }
+
+    // not through `km_trace!`, so that release builds with the feature log the calls too
+    let handle = Handle(handle);
+    match status {
+        Some(status) => crate::logging::log::trace!(
+            target: "km::wdf::ffi",
+            "{caller}: {function}({handle}) -> {status}"
+        ),
+        None => crate::logging::log::trace!(
+            target: "km::wdf::ffi",
+            "{caller}: {function}({handle})"
+        ),
+    }
+}
+
 /// Helper macro to declare a WDF function the way the C macros do.
 macro_rules! wdf_function {
     {
@@ -33,19 +185,38 @@ macro_rules! wdf_function {
     } => {
         $(#[$meta])*
         #[inline(always)]
+        #[track_caller]
         // needed as the comments below seem to be stripped
         // #[allow(clippy::undocumented_unsafe_blocks)]
         pub unsafe fn $symbol($($argname: $argtype),*) -> $rettype {
-            type Ty = unsafe extern "C" fn(PWDF_DRIVER_GLOBALS, $($argtype),*) -> $rettype;
+            // the calling convention of the framework's functions, see `[abi]` in `bindgen.toml`
+            type Ty = unsafe extern "system" fn(PWDF_DRIVER_GLOBALS, $($argtype),*) -> $rettype;
+
+            // The function table of an older framework is shorter than the one we're built
+            // against, so indexing it with newer functions would read past its end.
+            if !is_function_available($index) {
+                // only one of them is used, depending on `$rettype`
+                #[allow(unused_imports)]
+                use crate::wdf::ffi::{UnavailableOther as _, UnavailableStatus as _};
+
+                return (&core::marker::PhantomData::<$rettype>).unavailable(stringify!($symbol));
+            }
+
+            #[cfg(feature = "verification")]
+            {
+                // unused if all arguments are handles, see `VerifyArgument`
+                #[allow(unused_imports)]
+                use crate::verify::VerifyArgument as _;
+
+                crate::verify::driver_globals(stringify!($symbol));
+                $($argname.verify_argument(stringify!($symbol));)*
+            }
 
             // SAFETY: We assume here that `$argname`, `$argtype`, and `$rettype` really do
             // correspond to a symbol with the associated type in the `WdfFunctions` function table
             // we're accessing here.
             let fp: *const <$fp_ptr as Inner>::Inner = unsafe {
-                core::mem::transmute(
-                    ::km_sys::WdfFunctions_01015
-                        .offset($index.0 as isize),
-                )
+                core::mem::transmute(function_table().offset($index.0 as isize))
             };
 
             // SAFETY: Trusting that the definition is correct/ffi-compatible.
@@ -54,192 +225,45 @@ macro_rules! wdf_function {
                 core::mem::transmute(fp)
             };
 
+            // the first handle argument, e.g. the object a method is called on
+            #[cfg(feature = "trace-ffi")]
+            let handle = {
+                // unused if all arguments are handles, see `TraceArgument`
+                #[allow(unused_imports)]
+                use crate::wdf::ffi::TraceArgument as _;
+
+                None $(.or($argname.trace_handle()))*
+            };
+
             // SAFETY: We assume that:
             // 1. `fp` is usable as described above, and
             // 2. any invariants for this specific function are upheld by calling code.
-            unsafe {
+            let result = unsafe {
                     (*fp)(::km_sys::WdfDriverGlobals, $($argname),*)
-            }
-        }
-    };
-}
-
-wdf_function! {
-    (PFN_WDFDRIVERCREATE, WDFFUNCENUM::WdfDriverCreateTableIndex):
-    #[must_use]
-    pub unsafe fn driver_create(
-        driver_object: PDRIVER_OBJECT,
-        registry_path: PCUNICODE_STRING,
-        driver_attributes: PWDF_OBJECT_ATTRIBUTES,
-        driver_config: PWDF_DRIVER_CONFIG,
-        driver: *mut WDFDRIVER
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFCONTROLDEVICEINITALLOCATE, WDFFUNCENUM::WdfControlDeviceInitAllocateTableIndex):
-    #[must_use]
-    pub unsafe fn control_device_init_allocate(
-        driver: WDFDRIVER,
-        sddl_string: PCUNICODE_STRING
-    ) -> PWDFDEVICE_INIT
-}
-
-wdf_function! {
-    (PFN_WDFDEVICEINITFREE, WDFFUNCENUM::WdfDeviceInitFreeTableIndex):
-    pub unsafe fn device_init_free(
-        device_init: PWDFDEVICE_INIT
-    ) -> ()
-}
-
-wdf_function! {
-    (PFN_WDFDEVICEINITSETEXCLUSIVE, WDFFUNCENUM::WdfDeviceInitSetExclusiveTableIndex):
-    pub unsafe fn device_init_set_exclusive(
-        device_init: PWDFDEVICE_INIT,
-        is_exclusive: BOOLEAN
-    ) -> ()
-}
-
-wdf_function! {
-    (PFN_WDFDEVICEINITSETIOTYPE, WDFFUNCENUM::WdfDeviceInitSetIoTypeTableIndex):
-    pub unsafe fn device_init_set_io_type(
-        device_init: PWDFDEVICE_INIT,
-        io_type: WDF_DEVICE_IO_TYPE
-    ) -> ()
-}
-
-wdf_function! {
-    (PFN_WDFDEVICEINITASSIGNNAME, WDFFUNCENUM::WdfDeviceInitAssignNameTableIndex):
-    #[must_use]
-    pub unsafe fn device_init_assign_name(
-        device_init: PWDFDEVICE_INIT,
-        device_name: PCUNICODE_STRING
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFDEVICECREATE, WDFFUNCENUM::WdfDeviceCreateTableIndex):
-    #[must_use]
-    pub unsafe fn device_create(
-        device_init: *mut PWDFDEVICE_INIT,
-        device_attributes: PWDF_OBJECT_ATTRIBUTES,
-        device: *mut WDFDEVICE
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFDEVICECREATESYMBOLICLINK, WDFFUNCENUM::WdfDeviceCreateSymbolicLinkTableIndex):
-    #[must_use]
-    pub unsafe fn device_create_symbolic_link(
-        device: WdfObjectReference<'_, WDFDEVICE__>,
-        symbolic_link_name: PCUNICODE_STRING
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFCONTROLFINISHINITIALIZING, WDFFUNCENUM::WdfControlFinishInitializingTableIndex):
-    pub unsafe fn control_finish_initializing(
-        device: WdfObjectReference<'_, WDFDEVICE__>
-    ) -> ()
-}
-
-wdf_function! {
-    (PFN_WDFIOQUEUECREATE, WDFFUNCENUM::WdfIoQueueCreateTableIndex):
-    #[must_use]
-    pub unsafe fn io_queue_create(
-        device: WdfObjectReference<'_, WDFDEVICE__>,
-        config: PWDF_IO_QUEUE_CONFIG,
-        queue_attributes: PWDF_OBJECT_ATTRIBUTES,
-        queue: *mut WDFQUEUE
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFREQUESTCOMPLETE, WDFFUNCENUM::WdfRequestCompleteTableIndex):
-    pub unsafe fn request_complete(
-        request: WdfObjectReference<'_, WDFREQUEST__>,
-        status: NtStatus
-    ) -> ()
-}
-
-wdf_function! {
-    (PFN_WDFREQUESTRETRIEVEINPUTBUFFER, WDFFUNCENUM::WdfRequestRetrieveInputBufferTableIndex):
-    #[must_use]
-    pub unsafe fn request_retrieve_input_buffer(
-        request: WdfObjectReference<'_, WDFREQUEST__>,
-        minimum_required_length: usize,
-        buffer: *mut PVOID,
-        length: *mut usize,
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFREQUESTRETRIEVEOUTPUTBUFFER, WDFFUNCENUM::WdfRequestRetrieveOutputBufferTableIndex):
-    #[must_use]
-    pub unsafe fn request_retrieve_output_buffer(
-        request: WdfObjectReference<'_, WDFREQUEST__>,
-        minimum_required_length: usize,
-        buffer: *mut PVOID,
-        length: *mut usize,
-    ) -> NtStatus
-}
-
-wdf_function! {
-    (PFN_WDFOBJECTGETTYPEDCONTEXTWORKER, WDFFUNCENUM::WdfObjectGetTypedContextWorkerTableIndex):
-    #[must_use]
-    pub unsafe fn object_get_typed_context_worker(
-        handle: WdfObjectReference<'_, RawWdfObject>,
-        type_info: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
-    ) -> PVOID
-}
+            };
 
-wdf_function! {
-    (PFN_WDFOBJECTREFERENCEACTUAL, WDFFUNCENUM::WdfObjectReferenceActualTableIndex):
-    pub unsafe fn object_reference_actual(
-        handle: HANDLE,
-        tag: PVOID,
-        line: LONG,
-        file: PCHAR,
-    ) -> ()
-}
+            #[cfg(feature = "trace-ffi")]
+            {
+                // only one of them is used, depending on `$rettype`
+                #[allow(unused_imports)]
+                use crate::wdf::ffi::{TraceResult as _, TraceStatus as _};
 
-wdf_function! {
-    (PFN_WDFOBJECTDEREFERENCEACTUAL, WDFFUNCENUM::WdfObjectDereferenceActualTableIndex):
-    pub unsafe fn object_dereference_actual(
-        handle: HANDLE,
-        tag: PVOID,
-        line: LONG,
-        file: PCHAR,
-    ) -> ()
-}
-
-wdf_function! {
-    (PFN_WDFIOQUEUEGETDEVICE, WDFFUNCENUM::WdfIoQueueGetDeviceTableIndex):
-    pub unsafe fn io_queue_get_device(
-        queue: WdfObjectReference<'_, WDFQUEUE__>,
-    ) -> WdfObjectReference<'_, WDFDEVICE__>
-}
+                crate::wdf::ffi::trace_call(
+                    stringify!($symbol),
+                    handle,
+                    (&result).trace_status(),
+                    core::panic::Location::caller(),
+                );
+            }
 
-wdf_function! {
-    (PFN_WDFREQUESTSETINFORMATION, WDFFUNCENUM::WdfRequestSetInformationTableIndex):
-    pub unsafe fn request_set_information(
-        request: WdfObjectReference<'_, WDFREQUEST__>,
-        information: ULONG_PTR,
-    ) -> ()
+            result
+        }
+    };
 }
 
-wdf_function! {
-    (PFN_WDFREQUESTGETREQUESTORMODE, WDFFUNCENUM::WdfRequestGetRequestorModeTableIndex):
-    pub unsafe fn request_get_requestor_mode(
-        request: WdfObjectReference<'_, WDFREQUEST__>,
-    ) -> KPROCESSOR_MODE
-}
+// The wrappers are generated from the `PFN_WDF*` types of the bindings, so adding a function is a
+// matter of allowing its type in `km-sys-bindgen/bindgen.toml` and rerunning
+// `km-sys-bindgen --wdf-ffi ../km-sys/src/generated/wdf.rs ../km/src/wdf/ffi/generated.rs`.
+mod generated;
 
-wdf_function! {
-    (PFN_WDFDEVICEINITSETFILEOBJECTCONFIG, WDFFUNCENUM::WdfDeviceInitSetFileObjectConfigTableIndex):
-    pub unsafe fn device_init_set_file_object_config(
-        device_init: PWDFDEVICE_INIT,
-        file_object_config: PWDF_FILEOBJECT_CONFIG,
-        file_object_attributes: PWDF_OBJECT_ATTRIBUTES,
-    ) -> ()
-}
+pub use generated::*;
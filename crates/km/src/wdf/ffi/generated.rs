@@ -0,0 +1,985 @@
+// @generated by `km-sys-bindgen --wdf-ffi` from `km-sys/src/generated/wdf.rs`.
+// Do not edit manually.
+
+use super::{function_table, is_function_available, Inner};
+use crate::wdf::{RawWdfObject, WdfObjectReference};
+use km_shared::ntstatus::NtStatus;
+use km_sys::*;
+
+wdf_function! {
+    (PFN_WDFCOLLECTIONCREATE, WDFFUNCENUM::WdfCollectionCreateTableIndex):
+    #[must_use]
+    pub unsafe fn collection_create(
+        collection_attributes: PWDF_OBJECT_ATTRIBUTES,
+        collection: *mut WDFCOLLECTION,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFCOLLECTIONGETCOUNT, WDFFUNCENUM::WdfCollectionGetCountTableIndex):
+    #[must_use]
+    pub unsafe fn collection_get_count(
+        collection: WdfObjectReference<'_, WDFCOLLECTION__>,
+    ) -> ULONG
+}
+
+wdf_function! {
+    (PFN_WDFCOLLECTIONADD, WDFFUNCENUM::WdfCollectionAddTableIndex):
+    #[must_use]
+    pub unsafe fn collection_add(
+        collection: WdfObjectReference<'_, WDFCOLLECTION__>,
+        object: WdfObjectReference<'_, RawWdfObject>,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFCOLLECTIONREMOVE, WDFFUNCENUM::WdfCollectionRemoveTableIndex):
+    pub unsafe fn collection_remove(
+        collection: WdfObjectReference<'_, WDFCOLLECTION__>,
+        item: WdfObjectReference<'_, RawWdfObject>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFCOLLECTIONREMOVEITEM, WDFFUNCENUM::WdfCollectionRemoveItemTableIndex):
+    pub unsafe fn collection_remove_item(
+        collection: WdfObjectReference<'_, WDFCOLLECTION__>,
+        index: ULONG,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFCOLLECTIONGETITEM, WDFFUNCENUM::WdfCollectionGetItemTableIndex):
+    #[must_use]
+    pub unsafe fn collection_get_item(
+        collection: WdfObjectReference<'_, WDFCOLLECTION__>,
+        index: ULONG,
+    ) -> WdfObjectReference<'_, RawWdfObject>
+}
+
+wdf_function! {
+    (PFN_WDFCONTROLDEVICEINITALLOCATE, WDFFUNCENUM::WdfControlDeviceInitAllocateTableIndex):
+    #[must_use]
+    pub unsafe fn control_device_init_allocate(
+        driver: WDFDRIVER,
+        sddl_string: *const UNICODE_STRING,
+    ) -> PWDFDEVICE_INIT
+}
+
+wdf_function! {
+    (PFN_WDFCONTROLDEVICEINITSETSHUTDOWNNOTIFICATION, WDFFUNCENUM::WdfControlDeviceInitSetShutdownNotificationTableIndex):
+    pub unsafe fn control_device_init_set_shutdown_notification(
+        device_init: PWDFDEVICE_INIT,
+        notification: PFN_WDF_DEVICE_SHUTDOWN_NOTIFICATION,
+        flags: UCHAR,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFCONTROLFINISHINITIALIZING, WDFFUNCENUM::WdfControlFinishInitializingTableIndex):
+    pub unsafe fn control_finish_initializing(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICERETRIEVEDEVICENAME, WDFFUNCENUM::WdfDeviceRetrieveDeviceNameTableIndex):
+    #[must_use]
+    pub unsafe fn device_retrieve_device_name(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        string: WdfObjectReference<'_, WDFSTRING__>,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEGETIOTARGET, WDFFUNCENUM::WdfDeviceGetIoTargetTableIndex):
+    #[must_use]
+    pub unsafe fn device_get_io_target(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+    ) -> WdfObjectReference<'_, WDFIOTARGET__>
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEASSIGNS0IDLESETTINGS, WDFFUNCENUM::WdfDeviceAssignS0IdleSettingsTableIndex):
+    #[must_use]
+    pub unsafe fn device_assign_s_0_idle_settings(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        settings: PWDF_DEVICE_POWER_POLICY_IDLE_SETTINGS,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEASSIGNSXWAKESETTINGS, WDFFUNCENUM::WdfDeviceAssignSxWakeSettingsTableIndex):
+    #[must_use]
+    pub unsafe fn device_assign_sx_wake_settings(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        settings: PWDF_DEVICE_POWER_POLICY_WAKE_SETTINGS,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITFREE, WDFFUNCENUM::WdfDeviceInitFreeTableIndex):
+    pub unsafe fn device_init_free(
+        device_init: PWDFDEVICE_INIT,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITSETIOTYPE, WDFFUNCENUM::WdfDeviceInitSetIoTypeTableIndex):
+    pub unsafe fn device_init_set_io_type(
+        device_init: PWDFDEVICE_INIT,
+        io_type: WDF_DEVICE_IO_TYPE,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITSETEXCLUSIVE, WDFFUNCENUM::WdfDeviceInitSetExclusiveTableIndex):
+    pub unsafe fn device_init_set_exclusive(
+        device_init: PWDFDEVICE_INIT,
+        is_exclusive: BOOLEAN,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITASSIGNNAME, WDFFUNCENUM::WdfDeviceInitAssignNameTableIndex):
+    #[must_use]
+    pub unsafe fn device_init_assign_name(
+        device_init: PWDFDEVICE_INIT,
+        device_name: PCUNICODE_STRING,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITSETFILEOBJECTCONFIG, WDFFUNCENUM::WdfDeviceInitSetFileObjectConfigTableIndex):
+    pub unsafe fn device_init_set_file_object_config(
+        device_init: PWDFDEVICE_INIT,
+        file_object_config: PWDF_FILEOBJECT_CONFIG,
+        file_object_attributes: PWDF_OBJECT_ATTRIBUTES,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITSETREQUESTATTRIBUTES, WDFFUNCENUM::WdfDeviceInitSetRequestAttributesTableIndex):
+    pub unsafe fn device_init_set_request_attributes(
+        device_init: PWDFDEVICE_INIT,
+        request_attributes: PWDF_OBJECT_ATTRIBUTES,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEINITSETIOINCALLERCONTEXTCALLBACK, WDFFUNCENUM::WdfDeviceInitSetIoInCallerContextCallbackTableIndex):
+    pub unsafe fn device_init_set_io_in_caller_context_callback(
+        device_init: PWDFDEVICE_INIT,
+        evt_io_in_caller_context: PFN_WDF_IO_IN_CALLER_CONTEXT,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFDEVICECREATE, WDFFUNCENUM::WdfDeviceCreateTableIndex):
+    #[must_use]
+    pub unsafe fn device_create(
+        device_init: *mut PWDFDEVICE_INIT,
+        device_attributes: PWDF_OBJECT_ATTRIBUTES,
+        device: *mut WDFDEVICE,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDEVICECREATESYMBOLICLINK, WDFFUNCENUM::WdfDeviceCreateSymbolicLinkTableIndex):
+    #[must_use]
+    pub unsafe fn device_create_symbolic_link(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        symbolic_link_name: PCUNICODE_STRING,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDEVICEENQUEUEREQUEST, WDFFUNCENUM::WdfDeviceEnqueueRequestTableIndex):
+    #[must_use]
+    pub unsafe fn device_enqueue_request(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDRIVERCREATE, WDFFUNCENUM::WdfDriverCreateTableIndex):
+    #[must_use]
+    pub unsafe fn driver_create(
+        driver_object: PDRIVER_OBJECT,
+        registry_path: PCUNICODE_STRING,
+        driver_attributes: PWDF_OBJECT_ATTRIBUTES,
+        driver_config: PWDF_DRIVER_CONFIG,
+        driver: *mut WDFDRIVER,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDRIVEROPENPARAMETERSREGISTRYKEY, WDFFUNCENUM::WdfDriverOpenParametersRegistryKeyTableIndex):
+    #[must_use]
+    pub unsafe fn driver_open_parameters_registry_key(
+        driver: WdfObjectReference<'_, WDFDRIVER__>,
+        desired_access: ACCESS_MASK,
+        key_attributes: PWDF_OBJECT_ATTRIBUTES,
+        key: *mut WDFKEY,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFDRIVERISVERSIONAVAILABLE, WDFFUNCENUM::WdfDriverIsVersionAvailableTableIndex):
+    #[must_use]
+    pub unsafe fn driver_is_version_available(
+        driver: WDFDRIVER,
+        version_available_params: PWDF_DRIVER_VERSION_AVAILABLE_PARAMS,
+    ) -> BOOLEAN
+}
+
+wdf_function! {
+    (PFN_WDFFILEOBJECTGETDEVICE, WDFFUNCENUM::WdfFileObjectGetDeviceTableIndex):
+    #[must_use]
+    pub unsafe fn file_object_get_device(
+        file_object: WdfObjectReference<'_, WDFFILEOBJECT__>,
+    ) -> WdfObjectReference<'_, WDFDEVICE__>
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUECREATE, WDFFUNCENUM::WdfIoQueueCreateTableIndex):
+    #[must_use]
+    pub unsafe fn io_queue_create(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        config: PWDF_IO_QUEUE_CONFIG,
+        queue_attributes: PWDF_OBJECT_ATTRIBUTES,
+        queue: *mut WDFQUEUE,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEGETSTATE, WDFFUNCENUM::WdfIoQueueGetStateTableIndex):
+    #[must_use]
+    pub unsafe fn io_queue_get_state(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        queue_requests: PULONG,
+        driver_requests: PULONG,
+    ) -> WDF_IO_QUEUE_STATE
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUESTART, WDFFUNCENUM::WdfIoQueueStartTableIndex):
+    pub unsafe fn io_queue_start(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUESTOP, WDFFUNCENUM::WdfIoQueueStopTableIndex):
+    pub unsafe fn io_queue_stop(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        stop_complete: PFN_WDF_IO_QUEUE_STATE,
+        context: WDFCONTEXT,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUESTOPSYNCHRONOUSLY, WDFFUNCENUM::WdfIoQueueStopSynchronouslyTableIndex):
+    pub unsafe fn io_queue_stop_synchronously(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEGETDEVICE, WDFFUNCENUM::WdfIoQueueGetDeviceTableIndex):
+    #[must_use]
+    pub unsafe fn io_queue_get_device(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+    ) -> WdfObjectReference<'_, WDFDEVICE__>
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUERETRIEVENEXTREQUEST, WDFFUNCENUM::WdfIoQueueRetrieveNextRequestTableIndex):
+    #[must_use]
+    pub unsafe fn io_queue_retrieve_next_request(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        out_request: *mut WDFREQUEST,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEFINDREQUEST, WDFFUNCENUM::WdfIoQueueFindRequestTableIndex):
+    #[must_use]
+    pub unsafe fn io_queue_find_request(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        found_request: WDFREQUEST,
+        file_object: WDFFILEOBJECT,
+        parameters: PWDF_REQUEST_PARAMETERS,
+        out_request: *mut WDFREQUEST,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUERETRIEVEFOUNDREQUEST, WDFFUNCENUM::WdfIoQueueRetrieveFoundRequestTableIndex):
+    #[must_use]
+    pub unsafe fn io_queue_retrieve_found_request(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        found_request: WdfObjectReference<'_, WDFREQUEST__>,
+        out_request: *mut WDFREQUEST,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEDRAINSYNCHRONOUSLY, WDFFUNCENUM::WdfIoQueueDrainSynchronouslyTableIndex):
+    pub unsafe fn io_queue_drain_synchronously(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEDRAIN, WDFFUNCENUM::WdfIoQueueDrainTableIndex):
+    pub unsafe fn io_queue_drain(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        drain_complete: PFN_WDF_IO_QUEUE_STATE,
+        context: WDFCONTEXT,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEPURGESYNCHRONOUSLY, WDFFUNCENUM::WdfIoQueuePurgeSynchronouslyTableIndex):
+    pub unsafe fn io_queue_purge_synchronously(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOQUEUEPURGE, WDFFUNCENUM::WdfIoQueuePurgeTableIndex):
+    pub unsafe fn io_queue_purge(
+        queue: WdfObjectReference<'_, WDFQUEUE__>,
+        purge_complete: PFN_WDF_IO_QUEUE_STATE,
+        context: WDFCONTEXT,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETCREATE, WDFFUNCENUM::WdfIoTargetCreateTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_create(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        io_target_attributes: PWDF_OBJECT_ATTRIBUTES,
+        io_target: *mut WDFIOTARGET,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETOPEN, WDFFUNCENUM::WdfIoTargetOpenTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_open(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+        open_params: PWDF_IO_TARGET_OPEN_PARAMS,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETCLOSE, WDFFUNCENUM::WdfIoTargetCloseTableIndex):
+    pub unsafe fn io_target_close(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETSENDREADSYNCHRONOUSLY, WDFFUNCENUM::WdfIoTargetSendReadSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_send_read_synchronously(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+        request: WDFREQUEST,
+        output_buffer: PWDF_MEMORY_DESCRIPTOR,
+        device_offset: PLONGLONG,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        bytes_read: PULONG_PTR,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETSENDWRITESYNCHRONOUSLY, WDFFUNCENUM::WdfIoTargetSendWriteSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_send_write_synchronously(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+        request: WDFREQUEST,
+        input_buffer: PWDF_MEMORY_DESCRIPTOR,
+        device_offset: PLONGLONG,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        bytes_written: PULONG_PTR,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETSENDIOCTLSYNCHRONOUSLY, WDFFUNCENUM::WdfIoTargetSendIoctlSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_send_ioctl_synchronously(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+        request: WDFREQUEST,
+        ioctl_code: ULONG,
+        input_buffer: PWDF_MEMORY_DESCRIPTOR,
+        output_buffer: PWDF_MEMORY_DESCRIPTOR,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        bytes_returned: PULONG_PTR,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETFORMATREQUESTFORIOCTL, WDFFUNCENUM::WdfIoTargetFormatRequestForIoctlTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_format_request_for_ioctl(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        ioctl_code: ULONG,
+        input_buffer: WDFMEMORY,
+        input_buffer_offset: PWDFMEMORY_OFFSET,
+        output_buffer: WDFMEMORY,
+        output_buffer_offset: PWDFMEMORY_OFFSET,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFIOTARGETSENDINTERNALIOCTLSYNCHRONOUSLY, WDFFUNCENUM::WdfIoTargetSendInternalIoctlSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn io_target_send_internal_ioctl_synchronously(
+        io_target: WdfObjectReference<'_, WDFIOTARGET__>,
+        request: WDFREQUEST,
+        ioctl_code: ULONG,
+        input_buffer: PWDF_MEMORY_DESCRIPTOR,
+        output_buffer: PWDF_MEMORY_DESCRIPTOR,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        bytes_returned: PULONG_PTR,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFMEMORYCREATE, WDFFUNCENUM::WdfMemoryCreateTableIndex):
+    #[must_use]
+    pub unsafe fn memory_create(
+        attributes: PWDF_OBJECT_ATTRIBUTES,
+        pool_type: POOL_TYPE,
+        pool_tag: ULONG,
+        buffer_size: usize,
+        memory: *mut WDFMEMORY,
+        buffer: *mut PVOID,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFMEMORYGETBUFFER, WDFFUNCENUM::WdfMemoryGetBufferTableIndex):
+    #[must_use]
+    pub unsafe fn memory_get_buffer(
+        memory: WdfObjectReference<'_, WDFMEMORY__>,
+        buffer_size: *mut usize,
+    ) -> PVOID
+}
+
+wdf_function! {
+    (PFN_WDFOBJECTGETTYPEDCONTEXTWORKER, WDFFUNCENUM::WdfObjectGetTypedContextWorkerTableIndex):
+    #[must_use]
+    pub unsafe fn object_get_typed_context_worker(
+        handle: WdfObjectReference<'_, RawWdfObject>,
+        type_info: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+    ) -> PVOID
+}
+
+wdf_function! {
+    (PFN_WDFOBJECTALLOCATECONTEXT, WDFFUNCENUM::WdfObjectAllocateContextTableIndex):
+    #[must_use]
+    pub unsafe fn object_allocate_context(
+        handle: WdfObjectReference<'_, RawWdfObject>,
+        context_attributes: PWDF_OBJECT_ATTRIBUTES,
+        context: *mut PVOID,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFOBJECTREFERENCEACTUAL, WDFFUNCENUM::WdfObjectReferenceActualTableIndex):
+    pub unsafe fn object_reference_actual(
+        handle: WdfObjectReference<'_, RawWdfObject>,
+        tag: PVOID,
+        line: LONG,
+        file: PCHAR,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFOBJECTDEREFERENCEACTUAL, WDFFUNCENUM::WdfObjectDereferenceActualTableIndex):
+    pub unsafe fn object_dereference_actual(
+        handle: WdfObjectReference<'_, RawWdfObject>,
+        tag: PVOID,
+        line: LONG,
+        file: PCHAR,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFOBJECTDELETE, WDFFUNCENUM::WdfObjectDeleteTableIndex):
+    pub unsafe fn object_delete(
+        object: WdfObjectReference<'_, RawWdfObject>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREGISTRYCLOSE, WDFFUNCENUM::WdfRegistryCloseTableIndex):
+    pub unsafe fn registry_close(
+        key: WdfObjectReference<'_, WDFKEY__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREGISTRYWDMGETHANDLE, WDFFUNCENUM::WdfRegistryWdmGetHandleTableIndex):
+    #[must_use]
+    pub unsafe fn registry_wdm_get_handle(
+        key: WdfObjectReference<'_, WDFKEY__>,
+    ) -> HANDLE
+}
+
+wdf_function! {
+    (PFN_WDFREGISTRYQUERYVALUE, WDFFUNCENUM::WdfRegistryQueryValueTableIndex):
+    #[must_use]
+    pub unsafe fn registry_query_value(
+        key: WdfObjectReference<'_, WDFKEY__>,
+        value_name: PCUNICODE_STRING,
+        value_length: ULONG,
+        value: PVOID,
+        value_length_queried: PULONG,
+        value_type: PULONG,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTCREATE, WDFFUNCENUM::WdfRequestCreateTableIndex):
+    #[must_use]
+    pub unsafe fn request_create(
+        request_attributes: PWDF_OBJECT_ATTRIBUTES,
+        io_target: WDFIOTARGET,
+        request: *mut WDFREQUEST,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTFORMATREQUESTUSINGCURRENTTYPE, WDFFUNCENUM::WdfRequestFormatRequestUsingCurrentTypeTableIndex):
+    pub unsafe fn request_format_request_using_current_type(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTSEND, WDFFUNCENUM::WdfRequestSendTableIndex):
+    #[must_use]
+    pub unsafe fn request_send(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        target: WdfObjectReference<'_, WDFIOTARGET__>,
+        options: PWDF_REQUEST_SEND_OPTIONS,
+    ) -> BOOLEAN
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTGETSTATUS, WDFFUNCENUM::WdfRequestGetStatusTableIndex):
+    #[must_use]
+    pub unsafe fn request_get_status(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTISFROM32BITPROCESS, WDFFUNCENUM::WdfRequestIsFrom32BitProcessTableIndex):
+    #[must_use]
+    pub unsafe fn request_is_from_32_bit_process(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> BOOLEAN
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTSETCOMPLETIONROUTINE, WDFFUNCENUM::WdfRequestSetCompletionRoutineTableIndex):
+    pub unsafe fn request_set_completion_routine(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        completion_routine: PFN_WDF_REQUEST_COMPLETION_ROUTINE,
+        completion_context: WDFCONTEXT,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTCOMPLETE, WDFFUNCENUM::WdfRequestCompleteTableIndex):
+    pub unsafe fn request_complete(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        status: NtStatus,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTGETPARAMETERS, WDFFUNCENUM::WdfRequestGetParametersTableIndex):
+    pub unsafe fn request_get_parameters(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        parameters: PWDF_REQUEST_PARAMETERS,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTRETRIEVEINPUTBUFFER, WDFFUNCENUM::WdfRequestRetrieveInputBufferTableIndex):
+    #[must_use]
+    pub unsafe fn request_retrieve_input_buffer(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        minimum_required_length: usize,
+        buffer: *mut PVOID,
+        length: *mut usize,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTRETRIEVEOUTPUTBUFFER, WDFFUNCENUM::WdfRequestRetrieveOutputBufferTableIndex):
+    #[must_use]
+    pub unsafe fn request_retrieve_output_buffer(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        minimum_required_size: usize,
+        buffer: *mut PVOID,
+        length: *mut usize,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTRETRIEVEINPUTWDMMDL, WDFFUNCENUM::WdfRequestRetrieveInputWdmMdlTableIndex):
+    #[must_use]
+    pub unsafe fn request_retrieve_input_wdm_mdl(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        mdl: *mut PMDL,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTRETRIEVEOUTPUTWDMMDL, WDFFUNCENUM::WdfRequestRetrieveOutputWdmMdlTableIndex):
+    #[must_use]
+    pub unsafe fn request_retrieve_output_wdm_mdl(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        mdl: *mut PMDL,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTRETRIEVEUNSAFEUSERINPUTBUFFER, WDFFUNCENUM::WdfRequestRetrieveUnsafeUserInputBufferTableIndex):
+    #[must_use]
+    pub unsafe fn request_retrieve_unsafe_user_input_buffer(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        minimum_required_length: usize,
+        input_buffer: *mut PVOID,
+        length: *mut usize,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTRETRIEVEUNSAFEUSEROUTPUTBUFFER, WDFFUNCENUM::WdfRequestRetrieveUnsafeUserOutputBufferTableIndex):
+    #[must_use]
+    pub unsafe fn request_retrieve_unsafe_user_output_buffer(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        minimum_required_length: usize,
+        output_buffer: *mut PVOID,
+        length: *mut usize,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTSETINFORMATION, WDFFUNCENUM::WdfRequestSetInformationTableIndex):
+    pub unsafe fn request_set_information(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        information: ULONG_PTR,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTGETFILEOBJECT, WDFFUNCENUM::WdfRequestGetFileObjectTableIndex):
+    #[must_use]
+    pub unsafe fn request_get_file_object(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> WdfObjectReference<'_, WDFFILEOBJECT__>
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTPROBEANDLOCKUSERBUFFERFORREAD, WDFFUNCENUM::WdfRequestProbeAndLockUserBufferForReadTableIndex):
+    #[must_use]
+    pub unsafe fn request_probe_and_lock_user_buffer_for_read(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        buffer: PVOID,
+        length: usize,
+        memory_object: *mut WDFMEMORY,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTPROBEANDLOCKUSERBUFFERFORWRITE, WDFFUNCENUM::WdfRequestProbeAndLockUserBufferForWriteTableIndex):
+    #[must_use]
+    pub unsafe fn request_probe_and_lock_user_buffer_for_write(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        buffer: PVOID,
+        length: usize,
+        memory_object: *mut WDFMEMORY,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTGETREQUESTORMODE, WDFFUNCENUM::WdfRequestGetRequestorModeTableIndex):
+    #[must_use]
+    pub unsafe fn request_get_requestor_mode(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> KPROCESSOR_MODE
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTFORWARDTOIOQUEUE, WDFFUNCENUM::WdfRequestForwardToIoQueueTableIndex):
+    #[must_use]
+    pub unsafe fn request_forward_to_io_queue(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+        destination_queue: WdfObjectReference<'_, WDFQUEUE__>,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTGETIOQUEUE, WDFFUNCENUM::WdfRequestGetIoQueueTableIndex):
+    #[must_use]
+    pub unsafe fn request_get_io_queue(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> WdfObjectReference<'_, WDFQUEUE__>
+}
+
+wdf_function! {
+    (PFN_WDFREQUESTWDMGETIRP, WDFFUNCENUM::WdfRequestWdmGetIrpTableIndex):
+    #[must_use]
+    pub unsafe fn request_wdm_get_irp(
+        request: WdfObjectReference<'_, WDFREQUEST__>,
+    ) -> PIRP
+}
+
+wdf_function! {
+    (PFN_WDFSTRINGCREATE, WDFFUNCENUM::WdfStringCreateTableIndex):
+    #[must_use]
+    pub unsafe fn string_create(
+        unicode_string: PCUNICODE_STRING,
+        string_attributes: PWDF_OBJECT_ATTRIBUTES,
+        string: *mut WDFSTRING,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFSTRINGGETUNICODESTRING, WDFFUNCENUM::WdfStringGetUnicodeStringTableIndex):
+    pub unsafe fn string_get_unicode_string(
+        string: WdfObjectReference<'_, WDFSTRING__>,
+        unicode_string: PUNICODE_STRING,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFWAITLOCKCREATE, WDFFUNCENUM::WdfWaitLockCreateTableIndex):
+    #[must_use]
+    pub unsafe fn wait_lock_create(
+        lock_attributes: PWDF_OBJECT_ATTRIBUTES,
+        lock: *mut WDFWAITLOCK,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFWAITLOCKACQUIRE, WDFFUNCENUM::WdfWaitLockAcquireTableIndex):
+    #[must_use]
+    pub unsafe fn wait_lock_acquire(
+        lock: WdfObjectReference<'_, WDFWAITLOCK__>,
+        timeout: PLONGLONG,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFWAITLOCKRELEASE, WDFFUNCENUM::WdfWaitLockReleaseTableIndex):
+    pub unsafe fn wait_lock_release(
+        lock: WdfObjectReference<'_, WDFWAITLOCK__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFSPINLOCKCREATE, WDFFUNCENUM::WdfSpinLockCreateTableIndex):
+    #[must_use]
+    pub unsafe fn spin_lock_create(
+        spin_lock_attributes: PWDF_OBJECT_ATTRIBUTES,
+        spin_lock: *mut WDFSPINLOCK,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFSPINLOCKACQUIRE, WDFFUNCENUM::WdfSpinLockAcquireTableIndex):
+    pub unsafe fn spin_lock_acquire(
+        spin_lock: WdfObjectReference<'_, WDFSPINLOCK__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFSPINLOCKRELEASE, WDFFUNCENUM::WdfSpinLockReleaseTableIndex):
+    pub unsafe fn spin_lock_release(
+        spin_lock: WdfObjectReference<'_, WDFSPINLOCK__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFTIMERCREATE, WDFFUNCENUM::WdfTimerCreateTableIndex):
+    #[must_use]
+    pub unsafe fn timer_create(
+        config: PWDF_TIMER_CONFIG,
+        attributes: PWDF_OBJECT_ATTRIBUTES,
+        timer: *mut WDFTIMER,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFTIMERSTART, WDFFUNCENUM::WdfTimerStartTableIndex):
+    #[must_use]
+    pub unsafe fn timer_start(
+        timer: WdfObjectReference<'_, WDFTIMER__>,
+        due_time: LONGLONG,
+    ) -> BOOLEAN
+}
+
+wdf_function! {
+    (PFN_WDFTIMERSTOP, WDFFUNCENUM::WdfTimerStopTableIndex):
+    #[must_use]
+    pub unsafe fn timer_stop(
+        timer: WdfObjectReference<'_, WDFTIMER__>,
+        wait: BOOLEAN,
+    ) -> BOOLEAN
+}
+
+wdf_function! {
+    (PFN_WDFTIMERGETPARENTOBJECT, WDFFUNCENUM::WdfTimerGetParentObjectTableIndex):
+    #[must_use]
+    pub unsafe fn timer_get_parent_object(
+        timer: WdfObjectReference<'_, WDFTIMER__>,
+    ) -> WdfObjectReference<'_, RawWdfObject>
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETDEVICEGETDEVICEDESCRIPTOR, WDFFUNCENUM::WdfUsbTargetDeviceGetDeviceDescriptorTableIndex):
+    pub unsafe fn usb_target_device_get_device_descriptor(
+        usb_device: WdfObjectReference<'_, WDFUSBDEVICE__>,
+        usb_device_descriptor: PUSB_DEVICE_DESCRIPTOR,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETDEVICEGETNUMINTERFACES, WDFFUNCENUM::WdfUsbTargetDeviceGetNumInterfacesTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_device_get_num_interfaces(
+        usb_device: WdfObjectReference<'_, WDFUSBDEVICE__>,
+    ) -> UCHAR
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETDEVICESELECTCONFIG, WDFFUNCENUM::WdfUsbTargetDeviceSelectConfigTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_device_select_config(
+        usb_device: WdfObjectReference<'_, WDFUSBDEVICE__>,
+        pipe_attributes: PWDF_OBJECT_ATTRIBUTES,
+        params: PWDF_USB_DEVICE_SELECT_CONFIG_PARAMS,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETDEVICESENDCONTROLTRANSFERSYNCHRONOUSLY, WDFFUNCENUM::WdfUsbTargetDeviceSendControlTransferSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_device_send_control_transfer_synchronously(
+        usb_device: WdfObjectReference<'_, WDFUSBDEVICE__>,
+        request: WDFREQUEST,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        setup_packet: PWDF_USB_CONTROL_SETUP_PACKET,
+        memory_descriptor: PWDF_MEMORY_DESCRIPTOR,
+        bytes_transferred: PULONG,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETPIPEGETINFORMATION, WDFFUNCENUM::WdfUsbTargetPipeGetInformationTableIndex):
+    pub unsafe fn usb_target_pipe_get_information(
+        pipe: WdfObjectReference<'_, WDFUSBPIPE__>,
+        pipe_information: PWDF_USB_PIPE_INFORMATION,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETPIPESETNOMAXIMUMPACKETSIZECHECK, WDFFUNCENUM::WdfUsbTargetPipeSetNoMaximumPacketSizeCheckTableIndex):
+    pub unsafe fn usb_target_pipe_set_no_maximum_packet_size_check(
+        pipe: WdfObjectReference<'_, WDFUSBPIPE__>,
+    ) -> ()
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETPIPEWRITESYNCHRONOUSLY, WDFFUNCENUM::WdfUsbTargetPipeWriteSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_pipe_write_synchronously(
+        pipe: WdfObjectReference<'_, WDFUSBPIPE__>,
+        request: WDFREQUEST,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        memory_descriptor: PWDF_MEMORY_DESCRIPTOR,
+        bytes_written: PULONG,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETPIPEREADSYNCHRONOUSLY, WDFFUNCENUM::WdfUsbTargetPipeReadSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_pipe_read_synchronously(
+        pipe: WdfObjectReference<'_, WDFUSBPIPE__>,
+        request: WDFREQUEST,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+        memory_descriptor: PWDF_MEMORY_DESCRIPTOR,
+        bytes_read: PULONG,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETPIPERESETSYNCHRONOUSLY, WDFFUNCENUM::WdfUsbTargetPipeResetSynchronouslyTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_pipe_reset_synchronously(
+        pipe: WdfObjectReference<'_, WDFUSBPIPE__>,
+        request: WDFREQUEST,
+        request_options: PWDF_REQUEST_SEND_OPTIONS,
+    ) -> NtStatus
+}
+
+wdf_function! {
+    (PFN_WDFUSBINTERFACEGETINTERFACENUMBER, WDFFUNCENUM::WdfUsbInterfaceGetInterfaceNumberTableIndex):
+    #[must_use]
+    pub unsafe fn usb_interface_get_interface_number(
+        usb_interface: WdfObjectReference<'_, WDFUSBINTERFACE__>,
+    ) -> UCHAR
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETDEVICEGETINTERFACE, WDFFUNCENUM::WdfUsbTargetDeviceGetInterfaceTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_device_get_interface(
+        usb_device: WdfObjectReference<'_, WDFUSBDEVICE__>,
+        interface_index: UCHAR,
+    ) -> WdfObjectReference<'_, WDFUSBINTERFACE__>
+}
+
+wdf_function! {
+    (PFN_WDFUSBINTERFACEGETNUMCONFIGUREDPIPES, WDFFUNCENUM::WdfUsbInterfaceGetNumConfiguredPipesTableIndex):
+    #[must_use]
+    pub unsafe fn usb_interface_get_num_configured_pipes(
+        usb_interface: WdfObjectReference<'_, WDFUSBINTERFACE__>,
+    ) -> UCHAR
+}
+
+wdf_function! {
+    (PFN_WDFUSBINTERFACEGETCONFIGUREDPIPE, WDFFUNCENUM::WdfUsbInterfaceGetConfiguredPipeTableIndex):
+    #[must_use]
+    pub unsafe fn usb_interface_get_configured_pipe(
+        usb_interface: WdfObjectReference<'_, WDFUSBINTERFACE__>,
+        pipe_index: UCHAR,
+        pipe_info: PWDF_USB_PIPE_INFORMATION,
+    ) -> WdfObjectReference<'_, WDFUSBPIPE__>
+}
+
+wdf_function! {
+    (PFN_WDFUSBTARGETDEVICECREATEWITHPARAMETERS, WDFFUNCENUM::WdfUsbTargetDeviceCreateWithParametersTableIndex):
+    #[must_use]
+    pub unsafe fn usb_target_device_create_with_parameters(
+        device: WdfObjectReference<'_, WDFDEVICE__>,
+        config: PWDF_USB_DEVICE_CREATE_CONFIG,
+        attributes: PWDF_OBJECT_ATTRIBUTES,
+        usb_device: *mut WDFUSBDEVICE,
+    ) -> NtStatus
+}
@@ -1,35 +1,119 @@
-use super::{RawWdfDevice, RawWdfFileObject, RawWdfRequest, WdfObjectReference};
+use super::{
+    context::WdfObjectContextTypeInfo, device::Device, ffi, object_attributes::ObjectAttributes,
+    AsWdfReference, OwnedWdfObject, RawWdfDevice, RawWdfFileObject, RawWdfRequest,
+    WdfObjectReference,
+};
+use crate::Sealed;
 use core::mem::{size_of, transmute};
 use km_sys::WDF_FILEOBJECT_CONFIG;
 
-pub type EvtDeviceFileCreate = unsafe extern "C" fn(
+pub type EvtDeviceFileCreate = unsafe extern "system" fn(
     device: WdfObjectReference<'_, RawWdfDevice>,
     request: WdfObjectReference<'_, RawWdfRequest>,
     file_object: WdfObjectReference<'_, RawWdfFileObject>,
 );
 
-pub struct FileObjectConfig(pub(crate) WDF_FILEOBJECT_CONFIG);
+/// This is FFI-compatible with [`km_sys::PFN_WDF_FILE_CLOSE`]/[`km_sys::PFN_WDF_FILE_CLEANUP`].
+pub type EvtFileEvent =
+    unsafe extern "system" fn(file_object: WdfObjectReference<'_, RawWdfFileObject>);
+
+/// A guaranteed valid [`WDFFILEOBJECT`](km_sys::WDFFILEOBJECT), representing a handle a client
+/// opened to a device.
+///
+/// Per-client state can be attached to it with a context, see
+/// [`FileObjectConfigInit::file_object_attributes`].
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct FileObject(OwnedWdfObject<RawWdfFileObject>);
+impl Sealed for FileObject {}
+
+impl AsWdfReference for FileObject {
+    type ObjectType = RawWdfFileObject;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl From<WdfObjectReference<'_, RawWdfFileObject>> for FileObject {
+    fn from(raw: WdfObjectReference<'_, RawWdfFileObject>) -> Self {
+        Self(raw.to_owned())
+    }
+}
+
+impl From<OwnedWdfObject<RawWdfFileObject>> for FileObject {
+    fn from(owned: OwnedWdfObject<RawWdfFileObject>) -> Self {
+        Self(owned)
+    }
+}
+
+impl FileObject {
+    /// The device the file object was opened on.
+    pub fn device(&self) -> Device {
+        // SAFETY: The file object is guaranteed to be valid, and so is its device.
+        unsafe { Device::new(ffi::file_object_get_device(self.0.as_wdf_ref()).to_owned()) }
+    }
+
+    /// Calls `f` with the file object's context of the given type. Returns `None` if the file
+    /// object has no such context, or it wasn't
+    /// [initialized](WdfObjectContextTypeInfo::initialize) yet.
+    pub fn with_context<T: Sync, R>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        context_type.with(self, f)
+    }
+}
+
+pub struct FileObjectConfig {
+    pub(crate) config: WDF_FILEOBJECT_CONFIG,
+    pub(crate) attributes: Option<ObjectAttributes>,
+}
 
 impl FileObjectConfig {
     /// Creates a new `FileObjectConfig` with the default settings
     #[inline(always)]
     pub fn new(init: FileObjectConfigInit) -> Self {
-        Self(WDF_FILEOBJECT_CONFIG {
+        let config = WDF_FILEOBJECT_CONFIG {
             Size: size_of::<WDF_FILEOBJECT_CONFIG>() as u32,
 
             EvtDeviceFileCreate: init.evt_device_file_create.map(|f| {
                 // SAFETY: The function pointer definition is FFI-compatible.
                 unsafe { transmute(f) }
             }),
-            EvtFileClose: None,
-            EvtFileCleanup: None,
+            EvtFileClose: init.evt_file_close.map(|f| {
+                // SAFETY: The function pointer definition is FFI-compatible.
+                unsafe { transmute(f) }
+            }),
+            EvtFileCleanup: init.evt_file_cleanup.map(|f| {
+                // SAFETY: The function pointer definition is FFI-compatible.
+                unsafe { transmute(f) }
+            }),
             AutoForwardCleanupClose: km_sys::WDF_TRI_STATE::WdfUseDefault,
             FileObjectClass: km_sys::WDF_FILEOBJECT_CLASS::WdfFileObjectWdfCannotUseFsContexts,
-        })
+        };
+
+        Self {
+            config,
+            attributes: init.file_object_attributes,
+        }
     }
 }
 
 pub struct FileObjectConfigInit {
     // the rest will be added on demand
     pub evt_device_file_create: Option<EvtDeviceFileCreate>,
+    /// Called when the last handle to the file object was closed and all its requests were
+    /// completed, see [MSDN].
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nc-wdfdevice-evt_wdf_file_close
+    pub evt_file_close: Option<EvtFileEvent>,
+    /// Called when the last handle to the file object was closed, see [MSDN].
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nc-wdfdevice-evt_wdf_file_cleanup
+    pub evt_file_cleanup: Option<EvtFileEvent>,
+    /// Attributes of the file objects, e.g. created by [`ObjectAttributes::new_with_context`] to
+    /// attach per-client state to each file object.
+    pub file_object_attributes: Option<ObjectAttributes>,
 }
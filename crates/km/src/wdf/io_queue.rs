@@ -1,14 +1,26 @@
 use super::{
-    device::Device, ffi, AsWdfReference, OwnedWdfObject, RawWdfQueue, RawWdfRequest,
-    WdfObjectReference,
+    context::WdfObjectContextTypeInfo,
+    device::Device,
+    ffi,
+    file_object::FileObject,
+    request::{Request, RequestParameters},
+    AsWdfReference, OwnedWdfObject, RawWdfQueue, RawWdfRequest, WdfObjectReference,
 };
-use crate::private::Sealed;
+use crate::{private::Sealed, verify};
+use bitflags::bitflags;
 use core::{
     intrinsics::transmute,
     mem::{size_of, zeroed},
+    ptr::null_mut,
+};
+use km_shared::{
+    ioctl::{InternalIoControlCode, IoControlCode},
+    ntstatus::{NtStatus, NtStatusError},
+};
+use km_sys::{
+    PFN_WDF_IO_QUEUE_STATE, ULONG, WDFREQUEST, WDF_IO_QUEUE_CONFIG, WDF_IO_QUEUE_DISPATCH_TYPE,
+    WDF_IO_QUEUE_STATE, WDF_TRI_STATE,
 };
-use km_shared::ioctl::IoControlCode;
-use km_sys::{ULONG, WDF_IO_QUEUE_CONFIG, WDF_IO_QUEUE_DISPATCH_TYPE, WDF_TRI_STATE};
 
 pub type IoQueueDispatchType = WDF_IO_QUEUE_DISPATCH_TYPE;
 
@@ -19,6 +31,8 @@ pub enum IoQueueConfigInit {
     NonPnp {
         dispatch_type: IoQueueDispatchType,
         evt_io_device_control: Option<EvtIoDeviceControl>,
+        /// Called for internal device control requests, which are only sent by other drivers.
+        evt_io_internal_device_control: Option<EvtIoInternalDeviceControl>,
     },
 }
 
@@ -56,6 +70,7 @@ impl IoQueueConfigInit {
             IoQueueConfigInit::NonPnp {
                 dispatch_type,
                 evt_io_device_control,
+                evt_io_internal_device_control,
             } => {
                 let mut config = IoQueueConfig::init_default_queue(dispatch_type);
 
@@ -63,6 +78,11 @@ impl IoQueueConfigInit {
                     // SAFETY: `EvtIoDeviceControl` is defined to be compatible to
                     // `PFN_WDF_IO_QUEUE_IO_DEVICE_CONTROL` by using repr(transparent) wrappers.
                     evt_io_device_control.map(|f| unsafe { transmute(f) });
+                config.0.EvtIoInternalDeviceControl =
+                    // SAFETY: `EvtIoInternalDeviceControl` is defined to be compatible to
+                    // `PFN_WDF_IO_QUEUE_IO_INTERNAL_DEVICE_CONTROL` by using repr(transparent)
+                    // wrappers.
+                    evt_io_internal_device_control.map(|f| unsafe { transmute(f) });
 
                 config
             }
@@ -73,6 +93,28 @@ impl IoQueueConfigInit {
 pub struct IoQueueConfig(pub(crate) WDF_IO_QUEUE_CONFIG);
 
 impl IoQueueConfig {
+    /// Creates the config of a non-default queue with manual dispatching, i.e. a queue the driver
+    /// uses to hold on to requests until it [retrieves](IoQueue::retrieve_next_request) them.
+    ///
+    /// Requests are [forwarded](super::request::Request::forward_to_io_queue) to such a queue
+    /// explicitly. While a request is in the queue, the framework takes care of canceling it.
+    #[must_use]
+    pub fn manual() -> Self {
+        // SAFETY: It is initialized the same way as the force-inlined fn
+        // `WDF_IO_QUEUE_CONFIG_INIT` of the WDF would
+        let config = unsafe {
+            let mut config: WDF_IO_QUEUE_CONFIG = zeroed();
+            config.Size = size_of::<WDF_IO_QUEUE_CONFIG>() as ULONG;
+
+            config.PowerManaged = WDF_TRI_STATE::WdfUseDefault;
+            config.DispatchType = IoQueueDispatchType::WdfIoQueueDispatchManual;
+
+            config
+        };
+
+        IoQueueConfig(config)
+    }
+
     #[must_use]
     fn init_default_queue(dispatch_type: IoQueueDispatchType) -> Self {
         // SAFETY: It is initialized the same way as the force-inlined fn
@@ -96,7 +138,7 @@ impl IoQueueConfig {
     }
 }
 
-pub type EvtIoDeviceControl = unsafe extern "C" fn(
+pub type EvtIoDeviceControl = unsafe extern "system" fn(
     WdfObjectReference<'_, RawWdfQueue>,   // Queue
     WdfObjectReference<'_, RawWdfRequest>, // Request
     usize,                                 // OutputBufferLength
@@ -104,6 +146,16 @@ pub type EvtIoDeviceControl = unsafe extern "C" fn(
     IoControlCode,                         // IoControlCode
 );
 
+/// Like [`EvtIoDeviceControl`], but for internal device control requests
+/// (`IRP_MJ_INTERNAL_DEVICE_CONTROL`), whose codes are typed as [`InternalIoControlCode`].
+pub type EvtIoInternalDeviceControl = unsafe extern "system" fn(
+    WdfObjectReference<'_, RawWdfQueue>,   // Queue
+    WdfObjectReference<'_, RawWdfRequest>, // Request
+    usize,                                 // OutputBufferLength
+    usize,                                 // InputBufferLength
+    InternalIoControlCode,                 // IoControlCode
+);
+
 #[derive(Debug, Clone)]
 pub struct IoQueue(OwnedWdfObject<RawWdfQueue>);
 impl Sealed for IoQueue {}
@@ -137,8 +189,470 @@ impl From<OwnedWdfObject<RawWdfQueue>> for IoQueue {
 }
 
 impl IoQueue {
+    /// Deletes the queue before its device is deleted, canceling the requests in it.
+    ///
+    /// Other clones of the queue keep its memory valid, but mustn't be used anymore.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    /// No other clone of the queue may be used after this is called.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfobject/nf-wdfobject-wdfobjectdelete
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn delete(self) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: Queues are created by the driver, which may delete them. The caller guarantees
+        // that the queue isn't used anymore.
+        unsafe { self.0.delete() }
+    }
+
     pub fn device(&self) -> Device {
         // SAFETY: The queue is guaranteed to be valid.
         unsafe { Device::new(ffi::io_queue_get_device(self.0.as_wdf_ref()).to_owned()) }
     }
+
+    /// Retrieves the next request from a queue with manual dispatching (see
+    /// [`IoQueueConfig::manual`]). Returns `None` if the queue is empty.
+    ///
+    /// The retrieved request belongs to the driver, and isn't canceled by the framework anymore.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueueretrievenextrequest
+    pub fn retrieve_next_request(&self) -> Result<Option<Request>, NtStatusError> {
+        let mut request: WDFREQUEST = null_mut();
+
+        // SAFETY: The queue is guaranteed to be valid, and `request` is a valid pointer.
+        let status =
+            unsafe { ffi::io_queue_retrieve_next_request(self.0.as_wdf_ref(), &mut request) };
+
+        if status == NtStatus::STATUS_NO_MORE_ENTRIES {
+            return Ok(None);
+        }
+        status.result_for("WdfIoQueueRetrieveNextRequest")?;
+
+        debug_assert!(!request.is_null());
+
+        Ok(Some(OwnedWdfObject::from_new_raw(request).into()))
+    }
+
+    /// Resumes a queue that was [stopped](Self::stop), [drained](Self::drain) or
+    /// [purged](Self::purge), so that it accepts new requests and dispatches them to the driver
+    /// again.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuestart
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn start(&self) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The queue is guaranteed to be valid.
+        unsafe { ffi::io_queue_start(self.0.as_wdf_ref()) }
+    }
+
+    /// Stops dispatching requests to the driver. The queue keeps accepting new requests, which
+    /// are dispatched once it's [started](Self::start) again.
+    ///
+    /// `callback` is called once all requests that were dispatched to the driver were completed or
+    /// requeued.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    ///
+    /// `context` is passed to `callback` as is. The caller must ensure that it stays valid until
+    /// the callback has run.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuestop
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn stop<C>(&self, callback: Option<EvtIoQueueState<C>>, context: *mut C) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The queue is guaranteed to be valid, the validity of `context` is upheld by the
+        // caller.
+        unsafe {
+            ffi::io_queue_stop(
+                self.0.as_wdf_ref(),
+                raw_state_callback(callback),
+                context.cast(),
+            )
+        }
+    }
+
+    /// Like [`Self::stop`], but waits until all requests that were dispatched to the driver were
+    /// completed or requeued.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, and not from a callback of this queue, which would
+    /// deadlock.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuestopsynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn stop_synchronously(&self) {
+        verify::at_passive_level();
+
+        // SAFETY: The queue is guaranteed to be valid.
+        unsafe { ffi::io_queue_stop_synchronously(self.0.as_wdf_ref()) }
+    }
+
+    /// Stops accepting new requests, which are completed with `STATUS_INVALID_DEVICE_STATE`, but
+    /// keeps dispatching the requests already in the queue to the driver.
+    ///
+    /// `callback` is called once the queue is empty and all requests that were dispatched to the
+    /// driver were completed.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    ///
+    /// `context` is passed to `callback` as is. The caller must ensure that it stays valid until
+    /// the callback has run.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuedrain
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn drain<C>(&self, callback: Option<EvtIoQueueState<C>>, context: *mut C) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The queue is guaranteed to be valid, the validity of `context` is upheld by the
+        // caller.
+        unsafe {
+            ffi::io_queue_drain(
+                self.0.as_wdf_ref(),
+                raw_state_callback(callback),
+                context.cast(),
+            )
+        }
+    }
+
+    /// Like [`Self::drain`], but waits until the queue is empty and all requests that were
+    /// dispatched to the driver were completed.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, and not from a callback of this queue, which would
+    /// deadlock.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuedrainsynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn drain_synchronously(&self) {
+        verify::at_passive_level();
+
+        // SAFETY: The queue is guaranteed to be valid.
+        unsafe { ffi::io_queue_drain_synchronously(self.0.as_wdf_ref()) }
+    }
+
+    /// Stops accepting new requests, which are completed with `STATUS_INVALID_DEVICE_STATE`, and
+    /// cancels the requests in the queue. Requests that were dispatched to the driver are
+    /// canceled if they're cancelable.
+    ///
+    /// `callback` is called once all requests that were dispatched to the driver were completed.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    ///
+    /// `context` is passed to `callback` as is. The caller must ensure that it stays valid until
+    /// the callback has run.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuepurge
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn purge<C>(&self, callback: Option<EvtIoQueueState<C>>, context: *mut C) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The queue is guaranteed to be valid, the validity of `context` is upheld by the
+        // caller.
+        unsafe {
+            ffi::io_queue_purge(
+                self.0.as_wdf_ref(),
+                raw_state_callback(callback),
+                context.cast(),
+            )
+        }
+    }
+
+    /// Like [`Self::purge`], but waits until all requests that were dispatched to the driver were
+    /// completed.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, and not from a callback of this queue, which would
+    /// deadlock.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuepurgesynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn purge_synchronously(&self) {
+        verify::at_passive_level();
+
+        // SAFETY: The queue is guaranteed to be valid.
+        unsafe { ffi::io_queue_purge_synchronously(self.0.as_wdf_ref()) }
+    }
+
+    /// Gets the state of the queue, and the numbers of requests in it and dispatched from it, e.g.
+    /// to report how many requests are pending in diagnostics.
+    ///
+    /// The state can change right after it's queried, unless the queue is stopped.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuegetstate
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn state(&self) -> IoQueueState {
+        verify::at_most_dispatch_level();
+
+        let mut queued_requests = 0;
+        let mut driver_requests = 0;
+        // SAFETY: The queue is guaranteed to be valid, and the counts are valid pointers.
+        let state = unsafe {
+            ffi::io_queue_get_state(
+                self.0.as_wdf_ref(),
+                &mut queued_requests,
+                &mut driver_requests,
+            )
+        };
+
+        IoQueueState {
+            flags: IoQueueStateFlags::from_bits_retain(state.0 as u32),
+            queued_requests,
+            driver_requests,
+        }
+    }
+
+    /// Iterates over the requests in the queue, without removing them, e.g. to find the ones of a
+    /// client. If `file_object` is given, only the requests sent through it are found.
+    ///
+    /// The requests can be removed from a queue with manual dispatching with
+    /// [`Self::retrieve_found_request`]. If a found request is removed from the queue otherwise,
+    /// e.g. because it was canceled, the iteration ends early.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueuefindrequest
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn find_requests<'a>(&'a self, file_object: Option<&'a FileObject>) -> FoundRequests<'a> {
+        verify::at_most_dispatch_level();
+
+        FoundRequests {
+            queue: self,
+            file_object,
+            previous: None,
+        }
+    }
+
+    /// Removes a request found by [`Self::find_requests`] from a queue with manual dispatching
+    /// (see [`IoQueueConfig::manual`]), so that the driver can complete it. Returns `None` if the
+    /// request isn't in the queue anymore, e.g. because it was canceled.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfio/nf-wdfio-wdfioqueueretrievefoundrequest
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn retrieve_found_request(
+        &self,
+        found: &FoundRequest,
+    ) -> Result<Option<Request>, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let mut request: WDFREQUEST = null_mut();
+        // SAFETY: The queue and the found request are guaranteed to be valid, and `request` is a
+        // valid pointer.
+        let status = unsafe {
+            ffi::io_queue_retrieve_found_request(
+                self.0.as_wdf_ref(),
+                found.obj.as_wdf_ref(),
+                &mut request,
+            )
+        };
+
+        match status.result_for("WdfIoQueueRetrieveFoundRequest") {
+            Err(e) if e == NtStatusError::STATUS_NOT_FOUND => return Ok(None),
+            result => result?,
+        };
+
+        debug_assert!(!request.is_null());
+
+        Ok(Some(OwnedWdfObject::from_new_raw(request).into()))
+    }
+
+    /// Completes the requests in a queue with manual dispatching for which `matches` returns
+    /// `true` with `status`, e.g. to cancel the pending requests of a client that asked for it.
+    /// Only the requests sent through `file_object` are considered, if given. Returns the number of
+    /// completed requests.
+    ///
+    /// The search restarts after each completed request, so `matches` may be called more than once
+    /// for the same request.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn complete_matching(
+        &self,
+        file_object: Option<&FileObject>,
+        status: NtStatus,
+        mut matches: impl FnMut(&FoundRequest) -> bool,
+    ) -> usize {
+        let mut completed = 0;
+        'search: loop {
+            for found in self.find_requests(file_object) {
+                if !matches(&found) {
+                    continue;
+                }
+
+                // requests canceled in the meantime are completed by the framework
+                match self.retrieve_found_request(&found) {
+                    Ok(Some(request)) => {
+                        request.complete(status);
+                        completed += 1;
+                    }
+                    Ok(None) => {}
+                    Err(_) => break 'search,
+                }
+
+                // the search can't continue from a request that left the queue
+                continue 'search;
+            }
+            break;
+        }
+        completed
+    }
+
+    /// Calls `f` with the queue's context of the given type. Returns `None` if the queue has no
+    /// such context, or it wasn't [initialized](WdfObjectContextTypeInfo::initialize) yet.
+    pub fn with_context<T: Sync, R>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        context_type.with(self, f)
+    }
+}
+
+/// A callback for state changes of a queue, see e.g. [`IoQueue::stop`].
+///
+/// This is FFI-compatible with [`km_sys::PFN_WDF_IO_QUEUE_STATE`], with the context being typed
+/// as `C`.
+pub type EvtIoQueueState<C> =
+    unsafe extern "system" fn(queue: WdfObjectReference<'_, RawWdfQueue>, context: *mut C);
+
+fn raw_state_callback<C>(callback: Option<EvtIoQueueState<C>>) -> PFN_WDF_IO_QUEUE_STATE {
+    // SAFETY: `EvtIoQueueState` is defined to be compatible to `PFN_WDF_IO_QUEUE_STATE` by using
+    // repr(transparent) wrappers and a pointer for the context.
+    callback.map(|f| unsafe { transmute(f) })
+}
+
+bitflags! {
+    /// The state of a queue, see [`IoQueue::state`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct IoQueueStateFlags: u32 {
+        /// The queue accepts new requests.
+        const ACCEPT_REQUESTS = WDF_IO_QUEUE_STATE::WdfIoQueueAcceptRequests.0 as u32;
+        /// The queue dispatches requests to the driver.
+        const DISPATCH_REQUESTS = WDF_IO_QUEUE_STATE::WdfIoQueueDispatchRequests.0 as u32;
+        /// The queue is empty.
+        const NO_REQUESTS = WDF_IO_QUEUE_STATE::WdfIoQueueNoRequests.0 as u32;
+        /// No requests dispatched from the queue are owned by the driver.
+        const DRIVER_NO_REQUESTS = WDF_IO_QUEUE_STATE::WdfIoQueueDriverNoRequests.0 as u32;
+        /// The framework stopped the queue, as the device is changing its power state.
+        const PNP_HELD = WDF_IO_QUEUE_STATE::WdfIoQueuePnpHeld.0 as u32;
+    }
+}
+
+/// A snapshot of the state of a queue, see [`IoQueue::state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoQueueState {
+    pub flags: IoQueueStateFlags,
+    /// The number of requests in the queue, i.e. not yet dispatched to the driver.
+    pub queued_requests: u32,
+    /// The number of requests the queue dispatched to the driver, which weren't completed yet.
+    pub driver_requests: u32,
+}
+
+impl IoQueueState {
+    /// Whether the queue is empty, and all requests it dispatched were completed.
+    pub fn is_idle(&self) -> bool {
+        self.flags
+            .contains(IoQueueStateFlags::NO_REQUESTS | IoQueueStateFlags::DRIVER_NO_REQUESTS)
+    }
+}
+
+/// A request that is still in a queue, found by [`IoQueue::find_requests`].
+///
+/// The request stays in the queue, so it can't be completed or its buffers be accessed; only its
+/// parameters are available. It can be removed from the queue with
+/// [`IoQueue::retrieve_found_request`].
+pub struct FoundRequest {
+    obj: OwnedWdfObject<RawWdfRequest>,
+    parameters: RequestParameters,
+}
+
+impl FoundRequest {
+    /// The parameters of the request, e.g. its I/O control code.
+    pub fn parameters(&self) -> &RequestParameters {
+        &self.parameters
+    }
+}
+
+/// An iterator over the requests in a queue, see [`IoQueue::find_requests`].
+pub struct FoundRequests<'a> {
+    queue: &'a IoQueue,
+    file_object: Option<&'a FileObject>,
+    /// The request found last, from which the search continues.
+    previous: Option<OwnedWdfObject<RawWdfRequest>>,
+}
+
+impl Iterator for FoundRequests<'_> {
+    type Item = FoundRequest;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut parameters = RequestParameters::init();
+        let mut request: WDFREQUEST = null_mut();
+
+        // SAFETY: The queue, the previous request and the file object are either null or
+        // guaranteed to be valid, and the out pointers are valid.
+        let status = unsafe {
+            ffi::io_queue_find_request(
+                self.queue.0.as_wdf_ref(),
+                self.previous
+                    .as_ref()
+                    .map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                self.file_object
+                    .map_or(null_mut(), |f| f.as_wdf_ref().raw()),
+                &mut parameters.0,
+                &mut request,
+            )
+        };
+
+        // the previous request's reference is only released after the search continued from it,
+        // as required by the framework
+        self.previous = None;
+
+        // `STATUS_NO_MORE_ENTRIES` at the end, or `STATUS_NOT_FOUND` if the previous request was
+        // removed from the queue
+        if status.result().is_err() || request.is_null() {
+            return None;
+        }
+
+        // SAFETY: The framework took a reference on the found request for us.
+        let obj = unsafe { OwnedWdfObject::from_referenced_raw(request) };
+        self.previous = Some(obj.clone());
+        Some(FoundRequest { obj, parameters })
+    }
 }
@@ -0,0 +1,537 @@
+//! I/O targets, used to send requests to other drivers or device stacks.
+//!
+//! Every device has a [default (local) I/O target](Device::default_io_target) representing the
+//! next-lower driver in its stack. Remote I/O targets can be [created](IoTarget::create) and
+//! [opened](IoTarget::open) by device name (e.g. `\Device\Acpi`) to talk to any other stack.
+//!
+//! See [MSDN] for an overview.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/general-i-o-targets-in-kmdf
+
+use super::{
+    device::Device, ffi, memory::Memory, object_attributes::ObjectAttributes, request::Request,
+    AsWdfReference, OwnedWdfObject, RawWdfIoTarget, WdfObjectReference,
+};
+use crate::{time::relative_timeout, AsRawMutPtr, Sealed};
+use bitflags::bitflags;
+use bytemuck::{checked::CheckedCastError, CheckedBitPattern, NoUninit};
+use core::{
+    marker::PhantomData,
+    mem::{size_of, zeroed, MaybeUninit},
+    ptr::null_mut,
+    slice,
+    time::Duration,
+};
+use km_shared::{
+    ioctl::{InternalIoControlCode, IoControlCode, TypedInternalIoControlCode, TypedIoControlCode},
+    ntstatus::NtStatusError,
+    strings::UnicodeString,
+};
+use km_sys::{
+    ACCESS_MASK, PDEVICE_OBJECT, PFILE_OBJECT, PWDF_MEMORY_DESCRIPTOR, ULONG, WDFIOTARGET,
+    WDFMEMORY, WDF_IO_TARGET_OPEN_PARAMS, WDF_IO_TARGET_OPEN_TYPE, WDF_MEMORY_DESCRIPTOR,
+    WDF_MEMORY_DESCRIPTOR_TYPE, WDF_OBJECT_ATTRIBUTES, WDF_REQUEST_SEND_OPTIONS,
+    WDF_REQUEST_SEND_OPTIONS_FLAGS,
+};
+use snafu::{ensure, ResultExt, Snafu};
+
+/// A WDF I/O target. See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/introduction-to-i-o-targets
+#[derive(Debug, Clone)]
+pub struct IoTarget(OwnedWdfObject<RawWdfIoTarget>);
+impl Sealed for IoTarget {}
+
+impl AsWdfReference for IoTarget {
+    type ObjectType = RawWdfIoTarget;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl From<OwnedWdfObject<RawWdfIoTarget>> for IoTarget {
+    fn from(owned: OwnedWdfObject<RawWdfIoTarget>) -> Self {
+        Self(owned)
+    }
+}
+
+impl IoTarget {
+    /// Creates a new, not yet opened remote I/O target whose parent is `device`.
+    ///
+    /// The target has to be [opened](Self::open) before requests can be sent to it.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetcreate
+    pub fn create(
+        device: &Device,
+        mut attributes: Option<&mut ObjectAttributes>,
+    ) -> Result<IoTarget, NtStatusError> {
+        let mut io_target: WDFIOTARGET = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe {
+            ffi::io_target_create(
+                device.as_wdf_ref(),
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                &mut io_target,
+            )
+        }
+        .result_for("WdfIoTargetCreate")?;
+
+        debug_assert!(!io_target.is_null());
+
+        Ok(IoTarget(OwnedWdfObject::from_new_raw(io_target)))
+    }
+
+    /// Opens a remote I/O target.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetopen
+    pub fn open(&mut self, params: &IoTargetOpenParams<'_>) -> Result<(), NtStatusError> {
+        // `WdfIoTargetOpen` doesn't write to the params, it just isn't declared as `const`.
+        let mut params = params.0;
+
+        // SAFETY: The target is guaranteed to be valid, and the params are initialized the same
+        // way the `WDF_IO_TARGET_OPEN_PARAMS_INIT_*` functions would. Any borrowed names are kept
+        // alive by the lifetime of `IoTargetOpenParams`.
+        unsafe { ffi::io_target_open(self.as_wdf_ref(), &mut params) }
+            .result_for("WdfIoTargetOpen")?;
+
+        Ok(())
+    }
+
+    /// Closes a remote I/O target. Requests that are still pending are canceled.
+    ///
+    /// The target may be reopened with [`Self::open`] afterwards.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetclose
+    pub fn close(&mut self) {
+        // SAFETY: The target is guaranteed to be valid.
+        unsafe { ffi::io_target_close(self.as_wdf_ref()) }
+    }
+
+    /// Sends a device control request to the target and waits for it to complete.
+    ///
+    /// If `request` is `None`, the framework allocates a request internally. Returns the number of
+    /// bytes written to `output` by the target.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetsendioctlsynchronously
+    pub fn send_ioctl_synchronously(
+        &self,
+        request: Option<&Request>,
+        ioctl: IoControlCode,
+        input: Option<&[u8]>,
+        output: Option<&mut [u8]>,
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        let mut input = input.map(|input| {
+            // The target is not supposed to write to the input buffer. The `*mut` is just an
+            // artifact of the C signature.
+            MemoryDescriptor::buffer(input.as_ptr().cast_mut(), input.len())
+        });
+        let mut output =
+            output.map(|output| MemoryDescriptor::buffer(output.as_mut_ptr(), output.len()));
+        let mut options = options.0;
+        let mut bytes_returned = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call.
+        unsafe {
+            ffi::io_target_send_ioctl_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                ioctl.0,
+                MemoryDescriptor::as_raw(&mut input),
+                MemoryDescriptor::as_raw(&mut output),
+                &mut options,
+                &mut bytes_returned,
+            )
+        }
+        .result_for("WdfIoTargetSendIoctlSynchronously")?;
+
+        Ok(bytes_returned as usize)
+    }
+
+    /// Sends an internal device control request (`IRP_MJ_INTERNAL_DEVICE_CONTROL`) to the target,
+    /// e.g. a bus driver, and waits for it to complete. See [`Self::send_ioctl_synchronously`].
+    ///
+    /// If `request` is `None`, the framework allocates a request internally. Returns the number of
+    /// bytes written to `output` by the target.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetsendinternalioctlsynchronously
+    pub fn send_internal_ioctl_synchronously(
+        &self,
+        request: Option<&Request>,
+        ioctl: InternalIoControlCode,
+        input: Option<&[u8]>,
+        output: Option<&mut [u8]>,
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        let mut input = input.map(|input| {
+            // The target is not supposed to write to the input buffer. The `*mut` is just an
+            // artifact of the C signature.
+            MemoryDescriptor::buffer(input.as_ptr().cast_mut(), input.len())
+        });
+        let mut output =
+            output.map(|output| MemoryDescriptor::buffer(output.as_mut_ptr(), output.len()));
+        let mut options = options.0;
+        let mut bytes_returned = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call.
+        unsafe {
+            ffi::io_target_send_internal_ioctl_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                ioctl.code().0,
+                MemoryDescriptor::as_raw(&mut input),
+                MemoryDescriptor::as_raw(&mut output),
+                &mut options,
+                &mut bytes_returned,
+            )
+        }
+        .result_for("WdfIoTargetSendInternalIoctlSynchronously")?;
+
+        Ok(bytes_returned as usize)
+    }
+
+    /// Sends a read request to the target and waits for it to complete. Returns the number of
+    /// bytes read into `output`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetsendreadsynchronously
+    pub fn send_read_synchronously(
+        &self,
+        request: Option<&Request>,
+        output: &mut [u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        let mut output = MemoryDescriptor::buffer(output.as_mut_ptr(), output.len());
+        let mut options = options.0;
+        let mut bytes_read = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call. A null device offset means the current offset.
+        unsafe {
+            ffi::io_target_send_read_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                &mut output,
+                null_mut(),
+                &mut options,
+                &mut bytes_read,
+            )
+        }
+        .result_for("WdfIoTargetSendReadSynchronously")?;
+
+        Ok(bytes_read as usize)
+    }
+
+    /// Sends a write request to the target and waits for it to complete. Returns the number of
+    /// bytes written from `input`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetsendwritesynchronously
+    pub fn send_write_synchronously(
+        &self,
+        request: Option<&Request>,
+        input: &[u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        // The target is not supposed to write to the input buffer. The `*mut` is just an artifact
+        // of the C signature.
+        let mut input = MemoryDescriptor::buffer(input.as_ptr().cast_mut(), input.len());
+        let mut options = options.0;
+        let mut bytes_written = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call. A null device offset means the current offset.
+        unsafe {
+            ffi::io_target_send_write_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                &mut input,
+                null_mut(),
+                &mut options,
+                &mut bytes_written,
+            )
+        }
+        .result_for("WdfIoTargetSendWriteSynchronously")?;
+
+        Ok(bytes_written as usize)
+    }
+
+    /// Sends a typed device control request to the target and waits for it to complete.
+    ///
+    /// This is the sending counterpart to [`Request::handle_ioctl`]. The target has to fill the
+    /// entire output value, or this fails with [`SendIoctlError::OutputTooShort`].
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn send_typed_ioctl_synchronously<I, O>(
+        &self,
+        ioctl: TypedIoControlCode<I, O>,
+        input: &I,
+        options: &RequestSendOptions,
+    ) -> Result<O, SendIoctlError>
+    where
+        I: NoUninit,
+        O: CheckedBitPattern,
+    {
+        send_typed(input, |input, output| {
+            self.send_ioctl_synchronously(None, ioctl.code, input, output, options)
+        })
+    }
+
+    /// Sends a typed internal device control request to the target and waits for it to complete,
+    /// like [`Self::send_typed_ioctl_synchronously`].
+    ///
+    /// This is the sending counterpart to [`Request::handle_internal_ioctl`].
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn send_typed_internal_ioctl_synchronously<I, O>(
+        &self,
+        ioctl: TypedInternalIoControlCode<I, O>,
+        input: &I,
+        options: &RequestSendOptions,
+    ) -> Result<O, SendIoctlError>
+    where
+        I: NoUninit,
+        O: CheckedBitPattern,
+    {
+        send_typed(input, |input, output| {
+            self.send_internal_ioctl_synchronously(None, ioctl.code, input, output, options)
+        })
+    }
+
+    /// Formats `request` as a device control request for this target, using `input` and `output`
+    /// as its buffers. The request can then be [sent](Request::send).
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    ///
+    /// The framework accesses the buffers of `input` and `output` until the request is completed
+    /// or reformatted. The caller must not access these buffers in the meantime.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfiotarget/nf-wdfiotarget-wdfiotargetformatrequestforioctl
+    pub unsafe fn format_request_for_ioctl(
+        &self,
+        request: &Request,
+        ioctl: IoControlCode,
+        input: Option<&Memory>,
+        output: Option<&Memory>,
+    ) -> Result<(), NtStatusError> {
+        fn raw(memory: Option<&Memory>) -> WDFMEMORY {
+            memory.map_or(null_mut(), |m| m.as_wdf_ref().raw())
+        }
+
+        // SAFETY: The target, request and memory objects are guaranteed to be valid. Passing null
+        // offsets means the whole buffers are used. Access to the buffers is handled by the caller.
+        unsafe {
+            ffi::io_target_format_request_for_ioctl(
+                self.as_wdf_ref(),
+                request.as_wdf_ref(),
+                ioctl.0,
+                raw(input),
+                null_mut(),
+                raw(output),
+                null_mut(),
+            )
+        }
+        .result_for("WdfIoTargetFormatRequestForIoctl")?;
+
+        Ok(())
+    }
+}
+
+impl Device {
+    /// Gets the default (local) I/O target of the device, i.e. the next-lower driver in the
+    /// device's stack.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfdevice/nf-wdfdevice-wdfdevicegetiotarget
+    pub fn default_io_target(&self) -> IoTarget {
+        // SAFETY: The device is guaranteed to be valid.
+        IoTarget(unsafe { ffi::device_get_io_target(self.as_wdf_ref()) }.to_owned())
+    }
+}
+
+/// An error returned from [`IoTarget::send_typed_ioctl_synchronously`].
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum SendIoctlError {
+    NtStatus { source: NtStatusError },
+    OutputTooShort { bytes_returned: usize },
+    Cast { inner: CheckedCastError },
+}
+
+/// Parameters for [opening](IoTarget::open) a remote I/O target.
+pub struct IoTargetOpenParams<'a>(WDF_IO_TARGET_OPEN_PARAMS, PhantomData<&'a UnicodeString>);
+
+pub enum IoTargetOpenParamsInit<'a> {
+    /// Opens the device or file with the given name, e.g. `\Device\Acpi`.
+    ByName {
+        device_name: &'a UnicodeString,
+        /// Access rights requested for the target, e.g. `FILE_READ_DATA | FILE_WRITE_DATA`.
+        desired_access: ACCESS_MASK,
+    },
+    /// Uses a device stack the driver already has a pointer to.
+    UseExistingDevice {
+        device_object: PDEVICE_OBJECT,
+        file_object: PFILE_OBJECT,
+    },
+}
+
+impl<'a> IoTargetOpenParamsInit<'a> {
+    /// Builds the open parameters.
+    ///
+    /// ## Safety
+    ///
+    /// For [`Self::UseExistingDevice`], the caller ensures that `device_object` (and
+    /// `file_object`, if not null) are valid, and stay valid while the I/O target is open.
+    #[must_use]
+    pub unsafe fn build(self) -> IoTargetOpenParams<'a> {
+        // SAFETY: It is initialized the same way as the force-inlined fns
+        // `WDF_IO_TARGET_OPEN_PARAMS_INIT_*` of the WDF would.
+        let mut params = unsafe {
+            let mut params: WDF_IO_TARGET_OPEN_PARAMS = zeroed();
+            params.Size = size_of::<WDF_IO_TARGET_OPEN_PARAMS>() as ULONG;
+            params
+        };
+
+        match self {
+            IoTargetOpenParamsInit::ByName {
+                device_name,
+                desired_access,
+            } => {
+                params.Type = WDF_IO_TARGET_OPEN_TYPE::WdfIoTargetOpenByName;
+                params.TargetDeviceName = *device_name;
+                params.DesiredAccess = desired_access;
+                params.CreateDisposition = km_sys::FILE_OPEN;
+                params.CreateOptions = km_sys::FILE_NON_DIRECTORY_FILE;
+            }
+            IoTargetOpenParamsInit::UseExistingDevice {
+                device_object,
+                file_object,
+            } => {
+                params.Type = WDF_IO_TARGET_OPEN_TYPE::WdfIoTargetOpenUseExistingDevice;
+                params.TargetDeviceObject = device_object;
+                params.TargetFileObject = file_object;
+            }
+        }
+
+        IoTargetOpenParams(params, PhantomData)
+    }
+}
+
+bitflags! {
+    /// Flags for [`RequestSendOptions`]. See [MSDN] for more information.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/ne-wdfrequest-_wdf_request_send_options_flags
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RequestSendFlags: ULONG {
+        /// Send the request synchronously, i.e. wait for its completion.
+        const SYNCHRONOUS =
+            WDF_REQUEST_SEND_OPTIONS_FLAGS::WDF_REQUEST_SEND_OPTION_SYNCHRONOUS.0 as ULONG;
+        /// Send the request even if the target is stopped.
+        const IGNORE_TARGET_STATE =
+            WDF_REQUEST_SEND_OPTIONS_FLAGS::WDF_REQUEST_SEND_OPTION_IGNORE_TARGET_STATE.0 as ULONG;
+        /// Send the request without being notified of its completion. Only valid for requests
+        /// forwarded to the default I/O target.
+        const SEND_AND_FORGET =
+            WDF_REQUEST_SEND_OPTIONS_FLAGS::WDF_REQUEST_SEND_OPTION_SEND_AND_FORGET.0 as ULONG;
+    }
+}
+
+/// Options for sending a request to an I/O target.
+#[derive(Clone, Copy)]
+pub struct RequestSendOptions(pub(crate) WDF_REQUEST_SEND_OPTIONS);
+
+impl RequestSendOptions {
+    #[must_use]
+    #[inline(always)] // analogous to how `WDF_REQUEST_SEND_OPTIONS_INIT` works
+    pub fn new(flags: RequestSendFlags) -> Self {
+        Self(WDF_REQUEST_SEND_OPTIONS {
+            Size: size_of::<WDF_REQUEST_SEND_OPTIONS>() as ULONG,
+            Flags: flags.bits(),
+            Timeout: 0,
+        })
+    }
+
+    /// Cancels the request if it hasn't completed after `timeout`.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.0.Flags |= WDF_REQUEST_SEND_OPTIONS_FLAGS::WDF_REQUEST_SEND_OPTION_TIMEOUT.0 as ULONG;
+        self.0.Timeout = relative_timeout(timeout);
+        self
+    }
+}
+
+impl Default for RequestSendOptions {
+    fn default() -> Self {
+        Self::new(RequestSendFlags::empty())
+    }
+}
+
+/// Helper for describing a plain buffer passed to the framework.
+pub(crate) struct MemoryDescriptor;
+
+impl MemoryDescriptor {
+    /// Mimicks the `WDF_MEMORY_DESCRIPTOR_INIT_BUFFER` function of the WDF.
+    pub(crate) fn buffer(buffer: *mut u8, len: usize) -> WDF_MEMORY_DESCRIPTOR {
+        // SAFETY: All-zero is a valid bit pattern for the descriptor.
+        let mut descriptor: WDF_MEMORY_DESCRIPTOR = unsafe { zeroed() };
+        descriptor.Type = WDF_MEMORY_DESCRIPTOR_TYPE::WdfMemoryDescriptorTypeBuffer;
+        descriptor.u.BufferType.Buffer = buffer.cast();
+        // WDF only supports buffers up to `ULONG::MAX` here.
+        descriptor.u.BufferType.Length = len.try_into().unwrap_or(ULONG::MAX);
+        descriptor
+    }
+
+    pub(crate) fn as_raw(descriptor: &mut Option<WDF_MEMORY_DESCRIPTOR>) -> PWDF_MEMORY_DESCRIPTOR {
+        descriptor
+            .as_mut()
+            .map_or(null_mut(), |d| d as PWDF_MEMORY_DESCRIPTOR)
+    }
+}
+
+/// Sends `input` with `send`, and reads the output value from what the target wrote.
+fn send_typed<I, O>(
+    input: &I,
+    send: impl FnOnce(Option<&[u8]>, Option<&mut [u8]>) -> Result<usize, NtStatusError>,
+) -> Result<O, SendIoctlError>
+where
+    I: NoUninit,
+    O: CheckedBitPattern,
+{
+    let mut output = MaybeUninit::<O>::zeroed();
+    // SAFETY: The value is zero-initialized, so all of its bytes are initialized.
+    let output_bytes =
+        unsafe { slice::from_raw_parts_mut(output.as_mut_ptr().cast::<u8>(), size_of::<O>()) };
+
+    let bytes_returned = send(
+        (size_of::<I>() > 0).then(|| bytemuck::bytes_of(input)),
+        (size_of::<O>() > 0).then_some(&mut *output_bytes),
+    )
+    .context(send_ioctl_error::NtStatusSnafu)?;
+
+    ensure!(
+        bytes_returned >= size_of::<O>(),
+        send_ioctl_error::OutputTooShortSnafu { bytes_returned }
+    );
+
+    bytemuck::checked::try_pod_read_unaligned(output_bytes)
+        .map_err(|inner| send_ioctl_error::CastSnafu { inner }.build())
+}
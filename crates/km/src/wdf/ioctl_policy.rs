@@ -0,0 +1,234 @@
+//! Per-IOCTL access requirements, checked uniformly before a request is handled.
+//!
+//! The security descriptor of a device only controls who can open it, so codes that are more
+//! dangerous than the rest, e.g. flashing firmware, need further checks on the caller. An
+//! [`IoctlPolicy`] declares them next to the code, and
+//! [`Request::handle_ioctl_with_policy`] enforces them before the handler runs:
+//!
+//! ```rs, ignore
+//! static FLASH_OWNER: ExclusiveOpen = ExclusiveOpen::new();
+//!
+//! const FLASH_POLICY: IoctlPolicy = IoctlPolicy::new()
+//!     .requestor_mode(ProcessorMode::UserMode)
+//!     .privilege(Privilege::LoadDriver)
+//!     .exclusive(&FLASH_OWNER);
+//!
+//! // in `EvtIoDeviceControl`
+//! IOCTL_FLASH_BEGIN => {
+//!     let status = if FLASH_OWNER.try_claim(&request.file_object()?) {
+//!         STATUS_SUCCESS
+//!     } else {
+//!         STATUS_SHARING_VIOLATION
+//!     };
+//!     request.complete(status);
+//! }
+//! IOCTL_FLASH_WRITE => {
+//!     // SAFETY: The output buffer isn't retrieved anywhere else.
+//!     let result = unsafe {
+//!         request.handle_ioctl_with_policy(&FLASH_POLICY, IOCTL_FLASH_WRITE, |chunk, ()| {
+//!             flash.write(chunk)
+//!         })
+//!     };
+//!     // ...
+//! }
+//!
+//! // in `EvtFileCleanup`
+//! FLASH_OWNER.release(&file_object);
+//! ```
+//!
+//! [`Request::handle_ioctl_with_policy`]: super::request::Request::handle_ioctl_with_policy
+
+use super::{file_object::FileObject, request::Request, AsWdfReference};
+use crate::{
+    mode::ProcessorMode,
+    privileges::{Privilege, Token},
+    verify,
+};
+use core::{
+    ffi::c_void,
+    ptr::null_mut,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+use km_shared::ntstatus::NtStatusError;
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
+
+/// Why a request was rejected by an [`IoctlPolicy`].
+#[derive(Debug, Snafu, Clone, Copy)]
+#[snafu(module)]
+pub enum PolicyViolation {
+    /// The request was sent from the wrong processor mode.
+    #[snafu(display("request must come from {required:?}"))]
+    RequestorMode { required: ProcessorMode },
+    /// The requesting process doesn't hold the required privilege, or it isn't enabled.
+    #[snafu(display("requestor doesn't hold {privilege:?}"))]
+    PrivilegeNotHeld { privilege: Privilege },
+    /// The request wasn't sent through the file object that has claimed exclusive access.
+    #[snafu(display("request isn't from the exclusive owner"))]
+    NotExclusive,
+    /// The requestor's token couldn't be queried.
+    #[snafu(display("querying the requestor's token failed: {source}"))]
+    Token { source: NtStatusError },
+}
+
+impl PolicyViolation {
+    /// The status to complete the rejected request with.
+    pub fn nt_status(&self) -> NtStatusError {
+        match self {
+            Self::RequestorMode { .. } => NtStatusError::STATUS_INVALID_DEVICE_REQUEST,
+            Self::PrivilegeNotHeld { .. } => NtStatusError::STATUS_PRIVILEGE_NOT_HELD,
+            Self::NotExclusive => NtStatusError::STATUS_SHARING_VIOLATION,
+            Self::Token { source } => *source,
+        }
+    }
+}
+
+/// The requirements a request has to meet to be handled. See the
+/// [module documentation](self).
+///
+/// A policy is usually declared as a constant next to its code, and requires nothing until
+/// requirements are added.
+#[derive(Clone, Copy)]
+pub struct IoctlPolicy {
+    requestor_mode: Option<ProcessorMode>,
+    privilege: Option<Privilege>,
+    exclusive: Option<&'static ExclusiveOpen>,
+}
+
+impl IoctlPolicy {
+    /// A policy without any requirements.
+    pub const fn new() -> Self {
+        Self {
+            requestor_mode: None,
+            privilege: None,
+            exclusive: None,
+        }
+    }
+
+    /// Requires the request to be sent from `mode`, e.g. `KernelMode` for codes only other drivers
+    /// may use.
+    pub const fn requestor_mode(mut self, mode: ProcessorMode) -> Self {
+        self.requestor_mode = Some(mode);
+        self
+    }
+
+    /// Requires the process that sent the request to hold `privilege`, enabled. Requests from
+    /// kernel mode pass, like for `SeSinglePrivilegeCheck`.
+    ///
+    /// The privilege is checked on the primary token of the process, see
+    /// [`Token::of_requestor`], so the policy is only checked at `PASSIVE_LEVEL`.
+    pub const fn privilege(mut self, privilege: Privilege) -> Self {
+        self.privilege = Some(privilege);
+        self
+    }
+
+    /// Requires the request to be sent through the file object that claimed `owner`.
+    pub const fn exclusive(mut self, owner: &'static ExclusiveOpen) -> Self {
+        self.exclusive = Some(owner);
+        self
+    }
+
+    /// Checks whether `request` meets the requirements, in the order they're documented in.
+    ///
+    /// Must be called at `PASSIVE_LEVEL` if the policy requires a privilege, and at
+    /// `IRQL <= DISPATCH_LEVEL` otherwise.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn check(&self, request: &Request) -> Result<(), PolicyViolation> {
+        verify::at_most_dispatch_level();
+
+        let mode = request.requestor_mode();
+        if let Some(required) = self.requestor_mode {
+            ensure!(
+                mode == required,
+                policy_violation::RequestorModeSnafu { required }
+            );
+        }
+
+        if let Some(privilege) = self.privilege {
+            if mode == ProcessorMode::UserMode {
+                verify::at_passive_level();
+
+                let held = Token::of_requestor(request)
+                    .context(policy_violation::PrivilegeNotHeldSnafu { privilege })?
+                    .has_privilege(privilege)
+                    .context(policy_violation::TokenSnafu)?;
+                ensure!(held, policy_violation::PrivilegeNotHeldSnafu { privilege });
+            }
+        }
+
+        if let Some(owner) = self.exclusive {
+            let file_object = request
+                .file_object()
+                .context(policy_violation::NotExclusiveSnafu)?;
+            ensure!(
+                owner.is_held_by(&file_object),
+                policy_violation::NotExclusiveSnafu
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for IoctlPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Exclusive access to a set of codes, claimed by one file object, i.e. one open handle, at a
+/// time, and required by [`IoctlPolicy::exclusive`].
+///
+/// Unlike [`DeviceInit::set_exclusive_access`], other clients can still open the device and use
+/// its other codes.
+///
+/// [`DeviceInit::set_exclusive_access`]: super::device_init::DeviceInit::set_exclusive_access
+pub struct ExclusiveOpen {
+    holder: AtomicPtr<c_void>,
+}
+
+impl ExclusiveOpen {
+    pub const fn new() -> Self {
+        Self {
+            holder: AtomicPtr::new(null_mut()),
+        }
+    }
+
+    /// Claims exclusive access for `file_object`, returning whether it holds it now, i.e. `false`
+    /// if another file object holds it.
+    ///
+    /// Can be called at any IRQL.
+    pub fn try_claim(&self, file_object: &FileObject) -> bool {
+        let raw = file_object.as_wdf_ref().raw_obj();
+        match self
+            .holder
+            .compare_exchange(null_mut(), raw, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => true,
+            Err(holder) => holder == raw,
+        }
+    }
+
+    /// Releases exclusive access if `file_object` holds it. Has to be called when the file object
+    /// is cleaned up (`EvtFileCleanup`), so that the access is released when the client exits.
+    ///
+    /// Can be called at any IRQL.
+    pub fn release(&self, file_object: &FileObject) {
+        let raw = file_object.as_wdf_ref().raw_obj();
+        self.holder
+            .compare_exchange(raw, null_mut(), Ordering::AcqRel, Ordering::Relaxed)
+            .ok();
+    }
+
+    /// Whether `file_object` holds exclusive access.
+    ///
+    /// Can be called at any IRQL.
+    pub fn is_held_by(&self, file_object: &FileObject) -> bool {
+        self.holder.load(Ordering::Acquire) == file_object.as_wdf_ref().raw_obj()
+    }
+}
+
+impl Default for ExclusiveOpen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
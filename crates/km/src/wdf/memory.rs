@@ -0,0 +1,99 @@
+use super::{
+    ffi, object_attributes::ObjectAttributes, AsWdfReference, OwnedWdfObject, RawWdfMemory,
+    WdfObjectReference,
+};
+use crate::{AsRawMutPtr, Sealed};
+use core::{
+    ops::{Deref, DerefMut},
+    ptr::null_mut,
+    slice,
+};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{POOL_TYPE, WDFMEMORY, WDF_OBJECT_ATTRIBUTES};
+
+/// A framework memory object owning a buffer allocated from non-paged pool.
+///
+/// Memory objects are what [I/O targets](super::io_target::IoTarget) use to describe the buffers
+/// of a formatted request. See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/using-memory-buffers
+// (intentionally not providing a `Clone` impl as we are guaranteeing unique access to the buffer)
+#[derive(Debug)]
+pub struct Memory(OwnedWdfObject<RawWdfMemory>);
+impl Sealed for Memory {}
+
+impl AsWdfReference for Memory {
+    type ObjectType = RawWdfMemory;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl Memory {
+    /// Allocates a new memory object with a buffer of `size` bytes.
+    ///
+    /// The buffer is allocated from `NonPagedPoolNx` and is tagged with `pool_tag`, which is
+    /// usually written as a little-endian four character literal (e.g.
+    /// `u32::from_le_bytes(*b"Drv0")`). The contents of the buffer are not initialized by the
+    /// framework and are zeroed here before returning.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfmemory/nf-wdfmemory-wdfmemorycreate
+    pub fn create(
+        size: usize,
+        pool_tag: u32,
+        mut attributes: Option<&mut ObjectAttributes>,
+    ) -> Result<Memory, NtStatusError> {
+        let mut memory: WDFMEMORY = null_mut();
+        let mut buffer = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe {
+            ffi::memory_create(
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                POOL_TYPE::NonPagedPoolNx,
+                pool_tag,
+                size,
+                &mut memory,
+                &mut buffer,
+            )
+        }
+        .result_for("WdfMemoryCreate")?;
+
+        debug_assert!(!memory.is_null());
+
+        // SAFETY: The framework just handed us a buffer of exactly `size` bytes.
+        unsafe { buffer.cast::<u8>().write_bytes(0, size) };
+
+        Ok(Memory(OwnedWdfObject::from_new_raw(memory)))
+    }
+
+    fn raw_buffer(&self) -> (*mut u8, usize) {
+        let mut size = 0;
+        // SAFETY: The memory object is guaranteed to be valid.
+        let buffer = unsafe { ffi::memory_get_buffer(self.0.as_wdf_ref(), &mut size) };
+        (buffer.cast(), size)
+    }
+}
+
+impl Deref for Memory {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        let (buffer, size) = self.raw_buffer();
+        // SAFETY: The buffer is owned by the memory object, which outlives the returned borrow.
+        unsafe { slice::from_raw_parts(buffer, size) }
+    }
+}
+
+impl DerefMut for Memory {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let (buffer, size) = self.raw_buffer();
+        // SAFETY: The buffer is owned by the memory object, which outlives the returned borrow.
+        // Exclusive access is guaranteed by `&mut self`, as long as no request using this memory
+        // object is in flight (see `IoTarget::format_request_for_ioctl`).
+        unsafe { slice::from_raw_parts_mut(buffer, size) }
+    }
+}
@@ -0,0 +1,226 @@
+//! Event notifications to user mode through parked requests ("inverted call").
+//!
+//! User mode sends a "notify" I/O control request with an output buffer for one event, which the
+//! driver parks in a [`Notifier`] instead of completing it. Whenever the driver has an event to
+//! report, it [posts](Notifier::post) it, completing one of the parked requests with the event as
+//! its output. User mode usually keeps several requests pending, so that no events are missed
+//! while it handles one.
+//!
+//! Parked requests are held in a queue with manual dispatching, so the framework cancels them when
+//! the client closes its handle or cancels its I/O. See [Inverted Call Model][OSR] for background.
+//!
+//! ```rs, ignore
+//! struct DeviceContext {
+//!     alarms: Notifier<SensorAlarm, 8>,
+//! }
+//!
+//! unsafe extern "system" fn evt_io_device_control(/* ... */) {
+//!     match ioctl {
+//!         IOCTL_WAIT_FOR_ALARM => device.with_context(&DEVICE_CONTEXT, |ctx| {
+//!             unsafe { ctx.alarms.park(request) }
+//!         }),
+//!         // ...
+//!     }
+//! }
+//!
+//! fn on_sensor_alarm(ctx: &DeviceContext, alarm: SensorAlarm) {
+//!     ctx.alarms.post(alarm);
+//! }
+//! ```
+//!
+//! [OSR]: https://www.osr.com/nt-insider/2013-issue1/inverted-call-model-kmdf/
+
+use super::{
+    device::Device,
+    io_queue::{IoQueue, IoQueueConfig},
+    object_attributes::ObjectAttributes,
+    request::{Request, RetrieveOutputBufferError},
+    spin_lock::SpinLock,
+};
+use crate::verify;
+use bytemuck::NoUninit;
+use core::{
+    cell::UnsafeCell,
+    mem::size_of,
+    sync::atomic::{AtomicU32, Ordering},
+};
+use km_shared::{
+    ntstatus::{NtStatus, NtStatusError},
+    utils::ArrayVec,
+};
+
+/// What a [`Notifier`] does with a posted event if no request is parked and its backlog is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Drops the posted event, keeping the backlog as is.
+    DropNewest,
+    /// Drops the oldest event of the backlog to make room for the posted event.
+    DropOldest,
+}
+
+/// The outcome of [`Notifier::post`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// A parked request was completed with the event.
+    Completed,
+    /// No request was parked, so the event was added to the backlog.
+    Backlogged,
+    /// No request was parked and the backlog was full, so an event was dropped according to the
+    /// [`OverflowPolicy`].
+    Dropped,
+}
+
+/// Completes parked "notify" requests with events of type `T`, keeping up to `N` events in a
+/// backlog while no request is parked.
+///
+/// See the [module documentation](self) for an overview.
+pub struct Notifier<T, const N: usize> {
+    queue: IoQueue,
+    lock: SpinLock,
+    /// Only accessed while holding `lock`.
+    backlog: UnsafeCell<ArrayVec<T, N>>,
+    policy: OverflowPolicy,
+    dropped: AtomicU32,
+}
+
+// SAFETY: The backlog is only accessed while holding the lock, and events are sent to whichever
+// thread completes a request.
+unsafe impl<T: Send, const N: usize> Sync for Notifier<T, N> {}
+// SAFETY: See above.
+unsafe impl<T: Send, const N: usize> Send for Notifier<T, N> {}
+
+impl<T: NoUninit + Send, const N: usize> Notifier<T, N> {
+    /// Creates a notifier parking requests in a new queue of `device`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn new(device: &mut Device, policy: OverflowPolicy) -> Result<Self, NtStatusError> {
+        let queue = device.create_io_queue(&mut IoQueueConfig::manual(), None)?;
+
+        // The lock is deleted with the queue, instead of staying around until the driver unloads.
+        let mut lock_attributes = ObjectAttributes::default();
+        lock_attributes.set_parent(&queue);
+        let lock = SpinLock::create(Some(&mut lock_attributes))?;
+
+        Ok(Self {
+            queue,
+            lock,
+            backlog: UnsafeCell::new(ArrayVec::new()),
+            policy,
+            dropped: AtomicU32::new(0),
+        })
+    }
+
+    /// Parks a "notify" request until an event is posted, or completes it right away with the
+    /// oldest event of the backlog.
+    ///
+    /// The request is always taken care of: if its output buffer is too small for `T`, or it
+    /// can't be parked (e.g. because it was already canceled), it is completed with the failure
+    /// status.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// # Safety
+    /// Since the request's output buffer is written to, the same requirements as
+    /// [`Request::retrieve_output_buffer`] apply.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn park(&self, request: Request) {
+        verify::at_most_dispatch_level();
+
+        // Check the buffer before parking, so that posting never fails because of it.
+        // SAFETY: Guaranteed by the caller.
+        let checked = unsafe { request.retrieve_output_buffer(size_of::<T>()) }.map(drop);
+        if let Err(e) = checked {
+            request.complete(failure_status(e));
+            return;
+        }
+
+        let _guard = self.lock.acquire();
+        // SAFETY: The lock is held.
+        let backlog = unsafe { &mut *self.backlog.get() };
+
+        if !backlog.is_empty() {
+            let event = backlog.remove(0);
+            // SAFETY: Guaranteed by the caller.
+            unsafe { complete_with(request, &event) };
+        } else if let Err((request, e)) = request.forward_to_io_queue(&self.queue) {
+            request.complete(e.status());
+        }
+    }
+
+    /// Completes a parked request with `event`, or adds it to the backlog if no request is
+    /// parked.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn post(&self, event: T) -> Delivery {
+        verify::at_most_dispatch_level();
+
+        let _guard = self.lock.acquire();
+
+        // Requests whose output buffer can't be retrieved are completed with the failure, so just
+        // try the next. Canceled requests aren't retrieved at all, and retrieval only fails once
+        // the queue is purged (e.g. on teardown), in which case the event goes to the backlog.
+        while let Ok(Some(request)) = self.queue.retrieve_next_request() {
+            // SAFETY: Parked requests are only accessed here, and their output buffer was checked
+            // in `park`.
+            if unsafe { complete_with(request, &event) } {
+                return Delivery::Completed;
+            }
+        }
+
+        // SAFETY: The lock is held.
+        let backlog = unsafe { &mut *self.backlog.get() };
+
+        let delivery = match backlog.push(event) {
+            Ok(()) => Delivery::Backlogged,
+            Err(_) if self.policy == OverflowPolicy::DropNewest || N == 0 => Delivery::Dropped,
+            Err(e) => {
+                backlog.remove(0);
+                // Can't fail, as there is room for one event now.
+                let _ = backlog.push(e.0);
+                Delivery::Dropped
+            }
+        };
+
+        if delivery == Delivery::Dropped {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        delivery
+    }
+
+    /// The number of events dropped so far because the backlog was full.
+    pub fn dropped(&self) -> u32 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Completes `request` with `event` as its output. Returns whether the request was completed
+/// successfully; otherwise, it is completed with the failure status.
+///
+/// # Safety
+/// See [`Request::retrieve_output_buffer`].
+unsafe fn complete_with<T: NoUninit>(request: Request, event: &T) -> bool {
+    let bytes = bytemuck::bytes_of(event);
+
+    // SAFETY: Guaranteed by the caller.
+    let status = match unsafe { request.retrieve_output_buffer(bytes.len()) } {
+        Ok(mut buffer) => {
+            buffer[..bytes.len()].copy_from_slice(bytes);
+            drop(buffer);
+            request.set_information(bytes.len() as u64);
+            NtStatus::STATUS_SUCCESS
+        }
+        Err(e) => failure_status(e),
+    };
+
+    request.complete(status);
+    status == NtStatus::STATUS_SUCCESS
+}
+
+fn failure_status(e: RetrieveOutputBufferError) -> NtStatus {
+    match e {
+        RetrieveOutputBufferError::NtStatus { source } => source.status(),
+        // The request was moved in, so no buffer can be borrowed from it.
+        RetrieveOutputBufferError::OutputBufferAlreadyBorrowed => unreachable!(),
+    }
+}
@@ -1,5 +1,5 @@
 use super::{
-    ffi::{object_dereference_actual, object_reference_actual},
+    ffi::{object_delete, object_dereference_actual, object_reference_actual},
     RawWdfObject,
 };
 use crate::Sealed;
@@ -11,6 +11,13 @@ use km_sys::WDFOBJECT;
 pub struct WdfObjectReference<'a, T: 'static>(WDFOBJECT, PhantomData<&'a T>);
 impl<T> Sealed for WdfObjectReference<'_, T> {}
 
+// SAFETY: A handle only identifies a framework object, whose methods may be called from any thread
+// (at the IRQL they require). Wrappers with state of their own opt out where needed, see
+// `OwnedWdfObject`.
+unsafe impl<T> Send for WdfObjectReference<'_, T> {}
+// SAFETY: See above.
+unsafe impl<T> Sync for WdfObjectReference<'_, T> {}
+
 impl<T> Clone for WdfObjectReference<'_, T> {
     fn clone(&self) -> Self {
         *self
@@ -27,10 +34,32 @@ impl<T> WdfObjectReference<'_, T> {
         self.0
     }
 
+    /// Checks that the handle isn't null before passing it to `function`. Takes precedence over
+    /// [`VerifyArgument`](crate::verify::VerifyArgument) in `wdf_function!`.
+    #[cfg(feature = "verification")]
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn verify_argument(&self, function: &'static str) {
+        if self.0.is_null() {
+            crate::verify::violation(
+                crate::verify::ViolationKind::NullHandle { function },
+                core::panic::Location::caller(),
+            );
+        }
+    }
+
+    /// The handle to log for a WDF call. Takes precedence over
+    /// [`TraceArgument`](super::ffi::TraceArgument) in `wdf_function!`.
+    #[cfg(feature = "trace-ffi")]
+    #[inline(always)]
+    pub(crate) fn trace_handle(&self) -> Option<WDFOBJECT> {
+        Some(self.0)
+    }
+
     pub fn to_owned(&self) -> OwnedWdfObject<T> {
         // SAFETY: We're calling the function with a guaranteed valid handle, and the rest is set to
         // sane/null defaults.
-        unsafe { object_reference_actual(self.0.cast(), null_mut(), 0, null_mut()) }
+        unsafe { object_reference_actual(self.upcast(), null_mut(), 0, null_mut()) }
 
         OwnedWdfObject {
             raw: WdfObjectReference(self.0, PhantomData),
@@ -43,12 +72,12 @@ impl<T> WdfObjectReference<'_, T> {
     }
 }
 
-impl WdfObjectReference<'_, RawWdfObject> {
+impl<'a> WdfObjectReference<'a, RawWdfObject> {
     /// Converts the generic WDF object reference to a reference to a specific type.
     ///
     /// # Safety
     /// The caller must ensure that the object is actually of the type `T`.
-    pub unsafe fn downcast<T>(&self) -> WdfObjectReference<'_, T> {
+    pub unsafe fn downcast<T>(&self) -> WdfObjectReference<'a, T> {
         WdfObjectReference(self.0, PhantomData)
     }
 }
@@ -63,6 +92,21 @@ impl<T> AsWdfReference for WdfObjectReference<'_, T> {
 
 /// Represents an owned WDF object. See [Framework Object Life Cycle][msdn] for more details.
 ///
+/// # Thread safety
+///
+/// Framework objects are reference counted and synchronize their own state, so owned objects are
+/// `Send` and `Sync`, and so are the wrappers around them (e.g. [`Device`](super::device::Device),
+/// [`IoQueue`](super::io_queue::IoQueue) or [`Timer`](super::timer::Timer)). They can be moved
+/// into work items, timers and other threads as they are, as long as the IRQL requirements of
+/// their methods are met there. The exceptions are:
+/// - [`Request`](super::request::Request), which tracks the borrow of its output buffer, is only
+///   `Send`. Completing a request from another thread is best done through a
+///   [`PendingRequest`](super::request::PendingRequest).
+/// - Lock guards, like [`SpinLockGuard`](super::spin_lock::SpinLockGuard), are neither, as locks
+///   have to be released on the thread that acquired them.
+/// - Object contexts are only shared if they are `Sync`, see
+///   [`ContextHandle`](super::context::ContextHandle).
+///
 /// [msdn]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/framework-object-life-cycle
 #[derive(Debug)]
 #[repr(transparent)]
@@ -77,9 +121,39 @@ impl<T> OwnedWdfObject<T> {
         WdfObjectReference(obj.cast(), PhantomData).to_owned()
     }
 
+    /// Takes over a reference the framework already took for the driver, e.g. on a request found
+    /// by `WdfIoQueueFindRequest`, which is released when the `OwnedWdfObject` is dropped.
+    ///
+    /// # Safety
+    /// `obj` must be a valid handle, on which the caller holds a reference that isn't released
+    /// otherwise.
+    pub(crate) unsafe fn from_referenced_raw(obj: *mut T) -> Self {
+        Self {
+            raw: WdfObjectReference(obj.cast(), PhantomData),
+        }
+    }
+
     pub fn as_ref(&self) -> WdfObjectReference<'_, T> {
         WdfObjectReference(self.raw.0, PhantomData)
     }
+
+    /// Deletes the object and its children, and releases this reference.
+    ///
+    /// The memory of the object stays valid until all other references are released, but the
+    /// object mustn't be used through them anymore.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    /// The object must be one that the driver is allowed to delete, e.g. not a framework-created
+    /// request, or a PnP device.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfobject/nf-wdfobject-wdfobjectdelete
+    pub(crate) unsafe fn delete(self) {
+        // SAFETY: The handle is guaranteed to be valid, and the caller guarantees that it may be
+        // deleted. Our reference is released afterwards, when `self` is dropped.
+        unsafe { object_delete(self.raw.upcast()) }
+    }
 }
 
 impl<T> Clone for OwnedWdfObject<T> {
@@ -92,7 +166,7 @@ impl<T> Drop for OwnedWdfObject<T> {
     fn drop(&mut self) {
         // SAFETY: We're calling the function with a guaranteed valid handle, and the rest is set to
         // sane/null defaults.
-        unsafe { object_dereference_actual(self.raw.raw_obj(), null_mut(), 0, null_mut()) }
+        unsafe { object_dereference_actual(self.raw.upcast(), null_mut(), 0, null_mut()) }
     }
 }
 
@@ -108,3 +182,18 @@ impl<T> AsWdfReference for OwnedWdfObject<T> {
         self.as_ref()
     }
 }
+
+// compile-time check of the thread safety documented on `OwnedWdfObject`
+const _: () = {
+    const fn send_sync<T: Send + Sync>() {}
+    const fn send<T: Send>() {}
+
+    send_sync::<super::device::Device>();
+    send_sync::<super::io_queue::IoQueue>();
+    send_sync::<super::io_target::IoTarget>();
+    send_sync::<super::timer::Timer>();
+    send_sync::<super::spin_lock::SpinLock>();
+    send_sync::<super::wait_lock::WaitLock>();
+    send::<super::request::Request>();
+    send::<super::request::PendingRequest>();
+};
@@ -1,4 +1,4 @@
-use super::{context::WdfObjectContextTypeInfo, RawWdfObject, WdfObjectReference};
+use super::{context::WdfObjectContextTypeInfo, AsWdfReference, RawWdfObject, WdfObjectReference};
 use super::{ExecutionLevel, SynchronizationScope};
 use core::mem::{size_of, zeroed};
 use km_sys::{ULONG, WDF_OBJECT_ATTRIBUTES};
@@ -8,9 +8,16 @@ pub struct ObjectAttributes(pub(crate) WDF_OBJECT_ATTRIBUTES);
 
 /// This is FFI-compatible with
 /// [`km_sys::PFN_WDF_OBJECT_CONTEXT_CLEANUP`]/[`km_sys::PFN_WDF_OBJECT_CONTEXT_DESTROY`].
-pub type ObjectEventCallback = unsafe extern "C" fn(object: WdfObjectReference<'_, RawWdfObject>);
+pub type ObjectEventCallback =
+    unsafe extern "system" fn(object: WdfObjectReference<'_, RawWdfObject>);
 
 impl ObjectAttributes {
+    /// Creates object attributes with a context of the given type.
+    ///
+    /// If `init` has no `object_destroy_callback`, a callback is registered that drops the
+    /// context once the object is destroyed, if it was
+    /// [initialized](WdfObjectContextTypeInfo::initialize). Otherwise, the custom callback has to
+    /// call [`WdfObjectContextTypeInfo::destroy`] itself.
     #[must_use]
     #[inline(always)] // analogous to how the `WDF_OBJECT_ATTRIBUTES_INIT_CONTEXT_TYPE` macro works
     pub fn new_with_context<T>(
@@ -19,6 +26,12 @@ impl ObjectAttributes {
     ) -> Self {
         let mut attributes = Self::new(init);
         attributes.0.ContextTypeInfo = context_type.as_ptr();
+        if attributes.0.EvtDestroyCallback.is_none() {
+            attributes.0.EvtDestroyCallback = Some(context_type.destroy_callback())
+                // SAFETY: `ObjectEventCallback` is defined to be compatible with the FFI function
+                // type.
+                .map(|f| unsafe { core::mem::transmute(f) });
+        }
         attributes
     }
 
@@ -50,6 +63,12 @@ impl ObjectAttributes {
 
         Self(attributes)
     }
+
+    /// Sets the parent of the object, which deletes the object when it is deleted itself. Some
+    /// objects (e.g. timers) require a parent, others default to the driver object.
+    pub fn set_parent(&mut self, parent: &impl AsWdfReference) {
+        self.0.ParentObject = parent.as_wdf_ref().raw_obj();
+    }
 }
 
 impl Default for ObjectAttributes {
@@ -0,0 +1,157 @@
+//! The power policy of a device, i.e. whether the framework powers it down while it's idle (S0
+//! idle), and whether it can wake the system from sleep (Sx wake).
+//!
+//! Only the power policy owner of a PnP device (usually its function driver) can assign these
+//! settings, in `EvtDriverDeviceAdd` or later. They're rejected for control devices, which aren't
+//! power managed. See [MSDN] for an overview.
+//!
+//! ```rs, ignore
+//! // a USB sensor that may suspend after 10 s without I/O
+//! device.assign_s0_idle_settings(&mut S0IdleSettings::new(S0IdleSettingsInit {
+//!     idle_timeout: Duration::from_secs(10),
+//!     ..S0IdleSettingsInit::new(S0IdleCapabilities::IdleUsbSelectiveSuspend)
+//! }))?;
+//! ```
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/supporting-idle-power-down
+
+use core::{mem::size_of, time::Duration};
+use km_sys::{
+    ULONG, WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS, WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS,
+    WDF_TRI_STATE,
+};
+
+pub use km_sys::DEVICE_POWER_STATE as DevicePowerState;
+pub use km_sys::WDF_POWER_POLICY_IDLE_TIMEOUT_TYPE as IdleTimeoutType;
+pub use km_sys::WDF_POWER_POLICY_S0_IDLE_CAPABILITIES as S0IdleCapabilities;
+pub use km_sys::WDF_POWER_POLICY_S0_IDLE_USER_CONTROL as S0IdleUserControl;
+pub use km_sys::WDF_POWER_POLICY_SX_WAKE_USER_CONTROL as SxWakeUserControl;
+
+/// Converts an optional setting to a `WDF_TRI_STATE`, with `None` using the default.
+fn tri_state(value: Option<bool>) -> WDF_TRI_STATE {
+    match value {
+        Some(true) => WDF_TRI_STATE::WdfTrue,
+        Some(false) => WDF_TRI_STATE::WdfFalse,
+        None => WDF_TRI_STATE::WdfUseDefault,
+    }
+}
+
+pub struct S0IdleSettingsInit {
+    /// Whether the device can wake itself while the system is running, e.g. through USB
+    /// selective suspend.
+    pub capabilities: S0IdleCapabilities,
+    /// The low power state the device enters when idle. `PowerDeviceMaximum` selects the lowest
+    /// state the device can wake from.
+    pub dx_state: DevicePowerState,
+    /// How long the device has to be idle before it's powered down (with millisecond
+    /// resolution), or zero for the framework's default of five seconds.
+    pub idle_timeout: Duration,
+    /// Whether the user can turn idle power down off in the device's properties.
+    pub user_control: S0IdleUserControl,
+    /// Whether idle power down is enabled, or `None` to use the user's setting (enabled by
+    /// default).
+    pub enabled: Option<bool>,
+    /// Whether the device is powered up when the system wakes, even if it was idle before the
+    /// system slept. `None` leaves it powered down.
+    pub power_up_idle_device_on_system_wake: Option<bool>,
+    /// Whether the idle timeout is managed by the driver, or by the power framework.
+    pub idle_timeout_type: IdleTimeoutType,
+    /// Whether the device may not enter D3cold when idle, or `None` to use the setting of the
+    /// device's firmware.
+    pub exclude_d3_cold: Option<bool>,
+}
+
+impl S0IdleSettingsInit {
+    /// The default settings for the given capabilities, like the
+    /// `WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS_INIT` macro.
+    pub fn new(capabilities: S0IdleCapabilities) -> Self {
+        let dx_state = if capabilities == S0IdleCapabilities::IdleCannotWakeFromS0 {
+            DevicePowerState::PowerDeviceD3
+        } else {
+            DevicePowerState::PowerDeviceMaximum
+        };
+
+        Self {
+            capabilities,
+            dx_state,
+            idle_timeout: Duration::ZERO,
+            user_control: S0IdleUserControl::IdleAllowUserControl,
+            enabled: None,
+            power_up_idle_device_on_system_wake: None,
+            idle_timeout_type: IdleTimeoutType::DriverManagedIdleTimeout,
+            exclude_d3_cold: None,
+        }
+    }
+}
+
+/// The S0 idle settings of a device, see
+/// [`Device::assign_s0_idle_settings`](super::device::Device::assign_s0_idle_settings).
+pub struct S0IdleSettings(pub(crate) WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS);
+
+impl S0IdleSettings {
+    pub fn new(init: S0IdleSettingsInit) -> Self {
+        Self(WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS {
+            Size: size_of::<WDF_DEVICE_POWER_POLICY_IDLE_SETTINGS>() as ULONG,
+            IdleCaps: init.capabilities,
+            DxState: init.dx_state,
+            IdleTimeout: init
+                .idle_timeout
+                .as_millis()
+                .try_into()
+                .unwrap_or(ULONG::MAX),
+            UserControlOfIdleSettings: init.user_control,
+            Enabled: tri_state(init.enabled),
+            PowerUpIdleDeviceOnSystemWake: tri_state(init.power_up_idle_device_on_system_wake),
+            IdleTimeoutType: init.idle_timeout_type,
+            ExcludeD3Cold: tri_state(init.exclude_d3_cold),
+        })
+    }
+}
+
+pub struct SxWakeSettingsInit {
+    /// The low power state the device enters when the system sleeps. `PowerDeviceMaximum`
+    /// selects the lowest state the device can wake the system from.
+    pub dx_state: DevicePowerState,
+    /// Whether the user can turn waking the system off in the device's properties.
+    pub user_control: SxWakeUserControl,
+    /// Whether the device wakes the system, or `None` to use the user's setting (enabled by
+    /// default).
+    pub enabled: Option<bool>,
+    /// Whether the device is armed for wake whenever one of its children is, for bus drivers.
+    pub arm_for_wake_if_children_are_armed_for_wake: bool,
+    /// Whether the children armed for wake are reported as the wake source when the device
+    /// wakes the system, for bus drivers.
+    pub indicate_child_wake_on_parent_wake: bool,
+}
+
+impl Default for SxWakeSettingsInit {
+    /// The default settings, like the `WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS_INIT` macro.
+    fn default() -> Self {
+        Self {
+            dx_state: DevicePowerState::PowerDeviceMaximum,
+            user_control: SxWakeUserControl::WakeAllowUserControl,
+            enabled: None,
+            arm_for_wake_if_children_are_armed_for_wake: false,
+            indicate_child_wake_on_parent_wake: false,
+        }
+    }
+}
+
+/// The Sx wake settings of a device, see
+/// [`Device::assign_sx_wake_settings`](super::device::Device::assign_sx_wake_settings).
+pub struct SxWakeSettings(pub(crate) WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS);
+
+impl SxWakeSettings {
+    pub fn new(init: SxWakeSettingsInit) -> Self {
+        Self(WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS {
+            Size: size_of::<WDF_DEVICE_POWER_POLICY_WAKE_SETTINGS>() as ULONG,
+            DxState: init.dx_state,
+            UserControlOfWakeSettings: init.user_control,
+            Enabled: tri_state(init.enabled),
+            ArmForWakeIfChildrenAreArmedForWake: init
+                .arm_for_wake_if_children_are_armed_for_wake
+                .into(),
+            IndicateChildWakeOnParentWake: init.indicate_child_wake_on_parent_wake.into(),
+        })
+    }
+}
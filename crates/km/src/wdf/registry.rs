@@ -0,0 +1,495 @@
+//! The driver's configuration, read from the values of its `Parameters` key.
+//!
+//! [`declare_config!`](crate::declare_config!) declares a struct whose fields are loaded from
+//! values of the key, with defaults for missing values and validation, so that a misconfigured
+//! driver fails to load with an error naming the offending value, instead of running with a
+//! partially applied configuration:
+//!
+//! ```rs, ignore
+//! declare_config! {
+//!     /// The configuration of the sensor driver.
+//!     pub struct SensorConfig(pool_tag = POOL_TAG);
+//!
+//!     /// How often the sensors are polled, in milliseconds.
+//!     poll_interval_ms: u32 = "PollIntervalMs", default 100, valid |ms| (10..=10_000).contains(ms);
+//!     /// Whether every reading is logged.
+//!     verbose: bool = "Verbose", default false;
+//!     /// The controller to bind to, if it's not the first one.
+//!     controller: Option<UnicodeStringOwned> = "Controller";
+//!     /// The calibration of the sensors, which has to be provisioned.
+//!     calibration: [u8; 16] = "Calibration";
+//! }
+//!
+//! static CONFIG: OnceCell<SensorConfig> = OnceCell::new();
+//!
+//! // in `DriverEntry`, once the driver is created
+//! match SensorConfig::load(&driver) {
+//!     Ok(config) => CONFIG.set(config).ok(),
+//!     Err(e) => {
+//!         km_error!("invalid configuration: {e}");
+//!         return e.nt_status().into();
+//!     }
+//! };
+//! ```
+//!
+//! Values are read as any type implementing [`RegistryValue`], or directly through a
+//! [`RegistryKey`] from [`Driver::open_parameters_key`].
+
+use super::{
+    driver::Driver, ffi, memory::Memory, AsWdfReference, OwnedWdfObject, RawWdfKey,
+    WdfObjectReference,
+};
+use crate::{error::Error, strings::UnicodeStringOwned, verify, Sealed};
+use core::ptr::null_mut;
+use km_shared::{
+    ntstatus::{NtStatus, NtStatusError},
+    strings::UnicodeString,
+};
+use km_sys::{
+    REG_BINARY, REG_DWORD, REG_EXPAND_SZ, REG_MULTI_SZ, REG_NONE, REG_QWORD, REG_SZ, ULONG, WCHAR,
+    WDFKEY,
+};
+use snafu::{ensure, ResultExt, Snafu};
+
+/// An open registry key, which is closed on drop.
+///
+/// Closing the key has to happen at `PASSIVE_LEVEL`, so it mustn't be dropped at a higher IRQL.
+#[derive(Debug)]
+pub struct RegistryKey(OwnedWdfObject<RawWdfKey>);
+impl Sealed for RegistryKey {}
+
+impl AsWdfReference for RegistryKey {
+    type ObjectType = RawWdfKey;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+/// The type of a registry value, e.g. [`ValueType::DWORD`].
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-value-types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueType(pub u32);
+
+impl ValueType {
+    pub const NONE: Self = Self(REG_NONE);
+    pub const SZ: Self = Self(REG_SZ);
+    pub const EXPAND_SZ: Self = Self(REG_EXPAND_SZ);
+    pub const BINARY: Self = Self(REG_BINARY);
+    pub const DWORD: Self = Self(REG_DWORD);
+    pub const MULTI_SZ: Self = Self(REG_MULTI_SZ);
+    pub const QWORD: Self = Self(REG_QWORD);
+}
+
+/// The type and length of a value, returned from [`RegistryKey::query`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawValue {
+    pub value_type: ValueType,
+    /// The length of the data in bytes, which may be more than the buffer it was queried into.
+    pub len: usize,
+}
+
+impl RegistryKey {
+    /// Takes over a key from a `WdfXOpenRegistryKey` function.
+    pub(crate) fn from_new_raw(key: WDFKEY) -> Self {
+        Self(OwnedWdfObject::from_new_raw(key))
+    }
+
+    /// Queries value `name`, or returns `None` if there's no such value.
+    ///
+    /// The data is copied into `buffer` if it fits, i.e. if the returned length isn't larger than
+    /// `buffer`. Otherwise, `buffer` is left unchanged, and the returned length is the size of
+    /// the buffer needed to read it.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfregistry/nf-wdfregistry-wdfregistryqueryvalue
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn query(
+        &self,
+        name: &UnicodeString,
+        buffer: &mut [u8],
+    ) -> Result<Option<RawValue>, NtStatusError> {
+        verify::at_passive_level();
+
+        let value = if buffer.is_empty() {
+            null_mut()
+        } else {
+            buffer.as_mut_ptr().cast()
+        };
+        let mut len: ULONG = 0;
+        let mut value_type: ULONG = 0;
+
+        // SAFETY: The key is guaranteed to be valid, and `value` is either null or valid for
+        // writes of `buffer.len()` bytes, of which at most the passed length is written.
+        let status = unsafe {
+            ffi::registry_query_value(
+                self.as_wdf_ref(),
+                name,
+                ULONG::try_from(buffer.len()).unwrap_or(ULONG::MAX),
+                value,
+                &mut len,
+                &mut value_type,
+            )
+        };
+
+        // a warning, which would be an error in debug builds
+        if status != NtStatus::STATUS_BUFFER_OVERFLOW {
+            match status.result_for("WdfRegistryQueryValue") {
+                Ok(_) => {}
+                Err(e) if e == NtStatusError::STATUS_OBJECT_NAME_NOT_FOUND => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(Some(RawValue {
+            value_type: ValueType(value_type),
+            len: len as usize,
+        }))
+    }
+
+    /// Queries value `name` into a buffer of its size allocated with `pool_tag`, or returns `None`
+    /// if there's no such value. Empty values have no buffer.
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn query_allocated(
+        &self,
+        name: &UnicodeString,
+        pool_tag: u32,
+    ) -> Result<Option<(RawValue, Option<Memory>)>, NtStatusError> {
+        loop {
+            let Some(raw) = self.query(name, &mut [])? else {
+                return Ok(None);
+            };
+            if raw.len == 0 {
+                return Ok(Some((raw, None)));
+            }
+
+            let mut memory = Memory::create(raw.len, pool_tag, None)?;
+            match self.query(name, &mut memory)? {
+                None => return Ok(None),
+                Some(raw) if raw.len <= memory.len() => return Ok(Some((raw, Some(memory)))),
+                // the value grew in between, so try again with its new size
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+impl Drop for RegistryKey {
+    fn drop(&mut self) {
+        // SAFETY: The key is guaranteed to be valid, and isn't used after it's closed. Our
+        // reference is released afterwards, when the `OwnedWdfObject` is dropped.
+        unsafe { ffi::registry_close(self.0.as_wdf_ref()) }
+    }
+}
+
+/// Why a value couldn't be read as a [`RegistryValue`].
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum ValueError {
+    /// Querying the value failed.
+    #[snafu(context(false), display("{source}"))]
+    Query { source: Error },
+    /// The value has a type or length that the [`RegistryValue`] can't be read from.
+    #[snafu(display("value is {actual:?} with {len} bytes, expected {expected:?}"))]
+    WrongType {
+        expected: ValueType,
+        actual: ValueType,
+        len: usize,
+    },
+}
+
+impl From<NtStatusError> for ValueError {
+    fn from(source: NtStatusError) -> Self {
+        Self::Query {
+            source: source.into(),
+        }
+    }
+}
+
+/// A type that registry values can be read as, e.g. by [`declare_config!`](crate::declare_config!).
+///
+/// - `u32` is read from a [`ValueType::DWORD`].
+/// - `bool` is read from a [`ValueType::DWORD`], which is `true` unless it's 0.
+/// - `[u8; N]` is read from a [`ValueType::BINARY`] of exactly `N` bytes.
+/// - [`UnicodeStringOwned`] is read from a [`ValueType::SZ`], or a [`ValueType::EXPAND_SZ`],
+///   whose variables aren't expanded.
+/// - `Option<T>` is read as `T`, and is `None` if there's no such value.
+pub trait RegistryValue: Sized {
+    /// Reads value `name` of `key`, or returns `None` if there's no such value. Buffers are
+    /// allocated from non-paged pool tagged with `pool_tag`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    fn query(
+        key: &RegistryKey,
+        name: &UnicodeString,
+        pool_tag: u32,
+    ) -> Result<Option<Self>, ValueError>;
+
+    /// The value to use if there's no such value and no default, or `None` if the value is
+    /// required.
+    fn missing() -> Option<Self> {
+        None
+    }
+}
+
+/// Reads value `name` of `key` if it has type `expected` and exactly `N` bytes.
+#[cfg_attr(feature = "verification", track_caller)]
+fn query_fixed<const N: usize>(
+    key: &RegistryKey,
+    name: &UnicodeString,
+    expected: ValueType,
+) -> Result<Option<[u8; N]>, ValueError> {
+    let mut buffer = [0; N];
+    let Some(raw) = key.query(name, &mut buffer)? else {
+        return Ok(None);
+    };
+    ensure!(
+        raw.value_type == expected && raw.len == N,
+        value_error::WrongTypeSnafu {
+            expected,
+            actual: raw.value_type,
+            len: raw.len,
+        }
+    );
+
+    Ok(Some(buffer))
+}
+
+impl RegistryValue for u32 {
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn query(key: &RegistryKey, name: &UnicodeString, _: u32) -> Result<Option<Self>, ValueError> {
+        Ok(query_fixed(key, name, ValueType::DWORD)?.map(u32::from_le_bytes))
+    }
+}
+
+impl RegistryValue for bool {
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn query(
+        key: &RegistryKey,
+        name: &UnicodeString,
+        pool_tag: u32,
+    ) -> Result<Option<Self>, ValueError> {
+        Ok(u32::query(key, name, pool_tag)?.map(|value| value != 0))
+    }
+}
+
+impl<const N: usize> RegistryValue for [u8; N] {
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn query(key: &RegistryKey, name: &UnicodeString, _: u32) -> Result<Option<Self>, ValueError> {
+        query_fixed(key, name, ValueType::BINARY)
+    }
+}
+
+impl RegistryValue for UnicodeStringOwned {
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn query(
+        key: &RegistryKey,
+        name: &UnicodeString,
+        pool_tag: u32,
+    ) -> Result<Option<Self>, ValueError> {
+        let Some((raw, memory)) = key.query_allocated(name, pool_tag)? else {
+            return Ok(None);
+        };
+        ensure!(
+            raw.value_type == ValueType::SZ || raw.value_type == ValueType::EXPAND_SZ,
+            value_error::WrongTypeSnafu {
+                expected: ValueType::SZ,
+                actual: raw.value_type,
+                len: raw.len,
+            }
+        );
+
+        let mut string = UnicodeStringOwned::new(pool_tag);
+        if let Some(memory) = memory {
+            // the buffer is aligned for `WCHAR`s, as the pool aligns allocations to at least 8
+            // bytes, and the terminating NUL is optional
+            let wide: &[WCHAR] = bytemuck::cast_slice(&memory[..raw.len & !1]);
+            let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+            string.push_wide(&wide[..len])?;
+        }
+
+        Ok(Some(string))
+    }
+}
+
+impl<T: RegistryValue> RegistryValue for Option<T> {
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn query(
+        key: &RegistryKey,
+        name: &UnicodeString,
+        pool_tag: u32,
+    ) -> Result<Option<Self>, ValueError> {
+        Ok(T::query(key, name, pool_tag)?.map(Some))
+    }
+
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+/// Why loading a configuration declared with [`declare_config!`](crate::declare_config!) failed.
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum ConfigError {
+    /// The `Parameters` key couldn't be opened.
+    #[snafu(display("opening the parameters key failed: {source}"))]
+    Open { source: NtStatusError },
+    /// Value `name` couldn't be read as the type of its field.
+    #[snafu(display("reading {name} failed: {source}"))]
+    Value {
+        name: &'static str,
+        source: ValueError,
+    },
+    /// Value `name` has no default, but is missing.
+    #[snafu(display("{name} is missing"))]
+    Missing { name: &'static str },
+    /// Value `name` failed its validation.
+    #[snafu(display("{name} is invalid"))]
+    Invalid { name: &'static str },
+}
+
+impl ConfigError {
+    /// The status to fail `DriverEntry` with.
+    pub fn nt_status(&self) -> NtStatusError {
+        match self {
+            Self::Open { source } => *source,
+            Self::Value { source, .. } => match source {
+                ValueError::Query { source } => source.nt_status(),
+                ValueError::WrongType { .. } => NtStatusError::STATUS_OBJECT_TYPE_MISMATCH,
+            },
+            Self::Missing { .. } => NtStatusError::STATUS_OBJECT_NAME_NOT_FOUND,
+            Self::Invalid { .. } => NtStatusError::STATUS_INVALID_PARAMETER,
+        }
+    }
+}
+
+/// Declares a configuration struct, whose fields are loaded from values of the driver's
+/// `Parameters` key. See the [module documentation](crate::wdf::registry).
+///
+/// Each field names its value, and may have a `default` for when the value is missing, and a
+/// `valid` predicate that the value, or the default, has to satisfy. Fields without a default are
+/// required, unless their type is an `Option`. The fields have to implement [`RegistryValue`],
+/// and the struct is loaded at `PASSIVE_LEVEL` with:
+/// - `load(&Driver)`, from the driver's `Parameters` key.
+/// - `load_from(Option<&RegistryKey>)`, from another key. Without a key, all values are missing.
+///
+/// Buffers of values like strings are allocated with `pool_tag`.
+///
+/// [`RegistryValue`]: crate::wdf::registry::RegistryValue
+#[macro_export]
+macro_rules! declare_config {
+    {
+        $(#[$struct_attr:meta])*
+        $vis:vis struct $name:ident(pool_tag = $pool_tag:expr);
+
+        $(
+            $(#[$attr:meta])*
+            $field:ident: $ty:ty = $value:literal
+                $(, default $default:expr)?
+                $(, valid $valid:expr)?;
+        )*
+    } => {
+        $(#[$struct_attr])*
+        $vis struct $name {
+            $(
+                $(#[$attr])*
+                pub $field: $ty,
+            )*
+        }
+
+        impl $name {
+            /// Loads the configuration from the driver's `Parameters` key.
+            ///
+            /// Must be called at `PASSIVE_LEVEL`.
+            pub fn load(
+                driver: &$crate::wdf::driver::Driver,
+            ) -> Result<Self, $crate::wdf::registry::ConfigError> {
+                let key = $crate::wdf::registry::_internal_open_config(driver)?;
+                Self::load_from(key.as_ref())
+            }
+
+            /// Loads the configuration from `key`, or the defaults if there's no key.
+            ///
+            /// Must be called at `PASSIVE_LEVEL`.
+            pub fn load_from(
+                key: Option<&$crate::wdf::registry::RegistryKey>,
+            ) -> Result<Self, $crate::wdf::registry::ConfigError> {
+                $(
+                    let $field: $ty = match $crate::wdf::registry::_internal_read_config::<$ty>(
+                        key,
+                        $value,
+                        &$crate::shared::unicode_string!($value),
+                        $pool_tag,
+                    )? {
+                        Some(value) => value,
+                        None => $crate::__declare_config_default!($value, $ty $(, $default)?),
+                    };
+                    $(
+                        $crate::wdf::registry::_internal_validate_config(&$field, $value, $valid)?;
+                    )?
+                )*
+
+                Ok(Self { $($field),* })
+            }
+        }
+    };
+}
+
+/// Not to be used directly. The value of a missing field of [`declare_config!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_config_default {
+    ($value:literal, $ty:ty) => {
+        $crate::wdf::registry::_internal_missing_config::<$ty>($value)?
+    };
+    ($value:literal, $ty:ty, $default:expr) => {
+        $default
+    };
+}
+
+/// Not to be used directly. Used by [`declare_config!`](crate::declare_config!) to open the key.
+#[doc(hidden)]
+pub fn _internal_open_config(driver: &Driver) -> Result<Option<RegistryKey>, ConfigError> {
+    driver
+        .open_parameters_key()
+        .context(config_error::OpenSnafu)
+}
+
+/// Not to be used directly. Used by [`declare_config!`](crate::declare_config!) to read a value.
+#[doc(hidden)]
+pub fn _internal_read_config<T: RegistryValue>(
+    key: Option<&RegistryKey>,
+    name: &'static str,
+    value_name: &UnicodeString,
+    pool_tag: u32,
+) -> Result<Option<T>, ConfigError> {
+    match key {
+        Some(key) => T::query(key, value_name, pool_tag).context(config_error::ValueSnafu { name }),
+        None => Ok(None),
+    }
+}
+
+/// Not to be used directly. Used by [`declare_config!`](crate::declare_config!) for a missing
+/// value without a default.
+#[doc(hidden)]
+pub fn _internal_missing_config<T: RegistryValue>(name: &'static str) -> Result<T, ConfigError> {
+    T::missing().ok_or(ConfigError::Missing { name })
+}
+
+/// Not to be used directly. Used by [`declare_config!`](crate::declare_config!) to validate a
+/// value.
+#[doc(hidden)]
+pub fn _internal_validate_config<T>(
+    value: &T,
+    name: &'static str,
+    valid: impl FnOnce(&T) -> bool,
+) -> Result<(), ConfigError> {
+    ensure!(valid(value), config_error::InvalidSnafu { name });
+    Ok(())
+}
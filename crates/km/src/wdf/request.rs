@@ -1,17 +1,34 @@
-use super::{ffi, AsWdfReference, OwnedWdfObject, RawWdfRequest};
-use crate::{mode::ProcessorMode, private::Sealed};
-use bytemuck::{checked::CheckedCastError, CheckedBitPattern, NoUninit};
+use super::{
+    context::{ContextHandle, WdfObjectContextTypeInfo},
+    ffi,
+    file_object::FileObject,
+    io_queue::IoQueue,
+    io_target::{IoTarget, RequestSendFlags, RequestSendOptions},
+    ioctl_policy::{IoctlPolicy, PolicyViolation},
+    object_attributes::ObjectAttributes,
+    AsWdfReference, OwnedWdfObject, RawWdfIoTarget, RawWdfMemory, RawWdfRequest,
+    WdfObjectReference,
+};
+use crate::{km_warn, mdl::Mdl, mode::ProcessorMode, private::Sealed, verify, AsRawMutPtr};
+use bytemuck::{checked::CheckedCastError, CheckedBitPattern, NoUninit, Pod};
 use core::{
     cell::Cell,
-    mem::size_of,
+    marker::PhantomData,
+    mem::{size_of, transmute, zeroed},
     ops::{Deref, DerefMut},
-    ptr::null_mut,
+    ptr::{null_mut, NonNull},
     slice,
 };
 use km_shared::{
-    ioctl::TypedIoControlCode,
+    ioctl::{
+        InvalidField, IoControlCode, TypedInternalIoControlCode, TypedIoControlCode, Validate,
+    },
     ntstatus::{NtStatus, NtStatusError},
 };
+use km_sys::{
+    PIRP, ULONG_PTR, WDFMEMORY, WDFREQUEST, WDF_OBJECT_ATTRIBUTES, WDF_REQUEST_COMPLETION_PARAMS,
+    WDF_REQUEST_PARAMETERS, WDF_REQUEST_TYPE,
+};
 use snafu::{ensure, ResultExt, Snafu};
 
 /// A high-level wrapper around a [`RawRequest`](raw I/O control request).
@@ -51,71 +68,160 @@ pub enum IoCtlError {
         output_buffer: bool,
         inner: CheckedCastError,
     },
+    /// The input is a valid bit pattern, but violates its [`Validate`] constraints.
+    #[snafu(context(false))]
+    InvalidInput {
+        source: InvalidField,
+    },
+    /// The request doesn't meet the [`IoctlPolicy`] of its code.
+    #[snafu(context(false))]
+    PolicyViolation {
+        source: PolicyViolation,
+    },
 }
 
 impl Request {
+    /// Creates a new request object, e.g. for sending requests to an [`IoTarget`].
+    ///
+    /// If `io_target` is given, the request is sized for the target's stack.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestcreate
+    pub fn create(
+        io_target: Option<&IoTarget>,
+        mut attributes: Option<&mut ObjectAttributes>,
+    ) -> Result<Request, NtStatusError> {
+        let mut request: WDFREQUEST = null_mut();
+
+        // SAFETY: All pointers are either null or guaranteed to be valid.
+        unsafe {
+            ffi::request_create(
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                io_target.map_or(null_mut(), |t| t.as_wdf_ref().raw()),
+                &mut request,
+            )
+        }
+        .result_for("WdfRequestCreate")?;
+
+        debug_assert!(!request.is_null());
+
+        Ok(OwnedWdfObject::from_new_raw(request).into())
+    }
+
     /// Retrieve typed buffers for an I/O control request and calls the provided closure to handle
     /// the request.
     ///
+    /// For `METHOD_BUFFERED` codes, the input and output are the same system buffer, so the input
+    /// must not be read after the output was written, see [`Self::handle_ioctl_snapshot`].
+    ///
+    /// This is [`RequestOps::handle_ioctl`], available without importing the trait.
+    ///
     /// # Safety
     /// Since this function gives access to the output buffer, the same requirements as
     /// [`Self::retrieve_output_buffer`] apply.
     pub unsafe fn handle_ioctl<I, O, R>(
         &self,
-        // just to get the types without needing to manually specify them
-        _ioctl: TypedIoControlCode<I, O>,
+        ioctl: TypedIoControlCode<I, O>,
         f: impl FnOnce(&I, &mut O) -> R,
     ) -> Result<R, IoCtlError>
     where
-        I: CheckedBitPattern,
+        I: CheckedBitPattern + Validate,
         O: NoUninit + CheckedBitPattern,
     {
-        let input_buffer = if size_of::<I>() > 0 {
-            self.retrieve_input_buffer(size_of::<I>())?
-        } else {
-            InputBuffer {
-                slice: &[] as &'static [u8],
-            }
-        };
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe { RequestOps::handle_ioctl(self, ioctl, f) }
+    }
 
-        let input = bytemuck::checked::try_from_bytes(&input_buffer).map_err(|e| {
-            CastSnafu {
-                output_buffer: false,
-                inner: e,
-            }
-            .build()
-        })?;
+    /// Like [`Self::handle_ioctl`], but copies the input before the output buffer is retrieved,
+    /// so that it can be read after writing the output of a `METHOD_BUFFERED` code.
+    ///
+    /// This is [`RequestOps::handle_ioctl_snapshot`], available without importing the trait.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::handle_ioctl`] apply.
+    pub unsafe fn handle_ioctl_snapshot<I, O, R>(
+        &self,
+        ioctl: TypedIoControlCode<I, O>,
+        f: impl FnOnce(&I, &mut O) -> R,
+    ) -> Result<R, IoCtlError>
+    where
+        I: CheckedBitPattern + Validate,
+        O: NoUninit + CheckedBitPattern,
+    {
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe { RequestOps::handle_ioctl_snapshot(self, ioctl, f) }
+    }
 
-        let mut output_buffer = if size_of::<O>() > 0 {
-            // SAFETY: The requirements for this are promised to be upheld by the caller.
-            unsafe { self.retrieve_output_buffer(size_of::<O>()) }.map_err(|e| match e {
-                RetrieveOutputBufferError::OutputBufferAlreadyBorrowed => {
-                    IoCtlError::OutputBufferAlreadyBorrowed
-                }
-                RetrieveOutputBufferError::NtStatus { source } => IoCtlError::NtStatus { source },
-            })?
-        } else {
-            OutputBuffer {
-                request: self,
-                slice: &mut [] as &'static mut [u8],
-            }
-        };
+    /// Like [`Self::handle_ioctl`], but for internal device control requests, which are sent by
+    /// other drivers, e.g. in an
+    /// [`EvtIoInternalDeviceControl`](super::io_queue::EvtIoInternalDeviceControl) callback.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::handle_ioctl`] apply.
+    pub unsafe fn handle_internal_ioctl<I, O, R>(
+        &self,
+        ioctl: TypedInternalIoControlCode<I, O>,
+        f: impl FnOnce(&I, &mut O) -> R,
+    ) -> Result<R, IoCtlError>
+    where
+        I: CheckedBitPattern + Validate,
+        O: NoUninit + CheckedBitPattern,
+    {
+        let ioctl = TypedIoControlCode::new(ioctl.code.code());
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe { RequestOps::handle_ioctl(self, ioctl, f) }
+    }
 
-        let output = bytemuck::checked::try_from_bytes_mut(&mut output_buffer).map_err(|e| {
-            CastSnafu {
-                output_buffer: true,
-                inner: e,
-            }
-            .build()
-        })?;
+    /// Like [`Self::handle_ioctl`], but first checks that the request meets `policy`, failing
+    /// with [`IoCtlError::PolicyViolation`] without calling `f` otherwise.
+    ///
+    /// Must be called at `PASSIVE_LEVEL` if the policy requires a privilege, see
+    /// [`IoctlPolicy::check`].
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::handle_ioctl`] apply.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn handle_ioctl_with_policy<I, O, R>(
+        &self,
+        policy: &IoctlPolicy,
+        ioctl: TypedIoControlCode<I, O>,
+        f: impl FnOnce(&I, &mut O) -> R,
+    ) -> Result<R, IoCtlError>
+    where
+        I: CheckedBitPattern + Validate,
+        O: NoUninit + CheckedBitPattern,
+    {
+        policy.check(self)?;
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe { RequestOps::handle_ioctl(self, ioctl, f) }
+    }
 
-        let r = f(input, output);
+    /// Copies the input buffer into `scratch`, so it can be read after writing the output.
+    ///
+    /// This is [`RequestOps::snapshot_input`], available without importing the trait.
+    pub fn snapshot_input<'s>(&self, scratch: &'s mut [u8]) -> Result<&'s [u8], NtStatusError> {
+        RequestOps::snapshot_input(self, scratch)
+    }
 
-        if size_of::<O>() > 0 {
-            self.set_information(size_of::<O>() as u64);
-        }
+    /// Copies the input buffer of the request into a `T`.
+    ///
+    /// This is [`RequestOps::typed_input`], available without importing the trait.
+    pub fn typed_input<T: CheckedBitPattern + Validate>(&self) -> Result<T, IoCtlError> {
+        RequestOps::typed_input(self)
+    }
 
-        Ok(r)
+    /// Retrieves the output buffer of the request as a `T`.
+    ///
+    /// This is [`RequestOps::typed_output`], available without importing the trait.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::retrieve_output_buffer`] apply.
+    pub unsafe fn typed_output<T: NoUninit + CheckedBitPattern>(
+        &self,
+    ) -> Result<TypedBuffer<OutputBuffer<'_>, T>, IoCtlError> {
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe { RequestOps::typed_output(self) }
     }
 
     // Retrieves the input buffer of the request as a borrowed slice.
@@ -138,7 +244,7 @@ impl Request {
                 &mut buffer,
                 &mut buffer_len,
             )
-            .result()?;
+            .result_for("WdfRequestRetrieveInputBuffer")?;
         }
 
         Ok(InputBuffer {
@@ -181,7 +287,7 @@ impl Request {
                 &mut buffer,
                 &mut buffer_len,
             )
-            .result()
+            .result_for("WdfRequestRetrieveOutputBuffer")
             .context(retrieve_output_buffer_error::NtStatusSnafu)?;
         }
 
@@ -190,11 +296,58 @@ impl Request {
         Ok(unsafe { OutputBuffer::new(self, buffer.cast(), buffer_len) })
     }
 
+    /// Retrieves the MDL of the input buffer of a direct I/O request, e.g. a write request to a
+    /// device using direct I/O.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestretrieveinputwdmmdl
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn retrieve_input_mdl(&self) -> Result<Mdl<'_>, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let mut mdl = null_mut();
+
+        // SAFETY: We call the function with all valid parameters.
+        unsafe { ffi::request_retrieve_input_wdm_mdl(self.obj.as_wdf_ref(), &mut mdl) }
+            .result_for("WdfRequestRetrieveInputWdmMdl")?;
+
+        // SAFETY: The framework returns the locked MDL of the request, which stays valid until the
+        // request is completed, which requires giving up the borrow.
+        unsafe { Mdl::from_raw(mdl) }.ok_or(NtStatusError::STATUS_INVALID_DEVICE_REQUEST)
+    }
+
+    /// Retrieves the MDL of the output buffer of a direct I/O request, e.g. of a `METHOD_IN_DIRECT`
+    /// or `METHOD_OUT_DIRECT` I/O control request, or a read request to a device using direct I/O.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestretrieveoutputwdmmdl
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn retrieve_output_mdl(&self) -> Result<Mdl<'_>, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let mut mdl = null_mut();
+
+        // SAFETY: We call the function with all valid parameters.
+        unsafe { ffi::request_retrieve_output_wdm_mdl(self.obj.as_wdf_ref(), &mut mdl) }
+            .result_for("WdfRequestRetrieveOutputWdmMdl")?;
+
+        // SAFETY: The framework returns the locked MDL of the request, which stays valid until the
+        // request is completed, which requires giving up the borrow.
+        unsafe { Mdl::from_raw(mdl) }.ok_or(NtStatusError::STATUS_INVALID_DEVICE_REQUEST)
+    }
+
     /// Sets the number of bytes written to the output buffer.
     pub fn set_information(&self, information: u64) {
         // SAFETY: We call the function with all valid parameters.
         unsafe {
-            ffi::request_set_information(self.obj.as_wdf_ref(), information);
+            // `ULONG_PTR` is 64 bits on x64, the only supported target
+            ffi::request_set_information(self.obj.as_wdf_ref(), information as ULONG_PTR);
         }
     }
 
@@ -208,6 +361,315 @@ impl Request {
         }
     }
 
+    /// Checks whether the request was sent by a 32-bit process on 64-bit Windows (WOW64). Such
+    /// processes use 32-bit pointers in their buffers, so payloads that aren't
+    /// [`FixedLayout`](km_shared::ioctl::FixedLayout) have to be parsed differently for them.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestisfrom32bitprocess
+    pub fn is_from_32_bit_process(&self) -> bool {
+        // SAFETY: We call the ffi function with all valid parameters.
+        unsafe { ffi::request_is_from_32_bit_process(self.obj.as_wdf_ref()) != 0 }
+    }
+
+    /// Gets the parameters of the request, e.g. its I/O control code, without retrieving its
+    /// buffers.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestgetparameters
+    pub fn parameters(&self) -> RequestParameters {
+        let mut params = RequestParameters::init();
+
+        // SAFETY: We call the function with all valid parameters.
+        unsafe { ffi::request_get_parameters(self.obj.as_wdf_ref(), &mut params.0) };
+
+        params
+    }
+
+    /// Probes and locks the user mode input buffer of a `METHOD_NEITHER` I/O control request,
+    /// which has to be at least `minimum_required_length` bytes long.
+    ///
+    /// The buffer is usually [attached](WdfObjectContextTypeInfo::allocate) to the request
+    /// as a context, so it can be accessed by the queue's handler.
+    ///
+    /// See [MSDN] for more details on the underlying functions.
+    ///
+    /// # Safety
+    /// This must be called from the device's
+    /// [`EvtIoInCallerContext`](super::device_init::EvtIoInCallerContext) callback for this
+    /// request, as the buffer is only valid in the address space of the requesting process.
+    ///
+    /// The returned buffer must not be accessed after the request was completed, which unlocks it.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestprobeandlockuserbufferforread
+    pub unsafe fn probe_and_lock_user_input_buffer(
+        &self,
+        minimum_required_length: usize,
+    ) -> Result<LockedUserBuffer<ForRead>, NtStatusError> {
+        let mut buffer = null_mut();
+        let mut buffer_len = 0;
+        // SAFETY: We call the function with all valid parameters.
+        unsafe {
+            ffi::request_retrieve_unsafe_user_input_buffer(
+                self.obj.as_wdf_ref(),
+                minimum_required_length,
+                &mut buffer,
+                &mut buffer_len,
+            )
+        }
+        .result_for("WdfRequestRetrieveUnsafeUserInputBuffer")?;
+
+        let mut memory = null_mut();
+        // SAFETY: The buffer was retrieved from the request above, and the caller guarantees that
+        // we're in the context of the requesting process.
+        unsafe {
+            ffi::request_probe_and_lock_user_buffer_for_read(
+                self.obj.as_wdf_ref(),
+                buffer,
+                buffer_len,
+                &mut memory,
+            )
+        }
+        .result_for("WdfRequestProbeAndLockUserBufferForRead")?;
+
+        // SAFETY: The memory object was just created by the framework.
+        Ok(unsafe { LockedUserBuffer::new(memory) })
+    }
+
+    /// Probes and locks the user mode output buffer of a `METHOD_NEITHER` I/O control request,
+    /// which has to be at least `minimum_required_length` bytes long.
+    ///
+    /// See [`Self::probe_and_lock_user_input_buffer`], and [MSDN] for more details on the
+    /// underlying functions.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::probe_and_lock_user_input_buffer`] apply.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestprobeandlockuserbufferforwrite
+    pub unsafe fn probe_and_lock_user_output_buffer(
+        &self,
+        minimum_required_length: usize,
+    ) -> Result<LockedUserBuffer<ForWrite>, NtStatusError> {
+        let mut buffer = null_mut();
+        let mut buffer_len = 0;
+        // SAFETY: We call the function with all valid parameters.
+        unsafe {
+            ffi::request_retrieve_unsafe_user_output_buffer(
+                self.obj.as_wdf_ref(),
+                minimum_required_length,
+                &mut buffer,
+                &mut buffer_len,
+            )
+        }
+        .result_for("WdfRequestRetrieveUnsafeUserOutputBuffer")?;
+
+        let mut memory = null_mut();
+        // SAFETY: The buffer was retrieved from the request above, and the caller guarantees that
+        // we're in the context of the requesting process.
+        unsafe {
+            ffi::request_probe_and_lock_user_buffer_for_write(
+                self.obj.as_wdf_ref(),
+                buffer,
+                buffer_len,
+                &mut memory,
+            )
+        }
+        .result_for("WdfRequestProbeAndLockUserBufferForWrite")?;
+
+        // SAFETY: The memory object was just created by the framework.
+        Ok(unsafe { LockedUserBuffer::new(memory) })
+    }
+
+    /// Gets the underlying WDM IRP of the request.
+    pub(crate) fn wdm_irp(&self) -> PIRP {
+        // SAFETY: We call the function with all valid parameters.
+        unsafe { ffi::request_wdm_get_irp(self.obj.as_wdf_ref()) }
+    }
+
+    /// Gets the file object the request was sent through, i.e. the client handle it belongs to.
+    ///
+    /// Returns `None` if the request isn't associated with a file object, e.g. for requests
+    /// created by the driver itself.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestgetfileobject
+    pub fn file_object(&self) -> Option<FileObject> {
+        // SAFETY: The request is guaranteed to be valid.
+        let file_object = unsafe { ffi::request_get_file_object(self.obj.as_wdf_ref()) };
+
+        (!file_object.raw().is_null()).then(|| file_object.into())
+    }
+
+    /// Initializes the request's context of the given type with `value`, e.g. with a correlation
+    /// ID and the time the request was received. The value is dropped when the framework destroys
+    /// the request, after it was completed.
+    ///
+    /// Framework-created requests only have a context if one was configured with
+    /// [`DeviceInit::set_request_attributes`], or it was
+    /// [allocated](WdfObjectContextTypeInfo::allocate) for the request. Returns `value` back if
+    /// the request has no such context, or it was already initialized.
+    ///
+    /// [`DeviceInit::set_request_attributes`]: super::device_init::DeviceInit::set_request_attributes
+    pub fn initialize_context<T>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+        value: T,
+    ) -> Result<ContextHandle<RawWdfRequest, T>, T> {
+        context_type.initialize(self, value)
+    }
+
+    /// Returns a handle to the request's context of the given type, e.g. to keep it alongside the
+    /// request when forwarding it. Returns `None` if the request has no such context, or it wasn't
+    /// [initialized](Self::initialize_context) yet.
+    pub fn context<T>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+    ) -> Option<ContextHandle<RawWdfRequest, T>> {
+        context_type.handle(self)
+    }
+
+    /// Calls `f` with the request's context of the given type. Returns `None` if the request has
+    /// no such context, or it wasn't [initialized](Self::initialize_context) yet.
+    pub fn with_context<T: Sync, R>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        context_type.with(self, f)
+    }
+
+    /// Gets the I/O queue the request was delivered through. Its device can be retrieved with
+    /// [`IoQueue::device`].
+    ///
+    /// Returns `None` if the request doesn't belong to a queue, e.g. for requests created by the
+    /// driver itself.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestgetioqueue
+    pub fn io_queue(&self) -> Option<IoQueue> {
+        // SAFETY: The request is guaranteed to be valid.
+        let queue = unsafe { ffi::request_get_io_queue(self.obj.as_wdf_ref()) };
+
+        (!queue.raw().is_null()).then(|| queue.to_owned().into())
+    }
+
+    /// Gets the status of the request, e.g. after it was completed by an I/O target.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestgetstatus
+    pub fn status(&self) -> NtStatus {
+        // SAFETY: The request is guaranteed to be valid.
+        unsafe { ffi::request_get_status(self.obj.as_wdf_ref()) }
+    }
+
+    /// Formats the request so it can be sent unmodified to the next-lower driver, keeping its
+    /// current type and parameters.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestformatrequestusingcurrenttype
+    pub fn format_using_current_type(&self) {
+        // SAFETY: The request is guaranteed to be valid.
+        unsafe { ffi::request_format_request_using_current_type(self.obj.as_wdf_ref()) }
+    }
+
+    /// Sends the (formatted) request to `target`.
+    ///
+    /// On success, the request belongs to the target until it is completed, and must not be
+    /// completed by the caller; unless [`RequestSendFlags::SYNCHRONOUS`] was used, in which case the
+    /// request has already been completed by the target, and its result can be retrieved via
+    /// [`Self::status`]. On failure, the request still belongs to the caller.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestsend
+    pub fn send(
+        &self,
+        target: &IoTarget,
+        options: &RequestSendOptions,
+    ) -> Result<(), NtStatusError> {
+        let mut options = options.0;
+
+        // SAFETY: The request and target are guaranteed to be valid.
+        let sent =
+            unsafe { ffi::request_send(self.obj.as_wdf_ref(), target.as_wdf_ref(), &mut options) };
+
+        if sent != 0 {
+            Ok(())
+        } else {
+            self.status().result_for("WdfRequestSend").map(|_| ())
+        }
+    }
+
+    /// Sets the routine that is called when the (formatted) request is completed by the I/O target
+    /// it was [sent](Self::send) to.
+    ///
+    /// The completion routine becomes responsible for the request again, i.e. it has to complete
+    /// it, or delete it if the driver created it.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    ///
+    /// `context` is passed to `routine` as is. The caller must ensure that it stays valid until the
+    /// completion routine has run.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestsetcompletionroutine
+    pub unsafe fn set_completion_routine<C>(
+        &self,
+        routine: Option<EvtRequestCompletionRoutine<C>>,
+        context: *mut C,
+    ) {
+        // SAFETY: `EvtRequestCompletionRoutine` is defined to be compatible to
+        // `PFN_WDF_REQUEST_COMPLETION_ROUTINE` by using repr(transparent) wrappers.
+        let routine = routine.map(|f| unsafe { transmute(f) });
+
+        // SAFETY: The request is guaranteed to be valid, the validity of `context` is upheld by the
+        // caller.
+        unsafe {
+            ffi::request_set_completion_routine(self.obj.as_wdf_ref(), routine, context.cast())
+        }
+    }
+
+    /// Forwards the request unmodified to `target` without waiting for its completion. If the
+    /// request can't be sent, it is completed with the failure status instead.
+    ///
+    /// This is meant for passing requests down to the device's
+    /// [default I/O target](super::device::Device::default_io_target).
+    pub fn forward(self, target: &IoTarget) {
+        self.format_using_current_type();
+
+        let options = RequestSendOptions::new(RequestSendFlags::SEND_AND_FORGET);
+        if let Err(e) = self.send(target, &options) {
+            self.complete(e.status());
+        }
+    }
+
+    /// Forwards the request to `queue` of the same device, e.g. a queue with manual dispatching
+    /// (see [`IoQueueConfig::manual`](super::io_queue::IoQueueConfig::manual)) to hold on to it.
+    ///
+    /// On success, the request belongs to the queue. On failure, the request is returned, and
+    /// still has to be completed by the caller.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/nf-wdfrequest-wdfrequestforwardtoioqueue
+    pub fn forward_to_io_queue(self, queue: &IoQueue) -> Result<(), (Self, NtStatusError)> {
+        // SAFETY: The request and queue are guaranteed to be valid.
+        let status =
+            unsafe { ffi::request_forward_to_io_queue(self.obj.as_wdf_ref(), queue.as_wdf_ref()) };
+
+        match status.result_for("WdfRequestForwardToIoQueue") {
+            Ok(_) => Ok(()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
     /// Completes the I/O request.
     ///
     /// This *must* be called at some point (to not have the caller be stuck forever), but not
@@ -221,6 +683,578 @@ impl Request {
         // SAFETY: `self.0` is guaranteed to be a valid pointer to a `WDFREQUEST`
         unsafe { ffi::request_complete(self.obj.as_wdf_ref(), status) }
     }
+
+    /// Defers the completion of the request, e.g. until a timer or work item finished the
+    /// operation the request is waiting for.
+    ///
+    /// The returned [`PendingRequest`] can be moved to other threads and stored in object
+    /// contexts. It has to be completed exactly once. If it's dropped instead, the request is
+    /// completed with `STATUS_CANCELLED`, so the caller isn't stuck forever.
+    pub fn mark_pending(self) -> PendingRequest {
+        PendingRequest(Some(self))
+    }
+}
+
+/// A request whose completion was deferred, see [`Request::mark_pending`].
+///
+/// The request isn't cancelable while it's pending, so it should be completed in a bounded time
+/// (or be watched by a [`Watchdog`](crate::watchdog::Watchdog)).
+///
+/// ```rs, ignore
+/// // in `EvtIoDeviceControl`
+/// let input = request.typed_input::<StartMeasurement>()?;
+/// sensors.start(input.channel);
+/// drop(input);
+/// context.pending.lock().replace(request.mark_pending());
+///
+/// // in the timer callback, once the measurement is done
+/// if let Some(pending) = context.pending.lock().take() {
+///     pending.complete(NtStatus::STATUS_SUCCESS);
+/// }
+/// ```
+pub struct PendingRequest(Option<Request>);
+
+impl PendingRequest {
+    /// The pending request, e.g. for accessing its buffers before completing it.
+    pub fn request(&self) -> &Request {
+        self.0
+            .as_ref()
+            .expect("request is only taken on completion")
+    }
+
+    /// Completes the request with `status`.
+    pub fn complete(mut self, status: NtStatus) {
+        self.take().complete(status);
+    }
+
+    /// Completes the request with `status`, after setting its information (e.g. the number of
+    /// bytes written to the output buffer).
+    pub fn complete_with_information(mut self, status: NtStatus, information: u64) {
+        let request = self.take();
+        request.set_information(information);
+        request.complete(status);
+    }
+
+    fn take(&mut self) -> Request {
+        self.0.take().expect("request is only taken on completion")
+    }
+}
+
+impl Drop for PendingRequest {
+    fn drop(&mut self) {
+        if let Some(request) = self.0.take() {
+            km_warn!("pending request dropped without being completed, cancelling it");
+            request.complete(NtStatusError::STATUS_CANCELLED.into());
+        }
+    }
+}
+
+/// The operations used to handle a [`Request`], so that handlers can be written generically and
+/// tested with a mock request:
+///
+/// ```rs, ignore
+/// fn read_sensor(request: impl RequestOps, sensors: &Sensors) {
+///     // SAFETY: The request is not shared.
+///     let result =
+///         unsafe { request.handle_ioctl(IOCTL_READ_SENSOR, |id, value| *value = sensors.read(*id)) };
+///     request.complete(result.map_or(STATUS_INVALID_PARAMETER, |_| STATUS_SUCCESS));
+/// }
+/// ```
+///
+/// [`Request`] implements the trait by calling its inherent methods of the same name.
+pub trait RequestOps: Sized {
+    type InputBuffer<'a>: Deref<Target = [u8]>
+    where
+        Self: 'a;
+    type OutputBuffer<'a>: DerefMut<Target = [u8]>
+    where
+        Self: 'a;
+
+    /// See [`Request::retrieve_input_buffer`].
+    fn retrieve_input_buffer(
+        &self,
+        minimum_required_length: usize,
+    ) -> Result<Self::InputBuffer<'_>, NtStatusError>;
+
+    /// See [`Request::retrieve_output_buffer`].
+    ///
+    /// # Safety
+    /// The same requirements as for [`Request::retrieve_output_buffer`] apply.
+    unsafe fn retrieve_output_buffer(
+        &self,
+        minimum_required_length: usize,
+    ) -> Result<Self::OutputBuffer<'_>, RetrieveOutputBufferError>;
+
+    /// See [`Request::set_information`].
+    fn set_information(&self, information: u64);
+
+    /// See [`Request::requestor_mode`].
+    fn requestor_mode(&self) -> ProcessorMode;
+
+    /// See [`Request::is_from_32_bit_process`]. Defaults to `false`, for mocks of requests from
+    /// 64-bit processes.
+    fn is_from_32_bit_process(&self) -> bool {
+        false
+    }
+
+    /// See [`Request::complete`].
+    fn complete(self, status: NtStatus);
+
+    /// Copies the input buffer into a `T`, checking its length, alignment and contents. Longer
+    /// buffers are accepted, with `T` being read from their start.
+    ///
+    /// Like [`Self::handle_ioctl_snapshot`], the copy is validated, so it can't change after it
+    /// was checked, and it can still be read after the output buffer was written, which is the
+    /// same system buffer for `METHOD_BUFFERED` codes:
+    ///
+    /// ```rs, ignore
+    /// let input = request.typed_input::<SetFanSpeed>()?;
+    /// ensure!(input.percent <= 100, InvalidSpeedSnafu);
+    /// // SAFETY: The request is not shared.
+    /// let mut output = unsafe { request.typed_output::<FanStatus>() }?;
+    /// *output = fans.set_speed(input.fan, input.percent)?;
+    /// request.set_information(size_of::<FanStatus>() as u64);
+    /// drop(output);
+    /// request.complete(STATUS_SUCCESS);
+    /// ```
+    fn typed_input<T: CheckedBitPattern + Validate>(&self) -> Result<T, IoCtlError> {
+        let input: T = {
+            let buffer = self.retrieve_input_buffer(size_of::<T>())?;
+            let bytes = buffer
+                .get(..size_of::<T>())
+                .ok_or(NtStatusError::STATUS_BUFFER_TOO_SMALL)?;
+
+            *bytemuck::checked::try_from_bytes(bytes).map_err(|e| {
+                CastSnafu {
+                    output_buffer: false,
+                    inner: e,
+                }
+                .build()
+            })?
+        };
+        input.validate()?;
+        Ok(input)
+    }
+
+    /// Retrieves the output buffer as a `T`, checking its length, alignment and contents. Longer
+    /// buffers are accepted, with `T` being written to their start.
+    ///
+    /// The information of the request isn't set, so the caller has to
+    /// [set it](Self::set_information) to the size of `T` before completing the request. See
+    /// [`Self::typed_input`] for an example.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::retrieve_output_buffer`] apply. Additionally, the
+    /// [`OutputBuffer`](Self::OutputBuffer) has to deref to the same memory each time, as it does
+    /// for [`Request`].
+    unsafe fn typed_output<T: NoUninit + CheckedBitPattern>(
+        &self,
+    ) -> Result<TypedBuffer<Self::OutputBuffer<'_>, T>, IoCtlError> {
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        let buffer =
+            unsafe { self.retrieve_output_buffer(size_of::<T>()) }.map_err(|e| match e {
+                RetrieveOutputBufferError::OutputBufferAlreadyBorrowed => {
+                    IoCtlError::OutputBufferAlreadyBorrowed
+                }
+                RetrieveOutputBufferError::NtStatus { source } => IoCtlError::NtStatus { source },
+            })?;
+
+        // SAFETY: The buffer derefs to the same memory each time, as promised by the caller, and
+        // isn't accessed otherwise while it's borrowed. Inputs are copied by `typed_input`, so
+        // there's no view of a `METHOD_BUFFERED` input sharing the memory.
+        unsafe { TypedBuffer::new(buffer) }.map_err(|e| {
+            CastSnafu {
+                output_buffer: true,
+                inner: e,
+            }
+            .build()
+        })
+    }
+
+    /// Retrieve typed buffers for an I/O control request and calls the provided closure to handle
+    /// the request.
+    ///
+    /// For `METHOD_BUFFERED` codes, the input and output are the same system buffer, so writing to
+    /// the output overwrites the input, and the input must not be read after the output was
+    /// written. [`Self::handle_ioctl_snapshot`] passes a copy of the input instead.
+    ///
+    /// # Safety
+    /// Since this function gives access to the output buffer, the same requirements as
+    /// [`Self::retrieve_output_buffer`] apply.
+    unsafe fn handle_ioctl<I, O, R>(
+        &self,
+        // just to get the types without needing to manually specify them
+        _ioctl: TypedIoControlCode<I, O>,
+        f: impl FnOnce(&I, &mut O) -> R,
+    ) -> Result<R, IoCtlError>
+    where
+        I: CheckedBitPattern + Validate,
+        O: NoUninit + CheckedBitPattern,
+    {
+        let input_buffer;
+        let input: &[u8] = if size_of::<I>() > 0 {
+            input_buffer = self.retrieve_input_buffer(size_of::<I>())?;
+            &input_buffer
+        } else {
+            &[]
+        };
+
+        let input: &I = bytemuck::checked::try_from_bytes(input).map_err(|e| {
+            CastSnafu {
+                output_buffer: false,
+                inner: e,
+            }
+            .build()
+        })?;
+        input.validate()?;
+
+        let mut output_buffer;
+        let output: &mut [u8] = if size_of::<O>() > 0 {
+            // SAFETY: The requirements for this are promised to be upheld by the caller.
+            output_buffer =
+                unsafe { self.retrieve_output_buffer(size_of::<O>()) }.map_err(|e| match e {
+                    RetrieveOutputBufferError::OutputBufferAlreadyBorrowed => {
+                        IoCtlError::OutputBufferAlreadyBorrowed
+                    }
+                    RetrieveOutputBufferError::NtStatus { source } => {
+                        IoCtlError::NtStatus { source }
+                    }
+                })?;
+            &mut output_buffer
+        } else {
+            &mut []
+        };
+
+        let output = bytemuck::checked::try_from_bytes_mut(output).map_err(|e| {
+            CastSnafu {
+                output_buffer: true,
+                inner: e,
+            }
+            .build()
+        })?;
+
+        let r = f(input, output);
+
+        if size_of::<O>() > 0 {
+            self.set_information(size_of::<O>() as u64);
+        }
+
+        Ok(r)
+    }
+
+    /// Like [`Self::handle_ioctl`], but copies the input onto the stack before the output buffer
+    /// is retrieved, so `f` can read the input after writing the output, even for
+    /// `METHOD_BUFFERED` codes, whose input and output share the same system buffer:
+    ///
+    /// ```rs, ignore
+    /// // SAFETY: The request is not shared.
+    /// let result = unsafe {
+    ///     request.handle_ioctl_snapshot(IOCTL_TRANSFORM, |input, output| {
+    ///         output.x = input.y;
+    ///         output.y = input.x; // would read the new `output.x` with `handle_ioctl`
+    ///     })
+    /// };
+    /// ```
+    ///
+    /// The copy is validated, so the input can't change after it was checked either.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Self::handle_ioctl`] apply.
+    unsafe fn handle_ioctl_snapshot<I, O, R>(
+        &self,
+        ioctl: TypedIoControlCode<I, O>,
+        f: impl FnOnce(&I, &mut O) -> R,
+    ) -> Result<R, IoCtlError>
+    where
+        I: CheckedBitPattern + Validate,
+        O: NoUninit + CheckedBitPattern,
+    {
+        let input: I = {
+            let input_buffer;
+            let input: &[u8] = if size_of::<I>() > 0 {
+                input_buffer = self.retrieve_input_buffer(size_of::<I>())?;
+                &input_buffer
+            } else {
+                &[]
+            };
+
+            *bytemuck::checked::try_from_bytes(input).map_err(|e| {
+                CastSnafu {
+                    output_buffer: false,
+                    inner: e,
+                }
+                .build()
+            })?
+        };
+        input.validate()?;
+
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe {
+            self.handle_ioctl(
+                TypedIoControlCode::<(), O>::new(ioctl.code),
+                |(), output| f(&input, output),
+            )
+        }
+    }
+
+    /// Copies the input buffer into the start of `scratch`, returning the copied part, so that
+    /// the input can still be read after the output buffer is written, e.g. for variable-length
+    /// inputs of `METHOD_BUFFERED` codes. See [`Self::handle_ioctl`].
+    ///
+    /// Fails with `STATUS_INVALID_BUFFER_SIZE` if the input is longer than `scratch`, and like
+    /// [`Self::retrieve_input_buffer`] if the request has no input.
+    fn snapshot_input<'s>(&self, scratch: &'s mut [u8]) -> Result<&'s [u8], NtStatusError> {
+        let input = self.retrieve_input_buffer(0)?;
+        let scratch = scratch
+            .get_mut(..input.len())
+            .ok_or(NtStatusError::STATUS_INVALID_BUFFER_SIZE)?;
+        scratch.copy_from_slice(&input);
+        Ok(scratch)
+    }
+}
+
+impl RequestOps for Request {
+    type InputBuffer<'a> = InputBuffer<'a>;
+    type OutputBuffer<'a> = OutputBuffer<'a>;
+
+    fn retrieve_input_buffer(
+        &self,
+        minimum_required_length: usize,
+    ) -> Result<InputBuffer<'_>, NtStatusError> {
+        Request::retrieve_input_buffer(self, minimum_required_length)
+    }
+
+    unsafe fn retrieve_output_buffer(
+        &self,
+        minimum_required_length: usize,
+    ) -> Result<OutputBuffer<'_>, RetrieveOutputBufferError> {
+        // SAFETY: The requirements for this are promised to be upheld by the caller.
+        unsafe { Request::retrieve_output_buffer(self, minimum_required_length) }
+    }
+
+    fn set_information(&self, information: u64) {
+        Request::set_information(self, information);
+    }
+
+    fn requestor_mode(&self) -> ProcessorMode {
+        Request::requestor_mode(self)
+    }
+
+    fn is_from_32_bit_process(&self) -> bool {
+        Request::is_from_32_bit_process(self)
+    }
+
+    fn complete(self, status: NtStatus) {
+        Request::complete(self, status);
+    }
+}
+
+pub type RequestType = WDF_REQUEST_TYPE;
+
+/// A completion routine for requests sent to an I/O target, see
+/// [`Request::set_completion_routine`].
+///
+/// This is FFI-compatible with [`km_sys::PFN_WDF_REQUEST_COMPLETION_ROUTINE`], with the context
+/// being typed as `C`.
+pub type EvtRequestCompletionRoutine<C> = unsafe extern "system" fn(
+    request: WdfObjectReference<'_, RawWdfRequest>,
+    target: WdfObjectReference<'_, RawWdfIoTarget>,
+    params: &RequestCompletionParams,
+    context: *mut C,
+);
+
+/// The completion parameters passed to an [`EvtRequestCompletionRoutine`].
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/ns-wdfrequest-_wdf_request_completion_params
+#[repr(transparent)]
+pub struct RequestCompletionParams(WDF_REQUEST_COMPLETION_PARAMS);
+
+impl RequestCompletionParams {
+    pub fn request_type(&self) -> RequestType {
+        self.0.Type
+    }
+
+    /// The status the request was completed with.
+    pub fn status(&self) -> NtStatus {
+        // SAFETY: The `Status` member is always the one set on completion.
+        NtStatus(unsafe { self.0.IoStatus.__bindgen_anon_1.Status })
+    }
+
+    /// The number of bytes transferred; for device control requests, this is the number of bytes
+    /// written to the output buffer.
+    pub fn information(&self) -> usize {
+        self.0.IoStatus.Information as usize
+    }
+
+    /// Parameters specific to completed device control requests.
+    pub fn ioctl(&self) -> Option<IoctlCompletionParams> {
+        if self.0.Type != RequestType::WdfRequestTypeDeviceControl
+            && self.0.Type != RequestType::WdfRequestTypeDeviceControlInternal
+        {
+            return None;
+        }
+
+        // SAFETY: The `Ioctl` member is the active one for device control requests.
+        let ioctl = unsafe { self.0.Parameters.Ioctl };
+
+        Some(IoctlCompletionParams {
+            io_control_code: IoControlCode(ioctl.IoControlCode),
+            output_length: ioctl.Output.Length,
+        })
+    }
+}
+
+/// See [`RequestCompletionParams::ioctl`].
+#[derive(Debug, Clone, Copy)]
+pub struct IoctlCompletionParams {
+    pub io_control_code: IoControlCode,
+    /// The number of bytes written to the output buffer.
+    pub output_length: usize,
+}
+
+/// The parameters of a request, see [`Request::parameters`].
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfrequest/ns-wdfrequest-_wdf_request_parameters
+#[repr(transparent)]
+pub struct RequestParameters(pub(crate) WDF_REQUEST_PARAMETERS);
+
+impl RequestParameters {
+    /// Empty parameters to be filled in by the framework, like `WDF_REQUEST_PARAMETERS_INIT`.
+    pub(crate) fn init() -> Self {
+        // SAFETY: The parameters are a plain C struct, for which all zeroes is a valid value.
+        let mut params: WDF_REQUEST_PARAMETERS = unsafe { zeroed() };
+        params.Size = size_of::<WDF_REQUEST_PARAMETERS>() as u16;
+        Self(params)
+    }
+
+    pub fn request_type(&self) -> RequestType {
+        self.0.Type
+    }
+
+    /// Parameters specific to device control requests.
+    pub fn device_io_control(&self) -> Option<DeviceIoControlParams> {
+        if self.0.Type != RequestType::WdfRequestTypeDeviceControl
+            && self.0.Type != RequestType::WdfRequestTypeDeviceControlInternal
+        {
+            return None;
+        }
+
+        // SAFETY: The `DeviceIoControl` member is the active one for device control requests.
+        let ioctl = unsafe { self.0.Parameters.DeviceIoControl };
+
+        Some(DeviceIoControlParams {
+            io_control_code: IoControlCode(ioctl.IoControlCode),
+            input_buffer_length: ioctl.InputBufferLength,
+            output_buffer_length: ioctl.OutputBufferLength,
+        })
+    }
+}
+
+/// See [`RequestParameters::device_io_control`].
+#[derive(Debug, Clone, Copy)]
+pub struct DeviceIoControlParams {
+    pub io_control_code: IoControlCode,
+    pub input_buffer_length: usize,
+    pub output_buffer_length: usize,
+}
+
+/// Marks a [`LockedUserBuffer`] that was locked for reading, i.e. the request's input buffer.
+pub enum ForRead {}
+impl Sealed for ForRead {}
+
+/// Marks a [`LockedUserBuffer`] that was locked for writing, i.e. the request's output buffer.
+pub enum ForWrite {}
+impl Sealed for ForWrite {}
+
+/// A user mode buffer of a `METHOD_NEITHER` request that was probed and locked, see
+/// [`Request::probe_and_lock_user_input_buffer`].
+///
+/// The buffer is mapped into system space, so it can be accessed from any thread; but the
+/// requesting process can still modify it concurrently. Hence it's only accessed by copying, and
+/// values read from it have to be validated after copying them.
+pub struct LockedUserBuffer<A: Sealed> {
+    _memory: OwnedWdfObject<RawWdfMemory>,
+    buffer: *mut u8,
+    len: usize,
+    _access: PhantomData<A>,
+}
+
+// SAFETY: The buffer is mapped into system space, and only accessed by copying with volatile
+// operations, which tolerate concurrent modifications.
+unsafe impl<A: Sealed> Send for LockedUserBuffer<A> {}
+// SAFETY: See above.
+unsafe impl<A: Sealed> Sync for LockedUserBuffer<A> {}
+
+impl<A: Sealed> LockedUserBuffer<A> {
+    /// # Safety
+    /// `memory` must be a valid memory object describing a locked user mode buffer.
+    unsafe fn new(memory: WDFMEMORY) -> Self {
+        let memory = OwnedWdfObject::from_new_raw(memory);
+        let mut len = 0;
+        // SAFETY: The caller guarantees that the memory object is valid.
+        let buffer = unsafe { ffi::memory_get_buffer(memory.as_wdf_ref(), &mut len) };
+
+        Self {
+            _memory: memory,
+            buffer: buffer.cast(),
+            len,
+            _access: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Copies `buf.len()` bytes starting at `offset` into `buf`.
+    pub fn read(&self, offset: usize, buf: &mut [u8]) -> Result<(), NtStatusError> {
+        let src = self.range(offset, buf.len())?;
+        for (i, byte) in buf.iter_mut().enumerate() {
+            // SAFETY: The range was checked to be inside of the buffer.
+            *byte = unsafe { src.add(i).read_volatile() };
+        }
+        Ok(())
+    }
+
+    /// Copies a `T` starting at `offset` out of the buffer.
+    pub fn read_value<T: Pod>(&self, offset: usize) -> Result<T, NtStatusError> {
+        let mut value = T::zeroed();
+        self.read(offset, bytemuck::bytes_of_mut(&mut value))?;
+        Ok(value)
+    }
+
+    fn range(&self, offset: usize, len: usize) -> Result<*mut u8, NtStatusError> {
+        match offset.checked_add(len) {
+            // SAFETY: The offset is inside of the buffer.
+            Some(end) if end <= self.len => Ok(unsafe { self.buffer.add(offset) }),
+            _ => Err(NtStatusError::STATUS_BUFFER_TOO_SMALL),
+        }
+    }
+}
+
+impl LockedUserBuffer<ForWrite> {
+    /// Copies `buf` into the buffer, starting at `offset`.
+    pub fn write(&self, offset: usize, buf: &[u8]) -> Result<(), NtStatusError> {
+        let dst = self.range(offset, buf.len())?;
+        for (i, byte) in buf.iter().enumerate() {
+            // SAFETY: The range was checked to be inside of the buffer.
+            unsafe { dst.add(i).write_volatile(*byte) };
+        }
+        Ok(())
+    }
+
+    /// Copies `value` into the buffer, starting at `offset`.
+    pub fn write_value<T: NoUninit>(&self, offset: usize, value: &T) -> Result<(), NtStatusError> {
+        self.write(offset, bytemuck::bytes_of(value))
+    }
 }
 
 /// An input buffer returned from [`Request::retrieve_input_buffer`].
@@ -286,6 +1320,56 @@ impl Drop for OutputBuffer<'_> {
     }
 }
 
+/// An output buffer viewed as a `T`, returned from [`Request::typed_output`].
+///
+/// The view borrows the buffer without copying it. `T` is written to (and read from) the start of
+/// the buffer, whose length, alignment and contents are checked once when the view is created.
+pub struct TypedBuffer<B, T> {
+    buffer: B,
+    value: NonNull<T>,
+}
+
+impl<B: DerefMut<Target = [u8]>, T: NoUninit + CheckedBitPattern> TypedBuffer<B, T> {
+    /// Views the start of `buffer` as a `T`.
+    ///
+    /// # Safety
+    /// `buffer` has to deref to the same memory each time, which mustn't be accessed other than
+    /// through the view while it exists.
+    pub unsafe fn new(mut buffer: B) -> Result<Self, CheckedCastError> {
+        let bytes = buffer
+            .get_mut(..size_of::<T>())
+            .ok_or(CheckedCastError::PodCastError(
+                bytemuck::PodCastError::SizeMismatch,
+            ))?;
+        let value = NonNull::from(bytemuck::checked::try_from_bytes_mut::<T>(bytes)?);
+
+        Ok(Self { buffer, value })
+    }
+
+    /// Returns the whole underlying buffer, e.g. to access trailing data after `T`.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B: DerefMut<Target = [u8]>, T: NoUninit + CheckedBitPattern> Deref for TypedBuffer<B, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The value was checked in `new`, and points into the buffer, which is only
+        // accessed through this view, as promised by the creator. Writes through `deref_mut`
+        // keep it a valid `T`.
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<B: DerefMut<Target = [u8]>, T: NoUninit + CheckedBitPattern> DerefMut for TypedBuffer<B, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: See `deref`. The view is borrowed mutably.
+        unsafe { self.value.as_mut() }
+    }
+}
+
 /// An error returned from [`Request::retrieve_output_buffer`].
 #[derive(Debug, Snafu)]
 #[snafu(module)]
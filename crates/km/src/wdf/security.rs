@@ -1,8 +1,247 @@
-use km_shared::{strings::make_const_unicode_string, wchz};
-use km_sys::UNICODE_STRING;
-
-// from wdmsec.h - copied over instead of referencing the extern static to allow referencing it in
-// safe context
-pub const SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_RW_RES_R: UNICODE_STRING = make_const_unicode_string(
-    wchz!("D:P(A;;GA;;;SY)(A;;GRGWGX;;;BA)(A;;GRGW;;;WD)(A;;GR;;;RC)"),
-);
+//! Security descriptors for device objects, in the [SDDL] subset supported by
+//! `IoCreateDeviceSecure`.
+//!
+//! Instead of copy-pasting SDDL strings, descriptors are assembled from typed ACEs with
+//! [`SddlBuilder`], which is evaluated at compile time:
+//!
+//! ```rs, ignore
+//! const MY_SDDL: UnicodeString = SddlBuilder::new()
+//!     .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+//!     .allow(WellKnownSid::BuiltinAdministrators, GenericAccess::RWX)
+//!     .unicode_string();
+//! ```
+//!
+//! [SDDL]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/sddl-for-device-objects
+
+use bitflags::bitflags;
+use core::mem::size_of;
+use km_shared::strings::UnicodeString;
+use km_sys::{GENERIC_ALL, GENERIC_EXECUTE, GENERIC_READ, GENERIC_WRITE, UNICODE_STRING, WCHAR};
+
+/// A well-known SID that can be used in device object SDDL strings.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/sddl-for-device-objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownSid {
+    /// `SY`, the operating system itself, including kernel mode drivers.
+    LocalSystem,
+    /// `BA`, the local administrators group.
+    BuiltinAdministrators,
+    /// `BU`, the local users group.
+    BuiltinUsers,
+    /// `AU`, all users that logged on with credentials.
+    AuthenticatedUsers,
+    /// `WD`, everyone, including anonymous users.
+    World,
+    /// `RC`, restricted code, e.g. processes with a restricted token.
+    RestrictedCode,
+    /// `IU`, users that logged on interactively.
+    Interactive,
+    /// `LS`, the local service account.
+    LocalService,
+    /// `NS`, the network service account.
+    NetworkService,
+}
+
+impl WellKnownSid {
+    /// The SID's abbreviation in SDDL strings.
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            WellKnownSid::LocalSystem => "SY",
+            WellKnownSid::BuiltinAdministrators => "BA",
+            WellKnownSid::BuiltinUsers => "BU",
+            WellKnownSid::AuthenticatedUsers => "AU",
+            WellKnownSid::World => "WD",
+            WellKnownSid::RestrictedCode => "RC",
+            WellKnownSid::Interactive => "IU",
+            WellKnownSid::LocalService => "LS",
+            WellKnownSid::NetworkService => "NS",
+        }
+    }
+}
+
+bitflags! {
+    /// Generic access rights granted by an ACE, which are mapped to the device's specific rights.
+    ///
+    /// See [MSDN] for more details.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/access-mask
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct GenericAccess: u32 {
+        /// `GA`
+        const ALL = GENERIC_ALL;
+        /// `GR`
+        const READ = GENERIC_READ;
+        /// `GW`
+        const WRITE = GENERIC_WRITE;
+        /// `GX`
+        const EXECUTE = GENERIC_EXECUTE;
+    }
+}
+
+impl GenericAccess {
+    /// `GRGWGX`, which is how `wdmsec.h` spells out everything but `GA`.
+    pub const RWX: Self = Self::READ.union(Self::WRITE).union(Self::EXECUTE);
+
+    const TOKENS: [(Self, &'static str); 4] = [
+        (Self::ALL, "GA"),
+        (Self::READ, "GR"),
+        (Self::WRITE, "GW"),
+        (Self::EXECUTE, "GX"),
+    ];
+}
+
+/// Assembles a protected DACL (`D:P`) for a device object from ACEs, see the
+/// [module documentation](self).
+///
+/// Without any ACEs, only the kernel can access the device.
+#[derive(Clone, Copy)]
+pub struct SddlBuilder {
+    /// NUL-terminated, like the strings in wdmsec.h.
+    buf: [WCHAR; SddlBuilder::CAPACITY + 1],
+    len: usize,
+}
+
+impl Default for SddlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SddlBuilder {
+    /// The maximum length of an SDDL string, in characters.
+    pub const CAPACITY: usize = 127;
+
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; Self::CAPACITY + 1],
+            len: 0,
+        }
+        .push("D:P")
+    }
+
+    /// Adds an ACE allowing `access` to `sid`.
+    pub const fn allow(self, sid: WellKnownSid, access: GenericAccess) -> Self {
+        self.ace("A", sid, access)
+    }
+
+    /// Adds an ACE denying `access` to `sid`.
+    pub const fn deny(self, sid: WellKnownSid, access: GenericAccess) -> Self {
+        self.ace("D", sid, access)
+    }
+
+    const fn ace(self, ty: &str, sid: WellKnownSid, access: GenericAccess) -> Self {
+        if access.is_empty() {
+            panic!("ACEs must grant or deny some access");
+        }
+
+        let mut this = self.push("(").push(ty).push(";;");
+        let mut i = 0;
+        while i < GenericAccess::TOKENS.len() {
+            let (flag, token) = GenericAccess::TOKENS[i];
+            if access.contains(flag) {
+                this = this.push(token);
+            }
+            i += 1;
+        }
+        this.push(";;;").push(sid.abbreviation()).push(")")
+    }
+
+    const fn push(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        if self.len + bytes.len() > Self::CAPACITY {
+            panic!("SDDL string exceeds `SddlBuilder::CAPACITY`");
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buf[self.len] = bytes[i] as WCHAR;
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// The SDDL string, without the terminating NUL.
+    pub const fn as_wide(&self) -> &[WCHAR] {
+        self.buf.split_at(self.len).0
+    }
+
+    /// Creates a [`UnicodeString`] referencing the SDDL string, e.g. for
+    /// [`Driver::allocate_control_device_init`].
+    ///
+    /// [`Driver::allocate_control_device_init`]: super::driver::Driver::allocate_control_device_init
+    ///
+    /// This is usually called in a `const` initializer, which makes the builder `'static`.
+    pub const fn unicode_string(&'static self) -> UnicodeString {
+        UNICODE_STRING {
+            Length: (self.len * size_of::<WCHAR>()) as u16,
+            MaximumLength: ((self.len + 1) * size_of::<WCHAR>()) as u16,
+            Buffer: self.buf.as_ptr().cast_mut(),
+        }
+    }
+}
+
+// The predefined SDDL strings from wdmsec.h - built here instead of referencing the extern statics
+// to allow referencing them in safe context.
+
+/// `D:P`: only the kernel can access the device.
+pub const SDDL_DEVOBJ_KERNEL_ONLY: UNICODE_STRING = SddlBuilder::new().unicode_string();
+
+/// `D:P(A;;GA;;;SY)`
+pub const SDDL_DEVOBJ_SYS_ALL: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .unicode_string();
+
+/// `D:P(A;;GA;;;SY)(A;;GA;;;BA)`
+pub const SDDL_DEVOBJ_SYS_ALL_ADM_ALL: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .allow(WellKnownSid::BuiltinAdministrators, GenericAccess::ALL)
+    .unicode_string();
+
+/// `D:P(A;;GA;;;SY)(A;;GRGX;;;BA)`
+pub const SDDL_DEVOBJ_SYS_ALL_ADM_RX: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .allow(
+        WellKnownSid::BuiltinAdministrators,
+        GenericAccess::READ.union(GenericAccess::EXECUTE),
+    )
+    .unicode_string();
+
+/// `D:P(A;;GA;;;SY)(A;;GRGWGX;;;BA)(A;;GR;;;WD)`
+pub const SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_R: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .allow(WellKnownSid::BuiltinAdministrators, GenericAccess::RWX)
+    .allow(WellKnownSid::World, GenericAccess::READ)
+    .unicode_string();
+
+/// `D:P(A;;GA;;;SY)(A;;GRGWGX;;;BA)(A;;GRGW;;;WD)`
+pub const SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_RW: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .allow(WellKnownSid::BuiltinAdministrators, GenericAccess::RWX)
+    .allow(
+        WellKnownSid::World,
+        GenericAccess::READ.union(GenericAccess::WRITE),
+    )
+    .unicode_string();
+
+/// `D:P(A;;GA;;;SY)(A;;GRGWGX;;;BA)(A;;GRGW;;;WD)(A;;GR;;;RC)`
+pub const SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_RW_RES_R: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .allow(WellKnownSid::BuiltinAdministrators, GenericAccess::RWX)
+    .allow(
+        WellKnownSid::World,
+        GenericAccess::READ.union(GenericAccess::WRITE),
+    )
+    .allow(WellKnownSid::RestrictedCode, GenericAccess::READ)
+    .unicode_string();
+
+/// `D:P(A;;GA;;;SY)(A;;GRGWGX;;;BA)(A;;GRGWGX;;;WD)(A;;GRGWGX;;;RC)`
+pub const SDDL_DEVOBJ_SYS_ALL_ADM_RWX_WORLD_RWX_RES_RWX: UNICODE_STRING = SddlBuilder::new()
+    .allow(WellKnownSid::LocalSystem, GenericAccess::ALL)
+    .allow(WellKnownSid::BuiltinAdministrators, GenericAccess::RWX)
+    .allow(WellKnownSid::World, GenericAccess::RWX)
+    .allow(WellKnownSid::RestrictedCode, GenericAccess::RWX)
+    .unicode_string();
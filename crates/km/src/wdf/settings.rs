@@ -0,0 +1,441 @@
+//! Settings that can be changed at runtime, by writing values of the driver's `Parameters` key.
+//!
+//! Unlike a configuration declared with [`declare_config!`](crate::declare_config!), which is
+//! loaded once, a [`Setting`] is read again whenever a value of the key is written. A
+//! [`SettingsWatcher`] reads its settings when it's started, and watches the key until it's
+//! stopped. Readers either get the current value, or subscribe to its changes:
+//!
+//! ```rs, ignore
+//! static LOG_LEVEL: Setting<u32> =
+//!     Setting::new(unicode_string!("LogLevel"), 3).valid(|level| *level <= 5);
+//! static POLL_INTERVAL_MS: Setting<u32> = Setting::new(unicode_string!("PollIntervalMs"), 100);
+//! static SETTINGS: SettingsWatcher<2> = SettingsWatcher::new([&LOG_LEVEL, &POLL_INTERVAL_MS]);
+//!
+//! // in `DriverEntry`, once the driver is created
+//! LOG_LEVEL.on_change(|level| log::set_max_level(level_filter(level))).ok();
+//! SETTINGS.start(&driver)?;
+//!
+//! // in the poll callback
+//! if let Some(ms) = ctx.poll_interval.changed() {
+//!     poller.set_period(Duration::from_millis(ms.into()));
+//! }
+//!
+//! // in the unload routine
+//! SETTINGS.stop();
+//! km::unload::wait_for_quiescence();
+//! ```
+//!
+//! Written values that can't be read as their setting, or fail its validation, are logged and
+//! ignored, so the setting keeps its previous value. Deleted values revert to their default.
+
+use super::{
+    driver::Driver, ffi, registry::RegistryKey, registry::RegistryValue, wait_lock::WaitLock,
+    AsWdfReference,
+};
+use crate::{
+    km_warn,
+    sync::OnceCell,
+    unload::{self, RundownGuard},
+    verify, Sealed,
+};
+use core::{
+    cell::UnsafeCell,
+    ffi::c_void,
+    fmt,
+    mem::{transmute, zeroed},
+    ptr::null_mut,
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+};
+use km_shared::{
+    ntstatus::{NtStatus, NtStatusError},
+    strings::UnicodeString,
+};
+use km_sys::{
+    ZwNotifyChangeKey, IO_STATUS_BLOCK, PIO_APC_ROUTINE, PWORK_QUEUE_ITEM,
+    REG_NOTIFY_CHANGE_LAST_SET, WORK_QUEUE_ITEM, WORK_QUEUE_TYPE,
+};
+
+/// A type that a [`Setting`] can have, i.e. `u32` or `bool`, which can be read and written
+/// atomically.
+pub trait SettingValue: RegistryValue + Copy + Send + Sync + Sealed + 'static {
+    #[doc(hidden)]
+    fn to_bits(self) -> u32;
+    #[doc(hidden)]
+    fn from_bits(bits: u32) -> Self;
+}
+
+impl Sealed for u32 {}
+impl SettingValue for u32 {
+    fn to_bits(self) -> u32 {
+        self
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        bits
+    }
+}
+
+impl Sealed for bool {}
+impl SettingValue for bool {
+    fn to_bits(self) -> u32 {
+        self.into()
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        bits != 0
+    }
+}
+
+/// A value of the driver's `Parameters` key that's updated while the driver runs, see the
+/// [module documentation](self).
+///
+/// Settings are meant to be `static`s, which are read by a [`SettingsWatcher`].
+pub struct Setting<T: SettingValue> {
+    name: UnicodeString,
+    default: T,
+    valid: Option<fn(&T) -> bool>,
+    value: AtomicU32,
+    /// Incremented whenever `value` changes, and 0 until it's read for the first time.
+    generation: AtomicU64,
+    on_change: OnceCell<fn(T)>,
+}
+
+// SAFETY: The name is only ever read, and the rest is synchronized.
+unsafe impl<T: SettingValue> Sync for Setting<T> {}
+
+impl<T: SettingValue> Setting<T> {
+    /// Creates a setting read from value `name`, which is `default` while the value is missing.
+    pub const fn new(name: UnicodeString, default: T) -> Self {
+        Self {
+            name,
+            default,
+            valid: None,
+            value: AtomicU32::new(0),
+            generation: AtomicU64::new(0),
+            on_change: OnceCell::new(),
+        }
+    }
+
+    /// Only accepts values for which `valid` returns `true`. Others are ignored, so the setting
+    /// keeps its previous value. The default has to be valid.
+    pub const fn valid(mut self, valid: fn(&T) -> bool) -> Self {
+        self.valid = Some(valid);
+        self
+    }
+
+    /// The current value, or the default until the setting is read.
+    ///
+    /// Can be called at any IRQL.
+    pub fn get(&self) -> T {
+        if self.generation.load(Ordering::Acquire) == 0 {
+            return self.default;
+        }
+
+        T::from_bits(self.value.load(Ordering::Relaxed))
+    }
+
+    /// Subscribes to changes of the setting, which are reported by [`Subscription::changed`] from
+    /// now on.
+    ///
+    /// Can be called at any IRQL.
+    pub fn subscribe(&self) -> Subscription<'_, T> {
+        Subscription {
+            setting: self,
+            generation: self.generation.load(Ordering::Acquire),
+        }
+    }
+
+    /// Calls `callback` with the new value whenever the setting changes, but not when it's read
+    /// for the first time. Only one callback can be set, so this fails with `callback` if there
+    /// already is one.
+    ///
+    /// The callback runs at `PASSIVE_LEVEL` on a system worker thread, while the watcher is
+    /// locked, so it mustn't [stop](SettingsWatcher::stop) the watcher.
+    pub fn on_change(&self, callback: fn(T)) -> Result<(), fn(T)> {
+        self.on_change.set(callback)
+    }
+
+    /// Reads the setting from `key`, or reverts it to its default without a key.
+    fn read(&self, key: Option<&RegistryKey>) {
+        let value = match key.map(|key| T::query(key, &self.name, 0)) {
+            None | Some(Ok(None)) => self.default,
+            Some(Ok(Some(value))) if self.valid.is_none_or(|valid| valid(&value)) => value,
+            Some(Ok(Some(_))) => {
+                km_warn!("setting {} is invalid, ignoring it", Name(&self.name));
+                return;
+            }
+            Some(Err(e)) => {
+                km_warn!(
+                    "reading setting {} failed, ignoring it: {e}",
+                    Name(&self.name)
+                );
+                return;
+            }
+        };
+
+        let generation = self.generation.load(Ordering::Relaxed);
+        if generation != 0 && self.value.load(Ordering::Relaxed) == value.to_bits() {
+            return;
+        }
+
+        self.value.store(value.to_bits(), Ordering::Relaxed);
+        self.generation.store(generation + 1, Ordering::Release);
+
+        if generation != 0 {
+            if let Some(callback) = self.on_change.get() {
+                callback(value);
+            }
+        }
+    }
+}
+
+/// A setting of a [`SettingsWatcher`], i.e. any [`Setting`].
+pub trait AnySetting: Sealed + Sync {
+    #[doc(hidden)]
+    fn read(&self, key: Option<&RegistryKey>);
+}
+
+impl<T: SettingValue> Sealed for Setting<T> {}
+impl<T: SettingValue> AnySetting for Setting<T> {
+    fn read(&self, key: Option<&RegistryKey>) {
+        Setting::read(self, key);
+    }
+}
+
+/// The changes of a [`Setting`] since they were last checked, returned from
+/// [`Setting::subscribe`].
+pub struct Subscription<'a, T: SettingValue> {
+    setting: &'a Setting<T>,
+    generation: u64,
+}
+
+impl<T: SettingValue> Subscription<'_, T> {
+    /// Returns the current value if the setting changed since the last call, or since the
+    /// subscription was created. Multiple changes in between are only reported once.
+    ///
+    /// Can be called at any IRQL.
+    pub fn changed(&mut self) -> Option<T> {
+        let generation = self.setting.generation.load(Ordering::Acquire);
+        if generation == self.generation {
+            return None;
+        }
+
+        self.generation = generation;
+        Some(self.setting.get())
+    }
+}
+
+/// Formats the name of a setting for logging.
+struct Name<'a>(&'a UnicodeString);
+
+impl fmt::Display for Name<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wide: &[u16] = if self.0.Buffer.is_null() {
+            &[]
+        } else {
+            // SAFETY: The names of settings are constant strings, whose buffer holds `Length`
+            // bytes.
+            unsafe { core::slice::from_raw_parts(self.0.Buffer, usize::from(self.0.Length) / 2) }
+        };
+
+        for c in char::decode_utf16(wide.iter().copied()) {
+            fmt::Write::write_char(f, c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+/// Watches the driver's `Parameters` key for changes of up to `N` [`Setting`]s, see the
+/// [module documentation](self).
+///
+/// Watchers are meant to be `static`s, as the pending notification refers to them.
+pub struct SettingsWatcher<const N: usize> {
+    settings: [&'static dyn AnySetting; N],
+    state: OnceCell<WatchState>,
+}
+
+struct WatchState {
+    lock: WaitLock,
+    /// Only accessed while `lock` is held.
+    inner: UnsafeCell<WatchInner>,
+    /// Owned by the kernel while a notification is pending, and by the worker routine otherwise.
+    work_item: UnsafeCell<WORK_QUEUE_ITEM>,
+    /// See above.
+    io_status: UnsafeCell<IO_STATUS_BLOCK>,
+}
+
+struct WatchInner {
+    /// The watched key, whose closing cancels the pending notification.
+    key: Option<RegistryKey>,
+    /// Held while a notification is pending, or its worker routine is queued.
+    guard: Option<RundownGuard<'static>>,
+    stopped: bool,
+}
+
+// SAFETY: `inner` is only accessed while `lock` is held, and the work item and I/O status block
+// only by the kernel while a notification is pending, and by the worker routine otherwise, which
+// is serialized with arming the notification by `lock`.
+unsafe impl Send for WatchState {}
+// SAFETY: See above.
+unsafe impl Sync for WatchState {}
+
+impl<const N: usize> SettingsWatcher<N> {
+    pub const fn new(settings: [&'static dyn AnySetting; N]) -> Self {
+        Self {
+            settings,
+            state: OnceCell::new(),
+        }
+    }
+
+    /// Reads all settings from the driver's `Parameters` key, and starts watching it for changes.
+    ///
+    /// Without a `Parameters` key, the settings keep their defaults, and nothing is watched.
+    /// Fails with `STATUS_ALREADY_INITIALIZED` if the watcher was started before, and with
+    /// `STATUS_DELETE_PENDING` if the driver is already [unloading](crate::unload).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn start(&'static self, driver: &Driver) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        if self.state.get().is_some() {
+            return Err(NtStatusError::STATUS_ALREADY_INITIALIZED);
+        }
+
+        let key = driver.open_parameters_key()?;
+        for setting in self.settings {
+            setting.read(key.as_ref());
+        }
+        let Some(key) = key else {
+            km_warn!("no parameters key, settings keep their defaults");
+            return Ok(());
+        };
+
+        let guard = unload::acquire().ok_or(NtStatusError::STATUS_DELETE_PENDING)?;
+        let state = WatchState {
+            lock: WaitLock::create(None)?,
+            inner: UnsafeCell::new(WatchInner {
+                key: Some(key),
+                guard: Some(guard),
+                stopped: false,
+            }),
+            // SAFETY: Both are plain data, for which all zeroes are valid.
+            work_item: UnsafeCell::new(unsafe { zeroed() }),
+            // SAFETY: See above.
+            io_status: UnsafeCell::new(unsafe { zeroed() }),
+        };
+        if self.state.set(state).is_err() {
+            return Err(NtStatusError::STATUS_ALREADY_INITIALIZED);
+        }
+
+        let state = self.state.get().expect("the state was just set");
+        let _lock = state.lock.acquire();
+        // SAFETY: The lock is held.
+        let inner = unsafe { &mut *state.inner.get() };
+        let result = self.arm(state, inner);
+        if result.is_err() {
+            inner.key = None;
+            inner.guard = None;
+            inner.stopped = true;
+        }
+        result
+    }
+
+    /// Stops watching for changes. The settings keep their current values.
+    ///
+    /// Call this from the driver's unload routine, before
+    /// [`wait_for_quiescence`](crate::unload::wait_for_quiescence), which waits for the
+    /// notification to be cancelled.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn stop(&self) {
+        verify::at_passive_level();
+
+        let Some(state) = self.state.get() else {
+            return;
+        };
+
+        let _lock = state.lock.acquire();
+        // SAFETY: The lock is held.
+        let inner = unsafe { &mut *state.inner.get() };
+        // Closing the key cancels the pending notification, whose worker routine then releases
+        // the guard.
+        inner.stopped = true;
+        inner.key = None;
+    }
+
+    /// Requests a notification when a value of the watched key is written, which queues
+    /// [`Self::worker`].
+    ///
+    /// Must be called while `state.lock` is held, and no notification is pending.
+    fn arm(&'static self, state: &WatchState, inner: &WatchInner) -> Result<(), NtStatusError> {
+        let key = inner.key.as_ref().expect("the key is open until stopped");
+        let work_item = state.work_item.get();
+
+        // SAFETY: The work item isn't queued, as no notification is pending
+        // (`ExInitializeWorkItem`).
+        unsafe {
+            work_item.write(WORK_QUEUE_ITEM {
+                List: zeroed(),
+                WorkerRoutine: Some(Self::worker),
+                Parameter: (self as *const Self).cast_mut().cast(),
+            })
+        };
+
+        // SAFETY: The key is open, and the work item and I/O status block stay valid, as the
+        // watcher is `'static`. Kernel-mode callers pass the work item to queue instead of an APC
+        // routine, and the work queue instead of its context.
+        NtStatus::from(unsafe {
+            ZwNotifyChangeKey(
+                ffi::registry_wdm_get_handle(key.as_wdf_ref()),
+                null_mut(),
+                transmute::<PWORK_QUEUE_ITEM, PIO_APC_ROUTINE>(work_item),
+                WORK_QUEUE_TYPE::DelayedWorkQueue.0 as usize as *mut c_void,
+                state.io_status.get(),
+                REG_NOTIFY_CHANGE_LAST_SET,
+                false.into(),
+                null_mut(),
+                0,
+                true.into(),
+            )
+        })
+        .result_for("ZwNotifyChangeKey")?;
+
+        Ok(())
+    }
+
+    /// Reads the settings again, and waits for the next change, after a value was written or the
+    /// notification was cancelled.
+    unsafe extern "system" fn worker(parameter: *mut c_void) {
+        // SAFETY: The parameter is the watcher that armed the notification, which is `'static`.
+        let this = unsafe { &*parameter.cast::<Self>() };
+        let state = this.state.get().expect("the state is set before arming");
+
+        let lock = state.lock.acquire();
+        // SAFETY: The lock is held.
+        let inner = unsafe { &mut *state.inner.get() };
+
+        if !inner.stopped {
+            for setting in this.settings {
+                setting.read(inner.key.as_ref());
+            }
+
+            if let Err(e) = this.arm(state, inner) {
+                km_warn!("watching the settings failed, they won't change anymore: {e}");
+                inner.stopped = true;
+                inner.key = None;
+            }
+        }
+
+        // Nothing is pending anymore, so release the guard, last, as the driver may unload as soon
+        // as it's released.
+        let guard = if inner.stopped {
+            inner.guard.take()
+        } else {
+            None
+        };
+        drop(lock);
+        drop(guard);
+    }
+}
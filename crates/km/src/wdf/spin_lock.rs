@@ -0,0 +1,84 @@
+use super::{
+    ffi, object_attributes::ObjectAttributes, AsWdfReference, OwnedWdfObject, RawWdfSpinLock,
+    WdfObjectReference,
+};
+use crate::{verify, AsRawMutPtr, Sealed};
+use core::{marker::PhantomData, ptr::null_mut};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{WDFSPINLOCK, WDF_OBJECT_ATTRIBUTES};
+
+/// A framework spin lock object, raising the IRQL to `DISPATCH_LEVEL` while it is held.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/synchronization-techniques-for-wdf-drivers
+#[derive(Debug, Clone)]
+pub struct SpinLock(OwnedWdfObject<RawWdfSpinLock>);
+impl Sealed for SpinLock {}
+
+impl AsWdfReference for SpinLock {
+    type ObjectType = RawWdfSpinLock;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl SpinLock {
+    /// Creates a new spin lock. Without a parent set in `attributes`, the lock belongs to the
+    /// driver object.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfsync/nf-wdfsync-wdfspinlockcreate
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn create(mut attributes: Option<&mut ObjectAttributes>) -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let mut lock: WDFSPINLOCK = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe {
+            ffi::spin_lock_create(
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                &mut lock,
+            )
+        }
+        .result_for("WdfSpinLockCreate")?;
+
+        debug_assert!(!lock.is_null());
+
+        Ok(Self(OwnedWdfObject::from_new_raw(lock)))
+    }
+
+    /// Acquires the lock, which is released when the returned guard is dropped.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`, and the lock must not already be held by the
+    /// current thread, or it deadlocks.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn acquire(&self) -> SpinLockGuard<'_> {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The lock is guaranteed to be valid.
+        unsafe { ffi::spin_lock_acquire(self.0.as_wdf_ref()) };
+
+        SpinLockGuard(self, PhantomData)
+    }
+}
+
+/// A held [`SpinLock`], released on drop.
+#[must_use = "the lock is released immediately if the guard is dropped"]
+pub struct SpinLockGuard<'a>(
+    &'a SpinLock,
+    /// Not `Send`, as the lock has to be released on the thread that acquired it.
+    PhantomData<*const ()>,
+);
+
+impl Drop for SpinLockGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: The lock is valid, and held by this guard.
+        unsafe { ffi::spin_lock_release(self.0 .0.as_wdf_ref()) }
+    }
+}
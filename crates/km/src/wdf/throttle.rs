@@ -0,0 +1,247 @@
+//! Per-handle throttling of requests, so that a misbehaving client can't keep the driver busy.
+//!
+//! A [`HandleThrottle`] is kept in the context of each file object, i.e. each handle a client
+//! opened, and [`admit`] checks a request against a [`ThrottlePolicy`] before it's handled,
+//! bounding both the rate of requests and the number of requests that are outstanding at once:
+//!
+//! ```rs, ignore
+//! struct Client {
+//!     throttle: HandleThrottle,
+//!     // ...
+//! }
+//!
+//! impl AsRef<HandleThrottle> for Client {
+//!     fn as_ref(&self) -> &HandleThrottle {
+//!         &self.throttle
+//!     }
+//! }
+//!
+//! declare_wdf_object_context_type! {
+//!     static CLIENT => Client;
+//! }
+//!
+//! // 200 requests per second, in bursts of up to 50, with at most 4 in flight
+//! const THROTTLE: ThrottlePolicy = ThrottlePolicy::new()
+//!     .rate(200, Duration::from_secs(1))
+//!     .burst(50)
+//!     .max_outstanding(4);
+//!
+//! // in `EvtIoDeviceControl`
+//! let admission = match throttle::admit(&request, &CLIENT, &THROTTLE) {
+//!     Ok(admission) => admission,
+//!     Err(e) => return request.complete(e.nt_status().into()),
+//! };
+//! // the request counts as outstanding until `admission` is dropped, e.g. after completing it
+//! ```
+//!
+//! Requests without a file object, or whose file object has no initialized context, e.g. requests
+//! sent by other drivers, aren't throttled.
+
+use super::{
+    context::{ContextHandle, WdfObjectContextTypeInfo},
+    request::Request,
+    RawWdfFileObject,
+};
+use crate::time::Instant;
+use core::{
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+    time::Duration,
+};
+use km_shared::ntstatus::NtStatusError;
+use snafu::Snafu;
+
+/// Why a request was rejected by a [`ThrottlePolicy`].
+#[derive(Debug, Snafu, Clone, Copy, PartialEq, Eq)]
+#[snafu(module)]
+pub enum Throttled {
+    /// The handle sent requests faster than the policy's rate allows, beyond its burst.
+    #[snafu(display("request rate exceeded"))]
+    RateExceeded,
+    /// The handle already has the most outstanding requests the policy allows.
+    #[snafu(display("too many outstanding requests"))]
+    TooManyOutstanding,
+}
+
+impl Throttled {
+    /// The status to complete the rejected request with, `STATUS_QUOTA_EXCEEDED`.
+    pub fn nt_status(&self) -> NtStatusError {
+        NtStatusError::STATUS_QUOTA_EXCEEDED
+    }
+}
+
+/// Bounds on the requests of a handle. See the [module documentation](self).
+///
+/// A policy is usually declared as a constant, and doesn't throttle anything until bounds are
+/// added.
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottlePolicy {
+    /// `count` requests per `interval`.
+    rate: Option<(u32, Duration)>,
+    burst: Option<u32>,
+    max_outstanding: Option<u32>,
+}
+
+impl ThrottlePolicy {
+    /// A policy without any bounds.
+    pub const fn new() -> Self {
+        Self {
+            rate: None,
+            burst: None,
+            max_outstanding: None,
+        }
+    }
+
+    /// Allows `count` requests per `interval` on average. A `count` of 0 rejects all requests.
+    pub const fn rate(mut self, count: u32, interval: Duration) -> Self {
+        self.rate = Some((count, interval));
+        self
+    }
+
+    /// Allows up to `burst` requests in quick succession, as long as the average stays within the
+    /// [rate](Self::rate). Defaults to the count of the rate, i.e. a whole interval's worth.
+    pub const fn burst(mut self, burst: u32) -> Self {
+        self.burst = Some(burst);
+        self
+    }
+
+    /// Allows at most `max` requests of a handle to be outstanding at once, i.e. admitted but not
+    /// yet done.
+    pub const fn max_outstanding(mut self, max: u32) -> Self {
+        self.max_outstanding = Some(max);
+        self
+    }
+
+    /// The performance counter ticks between two requests at the policy's rate, and the ticks a
+    /// request may come early by, or `None` if the rate is unbounded.
+    fn rate_ticks(&self) -> Option<(u64, u64)> {
+        let (count, interval) = self.rate?;
+        if count == 0 {
+            return Some((u64::MAX, 0));
+        }
+
+        let interval_ticks =
+            interval.as_nanos() * Instant::frequency() as u128 / Duration::from_secs(1).as_nanos();
+        let emission = u64::try_from(interval_ticks / count as u128)
+            .unwrap_or(u64::MAX)
+            .max(1);
+        let tolerance =
+            emission.saturating_mul(self.burst.unwrap_or(count).saturating_sub(1) as u64);
+        Some((emission, tolerance))
+    }
+}
+
+impl Default for ThrottlePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The throttling state of a handle, kept in the context of its file object. See the
+/// [module documentation](self).
+#[derive(Debug, Default)]
+pub struct HandleThrottle {
+    /// The earliest performance counter tick at which a request conforms to the rate, in the
+    /// sense of the generic cell rate algorithm, i.e. a token bucket in a single atomic.
+    theoretical_arrival: AtomicU64,
+    outstanding: AtomicU32,
+}
+
+impl HandleThrottle {
+    pub const fn new() -> Self {
+        Self {
+            theoretical_arrival: AtomicU64::new(0),
+            outstanding: AtomicU32::new(0),
+        }
+    }
+
+    /// The number of admitted requests that aren't done yet.
+    pub fn outstanding(&self) -> u32 {
+        self.outstanding.load(Ordering::Relaxed)
+    }
+
+    /// Checks a request against `policy`, counting it as outstanding until
+    /// [`Self::release`] is called if it's admitted.
+    ///
+    /// Usually, [`admit`] is used instead, which releases the request automatically.
+    ///
+    /// Can be called at any IRQL.
+    pub fn try_acquire(&self, policy: &ThrottlePolicy) -> Result<(), Throttled> {
+        if let Some(max) = policy.max_outstanding {
+            self.outstanding
+                .fetch_update(Ordering::Acquire, Ordering::Relaxed, |n| {
+                    (n < max).then_some(n + 1)
+                })
+                .map_err(|_| Throttled::TooManyOutstanding)?;
+        } else {
+            self.outstanding.fetch_add(1, Ordering::Acquire);
+        }
+
+        if let Err(e) = self.consume_rate(policy) {
+            self.release();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Marks a request admitted by [`Self::try_acquire`] as done.
+    ///
+    /// Can be called at any IRQL.
+    pub fn release(&self) {
+        let previous = self.outstanding.fetch_sub(1, Ordering::Release);
+        debug_assert!(previous > 0, "released more requests than were admitted");
+    }
+
+    fn consume_rate(&self, policy: &ThrottlePolicy) -> Result<(), Throttled> {
+        let Some((emission, tolerance)) = policy.rate_ticks() else {
+            return Ok(());
+        };
+
+        let now = Instant::now().ticks();
+        self.theoretical_arrival
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |arrival| {
+                let arrival = arrival.max(now);
+                (arrival - now <= tolerance && emission != u64::MAX)
+                    .then(|| arrival.saturating_add(emission))
+            })
+            .map(|_| ())
+            .map_err(|_| Throttled::RateExceeded)
+    }
+}
+
+/// An admitted request, which counts as outstanding for its handle until this is dropped.
+///
+/// It keeps a reference to the file object, so it can be stored alongside a request that is
+/// completed later, e.g. one forwarded to a manual queue.
+pub struct Admission<T: AsRef<HandleThrottle> + Sync + 'static> {
+    context: Option<ContextHandle<RawWdfFileObject, T>>,
+}
+
+impl<T: AsRef<HandleThrottle> + Sync> Drop for Admission<T> {
+    fn drop(&mut self) {
+        if let Some(context) = &self.context {
+            context.get().as_ref().release();
+        }
+    }
+}
+
+/// Checks `request` against `policy`, using the [`HandleThrottle`] in the context of its file
+/// object. See the [module documentation](self).
+///
+/// Can be called at any IRQL.
+pub fn admit<T: AsRef<HandleThrottle> + Sync>(
+    request: &Request,
+    context_type: &'static WdfObjectContextTypeInfo<T>,
+    policy: &ThrottlePolicy,
+) -> Result<Admission<T>, Throttled> {
+    let Some(context) = request
+        .file_object()
+        .and_then(|file_object| context_type.handle(&file_object))
+    else {
+        return Ok(Admission { context: None });
+    };
+
+    context.get().as_ref().try_acquire(policy)?;
+    Ok(Admission {
+        context: Some(context),
+    })
+}
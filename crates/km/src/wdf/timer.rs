@@ -0,0 +1,190 @@
+use super::{
+    context::WdfObjectContextTypeInfo, ffi, object_attributes::ObjectAttributes, AsWdfReference,
+    OwnedWdfObject, RawWdfObject, RawWdfTimer, WdfObjectReference,
+};
+use crate::{time::relative_timeout, verify, Sealed};
+use core::{
+    mem::{size_of, transmute},
+    ptr::null_mut,
+    time::Duration,
+};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{ULONG, WDFTIMER, WDF_TIMER_CONFIG};
+
+/// This is FFI-compatible with [`km_sys::PFN_WDF_TIMER`].
+pub type EvtTimer = unsafe extern "system" fn(timer: WdfObjectReference<'_, RawWdfTimer>);
+
+pub struct TimerConfigInit {
+    /// Called when the timer expires, see [MSDN].
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdftimer/nc-wdftimer-evt_wdf_timer
+    pub evt_timer: EvtTimer,
+    /// The period of a periodic timer (with millisecond resolution), or zero for a one-shot
+    /// timer. Timers running at `PASSIVE_LEVEL` can't be periodic.
+    pub period: Duration,
+    /// How much later than its due time the timer may expire (with millisecond resolution), which
+    /// allows the system to coalesce timers to save power.
+    pub tolerable_delay: Duration,
+    /// Whether to use a high resolution timer, for periods that aren't multiples of the system
+    /// clock interval. Can't be combined with a tolerable delay.
+    pub use_high_resolution_timer: bool,
+    /// Whether the callback is synchronized with the callbacks of the parent object, according to
+    /// its [`SynchronizationScope`](super::SynchronizationScope).
+    pub automatic_serialization: bool,
+}
+
+impl TimerConfigInit {
+    /// The config of a one-shot timer, which is restarted manually.
+    pub fn one_shot(evt_timer: EvtTimer) -> Self {
+        Self {
+            evt_timer,
+            period: Duration::ZERO,
+            tolerable_delay: Duration::ZERO,
+            use_high_resolution_timer: false,
+            automatic_serialization: false,
+        }
+    }
+}
+
+pub struct TimerConfig(pub(crate) WDF_TIMER_CONFIG);
+
+impl TimerConfig {
+    /// Builds the timer config, like the `WDF_TIMER_CONFIG_INIT_PERIODIC` macro does.
+    #[inline(always)]
+    pub fn new(init: TimerConfigInit) -> Self {
+        let as_millis = |d: Duration| d.as_millis().try_into().unwrap_or(ULONG::MAX);
+
+        Self(WDF_TIMER_CONFIG {
+            Size: size_of::<WDF_TIMER_CONFIG>() as ULONG,
+            EvtTimerFunc: Some(init.evt_timer).map(|f| {
+                // SAFETY: The function pointer definition is FFI-compatible.
+                unsafe { transmute(f) }
+            }),
+            Period: as_millis(init.period),
+            AutomaticSerialization: init.automatic_serialization.into(),
+            TolerableDelay: as_millis(init.tolerable_delay),
+            UseHighResolutionTimer: init.use_high_resolution_timer.into(),
+        })
+    }
+}
+
+/// A framework timer object, calling its [`EvtTimer`] callback when it expires.
+///
+/// See [Using Timers][MSDN] for more information.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/using-timers
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct Timer(OwnedWdfObject<RawWdfTimer>);
+impl Sealed for Timer {}
+
+impl AsWdfReference for Timer {
+    type ObjectType = RawWdfTimer;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl From<WdfObjectReference<'_, RawWdfTimer>> for Timer {
+    fn from(raw: WdfObjectReference<'_, RawWdfTimer>) -> Self {
+        Self(raw.to_owned())
+    }
+}
+
+impl Timer {
+    /// Creates a new, stopped timer.
+    ///
+    /// Timers need a parent (e.g. a device or queue), which has to be
+    /// [set](ObjectAttributes::set_parent) in `attributes`. The timer is stopped and deleted
+    /// together with its parent.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdftimer/nf-wdftimer-wdftimercreate
+    pub fn create(
+        config: &mut TimerConfig,
+        attributes: &mut ObjectAttributes,
+    ) -> Result<Self, NtStatusError> {
+        let mut timer: WDFTIMER = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe { ffi::timer_create(&mut config.0, &mut attributes.0, &mut timer) }
+            .result_for("WdfTimerCreate")?;
+
+        debug_assert!(!timer.is_null());
+
+        Ok(Self(OwnedWdfObject::from_new_raw(timer)))
+    }
+
+    /// Starts the timer, expiring after `due_time`. If the timer was already started, it is
+    /// restarted with the new due time. Returns whether the timer was already started.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn start(&self, due_time: Duration) -> bool {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The timer is guaranteed to be valid.
+        unsafe { ffi::timer_start(self.0.as_wdf_ref(), relative_timeout(due_time)) != 0 }
+    }
+
+    /// Stops the timer. Returns whether the timer was started, i.e. its callback won't be called.
+    ///
+    /// If `wait` is set, this also waits for a running callback to return, and for all DPCs to
+    /// finish. In that case, this must be called at `PASSIVE_LEVEL`, and not from the timer's
+    /// callback. Otherwise, it must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdftimer/nf-wdftimer-wdftimerstop
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn stop(&self, wait: bool) -> bool {
+        if wait {
+            verify::at_passive_level();
+        } else {
+            verify::at_most_dispatch_level();
+        }
+
+        // SAFETY: The timer is guaranteed to be valid.
+        unsafe { ffi::timer_stop(self.0.as_wdf_ref(), wait.into()) != 0 }
+    }
+
+    /// Deletes the timer before its parent is deleted, stopping it first.
+    ///
+    /// Other clones of the timer keep its memory valid, but mustn't be used anymore.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`, or at `PASSIVE_LEVEL` if the timer's callback
+    /// runs at `PASSIVE_LEVEL`, and not from the timer's callback.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// # Safety
+    /// No other clone of the timer may be used after this is called.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfobject/nf-wdfobject-wdfobjectdelete
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub unsafe fn delete(self) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: Timers are created by the driver, which may delete them. The caller guarantees
+        // that the timer isn't used anymore.
+        unsafe { self.0.delete() }
+    }
+
+    /// The parent object the timer was created with.
+    pub fn parent(&self) -> WdfObjectReference<'_, RawWdfObject> {
+        // SAFETY: The timer is guaranteed to be valid, and so is its parent.
+        unsafe { ffi::timer_get_parent_object(self.0.as_wdf_ref()) }
+    }
+
+    /// Calls `f` with the timer's context of the given type. Returns `None` if the timer has no
+    /// such context, or it wasn't [initialized](WdfObjectContextTypeInfo::initialize) yet.
+    pub fn with_context<T: Sync, R>(
+        &self,
+        context_type: &'static WdfObjectContextTypeInfo<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        context_type.with(self, f)
+    }
+}
@@ -0,0 +1,603 @@
+//! USB I/O targets, for talking to a USB device from the driver of its device stack.
+//!
+//! A [`UsbDevice`] is created for the framework device of a USB function (or filter) driver, and
+//! gives access to the device's descriptors and control transfers. Bulk and interrupt transfers go
+//! through the [pipes](UsbPipe) of the device's [interfaces](UsbInterface), which exist once a
+//! configuration was [selected](UsbDevice::select_config):
+//!
+//! ```rs, ignore
+//! let mut usb = UsbDevice::create(&device, None)?;
+//! usb.select_config(SelectConfig::SingleInterface)?;
+//!
+//! let status = usb.control_transfer_in(
+//!     None,
+//!     &ControlSetup::vendor(GET_STATUS, 0, 0),
+//!     &mut buffer,
+//!     &RequestSendOptions::default(),
+//! )?;
+//!
+//! let interface = usb.interface(0).unwrap();
+//! let pipe = interface.pipes().find(|pipe| pipe.information().is_in()).unwrap();
+//! let len = pipe.read_synchronously(None, &mut report, &RequestSendOptions::default())?;
+//! ```
+//!
+//! See [MSDN] for an overview.
+//!
+//! [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/usb-i-o-targets
+
+use super::{
+    device::Device, ffi, io_target::MemoryDescriptor, io_target::RequestSendOptions,
+    object_attributes::ObjectAttributes, request::Request, AsWdfReference, OwnedWdfObject,
+    RawWdfUsbDevice, RawWdfUsbInterface, RawWdfUsbPipe, WdfObjectReference,
+};
+use crate::{verify, AsRawMutPtr, Sealed};
+use core::{
+    mem::{size_of, zeroed},
+    ptr::null_mut,
+};
+use km_shared::ntstatus::NtStatusError;
+use km_sys::{
+    UCHAR, ULONG, WDFUSBDEVICE, WDF_OBJECT_ATTRIBUTES, WDF_USB_CONTROL_SETUP_PACKET,
+    WDF_USB_DEVICE_CREATE_CONFIG, WDF_USB_DEVICE_SELECT_CONFIG_PARAMS, WDF_USB_PIPE_INFORMATION,
+    WDF_USB_SELECT_CONFIG_TYPE,
+};
+
+pub use km_sys::USB_DEVICE_DESCRIPTOR as UsbDeviceDescriptor;
+pub use km_sys::WDF_USB_PIPE_TYPE as UsbPipeType;
+
+/// The version of the USB driver stack's client contract the framework uses, which enables the
+/// USB 3.0 stack's features (`USBD_CLIENT_CONTRACT_VERSION_602`).
+const USBD_CLIENT_CONTRACT_VERSION_602: ULONG = 0x602;
+
+/// A WDF USB target device. See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/working-with-usb-devices
+#[derive(Debug, Clone)]
+pub struct UsbDevice(OwnedWdfObject<RawWdfUsbDevice>);
+impl Sealed for UsbDevice {}
+
+impl AsWdfReference for UsbDevice {
+    type ObjectType = RawWdfUsbDevice;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+/// How [`UsbDevice::select_config`] configures the device's interfaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectConfig {
+    /// Selects the first configuration, for devices with a single interface.
+    SingleInterface,
+    /// Selects the first configuration, and the default (first) setting of each interface.
+    MultipleInterfaces,
+}
+
+impl UsbDevice {
+    /// Creates the USB target device of `device`, which has to be in the device stack of a USB
+    /// device, e.g. in `EvtDevicePrepareHardware`. Control devices aren't, so this fails for
+    /// them.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetdevicecreatewithparameters
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn create(
+        device: &Device,
+        mut attributes: Option<&mut ObjectAttributes>,
+    ) -> Result<UsbDevice, NtStatusError> {
+        verify::at_passive_level();
+
+        // like `WDF_USB_DEVICE_CREATE_CONFIG_INIT`
+        let mut config = WDF_USB_DEVICE_CREATE_CONFIG {
+            Size: size_of::<WDF_USB_DEVICE_CREATE_CONFIG>() as ULONG,
+            USBDClientContractVersion: USBD_CLIENT_CONTRACT_VERSION_602,
+        };
+        let mut usb_device: WDFUSBDEVICE = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe {
+            ffi::usb_target_device_create_with_parameters(
+                device.as_wdf_ref(),
+                &mut config,
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                &mut usb_device,
+            )
+        }
+        .result_for("WdfUsbTargetDeviceCreateWithParameters")?;
+
+        debug_assert!(!usb_device.is_null());
+
+        Ok(UsbDevice(OwnedWdfObject::from_new_raw(usb_device)))
+    }
+
+    /// The device descriptor, e.g. for checking the vendor and product IDs.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn device_descriptor(&self) -> UsbDeviceDescriptor {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: All-zero is a valid bit pattern for the descriptor.
+        let mut descriptor: UsbDeviceDescriptor = unsafe { zeroed() };
+        // SAFETY: The USB device and the descriptor are guaranteed to be valid.
+        unsafe { ffi::usb_target_device_get_device_descriptor(self.as_wdf_ref(), &mut descriptor) };
+        descriptor
+    }
+
+    /// Selects the device's first configuration, which creates the [interfaces](Self::interface)
+    /// and their pipes.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetdeviceselectconfig
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn select_config(&mut self, config: SelectConfig) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        // SAFETY: All-zero is a valid bit pattern for the parameters.
+        let mut params: WDF_USB_DEVICE_SELECT_CONFIG_PARAMS = unsafe { zeroed() };
+        params.Size = size_of::<WDF_USB_DEVICE_SELECT_CONFIG_PARAMS>() as ULONG;
+        // like `WDF_USB_DEVICE_SELECT_CONFIG_PARAMS_INIT_SINGLE_INTERFACE`, and
+        // `WDF_USB_DEVICE_SELECT_CONFIG_PARAMS_INIT_MULTIPLE_INTERFACES` without setting pairs
+        params.Type = match config {
+            SelectConfig::SingleInterface => {
+                WDF_USB_SELECT_CONFIG_TYPE::WdfUsbTargetDeviceSelectConfigTypeSingleInterface
+            }
+            SelectConfig::MultipleInterfaces => {
+                WDF_USB_SELECT_CONFIG_TYPE::WdfUsbTargetDeviceSelectConfigTypeMultiInterface
+            }
+        };
+
+        // SAFETY: All pointers are guaranteed to be valid. The pipes get no extra attributes.
+        unsafe { ffi::usb_target_device_select_config(self.as_wdf_ref(), null_mut(), &mut params) }
+            .result_for("WdfUsbTargetDeviceSelectConfig")?;
+        Ok(())
+    }
+
+    /// The number of interfaces of the device's configuration.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn num_interfaces(&self) -> u8 {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The USB device is guaranteed to be valid.
+        unsafe { ffi::usb_target_device_get_num_interfaces(self.as_wdf_ref()) }
+    }
+
+    /// The interface at `index` (not necessarily its interface number), or `None` if there's no
+    /// such interface.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn interface(&self, index: u8) -> Option<UsbInterface> {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The USB device is guaranteed to be valid. Invalid indices return null.
+        let interface = unsafe { ffi::usb_target_device_get_interface(self.as_wdf_ref(), index) };
+
+        (!interface.raw().is_null()).then(|| UsbInterface(interface.to_owned()))
+    }
+
+    /// The interfaces of the device's configuration.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    pub fn interfaces(&self) -> impl Iterator<Item = UsbInterface> + '_ {
+        (0..self.num_interfaces()).filter_map(|index| self.interface(index))
+    }
+
+    /// Sends a control transfer reading from the device into `buffer`, and waits for it to
+    /// complete. Returns the number of bytes read.
+    ///
+    /// If `request` is `None`, the framework allocates a request internally.
+    ///
+    /// Fails with `STATUS_INVALID_PARAMETER` if `buffer` is larger than a control transfer can
+    /// be (64 KiB).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetdevicesendcontroltransfersynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn control_transfer_in(
+        &self,
+        request: Option<&Request>,
+        setup: &ControlSetup,
+        buffer: &mut [u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        let mut packet = setup.packet(true, buffer.len())?;
+        let mut buffer = (!buffer.is_empty())
+            .then(|| MemoryDescriptor::buffer(buffer.as_mut_ptr(), buffer.len()));
+
+        self.send_control_transfer(request, &mut packet, &mut buffer, options)
+    }
+
+    /// Sends a control transfer writing `data` to the device, and waits for it to complete.
+    /// Returns the number of bytes written.
+    ///
+    /// If `request` is `None`, the framework allocates a request internally.
+    ///
+    /// Fails with `STATUS_INVALID_PARAMETER` if `data` is larger than a control transfer can be
+    /// (64 KiB).
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetdevicesendcontroltransfersynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn control_transfer_out(
+        &self,
+        request: Option<&Request>,
+        setup: &ControlSetup,
+        data: &[u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        let mut packet = setup.packet(false, data.len())?;
+        // The device is not supposed to write to the buffer. The `*mut` is just an artifact of
+        // the C signature.
+        let mut data = (!data.is_empty())
+            .then(|| MemoryDescriptor::buffer(data.as_ptr().cast_mut(), data.len()));
+
+        self.send_control_transfer(request, &mut packet, &mut data, options)
+    }
+
+    #[cfg_attr(feature = "verification", track_caller)]
+    fn send_control_transfer(
+        &self,
+        request: Option<&Request>,
+        packet: &mut WDF_USB_CONTROL_SETUP_PACKET,
+        buffer: &mut Option<km_sys::WDF_MEMORY_DESCRIPTOR>,
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut options = options.0;
+        let mut bytes_transferred = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call.
+        unsafe {
+            ffi::usb_target_device_send_control_transfer_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                &mut options,
+                packet,
+                MemoryDescriptor::as_raw(buffer),
+                &mut bytes_transferred,
+            )
+        }
+        .result_for("WdfUsbTargetDeviceSendControlTransferSynchronously")?;
+
+        Ok(bytes_transferred as usize)
+    }
+}
+
+/// The type of a [control transfer](ControlSetup).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlRequestType {
+    /// A request defined by the USB specification, e.g. `GET_DESCRIPTOR`.
+    Standard,
+    /// A request defined by a device class, e.g. HID's `SET_REPORT`.
+    Class,
+    /// A request defined by the vendor of the device.
+    Vendor,
+}
+
+/// The recipient of a [control transfer](ControlSetup).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlRecipient {
+    Device,
+    /// The interface whose number is in the transfer's index.
+    Interface,
+    /// The endpoint whose address is in the transfer's index.
+    Endpoint,
+    Other,
+}
+
+/// The setup packet of a control transfer, without the direction and length, which are taken
+/// from the buffer it's sent with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ControlSetup {
+    pub request_type: ControlRequestType,
+    pub recipient: ControlRecipient,
+    /// The request code (`bRequest`).
+    pub request: u8,
+    /// The request specific value (`wValue`).
+    pub value: u16,
+    /// The request specific index (`wIndex`), often an interface number or endpoint address.
+    pub index: u16,
+}
+
+impl ControlSetup {
+    /// A vendor-defined request to the device.
+    pub const fn vendor(request: u8, value: u16, index: u16) -> Self {
+        Self {
+            request_type: ControlRequestType::Vendor,
+            recipient: ControlRecipient::Device,
+            request,
+            value,
+            index,
+        }
+    }
+
+    /// A class-defined request to the interface with the number `interface`.
+    pub const fn class_interface(request: u8, value: u16, interface: u8) -> Self {
+        Self {
+            request_type: ControlRequestType::Class,
+            recipient: ControlRecipient::Interface,
+            request,
+            value,
+            index: interface as u16,
+        }
+    }
+
+    /// Builds the raw setup packet for a transfer of `len` bytes in the given direction.
+    fn packet(
+        &self,
+        device_to_host: bool,
+        len: usize,
+    ) -> Result<WDF_USB_CONTROL_SETUP_PACKET, NtStatusError> {
+        let len = u16::try_from(len).map_err(|_| NtStatusError::STATUS_INVALID_PARAMETER)?;
+
+        let request_type = match self.request_type {
+            ControlRequestType::Standard => 0,
+            ControlRequestType::Class => 1,
+            ControlRequestType::Vendor => 2,
+        };
+        let recipient = match self.recipient {
+            ControlRecipient::Device => 0,
+            ControlRecipient::Interface => 1,
+            ControlRecipient::Endpoint => 2,
+            ControlRecipient::Other => 3,
+        };
+
+        // SAFETY: All-zero is a valid bit pattern for the packet.
+        let mut packet: WDF_USB_CONTROL_SETUP_PACKET = unsafe { zeroed() };
+        packet.Packet.bm = ((device_to_host as UCHAR) << 7) | (request_type << 5) | recipient;
+        packet.Packet.bRequest = self.request;
+        packet.Packet.wValue = self.value;
+        packet.Packet.wIndex = self.index;
+        packet.Packet.wLength = len;
+
+        Ok(packet)
+    }
+}
+
+/// An interface of a [`UsbDevice`].
+#[derive(Debug, Clone)]
+pub struct UsbInterface(OwnedWdfObject<RawWdfUsbInterface>);
+impl Sealed for UsbInterface {}
+
+impl AsWdfReference for UsbInterface {
+    type ObjectType = RawWdfUsbInterface;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl UsbInterface {
+    /// The interface number (`bInterfaceNumber`), e.g. for the index of class requests.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn number(&self) -> u8 {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The interface is guaranteed to be valid.
+        unsafe { ffi::usb_interface_get_interface_number(self.as_wdf_ref()) }
+    }
+
+    /// The number of pipes of the interface's selected setting.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn num_pipes(&self) -> u8 {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The interface is guaranteed to be valid.
+        unsafe { ffi::usb_interface_get_num_configured_pipes(self.as_wdf_ref()) }
+    }
+
+    /// The pipe at `index`, or `None` if there's no such pipe.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn pipe(&self, index: u8) -> Option<UsbPipe> {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The interface is guaranteed to be valid. The pipe information is optional, and
+        // invalid indices return null.
+        let pipe =
+            unsafe { ffi::usb_interface_get_configured_pipe(self.as_wdf_ref(), index, null_mut()) };
+
+        (!pipe.raw().is_null()).then(|| UsbPipe(pipe.to_owned()))
+    }
+
+    /// The pipes of the interface's selected setting.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    pub fn pipes(&self) -> impl Iterator<Item = UsbPipe> + '_ {
+        (0..self.num_pipes()).filter_map(|index| self.pipe(index))
+    }
+}
+
+/// Describes a [`UsbPipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsbPipeInformation {
+    pub pipe_type: UsbPipeType,
+    /// The endpoint address, whose top bit is set for IN endpoints.
+    pub endpoint_address: u8,
+    pub max_packet_size: u32,
+    /// The largest transfer the pipe can handle at once.
+    pub max_transfer_size: u32,
+    /// The polling interval of interrupt pipes, in frames (or microframes for high-speed devices).
+    pub interval: u8,
+}
+
+impl UsbPipeInformation {
+    /// Whether the pipe transfers data from the device to the host, i.e. can be read.
+    pub fn is_in(&self) -> bool {
+        self.endpoint_address & 0x80 != 0
+    }
+}
+
+/// A pipe of a [`UsbInterface`], for bulk or interrupt transfers.
+#[derive(Debug, Clone)]
+pub struct UsbPipe(OwnedWdfObject<RawWdfUsbPipe>);
+impl Sealed for UsbPipe {}
+
+impl AsWdfReference for UsbPipe {
+    type ObjectType = RawWdfUsbPipe;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl UsbPipe {
+    /// Describes the pipe.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn information(&self) -> UsbPipeInformation {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: All-zero is a valid bit pattern for the information.
+        let mut info: WDF_USB_PIPE_INFORMATION = unsafe { zeroed() };
+        info.Size = size_of::<WDF_USB_PIPE_INFORMATION>() as ULONG;
+        // SAFETY: The pipe and the information are guaranteed to be valid.
+        unsafe { ffi::usb_target_pipe_get_information(self.as_wdf_ref(), &mut info) };
+
+        UsbPipeInformation {
+            pipe_type: info.PipeType,
+            endpoint_address: info.EndpointAddress,
+            max_packet_size: info.MaximumPacketSize,
+            max_transfer_size: info.MaximumTransferSize,
+            interval: info.Interval,
+        }
+    }
+
+    /// Allows reads whose buffer isn't a multiple of the maximum packet size, e.g. for devices
+    /// that send short reports.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn set_no_maximum_packet_size_check(&self) {
+        verify::at_most_dispatch_level();
+
+        // SAFETY: The pipe is guaranteed to be valid.
+        unsafe { ffi::usb_target_pipe_set_no_maximum_packet_size_check(self.as_wdf_ref()) }
+    }
+
+    /// Reads from an IN pipe into `buffer`, and waits for the transfer to complete. Returns the
+    /// number of bytes read.
+    ///
+    /// If `request` is `None`, the framework allocates a request internally.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetpipereadsynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn read_synchronously(
+        &self,
+        request: Option<&Request>,
+        buffer: &mut [u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        verify::at_passive_level();
+
+        let mut buffer = MemoryDescriptor::buffer(buffer.as_mut_ptr(), buffer.len());
+        let mut options = options.0;
+        let mut bytes_read = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call.
+        unsafe {
+            ffi::usb_target_pipe_read_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                &mut options,
+                &mut buffer,
+                &mut bytes_read,
+            )
+        }
+        .result_for("WdfUsbTargetPipeReadSynchronously")?;
+
+        Ok(bytes_read as usize)
+    }
+
+    /// Writes `data` to an OUT pipe, and waits for the transfer to complete. Returns the number
+    /// of bytes written.
+    ///
+    /// If `request` is `None`, the framework allocates a request internally.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetpipewritesynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn write_synchronously(
+        &self,
+        request: Option<&Request>,
+        data: &[u8],
+        options: &RequestSendOptions,
+    ) -> Result<usize, NtStatusError> {
+        verify::at_passive_level();
+
+        // The device is not supposed to write to the buffer. The `*mut` is just an artifact of
+        // the C signature.
+        let mut data = MemoryDescriptor::buffer(data.as_ptr().cast_mut(), data.len());
+        let mut options = options.0;
+        let mut bytes_written = 0;
+
+        // SAFETY: All pointers are either null or point to buffers that are valid for the
+        // duration of the (synchronous) call.
+        unsafe {
+            ffi::usb_target_pipe_write_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                &mut options,
+                &mut data,
+                &mut bytes_written,
+            )
+        }
+        .result_for("WdfUsbTargetPipeWriteSynchronously")?;
+
+        Ok(bytes_written as usize)
+    }
+
+    /// Clears a halt (stall) condition of the pipe, e.g. after a transfer failed with
+    /// `STATUS_DEVICE_DATA_ERROR`, and waits for it to complete.
+    ///
+    /// If `request` is `None`, the framework allocates a request internally.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`. See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfusb/nf-wdfusb-wdfusbtargetpiperesetsynchronously
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn reset_synchronously(
+        &self,
+        request: Option<&Request>,
+        options: &RequestSendOptions,
+    ) -> Result<(), NtStatusError> {
+        verify::at_passive_level();
+
+        let mut options = options.0;
+
+        // SAFETY: All pointers are either null or valid for the duration of the (synchronous)
+        // call.
+        unsafe {
+            ffi::usb_target_pipe_reset_synchronously(
+                self.as_wdf_ref(),
+                request.map_or(null_mut(), |r| r.as_wdf_ref().raw()),
+                &mut options,
+            )
+        }
+        .result_for("WdfUsbTargetPipeResetSynchronously")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use super::{
+    ffi, object_attributes::ObjectAttributes, AsWdfReference, OwnedWdfObject, RawWdfWaitLock,
+    WdfObjectReference,
+};
+use crate::{time::relative_timeout, verify, AsRawMutPtr, Sealed};
+use core::{marker::PhantomData, ptr::null_mut, time::Duration};
+use km_shared::ntstatus::{NtStatus, NtStatusError};
+use km_sys::{WDFWAITLOCK, WDF_OBJECT_ATTRIBUTES};
+
+/// A framework wait lock object, which puts the waiting thread to sleep instead of spinning, so it
+/// can protect long operations at `PASSIVE_LEVEL`.
+///
+/// While the lock is held, normal kernel APCs are disabled for the thread holding it.
+///
+/// See [MSDN] for more details.
+///
+/// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/wdf/synchronization-techniques-for-wdf-drivers
+#[derive(Debug, Clone)]
+pub struct WaitLock(OwnedWdfObject<RawWdfWaitLock>);
+impl Sealed for WaitLock {}
+
+impl AsWdfReference for WaitLock {
+    type ObjectType = RawWdfWaitLock;
+
+    fn as_wdf_ref(&self) -> WdfObjectReference<'_, Self::ObjectType> {
+        self.0.as_wdf_ref()
+    }
+}
+
+impl WaitLock {
+    /// Creates a new wait lock. Without a parent set in `attributes`, the lock belongs to the
+    /// driver object.
+    ///
+    /// Must be called at `IRQL <= DISPATCH_LEVEL`.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfsync/nf-wdfsync-wdfwaitlockcreate
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn create(mut attributes: Option<&mut ObjectAttributes>) -> Result<Self, NtStatusError> {
+        verify::at_most_dispatch_level();
+
+        let mut lock: WDFWAITLOCK = null_mut();
+
+        // SAFETY: All pointers are guaranteed to be valid.
+        unsafe {
+            ffi::wait_lock_create(
+                attributes.as_raw_mut_ptr().cast::<WDF_OBJECT_ATTRIBUTES>(),
+                &mut lock,
+            )
+        }
+        .result_for("WdfWaitLockCreate")?;
+
+        debug_assert!(!lock.is_null());
+
+        Ok(Self(OwnedWdfObject::from_new_raw(lock)))
+    }
+
+    /// Acquires the lock, waiting as long as necessary. It's released when the returned guard is
+    /// dropped.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`, and the lock must not already be held by the current
+    /// thread, or it deadlocks.
+    ///
+    /// See [MSDN] for more details on the underlying function.
+    ///
+    /// [MSDN]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdfsync/nf-wdfsync-wdfwaitlockacquire
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn acquire(&self) -> WaitLockGuard<'_> {
+        verify::at_passive_level();
+
+        // SAFETY: The lock is guaranteed to be valid, and a null timeout waits indefinitely.
+        let status = unsafe { ffi::wait_lock_acquire(self.0.as_wdf_ref(), null_mut()) };
+        debug_assert_eq!(status, NtStatus::STATUS_SUCCESS);
+
+        WaitLockGuard(self, PhantomData)
+    }
+
+    /// Acquires the lock if it becomes available within `timeout`, or returns `None`.
+    ///
+    /// With a zero timeout, the lock is only acquired if it's available right away, which can be
+    /// done at `IRQL <= DISPATCH_LEVEL`. Otherwise, it must be called at `PASSIVE_LEVEL`.
+    #[cfg_attr(feature = "verification", track_caller)]
+    pub fn try_acquire(&self, timeout: Duration) -> Option<WaitLockGuard<'_>> {
+        if timeout.is_zero() {
+            verify::at_most_dispatch_level();
+        } else {
+            verify::at_passive_level();
+        }
+
+        let mut timeout = relative_timeout(timeout);
+
+        // SAFETY: The lock and the timeout are guaranteed to be valid.
+        match unsafe { ffi::wait_lock_acquire(self.0.as_wdf_ref(), &mut timeout) } {
+            NtStatus::STATUS_TIMEOUT => None,
+            status => {
+                debug_assert_eq!(status, NtStatus::STATUS_SUCCESS);
+                Some(WaitLockGuard(self, PhantomData))
+            }
+        }
+    }
+}
+
+/// A held [`WaitLock`], released on drop.
+#[must_use = "the lock is released immediately if the guard is dropped"]
+pub struct WaitLockGuard<'a>(
+    &'a WaitLock,
+    /// Not `Send`, as the lock has to be released on the thread that acquired it.
+    PhantomData<*const ()>,
+);
+
+impl Drop for WaitLockGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: The lock is valid, and held by this guard.
+        unsafe { ffi::wait_lock_release(self.0 .0.as_wdf_ref()) }
+    }
+}
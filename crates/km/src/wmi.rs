@@ -0,0 +1,390 @@
+//! Kernel-mode access to WMI data blocks.
+//!
+//! See [`WmiDataBlock`] for opening and querying a block, and [`WnodeAllData`] for parsing the
+//! returned `WNODE_ALL_DATA` layout.
+
+use bitflags::bitflags;
+use bytemuck::{checked::CheckedCastError, CheckedBitPattern, PodCastError};
+use core::{
+    ffi::c_void,
+    mem::{offset_of, size_of},
+    ptr::{null_mut, NonNull},
+};
+use km_shared::{
+    guid::Guid,
+    ntstatus::{NtStatus, NtStatusError},
+};
+use km_sys::{
+    IoWMIOpenBlock, IoWMIQueryAllData, ObfDereferenceObject, GUID, ULONG, WMIGUID_EXECUTE,
+    WMIGUID_NOTIFICATION, WMIGUID_QUERY, WMIGUID_READ_DESCRIPTION, WMIGUID_SET, WNODE_ALL_DATA,
+    WNODE_FLAG_ALL_DATA, WNODE_FLAG_ANSI_INSTANCENAMES, WNODE_FLAG_FIXED_INSTANCE_SIZE,
+    WNODE_HEADER,
+};
+use snafu::{ensure, ResultExt, Snafu};
+
+bitflags! {
+    /// Access rights requested when [opening](WmiDataBlock::open) a WMI data block.
+    ///
+    /// See [`IoWMIOpenBlock` on MSDN][msdn] for more information.
+    ///
+    /// [msdn]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-iowmiopenblock
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct WmiAccess: ULONG {
+        /// The data block can be queried.
+        const QUERY = WMIGUID_QUERY;
+        /// The data block can be set.
+        const SET = WMIGUID_SET;
+        /// Notifications for the event block can be received.
+        const NOTIFICATION = WMIGUID_NOTIFICATION;
+        /// The block's MOF description can be read.
+        const READ_DESCRIPTION = WMIGUID_READ_DESCRIPTION;
+        /// Methods of the block can be executed.
+        const EXECUTE = WMIGUID_EXECUTE;
+    }
+}
+
+/// An opened WMI data block object.
+///
+/// Dereferences the underlying data block object when dropped.
+#[derive(Debug)]
+pub struct WmiDataBlock(NonNull<c_void>);
+
+impl WmiDataBlock {
+    /// Opens the WMI data block identified by `guid`.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    pub fn open(guid: &Guid, access: WmiAccess) -> Result<Self, NtStatusError> {
+        let guid: GUID = (*guid).into();
+        let mut object = null_mut();
+
+        // SAFETY: `guid` is a valid pointer, and `object` is an out parameter.
+        NtStatus::from(unsafe { IoWMIOpenBlock(&guid, access.bits(), &mut object) })
+            .result_for("IoWMIOpenBlock")?;
+
+        Ok(Self(
+            NonNull::new(object).ok_or(NtStatusError::STATUS_UNSUCCESSFUL)?,
+        ))
+    }
+
+    /// Queries the size in bytes that is needed to hold the result of
+    /// [`query_all_data`](Self::query_all_data).
+    ///
+    /// Note that the size may change between this call and the actual query, e.g. when new
+    /// instances are registered in between.
+    pub fn required_buffer_size(&self) -> Result<usize, NtStatusError> {
+        let mut size: ULONG = 0;
+
+        // SAFETY: The data block object is guaranteed to be valid. A null buffer with a size of
+        // zero is explicitly allowed to query the needed size.
+        let status =
+            NtStatus::from(unsafe { IoWMIQueryAllData(self.0.as_ptr(), &mut size, null_mut()) });
+
+        match status.result_for("IoWMIQueryAllData") {
+            Ok(_) => Ok(size as usize),
+            Err(e) if e == NtStatusError::STATUS_BUFFER_TOO_SMALL => Ok(size as usize),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Queries all instances of the data block into `buffer`, returning a parsed view into it.
+    ///
+    /// If `buffer` is too small, [`QueryAllDataError::BufferTooSmall`] reports the size that is
+    /// needed.
+    ///
+    /// See [`IoWMIQueryAllData` on MSDN][msdn] for more information.
+    ///
+    /// Must be called at `PASSIVE_LEVEL`.
+    ///
+    /// [msdn]: https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-iowmiqueryalldata
+    pub fn query_all_data<'b>(
+        &self,
+        buffer: &'b mut [u8],
+    ) -> Result<WnodeAllData<'b>, QueryAllDataError> {
+        let mut size = ULONG::try_from(buffer.len()).unwrap_or(ULONG::MAX);
+
+        // SAFETY: The data block object is guaranteed to be valid, and `buffer` is valid for
+        // writes of `size` bytes.
+        let status = NtStatus::from(unsafe {
+            IoWMIQueryAllData(self.0.as_ptr(), &mut size, buffer.as_mut_ptr().cast())
+        });
+
+        match status.result_for("IoWMIQueryAllData") {
+            Ok(_) => {}
+            Err(e) if e == NtStatusError::STATUS_BUFFER_TOO_SMALL => {
+                return query_all_data_error::BufferTooSmallSnafu {
+                    size_needed: size as usize,
+                }
+                .fail()
+            }
+            Err(e) => return Err(e).context(query_all_data_error::NtStatusSnafu),
+        }
+
+        let len = usize::min(size as usize, buffer.len());
+        WnodeAllData::parse(&buffer[..len])
+    }
+}
+
+impl Drop for WmiDataBlock {
+    fn drop(&mut self) {
+        // SAFETY: The data block object was referenced by `IoWMIOpenBlock`, and is only
+        // dereferenced once by virtue of being a `Drop` implementation.
+        unsafe {
+            ObfDereferenceObject(self.0.as_ptr());
+        }
+    }
+}
+
+/// An error returned from [`WmiDataBlock::query_all_data`].
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum QueryAllDataError {
+    /// The buffer was too small to hold all instances.
+    BufferTooSmall {
+        size_needed: usize,
+    },
+    NtStatus {
+        source: NtStatusError,
+    },
+    /// The returned data did not follow the `WNODE_ALL_DATA` layout.
+    Malformed,
+}
+
+/// A parsed view into a chain of [`WNODE_ALL_DATA`] structures returned by
+/// [`WmiDataBlock::query_all_data`].
+///
+/// Data providers may return more than one `WNODE_ALL_DATA` structure, linked together through
+/// the header's `Linkage` field. [`instances`](Self::instances) iterates over the instances of all
+/// of them.
+#[derive(Debug, Clone, Copy)]
+pub struct WnodeAllData<'b> {
+    buffer: &'b [u8],
+}
+
+impl<'b> WnodeAllData<'b> {
+    /// Validates that `buffer` holds a chain of well-formed `WNODE_ALL_DATA` structures.
+    pub fn parse(buffer: &'b [u8]) -> Result<Self, QueryAllDataError> {
+        let mut node = Some(buffer);
+
+        while let Some(current) = node {
+            let header = WnodeAllDataHeader::parse(current)?;
+            for i in 0..header.instance_count {
+                header.instance(current, i)?;
+            }
+            node = header.next(current)?;
+        }
+
+        Ok(Self { buffer })
+    }
+
+    /// Returns an iterator over all instances contained in the data.
+    pub fn instances(&self) -> WmiInstances<'b> {
+        WmiInstances {
+            node: Some(self.buffer),
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the [instances](WmiInstance) of a [`WnodeAllData`].
+#[derive(Debug, Clone)]
+pub struct WmiInstances<'b> {
+    node: Option<&'b [u8]>,
+    index: u32,
+}
+
+impl<'b> Iterator for WmiInstances<'b> {
+    type Item = WmiInstance<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.node?;
+            // `WnodeAllData::parse` already validated the whole chain
+            let header = WnodeAllDataHeader::parse(node).ok()?;
+
+            if self.index < header.instance_count {
+                let instance = header.instance(node, self.index).ok()?;
+                self.index += 1;
+                return Some(instance);
+            }
+
+            self.node = header.next(node).ok()?;
+            self.index = 0;
+        }
+    }
+}
+
+/// A single instance of a WMI data block.
+#[derive(Debug, Clone, Copy)]
+pub struct WmiInstance<'b> {
+    name: Option<InstanceName<'b>>,
+    data: &'b [u8],
+}
+
+impl<'b> WmiInstance<'b> {
+    /// Returns the instance name, if the data provider supplied one.
+    pub fn name(&self) -> Option<InstanceName<'b>> {
+        self.name
+    }
+
+    /// Returns the raw instance data.
+    pub fn data(&self) -> &'b [u8] {
+        self.data
+    }
+
+    /// Reads the beginning of the instance data as a `T`.
+    ///
+    /// WMI data blocks are laid out as described by their MOF class, so `T` has to be a
+    /// `repr(C)` mirror of that class. Trailing data is ignored.
+    pub fn read<T: CheckedBitPattern>(&self) -> Result<T, CheckedCastError> {
+        let bytes = self
+            .data
+            .get(..size_of::<T>())
+            .ok_or(CheckedCastError::PodCastError(PodCastError::SizeMismatch))?;
+
+        bytemuck::checked::try_pod_read_unaligned(bytes)
+    }
+}
+
+/// A counted UTF-16 instance name of a [`WmiInstance`].
+#[derive(Debug, Clone, Copy)]
+pub struct InstanceName<'b>(&'b [u8]);
+
+impl InstanceName<'_> {
+    /// Returns the UTF-16 code units of the name.
+    pub fn code_units(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+    }
+}
+
+impl core::fmt::Display for InstanceName<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        char::decode_utf16(self.code_units())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// The fields of a `WNODE_ALL_DATA` needed for parsing, read with bounds checks from a byte
+/// buffer with no alignment guarantees.
+struct WnodeAllDataHeader {
+    buffer_size: u32,
+    linkage: u32,
+    flags: u32,
+    data_block_offset: u32,
+    instance_count: u32,
+    offset_instance_name_offsets: u32,
+    fixed_instance_size: u32,
+}
+
+impl WnodeAllDataHeader {
+    const HEADER: usize = offset_of!(WNODE_ALL_DATA, WnodeHeader);
+    const FIXED_PART_SIZE: usize = offset_of!(WNODE_ALL_DATA, __bindgen_anon_1);
+
+    fn parse(buffer: &[u8]) -> Result<Self, QueryAllDataError> {
+        ensure!(
+            buffer.len() >= Self::FIXED_PART_SIZE + size_of::<ULONG>(),
+            query_all_data_error::MalformedSnafu
+        );
+
+        let header = Self {
+            buffer_size: read_u32(buffer, Self::HEADER + offset_of!(WNODE_HEADER, BufferSize))?,
+            linkage: read_u32(
+                buffer,
+                Self::HEADER + offset_of!(WNODE_HEADER, __bindgen_anon_1.__bindgen_anon_1.Linkage),
+            )?,
+            flags: read_u32(buffer, Self::HEADER + offset_of!(WNODE_HEADER, Flags))?,
+            data_block_offset: read_u32(buffer, offset_of!(WNODE_ALL_DATA, DataBlockOffset))?,
+            instance_count: read_u32(buffer, offset_of!(WNODE_ALL_DATA, InstanceCount))?,
+            offset_instance_name_offsets: read_u32(
+                buffer,
+                offset_of!(WNODE_ALL_DATA, OffsetInstanceNameOffsets),
+            )?,
+            fixed_instance_size: read_u32(buffer, Self::FIXED_PART_SIZE)?,
+        };
+
+        ensure!(
+            header.flags & WNODE_FLAG_ALL_DATA != 0 && header.buffer_size as usize <= buffer.len(),
+            query_all_data_error::MalformedSnafu
+        );
+
+        Ok(header)
+    }
+
+    /// Returns the next `WNODE_ALL_DATA` in the chain, if any.
+    fn next<'b>(&self, buffer: &'b [u8]) -> Result<Option<&'b [u8]>, QueryAllDataError> {
+        match self.linkage {
+            0 => Ok(None),
+            linkage => buffer
+                .get(linkage as usize..)
+                .map(Some)
+                .ok_or(QueryAllDataError::Malformed),
+        }
+    }
+
+    fn instance<'b>(
+        &self,
+        buffer: &'b [u8],
+        index: u32,
+    ) -> Result<WmiInstance<'b>, QueryAllDataError> {
+        let (offset, length) = if self.flags & WNODE_FLAG_FIXED_INSTANCE_SIZE != 0 {
+            // every instance starts on an 8-byte boundary
+            let stride = (self.fixed_instance_size as usize).next_multiple_of(8);
+            let offset = (index as usize)
+                .checked_mul(stride)
+                .and_then(|o| o.checked_add(self.data_block_offset as usize))
+                .ok_or(QueryAllDataError::Malformed)?;
+            (offset, self.fixed_instance_size as usize)
+        } else {
+            let entry = Self::FIXED_PART_SIZE
+                + index as usize * size_of::<km_sys::OFFSETINSTANCEDATAANDLENGTH>();
+            (
+                read_u32(buffer, entry)? as usize,
+                read_u32(buffer, entry + size_of::<ULONG>())? as usize,
+            )
+        };
+
+        let data = offset
+            .checked_add(length)
+            .and_then(|end| buffer.get(offset..end))
+            .ok_or(QueryAllDataError::Malformed)?;
+
+        let name = if self.offset_instance_name_offsets != 0
+            && self.flags & WNODE_FLAG_ANSI_INSTANCENAMES == 0
+        {
+            let name_offset = read_u32(
+                buffer,
+                self.offset_instance_name_offsets as usize + index as usize * size_of::<ULONG>(),
+            )? as usize;
+            let name_len = read_u16(buffer, name_offset)? as usize;
+            let name_start = name_offset + size_of::<u16>();
+
+            Some(InstanceName(
+                buffer
+                    .get(name_start..name_start + name_len)
+                    .ok_or(QueryAllDataError::Malformed)?,
+            ))
+        } else {
+            None
+        };
+
+        Ok(WmiInstance { name, data })
+    }
+}
+
+fn read_u32(buffer: &[u8], offset: usize) -> Result<u32, QueryAllDataError> {
+    buffer
+        .get(offset..offset + size_of::<u32>())
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(QueryAllDataError::Malformed)
+}
+
+fn read_u16(buffer: &[u8], offset: usize) -> Result<u16, QueryAllDataError> {
+    buffer
+        .get(offset..offset + size_of::<u16>())
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or(QueryAllDataError::Malformed)
+}